@@ -0,0 +1,105 @@
+// The explicit returns are the style of the rest of the code
+#![allow(clippy::needless_return)]
+
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+/// The shaders that are embedded in the program
+const SHADER_DIR: &str = "src/window/graphics/shaders";
+/// Set this to the `fxc` to compile the shaders with, otherwise it is looked for on the `PATH`
+const FXC_ENV: &str = "FXC";
+
+/// Embed the HLSL files of [`SHADER_DIR`] and, on windows when `fxc` is there, their bytecode. Writes the
+/// list of `ShaderAsset`s that `ShaderSet::embedded()` includes.
+fn main() {
+    println!("cargo:rerun-if-changed={}", SHADER_DIR);
+    println!("cargo:rerun-if-env-changed={}", FXC_ENV);
+
+    let out_dir: PathBuf = PathBuf::from(env::var("OUT_DIR").unwrap());
+    let shader_dir: PathBuf = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join(SHADER_DIR);
+    let compile: bool = env::var("CARGO_CFG_TARGET_OS").as_deref() == Ok("windows");
+    let fxc: String = env::var(FXC_ENV).unwrap_or_else(|_| "fxc".to_string());
+
+    let mut files: Vec<PathBuf> = fs::read_dir(&shader_dir)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+                .filter(|path| path.extension().is_some_and(|extension| extension == "hlsl"))
+                .collect()
+        })
+        .unwrap_or_default();
+    files.sort();
+
+    let mut assets: String = "vec![\n".to_string();
+    for file in &files {
+        let name: String = file.file_name().unwrap().to_string_lossy().to_string();
+        println!("cargo:rerun-if-changed={}", file.display());
+
+        let profile: Option<&str> = profile(&name);
+        let bytecode: Option<PathBuf> = match profile {
+            Some(profile) if compile => compile_shader(&fxc, file, profile, &out_dir),
+            _ => None,
+        };
+        assets.push_str(&format!(
+            "    ShaderAsset {{ name: Cow::Borrowed({:?}), source: Some(Cow::Borrowed(include_str!({:?}))), profile: Cow::Borrowed({:?}), bytecode: {} }},\n",
+            name,
+            file.display().to_string(),
+            profile.unwrap_or_default(),
+            match bytecode {
+                Some(path) => format!("Some(Cow::Borrowed(include_bytes!({:?})))", path.display().to_string()),
+                None => "None".to_string(),
+            }
+        ));
+    }
+    assets.push(']');
+
+    fs::write(out_dir.join("embedded_shaders.rs"), assets).unwrap();
+}
+
+/// The profile of a shader by the end of its name, like `VertexShader.hlsl` or `sky_ps.hlsl`. Files
+/// without one, like includes, are only embedded as source.
+fn profile(name: &str) -> Option<&'static str> {
+    let stem: &str = name.trim_end_matches(".hlsl");
+    if stem.ends_with("VertexShader") || stem.ends_with("_vs") {
+        return Some("vs_5_0");
+    }
+    if stem.ends_with("PixelShader") || stem.ends_with("_ps") {
+        return Some("ps_5_0");
+    }
+    return None;
+}
+
+/// Compile the `main` function of a shader to a `.cso` file. When it can't be compiled the program
+/// compiles the source when it starts, so it is only a warning.
+fn compile_shader(fxc: &str, file: &Path, profile: &str, out_dir: &Path) -> Option<PathBuf> {
+    let output: PathBuf = out_dir.join(file.file_name()?).with_extension("cso");
+    let result = Command::new(fxc)
+        .args(["/nologo", "/E", "main", "/T", profile, "/Fo"])
+        .arg(&output)
+        .arg(file)
+        .output();
+
+    return match result {
+        Ok(result) if result.status.success() => Some(output),
+        Ok(result) => {
+            println!(
+                "cargo:warning=Unable to compile {}: {}",
+                file.display(),
+                String::from_utf8_lossy(&result.stderr).trim().replace('\n', " ")
+            );
+            None
+        }
+        Err(e) => {
+            println!(
+                "cargo:warning=Not precompiling {}, {} can't be run: {}",
+                file.display(),
+                fxc,
+                e
+            );
+            None
+        }
+    };
+}
@@ -5,10 +5,22 @@ use windows::Win32::UI::{
     WindowsAndMessaging::CS_OWNDC,
 };
 
-use crate::window::Window;
+use crate::{
+    log_debug, log_info,
+    window::{
+        errors::{
+            crash_report::{CrashReport, FrameStats},
+            info_queue::InfoQueueConfig,
+            Error,
+        },
+        graphics::shaderlocator::ShaderLocator,
+        headless::{HeadlessConfig, HeadlessWindow},
+        Host, Window,
+    },
+};
 
-pub struct App<'a> {
-    pub window: Window<'a>,
+pub struct App<W: Host> {
+    pub window: W,
     input_buffer: String,
     time_buffer: SystemTime,
     start_time_buffer: SystemTime,
@@ -16,6 +28,8 @@ pub struct App<'a> {
     clock_count: u128,
     fps: Fps,
     perf_counter: i64,
+    /// Amount of frames that were rendered
+    frame_count: u64,
 }
 
 struct Fps {
@@ -24,11 +38,38 @@ struct Fps {
     low: u128,
 }
 
-impl App<'_> {
-    pub fn create(width: i16, height: i16) -> App<'static> {
+impl App<Window<'static>> {
+    /// Create an app with a real window. `info_queue` configures the debug layer, see [`InfoQueueConfig`]
+    pub fn create(
+        width: i16,
+        height: i16,
+        info_queue: &InfoQueueConfig,
+        shaders: ShaderLocator,
+    ) -> Result<App<Window<'static>>, Error> {
         let debug = true;
-        let app = App {
-            window: Window::new("Example App", CS_OWNDC, width, height, debug),
+        let mut app = App::with_host(
+            Window::new("Example App", CS_OWNDC, width, height, debug, shaders)?,
+            debug,
+        );
+        if let Some(manager) = app.window.graphics.dx_info_manager.as_mut() {
+            manager.configure(info_queue)?;
+        }
+        app.window.show_window();
+        return Ok(app);
+    }
+}
+
+impl App<HeadlessWindow> {
+    /// Create an app that runs without a window or GPU, see [`HeadlessWindow`]
+    pub fn headless(config: HeadlessConfig) -> App<HeadlessWindow> {
+        return App::with_host(HeadlessWindow::new(config), true);
+    }
+}
+
+impl<W: Host> App<W> {
+    fn with_host(window: W, debug: bool) -> App<W> {
+        return App {
+            window,
             input_buffer: String::new(),
             time_buffer: SystemTime::now(),
             start_time_buffer: SystemTime::now(),
@@ -40,93 +81,129 @@ impl App<'_> {
                 low: u128::MAX,
             },
             perf_counter: 0,
+            frame_count: 0,
         };
-        app.window.show_window();
-        return app;
     }
 
-    pub fn launch(&mut self) -> usize {
+    pub fn launch(&mut self) -> Result<usize, Error> {
         let mut exit_code: Option<usize>;
         // unsafe { windows::Win32::System::Performance::QueryPerformanceCounter(&mut self.perf_counter) };
         self.time_buffer = SystemTime::now();
 
-        println!("{:#?}", self.window.width);
-        println!("{:#?}", self.window.height);
+        log_debug!("window size {}x{}", self.window.width(), self.window.height());
 
         loop {
             exit_code = self.window.handle_messages();
             if exit_code.is_some() {
                 break;
             }
-            self.render_frame();
+            self.render_frame()?;
         }
 
         if self.debug {
             self.print_fps_stats();
+            log_debug!("resource cache {}", self.window.graphics().cache_stats());
 
-            println!("{}", self.perf_counter);
+            log_debug!("perf counter {}", self.perf_counter);
 
             // Example DirectX error
             // unsafe { self.window.graphics.dx_info_manager.as_mut().unwrap().info_queue.AddMessage(windows::Win32::Graphics::Dxgi::DXGI_DEBUG_APP,
             //     windows::Win32::Graphics::Dxgi::DXGI_INFO_QUEUE_MESSAGE_CATEGORY_INITIALIZATION, windows::Win32::Graphics::Dxgi::DXGI_INFO_QUEUE_MESSAGE_SEVERITY_ERROR,
             //     69, windows::core::PCSTR::from_raw("Test Error message\0".as_ptr()))
-            // }.unwrap_or_else(|e| {
-            //     crate::window::errors::graphics::HResultError::new(e.code(), crate::loc!(), &e.message().to_string())
-            // });
-
-            for msg in self
-                .window
-                .graphics
-                .dx_info_manager
-                .as_ref()
-                .unwrap()
-                .get_messages()
-            {
-                println!("{}", msg);
-            }
+            // }.context("adding a test message")?;
+
+            // The messages of the last frame, the others were logged at the end of their frame
+            self.window.log_debug_messages()?;
         }
 
-        return exit_code.unwrap();
+        return Ok(exit_code.unwrap());
     }
 
-    pub fn render_frame(&mut self) {
+    pub fn render_frame(&mut self) -> Result<(), Error> {
         // Test
         // let angle: f32 = 70.0;
-        let angle: f32 = SystemTime::now().duration_since(self.start_time_buffer).unwrap().as_secs_f32();
-        let mouse_pos = self.window.mouse.get_pos();
+        let angle: f32 = match self.window.fixed_timestep() {
+            Some(timestep) => self.frame_count as f32 * timestep,
+            None => SystemTime::now()
+                .duration_since(self.start_time_buffer)
+                .unwrap_or_default()
+                .as_secs_f32(),
+        };
+        let mouse_pos = self.window.mouse().get_pos();
+        let (width, height) = (self.window.width(), self.window.height());
 
-        self.window.graphics.clear_buffer([0.0; 4]);
-        self.window.graphics.test_triangle(70.0, 0.0, 0.0);
-        self.window.graphics.test_triangle(
+        let graphics = self.window.graphics();
+        graphics.clear_buffer([0.0; 4])?;
+        graphics.test_triangle(70.0, 0.0, 0.0)?;
+        graphics.test_triangle(
             angle, 
-            mouse_pos.x as f32 / (self.window.width as f32 / 2.0) - 1.0, 
-            -(mouse_pos.y as f32 / (self.window.height as f32 / 2.0) - 1.0)
-        );
+            mouse_pos.x as f32 / (width as f32 / 2.0) - 1.0, 
+            -(mouse_pos.y as f32 / (height as f32 / 2.0) - 1.0)
+        )?;
 
         // App logic
-        if let Some(ch) = self.window.keyboard.read_char() {
+        if let Some(ch) = self.window.keyboard().read_char() {
             self.input_buffer.push(ch);
         }
 
-        if self.window.keyboard.key_is_pressed_pop(VK_RETURN.0) {
-            println!("{:?}", self.input_buffer);
+        if self.window.keyboard().key_is_pressed_pop(VK_RETURN.0) {
+            log_info!("input {:?}", self.input_buffer);
             self.input_buffer = "".to_string();
         }
 
         // Draw screen
-        self.window.graphics.end_frame();
+        self.window.graphics().end_frame()?;
+        self.frame_count += 1;
+        self.window.log_debug_messages()?;
 
         // Debug
         if self.debug {
             self.calc_fps();
         }
+
+        return Ok(());
+    }
+
+    /// Collect everything that helps to find the cause of a fatal error, see [`CrashReport`]
+    pub fn crash_report(&mut self, error: &Error) -> CrashReport {
+        let mut report: CrashReport = CrashReport::from_error(error);
+        report.debug_messages = self.window.debug_messages();
+        report.frame_stats = self.frame_stats();
+        report.adapter = self.window.graphics().adapter_description();
+        report.config = vec![
+            ("width".to_string(), self.window.width().to_string()),
+            ("height".to_string(), self.window.height().to_string()),
+            ("debug".to_string(), self.debug.to_string()),
+            (
+                "fixed timestep".to_string(),
+                match self.window.fixed_timestep() {
+                    Some(timestep) => timestep.to_string(),
+                    None => "none".to_string(),
+                },
+            ),
+            ("args".to_string(), std::env::args().collect::<Vec<String>>().join(" ")),
+        ];
+        return report;
+    }
+
+    pub fn frame_stats(&self) -> FrameStats {
+        return FrameStats {
+            frames: self.frame_count,
+            fps_high: self.fps.high,
+            fps_low: if self.clock_count == 0 { 0 } else { self.fps.low },
+            fps_avg: self.fps.total.checked_div(self.clock_count).unwrap_or(0),
+            run_time: SystemTime::now()
+                .duration_since(self.start_time_buffer)
+                .map_or(0.0, |time| time.as_secs_f32()),
+        };
     }
 
     fn calc_fps(&mut self) {
         let time_alive: std::time::Duration =
-            SystemTime::now().duration_since(self.time_buffer).unwrap();
+            SystemTime::now().duration_since(self.time_buffer).unwrap_or_default();
 
-        let frame_time = time_alive.as_micros();
+        // Headless frames can take less than a microsecond
+        let frame_time = time_alive.as_micros().max(1);
 
         let cur = 1_000_000 / frame_time;
 
@@ -145,11 +222,82 @@ impl App<'_> {
     }
 
     fn print_fps_stats(&self) {
-        if !self.debug {
+        if !self.debug || self.clock_count == 0 {
             return;
         }
-        println!("fps highest: {}", self.fps.high);
-        println!("fps lowest: {}", self.fps.low);
-        println!("fps avg: {}", self.fps.total / self.clock_count);
+        log_info!("fps highest: {}", self.fps.high);
+        log_info!("fps lowest: {}", self.fps.low);
+        log_info!("fps avg: {}", self.fps.total / self.clock_count);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::window::{
+        graphics::backend::software::golden::{GoldenImage, GoldenOutcome, Tolerance},
+        headless::{HeadlessRenderer, SyntheticInput},
+    };
+
+    use windows::Win32::UI::Input::KeyboardAndMouse::VK_SPACE;
+
+    #[test]
+    fn headless_app_runs_its_frames_and_exits_with_0() {
+        let mut app: App<HeadlessWindow> = App::headless(HeadlessConfig::new(64, 48, 5));
+        assert_eq!(app.launch().unwrap(), 0);
+        assert_eq!(app.frame_stats().frames, 5);
+        assert_eq!(app.window.frame, 5);
+    }
+
+    #[test]
+    fn software_run_matches_the_reference_image_of_the_readme() {
+        // Like `--headless 3 --renderer software --size 160x120 --golden tests/data/golden/headless_cube.ppm`
+        let mut config: HeadlessConfig = HeadlessConfig::new(160, 120, 3);
+        config.renderer = HeadlessRenderer::Software;
+        let mut app: App<HeadlessWindow> = App::headless(config);
+        assert_eq!(app.launch().unwrap(), 0);
+
+        let golden: GoldenImage = GoldenImage::new(
+            std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/data/golden/headless_cube.ppm"),
+            Tolerance::default(),
+        );
+        match golden.check(app.window.graphics.read_frame().unwrap()) {
+            Ok(GoldenOutcome::Matched(_) | GoldenOutcome::Written) => {}
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    #[test]
+    fn quit_input_stops_the_app_with_its_exit_code() {
+        let config: HeadlessConfig =
+            HeadlessConfig::new(64, 48, 10).with_input(3, SyntheticInput::Quit(7));
+        let mut app: App<HeadlessWindow> = App::headless(config);
+        assert_eq!(app.launch().unwrap(), 7);
+        // The frame with the input isn't rendered
+        assert_eq!(app.frame_stats().frames, 3);
+    }
+
+    #[test]
+    fn keyboard_input_reaches_the_app() {
+        let config: HeadlessConfig = HeadlessConfig::new(64, 48, 2)
+            .with_input(0, SyntheticInput::Char('h'))
+            .with_input(1, SyntheticInput::Char('i'))
+            .with_input(1, SyntheticInput::KeyDown(VK_SPACE.0 as u32));
+        let mut app: App<HeadlessWindow> = App::headless(config);
+        assert_eq!(app.launch().unwrap(), 0);
+        assert_eq!(app.input_buffer, "hi");
+        assert!(app.window.keyboard.key_is_pressed(VK_SPACE.0));
+
+        // Return takes the line that was typed
+        let config: HeadlessConfig = HeadlessConfig::new(64, 48, 3)
+            .with_input(0, SyntheticInput::Char('h'))
+            .with_input(1, SyntheticInput::KeyDown(VK_SPACE.0 as u32))
+            .with_input(1, SyntheticInput::KeyUp(VK_SPACE.0 as u32))
+            .with_input(2, SyntheticInput::KeyDown(VK_RETURN.0 as u32));
+        let mut app: App<HeadlessWindow> = App::headless(config);
+        assert_eq!(app.launch().unwrap(), 0);
+        assert_eq!(app.input_buffer, "");
+        assert!(!app.window.keyboard.key_is_pressed(VK_SPACE.0));
+        assert!(!app.window.keyboard.key_is_pressed(VK_RETURN.0));
     }
 }
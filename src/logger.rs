@@ -0,0 +1,563 @@
+use std::{
+    collections::VecDeque,
+    fmt,
+    fs::{self, File},
+    io::Write,
+    path::PathBuf,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+/// How important a log record is, from most to least important
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Level {
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl Level {
+    pub fn name(self) -> &'static str {
+        return match self {
+            Level::Error => "ERROR",
+            Level::Warn => "WARN",
+            Level::Info => "INFO",
+            Level::Debug => "DEBUG",
+            Level::Trace => "TRACE",
+        };
+    }
+
+    /// Parse a level name like `warn`. `off` is `Ok(None)`, which means nothing is logged.
+    pub fn parse(name: &str) -> Result<Option<Level>, String> {
+        return match name.trim().to_ascii_lowercase().as_str() {
+            "off" => Ok(None),
+            "error" => Ok(Some(Level::Error)),
+            "warn" | "warning" => Ok(Some(Level::Warn)),
+            "info" => Ok(Some(Level::Info)),
+            "debug" => Ok(Some(Level::Debug)),
+            "trace" => Ok(Some(Level::Trace)),
+            other => Err(format!("Unknown log level {:?}", other)),
+        };
+    }
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // pad so the records line up
+        f.pad(self.name())
+    }
+}
+
+/// A single log message
+#[derive(Debug, Clone, PartialEq)]
+pub struct Record {
+    pub level: Level,
+    /// The module the record was logged from without the crate name, like `app` or `window::graphics`.
+    /// Forwarded debug layer messages use `d3d11` and `dxgi`.
+    pub target: String,
+    pub message: String,
+    /// Time since the logger was created
+    pub elapsed: Duration,
+}
+
+impl fmt::Display for Record {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:>9.3} {:<5} {}: {}",
+            self.elapsed.as_secs_f32(),
+            self.level,
+            self.target,
+            self.message
+        )
+    }
+}
+
+/// Decides which records are logged. The most specific target wins, a target also matches its sub modules.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LevelFilter {
+    /// Level of targets that have no level of their own. `None` turns logging off.
+    pub default: Option<Level>,
+    pub targets: Vec<(String, Option<Level>)>,
+}
+
+impl LevelFilter {
+    pub fn new(default: Option<Level>) -> LevelFilter {
+        return LevelFilter {
+            default,
+            targets: vec![],
+        };
+    }
+
+    /// Parse a filter like `info,window::message=trace,d3d11=warn`. A level without a target is the default.
+    pub fn parse(spec: &str) -> Result<LevelFilter, String> {
+        let mut filter: LevelFilter = LevelFilter::new(Some(Level::Info));
+        for part in spec
+            .split(',')
+            .map(str::trim)
+            .filter(|part| !part.is_empty())
+        {
+            match part.split_once('=') {
+                Some((target, level)) => filter
+                    .targets
+                    .push((target.trim().to_string(), Level::parse(level)?)),
+                None => filter.default = Level::parse(part)?,
+            }
+        }
+        return Ok(filter);
+    }
+
+    /// The most detailed level that is logged for a target
+    pub fn level_for(&self, target: &str) -> Option<Level> {
+        return self
+            .targets
+            .iter()
+            .filter(|(filter_target, _)| {
+                target == filter_target
+                    || target
+                        .strip_prefix(filter_target.as_str())
+                        .is_some_and(|rest| rest.starts_with("::"))
+            })
+            .max_by_key(|(filter_target, _)| filter_target.len())
+            .map_or(self.default, |(_, level)| *level);
+    }
+
+    pub fn enabled(&self, level: Level, target: &str) -> bool {
+        return self.level_for(target).is_some_and(|max| level <= max);
+    }
+}
+
+/// Somewhere records are written to
+pub trait Sink: Send {
+    fn write(&mut self, record: &Record);
+    fn flush(&mut self) {}
+}
+
+/// Writes records to stderr
+pub struct StderrSink;
+
+impl Sink for StderrSink {
+    fn write(&mut self, record: &Record) {
+        eprintln!("{}", record);
+    }
+}
+
+/// Writes records to a file. When the file gets bigger than `max_bytes` it's renamed to `<path>.1`, the old
+/// `<path>.1` to `<path>.2` and so on. Only `keep` old files are kept.
+pub struct FileSink {
+    pub path: PathBuf,
+    pub max_bytes: u64,
+    pub keep: u32,
+    file: Option<File>,
+    written: u64,
+}
+
+impl FileSink {
+    /// Open the log file, records are appended to it
+    pub fn new(path: PathBuf, max_bytes: u64, keep: u32) -> std::io::Result<FileSink> {
+        let file: File = File::options().create(true).append(true).open(&path)?;
+        let written: u64 = file.metadata()?.len();
+        return Ok(FileSink {
+            path,
+            max_bytes,
+            keep,
+            file: Some(file),
+            written,
+        });
+    }
+
+    /// The path of the `index`th old log file
+    pub fn rotated_path(&self, index: u32) -> PathBuf {
+        let mut path = self.path.clone().into_os_string();
+        path.push(format!(".{}", index));
+        return PathBuf::from(path);
+    }
+
+    fn rotate(&mut self) -> std::io::Result<()> {
+        // Close the file before renaming it, windows doesn't like renaming open files
+        self.file = None;
+
+        if self.keep == 0 {
+            fs::remove_file(&self.path)?;
+        } else {
+            let _ = fs::remove_file(self.rotated_path(self.keep));
+            for index in (1..self.keep).rev() {
+                let from: PathBuf = self.rotated_path(index);
+                if from.exists() {
+                    fs::rename(&from, self.rotated_path(index + 1))?;
+                }
+            }
+            fs::rename(&self.path, self.rotated_path(1))?;
+        }
+
+        self.file = Some(File::create(&self.path)?);
+        self.written = 0;
+        return Ok(());
+    }
+}
+
+impl Sink for FileSink {
+    fn write(&mut self, record: &Record) {
+        let line: String = format!("{}\n", record);
+        if self.written > 0 && self.written + line.len() as u64 > self.max_bytes {
+            if let Err(e) = self.rotate() {
+                eprintln!("Unable to rotate log file {}: {}", self.path.display(), e);
+            }
+        }
+
+        if let Some(file) = self.file.as_mut() {
+            if file.write_all(line.as_bytes()).is_ok() {
+                self.written += line.len() as u64;
+            }
+        }
+    }
+
+    fn flush(&mut self) {
+        if let Some(file) = self.file.as_mut() {
+            let _ = file.flush();
+        }
+    }
+}
+
+/// Keeps the last records in memory, for an in-app console. Read them with the [`RingBuffer`] that
+/// [`RingBufferSink::new()`] returns.
+pub struct RingBufferSink {
+    buffer: RingBuffer,
+}
+
+/// Handle to the records of a [`RingBufferSink`]
+#[derive(Clone)]
+pub struct RingBuffer {
+    lines: Arc<Mutex<VecDeque<Record>>>,
+    capacity: usize,
+}
+
+impl RingBufferSink {
+    pub fn new(capacity: usize) -> (RingBufferSink, RingBuffer) {
+        let buffer: RingBuffer = RingBuffer {
+            lines: Arc::new(Mutex::new(VecDeque::with_capacity(capacity))),
+            capacity,
+        };
+        return (
+            RingBufferSink {
+                buffer: buffer.clone(),
+            },
+            buffer,
+        );
+    }
+}
+
+impl Sink for RingBufferSink {
+    fn write(&mut self, record: &Record) {
+        if let Ok(mut lines) = self.buffer.lines.lock() {
+            if lines.len() == self.buffer.capacity {
+                lines.pop_front();
+            }
+            if self.buffer.capacity > 0 {
+                lines.push_back(record.clone());
+            }
+        }
+    }
+}
+
+impl RingBuffer {
+    /// The records in the buffer, oldest first
+    pub fn records(&self) -> Vec<Record> {
+        return match self.lines.lock() {
+            Ok(lines) => lines.iter().cloned().collect(),
+            Err(_) => vec![],
+        };
+    }
+
+    pub fn clear(&self) {
+        if let Ok(mut lines) = self.lines.lock() {
+            lines.clear();
+        }
+    }
+}
+
+/// Writes records to the output window of the debugger with `OutputDebugStringA`
+#[cfg(windows)]
+pub struct DebuggerSink;
+
+#[cfg(windows)]
+impl Sink for DebuggerSink {
+    fn write(&mut self, record: &Record) {
+        let line: String = format!("{}\n\0", record);
+        unsafe {
+            windows::Win32::System::Diagnostics::Debug::OutputDebugStringA(
+                windows::core::PCSTR::from_raw(line.as_ptr()),
+            )
+        };
+    }
+}
+
+/// Filters records and sends them to all of its sinks
+pub struct Logger {
+    pub filter: LevelFilter,
+    sinks: Vec<Box<dyn Sink>>,
+    start: Instant,
+}
+
+impl Logger {
+    pub fn new(filter: LevelFilter) -> Logger {
+        return Logger {
+            filter,
+            sinks: vec![],
+            start: Instant::now(),
+        };
+    }
+
+    pub fn with_sink(mut self, sink: Box<dyn Sink>) -> Logger {
+        self.sinks.push(sink);
+        return self;
+    }
+
+    pub fn log(&mut self, level: Level, target: &str, message: fmt::Arguments) {
+        let target: &str = strip_crate_name(target);
+        if !self.filter.enabled(level, target) {
+            return;
+        }
+
+        let record: Record = Record {
+            level,
+            target: target.to_string(),
+            message: message.to_string(),
+            elapsed: self.start.elapsed(),
+        };
+        for sink in self.sinks.iter_mut() {
+            sink.write(&record);
+        }
+    }
+
+    pub fn flush(&mut self) {
+        for sink in self.sinks.iter_mut() {
+            sink.flush();
+        }
+    }
+}
+
+static LOGGER: Mutex<Option<Logger>> = Mutex::new(None);
+
+/// Install the logger the log macros write to, like [`crate::log_info!`]. Nothing is logged before this is called.
+pub fn init(logger: Logger) {
+    if let Ok(mut current) = LOGGER.lock() {
+        *current = Some(logger);
+    }
+}
+
+/// Remove the logger and flush its sinks
+pub fn shutdown() -> Option<Logger> {
+    let mut logger: Option<Logger> = LOGGER.lock().ok()?.take();
+    if let Some(logger) = logger.as_mut() {
+        logger.flush();
+    }
+    return logger;
+}
+
+/// Log a record with the installed logger. Use the macros instead, they fill in the target.
+pub fn log(level: Level, target: &str, message: fmt::Arguments) {
+    if let Ok(mut current) = LOGGER.lock() {
+        if let Some(logger) = current.as_mut() {
+            logger.log(level, target, message);
+        }
+    }
+}
+
+/// Flush the sinks of the installed logger. Does nothing when the logger is in use, this is also called
+/// from the panic hook, which could run while a record is being written.
+pub fn flush() {
+    if let Ok(mut current) = LOGGER.try_lock() {
+        if let Some(logger) = current.as_mut() {
+            logger.flush();
+        }
+    }
+}
+
+/// Targets are module paths, the crate name in front of them is the same for every record
+fn strip_crate_name(target: &str) -> &str {
+    let crate_name: &str = env!("CARGO_CRATE_NAME");
+    if target == crate_name {
+        return "main";
+    }
+    return target
+        .strip_prefix(crate_name)
+        .and_then(|rest| rest.strip_prefix("::"))
+        .unwrap_or(target);
+}
+
+#[macro_export]
+macro_rules! log_error {
+    ($($arg:tt)*) => {
+        $crate::logger::log($crate::logger::Level::Error, module_path!(), format_args!($($arg)*))
+    };
+}
+
+#[macro_export]
+macro_rules! log_warn {
+    ($($arg:tt)*) => {
+        $crate::logger::log($crate::logger::Level::Warn, module_path!(), format_args!($($arg)*))
+    };
+}
+
+#[macro_export]
+macro_rules! log_info {
+    ($($arg:tt)*) => {
+        $crate::logger::log($crate::logger::Level::Info, module_path!(), format_args!($($arg)*))
+    };
+}
+
+#[macro_export]
+macro_rules! log_debug {
+    ($($arg:tt)*) => {
+        $crate::logger::log($crate::logger::Level::Debug, module_path!(), format_args!($($arg)*))
+    };
+}
+
+#[macro_export]
+macro_rules! log_trace {
+    ($($arg:tt)*) => {
+        $crate::logger::log($crate::logger::Level::Trace, module_path!(), format_args!($($arg)*))
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(message: &str) -> Record {
+        return Record {
+            level: Level::Warn,
+            target: "window::graphics".to_string(),
+            message: message.to_string(),
+            elapsed: Duration::from_millis(1500),
+        };
+    }
+
+    #[test]
+    fn parses_levels() {
+        assert_eq!(Level::parse(" Warning "), Ok(Some(Level::Warn)));
+        assert_eq!(Level::parse("off"), Ok(None));
+        assert_eq!(
+            Level::parse("loud"),
+            Err("Unknown log level \"loud\"".to_string())
+        );
+        assert!(Level::Error < Level::Trace);
+    }
+
+    #[test]
+    fn records_line_up() {
+        assert_eq!(
+            record("slow frame").to_string(),
+            "    1.500 WARN  window::graphics: slow frame"
+        );
+    }
+
+    #[test]
+    fn the_most_specific_target_wins() {
+        let filter: LevelFilter =
+            LevelFilter::parse("warn, window=debug,window::message=off").unwrap();
+        assert_eq!(filter.default, Some(Level::Warn));
+        assert_eq!(filter.level_for("app"), Some(Level::Warn));
+        assert_eq!(filter.level_for("window"), Some(Level::Debug));
+        assert_eq!(
+            filter.level_for("window::graphics::cache"),
+            Some(Level::Debug)
+        );
+        assert_eq!(filter.level_for("window::message"), None);
+        // A target only matches whole modules
+        assert_eq!(filter.level_for("window::messages"), Some(Level::Debug));
+        assert_eq!(filter.level_for("windows"), Some(Level::Warn));
+
+        assert!(filter.enabled(Level::Debug, "window::mouse"));
+        assert!(!filter.enabled(Level::Trace, "window::mouse"));
+        assert!(!filter.enabled(Level::Error, "window::message"));
+        assert!(LevelFilter::parse("window=loud").is_err());
+    }
+
+    #[test]
+    fn logger_filters_and_strips_the_crate_name() {
+        let (sink, buffer) = RingBufferSink::new(8);
+        let mut logger: Logger =
+            Logger::new(LevelFilter::parse("info,d3d11=warn").unwrap()).with_sink(Box::new(sink));
+        let crate_name: &str = env!("CARGO_CRATE_NAME");
+        logger.log(Level::Info, crate_name, format_args!("started"));
+        logger.log(
+            Level::Debug,
+            &format!("{}::app", crate_name),
+            format_args!("hidden"),
+        );
+        logger.log(Level::Info, "d3d11", format_args!("hidden too"));
+        logger.log(Level::Error, "d3d11", format_args!("device {}", "removed"));
+
+        let records: Vec<(String, String)> = buffer
+            .records()
+            .into_iter()
+            .map(|record| (record.target, record.message))
+            .collect();
+        assert_eq!(
+            records,
+            vec![
+                ("main".to_string(), "started".to_string()),
+                ("d3d11".to_string(), "device removed".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn ring_buffer_keeps_the_last_records() {
+        let (mut sink, buffer) = RingBufferSink::new(2);
+        for message in ["a", "b", "c"] {
+            sink.write(&record(message));
+        }
+        let messages: Vec<String> = buffer
+            .records()
+            .into_iter()
+            .map(|record| record.message)
+            .collect();
+        assert_eq!(messages, vec!["b", "c"]);
+        buffer.clear();
+        assert!(buffer.records().is_empty());
+
+        let (mut sink, buffer) = RingBufferSink::new(0);
+        sink.write(&record("a"));
+        assert!(buffer.records().is_empty());
+    }
+
+    #[test]
+    fn file_sink_rotates_and_keeps_old_files() {
+        let dir: PathBuf = std::env::temp_dir().join(format!("logger_test_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path: PathBuf = dir.join("app.log");
+        let line_len: u64 = format!("{}\n", record("line 0")).len() as u64;
+
+        // Two lines fit in a file, so seven lines end up in four files and only two old ones are kept
+        let mut sink: FileSink = FileSink::new(path.clone(), line_len * 2, 2).unwrap();
+        for index in 0..7 {
+            sink.write(&record(&format!("line {}", index)));
+        }
+        sink.flush();
+
+        let read = |path: PathBuf| -> Vec<String> {
+            return fs::read_to_string(path)
+                .unwrap()
+                .lines()
+                .map(|line| line.rsplit(' ').next().unwrap().to_string())
+                .collect();
+        };
+        assert_eq!(read(path.clone()), vec!["6"]);
+        assert_eq!(read(sink.rotated_path(1)), vec!["4", "5"]);
+        assert_eq!(read(sink.rotated_path(2)), vec!["2", "3"]);
+        assert!(!sink.rotated_path(3).exists());
+
+        // A new sink appends to the file that is there
+        let mut sink: FileSink = FileSink::new(path.clone(), line_len * 2, 0).unwrap();
+        sink.write(&record("line 7"));
+        sink.write(&record("line 8"));
+        sink.flush();
+        assert_eq!(read(path.clone()), vec!["8"]);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -1,15 +1,482 @@
 use app::App;
 
 mod app;
+mod logger;
 mod window;
 
+use std::path::{Path, PathBuf};
+
+use logger::{FileSink, LevelFilter, Logger, StderrSink};
+use window::{
+    errors::{
+        crash_report::CrashReport,
+        info_queue::InfoQueueConfig,
+        message_text,
+        panic_hook::{self, PanicHook},
+        reporter::Reporter,
+        Error,
+    },
+    graphics::{
+        backend::software::golden::{GoldenImage, GoldenOutcome, Tolerance},
+        scene::{Node, Scene},
+        shaderlocator::{ShaderLocator, ShaderSet},
+    },
+    headless::{HeadlessConfig, HeadlessRenderer, HeadlessWindow, SyntheticInput},
+    message::{self, Spy, SpyFilter},
+    Host,
+};
+
+/// Directory crash reports are written to when `--crash-dir` isn't passed
+const DEFAULT_CRASH_DIR: &str = "crash_reports";
+
+/// Log files are rotated when they get bigger than this
+const LOG_FILE_MAX_BYTES: u64 = 1024 * 1024;
+/// Amount of rotated log files that are kept
+const LOG_FILES_KEPT: u32 = 3;
+
+/// Amount of frames that are rendered on platforms that can't create a window
+const DEFAULT_HEADLESS_FRAMES: u32 = 60;
+
 fn main() {
+    let args: Vec<String> = std::env::args().collect();
+
+    // count the debug layer messages in a saved log with `--summarize-messages <file>`
+    if let Some(path) = arg_value(&args, "--summarize-messages") {
+        std::process::exit(summarize_messages(path));
+    }
+
+    // pretty-print a crash report with `--read-crash-report <file>`
+    if let Some(path) = arg_value(&args, "--read-crash-report") {
+        std::process::exit(read_crash_report(path));
+    }
+
+    // write the embedded shaders to an asset pack with `--write-shader-pack <file>`, see `SHADER_PACK`
+    if let Some(path) = arg_value(&args, "--write-shader-pack") {
+        std::process::exit(write_shader_pack(path));
+    }
+
+    // pick what is logged with `--log info,window::message=trace` and also log to a file with `--log-file <file>`
+    logger::init(create_logger(&args));
+
+    // load a mesh and print what it has with `--inspect-mesh <file>`
+    if let Some(path) = arg_value(&args, "--inspect-mesh") {
+        let exit_code: i32 = inspect_mesh(path);
+        logger::shutdown();
+        std::process::exit(exit_code);
+    }
+
+    // log the window messages to a file with `--spy <file>` and optionally `--spy-filter WM_KEYDOWN,-WM_CHAR`
+    if let Some(path) = arg_value(&args, "--spy") {
+        let filter: SpyFilter = match arg_value(&args, "--spy-filter") {
+            Some(spec) => SpyFilter::parse(spec).unwrap_or_else(|e| {
+                eprintln!("{}", e);
+                std::process::exit(2);
+            }),
+            None => SpyFilter::quiet(),
+        };
+
+        match Spy::to_file(std::path::Path::new(path), filter) {
+            Ok(spy) => message::start_spy(spy),
+            Err(e) => eprintln!("Unable to create spy log {}: {}", path, e),
+        }
+    }
+
+    // pick how errors are shown with `--error-report <messagebox|stderr|file>`
+    let reporter: Reporter = arg_value(&args, "--error-report")
+        .map(Reporter::parse)
+        .unwrap_or_default();
+
+    // write crash reports to another directory with `--crash-dir <dir>`
+    let crash_dir: &Path = Path::new(arg_value(&args, "--crash-dir").unwrap_or(DEFAULT_CRASH_DIR));
+
+    // configure the debug layer with `--message-limit <n>`, `--deny-messages <ids>` and `--break-on <corruption|error>`
+    let info_queue: InfoQueueConfig = create_info_queue_config(&args).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        std::process::exit(2);
+    });
+
+    // report panics the same way as errors, with a crash report
+    panic_hook::install(PanicHook {
+        reporter: reporter.clone(),
+        crash_dir: crash_dir.to_path_buf(),
+        debug_layer: cfg!(windows) && arg_value(&args, "--headless").is_none(),
+    });
+
+    // find the shaders in the folders of SHADER_PATH, the packs of SHADER_PACK and the embedded shaders
+    let shaders: ShaderLocator = ShaderLocator::from_env();
+
+    // configure a run without a window with `--headless <frames>`, `--size <width>x<height>`, `--renderer <null|software>` and `--input <frame>:<input>,...`
+    let mut headless: HeadlessConfig = create_headless_config(&args).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        std::process::exit(2);
+    });
+    headless.shaders = shaders.clone();
+
+    // compare the last headless frame with a reference image with `--golden <file.ppm>` and `--golden-tolerance <channel>[,<pixels>]`
+    let golden: Option<GoldenImage> = create_golden_image(&args, headless.renderer).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        std::process::exit(2);
+    });
+
+    // run without a window with `--headless <frames>`
+    let exit_code: i32 = match arg_value(&args, "--headless") {
+        Some(_) => run_headless(headless, golden.as_ref(), &reporter, crash_dir),
+        None => run_windowed(&reporter, crash_dir, &info_queue, shaders, headless, golden.as_ref()),
+    };
+
+    message::stop_spy();
+    logger::shutdown();
+
+    std::process::exit(exit_code);
+}
+
+#[cfg(windows)]
+fn run_windowed(
+    reporter: &Reporter,
+    crash_dir: &Path,
+    info_queue: &InfoQueueConfig,
+    shaders: ShaderLocator,
+    _headless: HeadlessConfig,
+    _golden: Option<&GoldenImage>,
+) -> i32 {
     // create an app
-    let mut app: App = app::App::create(1000, 750);
+    let created: Result<App<window::Window>, Error> =
+        panic_hook::catch(|| app::App::create(1000, 750, info_queue, shaders));
+    let mut app: App<window::Window> = match created {
+        Ok(app) => app,
+        // reported by the panic hook
+        Err(e @ Error::Panic { .. }) => return e.exit_code(),
+        Err(e) => {
+            reporter.report(&e, &[]);
+            write_crash_report(&CrashReport::from_error(&e), crash_dir);
+            return e.exit_code();
+        }
+    };
 
     // launch the app
-    app.launch();
+    let exit_code: i32 = match panic_hook::catch(|| app.launch()) {
+        Ok(exit_code) => exit_code as i32,
+        Err(e) => {
+            report_error(&mut app, &e, reporter, crash_dir);
+            return e.exit_code();
+        }
+    };
 
     // print the exit codes
     app.window.print_exit_codes();
+
+    return exit_code;
+}
+
+#[cfg(not(windows))]
+fn run_windowed(
+    reporter: &Reporter,
+    crash_dir: &Path,
+    _info_queue: &InfoQueueConfig,
+    _shaders: ShaderLocator,
+    headless: HeadlessConfig,
+    golden: Option<&GoldenImage>,
+) -> i32 {
+    // There is no win32 on this platform so the only thing we can do is run headless
+    return run_headless(headless, golden, reporter, crash_dir);
+}
+
+fn run_headless(
+    config: HeadlessConfig,
+    golden: Option<&GoldenImage>,
+    reporter: &Reporter,
+    crash_dir: &Path,
+) -> i32 {
+    let mut app = App::headless(config);
+    let exit_code: i32 = match panic_hook::catch(|| app.launch()) {
+        Ok(exit_code) => exit_code as i32,
+        Err(e) => {
+            report_error(&mut app, &e, reporter, crash_dir);
+            return e.exit_code();
+        }
+    };
+
+    return match golden {
+        Some(golden) if exit_code == 0 => check_golden_image(&app.window, golden),
+        _ => exit_code,
+    };
+}
+
+/// Compare the last frame of a headless run with its reference image. A frame that doesn't match fails
+/// the run with exit code 1.
+fn check_golden_image(window: &HeadlessWindow, golden: &GoldenImage) -> i32 {
+    let Some(frame) = window.graphics.read_frame() else {
+        log_error!("The renderer can't read back frames to compare with {}", golden.path.display());
+        return 1;
+    };
+
+    return match golden.check(frame) {
+        Ok(GoldenOutcome::Matched(diff)) => {
+            log_info!(
+                "The frame matches {}, the biggest difference is {}",
+                golden.path.display(),
+                diff.max_difference
+            );
+            0
+        }
+        Ok(GoldenOutcome::Written) => {
+            log_info!("Wrote the reference image {}", golden.path.display());
+            0
+        }
+        Err(e) => {
+            log_error!("{}", e);
+            1
+        }
+    };
+}
+
+fn create_headless_config(args: &[String]) -> Result<HeadlessConfig, String> {
+    let frames: u32 = match arg_value(args, "--headless") {
+        Some(frames) => frames
+            .parse()
+            .map_err(|_| format!("--headless expects an amount of frames, got {}", frames))?,
+        None => DEFAULT_HEADLESS_FRAMES,
+    };
+
+    let (width, height) = match arg_value(args, "--size") {
+        Some(size) => parse_size(size)?,
+        None => (1000, 750),
+    };
+
+    let mut config: HeadlessConfig = HeadlessConfig::new(width, height, frames);
+    if let Some(value) = arg_value(args, "--renderer") {
+        config.renderer = HeadlessRenderer::parse(value)?;
+    }
+    if let Some(spec) = arg_value(args, "--input") {
+        for (frame, input) in SyntheticInput::parse_list(spec)? {
+            config = config.with_input(frame, input);
+        }
+    }
+    return Ok(config);
+}
+
+/// Parse a size like `1000x750`
+fn parse_size(size: &str) -> Result<(i16, i16), String> {
+    let parsed: Option<(i16, i16)> = size.split_once('x').and_then(|(width, height)| {
+        return Some((width.parse().ok()?, height.parse().ok()?));
+    });
+    return match parsed {
+        Some((width, height)) if width > 0 && height > 0 => Ok((width, height)),
+        _ => Err(format!("--size expects <width>x<height>, got {}", size)),
+    };
+}
+
+fn create_golden_image(args: &[String], renderer: HeadlessRenderer) -> Result<Option<GoldenImage>, String> {
+    let Some(path) = arg_value(args, "--golden") else {
+        return Ok(None);
+    };
+    if renderer != HeadlessRenderer::Software {
+        return Err("--golden needs a renderer that draws the frames, like --renderer software".to_string());
+    }
+
+    let tolerance: Tolerance = match arg_value(args, "--golden-tolerance") {
+        Some(value) => Tolerance::parse(value)?,
+        None => Tolerance::default(),
+    };
+    return Ok(Some(GoldenImage::new(path.into(), tolerance)));
+}
+
+/// Report an error that stopped the app and write a crash report for it
+fn report_error<W: Host>(app: &mut App<W>, error: &Error, reporter: &Reporter, crash_dir: &Path) {
+    // The panic hook already reported it
+    if let Error::Panic { .. } = error {
+        return;
+    }
+
+    // The messages of the frame that failed weren't logged yet
+    if let Err(e) = app.window.log_debug_messages() {
+        log_warn!("Unable to log the last debug messages: {}", e);
+    }
+
+    reporter.report(error, &app.window.debug_messages());
+    let report: CrashReport = app.crash_report(error);
+    write_crash_report(&report, crash_dir);
+
+    // The messages as JSON lines are easier to filter than the ones in the report
+    let messages_path: PathBuf = crash_dir.join(format!("crash-{}.jsonl", report.time));
+    if let Err(e) = app.window.export_debug_messages(&messages_path) {
+        log_warn!("Unable to export the debug messages: {}", e);
+    }
+}
+
+fn create_logger(args: &[String]) -> Logger {
+    let filter: LevelFilter = match arg_value(args, "--log") {
+        Some(spec) => LevelFilter::parse(spec).unwrap_or_else(|e| {
+            eprintln!("{}", e);
+            std::process::exit(2);
+        }),
+        None => LevelFilter::new(Some(logger::Level::Info)),
+    };
+
+    let mut logger: Logger = Logger::new(filter).with_sink(Box::new(StderrSink));
+
+    #[cfg(windows)]
+    {
+        logger = logger.with_sink(Box::new(logger::DebuggerSink));
+    }
+
+    if let Some(path) = arg_value(args, "--log-file") {
+        match FileSink::new(path.into(), LOG_FILE_MAX_BYTES, LOG_FILES_KEPT) {
+            Ok(sink) => logger = logger.with_sink(Box::new(sink)),
+            Err(e) => eprintln!("Unable to open log file {}: {}", path, e),
+        }
+    }
+    return logger;
+}
+
+fn create_info_queue_config(args: &[String]) -> Result<InfoQueueConfig, String> {
+    let mut config: InfoQueueConfig = InfoQueueConfig::default();
+    if let Some(limit) = arg_value(args, "--message-limit") {
+        config.message_limit = limit
+            .parse()
+            .map_err(|_| format!("--message-limit expects an amount of messages, got {}", limit))?;
+    }
+    if let Some(spec) = arg_value(args, "--deny-messages") {
+        config.deny_ids = InfoQueueConfig::parse_deny_ids(spec)?;
+    }
+    if let Some(severity) = arg_value(args, "--break-on") {
+        config.break_on = InfoQueueConfig::parse_break_on(severity)?;
+    }
+    return Ok(config);
+}
+
+fn write_crash_report(report: &CrashReport, crash_dir: &Path) {
+    match report.write_to_dir(crash_dir) {
+        Ok(path) => eprintln!("Crash report written to {}", path.display()),
+        Err(e) => eprintln!("Unable to write a crash report to {}: {}", crash_dir.display(), e),
+    }
+}
+
+/// Pretty-print a crash report that was written by [`write_crash_report()`]
+fn read_crash_report(path: &str) -> i32 {
+    let report: Result<CrashReport, String> = std::fs::read_to_string(path)
+        .map_err(|e| e.to_string())
+        .and_then(|text| CrashReport::parse(&text));
+
+    return match report {
+        Ok(report) => {
+            println!("{}", report);
+            0
+        }
+        Err(e) => {
+            eprintln!("Unable to read crash report {}: {}", path, e);
+            1
+        }
+    };
+}
+
+/// Write the shaders that are embedded in the program to an asset pack
+fn write_shader_pack(path: &str) -> i32 {
+    let shaders: ShaderSet = ShaderSet::embedded();
+    return match shaders.write_pack(Path::new(path)) {
+        Ok(()) => {
+            println!("Wrote {} shaders to {}", shaders.assets.len(), path);
+            0
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            1
+        }
+    };
+}
+
+/// Print the meshes, submeshes, materials and nodes of a mesh or scene file
+fn inspect_mesh(path: &str) -> i32 {
+    let scene: Scene = match Scene::load(Path::new(path)) {
+        Ok(scene) => scene,
+        Err(e) => {
+            eprintln!("{}", e);
+            return 1;
+        }
+    };
+
+    for (index, mesh) in scene.meshes.iter().enumerate() {
+        println!(
+            "mesh {}: {} vertices, {} triangles",
+            index,
+            mesh.vertices.len(),
+            mesh.triangle_count()
+        );
+        for submesh in &mesh.submeshes {
+            println!(
+                "  {:<24} {:>8} triangles  {}",
+                match submesh.name.is_empty() {
+                    true => "(unnamed)",
+                    false => &submesh.name,
+                },
+                submesh.index_count / 3,
+                submesh
+                    .material
+                    .map(|material| mesh.materials[material].name.as_str())
+                    .unwrap_or("(default material)")
+            );
+        }
+        for material in &mesh.materials {
+            println!(
+                "  material {}: color {:?}, texture {}",
+                material.name,
+                material.base_color,
+                material
+                    .base_color_texture
+                    .as_ref()
+                    .map(|texture| texture.to_string())
+                    .unwrap_or_else(|| "none".to_string())
+            );
+        }
+    }
+
+    // the nodes as a tree, a glTF file can have more than one
+    if scene.nodes.len() > 1 || scene.meshes.is_empty() {
+        println!("scene {}", scene.name);
+        let mut stack: Vec<(usize, usize)> =
+            scene.roots.iter().rev().map(|root| (*root, 1)).collect();
+        while let Some((index, depth)) = stack.pop() {
+            let node: &Node = &scene.nodes[index];
+            let mut line: String = format!("{}node {} {}", "  ".repeat(depth), index, node.name);
+            if let Some(mesh) = node.mesh {
+                line += &format!(", mesh {}", mesh);
+            }
+            if let Some(camera) = node.camera {
+                line += &format!(", camera {:?}", scene.cameras[camera].projection);
+            }
+            if let Some(light) = node.light {
+                line += &format!(", light {:?}", scene.lights[light].kind);
+            }
+            println!("{}", line);
+            stack.extend(node.children.iter().rev().map(|child| (*child, depth + 1)));
+        }
+    }
+    return 0;
+}
+
+/// Print how often every debug layer message shows up in a log file
+fn summarize_messages(path: &str) -> i32 {
+    let text: String = match std::fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(e) => {
+            eprintln!("Unable to read {}: {}", path, e);
+            return 1;
+        }
+    };
+
+    let messages: Vec<message_text::ParsedMessage> = message_text::parse_log(&text);
+    println!("{} debug layer messages in {}", messages.len(), path);
+    for (id, name, count) in message_text::count_by_id(&messages) {
+        println!("{:>6}x #{} {}", count, id, name);
+    }
+    return 0;
+}
+
+/// Get the value that follows `name` in the command line arguments
+fn arg_value<'a>(args: &'a [String], name: &str) -> Option<&'a str> {
+    return args
+        .iter()
+        .position(|arg| arg == name)
+        .and_then(|index| args.get(index + 1))
+        .map(String::as_str);
 }
@@ -21,14 +21,18 @@ use windows::{
 };
 
 use self::{
-    errors::{get_error_desc, FatalErrorBase},
-    graphics::Graphics,
+    errors::{
+        crash_report::{self, InputEvent},
+        get_error_desc, Error,
+    },
+    graphics::{shaderlocator::ShaderLocator, Graphics, Renderer},
     keyboard::Keyboard,
     mouse::Mouse,
 };
 
 pub mod errors;
 pub mod graphics;
+pub mod headless;
 pub mod keyboard;
 pub mod message;
 pub mod mouse;
@@ -85,6 +89,41 @@ pub struct Window<'a> {
     pub graphics: Graphics,
 }
 
+/// Everything the [`crate::app::App`] needs from a window. This is implemented by [`Window`] and by
+/// [`headless::HeadlessWindow`], which runs the app without creating a real window.
+pub trait Host {
+    fn width(&self) -> i16;
+    fn height(&self) -> i16;
+    fn keyboard(&mut self) -> &mut Keyboard;
+    fn mouse(&mut self) -> &mut Mouse;
+    fn graphics(&mut self) -> &mut dyn Renderer;
+
+    /// Handle all the pending messages. Returns the exit code when the app should stop.
+    fn handle_messages(&mut self) -> Option<usize>;
+
+    /// The time that passes every frame, if the app should not use the real clock.
+    fn fixed_timestep(&self) -> Option<f32> {
+        return None;
+    }
+
+    /// The messages that the debug layer collected, see [`errors::dx_info_module::Manager`].
+    fn debug_messages(&self) -> Vec<String> {
+        return vec![];
+    }
+
+    /// Write the messages of [`Host::debug_messages()`] to a file as JSON lines, nothing is written when
+    /// there is no debug layer
+    fn export_debug_messages(&self, _path: &std::path::Path) -> Result<(), errors::Error> {
+        return Ok(());
+    }
+
+    /// Forward the new messages of the debug layer to the logger. Called every frame. Fails when the debug
+    /// layer stored a message that is as bad as the severity it breaks on.
+    fn log_debug_messages(&mut self) -> Result<(), errors::Error> {
+        return Ok(());
+    }
+}
+
 /// Create a message box
 pub fn create_message_box(
     lptext: PCSTR,
@@ -117,7 +156,8 @@ impl Window<'_> {
         window_width: i16,
         window_height: i16,
         debug: bool,
-    ) -> Window<'static> {
+        shaders: ShaderLocator,
+    ) -> Result<Window<'static>, Error> {
         let mut base_details: String = window_name.to_string();
         base_details.push('\0');
         let class_name: PCSTR = PCSTR::from_raw(base_details.as_ptr());
@@ -128,14 +168,12 @@ impl Window<'_> {
             when it's loaded in memory.
         */
         let instance: HMODULE = unsafe {
-            GetModuleHandleA(None).unwrap_or_else(|_| {
-                errors::window::WindowError::new(
+            GetModuleHandleA(None).map_err(|e| {
+                Error::window_source(
                     "Unable to create an hInstance with GetModuleHandle.",
-                    None,
-                    crate::loc!(),
-                    None,
-                );
-            })
+                    e,
+                )
+            })?
         };
 
         /*
@@ -151,14 +189,9 @@ impl Window<'_> {
             lpfnWndProc: Some(Self::wndproc),
             hInstance: instance,
             hCursor: unsafe {
-                LoadCursorW(None, IDC_ARROW).unwrap_or_else(|_| {
-                    errors::window::WindowError::new(
-                        "Unable to load cursor.",
-                        None,
-                        crate::loc!(),
-                        None,
-                    )
-                })
+                LoadCursorW(None, IDC_ARROW).map_err(|e| {
+                    Error::window_source("Unable to load cursor.", e)
+                })?
             },
             lpszClassName: class_name,
             cbClsExtra: 0,
@@ -183,7 +216,10 @@ impl Window<'_> {
 
         if atom == 0 {
             // Check if the registerClass function failed
-            panic!("unable to register class");
+            return Err(Error::window_source(
+                "Unable to register class.",
+                windows::core::Error::from_win32(),
+            ));
         }
 
         /*
@@ -213,6 +249,13 @@ impl Window<'_> {
             )
         };
 
+        if hwnd.0 == 0 {
+            return Err(Error::window_source(
+                "Unable to create window.",
+                windows::core::Error::from_win32(),
+            ));
+        }
+
         unsafe {
             io::KEYBOARD.reset();
             io::MOUSE.reset();
@@ -221,7 +264,7 @@ impl Window<'_> {
         };
 
         // return the new Window instance
-        Window {
+        return Ok(Window {
             instance,
             class_name,
             atom,
@@ -233,8 +276,8 @@ impl Window<'_> {
             mouse: unsafe { &mut io::MOUSE },
             width: window_width,
             height: window_height,
-            graphics: Graphics::setup(hwnd, debug, window_height, window_width),
-        }
+            graphics: Graphics::setup(hwnd, debug, window_height, window_width, shaders)?,
+        });
     }
 
     pub fn show_window(&self) {
@@ -248,18 +291,6 @@ impl Window<'_> {
         };
     }
 
-    pub fn handle_messages(&mut self) -> Option<usize> {
-        while unsafe { PeekMessageA(&mut self.msg_buffer, None, 0, 0, PM_REMOVE).as_bool() } {
-            if self.msg_buffer.message == WM_QUIT {
-                return Some(self.msg_buffer.wParam.0);
-            }
-            unsafe { TranslateMessage(&mut self.msg_buffer) };
-            unsafe { DispatchMessageA(&mut self.msg_buffer) };
-        }
-
-        return None;
-    }
-
     extern "system" fn wndproc(hwnd: HWND, msg: u32, wparam: WPARAM, lparam: LPARAM) -> LRESULT {
         /*
             It is very hard to explain how this works without typing a lot of text so i'll just refer you to
@@ -270,7 +301,13 @@ impl Window<'_> {
             And for a list with all the messages see: https://wiki.winehq.org/List_Of_Windows_Messages
         */
 
-        // println!("{}", message::_id_to_name(msg));
+        // Log the message if a spy was started, see [`message::start_spy()`]
+        message::spy(msg, wparam, lparam);
+
+        // Keep the last input for crash reports
+        if message::is_input_message(msg) {
+            crash_report::record_input(InputEvent::Message { msg, wparam, lparam });
+        }
 
         unsafe {
             match msg {
@@ -365,13 +402,67 @@ impl Window<'_> {
     }
 
     pub fn print_exit_codes(&self) {
-        return println!(
+        crate::log_info!(
             "{}",
             get_error_desc(Some(self.last_result), Some(self.msg_buffer))
         );
     }
 }
 
+impl Host for Window<'_> {
+    fn width(&self) -> i16 {
+        return self.width;
+    }
+
+    fn height(&self) -> i16 {
+        return self.height;
+    }
+
+    fn keyboard(&mut self) -> &mut Keyboard {
+        return self.keyboard;
+    }
+
+    fn mouse(&mut self) -> &mut Mouse {
+        return self.mouse;
+    }
+
+    fn graphics(&mut self) -> &mut dyn Renderer {
+        return &mut self.graphics;
+    }
+
+    fn handle_messages(&mut self) -> Option<usize> {
+        while unsafe { PeekMessageA(&mut self.msg_buffer, None, 0, 0, PM_REMOVE).as_bool() } {
+            if self.msg_buffer.message == WM_QUIT {
+                return Some(self.msg_buffer.wParam.0);
+            }
+            unsafe { TranslateMessage(&mut self.msg_buffer) };
+            unsafe { DispatchMessageA(&mut self.msg_buffer) };
+        }
+
+        return None;
+    }
+
+    fn debug_messages(&self) -> Vec<String> {
+        return match self.graphics.dx_info_manager.as_ref() {
+            Some(manager) => manager
+                .get_messages()
+                .unwrap_or_else(|e| vec![format!("Unable to read the debug messages: {}", e)]),
+            None => vec![],
+        };
+    }
+
+    fn export_debug_messages(&self, path: &std::path::Path) -> Result<(), errors::Error> {
+        return match self.graphics.dx_info_manager.as_ref() {
+            Some(manager) => manager.export_json_lines(path),
+            None => Ok(()),
+        };
+    }
+
+    fn log_debug_messages(&mut self) -> Result<(), errors::Error> {
+        return self.graphics.check_debug_layer();
+    }
+}
+
 /**
     This function is not in the windows crate so i made it my self. For more info <br>
     see [this](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-makepoints?source=recommendations)
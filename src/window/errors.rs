@@ -1,65 +1,352 @@
-use super::graphics::Graphics;
+use std::{
+    backtrace::{Backtrace, BacktraceStatus},
+    fmt,
+    sync::Arc,
+};
 
+use windows::core::HRESULT;
+
+use self::{graphics::DeviceRemovedReason, hresult::lookup_hresult, info_message::InfoMessage};
+
+pub mod crash_report;
+pub mod d3d11_message_ids;
 pub mod dx_info_module;
 pub mod graphics;
-pub mod window;
+pub mod hresult;
+pub mod info_message;
+pub mod info_queue;
+pub mod message_text;
+pub mod panic_hook;
+pub mod reporter;
 
-#[derive(Debug)]
+/// Where an error was created. Has a backtrace when backtraces are enabled with `RUST_BACKTRACE=1`
+#[derive(Debug, Clone)]
 pub struct CallLocation {
     pub file: String,
     pub line: u32,
     pub column: u32,
+    pub backtrace: Option<Arc<Backtrace>>,
 }
 
-#[macro_export]
-macro_rules! loc {
-    () => {
-        $crate::window::errors::CallLocation {
-            file: file!().to_string(),
-            line: line!(),
-            column: column!(),
-        }
-    };
+impl CallLocation {
+    /// The location this function is called from. Inside a `#[track_caller]` function this is the location
+    /// the function itself is called from, so the error constructors don't need to be passed a location.
+    #[track_caller]
+    pub fn caller() -> CallLocation {
+        let location: &std::panic::Location = std::panic::Location::caller();
+        let backtrace: Backtrace = Backtrace::capture();
+        return CallLocation {
+            file: location.file().to_string(),
+            line: location.line(),
+            column: location.column(),
+            backtrace: match backtrace.status() {
+                BacktraceStatus::Captured => Some(Arc::new(backtrace)),
+                _ => None,
+            },
+        };
+    }
+}
+
+impl fmt::Display for CallLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}:{}", self.file, self.line, self.column)
+    }
+}
+
+/// The error of a failing `windows` call. We keep the code and message instead of the [`windows::core::Error`]
+/// itself, because formatting that one needs the OLE functions of win32. This way errors can be formatted
+/// on every platform.
+#[derive(Debug, Clone)]
+pub struct WindowsError {
+    pub code: HRESULT,
+    pub message: String,
+}
+
+impl From<windows::core::Error> for WindowsError {
+    fn from(error: windows::core::Error) -> WindowsError {
+        return WindowsError {
+            code: error.code(),
+            message: error.message().to_string().trim_end().to_string(),
+        };
+    }
+}
+
+impl fmt::Display for WindowsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "0x{:08X}: {}", self.code.0, self.message)
+    }
 }
 
-pub trait FatalErrorBase {
-    fn new(
-        error_details: &str,
-        error_code: Option<i32>,
+impl std::error::Error for WindowsError {}
+
+/// Every error that can happen in the window, the graphics or the debug layer. The errors are passed up
+/// to the caller, which can decide to recover or to hand them to a [`reporter::Reporter`].
+#[derive(Debug)]
+pub enum Error {
+    /// Something went wrong while creating or running the win32 window
+    Window {
+        details: String,
         origin: CallLocation,
-        graphics: Option<&Graphics>,
-    ) -> ! {
-        let base_details: String = format!(
-            "Error in {}:{}\n{}\0",
-            origin.file, origin.line, error_details
-        );
+        source: Option<WindowsError>,
+    },
+    /// A D3D11, DXGI or win32 call returned a failing HRESULT
+    HResult {
+        code: HRESULT,
+        details: String,
+        origin: CallLocation,
+        source: Option<WindowsError>,
+    },
+    /// The GPU was removed, reset or hung. `reason` is the result of `GetDeviceRemovedReason()`
+    DeviceRemoved { reason: HRESULT, origin: CallLocation },
+    /// A HLSL file could not be compiled. `log` holds the output of the compiler
+    ShaderCompile {
+        path: String,
+        code: HRESULT,
+        log: String,
+        origin: CallLocation,
+    },
+    /// Reading or writing a file failed
+    Io {
+        details: String,
+        origin: CallLocation,
+        source: std::io::Error,
+    },
+    /// The debug layer stored a message that is as bad as the severity the info queue breaks on,
+    /// see [`info_queue::InfoQueueConfig::break_on`]
+    DebugLayer {
+        message: InfoMessage,
+        origin: CallLocation,
+    },
+    /// The app panicked, see [`panic_hook`]
+    Panic { message: String, origin: CallLocation },
+    /// Another error with a description of what was being done when it happened, see [`Context`]
+    Context { context: String, source: Box<Error> },
+}
 
-        let formatted_details: windows::core::PCSTR =
-            windows::core::PCSTR::from_raw(base_details.as_ptr());
+impl Error {
+    #[track_caller]
+    pub fn window(details: &str) -> Error {
+        return Error::Window {
+            details: details.to_string(),
+            origin: CallLocation::caller(),
+            source: None,
+        };
+    }
 
-        crate::window::create_message_box(
-            formatted_details,
-            windows::Win32::UI::WindowsAndMessaging::MB_ICONERROR
-                | windows::Win32::UI::WindowsAndMessaging::MB_OK,
-            0,
-        );
+    /// A window error that was caused by a failing win32 call
+    #[track_caller]
+    pub fn window_source(details: &str, source: windows::core::Error) -> Error {
+        return Error::Window {
+            details: details.to_string(),
+            origin: CallLocation::caller(),
+            source: Some(source.into()),
+        };
+    }
 
-        if graphics.is_some() {
-            for msg in graphics
-                .unwrap()
-                .dx_info_manager
-                .as_ref()
-                .unwrap_or_else(|| { 
-                    println!("For an error log you should enable debug mode."); 
-                    std::process::exit(error_code.unwrap_or(1));
-                })
-                .get_messages()
-            {
-                println!("{}", msg);
-            }
+    /// Create an error from the error of a failing `windows` call
+    #[track_caller]
+    pub fn hresult(source: windows::core::Error) -> Error {
+        let source: WindowsError = source.into();
+        return Error::HResult {
+            code: source.code,
+            details: source.message.clone(),
+            origin: CallLocation::caller(),
+            source: Some(source),
+        };
+    }
+
+    /// Create an error from a failing HRESULT that wasn't wrapped in a [`windows::core::Error`]
+    #[track_caller]
+    pub fn hresult_code(code: HRESULT, details: &str) -> Error {
+        return Error::HResult {
+            code,
+            details: details.to_string(),
+            origin: CallLocation::caller(),
+            source: None,
+        };
+    }
+
+    #[track_caller]
+    pub fn device_removed(reason: HRESULT) -> Error {
+        return Error::DeviceRemoved {
+            reason,
+            origin: CallLocation::caller(),
+        };
+    }
+
+    #[track_caller]
+    pub fn shader_compile(path: &str, code: HRESULT, log: &str) -> Error {
+        return Error::ShaderCompile {
+            path: path.to_string(),
+            code,
+            log: log.trim_end().to_string(),
+            origin: CallLocation::caller(),
+        };
+    }
+
+    #[track_caller]
+    pub fn io(details: &str, source: std::io::Error) -> Error {
+        return Error::Io {
+            details: details.to_string(),
+            origin: CallLocation::caller(),
+            source,
+        };
+    }
+
+    #[track_caller]
+    pub fn debug_layer(message: InfoMessage) -> Error {
+        return Error::DebugLayer {
+            message,
+            origin: CallLocation::caller(),
+        };
+    }
+
+    /// A panic with the location of the `panic!()` or `unwrap()` that caused it
+    pub fn panic(message: &str, origin: CallLocation) -> Error {
+        return Error::Panic {
+            message: message.to_string(),
+            origin,
+        };
+    }
+
+    /// Wrap the error with a description of what was being done, like `creating depth buffer`
+    pub fn context(self, context: &str) -> Error {
+        return Error::Context {
+            context: context.to_string(),
+            source: Box::new(self),
+        };
+    }
+
+    /// Where the error was created
+    pub fn origin(&self) -> &CallLocation {
+        return match self {
+            Error::Window { origin, .. }
+            | Error::HResult { origin, .. }
+            | Error::DeviceRemoved { origin, .. }
+            | Error::ShaderCompile { origin, .. }
+            | Error::Io { origin, .. }
+            | Error::DebugLayer { origin, .. }
+            | Error::Panic { origin, .. } => origin,
+            Error::Context { source, .. } => source.origin(),
+        };
+    }
+
+    /// The backtrace of where the error was created, if backtraces are enabled
+    pub fn backtrace(&self) -> Option<&Backtrace> {
+        return self.origin().backtrace.as_deref();
+    }
+
+    /// The HRESULT of the error, if it has one
+    pub fn code(&self) -> Option<HRESULT> {
+        return match self {
+            Error::HResult { code, .. } | Error::ShaderCompile { code, .. } => Some(*code),
+            Error::DeviceRemoved { reason, .. } => Some(*reason),
+            Error::Window { source, .. } => source.as_ref().map(|source| source.code),
+            Error::Io { .. } | Error::DebugLayer { .. } | Error::Panic { .. } => None,
+            Error::Context { source, .. } => source.code(),
+        };
+    }
+
+    /// The exit code the process should use when it stops because of this error
+    pub fn exit_code(&self) -> i32 {
+        return match self {
+            Error::HResult { code, .. } | Error::ShaderCompile { code, .. } => code.0,
+            Error::DeviceRemoved { reason, .. } => reason.0,
+            Error::Window { .. } | Error::Io { .. } | Error::DebugLayer { .. } => 1,
+            // The same code the process exits with when a panic isn't caught
+            Error::Panic { .. } => 101,
+            Error::Context { source, .. } => source.exit_code(),
+        };
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Window { details, .. } => write!(f, "Window error: {}", details),
+            Error::HResult { code, details, .. } => match lookup_hresult(*code) {
+                // The message of a windows error is empty if the system doesn't know the code
+                Some(known) if details.trim().is_empty() => {
+                    write!(f, "{} (0x{:08X}): {}", known.name, code.0, known.description)
+                }
+                Some(known) => {
+                    write!(f, "{} (0x{:08X}): {}", known.name, code.0, details.trim_end())
+                }
+                None => write!(f, "HRESULT 0x{:08X}: {}", code.0, details.trim_end()),
+            },
+            Error::DeviceRemoved { reason, .. } => write!(
+                f,
+                "The graphics device was removed, {} (0x{:08X})",
+                DeviceRemovedReason::from_hresult(*reason),
+                reason.0
+            ),
+            Error::ShaderCompile { path, code, log, .. } => write!(
+                f,
+                "Unable to compile shader {} (HRESULT 0x{:08X})\n{}",
+                path, code.0, log
+            ),
+            Error::Io { details, .. } => write!(f, "I/O error: {}", details),
+            Error::DebugLayer { message, .. } => write!(f, "Debug layer message: {}", message),
+            Error::Panic { message, .. } => write!(f, "Panicked: {}", message),
+            Error::Context { context, source } => write!(f, "{}: {}", context, source),
         }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        return match self {
+            Error::Window {
+                source: Some(source),
+                ..
+            }
+            | Error::HResult {
+                source: Some(source),
+                ..
+            } => Some(source),
+            Error::Io { source, .. } => Some(source),
+            Error::Context { source, .. } => Some(source.as_ref()),
+            _ => None,
+        };
+    }
+}
+
+/// Add a description of what was being done to the error of a failing call, like
+/// `device.CreateTexture2D(...).context("creating depth buffer")?`. The error gets the location
+/// `context()` was called from.
+pub trait Context<T> {
+    #[track_caller]
+    fn context(self, context: &str) -> Result<T, Error>;
+    #[track_caller]
+    fn with_context<F: FnOnce() -> String>(self, context: F) -> Result<T, Error>;
+}
+
+impl<T> Context<T> for windows::core::Result<T> {
+    #[track_caller]
+    fn context(self, context: &str) -> Result<T, Error> {
+        // No map_err here, the closure would hide the location of the caller
+        return match self {
+            Ok(value) => Ok(value),
+            Err(e) => Err(Error::hresult(e).context(context)),
+        };
+    }
+
+    #[track_caller]
+    fn with_context<F: FnOnce() -> String>(self, context: F) -> Result<T, Error> {
+        return match self {
+            Ok(value) => Ok(value),
+            Err(e) => Err(Error::hresult(e).context(&context())),
+        };
+    }
+}
+
+impl<T> Context<T> for Result<T, Error> {
+    fn context(self, context: &str) -> Result<T, Error> {
+        return self.map_err(|e| e.context(context));
+    }
 
-        std::process::exit(error_code.unwrap_or(1));
+    fn with_context<F: FnOnce() -> String>(self, context: F) -> Result<T, Error> {
+        return self.map_err(|e| e.context(&context()));
     }
 }
 
@@ -78,6 +365,11 @@ pub fn get_error_desc(
         );
     }
 
+    // The common errors are in our own table, only the unknown ones need FormatMessage
+    if let Some(known) = hresult::lookup_win32(err_code) {
+        return format!("Code {} ({}): {}", err_code, known.name, known.description);
+    }
+
     let err_msg_lenght: u32 = unsafe {
         windows::Win32::System::Diagnostics::Debug::FormatMessageA(
             /*
@@ -146,3 +438,98 @@ pub fn get_error_desc(
 
     return format!("Code {}: {}", err_code, String::from_utf8(slice).unwrap());
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use windows::Win32::Foundation::{E_FAIL, E_INVALIDARG};
+
+    #[test]
+    fn hresult_errors_show_the_name_of_the_code() {
+        assert_eq!(
+            Error::hresult_code(E_INVALIDARG, "").to_string(),
+            "E_INVALIDARG (0x80070057): One or more arguments are invalid."
+        );
+        assert_eq!(
+            Error::hresult_code(E_INVALIDARG, "The width is 0\r\n").to_string(),
+            "E_INVALIDARG (0x80070057): The width is 0"
+        );
+        assert_eq!(
+            Error::hresult_code(HRESULT(0x8123_4567_u32 as i32), "odd").to_string(),
+            "HRESULT 0x81234567: odd"
+        );
+    }
+
+    #[test]
+    fn other_errors_are_displayed() {
+        assert_eq!(Error::window("no class").to_string(), "Window error: no class");
+        assert_eq!(
+            Error::device_removed(HRESULT(0x887A_0006_u32 as i32)).to_string(),
+            format!(
+                "The graphics device was removed, {} (0x887A0006)",
+                DeviceRemovedReason::Hung
+            )
+        );
+        assert_eq!(
+            Error::shader_compile("cube.hlsl", E_FAIL, "cube.hlsl(3): error X3000\n").to_string(),
+            "Unable to compile shader cube.hlsl (HRESULT 0x80004005)\ncube.hlsl(3): error X3000"
+        );
+        let io: std::io::Error = std::io::Error::new(std::io::ErrorKind::NotFound, "gone");
+        assert_eq!(Error::io("reading t.txt", io).to_string(), "I/O error: reading t.txt");
+
+        let message: InfoMessage = InfoMessage {
+            producer: info_message::Producer::D3D11,
+            id: 388,
+            name: "OMSETRENDERTARGETS_INVALIDVIEW".to_string(),
+            category: info_message::Category::StateSetting,
+            severity: info_message::Severity::Error,
+            description: "The view is bad.\n".to_string(),
+        };
+        assert_eq!(
+            Error::debug_layer(message).to_string(),
+            "Debug layer message: D3D11 ERROR: The view is bad. [ STATE_SETTING ERROR #388: OMSETRENDERTARGETS_INVALIDVIEW ]"
+        );
+        assert_eq!(
+            Error::panic("index out of bounds", CallLocation::caller()).to_string(),
+            "Panicked: index out of bounds"
+        );
+    }
+
+    #[test]
+    fn context_is_chained_in_front() {
+        let error: Error = Error::hresult_code(E_INVALIDARG, "bad")
+            .context("creating depth buffer")
+            .context("resizing");
+        assert_eq!(
+            error.to_string(),
+            "resizing: creating depth buffer: E_INVALIDARG (0x80070057): bad"
+        );
+        assert_eq!(error.code(), Some(E_INVALIDARG));
+        assert_eq!(error.exit_code(), E_INVALIDARG.0);
+
+        // The source chain goes down to the error that was wrapped
+        let source: &dyn std::error::Error = std::error::Error::source(&error).unwrap();
+        assert_eq!(
+            source.to_string(),
+            "creating depth buffer: E_INVALIDARG (0x80070057): bad"
+        );
+    }
+
+    #[test]
+    fn errors_point_at_where_they_were_created() {
+        let line: u32 = line!() + 1;
+        let result: Result<(), Error> = Err(Error::window("gone"));
+        let error: Error = result.with_context(|| format!("frame {}", 3)).unwrap_err();
+        assert_eq!(error.to_string(), "frame 3: Window error: gone");
+        // Adding context keeps the location of the error it wraps
+        assert_eq!(error.origin().file, file!());
+        assert_eq!(error.origin().line, line);
+    }
+
+    #[test]
+    fn exit_codes_follow_the_error() {
+        assert_eq!(Error::window("no class").exit_code(), 1);
+        assert_eq!(Error::window("no class").code(), None);
+        assert_eq!(Error::panic("oops", CallLocation::caller()).exit_code(), 101);
+    }
+}
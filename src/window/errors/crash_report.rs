@@ -0,0 +1,522 @@
+use std::{
+    collections::VecDeque,
+    fmt, fs,
+    path::{Path, PathBuf},
+    sync::Mutex,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use windows::Win32::Foundation::{LPARAM, WPARAM};
+
+use super::{hresult::describe_hresult, message_text, Error};
+use crate::window::{headless::SyntheticInput, message};
+
+/// The version of the crash report format. Bump this when a section changes meaning,
+/// [`CrashReport::parse()`] refuses reports of a newer version.
+pub const CRASH_REPORT_VERSION: u32 = 1;
+
+const HEADER: &str = "win32-d3d11 crash report v";
+
+/// Amount of input events that are kept for the crash report
+const INPUT_HISTORY_LEN: usize = 32;
+
+static INPUT_HISTORY: Mutex<InputHistory> = Mutex::new(InputHistory::new());
+
+/// An input event as it was received. It is only formatted when a crash report is made, so recording it
+/// is cheap enough for every mouse move.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum InputEvent {
+    /// A window message that [`message::is_input_message()`]
+    Message {
+        msg: u32,
+        wparam: WPARAM,
+        lparam: LPARAM,
+    },
+    /// Input of a headless run and the frame that handled it
+    Synthetic { frame: u32, input: SyntheticInput },
+}
+
+impl fmt::Display for InputEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            InputEvent::Message {
+                msg,
+                wparam,
+                lparam,
+            } => write!(f, "{}", message::format_message(*msg, *wparam, *lparam)),
+            InputEvent::Synthetic { frame, input } => write!(f, "frame {}: {:?}", frame, input),
+        };
+    }
+}
+
+/// The last [`INPUT_HISTORY_LEN`] input events
+#[derive(Debug, Default)]
+pub struct InputHistory {
+    events: VecDeque<InputEvent>,
+}
+
+impl InputHistory {
+    pub const fn new() -> InputHistory {
+        return InputHistory {
+            events: VecDeque::new(),
+        };
+    }
+
+    pub fn record(&mut self, event: InputEvent) {
+        if self.events.len() == INPUT_HISTORY_LEN {
+            self.events.pop_front();
+        }
+        self.events.push_back(event);
+    }
+
+    /// The events formatted, oldest first
+    pub fn formatted(&self) -> Vec<String> {
+        return self.events.iter().map(InputEvent::to_string).collect();
+    }
+}
+
+/// Remember an input event for crash reports, only the last [`INPUT_HISTORY_LEN`] events are kept
+pub fn record_input(event: InputEvent) {
+    if let Ok(mut history) = INPUT_HISTORY.lock() {
+        history.record(event);
+    }
+}
+
+/// The last input events, oldest first
+pub fn recent_input() -> Vec<String> {
+    return match INPUT_HISTORY.lock() {
+        Ok(history) => history.formatted(),
+        Err(_) => vec![],
+    };
+}
+
+/// Timing of the frames that were rendered before the crash
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FrameStats {
+    pub frames: u64,
+    pub fps_high: u128,
+    pub fps_low: u128,
+    pub fps_avg: u128,
+    /// Seconds since the app started
+    pub run_time: f32,
+}
+
+/// Everything we know about a fatal error, written to disk so it survives the console closing.
+/// The report is versioned text: a header line followed by `[section]` lines with their content indented by
+/// four spaces.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CrashReport {
+    pub version: u32,
+    /// Seconds since the unix epoch
+    pub time: u64,
+    pub error: String,
+    /// The [`super::CallLocation`] of the error
+    pub origin: String,
+    /// The decoded HRESULT, empty if the error has no HRESULT
+    pub hresult: String,
+    pub backtrace: String,
+    /// The info queue messages since the last mark
+    pub debug_messages: Vec<String>,
+    pub input: Vec<String>,
+    pub frame_stats: FrameStats,
+    pub adapter: String,
+    pub config: Vec<(String, String)>,
+}
+
+impl CrashReport {
+    /// Create a report of an error with its backtrace and the recent input. If the error has no backtrace
+    /// the backtrace of the current thread is used.
+    /// The app fills in the frame stats, the adapter and the config, see `App::crash_report()`
+    pub fn from_error(error: &Error) -> CrashReport {
+        return CrashReport {
+            version: CRASH_REPORT_VERSION,
+            time: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |time| time.as_secs()),
+            error: error.to_string(),
+            origin: error.origin().to_string(),
+            hresult: error.code().map(describe_hresult).unwrap_or_default(),
+            // The backtrace of where the error was created is better than the one of where it ends up
+            backtrace: match error.backtrace() {
+                Some(backtrace) => backtrace.to_string(),
+                None => std::backtrace::Backtrace::force_capture().to_string(),
+            }
+            .trim_end()
+            .to_string(),
+            input: recent_input(),
+            ..CrashReport::default()
+        };
+    }
+
+    /// Write the report in the crash report format
+    pub fn to_text(&self) -> String {
+        let mut text: String = format!("{}{}\n", HEADER, self.version);
+        write_section(&mut text, "time", &self.time.to_string());
+        write_section(&mut text, "error", &self.error);
+        write_section(&mut text, "origin", &self.origin);
+        write_section(&mut text, "hresult", &self.hresult);
+        write_section(&mut text, "debug messages", &self.debug_messages.join("\n"));
+        write_section(&mut text, "input", &self.input.join("\n"));
+        write_section(
+            &mut text,
+            "frame stats",
+            &format!(
+                "frames: {}\nfps high: {}\nfps low: {}\nfps avg: {}\nrun time: {}",
+                self.frame_stats.frames,
+                self.frame_stats.fps_high,
+                self.frame_stats.fps_low,
+                self.frame_stats.fps_avg,
+                self.frame_stats.run_time
+            ),
+        );
+        write_section(&mut text, "adapter", &self.adapter);
+        write_section(
+            &mut text,
+            "config",
+            &self
+                .config
+                .iter()
+                .map(|(key, value)| format!("{}: {}", key, value))
+                .collect::<Vec<String>>()
+                .join("\n"),
+        );
+        write_section(&mut text, "backtrace", &self.backtrace);
+        return text;
+    }
+
+    /// Read a report that was written by [`CrashReport::to_text()`]. Unknown sections are skipped,
+    /// so reports of older versions can be read.
+    pub fn parse(text: &str) -> Result<CrashReport, String> {
+        let mut lines = text.lines();
+        let version: u32 = lines
+            .next()
+            .and_then(|header| header.strip_prefix(HEADER))
+            .and_then(|version| version.trim().parse().ok())
+            .ok_or("This is not a crash report")?;
+        if version > CRASH_REPORT_VERSION {
+            return Err(format!(
+                "Crash report version {} is newer than the supported version {}",
+                version, CRASH_REPORT_VERSION
+            ));
+        }
+
+        let mut sections: Vec<(String, Vec<&str>)> = Vec::new();
+        for line in lines {
+            if let Some(name) = line
+                .strip_prefix('[')
+                .and_then(|line| line.strip_suffix(']'))
+            {
+                sections.push((name.to_string(), vec![]));
+            } else if let Some((_, content)) = sections.last_mut() {
+                content.push(line.strip_prefix("    ").unwrap_or(line.trim()));
+            }
+        }
+
+        let mut report: CrashReport = CrashReport {
+            version,
+            ..CrashReport::default()
+        };
+        for (name, content) in sections {
+            let body: String = content.join("\n").trim_end().to_string();
+            let list: Vec<String> = content
+                .iter()
+                .filter(|line| !line.is_empty())
+                .map(|line| line.to_string())
+                .collect();
+            match name.as_str() {
+                "time" => report.time = body.parse().unwrap_or(0),
+                "error" => report.error = body,
+                "origin" => report.origin = body,
+                "hresult" => report.hresult = body,
+                "backtrace" => report.backtrace = body,
+                "debug messages" => report.debug_messages = list,
+                "input" => report.input = list,
+                "adapter" => report.adapter = body,
+                "frame stats" => {
+                    for (key, value) in key_values(&list) {
+                        match key.as_str() {
+                            "frames" => report.frame_stats.frames = value.parse().unwrap_or(0),
+                            "fps high" => report.frame_stats.fps_high = value.parse().unwrap_or(0),
+                            "fps low" => report.frame_stats.fps_low = value.parse().unwrap_or(0),
+                            "fps avg" => report.frame_stats.fps_avg = value.parse().unwrap_or(0),
+                            "run time" => {
+                                report.frame_stats.run_time = value.parse().unwrap_or(0.0)
+                            }
+                            _ => {}
+                        }
+                    }
+                }
+                "config" => report.config = key_values(&list),
+                _ => {}
+            }
+        }
+        return Ok(report);
+    }
+
+    /// Write the report to `crash-<time>.txt` in `dir`. Returns the path of the report.
+    pub fn write_to_dir(&self, dir: &Path) -> std::io::Result<PathBuf> {
+        fs::create_dir_all(dir)?;
+        let path: PathBuf = dir.join(format!("crash-{}.txt", self.time));
+        fs::write(&path, self.to_text())?;
+        return Ok(path);
+    }
+}
+
+/// Pretty-prints the report for people, use [`CrashReport::to_text()`] to save it
+impl fmt::Display for CrashReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "Crash report (format v{}, time {})",
+            self.version, self.time
+        )?;
+        writeln!(
+            f,
+            "\nError in {}\n  {}",
+            self.origin,
+            self.error.replace('\n', "\n  ")
+        )?;
+        // The error already shows the HRESULT if it came from a failing call
+        if !self.hresult.is_empty() && !self.error.contains(&self.hresult) {
+            writeln!(f, "  {}", self.hresult)?;
+        }
+
+        writeln!(
+            f,
+            "\nAdapter: {}",
+            if self.adapter.is_empty() {
+                "unknown"
+            } else {
+                &self.adapter
+            }
+        )?;
+        for (key, value) in &self.config {
+            writeln!(f, "  {}: {}", key, value)?;
+        }
+
+        let stats: &FrameStats = &self.frame_stats;
+        writeln!(
+            f,
+            "\n{} frames in {:.2}s, fps avg {} (low {}, high {})",
+            stats.frames, stats.run_time, stats.fps_avg, stats.fps_low, stats.fps_high
+        )?;
+
+        if !self.debug_messages.is_empty() {
+            writeln!(f, "\nDebug layer messages ({}):", self.debug_messages.len())?;
+            for msg in &self.debug_messages {
+                writeln!(f, "  {}", msg)?;
+            }
+
+            // Recurring messages are easier to spot when they are counted
+            let parsed: Vec<message_text::ParsedMessage> = self
+                .debug_messages
+                .iter()
+                .filter_map(|msg| message_text::parse_message(msg))
+                .collect();
+            let counts = message_text::count_by_id(&parsed);
+            if counts.iter().any(|(_, _, count)| *count > 1) {
+                writeln!(f, "Most common:")?;
+                for (id, name, count) in counts.iter().take(5) {
+                    writeln!(f, "  {:>4}x #{} {}", count, id, name)?;
+                }
+            }
+        }
+
+        if !self.input.is_empty() {
+            writeln!(f, "\nLast {} input events:", self.input.len())?;
+            for event in &self.input {
+                writeln!(f, "  {}", event)?;
+            }
+        }
+
+        if !self.backtrace.is_empty() {
+            writeln!(f, "\nBacktrace:\n{}", self.backtrace)?;
+        }
+        return Ok(());
+    }
+}
+
+fn write_section(text: &mut String, name: &str, content: &str) {
+    text.push_str(&format!("[{}]\n", name));
+    for line in content.lines() {
+        text.push_str("    ");
+        text.push_str(line);
+        text.push('\n');
+    }
+}
+
+fn key_values(lines: &[String]) -> Vec<(String, String)> {
+    return lines
+        .iter()
+        .filter_map(|line| line.split_once(": "))
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .collect();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report() -> CrashReport {
+        return CrashReport {
+            version: CRASH_REPORT_VERSION,
+            time: 1700000000,
+            error: "creating depth buffer: E_INVALIDARG (0x80070057): The parameter is wrong\nsecond line"
+                .to_string(),
+            origin: "src/window/graphics.rs:12:5".to_string(),
+            hresult: "E_INVALIDARG (0x80070057): One or more arguments are invalid.".to_string(),
+            backtrace: "0: main\n1: start".to_string(),
+            debug_messages: vec![
+                "D3D11 ERROR: Bad view [ STATE_SETTING ERROR #388: OMSETRENDERTARGETS_INVALIDVIEW ]"
+                    .to_string();
+                2
+            ],
+            input: vec!["WM_KEYDOWN vk=A".to_string(), "WM_CHAR 'a'".to_string()],
+            frame_stats: FrameStats {
+                frames: 120,
+                fps_high: 144,
+                fps_low: 30,
+                fps_avg: 60,
+                run_time: 2.5,
+            },
+            adapter: "Fake GPU (1024 MB)".to_string(),
+            config: vec![
+                ("width".to_string(), "800".to_string()),
+                ("args".to_string(), "app --debug".to_string()),
+            ],
+        };
+    }
+
+    #[test]
+    fn text_round_trips() {
+        let text: String = report().to_text();
+        assert!(text.starts_with("win32-d3d11 crash report v1\n[time]\n    1700000000\n[error]\n"));
+        assert_eq!(CrashReport::parse(&text), Ok(report()));
+    }
+
+    #[test]
+    fn parse_skips_unknown_sections() {
+        let text: String = format!("{}[later]\n    something new\n", report().to_text());
+        assert_eq!(CrashReport::parse(&text), Ok(report()));
+    }
+
+    #[test]
+    fn parse_refuses_other_files() {
+        assert_eq!(
+            CrashReport::parse("hello"),
+            Err("This is not a crash report".to_string())
+        );
+        assert_eq!(
+            CrashReport::parse("win32-d3d11 crash report v99\n"),
+            Err("Crash report version 99 is newer than the supported version 1".to_string())
+        );
+    }
+
+    #[test]
+    fn display_counts_recurring_messages() {
+        let text: String = report().to_string();
+        assert!(text.starts_with("Crash report (format v1, time 1700000000)\n"));
+        assert!(text.contains(
+            "\nError in src/window/graphics.rs:12:5\n  creating depth buffer: E_INVALIDARG (0x80070057): The parameter is wrong\n  second line\n"
+        ));
+        assert!(text.contains(
+            "\n  second line\n  E_INVALIDARG (0x80070057): One or more arguments are invalid.\n"
+        ));
+        assert!(text.contains("\nAdapter: Fake GPU (1024 MB)\n  width: 800\n  args: app --debug\n"));
+        assert!(text.contains("\n120 frames in 2.50s, fps avg 60 (low 30, high 144)\n"));
+        assert!(text.contains("\nDebug layer messages (2):\n"));
+        assert!(text.contains("Most common:\n     2x #388 OMSETRENDERTARGETS_INVALIDVIEW\n"));
+        assert!(text.contains("\nLast 2 input events:\n  WM_KEYDOWN vk=A\n  WM_CHAR 'a'\n"));
+        assert!(text.ends_with("\nBacktrace:\n0: main\n1: start\n"));
+    }
+
+    #[test]
+    fn display_shows_the_hresult_once() {
+        let mut report: CrashReport = report();
+        report.error = format!("creating depth buffer: {}", report.hresult);
+        assert_eq!(
+            report.to_string().matches("One or more arguments").count(),
+            1
+        );
+    }
+
+    #[test]
+    fn display_leaves_out_empty_sections() {
+        let text: String = CrashReport::default().to_string();
+        assert!(text.contains("\nAdapter: unknown\n"));
+        assert!(!text.contains("Debug layer messages"));
+        assert!(!text.contains("input events"));
+        assert!(!text.contains("Backtrace"));
+    }
+
+    #[test]
+    fn from_error_decodes_the_hresult() {
+        let error: Error = Error::hresult_code(windows::Win32::Foundation::E_INVALIDARG, "bad")
+            .context("creating depth buffer");
+        let report: CrashReport = CrashReport::from_error(&error);
+        assert_eq!(report.version, CRASH_REPORT_VERSION);
+        assert_eq!(
+            report.error,
+            "creating depth buffer: E_INVALIDARG (0x80070057): bad"
+        );
+        assert!(report
+            .origin
+            .starts_with("src/window/errors/crash_report.rs:"));
+        assert_eq!(
+            report.hresult,
+            "E_INVALIDARG (0x80070057): One or more arguments are invalid."
+        );
+        assert!(!report.backtrace.is_empty());
+    }
+
+    #[test]
+    fn writes_the_report_to_a_file() {
+        let dir: PathBuf =
+            std::env::temp_dir().join(format!("crash_report_test_{}", std::process::id()));
+        let path: PathBuf = report().write_to_dir(&dir).unwrap();
+        assert_eq!(path, dir.join("crash-1700000000.txt"));
+        let text: String = fs::read_to_string(&path).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(CrashReport::parse(&text), Ok(report()));
+    }
+
+    #[test]
+    fn keeps_the_last_input_events() {
+        let mut history: InputHistory = InputHistory::new();
+        for frame in 0..INPUT_HISTORY_LEN as u32 + 3 {
+            history.record(InputEvent::Synthetic {
+                frame,
+                input: SyntheticInput::Char('a'),
+            });
+        }
+        let input: Vec<String> = history.formatted();
+        assert_eq!(input.len(), INPUT_HISTORY_LEN);
+        assert_eq!(input[0], "frame 3: Char('a')");
+        assert_eq!(
+            input.last().unwrap(),
+            &format!("frame {}: Char('a')", INPUT_HISTORY_LEN + 2)
+        );
+    }
+
+    #[test]
+    fn window_messages_are_formatted_when_they_are_read() {
+        let mut history: InputHistory = InputHistory::new();
+        history.record(InputEvent::Message {
+            msg: message::name_to_id("WM_KEYDOWN").unwrap(),
+            wparam: WPARAM(0x41),
+            lparam: LPARAM(1),
+        });
+        history.record(InputEvent::Message {
+            msg: message::name_to_id("WM_MOUSEMOVE").unwrap(),
+            wparam: WPARAM(0),
+            lparam: LPARAM(20 << 16 | 10),
+        });
+        assert_eq!(
+            history.formatted(),
+            vec![
+                "WM_KEYDOWN vk=A repeat=1 scan=0x00",
+                "WM_MOUSEMOVE x=10 y=20 keys=NONE"
+            ]
+        );
+    }
+}
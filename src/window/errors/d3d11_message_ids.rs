@@ -0,0 +1,2811 @@
+use std::fmt;
+
+const MESSAGE_ID_COUNT: usize = 1335;
+
+/// The part of the D3D11 API a message id belongs to, so messages can be filtered by pipeline stage.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ApiGroup {
+    InputAssembler,
+    VertexShader,
+    HullShader,
+    DomainShader,
+    GeometryShader,
+    Rasterizer,
+    PixelShader,
+    OutputMerger,
+    ComputeShader,
+    /// Validation that happens when a draw call is made
+    Draw,
+    /// Creating buffers, textures, views and samplers
+    ResourceCreation,
+    /// Copying, updating and mapping resources
+    ResourceAccess,
+    /// The CREATE_, LIVE_ and DESTROY_ messages of object tracking
+    ObjectLifetime,
+    /// Video decoding and processing, content protection and JPEG
+    Video,
+    Other,
+}
+
+impl ApiGroup {
+    /// Short name for the group, like `IA` or `OM`
+    pub fn name(self) -> &'static str {
+        return match self {
+            ApiGroup::InputAssembler => "IA",
+            ApiGroup::VertexShader => "VS",
+            ApiGroup::HullShader => "HS",
+            ApiGroup::DomainShader => "DS",
+            ApiGroup::GeometryShader => "GS",
+            ApiGroup::Rasterizer => "RS",
+            ApiGroup::PixelShader => "PS",
+            ApiGroup::OutputMerger => "OM",
+            ApiGroup::ComputeShader => "CS",
+            ApiGroup::Draw => "DRAW",
+            ApiGroup::ResourceCreation => "RESOURCE_CREATION",
+            ApiGroup::ResourceAccess => "RESOURCE_ACCESS",
+            ApiGroup::ObjectLifetime => "OBJECT_LIFETIME",
+            ApiGroup::Video => "VIDEO",
+            ApiGroup::Other => "OTHER",
+        };
+    }
+}
+
+impl fmt::Display for ApiGroup {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Every value of the [D3D11_MESSAGE_ID](https://learn.microsoft.com/en-us/windows/win32/api/d3d11sdklayers/ne-d3d11sdklayers-d3d11_message_id)
+/// enum without the `D3D11_MESSAGE_ID_` prefix, with the API group it belongs to.<br>
+/// The table is sorted on id so we can binary search it.
+#[rustfmt::skip]
+static MESSAGE_IDS: [(i32, &str, ApiGroup); MESSAGE_ID_COUNT] = [
+    (0, "UNKNOWN", ApiGroup::Other),
+    (1, "DEVICE_IASETVERTEXBUFFERS_HAZARD", ApiGroup::InputAssembler),
+    (2, "DEVICE_IASETINDEXBUFFER_HAZARD", ApiGroup::InputAssembler),
+    (3, "DEVICE_VSSETSHADERRESOURCES_HAZARD", ApiGroup::VertexShader),
+    (4, "DEVICE_VSSETCONSTANTBUFFERS_HAZARD", ApiGroup::VertexShader),
+    (5, "DEVICE_GSSETSHADERRESOURCES_HAZARD", ApiGroup::GeometryShader),
+    (6, "DEVICE_GSSETCONSTANTBUFFERS_HAZARD", ApiGroup::GeometryShader),
+    (7, "DEVICE_PSSETSHADERRESOURCES_HAZARD", ApiGroup::PixelShader),
+    (8, "DEVICE_PSSETCONSTANTBUFFERS_HAZARD", ApiGroup::PixelShader),
+    (9, "DEVICE_OMSETRENDERTARGETS_HAZARD", ApiGroup::OutputMerger),
+    (10, "DEVICE_SOSETTARGETS_HAZARD", ApiGroup::GeometryShader),
+    (11, "STRING_FROM_APPLICATION", ApiGroup::Other),
+    (12, "CORRUPTED_THIS", ApiGroup::Other),
+    (13, "CORRUPTED_PARAMETER1", ApiGroup::Other),
+    (14, "CORRUPTED_PARAMETER2", ApiGroup::Other),
+    (15, "CORRUPTED_PARAMETER3", ApiGroup::Other),
+    (16, "CORRUPTED_PARAMETER4", ApiGroup::Other),
+    (17, "CORRUPTED_PARAMETER5", ApiGroup::Other),
+    (18, "CORRUPTED_PARAMETER6", ApiGroup::Other),
+    (19, "CORRUPTED_PARAMETER7", ApiGroup::Other),
+    (20, "CORRUPTED_PARAMETER8", ApiGroup::Other),
+    (21, "CORRUPTED_PARAMETER9", ApiGroup::Other),
+    (22, "CORRUPTED_PARAMETER10", ApiGroup::Other),
+    (23, "CORRUPTED_PARAMETER11", ApiGroup::Other),
+    (24, "CORRUPTED_PARAMETER12", ApiGroup::Other),
+    (25, "CORRUPTED_PARAMETER13", ApiGroup::Other),
+    (26, "CORRUPTED_PARAMETER14", ApiGroup::Other),
+    (27, "CORRUPTED_PARAMETER15", ApiGroup::Other),
+    (28, "CORRUPTED_MULTITHREADING", ApiGroup::Other),
+    (29, "MESSAGE_REPORTING_OUTOFMEMORY", ApiGroup::Other),
+    (30, "IASETINPUTLAYOUT_UNBINDDELETINGOBJECT", ApiGroup::InputAssembler),
+    (31, "IASETVERTEXBUFFERS_UNBINDDELETINGOBJECT", ApiGroup::InputAssembler),
+    (32, "IASETINDEXBUFFER_UNBINDDELETINGOBJECT", ApiGroup::InputAssembler),
+    (33, "VSSETSHADER_UNBINDDELETINGOBJECT", ApiGroup::VertexShader),
+    (34, "VSSETSHADERRESOURCES_UNBINDDELETINGOBJECT", ApiGroup::VertexShader),
+    (35, "VSSETCONSTANTBUFFERS_UNBINDDELETINGOBJECT", ApiGroup::VertexShader),
+    (36, "VSSETSAMPLERS_UNBINDDELETINGOBJECT", ApiGroup::VertexShader),
+    (37, "GSSETSHADER_UNBINDDELETINGOBJECT", ApiGroup::GeometryShader),
+    (38, "GSSETSHADERRESOURCES_UNBINDDELETINGOBJECT", ApiGroup::GeometryShader),
+    (39, "GSSETCONSTANTBUFFERS_UNBINDDELETINGOBJECT", ApiGroup::GeometryShader),
+    (40, "GSSETSAMPLERS_UNBINDDELETINGOBJECT", ApiGroup::GeometryShader),
+    (41, "SOSETTARGETS_UNBINDDELETINGOBJECT", ApiGroup::GeometryShader),
+    (42, "PSSETSHADER_UNBINDDELETINGOBJECT", ApiGroup::PixelShader),
+    (43, "PSSETSHADERRESOURCES_UNBINDDELETINGOBJECT", ApiGroup::PixelShader),
+    (44, "PSSETCONSTANTBUFFERS_UNBINDDELETINGOBJECT", ApiGroup::PixelShader),
+    (45, "PSSETSAMPLERS_UNBINDDELETINGOBJECT", ApiGroup::PixelShader),
+    (46, "RSSETSTATE_UNBINDDELETINGOBJECT", ApiGroup::Rasterizer),
+    (47, "OMSETBLENDSTATE_UNBINDDELETINGOBJECT", ApiGroup::OutputMerger),
+    (48, "OMSETDEPTHSTENCILSTATE_UNBINDDELETINGOBJECT", ApiGroup::OutputMerger),
+    (49, "OMSETRENDERTARGETS_UNBINDDELETINGOBJECT", ApiGroup::OutputMerger),
+    (50, "SETPREDICATION_UNBINDDELETINGOBJECT", ApiGroup::Other),
+    (51, "GETPRIVATEDATA_MOREDATA", ApiGroup::Other),
+    (52, "SETPRIVATEDATA_INVALIDFREEDATA", ApiGroup::Other),
+    (53, "SETPRIVATEDATA_INVALIDIUNKNOWN", ApiGroup::Other),
+    (54, "SETPRIVATEDATA_INVALIDFLAGS", ApiGroup::Other),
+    (55, "SETPRIVATEDATA_CHANGINGPARAMS", ApiGroup::Other),
+    (56, "SETPRIVATEDATA_OUTOFMEMORY", ApiGroup::Other),
+    (57, "CREATEBUFFER_UNRECOGNIZEDFORMAT", ApiGroup::ResourceCreation),
+    (58, "CREATEBUFFER_INVALIDSAMPLES", ApiGroup::ResourceCreation),
+    (59, "CREATEBUFFER_UNRECOGNIZEDUSAGE", ApiGroup::ResourceCreation),
+    (60, "CREATEBUFFER_UNRECOGNIZEDBINDFLAGS", ApiGroup::ResourceCreation),
+    (61, "CREATEBUFFER_UNRECOGNIZEDCPUACCESSFLAGS", ApiGroup::ResourceCreation),
+    (62, "CREATEBUFFER_UNRECOGNIZEDMISCFLAGS", ApiGroup::ResourceCreation),
+    (63, "CREATEBUFFER_INVALIDCPUACCESSFLAGS", ApiGroup::ResourceCreation),
+    (64, "CREATEBUFFER_INVALIDBINDFLAGS", ApiGroup::ResourceCreation),
+    (65, "CREATEBUFFER_INVALIDINITIALDATA", ApiGroup::ResourceCreation),
+    (66, "CREATEBUFFER_INVALIDDIMENSIONS", ApiGroup::ResourceCreation),
+    (67, "CREATEBUFFER_INVALIDMIPLEVELS", ApiGroup::ResourceCreation),
+    (68, "CREATEBUFFER_INVALIDMISCFLAGS", ApiGroup::ResourceCreation),
+    (69, "CREATEBUFFER_INVALIDARG_RETURN", ApiGroup::ResourceCreation),
+    (70, "CREATEBUFFER_OUTOFMEMORY_RETURN", ApiGroup::ResourceCreation),
+    (71, "CREATEBUFFER_NULLDESC", ApiGroup::ResourceCreation),
+    (72, "CREATEBUFFER_INVALIDCONSTANTBUFFERBINDINGS", ApiGroup::ResourceCreation),
+    (73, "CREATEBUFFER_LARGEALLOCATION", ApiGroup::ResourceCreation),
+    (74, "CREATETEXTURE1D_UNRECOGNIZEDFORMAT", ApiGroup::ResourceCreation),
+    (75, "CREATETEXTURE1D_UNSUPPORTEDFORMAT", ApiGroup::ResourceCreation),
+    (76, "CREATETEXTURE1D_INVALIDSAMPLES", ApiGroup::ResourceCreation),
+    (77, "CREATETEXTURE1D_UNRECOGNIZEDUSAGE", ApiGroup::ResourceCreation),
+    (78, "CREATETEXTURE1D_UNRECOGNIZEDBINDFLAGS", ApiGroup::ResourceCreation),
+    (79, "CREATETEXTURE1D_UNRECOGNIZEDCPUACCESSFLAGS", ApiGroup::ResourceCreation),
+    (80, "CREATETEXTURE1D_UNRECOGNIZEDMISCFLAGS", ApiGroup::ResourceCreation),
+    (81, "CREATETEXTURE1D_INVALIDCPUACCESSFLAGS", ApiGroup::ResourceCreation),
+    (82, "CREATETEXTURE1D_INVALIDBINDFLAGS", ApiGroup::ResourceCreation),
+    (83, "CREATETEXTURE1D_INVALIDINITIALDATA", ApiGroup::ResourceCreation),
+    (84, "CREATETEXTURE1D_INVALIDDIMENSIONS", ApiGroup::ResourceCreation),
+    (85, "CREATETEXTURE1D_INVALIDMIPLEVELS", ApiGroup::ResourceCreation),
+    (86, "CREATETEXTURE1D_INVALIDMISCFLAGS", ApiGroup::ResourceCreation),
+    (87, "CREATETEXTURE1D_INVALIDARG_RETURN", ApiGroup::ResourceCreation),
+    (88, "CREATETEXTURE1D_OUTOFMEMORY_RETURN", ApiGroup::ResourceCreation),
+    (89, "CREATETEXTURE1D_NULLDESC", ApiGroup::ResourceCreation),
+    (90, "CREATETEXTURE1D_LARGEALLOCATION", ApiGroup::ResourceCreation),
+    (91, "CREATETEXTURE2D_UNRECOGNIZEDFORMAT", ApiGroup::ResourceCreation),
+    (92, "CREATETEXTURE2D_UNSUPPORTEDFORMAT", ApiGroup::ResourceCreation),
+    (93, "CREATETEXTURE2D_INVALIDSAMPLES", ApiGroup::ResourceCreation),
+    (94, "CREATETEXTURE2D_UNRECOGNIZEDUSAGE", ApiGroup::ResourceCreation),
+    (95, "CREATETEXTURE2D_UNRECOGNIZEDBINDFLAGS", ApiGroup::ResourceCreation),
+    (96, "CREATETEXTURE2D_UNRECOGNIZEDCPUACCESSFLAGS", ApiGroup::ResourceCreation),
+    (97, "CREATETEXTURE2D_UNRECOGNIZEDMISCFLAGS", ApiGroup::ResourceCreation),
+    (98, "CREATETEXTURE2D_INVALIDCPUACCESSFLAGS", ApiGroup::ResourceCreation),
+    (99, "CREATETEXTURE2D_INVALIDBINDFLAGS", ApiGroup::ResourceCreation),
+    (100, "CREATETEXTURE2D_INVALIDINITIALDATA", ApiGroup::ResourceCreation),
+    (101, "CREATETEXTURE2D_INVALIDDIMENSIONS", ApiGroup::ResourceCreation),
+    (102, "CREATETEXTURE2D_INVALIDMIPLEVELS", ApiGroup::ResourceCreation),
+    (103, "CREATETEXTURE2D_INVALIDMISCFLAGS", ApiGroup::ResourceCreation),
+    (104, "CREATETEXTURE2D_INVALIDARG_RETURN", ApiGroup::ResourceCreation),
+    (105, "CREATETEXTURE2D_OUTOFMEMORY_RETURN", ApiGroup::ResourceCreation),
+    (106, "CREATETEXTURE2D_NULLDESC", ApiGroup::ResourceCreation),
+    (107, "CREATETEXTURE2D_LARGEALLOCATION", ApiGroup::ResourceCreation),
+    (108, "CREATETEXTURE3D_UNRECOGNIZEDFORMAT", ApiGroup::ResourceCreation),
+    (109, "CREATETEXTURE3D_UNSUPPORTEDFORMAT", ApiGroup::ResourceCreation),
+    (110, "CREATETEXTURE3D_INVALIDSAMPLES", ApiGroup::ResourceCreation),
+    (111, "CREATETEXTURE3D_UNRECOGNIZEDUSAGE", ApiGroup::ResourceCreation),
+    (112, "CREATETEXTURE3D_UNRECOGNIZEDBINDFLAGS", ApiGroup::ResourceCreation),
+    (113, "CREATETEXTURE3D_UNRECOGNIZEDCPUACCESSFLAGS", ApiGroup::ResourceCreation),
+    (114, "CREATETEXTURE3D_UNRECOGNIZEDMISCFLAGS", ApiGroup::ResourceCreation),
+    (115, "CREATETEXTURE3D_INVALIDCPUACCESSFLAGS", ApiGroup::ResourceCreation),
+    (116, "CREATETEXTURE3D_INVALIDBINDFLAGS", ApiGroup::ResourceCreation),
+    (117, "CREATETEXTURE3D_INVALIDINITIALDATA", ApiGroup::ResourceCreation),
+    (118, "CREATETEXTURE3D_INVALIDDIMENSIONS", ApiGroup::ResourceCreation),
+    (119, "CREATETEXTURE3D_INVALIDMIPLEVELS", ApiGroup::ResourceCreation),
+    (120, "CREATETEXTURE3D_INVALIDMISCFLAGS", ApiGroup::ResourceCreation),
+    (121, "CREATETEXTURE3D_INVALIDARG_RETURN", ApiGroup::ResourceCreation),
+    (122, "CREATETEXTURE3D_OUTOFMEMORY_RETURN", ApiGroup::ResourceCreation),
+    (123, "CREATETEXTURE3D_NULLDESC", ApiGroup::ResourceCreation),
+    (124, "CREATETEXTURE3D_LARGEALLOCATION", ApiGroup::ResourceCreation),
+    (125, "CREATESHADERRESOURCEVIEW_UNRECOGNIZEDFORMAT", ApiGroup::ResourceCreation),
+    (126, "CREATESHADERRESOURCEVIEW_INVALIDDESC", ApiGroup::ResourceCreation),
+    (127, "CREATESHADERRESOURCEVIEW_INVALIDFORMAT", ApiGroup::ResourceCreation),
+    (128, "CREATESHADERRESOURCEVIEW_INVALIDDIMENSIONS", ApiGroup::ResourceCreation),
+    (129, "CREATESHADERRESOURCEVIEW_INVALIDRESOURCE", ApiGroup::ResourceCreation),
+    (130, "CREATESHADERRESOURCEVIEW_TOOMANYOBJECTS", ApiGroup::ResourceCreation),
+    (131, "CREATESHADERRESOURCEVIEW_INVALIDARG_RETURN", ApiGroup::ResourceCreation),
+    (132, "CREATESHADERRESOURCEVIEW_OUTOFMEMORY_RETURN", ApiGroup::ResourceCreation),
+    (133, "CREATERENDERTARGETVIEW_UNRECOGNIZEDFORMAT", ApiGroup::ResourceCreation),
+    (134, "CREATERENDERTARGETVIEW_UNSUPPORTEDFORMAT", ApiGroup::ResourceCreation),
+    (135, "CREATERENDERTARGETVIEW_INVALIDDESC", ApiGroup::ResourceCreation),
+    (136, "CREATERENDERTARGETVIEW_INVALIDFORMAT", ApiGroup::ResourceCreation),
+    (137, "CREATERENDERTARGETVIEW_INVALIDDIMENSIONS", ApiGroup::ResourceCreation),
+    (138, "CREATERENDERTARGETVIEW_INVALIDRESOURCE", ApiGroup::ResourceCreation),
+    (139, "CREATERENDERTARGETVIEW_TOOMANYOBJECTS", ApiGroup::ResourceCreation),
+    (140, "CREATERENDERTARGETVIEW_INVALIDARG_RETURN", ApiGroup::ResourceCreation),
+    (141, "CREATERENDERTARGETVIEW_OUTOFMEMORY_RETURN", ApiGroup::ResourceCreation),
+    (142, "CREATEDEPTHSTENCILVIEW_UNRECOGNIZEDFORMAT", ApiGroup::ResourceCreation),
+    (143, "CREATEDEPTHSTENCILVIEW_INVALIDDESC", ApiGroup::ResourceCreation),
+    (144, "CREATEDEPTHSTENCILVIEW_INVALIDFORMAT", ApiGroup::ResourceCreation),
+    (145, "CREATEDEPTHSTENCILVIEW_INVALIDDIMENSIONS", ApiGroup::ResourceCreation),
+    (146, "CREATEDEPTHSTENCILVIEW_INVALIDRESOURCE", ApiGroup::ResourceCreation),
+    (147, "CREATEDEPTHSTENCILVIEW_TOOMANYOBJECTS", ApiGroup::ResourceCreation),
+    (148, "CREATEDEPTHSTENCILVIEW_INVALIDARG_RETURN", ApiGroup::ResourceCreation),
+    (149, "CREATEDEPTHSTENCILVIEW_OUTOFMEMORY_RETURN", ApiGroup::ResourceCreation),
+    (150, "CREATEINPUTLAYOUT_OUTOFMEMORY", ApiGroup::InputAssembler),
+    (151, "CREATEINPUTLAYOUT_TOOMANYELEMENTS", ApiGroup::InputAssembler),
+    (152, "CREATEINPUTLAYOUT_INVALIDFORMAT", ApiGroup::InputAssembler),
+    (153, "CREATEINPUTLAYOUT_INCOMPATIBLEFORMAT", ApiGroup::InputAssembler),
+    (154, "CREATEINPUTLAYOUT_INVALIDSLOT", ApiGroup::InputAssembler),
+    (155, "CREATEINPUTLAYOUT_INVALIDINPUTSLOTCLASS", ApiGroup::InputAssembler),
+    (156, "CREATEINPUTLAYOUT_STEPRATESLOTCLASSMISMATCH", ApiGroup::InputAssembler),
+    (157, "CREATEINPUTLAYOUT_INVALIDSLOTCLASSCHANGE", ApiGroup::InputAssembler),
+    (158, "CREATEINPUTLAYOUT_INVALIDSTEPRATECHANGE", ApiGroup::InputAssembler),
+    (159, "CREATEINPUTLAYOUT_INVALIDALIGNMENT", ApiGroup::InputAssembler),
+    (160, "CREATEINPUTLAYOUT_DUPLICATESEMANTIC", ApiGroup::InputAssembler),
+    (161, "CREATEINPUTLAYOUT_UNPARSEABLEINPUTSIGNATURE", ApiGroup::InputAssembler),
+    (162, "CREATEINPUTLAYOUT_NULLSEMANTIC", ApiGroup::InputAssembler),
+    (163, "CREATEINPUTLAYOUT_MISSINGELEMENT", ApiGroup::InputAssembler),
+    (164, "CREATEINPUTLAYOUT_NULLDESC", ApiGroup::InputAssembler),
+    (165, "CREATEVERTEXSHADER_OUTOFMEMORY", ApiGroup::VertexShader),
+    (166, "CREATEVERTEXSHADER_INVALIDSHADERBYTECODE", ApiGroup::VertexShader),
+    (167, "CREATEVERTEXSHADER_INVALIDSHADERTYPE", ApiGroup::VertexShader),
+    (168, "CREATEGEOMETRYSHADER_OUTOFMEMORY", ApiGroup::GeometryShader),
+    (169, "CREATEGEOMETRYSHADER_INVALIDSHADERBYTECODE", ApiGroup::GeometryShader),
+    (170, "CREATEGEOMETRYSHADER_INVALIDSHADERTYPE", ApiGroup::GeometryShader),
+    (171, "CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_OUTOFMEMORY", ApiGroup::GeometryShader),
+    (172, "CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_INVALIDSHADERBYTECODE", ApiGroup::GeometryShader),
+    (173, "CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_INVALIDSHADERTYPE", ApiGroup::GeometryShader),
+    (174, "CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_INVALIDNUMENTRIES", ApiGroup::GeometryShader),
+    (175, "CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_OUTPUTSTREAMSTRIDEUNUSED", ApiGroup::GeometryShader),
+    (176, "CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_UNEXPECTEDDECL", ApiGroup::GeometryShader),
+    (177, "CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_EXPECTEDDECL", ApiGroup::GeometryShader),
+    (178, "CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_OUTPUTSLOT0EXPECTED", ApiGroup::GeometryShader),
+    (179, "CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_INVALIDOUTPUTSLOT", ApiGroup::GeometryShader),
+    (180, "CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_ONLYONEELEMENTPERSLOT", ApiGroup::GeometryShader),
+    (181, "CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_INVALIDCOMPONENTCOUNT", ApiGroup::GeometryShader),
+    (182, "CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_INVALIDSTARTCOMPONENTANDCOMPONENTCOUNT", ApiGroup::GeometryShader),
+    (183, "CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_INVALIDGAPDEFINITION", ApiGroup::GeometryShader),
+    (184, "CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_REPEATEDOUTPUT", ApiGroup::GeometryShader),
+    (185, "CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_INVALIDOUTPUTSTREAMSTRIDE", ApiGroup::GeometryShader),
+    (186, "CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_MISSINGSEMANTIC", ApiGroup::GeometryShader),
+    (187, "CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_MASKMISMATCH", ApiGroup::GeometryShader),
+    (188, "CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_CANTHAVEONLYGAPS", ApiGroup::GeometryShader),
+    (189, "CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_DECLTOOCOMPLEX", ApiGroup::GeometryShader),
+    (190, "CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_MISSINGOUTPUTSIGNATURE", ApiGroup::GeometryShader),
+    (191, "CREATEPIXELSHADER_OUTOFMEMORY", ApiGroup::PixelShader),
+    (192, "CREATEPIXELSHADER_INVALIDSHADERBYTECODE", ApiGroup::PixelShader),
+    (193, "CREATEPIXELSHADER_INVALIDSHADERTYPE", ApiGroup::PixelShader),
+    (194, "CREATERASTERIZERSTATE_INVALIDFILLMODE", ApiGroup::Rasterizer),
+    (195, "CREATERASTERIZERSTATE_INVALIDCULLMODE", ApiGroup::Rasterizer),
+    (196, "CREATERASTERIZERSTATE_INVALIDDEPTHBIASCLAMP", ApiGroup::Rasterizer),
+    (197, "CREATERASTERIZERSTATE_INVALIDSLOPESCALEDDEPTHBIAS", ApiGroup::Rasterizer),
+    (198, "CREATERASTERIZERSTATE_TOOMANYOBJECTS", ApiGroup::Rasterizer),
+    (199, "CREATERASTERIZERSTATE_NULLDESC", ApiGroup::Rasterizer),
+    (200, "CREATEDEPTHSTENCILSTATE_INVALIDDEPTHWRITEMASK", ApiGroup::OutputMerger),
+    (201, "CREATEDEPTHSTENCILSTATE_INVALIDDEPTHFUNC", ApiGroup::OutputMerger),
+    (202, "CREATEDEPTHSTENCILSTATE_INVALIDFRONTFACESTENCILFAILOP", ApiGroup::OutputMerger),
+    (203, "CREATEDEPTHSTENCILSTATE_INVALIDFRONTFACESTENCILZFAILOP", ApiGroup::OutputMerger),
+    (204, "CREATEDEPTHSTENCILSTATE_INVALIDFRONTFACESTENCILPASSOP", ApiGroup::OutputMerger),
+    (205, "CREATEDEPTHSTENCILSTATE_INVALIDFRONTFACESTENCILFUNC", ApiGroup::OutputMerger),
+    (206, "CREATEDEPTHSTENCILSTATE_INVALIDBACKFACESTENCILFAILOP", ApiGroup::OutputMerger),
+    (207, "CREATEDEPTHSTENCILSTATE_INVALIDBACKFACESTENCILZFAILOP", ApiGroup::OutputMerger),
+    (208, "CREATEDEPTHSTENCILSTATE_INVALIDBACKFACESTENCILPASSOP", ApiGroup::OutputMerger),
+    (209, "CREATEDEPTHSTENCILSTATE_INVALIDBACKFACESTENCILFUNC", ApiGroup::OutputMerger),
+    (210, "CREATEDEPTHSTENCILSTATE_TOOMANYOBJECTS", ApiGroup::OutputMerger),
+    (211, "CREATEDEPTHSTENCILSTATE_NULLDESC", ApiGroup::OutputMerger),
+    (212, "CREATEBLENDSTATE_INVALIDSRCBLEND", ApiGroup::OutputMerger),
+    (213, "CREATEBLENDSTATE_INVALIDDESTBLEND", ApiGroup::OutputMerger),
+    (214, "CREATEBLENDSTATE_INVALIDBLENDOP", ApiGroup::OutputMerger),
+    (215, "CREATEBLENDSTATE_INVALIDSRCBLENDALPHA", ApiGroup::OutputMerger),
+    (216, "CREATEBLENDSTATE_INVALIDDESTBLENDALPHA", ApiGroup::OutputMerger),
+    (217, "CREATEBLENDSTATE_INVALIDBLENDOPALPHA", ApiGroup::OutputMerger),
+    (218, "CREATEBLENDSTATE_INVALIDRENDERTARGETWRITEMASK", ApiGroup::OutputMerger),
+    (219, "CREATEBLENDSTATE_TOOMANYOBJECTS", ApiGroup::OutputMerger),
+    (220, "CREATEBLENDSTATE_NULLDESC", ApiGroup::OutputMerger),
+    (221, "CREATESAMPLERSTATE_INVALIDFILTER", ApiGroup::ResourceCreation),
+    (222, "CREATESAMPLERSTATE_INVALIDADDRESSU", ApiGroup::ResourceCreation),
+    (223, "CREATESAMPLERSTATE_INVALIDADDRESSV", ApiGroup::ResourceCreation),
+    (224, "CREATESAMPLERSTATE_INVALIDADDRESSW", ApiGroup::ResourceCreation),
+    (225, "CREATESAMPLERSTATE_INVALIDMIPLODBIAS", ApiGroup::ResourceCreation),
+    (226, "CREATESAMPLERSTATE_INVALIDMAXANISOTROPY", ApiGroup::ResourceCreation),
+    (227, "CREATESAMPLERSTATE_INVALIDCOMPARISONFUNC", ApiGroup::ResourceCreation),
+    (228, "CREATESAMPLERSTATE_INVALIDMINLOD", ApiGroup::ResourceCreation),
+    (229, "CREATESAMPLERSTATE_INVALIDMAXLOD", ApiGroup::ResourceCreation),
+    (230, "CREATESAMPLERSTATE_TOOMANYOBJECTS", ApiGroup::ResourceCreation),
+    (231, "CREATESAMPLERSTATE_NULLDESC", ApiGroup::ResourceCreation),
+    (232, "CREATEQUERYORPREDICATE_INVALIDQUERY", ApiGroup::Other),
+    (233, "CREATEQUERYORPREDICATE_INVALIDMISCFLAGS", ApiGroup::Other),
+    (234, "CREATEQUERYORPREDICATE_UNEXPECTEDMISCFLAG", ApiGroup::Other),
+    (235, "CREATEQUERYORPREDICATE_NULLDESC", ApiGroup::Other),
+    (236, "DEVICE_IASETPRIMITIVETOPOLOGY_TOPOLOGY_UNRECOGNIZED", ApiGroup::InputAssembler),
+    (237, "DEVICE_IASETPRIMITIVETOPOLOGY_TOPOLOGY_UNDEFINED", ApiGroup::InputAssembler),
+    (238, "IASETVERTEXBUFFERS_INVALIDBUFFER", ApiGroup::InputAssembler),
+    (239, "DEVICE_IASETVERTEXBUFFERS_OFFSET_TOO_LARGE", ApiGroup::InputAssembler),
+    (240, "DEVICE_IASETVERTEXBUFFERS_BUFFERS_EMPTY", ApiGroup::InputAssembler),
+    (241, "IASETINDEXBUFFER_INVALIDBUFFER", ApiGroup::InputAssembler),
+    (242, "DEVICE_IASETINDEXBUFFER_FORMAT_INVALID", ApiGroup::InputAssembler),
+    (243, "DEVICE_IASETINDEXBUFFER_OFFSET_TOO_LARGE", ApiGroup::InputAssembler),
+    (244, "DEVICE_IASETINDEXBUFFER_OFFSET_UNALIGNED", ApiGroup::InputAssembler),
+    (245, "DEVICE_VSSETSHADERRESOURCES_VIEWS_EMPTY", ApiGroup::VertexShader),
+    (246, "VSSETCONSTANTBUFFERS_INVALIDBUFFER", ApiGroup::VertexShader),
+    (247, "DEVICE_VSSETCONSTANTBUFFERS_BUFFERS_EMPTY", ApiGroup::VertexShader),
+    (248, "DEVICE_VSSETSAMPLERS_SAMPLERS_EMPTY", ApiGroup::VertexShader),
+    (249, "DEVICE_GSSETSHADERRESOURCES_VIEWS_EMPTY", ApiGroup::GeometryShader),
+    (250, "GSSETCONSTANTBUFFERS_INVALIDBUFFER", ApiGroup::GeometryShader),
+    (251, "DEVICE_GSSETCONSTANTBUFFERS_BUFFERS_EMPTY", ApiGroup::GeometryShader),
+    (252, "DEVICE_GSSETSAMPLERS_SAMPLERS_EMPTY", ApiGroup::GeometryShader),
+    (253, "SOSETTARGETS_INVALIDBUFFER", ApiGroup::GeometryShader),
+    (254, "DEVICE_SOSETTARGETS_OFFSET_UNALIGNED", ApiGroup::GeometryShader),
+    (255, "DEVICE_PSSETSHADERRESOURCES_VIEWS_EMPTY", ApiGroup::PixelShader),
+    (256, "PSSETCONSTANTBUFFERS_INVALIDBUFFER", ApiGroup::PixelShader),
+    (257, "DEVICE_PSSETCONSTANTBUFFERS_BUFFERS_EMPTY", ApiGroup::PixelShader),
+    (258, "DEVICE_PSSETSAMPLERS_SAMPLERS_EMPTY", ApiGroup::PixelShader),
+    (259, "DEVICE_RSSETVIEWPORTS_INVALIDVIEWPORT", ApiGroup::Rasterizer),
+    (260, "DEVICE_RSSETSCISSORRECTS_INVALIDSCISSOR", ApiGroup::Rasterizer),
+    (261, "CLEARRENDERTARGETVIEW_DENORMFLUSH", ApiGroup::OutputMerger),
+    (262, "CLEARDEPTHSTENCILVIEW_DENORMFLUSH", ApiGroup::OutputMerger),
+    (263, "CLEARDEPTHSTENCILVIEW_INVALID", ApiGroup::OutputMerger),
+    (264, "DEVICE_IAGETVERTEXBUFFERS_BUFFERS_EMPTY", ApiGroup::InputAssembler),
+    (265, "DEVICE_VSGETSHADERRESOURCES_VIEWS_EMPTY", ApiGroup::VertexShader),
+    (266, "DEVICE_VSGETCONSTANTBUFFERS_BUFFERS_EMPTY", ApiGroup::VertexShader),
+    (267, "DEVICE_VSGETSAMPLERS_SAMPLERS_EMPTY", ApiGroup::VertexShader),
+    (268, "DEVICE_GSGETSHADERRESOURCES_VIEWS_EMPTY", ApiGroup::GeometryShader),
+    (269, "DEVICE_GSGETCONSTANTBUFFERS_BUFFERS_EMPTY", ApiGroup::GeometryShader),
+    (270, "DEVICE_GSGETSAMPLERS_SAMPLERS_EMPTY", ApiGroup::GeometryShader),
+    (271, "DEVICE_SOGETTARGETS_BUFFERS_EMPTY", ApiGroup::GeometryShader),
+    (272, "DEVICE_PSGETSHADERRESOURCES_VIEWS_EMPTY", ApiGroup::PixelShader),
+    (273, "DEVICE_PSGETCONSTANTBUFFERS_BUFFERS_EMPTY", ApiGroup::PixelShader),
+    (274, "DEVICE_PSGETSAMPLERS_SAMPLERS_EMPTY", ApiGroup::PixelShader),
+    (275, "DEVICE_RSGETVIEWPORTS_VIEWPORTS_EMPTY", ApiGroup::Rasterizer),
+    (276, "DEVICE_RSGETSCISSORRECTS_RECTS_EMPTY", ApiGroup::Rasterizer),
+    (277, "DEVICE_GENERATEMIPS_RESOURCE_INVALID", ApiGroup::ResourceAccess),
+    (278, "COPYSUBRESOURCEREGION_INVALIDDESTINATIONSUBRESOURCE", ApiGroup::ResourceAccess),
+    (279, "COPYSUBRESOURCEREGION_INVALIDSOURCESUBRESOURCE", ApiGroup::ResourceAccess),
+    (280, "COPYSUBRESOURCEREGION_INVALIDSOURCEBOX", ApiGroup::ResourceAccess),
+    (281, "COPYSUBRESOURCEREGION_INVALIDSOURCE", ApiGroup::ResourceAccess),
+    (282, "COPYSUBRESOURCEREGION_INVALIDDESTINATIONSTATE", ApiGroup::ResourceAccess),
+    (283, "COPYSUBRESOURCEREGION_INVALIDSOURCESTATE", ApiGroup::ResourceAccess),
+    (284, "COPYRESOURCE_INVALIDSOURCE", ApiGroup::ResourceAccess),
+    (285, "COPYRESOURCE_INVALIDDESTINATIONSTATE", ApiGroup::ResourceAccess),
+    (286, "COPYRESOURCE_INVALIDSOURCESTATE", ApiGroup::ResourceAccess),
+    (287, "UPDATESUBRESOURCE_INVALIDDESTINATIONSUBRESOURCE", ApiGroup::ResourceAccess),
+    (288, "UPDATESUBRESOURCE_INVALIDDESTINATIONBOX", ApiGroup::ResourceAccess),
+    (289, "UPDATESUBRESOURCE_INVALIDDESTINATIONSTATE", ApiGroup::ResourceAccess),
+    (290, "DEVICE_RESOLVESUBRESOURCE_DESTINATION_INVALID", ApiGroup::ResourceAccess),
+    (291, "DEVICE_RESOLVESUBRESOURCE_DESTINATION_SUBRESOURCE_INVALID", ApiGroup::ResourceAccess),
+    (292, "DEVICE_RESOLVESUBRESOURCE_SOURCE_INVALID", ApiGroup::ResourceAccess),
+    (293, "DEVICE_RESOLVESUBRESOURCE_SOURCE_SUBRESOURCE_INVALID", ApiGroup::ResourceAccess),
+    (294, "DEVICE_RESOLVESUBRESOURCE_FORMAT_INVALID", ApiGroup::ResourceAccess),
+    (295, "BUFFER_MAP_INVALIDMAPTYPE", ApiGroup::ResourceAccess),
+    (296, "BUFFER_MAP_INVALIDFLAGS", ApiGroup::ResourceAccess),
+    (297, "BUFFER_MAP_ALREADYMAPPED", ApiGroup::ResourceAccess),
+    (298, "BUFFER_MAP_DEVICEREMOVED_RETURN", ApiGroup::ResourceAccess),
+    (299, "BUFFER_UNMAP_NOTMAPPED", ApiGroup::ResourceAccess),
+    (300, "TEXTURE1D_MAP_INVALIDMAPTYPE", ApiGroup::ResourceAccess),
+    (301, "TEXTURE1D_MAP_INVALIDSUBRESOURCE", ApiGroup::ResourceAccess),
+    (302, "TEXTURE1D_MAP_INVALIDFLAGS", ApiGroup::ResourceAccess),
+    (303, "TEXTURE1D_MAP_ALREADYMAPPED", ApiGroup::ResourceAccess),
+    (304, "TEXTURE1D_MAP_DEVICEREMOVED_RETURN", ApiGroup::ResourceAccess),
+    (305, "TEXTURE1D_UNMAP_INVALIDSUBRESOURCE", ApiGroup::ResourceAccess),
+    (306, "TEXTURE1D_UNMAP_NOTMAPPED", ApiGroup::ResourceAccess),
+    (307, "TEXTURE2D_MAP_INVALIDMAPTYPE", ApiGroup::ResourceAccess),
+    (308, "TEXTURE2D_MAP_INVALIDSUBRESOURCE", ApiGroup::ResourceAccess),
+    (309, "TEXTURE2D_MAP_INVALIDFLAGS", ApiGroup::ResourceAccess),
+    (310, "TEXTURE2D_MAP_ALREADYMAPPED", ApiGroup::ResourceAccess),
+    (311, "TEXTURE2D_MAP_DEVICEREMOVED_RETURN", ApiGroup::ResourceAccess),
+    (312, "TEXTURE2D_UNMAP_INVALIDSUBRESOURCE", ApiGroup::ResourceAccess),
+    (313, "TEXTURE2D_UNMAP_NOTMAPPED", ApiGroup::ResourceAccess),
+    (314, "TEXTURE3D_MAP_INVALIDMAPTYPE", ApiGroup::ResourceAccess),
+    (315, "TEXTURE3D_MAP_INVALIDSUBRESOURCE", ApiGroup::ResourceAccess),
+    (316, "TEXTURE3D_MAP_INVALIDFLAGS", ApiGroup::ResourceAccess),
+    (317, "TEXTURE3D_MAP_ALREADYMAPPED", ApiGroup::ResourceAccess),
+    (318, "TEXTURE3D_MAP_DEVICEREMOVED_RETURN", ApiGroup::ResourceAccess),
+    (319, "TEXTURE3D_UNMAP_INVALIDSUBRESOURCE", ApiGroup::ResourceAccess),
+    (320, "TEXTURE3D_UNMAP_NOTMAPPED", ApiGroup::ResourceAccess),
+    (321, "CHECKFORMATSUPPORT_FORMAT_DEPRECATED", ApiGroup::Other),
+    (322, "CHECKMULTISAMPLEQUALITYLEVELS_FORMAT_DEPRECATED", ApiGroup::Other),
+    (323, "SETEXCEPTIONMODE_UNRECOGNIZEDFLAGS", ApiGroup::Other),
+    (324, "SETEXCEPTIONMODE_INVALIDARG_RETURN", ApiGroup::Other),
+    (325, "SETEXCEPTIONMODE_DEVICEREMOVED_RETURN", ApiGroup::Other),
+    (326, "REF_SIMULATING_INFINITELY_FAST_HARDWARE", ApiGroup::Other),
+    (327, "REF_THREADING_MODE", ApiGroup::Other),
+    (328, "REF_UMDRIVER_EXCEPTION", ApiGroup::Other),
+    (329, "REF_KMDRIVER_EXCEPTION", ApiGroup::Other),
+    (330, "REF_HARDWARE_EXCEPTION", ApiGroup::Other),
+    (331, "REF_ACCESSING_INDEXABLE_TEMP_OUT_OF_RANGE", ApiGroup::Other),
+    (332, "REF_PROBLEM_PARSING_SHADER", ApiGroup::Other),
+    (333, "REF_OUT_OF_MEMORY", ApiGroup::Other),
+    (334, "REF_INFO", ApiGroup::Other),
+    (335, "DEVICE_DRAW_VERTEXPOS_OVERFLOW", ApiGroup::Draw),
+    (336, "DEVICE_DRAWINDEXED_INDEXPOS_OVERFLOW", ApiGroup::Draw),
+    (337, "DEVICE_DRAWINSTANCED_VERTEXPOS_OVERFLOW", ApiGroup::Draw),
+    (338, "DEVICE_DRAWINSTANCED_INSTANCEPOS_OVERFLOW", ApiGroup::Draw),
+    (339, "DEVICE_DRAWINDEXEDINSTANCED_INSTANCEPOS_OVERFLOW", ApiGroup::Draw),
+    (340, "DEVICE_DRAWINDEXEDINSTANCED_INDEXPOS_OVERFLOW", ApiGroup::Draw),
+    (341, "DEVICE_DRAW_VERTEX_SHADER_NOT_SET", ApiGroup::Draw),
+    (342, "DEVICE_SHADER_LINKAGE_SEMANTICNAME_NOT_FOUND", ApiGroup::Other),
+    (343, "DEVICE_SHADER_LINKAGE_REGISTERINDEX", ApiGroup::Other),
+    (344, "DEVICE_SHADER_LINKAGE_COMPONENTTYPE", ApiGroup::Other),
+    (345, "DEVICE_SHADER_LINKAGE_REGISTERMASK", ApiGroup::Other),
+    (346, "DEVICE_SHADER_LINKAGE_SYSTEMVALUE", ApiGroup::Other),
+    (347, "DEVICE_SHADER_LINKAGE_NEVERWRITTEN_ALWAYSREADS", ApiGroup::Other),
+    (348, "DEVICE_DRAW_VERTEX_BUFFER_NOT_SET", ApiGroup::Draw),
+    (349, "DEVICE_DRAW_INPUTLAYOUT_NOT_SET", ApiGroup::Draw),
+    (350, "DEVICE_DRAW_CONSTANT_BUFFER_NOT_SET", ApiGroup::Draw),
+    (351, "DEVICE_DRAW_CONSTANT_BUFFER_TOO_SMALL", ApiGroup::Draw),
+    (352, "DEVICE_DRAW_SAMPLER_NOT_SET", ApiGroup::Draw),
+    (353, "DEVICE_DRAW_SHADERRESOURCEVIEW_NOT_SET", ApiGroup::Draw),
+    (354, "DEVICE_DRAW_VIEW_DIMENSION_MISMATCH", ApiGroup::Draw),
+    (355, "DEVICE_DRAW_VERTEX_BUFFER_STRIDE_TOO_SMALL", ApiGroup::Draw),
+    (356, "DEVICE_DRAW_VERTEX_BUFFER_TOO_SMALL", ApiGroup::Draw),
+    (357, "DEVICE_DRAW_INDEX_BUFFER_NOT_SET", ApiGroup::Draw),
+    (358, "DEVICE_DRAW_INDEX_BUFFER_FORMAT_INVALID", ApiGroup::Draw),
+    (359, "DEVICE_DRAW_INDEX_BUFFER_TOO_SMALL", ApiGroup::Draw),
+    (360, "DEVICE_DRAW_GS_INPUT_PRIMITIVE_MISMATCH", ApiGroup::Draw),
+    (361, "DEVICE_DRAW_RESOURCE_RETURN_TYPE_MISMATCH", ApiGroup::Draw),
+    (362, "DEVICE_DRAW_POSITION_NOT_PRESENT", ApiGroup::Draw),
+    (363, "DEVICE_DRAW_OUTPUT_STREAM_NOT_SET", ApiGroup::Draw),
+    (364, "DEVICE_DRAW_BOUND_RESOURCE_MAPPED", ApiGroup::Draw),
+    (365, "DEVICE_DRAW_INVALID_PRIMITIVETOPOLOGY", ApiGroup::Draw),
+    (366, "DEVICE_DRAW_VERTEX_OFFSET_UNALIGNED", ApiGroup::Draw),
+    (367, "DEVICE_DRAW_VERTEX_STRIDE_UNALIGNED", ApiGroup::Draw),
+    (368, "DEVICE_DRAW_INDEX_OFFSET_UNALIGNED", ApiGroup::Draw),
+    (369, "DEVICE_DRAW_OUTPUT_STREAM_OFFSET_UNALIGNED", ApiGroup::Draw),
+    (370, "DEVICE_DRAW_RESOURCE_FORMAT_LD_UNSUPPORTED", ApiGroup::Draw),
+    (371, "DEVICE_DRAW_RESOURCE_FORMAT_SAMPLE_UNSUPPORTED", ApiGroup::Draw),
+    (372, "DEVICE_DRAW_RESOURCE_FORMAT_SAMPLE_C_UNSUPPORTED", ApiGroup::Draw),
+    (373, "DEVICE_DRAW_RESOURCE_MULTISAMPLE_UNSUPPORTED", ApiGroup::Draw),
+    (374, "DEVICE_DRAW_SO_TARGETS_BOUND_WITHOUT_SOURCE", ApiGroup::Draw),
+    (375, "DEVICE_DRAW_SO_STRIDE_LARGER_THAN_BUFFER", ApiGroup::Draw),
+    (376, "DEVICE_DRAW_OM_RENDER_TARGET_DOES_NOT_SUPPORT_BLENDING", ApiGroup::Draw),
+    (377, "DEVICE_DRAW_OM_DUAL_SOURCE_BLENDING_CAN_ONLY_HAVE_RENDER_TARGET_0", ApiGroup::Draw),
+    (378, "DEVICE_REMOVAL_PROCESS_AT_FAULT", ApiGroup::Other),
+    (379, "DEVICE_REMOVAL_PROCESS_POSSIBLY_AT_FAULT", ApiGroup::Other),
+    (380, "DEVICE_REMOVAL_PROCESS_NOT_AT_FAULT", ApiGroup::Other),
+    (381, "DEVICE_OPEN_SHARED_RESOURCE_INVALIDARG_RETURN", ApiGroup::Other),
+    (382, "DEVICE_OPEN_SHARED_RESOURCE_OUTOFMEMORY_RETURN", ApiGroup::Other),
+    (383, "DEVICE_OPEN_SHARED_RESOURCE_BADINTERFACE_RETURN", ApiGroup::Other),
+    (384, "DEVICE_DRAW_VIEWPORT_NOT_SET", ApiGroup::Draw),
+    (385, "CREATEINPUTLAYOUT_TRAILING_DIGIT_IN_SEMANTIC", ApiGroup::InputAssembler),
+    (386, "CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_TRAILING_DIGIT_IN_SEMANTIC", ApiGroup::GeometryShader),
+    (387, "DEVICE_RSSETVIEWPORTS_DENORMFLUSH", ApiGroup::Rasterizer),
+    (388, "OMSETRENDERTARGETS_INVALIDVIEW", ApiGroup::OutputMerger),
+    (389, "DEVICE_SETTEXTFILTERSIZE_INVALIDDIMENSIONS", ApiGroup::Other),
+    (390, "DEVICE_DRAW_SAMPLER_MISMATCH", ApiGroup::Draw),
+    (391, "CREATEINPUTLAYOUT_TYPE_MISMATCH", ApiGroup::InputAssembler),
+    (392, "BLENDSTATE_GETDESC_LEGACY", ApiGroup::OutputMerger),
+    (393, "SHADERRESOURCEVIEW_GETDESC_LEGACY", ApiGroup::Other),
+    (394, "CREATEQUERY_OUTOFMEMORY_RETURN", ApiGroup::Other),
+    (395, "CREATEPREDICATE_OUTOFMEMORY_RETURN", ApiGroup::Other),
+    (396, "CREATECOUNTER_OUTOFRANGE_COUNTER", ApiGroup::Other),
+    (397, "CREATECOUNTER_SIMULTANEOUS_ACTIVE_COUNTERS_EXHAUSTED", ApiGroup::Other),
+    (398, "CREATECOUNTER_UNSUPPORTED_WELLKNOWN_COUNTER", ApiGroup::Other),
+    (399, "CREATECOUNTER_OUTOFMEMORY_RETURN", ApiGroup::Other),
+    (400, "CREATECOUNTER_NONEXCLUSIVE_RETURN", ApiGroup::Other),
+    (401, "CREATECOUNTER_NULLDESC", ApiGroup::Other),
+    (402, "CHECKCOUNTER_OUTOFRANGE_COUNTER", ApiGroup::Other),
+    (403, "CHECKCOUNTER_UNSUPPORTED_WELLKNOWN_COUNTER", ApiGroup::Other),
+    (404, "SETPREDICATION_INVALID_PREDICATE_STATE", ApiGroup::Other),
+    (405, "QUERY_BEGIN_UNSUPPORTED", ApiGroup::Other),
+    (406, "PREDICATE_BEGIN_DURING_PREDICATION", ApiGroup::Other),
+    (407, "QUERY_BEGIN_DUPLICATE", ApiGroup::Other),
+    (408, "QUERY_BEGIN_ABANDONING_PREVIOUS_RESULTS", ApiGroup::Other),
+    (409, "PREDICATE_END_DURING_PREDICATION", ApiGroup::Other),
+    (410, "QUERY_END_ABANDONING_PREVIOUS_RESULTS", ApiGroup::Other),
+    (411, "QUERY_END_WITHOUT_BEGIN", ApiGroup::Other),
+    (412, "QUERY_GETDATA_INVALID_DATASIZE", ApiGroup::Other),
+    (413, "QUERY_GETDATA_INVALID_FLAGS", ApiGroup::Other),
+    (414, "QUERY_GETDATA_INVALID_CALL", ApiGroup::Other),
+    (415, "DEVICE_DRAW_PS_OUTPUT_TYPE_MISMATCH", ApiGroup::Draw),
+    (416, "DEVICE_DRAW_RESOURCE_FORMAT_GATHER_UNSUPPORTED", ApiGroup::Draw),
+    (417, "DEVICE_DRAW_INVALID_USE_OF_CENTER_MULTISAMPLE_PATTERN", ApiGroup::Draw),
+    (418, "DEVICE_IASETVERTEXBUFFERS_STRIDE_TOO_LARGE", ApiGroup::InputAssembler),
+    (419, "DEVICE_IASETVERTEXBUFFERS_INVALIDRANGE", ApiGroup::InputAssembler),
+    (420, "CREATEINPUTLAYOUT_EMPTY_LAYOUT", ApiGroup::InputAssembler),
+    (421, "DEVICE_DRAW_RESOURCE_SAMPLE_COUNT_MISMATCH", ApiGroup::Draw),
+    (422, "LIVE_OBJECT_SUMMARY", ApiGroup::ObjectLifetime),
+    (423, "LIVE_BUFFER", ApiGroup::ObjectLifetime),
+    (424, "LIVE_TEXTURE1D", ApiGroup::ObjectLifetime),
+    (425, "LIVE_TEXTURE2D", ApiGroup::ObjectLifetime),
+    (426, "LIVE_TEXTURE3D", ApiGroup::ObjectLifetime),
+    (427, "LIVE_SHADERRESOURCEVIEW", ApiGroup::ObjectLifetime),
+    (428, "LIVE_RENDERTARGETVIEW", ApiGroup::ObjectLifetime),
+    (429, "LIVE_DEPTHSTENCILVIEW", ApiGroup::ObjectLifetime),
+    (430, "LIVE_VERTEXSHADER", ApiGroup::ObjectLifetime),
+    (431, "LIVE_GEOMETRYSHADER", ApiGroup::ObjectLifetime),
+    (432, "LIVE_PIXELSHADER", ApiGroup::ObjectLifetime),
+    (433, "LIVE_INPUTLAYOUT", ApiGroup::ObjectLifetime),
+    (434, "LIVE_SAMPLER", ApiGroup::ObjectLifetime),
+    (435, "LIVE_BLENDSTATE", ApiGroup::ObjectLifetime),
+    (436, "LIVE_DEPTHSTENCILSTATE", ApiGroup::ObjectLifetime),
+    (437, "LIVE_RASTERIZERSTATE", ApiGroup::ObjectLifetime),
+    (438, "LIVE_QUERY", ApiGroup::ObjectLifetime),
+    (439, "LIVE_PREDICATE", ApiGroup::ObjectLifetime),
+    (440, "LIVE_COUNTER", ApiGroup::ObjectLifetime),
+    (441, "LIVE_DEVICE", ApiGroup::ObjectLifetime),
+    (442, "LIVE_SWAPCHAIN", ApiGroup::ObjectLifetime),
+    (443, "D3D10_MESSAGES_END", ApiGroup::Other),
+    (1048576, "D3D10L9_MESSAGES_START", ApiGroup::Other),
+    (1048577, "CREATEDEPTHSTENCILSTATE_STENCIL_NO_TWO_SIDED", ApiGroup::OutputMerger),
+    (1048579, "CREATESAMPLERSTATE_NO_COMPARISON_SUPPORT", ApiGroup::ResourceCreation),
+    (1048580, "CREATESAMPLERSTATE_EXCESSIVE_ANISOTROPY", ApiGroup::ResourceCreation),
+    (1048581, "CREATESAMPLERSTATE_BORDER_OUT_OF_RANGE", ApiGroup::ResourceCreation),
+    (1048582, "VSSETSAMPLERS_NOT_SUPPORTED", ApiGroup::VertexShader),
+    (1048583, "VSSETSAMPLERS_TOO_MANY_SAMPLERS", ApiGroup::VertexShader),
+    (1048584, "PSSETSAMPLERS_TOO_MANY_SAMPLERS", ApiGroup::PixelShader),
+    (1048585, "CREATERESOURCE_NO_ARRAYS", ApiGroup::ResourceCreation),
+    (1048586, "CREATERESOURCE_NO_VB_AND_IB_BIND", ApiGroup::ResourceCreation),
+    (1048587, "CREATERESOURCE_NO_TEXTURE_1D", ApiGroup::ResourceCreation),
+    (1048588, "CREATERESOURCE_DIMENSION_OUT_OF_RANGE", ApiGroup::ResourceCreation),
+    (1048589, "CREATERESOURCE_NOT_BINDABLE_AS_SHADER_RESOURCE", ApiGroup::ResourceCreation),
+    (1048590, "OMSETRENDERTARGETS_TOO_MANY_RENDER_TARGETS", ApiGroup::OutputMerger),
+    (1048591, "OMSETRENDERTARGETS_NO_DIFFERING_BIT_DEPTHS", ApiGroup::OutputMerger),
+    (1048592, "IASETVERTEXBUFFERS_BAD_BUFFER_INDEX", ApiGroup::InputAssembler),
+    (1048593, "DEVICE_RSSETVIEWPORTS_TOO_MANY_VIEWPORTS", ApiGroup::Rasterizer),
+    (1048594, "DEVICE_IASETPRIMITIVETOPOLOGY_ADJACENCY_UNSUPPORTED", ApiGroup::InputAssembler),
+    (1048595, "DEVICE_RSSETSCISSORRECTS_TOO_MANY_SCISSORS", ApiGroup::Rasterizer),
+    (1048596, "COPYRESOURCE_ONLY_TEXTURE_2D_WITHIN_GPU_MEMORY", ApiGroup::ResourceAccess),
+    (1048597, "COPYRESOURCE_NO_TEXTURE_3D_READBACK", ApiGroup::ResourceAccess),
+    (1048598, "COPYRESOURCE_NO_TEXTURE_ONLY_READBACK", ApiGroup::ResourceAccess),
+    (1048599, "CREATEINPUTLAYOUT_UNSUPPORTED_FORMAT", ApiGroup::InputAssembler),
+    (1048600, "CREATEBLENDSTATE_NO_ALPHA_TO_COVERAGE", ApiGroup::OutputMerger),
+    (1048602, "DRAWINDEXED_STARTINDEXLOCATION_MUST_BE_POSITIVE", ApiGroup::Draw),
+    (1048603, "CREATESHADERRESOURCEVIEW_MUST_USE_LOWEST_LOD", ApiGroup::ResourceCreation),
+    (1048604, "CREATESAMPLERSTATE_MINLOD_MUST_NOT_BE_FRACTIONAL", ApiGroup::ResourceCreation),
+    (1048605, "CREATESAMPLERSTATE_MAXLOD_MUST_BE_FLT_MAX", ApiGroup::ResourceCreation),
+    (1048606, "CREATESHADERRESOURCEVIEW_FIRSTARRAYSLICE_MUST_BE_ZERO", ApiGroup::ResourceCreation),
+    (1048607, "CREATESHADERRESOURCEVIEW_CUBES_MUST_HAVE_6_SIDES", ApiGroup::ResourceCreation),
+    (1048608, "CREATERESOURCE_NOT_BINDABLE_AS_RENDER_TARGET", ApiGroup::ResourceCreation),
+    (1048609, "CREATERESOURCE_NO_DWORD_INDEX_BUFFER", ApiGroup::ResourceCreation),
+    (1048610, "CREATERESOURCE_MSAA_PRECLUDES_SHADER_RESOURCE", ApiGroup::ResourceCreation),
+    (1048611, "CREATERESOURCE_PRESENTATION_PRECLUDES_SHADER_RESOURCE", ApiGroup::ResourceCreation),
+    (1048612, "CREATEBLENDSTATE_NO_INDEPENDENT_BLEND_ENABLE", ApiGroup::OutputMerger),
+    (1048613, "CREATEBLENDSTATE_NO_INDEPENDENT_WRITE_MASKS", ApiGroup::OutputMerger),
+    (1048614, "CREATERESOURCE_NO_STREAM_OUT", ApiGroup::ResourceCreation),
+    (1048615, "CREATERESOURCE_ONLY_VB_IB_FOR_BUFFERS", ApiGroup::ResourceCreation),
+    (1048616, "CREATERESOURCE_NO_AUTOGEN_FOR_VOLUMES", ApiGroup::ResourceCreation),
+    (1048617, "CREATERESOURCE_DXGI_FORMAT_R8G8B8A8_CANNOT_BE_SHARED", ApiGroup::ResourceCreation),
+    (1048618, "VSSHADERRESOURCES_NOT_SUPPORTED", ApiGroup::Other),
+    (1048619, "GEOMETRY_SHADER_NOT_SUPPORTED", ApiGroup::Other),
+    (1048620, "STREAM_OUT_NOT_SUPPORTED", ApiGroup::Other),
+    (1048621, "TEXT_FILTER_NOT_SUPPORTED", ApiGroup::Other),
+    (1048622, "CREATEBLENDSTATE_NO_SEPARATE_ALPHA_BLEND", ApiGroup::OutputMerger),
+    (1048623, "CREATEBLENDSTATE_NO_MRT_BLEND", ApiGroup::OutputMerger),
+    (1048624, "CREATEBLENDSTATE_OPERATION_NOT_SUPPORTED", ApiGroup::OutputMerger),
+    (1048625, "CREATESAMPLERSTATE_NO_MIRRORONCE", ApiGroup::ResourceCreation),
+    (1048626, "DRAWINSTANCED_NOT_SUPPORTED", ApiGroup::Draw),
+    (1048627, "DRAWINDEXEDINSTANCED_NOT_SUPPORTED_BELOW_9_3", ApiGroup::Draw),
+    (1048628, "DRAWINDEXED_POINTLIST_UNSUPPORTED", ApiGroup::Draw),
+    (1048629, "SETBLENDSTATE_SAMPLE_MASK_CANNOT_BE_ZERO", ApiGroup::OutputMerger),
+    (1048630, "CREATERESOURCE_DIMENSION_EXCEEDS_FEATURE_LEVEL_DEFINITION", ApiGroup::ResourceCreation),
+    (1048631, "CREATERESOURCE_ONLY_SINGLE_MIP_LEVEL_DEPTH_STENCIL_SUPPORTED", ApiGroup::ResourceCreation),
+    (1048632, "DEVICE_RSSETSCISSORRECTS_NEGATIVESCISSOR", ApiGroup::Rasterizer),
+    (1048633, "SLOT_ZERO_MUST_BE_D3D10_INPUT_PER_VERTEX_DATA", ApiGroup::Other),
+    (1048634, "CREATERESOURCE_NON_POW_2_MIPMAP", ApiGroup::ResourceCreation),
+    (1048635, "CREATESAMPLERSTATE_BORDER_NOT_SUPPORTED", ApiGroup::ResourceCreation),
+    (1048636, "OMSETRENDERTARGETS_NO_SRGB_MRT", ApiGroup::OutputMerger),
+    (1048637, "COPYRESOURCE_NO_3D_MISMATCHED_UPDATES", ApiGroup::ResourceAccess),
+    (1048638, "D3D10L9_MESSAGES_END", ApiGroup::Other),
+    (2097152, "D3D11_MESSAGES_START", ApiGroup::Other),
+    (2097153, "CREATEDEPTHSTENCILVIEW_INVALIDFLAGS", ApiGroup::ResourceCreation),
+    (2097154, "CREATEVERTEXSHADER_INVALIDCLASSLINKAGE", ApiGroup::VertexShader),
+    (2097155, "CREATEGEOMETRYSHADER_INVALIDCLASSLINKAGE", ApiGroup::GeometryShader),
+    (2097156, "CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_INVALIDNUMSTREAMS", ApiGroup::GeometryShader),
+    (2097157, "CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_INVALIDSTREAMTORASTERIZER", ApiGroup::GeometryShader),
+    (2097158, "CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_UNEXPECTEDSTREAMS", ApiGroup::GeometryShader),
+    (2097159, "CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_INVALIDCLASSLINKAGE", ApiGroup::GeometryShader),
+    (2097160, "CREATEPIXELSHADER_INVALIDCLASSLINKAGE", ApiGroup::PixelShader),
+    (2097161, "CREATEDEFERREDCONTEXT_INVALID_COMMANDLISTFLAGS", ApiGroup::Other),
+    (2097162, "CREATEDEFERREDCONTEXT_SINGLETHREADED", ApiGroup::Other),
+    (2097163, "CREATEDEFERREDCONTEXT_INVALIDARG_RETURN", ApiGroup::Other),
+    (2097164, "CREATEDEFERREDCONTEXT_INVALID_CALL_RETURN", ApiGroup::Other),
+    (2097165, "CREATEDEFERREDCONTEXT_OUTOFMEMORY_RETURN", ApiGroup::Other),
+    (2097166, "FINISHDISPLAYLIST_ONIMMEDIATECONTEXT", ApiGroup::Other),
+    (2097167, "FINISHDISPLAYLIST_OUTOFMEMORY_RETURN", ApiGroup::Other),
+    (2097168, "FINISHDISPLAYLIST_INVALID_CALL_RETURN", ApiGroup::Other),
+    (2097169, "CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_INVALIDSTREAM", ApiGroup::GeometryShader),
+    (2097170, "CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_UNEXPECTEDENTRIES", ApiGroup::GeometryShader),
+    (2097171, "CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_UNEXPECTEDSTRIDES", ApiGroup::GeometryShader),
+    (2097172, "CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_INVALIDNUMSTRIDES", ApiGroup::GeometryShader),
+    (2097173, "DEVICE_HSSETSHADERRESOURCES_HAZARD", ApiGroup::HullShader),
+    (2097174, "DEVICE_HSSETCONSTANTBUFFERS_HAZARD", ApiGroup::HullShader),
+    (2097175, "HSSETSHADERRESOURCES_UNBINDDELETINGOBJECT", ApiGroup::HullShader),
+    (2097176, "HSSETCONSTANTBUFFERS_UNBINDDELETINGOBJECT", ApiGroup::HullShader),
+    (2097177, "CREATEHULLSHADER_INVALIDCALL", ApiGroup::HullShader),
+    (2097178, "CREATEHULLSHADER_OUTOFMEMORY", ApiGroup::HullShader),
+    (2097179, "CREATEHULLSHADER_INVALIDSHADERBYTECODE", ApiGroup::HullShader),
+    (2097180, "CREATEHULLSHADER_INVALIDSHADERTYPE", ApiGroup::HullShader),
+    (2097181, "CREATEHULLSHADER_INVALIDCLASSLINKAGE", ApiGroup::HullShader),
+    (2097182, "DEVICE_HSSETSHADERRESOURCES_VIEWS_EMPTY", ApiGroup::HullShader),
+    (2097183, "HSSETCONSTANTBUFFERS_INVALIDBUFFER", ApiGroup::HullShader),
+    (2097184, "DEVICE_HSSETCONSTANTBUFFERS_BUFFERS_EMPTY", ApiGroup::HullShader),
+    (2097185, "DEVICE_HSSETSAMPLERS_SAMPLERS_EMPTY", ApiGroup::HullShader),
+    (2097186, "DEVICE_HSGETSHADERRESOURCES_VIEWS_EMPTY", ApiGroup::HullShader),
+    (2097187, "DEVICE_HSGETCONSTANTBUFFERS_BUFFERS_EMPTY", ApiGroup::HullShader),
+    (2097188, "DEVICE_HSGETSAMPLERS_SAMPLERS_EMPTY", ApiGroup::HullShader),
+    (2097189, "DEVICE_DSSETSHADERRESOURCES_HAZARD", ApiGroup::DomainShader),
+    (2097190, "DEVICE_DSSETCONSTANTBUFFERS_HAZARD", ApiGroup::DomainShader),
+    (2097191, "DSSETSHADERRESOURCES_UNBINDDELETINGOBJECT", ApiGroup::DomainShader),
+    (2097192, "DSSETCONSTANTBUFFERS_UNBINDDELETINGOBJECT", ApiGroup::DomainShader),
+    (2097193, "CREATEDOMAINSHADER_INVALIDCALL", ApiGroup::DomainShader),
+    (2097194, "CREATEDOMAINSHADER_OUTOFMEMORY", ApiGroup::DomainShader),
+    (2097195, "CREATEDOMAINSHADER_INVALIDSHADERBYTECODE", ApiGroup::DomainShader),
+    (2097196, "CREATEDOMAINSHADER_INVALIDSHADERTYPE", ApiGroup::DomainShader),
+    (2097197, "CREATEDOMAINSHADER_INVALIDCLASSLINKAGE", ApiGroup::DomainShader),
+    (2097198, "DEVICE_DSSETSHADERRESOURCES_VIEWS_EMPTY", ApiGroup::DomainShader),
+    (2097199, "DSSETCONSTANTBUFFERS_INVALIDBUFFER", ApiGroup::DomainShader),
+    (2097200, "DEVICE_DSSETCONSTANTBUFFERS_BUFFERS_EMPTY", ApiGroup::DomainShader),
+    (2097201, "DEVICE_DSSETSAMPLERS_SAMPLERS_EMPTY", ApiGroup::DomainShader),
+    (2097202, "DEVICE_DSGETSHADERRESOURCES_VIEWS_EMPTY", ApiGroup::DomainShader),
+    (2097203, "DEVICE_DSGETCONSTANTBUFFERS_BUFFERS_EMPTY", ApiGroup::DomainShader),
+    (2097204, "DEVICE_DSGETSAMPLERS_SAMPLERS_EMPTY", ApiGroup::DomainShader),
+    (2097205, "DEVICE_DRAW_HS_XOR_DS_MISMATCH", ApiGroup::Draw),
+    (2097206, "DEFERRED_CONTEXT_REMOVAL_PROCESS_AT_FAULT", ApiGroup::Other),
+    (2097207, "DEVICE_DRAWINDIRECT_INVALID_ARG_BUFFER", ApiGroup::Draw),
+    (2097208, "DEVICE_DRAWINDIRECT_OFFSET_UNALIGNED", ApiGroup::Draw),
+    (2097209, "DEVICE_DRAWINDIRECT_OFFSET_OVERFLOW", ApiGroup::Draw),
+    (2097210, "RESOURCE_MAP_INVALIDMAPTYPE", ApiGroup::ResourceAccess),
+    (2097211, "RESOURCE_MAP_INVALIDSUBRESOURCE", ApiGroup::ResourceAccess),
+    (2097212, "RESOURCE_MAP_INVALIDFLAGS", ApiGroup::ResourceAccess),
+    (2097213, "RESOURCE_MAP_ALREADYMAPPED", ApiGroup::ResourceAccess),
+    (2097214, "RESOURCE_MAP_DEVICEREMOVED_RETURN", ApiGroup::ResourceAccess),
+    (2097215, "RESOURCE_MAP_OUTOFMEMORY_RETURN", ApiGroup::ResourceAccess),
+    (2097216, "RESOURCE_MAP_WITHOUT_INITIAL_DISCARD", ApiGroup::ResourceAccess),
+    (2097217, "RESOURCE_UNMAP_INVALIDSUBRESOURCE", ApiGroup::ResourceAccess),
+    (2097218, "RESOURCE_UNMAP_NOTMAPPED", ApiGroup::ResourceAccess),
+    (2097219, "DEVICE_DRAW_RASTERIZING_CONTROL_POINTS", ApiGroup::Draw),
+    (2097220, "DEVICE_IASETPRIMITIVETOPOLOGY_TOPOLOGY_UNSUPPORTED", ApiGroup::InputAssembler),
+    (2097221, "DEVICE_DRAW_HS_DS_SIGNATURE_MISMATCH", ApiGroup::Draw),
+    (2097222, "DEVICE_DRAW_HULL_SHADER_INPUT_TOPOLOGY_MISMATCH", ApiGroup::Draw),
+    (2097223, "DEVICE_DRAW_HS_DS_CONTROL_POINT_COUNT_MISMATCH", ApiGroup::Draw),
+    (2097224, "DEVICE_DRAW_HS_DS_TESSELLATOR_DOMAIN_MISMATCH", ApiGroup::Draw),
+    (2097225, "CREATE_CONTEXT", ApiGroup::ObjectLifetime),
+    (2097226, "LIVE_CONTEXT", ApiGroup::ObjectLifetime),
+    (2097227, "DESTROY_CONTEXT", ApiGroup::ObjectLifetime),
+    (2097228, "CREATE_BUFFER", ApiGroup::ObjectLifetime),
+    (2097229, "LIVE_BUFFER_WIN7", ApiGroup::ObjectLifetime),
+    (2097230, "DESTROY_BUFFER", ApiGroup::ObjectLifetime),
+    (2097231, "CREATE_TEXTURE1D", ApiGroup::ObjectLifetime),
+    (2097232, "LIVE_TEXTURE1D_WIN7", ApiGroup::ObjectLifetime),
+    (2097233, "DESTROY_TEXTURE1D", ApiGroup::ObjectLifetime),
+    (2097234, "CREATE_TEXTURE2D", ApiGroup::ObjectLifetime),
+    (2097235, "LIVE_TEXTURE2D_WIN7", ApiGroup::ObjectLifetime),
+    (2097236, "DESTROY_TEXTURE2D", ApiGroup::ObjectLifetime),
+    (2097237, "CREATE_TEXTURE3D", ApiGroup::ObjectLifetime),
+    (2097238, "LIVE_TEXTURE3D_WIN7", ApiGroup::ObjectLifetime),
+    (2097239, "DESTROY_TEXTURE3D", ApiGroup::ObjectLifetime),
+    (2097240, "CREATE_SHADERRESOURCEVIEW", ApiGroup::ObjectLifetime),
+    (2097241, "LIVE_SHADERRESOURCEVIEW_WIN7", ApiGroup::ObjectLifetime),
+    (2097242, "DESTROY_SHADERRESOURCEVIEW", ApiGroup::ObjectLifetime),
+    (2097243, "CREATE_RENDERTARGETVIEW", ApiGroup::ObjectLifetime),
+    (2097244, "LIVE_RENDERTARGETVIEW_WIN7", ApiGroup::ObjectLifetime),
+    (2097245, "DESTROY_RENDERTARGETVIEW", ApiGroup::ObjectLifetime),
+    (2097246, "CREATE_DEPTHSTENCILVIEW", ApiGroup::ObjectLifetime),
+    (2097247, "LIVE_DEPTHSTENCILVIEW_WIN7", ApiGroup::ObjectLifetime),
+    (2097248, "DESTROY_DEPTHSTENCILVIEW", ApiGroup::ObjectLifetime),
+    (2097249, "CREATE_VERTEXSHADER", ApiGroup::ObjectLifetime),
+    (2097250, "LIVE_VERTEXSHADER_WIN7", ApiGroup::ObjectLifetime),
+    (2097251, "DESTROY_VERTEXSHADER", ApiGroup::ObjectLifetime),
+    (2097252, "CREATE_HULLSHADER", ApiGroup::ObjectLifetime),
+    (2097253, "LIVE_HULLSHADER", ApiGroup::ObjectLifetime),
+    (2097254, "DESTROY_HULLSHADER", ApiGroup::ObjectLifetime),
+    (2097255, "CREATE_DOMAINSHADER", ApiGroup::ObjectLifetime),
+    (2097256, "LIVE_DOMAINSHADER", ApiGroup::ObjectLifetime),
+    (2097257, "DESTROY_DOMAINSHADER", ApiGroup::ObjectLifetime),
+    (2097258, "CREATE_GEOMETRYSHADER", ApiGroup::ObjectLifetime),
+    (2097259, "LIVE_GEOMETRYSHADER_WIN7", ApiGroup::ObjectLifetime),
+    (2097260, "DESTROY_GEOMETRYSHADER", ApiGroup::ObjectLifetime),
+    (2097261, "CREATE_PIXELSHADER", ApiGroup::ObjectLifetime),
+    (2097262, "LIVE_PIXELSHADER_WIN7", ApiGroup::ObjectLifetime),
+    (2097263, "DESTROY_PIXELSHADER", ApiGroup::ObjectLifetime),
+    (2097264, "CREATE_INPUTLAYOUT", ApiGroup::ObjectLifetime),
+    (2097265, "LIVE_INPUTLAYOUT_WIN7", ApiGroup::ObjectLifetime),
+    (2097266, "DESTROY_INPUTLAYOUT", ApiGroup::ObjectLifetime),
+    (2097267, "CREATE_SAMPLER", ApiGroup::ObjectLifetime),
+    (2097268, "LIVE_SAMPLER_WIN7", ApiGroup::ObjectLifetime),
+    (2097269, "DESTROY_SAMPLER", ApiGroup::ObjectLifetime),
+    (2097270, "CREATE_BLENDSTATE", ApiGroup::ObjectLifetime),
+    (2097271, "LIVE_BLENDSTATE_WIN7", ApiGroup::ObjectLifetime),
+    (2097272, "DESTROY_BLENDSTATE", ApiGroup::ObjectLifetime),
+    (2097273, "CREATE_DEPTHSTENCILSTATE", ApiGroup::ObjectLifetime),
+    (2097274, "LIVE_DEPTHSTENCILSTATE_WIN7", ApiGroup::ObjectLifetime),
+    (2097275, "DESTROY_DEPTHSTENCILSTATE", ApiGroup::ObjectLifetime),
+    (2097276, "CREATE_RASTERIZERSTATE", ApiGroup::ObjectLifetime),
+    (2097277, "LIVE_RASTERIZERSTATE_WIN7", ApiGroup::ObjectLifetime),
+    (2097278, "DESTROY_RASTERIZERSTATE", ApiGroup::ObjectLifetime),
+    (2097279, "CREATE_QUERY", ApiGroup::ObjectLifetime),
+    (2097280, "LIVE_QUERY_WIN7", ApiGroup::ObjectLifetime),
+    (2097281, "DESTROY_QUERY", ApiGroup::ObjectLifetime),
+    (2097282, "CREATE_PREDICATE", ApiGroup::ObjectLifetime),
+    (2097283, "LIVE_PREDICATE_WIN7", ApiGroup::ObjectLifetime),
+    (2097284, "DESTROY_PREDICATE", ApiGroup::ObjectLifetime),
+    (2097285, "CREATE_COUNTER", ApiGroup::ObjectLifetime),
+    (2097286, "DESTROY_COUNTER", ApiGroup::ObjectLifetime),
+    (2097287, "CREATE_COMMANDLIST", ApiGroup::ObjectLifetime),
+    (2097288, "LIVE_COMMANDLIST", ApiGroup::ObjectLifetime),
+    (2097289, "DESTROY_COMMANDLIST", ApiGroup::ObjectLifetime),
+    (2097290, "CREATE_CLASSINSTANCE", ApiGroup::ObjectLifetime),
+    (2097291, "LIVE_CLASSINSTANCE", ApiGroup::ObjectLifetime),
+    (2097292, "DESTROY_CLASSINSTANCE", ApiGroup::ObjectLifetime),
+    (2097293, "CREATE_CLASSLINKAGE", ApiGroup::ObjectLifetime),
+    (2097294, "LIVE_CLASSLINKAGE", ApiGroup::ObjectLifetime),
+    (2097295, "DESTROY_CLASSLINKAGE", ApiGroup::ObjectLifetime),
+    (2097296, "LIVE_DEVICE_WIN7", ApiGroup::ObjectLifetime),
+    (2097297, "LIVE_OBJECT_SUMMARY_WIN7", ApiGroup::ObjectLifetime),
+    (2097298, "CREATE_COMPUTESHADER", ApiGroup::ObjectLifetime),
+    (2097299, "LIVE_COMPUTESHADER", ApiGroup::ObjectLifetime),
+    (2097300, "DESTROY_COMPUTESHADER", ApiGroup::ObjectLifetime),
+    (2097301, "CREATE_UNORDEREDACCESSVIEW", ApiGroup::ObjectLifetime),
+    (2097302, "LIVE_UNORDEREDACCESSVIEW", ApiGroup::ObjectLifetime),
+    (2097303, "DESTROY_UNORDEREDACCESSVIEW", ApiGroup::ObjectLifetime),
+    (2097304, "DEVICE_SETSHADER_INTERFACES_FEATURELEVEL", ApiGroup::Other),
+    (2097305, "DEVICE_SETSHADER_INTERFACE_COUNT_MISMATCH", ApiGroup::Other),
+    (2097306, "DEVICE_SETSHADER_INVALID_INSTANCE", ApiGroup::Other),
+    (2097307, "DEVICE_SETSHADER_INVALID_INSTANCE_INDEX", ApiGroup::Other),
+    (2097308, "DEVICE_SETSHADER_INVALID_INSTANCE_TYPE", ApiGroup::Other),
+    (2097309, "DEVICE_SETSHADER_INVALID_INSTANCE_DATA", ApiGroup::Other),
+    (2097310, "DEVICE_SETSHADER_UNBOUND_INSTANCE_DATA", ApiGroup::Other),
+    (2097311, "DEVICE_SETSHADER_INSTANCE_DATA_BINDINGS", ApiGroup::Other),
+    (2097312, "DEVICE_CREATESHADER_CLASSLINKAGE_FULL", ApiGroup::Other),
+    (2097313, "DEVICE_CHECKFEATURESUPPORT_UNRECOGNIZED_FEATURE", ApiGroup::Other),
+    (2097314, "DEVICE_CHECKFEATURESUPPORT_MISMATCHED_DATA_SIZE", ApiGroup::Other),
+    (2097315, "DEVICE_CHECKFEATURESUPPORT_INVALIDARG_RETURN", ApiGroup::Other),
+    (2097316, "DEVICE_CSSETSHADERRESOURCES_HAZARD", ApiGroup::ComputeShader),
+    (2097317, "DEVICE_CSSETCONSTANTBUFFERS_HAZARD", ApiGroup::ComputeShader),
+    (2097318, "CSSETSHADERRESOURCES_UNBINDDELETINGOBJECT", ApiGroup::ComputeShader),
+    (2097319, "CSSETCONSTANTBUFFERS_UNBINDDELETINGOBJECT", ApiGroup::ComputeShader),
+    (2097320, "CREATECOMPUTESHADER_INVALIDCALL", ApiGroup::ComputeShader),
+    (2097321, "CREATECOMPUTESHADER_OUTOFMEMORY", ApiGroup::ComputeShader),
+    (2097322, "CREATECOMPUTESHADER_INVALIDSHADERBYTECODE", ApiGroup::ComputeShader),
+    (2097323, "CREATECOMPUTESHADER_INVALIDSHADERTYPE", ApiGroup::ComputeShader),
+    (2097324, "CREATECOMPUTESHADER_INVALIDCLASSLINKAGE", ApiGroup::ComputeShader),
+    (2097325, "DEVICE_CSSETSHADERRESOURCES_VIEWS_EMPTY", ApiGroup::ComputeShader),
+    (2097326, "CSSETCONSTANTBUFFERS_INVALIDBUFFER", ApiGroup::ComputeShader),
+    (2097327, "DEVICE_CSSETCONSTANTBUFFERS_BUFFERS_EMPTY", ApiGroup::ComputeShader),
+    (2097328, "DEVICE_CSSETSAMPLERS_SAMPLERS_EMPTY", ApiGroup::ComputeShader),
+    (2097329, "DEVICE_CSGETSHADERRESOURCES_VIEWS_EMPTY", ApiGroup::ComputeShader),
+    (2097330, "DEVICE_CSGETCONSTANTBUFFERS_BUFFERS_EMPTY", ApiGroup::ComputeShader),
+    (2097331, "DEVICE_CSGETSAMPLERS_SAMPLERS_EMPTY", ApiGroup::ComputeShader),
+    (2097332, "DEVICE_CREATEVERTEXSHADER_DOUBLEFLOATOPSNOTSUPPORTED", ApiGroup::VertexShader),
+    (2097333, "DEVICE_CREATEHULLSHADER_DOUBLEFLOATOPSNOTSUPPORTED", ApiGroup::HullShader),
+    (2097334, "DEVICE_CREATEDOMAINSHADER_DOUBLEFLOATOPSNOTSUPPORTED", ApiGroup::DomainShader),
+    (2097335, "DEVICE_CREATEGEOMETRYSHADER_DOUBLEFLOATOPSNOTSUPPORTED", ApiGroup::GeometryShader),
+    (2097336, "DEVICE_CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_DOUBLEFLOATOPSNOTSUPPORTED", ApiGroup::GeometryShader),
+    (2097337, "DEVICE_CREATEPIXELSHADER_DOUBLEFLOATOPSNOTSUPPORTED", ApiGroup::PixelShader),
+    (2097338, "DEVICE_CREATECOMPUTESHADER_DOUBLEFLOATOPSNOTSUPPORTED", ApiGroup::ComputeShader),
+    (2097339, "CREATEBUFFER_INVALIDSTRUCTURESTRIDE", ApiGroup::ResourceCreation),
+    (2097340, "CREATESHADERRESOURCEVIEW_INVALIDFLAGS", ApiGroup::ResourceCreation),
+    (2097341, "CREATEUNORDEREDACCESSVIEW_INVALIDRESOURCE", ApiGroup::ResourceCreation),
+    (2097342, "CREATEUNORDEREDACCESSVIEW_INVALIDDESC", ApiGroup::ResourceCreation),
+    (2097343, "CREATEUNORDEREDACCESSVIEW_INVALIDFORMAT", ApiGroup::ResourceCreation),
+    (2097344, "CREATEUNORDEREDACCESSVIEW_INVALIDDIMENSIONS", ApiGroup::ResourceCreation),
+    (2097345, "CREATEUNORDEREDACCESSVIEW_UNRECOGNIZEDFORMAT", ApiGroup::ResourceCreation),
+    (2097346, "DEVICE_OMSETRENDERTARGETSANDUNORDEREDACCESSVIEWS_HAZARD", ApiGroup::OutputMerger),
+    (2097347, "DEVICE_OMSETRENDERTARGETSANDUNORDEREDACCESSVIEWS_OVERLAPPING_OLD_SLOTS", ApiGroup::OutputMerger),
+    (2097348, "DEVICE_OMSETRENDERTARGETSANDUNORDEREDACCESSVIEWS_NO_OP", ApiGroup::OutputMerger),
+    (2097349, "CSSETUNORDEREDACCESSVIEWS_UNBINDDELETINGOBJECT", ApiGroup::ComputeShader),
+    (2097350, "PSSETUNORDEREDACCESSVIEWS_UNBINDDELETINGOBJECT", ApiGroup::PixelShader),
+    (2097351, "CREATEUNORDEREDACCESSVIEW_INVALIDARG_RETURN", ApiGroup::ResourceCreation),
+    (2097352, "CREATEUNORDEREDACCESSVIEW_OUTOFMEMORY_RETURN", ApiGroup::ResourceCreation),
+    (2097353, "CREATEUNORDEREDACCESSVIEW_TOOMANYOBJECTS", ApiGroup::ResourceCreation),
+    (2097354, "DEVICE_CSSETUNORDEREDACCESSVIEWS_HAZARD", ApiGroup::ComputeShader),
+    (2097355, "CLEARUNORDEREDACCESSVIEW_DENORMFLUSH", ApiGroup::ComputeShader),
+    (2097356, "DEVICE_CSSETUNORDEREDACCESSS_VIEWS_EMPTY", ApiGroup::ComputeShader),
+    (2097357, "DEVICE_CSGETUNORDEREDACCESSS_VIEWS_EMPTY", ApiGroup::ComputeShader),
+    (2097358, "CREATEUNORDEREDACCESSVIEW_INVALIDFLAGS", ApiGroup::ResourceCreation),
+    (2097359, "CREATESHADERRESESOURCEVIEW_TOOMANYOBJECTS", ApiGroup::Other),
+    (2097360, "DEVICE_DISPATCHINDIRECT_INVALID_ARG_BUFFER", ApiGroup::ComputeShader),
+    (2097361, "DEVICE_DISPATCHINDIRECT_OFFSET_UNALIGNED", ApiGroup::ComputeShader),
+    (2097362, "DEVICE_DISPATCHINDIRECT_OFFSET_OVERFLOW", ApiGroup::ComputeShader),
+    (2097363, "DEVICE_SETRESOURCEMINLOD_INVALIDCONTEXT", ApiGroup::Other),
+    (2097364, "DEVICE_SETRESOURCEMINLOD_INVALIDRESOURCE", ApiGroup::Other),
+    (2097365, "DEVICE_SETRESOURCEMINLOD_INVALIDMINLOD", ApiGroup::Other),
+    (2097366, "DEVICE_GETRESOURCEMINLOD_INVALIDCONTEXT", ApiGroup::Other),
+    (2097367, "DEVICE_GETRESOURCEMINLOD_INVALIDRESOURCE", ApiGroup::Other),
+    (2097368, "OMSETDEPTHSTENCIL_UNBINDDELETINGOBJECT", ApiGroup::OutputMerger),
+    (2097369, "CLEARDEPTHSTENCILVIEW_DEPTH_READONLY", ApiGroup::OutputMerger),
+    (2097370, "CLEARDEPTHSTENCILVIEW_STENCIL_READONLY", ApiGroup::OutputMerger),
+    (2097371, "CHECKFEATURESUPPORT_FORMAT_DEPRECATED", ApiGroup::Other),
+    (2097372, "DEVICE_UNORDEREDACCESSVIEW_RETURN_TYPE_MISMATCH", ApiGroup::Other),
+    (2097373, "DEVICE_UNORDEREDACCESSVIEW_NOT_SET", ApiGroup::Other),
+    (2097374, "DEVICE_DRAW_UNORDEREDACCESSVIEW_RENDERTARGETVIEW_OVERLAP", ApiGroup::Draw),
+    (2097375, "DEVICE_UNORDEREDACCESSVIEW_DIMENSION_MISMATCH", ApiGroup::Other),
+    (2097376, "DEVICE_UNORDEREDACCESSVIEW_APPEND_UNSUPPORTED", ApiGroup::Other),
+    (2097377, "DEVICE_UNORDEREDACCESSVIEW_ATOMICS_UNSUPPORTED", ApiGroup::Other),
+    (2097378, "DEVICE_UNORDEREDACCESSVIEW_STRUCTURE_STRIDE_MISMATCH", ApiGroup::Other),
+    (2097379, "DEVICE_UNORDEREDACCESSVIEW_BUFFER_TYPE_MISMATCH", ApiGroup::Other),
+    (2097380, "DEVICE_UNORDEREDACCESSVIEW_RAW_UNSUPPORTED", ApiGroup::Other),
+    (2097381, "DEVICE_UNORDEREDACCESSVIEW_FORMAT_LD_UNSUPPORTED", ApiGroup::Other),
+    (2097382, "DEVICE_UNORDEREDACCESSVIEW_FORMAT_STORE_UNSUPPORTED", ApiGroup::Other),
+    (2097383, "DEVICE_UNORDEREDACCESSVIEW_ATOMIC_ADD_UNSUPPORTED", ApiGroup::Other),
+    (2097384, "DEVICE_UNORDEREDACCESSVIEW_ATOMIC_BITWISE_OPS_UNSUPPORTED", ApiGroup::Other),
+    (2097385, "DEVICE_UNORDEREDACCESSVIEW_ATOMIC_CMPSTORE_CMPEXCHANGE_UNSUPPORTED", ApiGroup::Other),
+    (2097386, "DEVICE_UNORDEREDACCESSVIEW_ATOMIC_EXCHANGE_UNSUPPORTED", ApiGroup::Other),
+    (2097387, "DEVICE_UNORDEREDACCESSVIEW_ATOMIC_SIGNED_MINMAX_UNSUPPORTED", ApiGroup::Other),
+    (2097388, "DEVICE_UNORDEREDACCESSVIEW_ATOMIC_UNSIGNED_MINMAX_UNSUPPORTED", ApiGroup::Other),
+    (2097389, "DEVICE_DISPATCH_BOUND_RESOURCE_MAPPED", ApiGroup::ComputeShader),
+    (2097390, "DEVICE_DISPATCH_THREADGROUPCOUNT_OVERFLOW", ApiGroup::ComputeShader),
+    (2097391, "DEVICE_DISPATCH_THREADGROUPCOUNT_ZERO", ApiGroup::ComputeShader),
+    (2097392, "DEVICE_SHADERRESOURCEVIEW_STRUCTURE_STRIDE_MISMATCH", ApiGroup::Other),
+    (2097393, "DEVICE_SHADERRESOURCEVIEW_BUFFER_TYPE_MISMATCH", ApiGroup::Other),
+    (2097394, "DEVICE_SHADERRESOURCEVIEW_RAW_UNSUPPORTED", ApiGroup::Other),
+    (2097395, "DEVICE_DISPATCH_UNSUPPORTED", ApiGroup::ComputeShader),
+    (2097396, "DEVICE_DISPATCHINDIRECT_UNSUPPORTED", ApiGroup::ComputeShader),
+    (2097397, "COPYSTRUCTURECOUNT_INVALIDOFFSET", ApiGroup::ResourceAccess),
+    (2097398, "COPYSTRUCTURECOUNT_LARGEOFFSET", ApiGroup::ResourceAccess),
+    (2097399, "COPYSTRUCTURECOUNT_INVALIDDESTINATIONSTATE", ApiGroup::ResourceAccess),
+    (2097400, "COPYSTRUCTURECOUNT_INVALIDSOURCESTATE", ApiGroup::ResourceAccess),
+    (2097401, "CHECKFORMATSUPPORT_FORMAT_NOT_SUPPORTED", ApiGroup::Other),
+    (2097402, "DEVICE_CSSETUNORDEREDACCESSVIEWS_INVALIDVIEW", ApiGroup::ComputeShader),
+    (2097403, "DEVICE_CSSETUNORDEREDACCESSVIEWS_INVALIDOFFSET", ApiGroup::ComputeShader),
+    (2097404, "DEVICE_CSSETUNORDEREDACCESSVIEWS_TOOMANYVIEWS", ApiGroup::ComputeShader),
+    (2097405, "CLEARUNORDEREDACCESSVIEWFLOAT_INVALIDFORMAT", ApiGroup::ComputeShader),
+    (2097406, "DEVICE_UNORDEREDACCESSVIEW_COUNTER_UNSUPPORTED", ApiGroup::Other),
+    (2097407, "REF_WARNING", ApiGroup::Other),
+    (2097408, "DEVICE_DRAW_PIXEL_SHADER_WITHOUT_RTV_OR_DSV", ApiGroup::Draw),
+    (2097409, "SHADER_ABORT", ApiGroup::Other),
+    (2097410, "SHADER_MESSAGE", ApiGroup::Other),
+    (2097411, "SHADER_ERROR", ApiGroup::Other),
+    (2097412, "OFFERRESOURCES_INVALIDRESOURCE", ApiGroup::Other),
+    (2097413, "HSSETSAMPLERS_UNBINDDELETINGOBJECT", ApiGroup::HullShader),
+    (2097414, "DSSETSAMPLERS_UNBINDDELETINGOBJECT", ApiGroup::DomainShader),
+    (2097415, "CSSETSAMPLERS_UNBINDDELETINGOBJECT", ApiGroup::ComputeShader),
+    (2097416, "HSSETSHADER_UNBINDDELETINGOBJECT", ApiGroup::HullShader),
+    (2097417, "DSSETSHADER_UNBINDDELETINGOBJECT", ApiGroup::DomainShader),
+    (2097418, "CSSETSHADER_UNBINDDELETINGOBJECT", ApiGroup::ComputeShader),
+    (2097419, "ENQUEUESETEVENT_INVALIDARG_RETURN", ApiGroup::Other),
+    (2097420, "ENQUEUESETEVENT_OUTOFMEMORY_RETURN", ApiGroup::Other),
+    (2097421, "ENQUEUESETEVENT_ACCESSDENIED_RETURN", ApiGroup::Other),
+    (2097422, "DEVICE_OMSETRENDERTARGETSANDUNORDEREDACCESSVIEWS_NUMUAVS_INVALIDRANGE", ApiGroup::OutputMerger),
+    (2097423, "USE_OF_ZERO_REFCOUNT_OBJECT", ApiGroup::Other),
+    (2097424, "D3D11_MESSAGES_END", ApiGroup::Other),
+    (3145728, "D3D11_1_MESSAGES_START", ApiGroup::Other),
+    (3145729, "CREATE_VIDEODECODER", ApiGroup::ObjectLifetime),
+    (3145730, "CREATE_VIDEOPROCESSORENUM", ApiGroup::ObjectLifetime),
+    (3145731, "CREATE_VIDEOPROCESSOR", ApiGroup::ObjectLifetime),
+    (3145732, "CREATE_DECODEROUTPUTVIEW", ApiGroup::ObjectLifetime),
+    (3145733, "CREATE_PROCESSORINPUTVIEW", ApiGroup::ObjectLifetime),
+    (3145734, "CREATE_PROCESSOROUTPUTVIEW", ApiGroup::ObjectLifetime),
+    (3145735, "CREATE_DEVICECONTEXTSTATE", ApiGroup::ObjectLifetime),
+    (3145736, "LIVE_VIDEODECODER", ApiGroup::ObjectLifetime),
+    (3145737, "LIVE_VIDEOPROCESSORENUM", ApiGroup::ObjectLifetime),
+    (3145738, "LIVE_VIDEOPROCESSOR", ApiGroup::ObjectLifetime),
+    (3145739, "LIVE_DECODEROUTPUTVIEW", ApiGroup::ObjectLifetime),
+    (3145740, "LIVE_PROCESSORINPUTVIEW", ApiGroup::ObjectLifetime),
+    (3145741, "LIVE_PROCESSOROUTPUTVIEW", ApiGroup::ObjectLifetime),
+    (3145742, "LIVE_DEVICECONTEXTSTATE", ApiGroup::ObjectLifetime),
+    (3145743, "DESTROY_VIDEODECODER", ApiGroup::ObjectLifetime),
+    (3145744, "DESTROY_VIDEOPROCESSORENUM", ApiGroup::ObjectLifetime),
+    (3145745, "DESTROY_VIDEOPROCESSOR", ApiGroup::ObjectLifetime),
+    (3145746, "DESTROY_DECODEROUTPUTVIEW", ApiGroup::ObjectLifetime),
+    (3145747, "DESTROY_PROCESSORINPUTVIEW", ApiGroup::ObjectLifetime),
+    (3145748, "DESTROY_PROCESSOROUTPUTVIEW", ApiGroup::ObjectLifetime),
+    (3145749, "DESTROY_DEVICECONTEXTSTATE", ApiGroup::ObjectLifetime),
+    (3145750, "CREATEDEVICECONTEXTSTATE_INVALIDFLAGS", ApiGroup::Other),
+    (3145751, "CREATEDEVICECONTEXTSTATE_INVALIDFEATURELEVEL", ApiGroup::Other),
+    (3145752, "CREATEDEVICECONTEXTSTATE_FEATURELEVELS_NOT_SUPPORTED", ApiGroup::Other),
+    (3145753, "CREATEDEVICECONTEXTSTATE_INVALIDREFIID", ApiGroup::Other),
+    (3145754, "DEVICE_DISCARDVIEW_INVALIDVIEW", ApiGroup::ResourceAccess),
+    (3145755, "COPYSUBRESOURCEREGION1_INVALIDCOPYFLAGS", ApiGroup::ResourceAccess),
+    (3145756, "UPDATESUBRESOURCE1_INVALIDCOPYFLAGS", ApiGroup::ResourceAccess),
+    (3145757, "CREATERASTERIZERSTATE_INVALIDFORCEDSAMPLECOUNT", ApiGroup::Rasterizer),
+    (3145758, "CREATEVIDEODECODER_OUTOFMEMORY_RETURN", ApiGroup::Video),
+    (3145759, "CREATEVIDEODECODER_NULLPARAM", ApiGroup::Video),
+    (3145760, "CREATEVIDEODECODER_INVALIDFORMAT", ApiGroup::Video),
+    (3145761, "CREATEVIDEODECODER_ZEROWIDTHHEIGHT", ApiGroup::Video),
+    (3145762, "CREATEVIDEODECODER_DRIVER_INVALIDBUFFERSIZE", ApiGroup::Video),
+    (3145763, "CREATEVIDEODECODER_DRIVER_INVALIDBUFFERUSAGE", ApiGroup::Video),
+    (3145764, "GETVIDEODECODERPROFILECOUNT_OUTOFMEMORY", ApiGroup::Video),
+    (3145765, "GETVIDEODECODERPROFILE_NULLPARAM", ApiGroup::Video),
+    (3145766, "GETVIDEODECODERPROFILE_INVALIDINDEX", ApiGroup::Video),
+    (3145767, "GETVIDEODECODERPROFILE_OUTOFMEMORY_RETURN", ApiGroup::Video),
+    (3145768, "CHECKVIDEODECODERFORMAT_NULLPARAM", ApiGroup::Video),
+    (3145769, "CHECKVIDEODECODERFORMAT_OUTOFMEMORY_RETURN", ApiGroup::Video),
+    (3145770, "GETVIDEODECODERCONFIGCOUNT_NULLPARAM", ApiGroup::Video),
+    (3145771, "GETVIDEODECODERCONFIGCOUNT_OUTOFMEMORY_RETURN", ApiGroup::Video),
+    (3145772, "GETVIDEODECODERCONFIG_NULLPARAM", ApiGroup::Video),
+    (3145773, "GETVIDEODECODERCONFIG_INVALIDINDEX", ApiGroup::Video),
+    (3145774, "GETVIDEODECODERCONFIG_OUTOFMEMORY_RETURN", ApiGroup::Video),
+    (3145775, "GETDECODERCREATIONPARAMS_NULLPARAM", ApiGroup::Video),
+    (3145776, "GETDECODERDRIVERHANDLE_NULLPARAM", ApiGroup::Video),
+    (3145777, "GETDECODERBUFFER_NULLPARAM", ApiGroup::Video),
+    (3145778, "GETDECODERBUFFER_INVALIDBUFFER", ApiGroup::Video),
+    (3145779, "GETDECODERBUFFER_INVALIDTYPE", ApiGroup::Video),
+    (3145780, "GETDECODERBUFFER_LOCKED", ApiGroup::Video),
+    (3145781, "RELEASEDECODERBUFFER_NULLPARAM", ApiGroup::Video),
+    (3145782, "RELEASEDECODERBUFFER_INVALIDTYPE", ApiGroup::Video),
+    (3145783, "RELEASEDECODERBUFFER_NOTLOCKED", ApiGroup::Video),
+    (3145784, "DECODERBEGINFRAME_NULLPARAM", ApiGroup::Video),
+    (3145785, "DECODERBEGINFRAME_HAZARD", ApiGroup::Video),
+    (3145786, "DECODERENDFRAME_NULLPARAM", ApiGroup::Video),
+    (3145787, "SUBMITDECODERBUFFERS_NULLPARAM", ApiGroup::Video),
+    (3145788, "SUBMITDECODERBUFFERS_INVALIDTYPE", ApiGroup::Video),
+    (3145789, "DECODEREXTENSION_NULLPARAM", ApiGroup::Video),
+    (3145790, "DECODEREXTENSION_INVALIDRESOURCE", ApiGroup::Video),
+    (3145791, "CREATEVIDEOPROCESSORENUMERATOR_OUTOFMEMORY_RETURN", ApiGroup::Video),
+    (3145792, "CREATEVIDEOPROCESSORENUMERATOR_NULLPARAM", ApiGroup::Video),
+    (3145793, "CREATEVIDEOPROCESSORENUMERATOR_INVALIDFRAMEFORMAT", ApiGroup::Video),
+    (3145794, "CREATEVIDEOPROCESSORENUMERATOR_INVALIDUSAGE", ApiGroup::Video),
+    (3145795, "CREATEVIDEOPROCESSORENUMERATOR_INVALIDINPUTFRAMERATE", ApiGroup::Video),
+    (3145796, "CREATEVIDEOPROCESSORENUMERATOR_INVALIDOUTPUTFRAMERATE", ApiGroup::Video),
+    (3145797, "CREATEVIDEOPROCESSORENUMERATOR_INVALIDWIDTHHEIGHT", ApiGroup::Video),
+    (3145798, "GETVIDEOPROCESSORCONTENTDESC_NULLPARAM", ApiGroup::Video),
+    (3145799, "CHECKVIDEOPROCESSORFORMAT_NULLPARAM", ApiGroup::Video),
+    (3145800, "GETVIDEOPROCESSORCAPS_NULLPARAM", ApiGroup::Video),
+    (3145801, "GETVIDEOPROCESSORRATECONVERSIONCAPS_NULLPARAM", ApiGroup::Video),
+    (3145802, "GETVIDEOPROCESSORRATECONVERSIONCAPS_INVALIDINDEX", ApiGroup::Video),
+    (3145803, "GETVIDEOPROCESSORCUSTOMRATE_NULLPARAM", ApiGroup::Video),
+    (3145804, "GETVIDEOPROCESSORCUSTOMRATE_INVALIDINDEX", ApiGroup::Video),
+    (3145805, "GETVIDEOPROCESSORFILTERRANGE_NULLPARAM", ApiGroup::Video),
+    (3145806, "GETVIDEOPROCESSORFILTERRANGE_UNSUPPORTED", ApiGroup::Video),
+    (3145807, "CREATEVIDEOPROCESSOR_OUTOFMEMORY_RETURN", ApiGroup::Video),
+    (3145808, "CREATEVIDEOPROCESSOR_NULLPARAM", ApiGroup::Video),
+    (3145809, "VIDEOPROCESSORSETOUTPUTTARGETRECT_NULLPARAM", ApiGroup::Video),
+    (3145810, "VIDEOPROCESSORSETOUTPUTBACKGROUNDCOLOR_NULLPARAM", ApiGroup::Video),
+    (3145811, "VIDEOPROCESSORSETOUTPUTBACKGROUNDCOLOR_INVALIDALPHA", ApiGroup::Video),
+    (3145812, "VIDEOPROCESSORSETOUTPUTCOLORSPACE_NULLPARAM", ApiGroup::Video),
+    (3145813, "VIDEOPROCESSORSETOUTPUTALPHAFILLMODE_NULLPARAM", ApiGroup::Video),
+    (3145814, "VIDEOPROCESSORSETOUTPUTALPHAFILLMODE_UNSUPPORTED", ApiGroup::Video),
+    (3145815, "VIDEOPROCESSORSETOUTPUTALPHAFILLMODE_INVALIDSTREAM", ApiGroup::Video),
+    (3145816, "VIDEOPROCESSORSETOUTPUTALPHAFILLMODE_INVALIDFILLMODE", ApiGroup::Video),
+    (3145817, "VIDEOPROCESSORSETOUTPUTCONSTRICTION_NULLPARAM", ApiGroup::Video),
+    (3145818, "VIDEOPROCESSORSETOUTPUTSTEREOMODE_NULLPARAM", ApiGroup::Video),
+    (3145819, "VIDEOPROCESSORSETOUTPUTSTEREOMODE_UNSUPPORTED", ApiGroup::Video),
+    (3145820, "VIDEOPROCESSORSETOUTPUTEXTENSION_NULLPARAM", ApiGroup::Video),
+    (3145821, "VIDEOPROCESSORGETOUTPUTTARGETRECT_NULLPARAM", ApiGroup::Video),
+    (3145822, "VIDEOPROCESSORGETOUTPUTBACKGROUNDCOLOR_NULLPARAM", ApiGroup::Video),
+    (3145823, "VIDEOPROCESSORGETOUTPUTCOLORSPACE_NULLPARAM", ApiGroup::Video),
+    (3145824, "VIDEOPROCESSORGETOUTPUTALPHAFILLMODE_NULLPARAM", ApiGroup::Video),
+    (3145825, "VIDEOPROCESSORGETOUTPUTCONSTRICTION_NULLPARAM", ApiGroup::Video),
+    (3145826, "VIDEOPROCESSORSETOUTPUTCONSTRICTION_UNSUPPORTED", ApiGroup::Video),
+    (3145827, "VIDEOPROCESSORSETOUTPUTCONSTRICTION_INVALIDSIZE", ApiGroup::Video),
+    (3145828, "VIDEOPROCESSORGETOUTPUTSTEREOMODE_NULLPARAM", ApiGroup::Video),
+    (3145829, "VIDEOPROCESSORGETOUTPUTEXTENSION_NULLPARAM", ApiGroup::Video),
+    (3145830, "VIDEOPROCESSORSETSTREAMFRAMEFORMAT_NULLPARAM", ApiGroup::Video),
+    (3145831, "VIDEOPROCESSORSETSTREAMFRAMEFORMAT_INVALIDFORMAT", ApiGroup::Video),
+    (3145832, "VIDEOPROCESSORSETSTREAMFRAMEFORMAT_INVALIDSTREAM", ApiGroup::Video),
+    (3145833, "VIDEOPROCESSORSETSTREAMCOLORSPACE_NULLPARAM", ApiGroup::Video),
+    (3145834, "VIDEOPROCESSORSETSTREAMCOLORSPACE_INVALIDSTREAM", ApiGroup::Video),
+    (3145835, "VIDEOPROCESSORSETSTREAMOUTPUTRATE_NULLPARAM", ApiGroup::Video),
+    (3145836, "VIDEOPROCESSORSETSTREAMOUTPUTRATE_INVALIDRATE", ApiGroup::Video),
+    (3145837, "VIDEOPROCESSORSETSTREAMOUTPUTRATE_INVALIDFLAG", ApiGroup::Video),
+    (3145838, "VIDEOPROCESSORSETSTREAMOUTPUTRATE_INVALIDSTREAM", ApiGroup::Video),
+    (3145839, "VIDEOPROCESSORSETSTREAMSOURCERECT_NULLPARAM", ApiGroup::Video),
+    (3145840, "VIDEOPROCESSORSETSTREAMSOURCERECT_INVALIDSTREAM", ApiGroup::Video),
+    (3145841, "VIDEOPROCESSORSETSTREAMSOURCERECT_INVALIDRECT", ApiGroup::Video),
+    (3145842, "VIDEOPROCESSORSETSTREAMDESTRECT_NULLPARAM", ApiGroup::Video),
+    (3145843, "VIDEOPROCESSORSETSTREAMDESTRECT_INVALIDSTREAM", ApiGroup::Video),
+    (3145844, "VIDEOPROCESSORSETSTREAMDESTRECT_INVALIDRECT", ApiGroup::Video),
+    (3145845, "VIDEOPROCESSORSETSTREAMALPHA_NULLPARAM", ApiGroup::Video),
+    (3145846, "VIDEOPROCESSORSETSTREAMALPHA_INVALIDSTREAM", ApiGroup::Video),
+    (3145847, "VIDEOPROCESSORSETSTREAMALPHA_INVALIDALPHA", ApiGroup::Video),
+    (3145848, "VIDEOPROCESSORSETSTREAMPALETTE_NULLPARAM", ApiGroup::Video),
+    (3145849, "VIDEOPROCESSORSETSTREAMPALETTE_INVALIDSTREAM", ApiGroup::Video),
+    (3145850, "VIDEOPROCESSORSETSTREAMPALETTE_INVALIDCOUNT", ApiGroup::Video),
+    (3145851, "VIDEOPROCESSORSETSTREAMPALETTE_INVALIDALPHA", ApiGroup::Video),
+    (3145852, "VIDEOPROCESSORSETSTREAMPIXELASPECTRATIO_NULLPARAM", ApiGroup::Video),
+    (3145853, "VIDEOPROCESSORSETSTREAMPIXELASPECTRATIO_INVALIDSTREAM", ApiGroup::Video),
+    (3145854, "VIDEOPROCESSORSETSTREAMPIXELASPECTRATIO_INVALIDRATIO", ApiGroup::Video),
+    (3145855, "VIDEOPROCESSORSETSTREAMLUMAKEY_NULLPARAM", ApiGroup::Video),
+    (3145856, "VIDEOPROCESSORSETSTREAMLUMAKEY_INVALIDSTREAM", ApiGroup::Video),
+    (3145857, "VIDEOPROCESSORSETSTREAMLUMAKEY_INVALIDRANGE", ApiGroup::Video),
+    (3145858, "VIDEOPROCESSORSETSTREAMLUMAKEY_UNSUPPORTED", ApiGroup::Video),
+    (3145859, "VIDEOPROCESSORSETSTREAMSTEREOFORMAT_NULLPARAM", ApiGroup::Video),
+    (3145860, "VIDEOPROCESSORSETSTREAMSTEREOFORMAT_INVALIDSTREAM", ApiGroup::Video),
+    (3145861, "VIDEOPROCESSORSETSTREAMSTEREOFORMAT_UNSUPPORTED", ApiGroup::Video),
+    (3145862, "VIDEOPROCESSORSETSTREAMSTEREOFORMAT_FLIPUNSUPPORTED", ApiGroup::Video),
+    (3145863, "VIDEOPROCESSORSETSTREAMSTEREOFORMAT_MONOOFFSETUNSUPPORTED", ApiGroup::Video),
+    (3145864, "VIDEOPROCESSORSETSTREAMSTEREOFORMAT_FORMATUNSUPPORTED", ApiGroup::Video),
+    (3145865, "VIDEOPROCESSORSETSTREAMSTEREOFORMAT_INVALIDFORMAT", ApiGroup::Video),
+    (3145866, "VIDEOPROCESSORSETSTREAMAUTOPROCESSINGMODE_NULLPARAM", ApiGroup::Video),
+    (3145867, "VIDEOPROCESSORSETSTREAMAUTOPROCESSINGMODE_INVALIDSTREAM", ApiGroup::Video),
+    (3145868, "VIDEOPROCESSORSETSTREAMFILTER_NULLPARAM", ApiGroup::Video),
+    (3145869, "VIDEOPROCESSORSETSTREAMFILTER_INVALIDSTREAM", ApiGroup::Video),
+    (3145870, "VIDEOPROCESSORSETSTREAMFILTER_INVALIDFILTER", ApiGroup::Video),
+    (3145871, "VIDEOPROCESSORSETSTREAMFILTER_UNSUPPORTED", ApiGroup::Video),
+    (3145872, "VIDEOPROCESSORSETSTREAMFILTER_INVALIDLEVEL", ApiGroup::Video),
+    (3145873, "VIDEOPROCESSORSETSTREAMEXTENSION_NULLPARAM", ApiGroup::Video),
+    (3145874, "VIDEOPROCESSORSETSTREAMEXTENSION_INVALIDSTREAM", ApiGroup::Video),
+    (3145875, "VIDEOPROCESSORGETSTREAMFRAMEFORMAT_NULLPARAM", ApiGroup::Video),
+    (3145876, "VIDEOPROCESSORGETSTREAMCOLORSPACE_NULLPARAM", ApiGroup::Video),
+    (3145877, "VIDEOPROCESSORGETSTREAMOUTPUTRATE_NULLPARAM", ApiGroup::Video),
+    (3145878, "VIDEOPROCESSORGETSTREAMSOURCERECT_NULLPARAM", ApiGroup::Video),
+    (3145879, "VIDEOPROCESSORGETSTREAMDESTRECT_NULLPARAM", ApiGroup::Video),
+    (3145880, "VIDEOPROCESSORGETSTREAMALPHA_NULLPARAM", ApiGroup::Video),
+    (3145881, "VIDEOPROCESSORGETSTREAMPALETTE_NULLPARAM", ApiGroup::Video),
+    (3145882, "VIDEOPROCESSORGETSTREAMPIXELASPECTRATIO_NULLPARAM", ApiGroup::Video),
+    (3145883, "VIDEOPROCESSORGETSTREAMLUMAKEY_NULLPARAM", ApiGroup::Video),
+    (3145884, "VIDEOPROCESSORGETSTREAMSTEREOFORMAT_NULLPARAM", ApiGroup::Video),
+    (3145885, "VIDEOPROCESSORGETSTREAMAUTOPROCESSINGMODE_NULLPARAM", ApiGroup::Video),
+    (3145886, "VIDEOPROCESSORGETSTREAMFILTER_NULLPARAM", ApiGroup::Video),
+    (3145887, "VIDEOPROCESSORGETSTREAMEXTENSION_NULLPARAM", ApiGroup::Video),
+    (3145888, "VIDEOPROCESSORGETSTREAMEXTENSION_INVALIDSTREAM", ApiGroup::Video),
+    (3145889, "VIDEOPROCESSORBLT_NULLPARAM", ApiGroup::Video),
+    (3145890, "VIDEOPROCESSORBLT_INVALIDSTREAMCOUNT", ApiGroup::Video),
+    (3145891, "VIDEOPROCESSORBLT_TARGETRECT", ApiGroup::Video),
+    (3145892, "VIDEOPROCESSORBLT_INVALIDOUTPUT", ApiGroup::Video),
+    (3145893, "VIDEOPROCESSORBLT_INVALIDPASTFRAMES", ApiGroup::Video),
+    (3145894, "VIDEOPROCESSORBLT_INVALIDFUTUREFRAMES", ApiGroup::Video),
+    (3145895, "VIDEOPROCESSORBLT_INVALIDSOURCERECT", ApiGroup::Video),
+    (3145896, "VIDEOPROCESSORBLT_INVALIDDESTRECT", ApiGroup::Video),
+    (3145897, "VIDEOPROCESSORBLT_INVALIDINPUTRESOURCE", ApiGroup::Video),
+    (3145898, "VIDEOPROCESSORBLT_INVALIDARRAYSIZE", ApiGroup::Video),
+    (3145899, "VIDEOPROCESSORBLT_INVALIDARRAY", ApiGroup::Video),
+    (3145900, "VIDEOPROCESSORBLT_RIGHTEXPECTED", ApiGroup::Video),
+    (3145901, "VIDEOPROCESSORBLT_RIGHTNOTEXPECTED", ApiGroup::Video),
+    (3145902, "VIDEOPROCESSORBLT_STEREONOTENABLED", ApiGroup::Video),
+    (3145903, "VIDEOPROCESSORBLT_INVALIDRIGHTRESOURCE", ApiGroup::Video),
+    (3145904, "VIDEOPROCESSORBLT_NOSTEREOSTREAMS", ApiGroup::Video),
+    (3145905, "VIDEOPROCESSORBLT_INPUTHAZARD", ApiGroup::Video),
+    (3145906, "VIDEOPROCESSORBLT_OUTPUTHAZARD", ApiGroup::Video),
+    (3145907, "CREATEVIDEODECODEROUTPUTVIEW_OUTOFMEMORY_RETURN", ApiGroup::Video),
+    (3145908, "CREATEVIDEODECODEROUTPUTVIEW_NULLPARAM", ApiGroup::Video),
+    (3145909, "CREATEVIDEODECODEROUTPUTVIEW_INVALIDTYPE", ApiGroup::Video),
+    (3145910, "CREATEVIDEODECODEROUTPUTVIEW_INVALIDBIND", ApiGroup::Video),
+    (3145911, "CREATEVIDEODECODEROUTPUTVIEW_UNSUPPORTEDFORMAT", ApiGroup::Video),
+    (3145912, "CREATEVIDEODECODEROUTPUTVIEW_INVALIDMIP", ApiGroup::Video),
+    (3145913, "CREATEVIDEODECODEROUTPUTVIEW_UNSUPPORTEMIP", ApiGroup::Video),
+    (3145914, "CREATEVIDEODECODEROUTPUTVIEW_INVALIDARRAYSIZE", ApiGroup::Video),
+    (3145915, "CREATEVIDEODECODEROUTPUTVIEW_INVALIDARRAY", ApiGroup::Video),
+    (3145916, "CREATEVIDEODECODEROUTPUTVIEW_INVALIDDIMENSION", ApiGroup::Video),
+    (3145917, "CREATEVIDEOPROCESSORINPUTVIEW_OUTOFMEMORY_RETURN", ApiGroup::Video),
+    (3145918, "CREATEVIDEOPROCESSORINPUTVIEW_NULLPARAM", ApiGroup::Video),
+    (3145919, "CREATEVIDEOPROCESSORINPUTVIEW_INVALIDTYPE", ApiGroup::Video),
+    (3145920, "CREATEVIDEOPROCESSORINPUTVIEW_INVALIDBIND", ApiGroup::Video),
+    (3145921, "CREATEVIDEOPROCESSORINPUTVIEW_INVALIDMISC", ApiGroup::Video),
+    (3145922, "CREATEVIDEOPROCESSORINPUTVIEW_INVALIDUSAGE", ApiGroup::Video),
+    (3145923, "CREATEVIDEOPROCESSORINPUTVIEW_INVALIDFORMAT", ApiGroup::Video),
+    (3145924, "CREATEVIDEOPROCESSORINPUTVIEW_INVALIDFOURCC", ApiGroup::Video),
+    (3145925, "CREATEVIDEOPROCESSORINPUTVIEW_INVALIDMIP", ApiGroup::Video),
+    (3145926, "CREATEVIDEOPROCESSORINPUTVIEW_UNSUPPORTEDMIP", ApiGroup::Video),
+    (3145927, "CREATEVIDEOPROCESSORINPUTVIEW_INVALIDARRAYSIZE", ApiGroup::Video),
+    (3145928, "CREATEVIDEOPROCESSORINPUTVIEW_INVALIDARRAY", ApiGroup::Video),
+    (3145929, "CREATEVIDEOPROCESSORINPUTVIEW_INVALIDDIMENSION", ApiGroup::Video),
+    (3145930, "CREATEVIDEOPROCESSOROUTPUTVIEW_OUTOFMEMORY_RETURN", ApiGroup::Video),
+    (3145931, "CREATEVIDEOPROCESSOROUTPUTVIEW_NULLPARAM", ApiGroup::Video),
+    (3145932, "CREATEVIDEOPROCESSOROUTPUTVIEW_INVALIDTYPE", ApiGroup::Video),
+    (3145933, "CREATEVIDEOPROCESSOROUTPUTVIEW_INVALIDBIND", ApiGroup::Video),
+    (3145934, "CREATEVIDEOPROCESSOROUTPUTVIEW_INVALIDFORMAT", ApiGroup::Video),
+    (3145935, "CREATEVIDEOPROCESSOROUTPUTVIEW_INVALIDMIP", ApiGroup::Video),
+    (3145936, "CREATEVIDEOPROCESSOROUTPUTVIEW_UNSUPPORTEDMIP", ApiGroup::Video),
+    (3145937, "CREATEVIDEOPROCESSOROUTPUTVIEW_UNSUPPORTEDARRAY", ApiGroup::Video),
+    (3145938, "CREATEVIDEOPROCESSOROUTPUTVIEW_INVALIDARRAY", ApiGroup::Video),
+    (3145939, "CREATEVIDEOPROCESSOROUTPUTVIEW_INVALIDDIMENSION", ApiGroup::Video),
+    (3145940, "DEVICE_DRAW_INVALID_USE_OF_FORCED_SAMPLE_COUNT", ApiGroup::Draw),
+    (3145941, "CREATEBLENDSTATE_INVALIDLOGICOPS", ApiGroup::OutputMerger),
+    (3145942, "CREATESHADERRESOURCEVIEW_INVALIDDARRAYWITHDECODER", ApiGroup::Video),
+    (3145943, "CREATEUNORDEREDACCESSVIEW_INVALIDDARRAYWITHDECODER", ApiGroup::Video),
+    (3145944, "CREATERENDERTARGETVIEW_INVALIDDARRAYWITHDECODER", ApiGroup::Video),
+    (3145945, "DEVICE_LOCKEDOUT_INTERFACE", ApiGroup::Other),
+    (3145946, "REF_WARNING_ATOMIC_INCONSISTENT", ApiGroup::Other),
+    (3145947, "REF_WARNING_READING_UNINITIALIZED_RESOURCE", ApiGroup::Other),
+    (3145948, "REF_WARNING_RAW_HAZARD", ApiGroup::Other),
+    (3145949, "REF_WARNING_WAR_HAZARD", ApiGroup::Other),
+    (3145950, "REF_WARNING_WAW_HAZARD", ApiGroup::Other),
+    (3145951, "CREATECRYPTOSESSION_NULLPARAM", ApiGroup::Video),
+    (3145952, "CREATECRYPTOSESSION_OUTOFMEMORY_RETURN", ApiGroup::Video),
+    (3145953, "GETCRYPTOTYPE_NULLPARAM", ApiGroup::Video),
+    (3145954, "GETDECODERPROFILE_NULLPARAM", ApiGroup::Video),
+    (3145955, "GETCRYPTOSESSIONCERTIFICATESIZE_NULLPARAM", ApiGroup::Video),
+    (3145956, "GETCRYPTOSESSIONCERTIFICATE_NULLPARAM", ApiGroup::Video),
+    (3145957, "GETCRYPTOSESSIONCERTIFICATE_WRONGSIZE", ApiGroup::Video),
+    (3145958, "GETCRYPTOSESSIONHANDLE_WRONGSIZE", ApiGroup::Video),
+    (3145959, "NEGOTIATECRPYTOSESSIONKEYEXCHANGE_NULLPARAM", ApiGroup::Video),
+    (3145960, "ENCRYPTIONBLT_UNSUPPORTED", ApiGroup::Video),
+    (3145961, "ENCRYPTIONBLT_NULLPARAM", ApiGroup::Video),
+    (3145962, "ENCRYPTIONBLT_SRC_WRONGDEVICE", ApiGroup::Video),
+    (3145963, "ENCRYPTIONBLT_DST_WRONGDEVICE", ApiGroup::Video),
+    (3145964, "ENCRYPTIONBLT_FORMAT_MISMATCH", ApiGroup::Video),
+    (3145965, "ENCRYPTIONBLT_SIZE_MISMATCH", ApiGroup::Video),
+    (3145966, "ENCRYPTIONBLT_SRC_MULTISAMPLED", ApiGroup::Video),
+    (3145967, "ENCRYPTIONBLT_DST_NOT_STAGING", ApiGroup::Video),
+    (3145968, "ENCRYPTIONBLT_SRC_MAPPED", ApiGroup::Video),
+    (3145969, "ENCRYPTIONBLT_DST_MAPPED", ApiGroup::Video),
+    (3145970, "ENCRYPTIONBLT_SRC_OFFERED", ApiGroup::Video),
+    (3145971, "ENCRYPTIONBLT_DST_OFFERED", ApiGroup::Video),
+    (3145972, "ENCRYPTIONBLT_SRC_CONTENT_UNDEFINED", ApiGroup::Video),
+    (3145973, "DECRYPTIONBLT_UNSUPPORTED", ApiGroup::Video),
+    (3145974, "DECRYPTIONBLT_NULLPARAM", ApiGroup::Video),
+    (3145975, "DECRYPTIONBLT_SRC_WRONGDEVICE", ApiGroup::Video),
+    (3145976, "DECRYPTIONBLT_DST_WRONGDEVICE", ApiGroup::Video),
+    (3145977, "DECRYPTIONBLT_FORMAT_MISMATCH", ApiGroup::Video),
+    (3145978, "DECRYPTIONBLT_SIZE_MISMATCH", ApiGroup::Video),
+    (3145979, "DECRYPTIONBLT_DST_MULTISAMPLED", ApiGroup::Video),
+    (3145980, "DECRYPTIONBLT_SRC_NOT_STAGING", ApiGroup::Video),
+    (3145981, "DECRYPTIONBLT_DST_NOT_RENDER_TARGET", ApiGroup::Video),
+    (3145982, "DECRYPTIONBLT_SRC_MAPPED", ApiGroup::Video),
+    (3145983, "DECRYPTIONBLT_DST_MAPPED", ApiGroup::Video),
+    (3145984, "DECRYPTIONBLT_SRC_OFFERED", ApiGroup::Video),
+    (3145985, "DECRYPTIONBLT_DST_OFFERED", ApiGroup::Video),
+    (3145986, "DECRYPTIONBLT_SRC_CONTENT_UNDEFINED", ApiGroup::Video),
+    (3145987, "STARTSESSIONKEYREFRESH_NULLPARAM", ApiGroup::Video),
+    (3145988, "STARTSESSIONKEYREFRESH_INVALIDSIZE", ApiGroup::Video),
+    (3145989, "FINISHSESSIONKEYREFRESH_NULLPARAM", ApiGroup::Video),
+    (3145990, "GETENCRYPTIONBLTKEY_NULLPARAM", ApiGroup::Video),
+    (3145991, "GETENCRYPTIONBLTKEY_INVALIDSIZE", ApiGroup::Video),
+    (3145992, "GETCONTENTPROTECTIONCAPS_NULLPARAM", ApiGroup::Video),
+    (3145993, "CHECKCRYPTOKEYEXCHANGE_NULLPARAM", ApiGroup::Video),
+    (3145994, "CHECKCRYPTOKEYEXCHANGE_INVALIDINDEX", ApiGroup::Video),
+    (3145995, "CREATEAUTHENTICATEDCHANNEL_NULLPARAM", ApiGroup::Video),
+    (3145996, "CREATEAUTHENTICATEDCHANNEL_UNSUPPORTED", ApiGroup::Video),
+    (3145997, "CREATEAUTHENTICATEDCHANNEL_INVALIDTYPE", ApiGroup::Video),
+    (3145998, "CREATEAUTHENTICATEDCHANNEL_OUTOFMEMORY_RETURN", ApiGroup::Video),
+    (3145999, "GETAUTHENTICATEDCHANNELCERTIFICATESIZE_INVALIDCHANNEL", ApiGroup::Video),
+    (3146000, "GETAUTHENTICATEDCHANNELCERTIFICATESIZE_NULLPARAM", ApiGroup::Video),
+    (3146001, "GETAUTHENTICATEDCHANNELCERTIFICATE_INVALIDCHANNEL", ApiGroup::Video),
+    (3146002, "GETAUTHENTICATEDCHANNELCERTIFICATE_NULLPARAM", ApiGroup::Video),
+    (3146003, "GETAUTHENTICATEDCHANNELCERTIFICATE_WRONGSIZE", ApiGroup::Video),
+    (3146004, "NEGOTIATEAUTHENTICATEDCHANNELKEYEXCHANGE_INVALIDCHANNEL", ApiGroup::Video),
+    (3146005, "NEGOTIATEAUTHENTICATEDCHANNELKEYEXCHANGE_NULLPARAM", ApiGroup::Video),
+    (3146006, "QUERYAUTHENTICATEDCHANNEL_NULLPARAM", ApiGroup::Video),
+    (3146007, "QUERYAUTHENTICATEDCHANNEL_WRONGCHANNEL", ApiGroup::Video),
+    (3146008, "QUERYAUTHENTICATEDCHANNEL_UNSUPPORTEDQUERY", ApiGroup::Video),
+    (3146009, "QUERYAUTHENTICATEDCHANNEL_WRONGSIZE", ApiGroup::Video),
+    (3146010, "QUERYAUTHENTICATEDCHANNEL_INVALIDPROCESSINDEX", ApiGroup::Video),
+    (3146011, "CONFIGUREAUTHENTICATEDCHANNEL_NULLPARAM", ApiGroup::Video),
+    (3146012, "CONFIGUREAUTHENTICATEDCHANNEL_WRONGCHANNEL", ApiGroup::Video),
+    (3146013, "CONFIGUREAUTHENTICATEDCHANNEL_UNSUPPORTEDCONFIGURE", ApiGroup::Video),
+    (3146014, "CONFIGUREAUTHENTICATEDCHANNEL_WRONGSIZE", ApiGroup::Video),
+    (3146015, "CONFIGUREAUTHENTICATEDCHANNEL_INVALIDPROCESSIDTYPE", ApiGroup::Video),
+    (3146016, "VSSETCONSTANTBUFFERS_INVALIDBUFFEROFFSETORCOUNT", ApiGroup::VertexShader),
+    (3146017, "DSSETCONSTANTBUFFERS_INVALIDBUFFEROFFSETORCOUNT", ApiGroup::DomainShader),
+    (3146018, "HSSETCONSTANTBUFFERS_INVALIDBUFFEROFFSETORCOUNT", ApiGroup::HullShader),
+    (3146019, "GSSETCONSTANTBUFFERS_INVALIDBUFFEROFFSETORCOUNT", ApiGroup::GeometryShader),
+    (3146020, "PSSETCONSTANTBUFFERS_INVALIDBUFFEROFFSETORCOUNT", ApiGroup::PixelShader),
+    (3146021, "CSSETCONSTANTBUFFERS_INVALIDBUFFEROFFSETORCOUNT", ApiGroup::ComputeShader),
+    (3146022, "NEGOTIATECRPYTOSESSIONKEYEXCHANGE_INVALIDSIZE", ApiGroup::Video),
+    (3146023, "NEGOTIATEAUTHENTICATEDCHANNELKEYEXCHANGE_INVALIDSIZE", ApiGroup::Video),
+    (3146024, "OFFERRESOURCES_INVALIDPRIORITY", ApiGroup::Other),
+    (3146025, "GETCRYPTOSESSIONHANDLE_OUTOFMEMORY", ApiGroup::Video),
+    (3146026, "ACQUIREHANDLEFORCAPTURE_NULLPARAM", ApiGroup::Other),
+    (3146027, "ACQUIREHANDLEFORCAPTURE_INVALIDTYPE", ApiGroup::Other),
+    (3146028, "ACQUIREHANDLEFORCAPTURE_INVALIDBIND", ApiGroup::Other),
+    (3146029, "ACQUIREHANDLEFORCAPTURE_INVALIDARRAY", ApiGroup::Other),
+    (3146030, "VIDEOPROCESSORSETSTREAMROTATION_NULLPARAM", ApiGroup::Video),
+    (3146031, "VIDEOPROCESSORSETSTREAMROTATION_INVALIDSTREAM", ApiGroup::Video),
+    (3146032, "VIDEOPROCESSORSETSTREAMROTATION_INVALID", ApiGroup::Video),
+    (3146033, "VIDEOPROCESSORSETSTREAMROTATION_UNSUPPORTED", ApiGroup::Video),
+    (3146034, "VIDEOPROCESSORGETSTREAMROTATION_NULLPARAM", ApiGroup::Video),
+    (3146035, "DEVICE_CLEARVIEW_INVALIDVIEW", ApiGroup::Other),
+    (3146036, "DEVICE_CREATEVERTEXSHADER_DOUBLEEXTENSIONSNOTSUPPORTED", ApiGroup::VertexShader),
+    (3146037, "DEVICE_CREATEVERTEXSHADER_SHADEREXTENSIONSNOTSUPPORTED", ApiGroup::VertexShader),
+    (3146038, "DEVICE_CREATEHULLSHADER_DOUBLEEXTENSIONSNOTSUPPORTED", ApiGroup::HullShader),
+    (3146039, "DEVICE_CREATEHULLSHADER_SHADEREXTENSIONSNOTSUPPORTED", ApiGroup::HullShader),
+    (3146040, "DEVICE_CREATEDOMAINSHADER_DOUBLEEXTENSIONSNOTSUPPORTED", ApiGroup::DomainShader),
+    (3146041, "DEVICE_CREATEDOMAINSHADER_SHADEREXTENSIONSNOTSUPPORTED", ApiGroup::DomainShader),
+    (3146042, "DEVICE_CREATEGEOMETRYSHADER_DOUBLEEXTENSIONSNOTSUPPORTED", ApiGroup::GeometryShader),
+    (3146043, "DEVICE_CREATEGEOMETRYSHADER_SHADEREXTENSIONSNOTSUPPORTED", ApiGroup::GeometryShader),
+    (3146044, "DEVICE_CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_DOUBLEEXTENSIONSNOTSUPPORTED", ApiGroup::GeometryShader),
+    (3146045, "DEVICE_CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_SHADEREXTENSIONSNOTSUPPORTED", ApiGroup::GeometryShader),
+    (3146046, "DEVICE_CREATEPIXELSHADER_DOUBLEEXTENSIONSNOTSUPPORTED", ApiGroup::PixelShader),
+    (3146047, "DEVICE_CREATEPIXELSHADER_SHADEREXTENSIONSNOTSUPPORTED", ApiGroup::PixelShader),
+    (3146048, "DEVICE_CREATECOMPUTESHADER_DOUBLEEXTENSIONSNOTSUPPORTED", ApiGroup::ComputeShader),
+    (3146049, "DEVICE_CREATECOMPUTESHADER_SHADEREXTENSIONSNOTSUPPORTED", ApiGroup::ComputeShader),
+    (3146050, "DEVICE_SHADER_LINKAGE_MINPRECISION", ApiGroup::Other),
+    (3146051, "VIDEOPROCESSORSETSTREAMALPHA_UNSUPPORTED", ApiGroup::Video),
+    (3146052, "VIDEOPROCESSORSETSTREAMPIXELASPECTRATIO_UNSUPPORTED", ApiGroup::Video),
+    (3146053, "DEVICE_CREATEVERTEXSHADER_UAVSNOTSUPPORTED", ApiGroup::VertexShader),
+    (3146054, "DEVICE_CREATEHULLSHADER_UAVSNOTSUPPORTED", ApiGroup::HullShader),
+    (3146055, "DEVICE_CREATEDOMAINSHADER_UAVSNOTSUPPORTED", ApiGroup::DomainShader),
+    (3146056, "DEVICE_CREATEGEOMETRYSHADER_UAVSNOTSUPPORTED", ApiGroup::GeometryShader),
+    (3146057, "DEVICE_CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_UAVSNOTSUPPORTED", ApiGroup::GeometryShader),
+    (3146058, "DEVICE_CREATEPIXELSHADER_UAVSNOTSUPPORTED", ApiGroup::PixelShader),
+    (3146059, "DEVICE_CREATECOMPUTESHADER_UAVSNOTSUPPORTED", ApiGroup::ComputeShader),
+    (3146060, "DEVICE_OMSETRENDERTARGETSANDUNORDEREDACCESSVIEWS_INVALIDOFFSET", ApiGroup::OutputMerger),
+    (3146061, "DEVICE_OMSETRENDERTARGETSANDUNORDEREDACCESSVIEWS_TOOMANYVIEWS", ApiGroup::OutputMerger),
+    (3146062, "DEVICE_CLEARVIEW_NOTSUPPORTED", ApiGroup::Other),
+    (3146063, "SWAPDEVICECONTEXTSTATE_NOTSUPPORTED", ApiGroup::Other),
+    (3146064, "UPDATESUBRESOURCE_PREFERUPDATESUBRESOURCE1", ApiGroup::ResourceAccess),
+    (3146065, "GETDC_INACCESSIBLE", ApiGroup::Other),
+    (3146066, "DEVICE_CLEARVIEW_INVALIDRECT", ApiGroup::Other),
+    (3146067, "DEVICE_DRAW_SAMPLE_MASK_IGNORED_ON_FL9", ApiGroup::Draw),
+    (3146068, "DEVICE_OPEN_SHARED_RESOURCE1_NOT_SUPPORTED", ApiGroup::Other),
+    (3146069, "DEVICE_OPEN_SHARED_RESOURCE_BY_NAME_NOT_SUPPORTED", ApiGroup::Other),
+    (3146070, "ENQUEUESETEVENT_NOT_SUPPORTED", ApiGroup::Other),
+    (3146071, "OFFERRELEASE_NOT_SUPPORTED", ApiGroup::Other),
+    (3146072, "OFFERRESOURCES_INACCESSIBLE", ApiGroup::Other),
+    (3146073, "CREATEVIDEOPROCESSORINPUTVIEW_INVALIDMSAA", ApiGroup::Video),
+    (3146074, "CREATEVIDEOPROCESSOROUTPUTVIEW_INVALIDMSAA", ApiGroup::Video),
+    (3146075, "DEVICE_CLEARVIEW_INVALIDSOURCERECT", ApiGroup::Other),
+    (3146076, "DEVICE_CLEARVIEW_EMPTYRECT", ApiGroup::Other),
+    (3146077, "UPDATESUBRESOURCE_EMPTYDESTBOX", ApiGroup::ResourceAccess),
+    (3146078, "COPYSUBRESOURCEREGION_EMPTYSOURCEBOX", ApiGroup::ResourceAccess),
+    (3146079, "DEVICE_DRAW_OM_RENDER_TARGET_DOES_NOT_SUPPORT_LOGIC_OPS", ApiGroup::Draw),
+    (3146080, "DEVICE_DRAW_DEPTHSTENCILVIEW_NOT_SET", ApiGroup::Draw),
+    (3146081, "DEVICE_DRAW_RENDERTARGETVIEW_NOT_SET", ApiGroup::Draw),
+    (3146082, "DEVICE_DRAW_RENDERTARGETVIEW_NOT_SET_DUE_TO_FLIP_PRESENT", ApiGroup::Draw),
+    (3146083, "DEVICE_UNORDEREDACCESSVIEW_NOT_SET_DUE_TO_FLIP_PRESENT", ApiGroup::Other),
+    (3146084, "GETDATAFORNEWHARDWAREKEY_NULLPARAM", ApiGroup::Other),
+    (3146085, "CHECKCRYPTOSESSIONSTATUS_NULLPARAM", ApiGroup::Video),
+    (3146086, "GETCRYPTOSESSIONPRIVATEDATASIZE_NULLPARAM", ApiGroup::Video),
+    (3146087, "GETVIDEODECODERCAPS_NULLPARAM", ApiGroup::Video),
+    (3146088, "GETVIDEODECODERCAPS_ZEROWIDTHHEIGHT", ApiGroup::Video),
+    (3146089, "CHECKVIDEODECODERDOWNSAMPLING_NULLPARAM", ApiGroup::Video),
+    (3146090, "CHECKVIDEODECODERDOWNSAMPLING_INVALIDCOLORSPACE", ApiGroup::Video),
+    (3146091, "CHECKVIDEODECODERDOWNSAMPLING_ZEROWIDTHHEIGHT", ApiGroup::Video),
+    (3146092, "VIDEODECODERENABLEDOWNSAMPLING_NULLPARAM", ApiGroup::Video),
+    (3146093, "VIDEODECODERENABLEDOWNSAMPLING_UNSUPPORTED", ApiGroup::Video),
+    (3146094, "VIDEODECODERUPDATEDOWNSAMPLING_NULLPARAM", ApiGroup::Video),
+    (3146095, "VIDEODECODERUPDATEDOWNSAMPLING_UNSUPPORTED", ApiGroup::Video),
+    (3146096, "CHECKVIDEOPROCESSORFORMATCONVERSION_NULLPARAM", ApiGroup::Video),
+    (3146097, "VIDEOPROCESSORSETOUTPUTCOLORSPACE1_NULLPARAM", ApiGroup::Video),
+    (3146098, "VIDEOPROCESSORGETOUTPUTCOLORSPACE1_NULLPARAM", ApiGroup::Video),
+    (3146099, "VIDEOPROCESSORSETSTREAMCOLORSPACE1_NULLPARAM", ApiGroup::Video),
+    (3146100, "VIDEOPROCESSORSETSTREAMCOLORSPACE1_INVALIDSTREAM", ApiGroup::Video),
+    (3146101, "VIDEOPROCESSORSETSTREAMMIRROR_NULLPARAM", ApiGroup::Video),
+    (3146102, "VIDEOPROCESSORSETSTREAMMIRROR_INVALIDSTREAM", ApiGroup::Video),
+    (3146103, "VIDEOPROCESSORSETSTREAMMIRROR_UNSUPPORTED", ApiGroup::Video),
+    (3146104, "VIDEOPROCESSORGETSTREAMCOLORSPACE1_NULLPARAM", ApiGroup::Video),
+    (3146105, "VIDEOPROCESSORGETSTREAMMIRROR_NULLPARAM", ApiGroup::Video),
+    (3146106, "RECOMMENDVIDEODECODERDOWNSAMPLING_NULLPARAM", ApiGroup::Video),
+    (3146107, "RECOMMENDVIDEODECODERDOWNSAMPLING_INVALIDCOLORSPACE", ApiGroup::Video),
+    (3146108, "RECOMMENDVIDEODECODERDOWNSAMPLING_ZEROWIDTHHEIGHT", ApiGroup::Video),
+    (3146109, "VIDEOPROCESSORSETOUTPUTSHADERUSAGE_NULLPARAM", ApiGroup::Video),
+    (3146110, "VIDEOPROCESSORGETOUTPUTSHADERUSAGE_NULLPARAM", ApiGroup::Video),
+    (3146111, "VIDEOPROCESSORGETBEHAVIORHINTS_NULLPARAM", ApiGroup::Video),
+    (3146112, "VIDEOPROCESSORGETBEHAVIORHINTS_INVALIDSTREAMCOUNT", ApiGroup::Video),
+    (3146113, "VIDEOPROCESSORGETBEHAVIORHINTS_TARGETRECT", ApiGroup::Video),
+    (3146114, "VIDEOPROCESSORGETBEHAVIORHINTS_INVALIDSOURCERECT", ApiGroup::Video),
+    (3146115, "VIDEOPROCESSORGETBEHAVIORHINTS_INVALIDDESTRECT", ApiGroup::Video),
+    (3146116, "GETCRYPTOSESSIONPRIVATEDATASIZE_INVALID_KEY_EXCHANGE_TYPE", ApiGroup::Video),
+    (3146117, "DEVICE_OPEN_SHARED_RESOURCE1_ACCESS_DENIED", ApiGroup::Other),
+    (3146118, "D3D11_1_MESSAGES_END", ApiGroup::Other),
+    (3146119, "D3D11_2_MESSAGES_START", ApiGroup::Other),
+    (3146120, "CREATEBUFFER_INVALIDUSAGE", ApiGroup::ResourceCreation),
+    (3146121, "CREATETEXTURE1D_INVALIDUSAGE", ApiGroup::ResourceCreation),
+    (3146122, "CREATETEXTURE2D_INVALIDUSAGE", ApiGroup::ResourceCreation),
+    (3146123, "CREATEINPUTLAYOUT_LEVEL9_STEPRATE_NOT_1", ApiGroup::InputAssembler),
+    (3146124, "CREATEINPUTLAYOUT_LEVEL9_INSTANCING_NOT_SUPPORTED", ApiGroup::InputAssembler),
+    (3146125, "UPDATETILEMAPPINGS_INVALID_PARAMETER", ApiGroup::ResourceAccess),
+    (3146126, "COPYTILEMAPPINGS_INVALID_PARAMETER", ApiGroup::ResourceAccess),
+    (3146127, "COPYTILES_INVALID_PARAMETER", ApiGroup::ResourceAccess),
+    (3146128, "UPDATETILES_INVALID_PARAMETER", ApiGroup::ResourceAccess),
+    (3146129, "RESIZETILEPOOL_INVALID_PARAMETER", ApiGroup::Other),
+    (3146130, "TILEDRESOURCEBARRIER_INVALID_PARAMETER", ApiGroup::Other),
+    (3146131, "NULL_TILE_MAPPING_ACCESS_WARNING", ApiGroup::Other),
+    (3146132, "NULL_TILE_MAPPING_ACCESS_ERROR", ApiGroup::Other),
+    (3146133, "DIRTY_TILE_MAPPING_ACCESS", ApiGroup::Other),
+    (3146134, "DUPLICATE_TILE_MAPPINGS_IN_COVERED_AREA", ApiGroup::Other),
+    (3146135, "TILE_MAPPINGS_IN_COVERED_AREA_DUPLICATED_OUTSIDE", ApiGroup::Other),
+    (3146136, "TILE_MAPPINGS_SHARED_BETWEEN_INCOMPATIBLE_RESOURCES", ApiGroup::Other),
+    (3146137, "TILE_MAPPINGS_SHARED_BETWEEN_INPUT_AND_OUTPUT", ApiGroup::Other),
+    (3146138, "CHECKMULTISAMPLEQUALITYLEVELS_INVALIDFLAGS", ApiGroup::Other),
+    (3146139, "GETRESOURCETILING_NONTILED_RESOURCE", ApiGroup::Other),
+    (3146140, "RESIZETILEPOOL_SHRINK_WITH_MAPPINGS_STILL_DEFINED_PAST_END", ApiGroup::Other),
+    (3146141, "NEED_TO_CALL_TILEDRESOURCEBARRIER", ApiGroup::Other),
+    (3146142, "CREATEDEVICE_INVALIDARGS", ApiGroup::Other),
+    (3146143, "CREATEDEVICE_WARNING", ApiGroup::Other),
+    (3146144, "CLEARUNORDEREDACCESSVIEWUINT_HAZARD", ApiGroup::ComputeShader),
+    (3146145, "CLEARUNORDEREDACCESSVIEWFLOAT_HAZARD", ApiGroup::ComputeShader),
+    (3146146, "TILED_RESOURCE_TIER_1_BUFFER_TEXTURE_MISMATCH", ApiGroup::Other),
+    (3146147, "CREATE_CRYPTOSESSION", ApiGroup::ObjectLifetime),
+    (3146148, "CREATE_AUTHENTICATEDCHANNEL", ApiGroup::ObjectLifetime),
+    (3146149, "LIVE_CRYPTOSESSION", ApiGroup::ObjectLifetime),
+    (3146150, "LIVE_AUTHENTICATEDCHANNEL", ApiGroup::ObjectLifetime),
+    (3146151, "DESTROY_CRYPTOSESSION", ApiGroup::ObjectLifetime),
+    (3146152, "DESTROY_AUTHENTICATEDCHANNEL", ApiGroup::ObjectLifetime),
+    (3146153, "D3D11_2_MESSAGES_END", ApiGroup::Other),
+    (3146154, "D3D11_3_MESSAGES_START", ApiGroup::Other),
+    (3146155, "CREATERASTERIZERSTATE_INVALID_CONSERVATIVERASTERMODE", ApiGroup::Rasterizer),
+    (3146156, "DEVICE_DRAW_INVALID_SYSTEMVALUE", ApiGroup::Draw),
+    (3146157, "CREATEQUERYORPREDICATE_INVALIDCONTEXTTYPE", ApiGroup::Other),
+    (3146158, "CREATEQUERYORPREDICATE_DECODENOTSUPPORTED", ApiGroup::Other),
+    (3146159, "CREATEQUERYORPREDICATE_ENCODENOTSUPPORTED", ApiGroup::Other),
+    (3146160, "CREATESHADERRESOURCEVIEW_INVALIDPLANEINDEX", ApiGroup::ResourceCreation),
+    (3146161, "CREATESHADERRESOURCEVIEW_INVALIDVIDEOPLANEINDEX", ApiGroup::Video),
+    (3146162, "CREATESHADERRESOURCEVIEW_AMBIGUOUSVIDEOPLANEINDEX", ApiGroup::Video),
+    (3146163, "CREATERENDERTARGETVIEW_INVALIDPLANEINDEX", ApiGroup::ResourceCreation),
+    (3146164, "CREATERENDERTARGETVIEW_INVALIDVIDEOPLANEINDEX", ApiGroup::Video),
+    (3146165, "CREATERENDERTARGETVIEW_AMBIGUOUSVIDEOPLANEINDEX", ApiGroup::Video),
+    (3146166, "CREATEUNORDEREDACCESSVIEW_INVALIDPLANEINDEX", ApiGroup::ResourceCreation),
+    (3146167, "CREATEUNORDEREDACCESSVIEW_INVALIDVIDEOPLANEINDEX", ApiGroup::Video),
+    (3146168, "CREATEUNORDEREDACCESSVIEW_AMBIGUOUSVIDEOPLANEINDEX", ApiGroup::Video),
+    (3146169, "JPEGDECODE_INVALIDSCANDATAOFFSET", ApiGroup::Video),
+    (3146170, "JPEGDECODE_NOTSUPPORTED", ApiGroup::Video),
+    (3146171, "JPEGDECODE_DIMENSIONSTOOLARGE", ApiGroup::Video),
+    (3146172, "JPEGDECODE_INVALIDCOMPONENTS", ApiGroup::Video),
+    (3146173, "JPEGDECODE_DESTINATIONNOT2D", ApiGroup::Video),
+    (3146174, "JPEGDECODE_TILEDRESOURCESUNSUPPORTED", ApiGroup::Video),
+    (3146175, "JPEGDECODE_GUARDRECTSUNSUPPORTED", ApiGroup::Video),
+    (3146176, "JPEGDECODE_FORMATUNSUPPORTED", ApiGroup::Video),
+    (3146177, "JPEGDECODE_INVALIDSUBRESOURCE", ApiGroup::Video),
+    (3146178, "JPEGDECODE_INVALIDMIPLEVEL", ApiGroup::Video),
+    (3146179, "JPEGDECODE_EMPTYDESTBOX", ApiGroup::Video),
+    (3146180, "JPEGDECODE_DESTBOXNOT2D", ApiGroup::Video),
+    (3146181, "JPEGDECODE_DESTBOXNOTSUB", ApiGroup::Video),
+    (3146182, "JPEGDECODE_DESTBOXESINTERSECT", ApiGroup::Video),
+    (3146183, "JPEGDECODE_XSUBSAMPLEMISMATCH", ApiGroup::Video),
+    (3146184, "JPEGDECODE_YSUBSAMPLEMISMATCH", ApiGroup::Video),
+    (3146185, "JPEGDECODE_XSUBSAMPLEODD", ApiGroup::Video),
+    (3146186, "JPEGDECODE_YSUBSAMPLEODD", ApiGroup::Video),
+    (3146187, "JPEGDECODE_OUTPUTDIMENSIONSTOOLARGE", ApiGroup::Video),
+    (3146188, "JPEGDECODE_NONPOW2SCALEUNSUPPORTED", ApiGroup::Video),
+    (3146189, "JPEGDECODE_FRACTIONALDOWNSCALETOLARGE", ApiGroup::Video),
+    (3146190, "JPEGDECODE_CHROMASIZEMISMATCH", ApiGroup::Video),
+    (3146191, "JPEGDECODE_LUMACHROMASIZEMISMATCH", ApiGroup::Video),
+    (3146192, "JPEGDECODE_INVALIDNUMDESTINATIONS", ApiGroup::Video),
+    (3146193, "JPEGDECODE_SUBBOXUNSUPPORTED", ApiGroup::Video),
+    (3146194, "JPEGDECODE_1DESTUNSUPPORTEDFORMAT", ApiGroup::Video),
+    (3146195, "JPEGDECODE_3DESTUNSUPPORTEDFORMAT", ApiGroup::Video),
+    (3146196, "JPEGDECODE_SCALEUNSUPPORTED", ApiGroup::Video),
+    (3146197, "JPEGDECODE_INVALIDSOURCESIZE", ApiGroup::Video),
+    (3146198, "JPEGDECODE_INVALIDCOPYFLAGS", ApiGroup::Video),
+    (3146199, "JPEGDECODE_HAZARD", ApiGroup::Video),
+    (3146200, "JPEGDECODE_UNSUPPORTEDSRCBUFFERUSAGE", ApiGroup::Video),
+    (3146201, "JPEGDECODE_UNSUPPORTEDSRCBUFFERMISCFLAGS", ApiGroup::Video),
+    (3146202, "JPEGDECODE_UNSUPPORTEDDSTTEXTUREUSAGE", ApiGroup::Video),
+    (3146203, "JPEGDECODE_BACKBUFFERNOTSUPPORTED", ApiGroup::Video),
+    (3146204, "JPEGDECODE_UNSUPPRTEDCOPYFLAGS", ApiGroup::Video),
+    (3146205, "JPEGENCODE_NOTSUPPORTED", ApiGroup::Video),
+    (3146206, "JPEGENCODE_INVALIDSCANDATAOFFSET", ApiGroup::Video),
+    (3146207, "JPEGENCODE_INVALIDCOMPONENTS", ApiGroup::Video),
+    (3146208, "JPEGENCODE_SOURCENOT2D", ApiGroup::Video),
+    (3146209, "JPEGENCODE_TILEDRESOURCESUNSUPPORTED", ApiGroup::Video),
+    (3146210, "JPEGENCODE_GUARDRECTSUNSUPPORTED", ApiGroup::Video),
+    (3146211, "JPEGENCODE_XSUBSAMPLEMISMATCH", ApiGroup::Video),
+    (3146212, "JPEGENCODE_YSUBSAMPLEMISMATCH", ApiGroup::Video),
+    (3146213, "JPEGENCODE_FORMATUNSUPPORTED", ApiGroup::Video),
+    (3146214, "JPEGENCODE_INVALIDSUBRESOURCE", ApiGroup::Video),
+    (3146215, "JPEGENCODE_INVALIDMIPLEVEL", ApiGroup::Video),
+    (3146216, "JPEGENCODE_DIMENSIONSTOOLARGE", ApiGroup::Video),
+    (3146217, "JPEGENCODE_HAZARD", ApiGroup::Video),
+    (3146218, "JPEGENCODE_UNSUPPORTEDDSTBUFFERUSAGE", ApiGroup::Video),
+    (3146219, "JPEGENCODE_UNSUPPORTEDDSTBUFFERMISCFLAGS", ApiGroup::Video),
+    (3146220, "JPEGENCODE_UNSUPPORTEDSRCTEXTUREUSAGE", ApiGroup::Video),
+    (3146221, "JPEGENCODE_BACKBUFFERNOTSUPPORTED", ApiGroup::Video),
+    (3146222, "CREATEQUERYORPREDICATE_UNSUPPORTEDCONTEXTTTYPEFORQUERY", ApiGroup::Other),
+    (3146223, "FLUSH1_INVALIDCONTEXTTYPE", ApiGroup::Other),
+    (3146224, "DEVICE_SETHARDWAREPROTECTION_INVALIDCONTEXT", ApiGroup::Other),
+    (3146225, "VIDEOPROCESSORSETOUTPUTHDRMETADATA_NULLPARAM", ApiGroup::Video),
+    (3146226, "VIDEOPROCESSORSETOUTPUTHDRMETADATA_INVALIDSIZE", ApiGroup::Video),
+    (3146227, "VIDEOPROCESSORGETOUTPUTHDRMETADATA_NULLPARAM", ApiGroup::Video),
+    (3146228, "VIDEOPROCESSORGETOUTPUTHDRMETADATA_INVALIDSIZE", ApiGroup::Video),
+    (3146229, "VIDEOPROCESSORSETSTREAMHDRMETADATA_NULLPARAM", ApiGroup::Video),
+    (3146230, "VIDEOPROCESSORSETSTREAMHDRMETADATA_INVALIDSTREAM", ApiGroup::Video),
+    (3146231, "VIDEOPROCESSORSETSTREAMHDRMETADATA_INVALIDSIZE", ApiGroup::Video),
+    (3146232, "VIDEOPROCESSORGETSTREAMHDRMETADATA_NULLPARAM", ApiGroup::Video),
+    (3146233, "VIDEOPROCESSORGETSTREAMHDRMETADATA_INVALIDSTREAM", ApiGroup::Video),
+    (3146234, "VIDEOPROCESSORGETSTREAMHDRMETADATA_INVALIDSIZE", ApiGroup::Video),
+    (3146235, "VIDEOPROCESSORGETSTREAMFRAMEFORMAT_INVALIDSTREAM", ApiGroup::Video),
+    (3146236, "VIDEOPROCESSORGETSTREAMCOLORSPACE_INVALIDSTREAM", ApiGroup::Video),
+    (3146237, "VIDEOPROCESSORGETSTREAMOUTPUTRATE_INVALIDSTREAM", ApiGroup::Video),
+    (3146238, "VIDEOPROCESSORGETSTREAMSOURCERECT_INVALIDSTREAM", ApiGroup::Video),
+    (3146239, "VIDEOPROCESSORGETSTREAMDESTRECT_INVALIDSTREAM", ApiGroup::Video),
+    (3146240, "VIDEOPROCESSORGETSTREAMALPHA_INVALIDSTREAM", ApiGroup::Video),
+    (3146241, "VIDEOPROCESSORGETSTREAMPALETTE_INVALIDSTREAM", ApiGroup::Video),
+    (3146242, "VIDEOPROCESSORGETSTREAMPIXELASPECTRATIO_INVALIDSTREAM", ApiGroup::Video),
+    (3146243, "VIDEOPROCESSORGETSTREAMLUMAKEY_INVALIDSTREAM", ApiGroup::Video),
+    (3146244, "VIDEOPROCESSORGETSTREAMSTEREOFORMAT_INVALIDSTREAM", ApiGroup::Video),
+    (3146245, "VIDEOPROCESSORGETSTREAMAUTOPROCESSINGMODE_INVALIDSTREAM", ApiGroup::Video),
+    (3146246, "VIDEOPROCESSORGETSTREAMFILTER_INVALIDSTREAM", ApiGroup::Video),
+    (3146247, "VIDEOPROCESSORGETSTREAMROTATION_INVALIDSTREAM", ApiGroup::Video),
+    (3146248, "VIDEOPROCESSORGETSTREAMCOLORSPACE1_INVALIDSTREAM", ApiGroup::Video),
+    (3146249, "VIDEOPROCESSORGETSTREAMMIRROR_INVALIDSTREAM", ApiGroup::Video),
+    (3146250, "CREATE_FENCE", ApiGroup::ObjectLifetime),
+    (3146251, "LIVE_FENCE", ApiGroup::ObjectLifetime),
+    (3146252, "DESTROY_FENCE", ApiGroup::ObjectLifetime),
+    (3146253, "CREATE_SYNCHRONIZEDCHANNEL", ApiGroup::ObjectLifetime),
+    (3146254, "LIVE_SYNCHRONIZEDCHANNEL", ApiGroup::ObjectLifetime),
+    (3146255, "DESTROY_SYNCHRONIZEDCHANNEL", ApiGroup::ObjectLifetime),
+    (3146256, "CREATEFENCE_INVALIDFLAGS", ApiGroup::Other),
+    (3146257, "D3D11_3_MESSAGES_END", ApiGroup::Other),
+    (3146258, "D3D11_5_MESSAGES_START", ApiGroup::Other),
+    (3146259, "NEGOTIATECRYPTOSESSIONKEYEXCHANGEMT_INVALIDKEYEXCHANGETYPE", ApiGroup::Video),
+    (3146260, "NEGOTIATECRYPTOSESSIONKEYEXCHANGEMT_NOT_SUPPORTED", ApiGroup::Video),
+    (3146261, "DECODERBEGINFRAME_INVALID_HISTOGRAM_COMPONENT_COUNT", ApiGroup::Video),
+    (3146262, "DECODERBEGINFRAME_INVALID_HISTOGRAM_COMPONENT", ApiGroup::Video),
+    (3146263, "DECODERBEGINFRAME_INVALID_HISTOGRAM_BUFFER_SIZE", ApiGroup::Video),
+    (3146264, "DECODERBEGINFRAME_INVALID_HISTOGRAM_BUFFER_USAGE", ApiGroup::Video),
+    (3146265, "DECODERBEGINFRAME_INVALID_HISTOGRAM_BUFFER_MISC_FLAGS", ApiGroup::Video),
+    (3146266, "DECODERBEGINFRAME_INVALID_HISTOGRAM_BUFFER_OFFSET", ApiGroup::Video),
+    (3146267, "CREATE_TRACKEDWORKLOAD", ApiGroup::ObjectLifetime),
+    (3146268, "LIVE_TRACKEDWORKLOAD", ApiGroup::ObjectLifetime),
+    (3146269, "DESTROY_TRACKEDWORKLOAD", ApiGroup::ObjectLifetime),
+    (3146270, "CREATE_TRACKED_WORKLOAD_NULLPARAM", ApiGroup::ObjectLifetime),
+    (3146271, "CREATE_TRACKED_WORKLOAD_INVALID_MAX_INSTANCES", ApiGroup::ObjectLifetime),
+    (3146272, "CREATE_TRACKED_WORKLOAD_INVALID_DEADLINE_TYPE", ApiGroup::ObjectLifetime),
+    (3146273, "CREATE_TRACKED_WORKLOAD_INVALID_ENGINE_TYPE", ApiGroup::ObjectLifetime),
+    (3146274, "MULTIPLE_TRACKED_WORKLOADS", ApiGroup::Other),
+    (3146275, "MULTIPLE_TRACKED_WORKLOAD_PAIRS", ApiGroup::Other),
+    (3146276, "INCOMPLETE_TRACKED_WORKLOAD_PAIR", ApiGroup::Other),
+    (3146277, "OUT_OF_ORDER_TRACKED_WORKLOAD_PAIR", ApiGroup::Other),
+    (3146278, "CANNOT_ADD_TRACKED_WORKLOAD", ApiGroup::Other),
+    (3146279, "TRACKED_WORKLOAD_NOT_SUPPORTED", ApiGroup::Other),
+    (3146280, "TRACKED_WORKLOAD_ENGINE_TYPE_NOT_FOUND", ApiGroup::Other),
+    (3146281, "NO_TRACKED_WORKLOAD_SLOT_AVAILABLE", ApiGroup::Other),
+    (3146282, "END_TRACKED_WORKLOAD_INVALID_ARG", ApiGroup::Other),
+    (3146283, "TRACKED_WORKLOAD_DISJOINT_FAILURE", ApiGroup::Other),
+    (3146284, "D3D11_5_MESSAGES_END", ApiGroup::Other),
+];
+
+/// The same ids as [`MESSAGE_IDS`] sorted on name.
+#[rustfmt::skip]
+static MESSAGE_IDS_BY_NAME: [(&str, i32); MESSAGE_ID_COUNT] = [
+    ("ACQUIREHANDLEFORCAPTURE_INVALIDARRAY", 3146029),
+    ("ACQUIREHANDLEFORCAPTURE_INVALIDBIND", 3146028),
+    ("ACQUIREHANDLEFORCAPTURE_INVALIDTYPE", 3146027),
+    ("ACQUIREHANDLEFORCAPTURE_NULLPARAM", 3146026),
+    ("BLENDSTATE_GETDESC_LEGACY", 392),
+    ("BUFFER_MAP_ALREADYMAPPED", 297),
+    ("BUFFER_MAP_DEVICEREMOVED_RETURN", 298),
+    ("BUFFER_MAP_INVALIDFLAGS", 296),
+    ("BUFFER_MAP_INVALIDMAPTYPE", 295),
+    ("BUFFER_UNMAP_NOTMAPPED", 299),
+    ("CANNOT_ADD_TRACKED_WORKLOAD", 3146278),
+    ("CHECKCOUNTER_OUTOFRANGE_COUNTER", 402),
+    ("CHECKCOUNTER_UNSUPPORTED_WELLKNOWN_COUNTER", 403),
+    ("CHECKCRYPTOKEYEXCHANGE_INVALIDINDEX", 3145994),
+    ("CHECKCRYPTOKEYEXCHANGE_NULLPARAM", 3145993),
+    ("CHECKCRYPTOSESSIONSTATUS_NULLPARAM", 3146085),
+    ("CHECKFEATURESUPPORT_FORMAT_DEPRECATED", 2097371),
+    ("CHECKFORMATSUPPORT_FORMAT_DEPRECATED", 321),
+    ("CHECKFORMATSUPPORT_FORMAT_NOT_SUPPORTED", 2097401),
+    ("CHECKMULTISAMPLEQUALITYLEVELS_FORMAT_DEPRECATED", 322),
+    ("CHECKMULTISAMPLEQUALITYLEVELS_INVALIDFLAGS", 3146138),
+    ("CHECKVIDEODECODERDOWNSAMPLING_INVALIDCOLORSPACE", 3146090),
+    ("CHECKVIDEODECODERDOWNSAMPLING_NULLPARAM", 3146089),
+    ("CHECKVIDEODECODERDOWNSAMPLING_ZEROWIDTHHEIGHT", 3146091),
+    ("CHECKVIDEODECODERFORMAT_NULLPARAM", 3145768),
+    ("CHECKVIDEODECODERFORMAT_OUTOFMEMORY_RETURN", 3145769),
+    ("CHECKVIDEOPROCESSORFORMATCONVERSION_NULLPARAM", 3146096),
+    ("CHECKVIDEOPROCESSORFORMAT_NULLPARAM", 3145799),
+    ("CLEARDEPTHSTENCILVIEW_DENORMFLUSH", 262),
+    ("CLEARDEPTHSTENCILVIEW_DEPTH_READONLY", 2097369),
+    ("CLEARDEPTHSTENCILVIEW_INVALID", 263),
+    ("CLEARDEPTHSTENCILVIEW_STENCIL_READONLY", 2097370),
+    ("CLEARRENDERTARGETVIEW_DENORMFLUSH", 261),
+    ("CLEARUNORDEREDACCESSVIEWFLOAT_HAZARD", 3146145),
+    ("CLEARUNORDEREDACCESSVIEWFLOAT_INVALIDFORMAT", 2097405),
+    ("CLEARUNORDEREDACCESSVIEWUINT_HAZARD", 3146144),
+    ("CLEARUNORDEREDACCESSVIEW_DENORMFLUSH", 2097355),
+    ("CONFIGUREAUTHENTICATEDCHANNEL_INVALIDPROCESSIDTYPE", 3146015),
+    ("CONFIGUREAUTHENTICATEDCHANNEL_NULLPARAM", 3146011),
+    ("CONFIGUREAUTHENTICATEDCHANNEL_UNSUPPORTEDCONFIGURE", 3146013),
+    ("CONFIGUREAUTHENTICATEDCHANNEL_WRONGCHANNEL", 3146012),
+    ("CONFIGUREAUTHENTICATEDCHANNEL_WRONGSIZE", 3146014),
+    ("COPYRESOURCE_INVALIDDESTINATIONSTATE", 285),
+    ("COPYRESOURCE_INVALIDSOURCE", 284),
+    ("COPYRESOURCE_INVALIDSOURCESTATE", 286),
+    ("COPYRESOURCE_NO_3D_MISMATCHED_UPDATES", 1048637),
+    ("COPYRESOURCE_NO_TEXTURE_3D_READBACK", 1048597),
+    ("COPYRESOURCE_NO_TEXTURE_ONLY_READBACK", 1048598),
+    ("COPYRESOURCE_ONLY_TEXTURE_2D_WITHIN_GPU_MEMORY", 1048596),
+    ("COPYSTRUCTURECOUNT_INVALIDDESTINATIONSTATE", 2097399),
+    ("COPYSTRUCTURECOUNT_INVALIDOFFSET", 2097397),
+    ("COPYSTRUCTURECOUNT_INVALIDSOURCESTATE", 2097400),
+    ("COPYSTRUCTURECOUNT_LARGEOFFSET", 2097398),
+    ("COPYSUBRESOURCEREGION1_INVALIDCOPYFLAGS", 3145755),
+    ("COPYSUBRESOURCEREGION_EMPTYSOURCEBOX", 3146078),
+    ("COPYSUBRESOURCEREGION_INVALIDDESTINATIONSTATE", 282),
+    ("COPYSUBRESOURCEREGION_INVALIDDESTINATIONSUBRESOURCE", 278),
+    ("COPYSUBRESOURCEREGION_INVALIDSOURCE", 281),
+    ("COPYSUBRESOURCEREGION_INVALIDSOURCEBOX", 280),
+    ("COPYSUBRESOURCEREGION_INVALIDSOURCESTATE", 283),
+    ("COPYSUBRESOURCEREGION_INVALIDSOURCESUBRESOURCE", 279),
+    ("COPYTILEMAPPINGS_INVALID_PARAMETER", 3146126),
+    ("COPYTILES_INVALID_PARAMETER", 3146127),
+    ("CORRUPTED_MULTITHREADING", 28),
+    ("CORRUPTED_PARAMETER1", 13),
+    ("CORRUPTED_PARAMETER10", 22),
+    ("CORRUPTED_PARAMETER11", 23),
+    ("CORRUPTED_PARAMETER12", 24),
+    ("CORRUPTED_PARAMETER13", 25),
+    ("CORRUPTED_PARAMETER14", 26),
+    ("CORRUPTED_PARAMETER15", 27),
+    ("CORRUPTED_PARAMETER2", 14),
+    ("CORRUPTED_PARAMETER3", 15),
+    ("CORRUPTED_PARAMETER4", 16),
+    ("CORRUPTED_PARAMETER5", 17),
+    ("CORRUPTED_PARAMETER6", 18),
+    ("CORRUPTED_PARAMETER7", 19),
+    ("CORRUPTED_PARAMETER8", 20),
+    ("CORRUPTED_PARAMETER9", 21),
+    ("CORRUPTED_THIS", 12),
+    ("CREATEAUTHENTICATEDCHANNEL_INVALIDTYPE", 3145997),
+    ("CREATEAUTHENTICATEDCHANNEL_NULLPARAM", 3145995),
+    ("CREATEAUTHENTICATEDCHANNEL_OUTOFMEMORY_RETURN", 3145998),
+    ("CREATEAUTHENTICATEDCHANNEL_UNSUPPORTED", 3145996),
+    ("CREATEBLENDSTATE_INVALIDBLENDOP", 214),
+    ("CREATEBLENDSTATE_INVALIDBLENDOPALPHA", 217),
+    ("CREATEBLENDSTATE_INVALIDDESTBLEND", 213),
+    ("CREATEBLENDSTATE_INVALIDDESTBLENDALPHA", 216),
+    ("CREATEBLENDSTATE_INVALIDLOGICOPS", 3145941),
+    ("CREATEBLENDSTATE_INVALIDRENDERTARGETWRITEMASK", 218),
+    ("CREATEBLENDSTATE_INVALIDSRCBLEND", 212),
+    ("CREATEBLENDSTATE_INVALIDSRCBLENDALPHA", 215),
+    ("CREATEBLENDSTATE_NO_ALPHA_TO_COVERAGE", 1048600),
+    ("CREATEBLENDSTATE_NO_INDEPENDENT_BLEND_ENABLE", 1048612),
+    ("CREATEBLENDSTATE_NO_INDEPENDENT_WRITE_MASKS", 1048613),
+    ("CREATEBLENDSTATE_NO_MRT_BLEND", 1048623),
+    ("CREATEBLENDSTATE_NO_SEPARATE_ALPHA_BLEND", 1048622),
+    ("CREATEBLENDSTATE_NULLDESC", 220),
+    ("CREATEBLENDSTATE_OPERATION_NOT_SUPPORTED", 1048624),
+    ("CREATEBLENDSTATE_TOOMANYOBJECTS", 219),
+    ("CREATEBUFFER_INVALIDARG_RETURN", 69),
+    ("CREATEBUFFER_INVALIDBINDFLAGS", 64),
+    ("CREATEBUFFER_INVALIDCONSTANTBUFFERBINDINGS", 72),
+    ("CREATEBUFFER_INVALIDCPUACCESSFLAGS", 63),
+    ("CREATEBUFFER_INVALIDDIMENSIONS", 66),
+    ("CREATEBUFFER_INVALIDINITIALDATA", 65),
+    ("CREATEBUFFER_INVALIDMIPLEVELS", 67),
+    ("CREATEBUFFER_INVALIDMISCFLAGS", 68),
+    ("CREATEBUFFER_INVALIDSAMPLES", 58),
+    ("CREATEBUFFER_INVALIDSTRUCTURESTRIDE", 2097339),
+    ("CREATEBUFFER_INVALIDUSAGE", 3146120),
+    ("CREATEBUFFER_LARGEALLOCATION", 73),
+    ("CREATEBUFFER_NULLDESC", 71),
+    ("CREATEBUFFER_OUTOFMEMORY_RETURN", 70),
+    ("CREATEBUFFER_UNRECOGNIZEDBINDFLAGS", 60),
+    ("CREATEBUFFER_UNRECOGNIZEDCPUACCESSFLAGS", 61),
+    ("CREATEBUFFER_UNRECOGNIZEDFORMAT", 57),
+    ("CREATEBUFFER_UNRECOGNIZEDMISCFLAGS", 62),
+    ("CREATEBUFFER_UNRECOGNIZEDUSAGE", 59),
+    ("CREATECOMPUTESHADER_INVALIDCALL", 2097320),
+    ("CREATECOMPUTESHADER_INVALIDCLASSLINKAGE", 2097324),
+    ("CREATECOMPUTESHADER_INVALIDSHADERBYTECODE", 2097322),
+    ("CREATECOMPUTESHADER_INVALIDSHADERTYPE", 2097323),
+    ("CREATECOMPUTESHADER_OUTOFMEMORY", 2097321),
+    ("CREATECOUNTER_NONEXCLUSIVE_RETURN", 400),
+    ("CREATECOUNTER_NULLDESC", 401),
+    ("CREATECOUNTER_OUTOFMEMORY_RETURN", 399),
+    ("CREATECOUNTER_OUTOFRANGE_COUNTER", 396),
+    ("CREATECOUNTER_SIMULTANEOUS_ACTIVE_COUNTERS_EXHAUSTED", 397),
+    ("CREATECOUNTER_UNSUPPORTED_WELLKNOWN_COUNTER", 398),
+    ("CREATECRYPTOSESSION_NULLPARAM", 3145951),
+    ("CREATECRYPTOSESSION_OUTOFMEMORY_RETURN", 3145952),
+    ("CREATEDEFERREDCONTEXT_INVALIDARG_RETURN", 2097163),
+    ("CREATEDEFERREDCONTEXT_INVALID_CALL_RETURN", 2097164),
+    ("CREATEDEFERREDCONTEXT_INVALID_COMMANDLISTFLAGS", 2097161),
+    ("CREATEDEFERREDCONTEXT_OUTOFMEMORY_RETURN", 2097165),
+    ("CREATEDEFERREDCONTEXT_SINGLETHREADED", 2097162),
+    ("CREATEDEPTHSTENCILSTATE_INVALIDBACKFACESTENCILFAILOP", 206),
+    ("CREATEDEPTHSTENCILSTATE_INVALIDBACKFACESTENCILFUNC", 209),
+    ("CREATEDEPTHSTENCILSTATE_INVALIDBACKFACESTENCILPASSOP", 208),
+    ("CREATEDEPTHSTENCILSTATE_INVALIDBACKFACESTENCILZFAILOP", 207),
+    ("CREATEDEPTHSTENCILSTATE_INVALIDDEPTHFUNC", 201),
+    ("CREATEDEPTHSTENCILSTATE_INVALIDDEPTHWRITEMASK", 200),
+    ("CREATEDEPTHSTENCILSTATE_INVALIDFRONTFACESTENCILFAILOP", 202),
+    ("CREATEDEPTHSTENCILSTATE_INVALIDFRONTFACESTENCILFUNC", 205),
+    ("CREATEDEPTHSTENCILSTATE_INVALIDFRONTFACESTENCILPASSOP", 204),
+    ("CREATEDEPTHSTENCILSTATE_INVALIDFRONTFACESTENCILZFAILOP", 203),
+    ("CREATEDEPTHSTENCILSTATE_NULLDESC", 211),
+    ("CREATEDEPTHSTENCILSTATE_STENCIL_NO_TWO_SIDED", 1048577),
+    ("CREATEDEPTHSTENCILSTATE_TOOMANYOBJECTS", 210),
+    ("CREATEDEPTHSTENCILVIEW_INVALIDARG_RETURN", 148),
+    ("CREATEDEPTHSTENCILVIEW_INVALIDDESC", 143),
+    ("CREATEDEPTHSTENCILVIEW_INVALIDDIMENSIONS", 145),
+    ("CREATEDEPTHSTENCILVIEW_INVALIDFLAGS", 2097153),
+    ("CREATEDEPTHSTENCILVIEW_INVALIDFORMAT", 144),
+    ("CREATEDEPTHSTENCILVIEW_INVALIDRESOURCE", 146),
+    ("CREATEDEPTHSTENCILVIEW_OUTOFMEMORY_RETURN", 149),
+    ("CREATEDEPTHSTENCILVIEW_TOOMANYOBJECTS", 147),
+    ("CREATEDEPTHSTENCILVIEW_UNRECOGNIZEDFORMAT", 142),
+    ("CREATEDEVICECONTEXTSTATE_FEATURELEVELS_NOT_SUPPORTED", 3145752),
+    ("CREATEDEVICECONTEXTSTATE_INVALIDFEATURELEVEL", 3145751),
+    ("CREATEDEVICECONTEXTSTATE_INVALIDFLAGS", 3145750),
+    ("CREATEDEVICECONTEXTSTATE_INVALIDREFIID", 3145753),
+    ("CREATEDEVICE_INVALIDARGS", 3146142),
+    ("CREATEDEVICE_WARNING", 3146143),
+    ("CREATEDOMAINSHADER_INVALIDCALL", 2097193),
+    ("CREATEDOMAINSHADER_INVALIDCLASSLINKAGE", 2097197),
+    ("CREATEDOMAINSHADER_INVALIDSHADERBYTECODE", 2097195),
+    ("CREATEDOMAINSHADER_INVALIDSHADERTYPE", 2097196),
+    ("CREATEDOMAINSHADER_OUTOFMEMORY", 2097194),
+    ("CREATEFENCE_INVALIDFLAGS", 3146256),
+    ("CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_CANTHAVEONLYGAPS", 188),
+    ("CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_DECLTOOCOMPLEX", 189),
+    ("CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_EXPECTEDDECL", 177),
+    ("CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_INVALIDCLASSLINKAGE", 2097159),
+    ("CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_INVALIDCOMPONENTCOUNT", 181),
+    ("CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_INVALIDGAPDEFINITION", 183),
+    ("CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_INVALIDNUMENTRIES", 174),
+    ("CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_INVALIDNUMSTREAMS", 2097156),
+    ("CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_INVALIDNUMSTRIDES", 2097172),
+    ("CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_INVALIDOUTPUTSLOT", 179),
+    ("CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_INVALIDOUTPUTSTREAMSTRIDE", 185),
+    ("CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_INVALIDSHADERBYTECODE", 172),
+    ("CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_INVALIDSHADERTYPE", 173),
+    ("CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_INVALIDSTARTCOMPONENTANDCOMPONENTCOUNT", 182),
+    ("CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_INVALIDSTREAM", 2097169),
+    ("CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_INVALIDSTREAMTORASTERIZER", 2097157),
+    ("CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_MASKMISMATCH", 187),
+    ("CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_MISSINGOUTPUTSIGNATURE", 190),
+    ("CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_MISSINGSEMANTIC", 186),
+    ("CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_ONLYONEELEMENTPERSLOT", 180),
+    ("CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_OUTOFMEMORY", 171),
+    ("CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_OUTPUTSLOT0EXPECTED", 178),
+    ("CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_OUTPUTSTREAMSTRIDEUNUSED", 175),
+    ("CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_REPEATEDOUTPUT", 184),
+    ("CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_TRAILING_DIGIT_IN_SEMANTIC", 386),
+    ("CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_UNEXPECTEDDECL", 176),
+    ("CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_UNEXPECTEDENTRIES", 2097170),
+    ("CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_UNEXPECTEDSTREAMS", 2097158),
+    ("CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_UNEXPECTEDSTRIDES", 2097171),
+    ("CREATEGEOMETRYSHADER_INVALIDCLASSLINKAGE", 2097155),
+    ("CREATEGEOMETRYSHADER_INVALIDSHADERBYTECODE", 169),
+    ("CREATEGEOMETRYSHADER_INVALIDSHADERTYPE", 170),
+    ("CREATEGEOMETRYSHADER_OUTOFMEMORY", 168),
+    ("CREATEHULLSHADER_INVALIDCALL", 2097177),
+    ("CREATEHULLSHADER_INVALIDCLASSLINKAGE", 2097181),
+    ("CREATEHULLSHADER_INVALIDSHADERBYTECODE", 2097179),
+    ("CREATEHULLSHADER_INVALIDSHADERTYPE", 2097180),
+    ("CREATEHULLSHADER_OUTOFMEMORY", 2097178),
+    ("CREATEINPUTLAYOUT_DUPLICATESEMANTIC", 160),
+    ("CREATEINPUTLAYOUT_EMPTY_LAYOUT", 420),
+    ("CREATEINPUTLAYOUT_INCOMPATIBLEFORMAT", 153),
+    ("CREATEINPUTLAYOUT_INVALIDALIGNMENT", 159),
+    ("CREATEINPUTLAYOUT_INVALIDFORMAT", 152),
+    ("CREATEINPUTLAYOUT_INVALIDINPUTSLOTCLASS", 155),
+    ("CREATEINPUTLAYOUT_INVALIDSLOT", 154),
+    ("CREATEINPUTLAYOUT_INVALIDSLOTCLASSCHANGE", 157),
+    ("CREATEINPUTLAYOUT_INVALIDSTEPRATECHANGE", 158),
+    ("CREATEINPUTLAYOUT_LEVEL9_INSTANCING_NOT_SUPPORTED", 3146124),
+    ("CREATEINPUTLAYOUT_LEVEL9_STEPRATE_NOT_1", 3146123),
+    ("CREATEINPUTLAYOUT_MISSINGELEMENT", 163),
+    ("CREATEINPUTLAYOUT_NULLDESC", 164),
+    ("CREATEINPUTLAYOUT_NULLSEMANTIC", 162),
+    ("CREATEINPUTLAYOUT_OUTOFMEMORY", 150),
+    ("CREATEINPUTLAYOUT_STEPRATESLOTCLASSMISMATCH", 156),
+    ("CREATEINPUTLAYOUT_TOOMANYELEMENTS", 151),
+    ("CREATEINPUTLAYOUT_TRAILING_DIGIT_IN_SEMANTIC", 385),
+    ("CREATEINPUTLAYOUT_TYPE_MISMATCH", 391),
+    ("CREATEINPUTLAYOUT_UNPARSEABLEINPUTSIGNATURE", 161),
+    ("CREATEINPUTLAYOUT_UNSUPPORTED_FORMAT", 1048599),
+    ("CREATEPIXELSHADER_INVALIDCLASSLINKAGE", 2097160),
+    ("CREATEPIXELSHADER_INVALIDSHADERBYTECODE", 192),
+    ("CREATEPIXELSHADER_INVALIDSHADERTYPE", 193),
+    ("CREATEPIXELSHADER_OUTOFMEMORY", 191),
+    ("CREATEPREDICATE_OUTOFMEMORY_RETURN", 395),
+    ("CREATEQUERYORPREDICATE_DECODENOTSUPPORTED", 3146158),
+    ("CREATEQUERYORPREDICATE_ENCODENOTSUPPORTED", 3146159),
+    ("CREATEQUERYORPREDICATE_INVALIDCONTEXTTYPE", 3146157),
+    ("CREATEQUERYORPREDICATE_INVALIDMISCFLAGS", 233),
+    ("CREATEQUERYORPREDICATE_INVALIDQUERY", 232),
+    ("CREATEQUERYORPREDICATE_NULLDESC", 235),
+    ("CREATEQUERYORPREDICATE_UNEXPECTEDMISCFLAG", 234),
+    ("CREATEQUERYORPREDICATE_UNSUPPORTEDCONTEXTTTYPEFORQUERY", 3146222),
+    ("CREATEQUERY_OUTOFMEMORY_RETURN", 394),
+    ("CREATERASTERIZERSTATE_INVALIDCULLMODE", 195),
+    ("CREATERASTERIZERSTATE_INVALIDDEPTHBIASCLAMP", 196),
+    ("CREATERASTERIZERSTATE_INVALIDFILLMODE", 194),
+    ("CREATERASTERIZERSTATE_INVALIDFORCEDSAMPLECOUNT", 3145757),
+    ("CREATERASTERIZERSTATE_INVALIDSLOPESCALEDDEPTHBIAS", 197),
+    ("CREATERASTERIZERSTATE_INVALID_CONSERVATIVERASTERMODE", 3146155),
+    ("CREATERASTERIZERSTATE_NULLDESC", 199),
+    ("CREATERASTERIZERSTATE_TOOMANYOBJECTS", 198),
+    ("CREATERENDERTARGETVIEW_AMBIGUOUSVIDEOPLANEINDEX", 3146165),
+    ("CREATERENDERTARGETVIEW_INVALIDARG_RETURN", 140),
+    ("CREATERENDERTARGETVIEW_INVALIDDARRAYWITHDECODER", 3145944),
+    ("CREATERENDERTARGETVIEW_INVALIDDESC", 135),
+    ("CREATERENDERTARGETVIEW_INVALIDDIMENSIONS", 137),
+    ("CREATERENDERTARGETVIEW_INVALIDFORMAT", 136),
+    ("CREATERENDERTARGETVIEW_INVALIDPLANEINDEX", 3146163),
+    ("CREATERENDERTARGETVIEW_INVALIDRESOURCE", 138),
+    ("CREATERENDERTARGETVIEW_INVALIDVIDEOPLANEINDEX", 3146164),
+    ("CREATERENDERTARGETVIEW_OUTOFMEMORY_RETURN", 141),
+    ("CREATERENDERTARGETVIEW_TOOMANYOBJECTS", 139),
+    ("CREATERENDERTARGETVIEW_UNRECOGNIZEDFORMAT", 133),
+    ("CREATERENDERTARGETVIEW_UNSUPPORTEDFORMAT", 134),
+    ("CREATERESOURCE_DIMENSION_EXCEEDS_FEATURE_LEVEL_DEFINITION", 1048630),
+    ("CREATERESOURCE_DIMENSION_OUT_OF_RANGE", 1048588),
+    ("CREATERESOURCE_DXGI_FORMAT_R8G8B8A8_CANNOT_BE_SHARED", 1048617),
+    ("CREATERESOURCE_MSAA_PRECLUDES_SHADER_RESOURCE", 1048610),
+    ("CREATERESOURCE_NON_POW_2_MIPMAP", 1048634),
+    ("CREATERESOURCE_NOT_BINDABLE_AS_RENDER_TARGET", 1048608),
+    ("CREATERESOURCE_NOT_BINDABLE_AS_SHADER_RESOURCE", 1048589),
+    ("CREATERESOURCE_NO_ARRAYS", 1048585),
+    ("CREATERESOURCE_NO_AUTOGEN_FOR_VOLUMES", 1048616),
+    ("CREATERESOURCE_NO_DWORD_INDEX_BUFFER", 1048609),
+    ("CREATERESOURCE_NO_STREAM_OUT", 1048614),
+    ("CREATERESOURCE_NO_TEXTURE_1D", 1048587),
+    ("CREATERESOURCE_NO_VB_AND_IB_BIND", 1048586),
+    ("CREATERESOURCE_ONLY_SINGLE_MIP_LEVEL_DEPTH_STENCIL_SUPPORTED", 1048631),
+    ("CREATERESOURCE_ONLY_VB_IB_FOR_BUFFERS", 1048615),
+    ("CREATERESOURCE_PRESENTATION_PRECLUDES_SHADER_RESOURCE", 1048611),
+    ("CREATESAMPLERSTATE_BORDER_NOT_SUPPORTED", 1048635),
+    ("CREATESAMPLERSTATE_BORDER_OUT_OF_RANGE", 1048581),
+    ("CREATESAMPLERSTATE_EXCESSIVE_ANISOTROPY", 1048580),
+    ("CREATESAMPLERSTATE_INVALIDADDRESSU", 222),
+    ("CREATESAMPLERSTATE_INVALIDADDRESSV", 223),
+    ("CREATESAMPLERSTATE_INVALIDADDRESSW", 224),
+    ("CREATESAMPLERSTATE_INVALIDCOMPARISONFUNC", 227),
+    ("CREATESAMPLERSTATE_INVALIDFILTER", 221),
+    ("CREATESAMPLERSTATE_INVALIDMAXANISOTROPY", 226),
+    ("CREATESAMPLERSTATE_INVALIDMAXLOD", 229),
+    ("CREATESAMPLERSTATE_INVALIDMINLOD", 228),
+    ("CREATESAMPLERSTATE_INVALIDMIPLODBIAS", 225),
+    ("CREATESAMPLERSTATE_MAXLOD_MUST_BE_FLT_MAX", 1048605),
+    ("CREATESAMPLERSTATE_MINLOD_MUST_NOT_BE_FRACTIONAL", 1048604),
+    ("CREATESAMPLERSTATE_NO_COMPARISON_SUPPORT", 1048579),
+    ("CREATESAMPLERSTATE_NO_MIRRORONCE", 1048625),
+    ("CREATESAMPLERSTATE_NULLDESC", 231),
+    ("CREATESAMPLERSTATE_TOOMANYOBJECTS", 230),
+    ("CREATESHADERRESESOURCEVIEW_TOOMANYOBJECTS", 2097359),
+    ("CREATESHADERRESOURCEVIEW_AMBIGUOUSVIDEOPLANEINDEX", 3146162),
+    ("CREATESHADERRESOURCEVIEW_CUBES_MUST_HAVE_6_SIDES", 1048607),
+    ("CREATESHADERRESOURCEVIEW_FIRSTARRAYSLICE_MUST_BE_ZERO", 1048606),
+    ("CREATESHADERRESOURCEVIEW_INVALIDARG_RETURN", 131),
+    ("CREATESHADERRESOURCEVIEW_INVALIDDARRAYWITHDECODER", 3145942),
+    ("CREATESHADERRESOURCEVIEW_INVALIDDESC", 126),
+    ("CREATESHADERRESOURCEVIEW_INVALIDDIMENSIONS", 128),
+    ("CREATESHADERRESOURCEVIEW_INVALIDFLAGS", 2097340),
+    ("CREATESHADERRESOURCEVIEW_INVALIDFORMAT", 127),
+    ("CREATESHADERRESOURCEVIEW_INVALIDPLANEINDEX", 3146160),
+    ("CREATESHADERRESOURCEVIEW_INVALIDRESOURCE", 129),
+    ("CREATESHADERRESOURCEVIEW_INVALIDVIDEOPLANEINDEX", 3146161),
+    ("CREATESHADERRESOURCEVIEW_MUST_USE_LOWEST_LOD", 1048603),
+    ("CREATESHADERRESOURCEVIEW_OUTOFMEMORY_RETURN", 132),
+    ("CREATESHADERRESOURCEVIEW_TOOMANYOBJECTS", 130),
+    ("CREATESHADERRESOURCEVIEW_UNRECOGNIZEDFORMAT", 125),
+    ("CREATETEXTURE1D_INVALIDARG_RETURN", 87),
+    ("CREATETEXTURE1D_INVALIDBINDFLAGS", 82),
+    ("CREATETEXTURE1D_INVALIDCPUACCESSFLAGS", 81),
+    ("CREATETEXTURE1D_INVALIDDIMENSIONS", 84),
+    ("CREATETEXTURE1D_INVALIDINITIALDATA", 83),
+    ("CREATETEXTURE1D_INVALIDMIPLEVELS", 85),
+    ("CREATETEXTURE1D_INVALIDMISCFLAGS", 86),
+    ("CREATETEXTURE1D_INVALIDSAMPLES", 76),
+    ("CREATETEXTURE1D_INVALIDUSAGE", 3146121),
+    ("CREATETEXTURE1D_LARGEALLOCATION", 90),
+    ("CREATETEXTURE1D_NULLDESC", 89),
+    ("CREATETEXTURE1D_OUTOFMEMORY_RETURN", 88),
+    ("CREATETEXTURE1D_UNRECOGNIZEDBINDFLAGS", 78),
+    ("CREATETEXTURE1D_UNRECOGNIZEDCPUACCESSFLAGS", 79),
+    ("CREATETEXTURE1D_UNRECOGNIZEDFORMAT", 74),
+    ("CREATETEXTURE1D_UNRECOGNIZEDMISCFLAGS", 80),
+    ("CREATETEXTURE1D_UNRECOGNIZEDUSAGE", 77),
+    ("CREATETEXTURE1D_UNSUPPORTEDFORMAT", 75),
+    ("CREATETEXTURE2D_INVALIDARG_RETURN", 104),
+    ("CREATETEXTURE2D_INVALIDBINDFLAGS", 99),
+    ("CREATETEXTURE2D_INVALIDCPUACCESSFLAGS", 98),
+    ("CREATETEXTURE2D_INVALIDDIMENSIONS", 101),
+    ("CREATETEXTURE2D_INVALIDINITIALDATA", 100),
+    ("CREATETEXTURE2D_INVALIDMIPLEVELS", 102),
+    ("CREATETEXTURE2D_INVALIDMISCFLAGS", 103),
+    ("CREATETEXTURE2D_INVALIDSAMPLES", 93),
+    ("CREATETEXTURE2D_INVALIDUSAGE", 3146122),
+    ("CREATETEXTURE2D_LARGEALLOCATION", 107),
+    ("CREATETEXTURE2D_NULLDESC", 106),
+    ("CREATETEXTURE2D_OUTOFMEMORY_RETURN", 105),
+    ("CREATETEXTURE2D_UNRECOGNIZEDBINDFLAGS", 95),
+    ("CREATETEXTURE2D_UNRECOGNIZEDCPUACCESSFLAGS", 96),
+    ("CREATETEXTURE2D_UNRECOGNIZEDFORMAT", 91),
+    ("CREATETEXTURE2D_UNRECOGNIZEDMISCFLAGS", 97),
+    ("CREATETEXTURE2D_UNRECOGNIZEDUSAGE", 94),
+    ("CREATETEXTURE2D_UNSUPPORTEDFORMAT", 92),
+    ("CREATETEXTURE3D_INVALIDARG_RETURN", 121),
+    ("CREATETEXTURE3D_INVALIDBINDFLAGS", 116),
+    ("CREATETEXTURE3D_INVALIDCPUACCESSFLAGS", 115),
+    ("CREATETEXTURE3D_INVALIDDIMENSIONS", 118),
+    ("CREATETEXTURE3D_INVALIDINITIALDATA", 117),
+    ("CREATETEXTURE3D_INVALIDMIPLEVELS", 119),
+    ("CREATETEXTURE3D_INVALIDMISCFLAGS", 120),
+    ("CREATETEXTURE3D_INVALIDSAMPLES", 110),
+    ("CREATETEXTURE3D_LARGEALLOCATION", 124),
+    ("CREATETEXTURE3D_NULLDESC", 123),
+    ("CREATETEXTURE3D_OUTOFMEMORY_RETURN", 122),
+    ("CREATETEXTURE3D_UNRECOGNIZEDBINDFLAGS", 112),
+    ("CREATETEXTURE3D_UNRECOGNIZEDCPUACCESSFLAGS", 113),
+    ("CREATETEXTURE3D_UNRECOGNIZEDFORMAT", 108),
+    ("CREATETEXTURE3D_UNRECOGNIZEDMISCFLAGS", 114),
+    ("CREATETEXTURE3D_UNRECOGNIZEDUSAGE", 111),
+    ("CREATETEXTURE3D_UNSUPPORTEDFORMAT", 109),
+    ("CREATEUNORDEREDACCESSVIEW_AMBIGUOUSVIDEOPLANEINDEX", 3146168),
+    ("CREATEUNORDEREDACCESSVIEW_INVALIDARG_RETURN", 2097351),
+    ("CREATEUNORDEREDACCESSVIEW_INVALIDDARRAYWITHDECODER", 3145943),
+    ("CREATEUNORDEREDACCESSVIEW_INVALIDDESC", 2097342),
+    ("CREATEUNORDEREDACCESSVIEW_INVALIDDIMENSIONS", 2097344),
+    ("CREATEUNORDEREDACCESSVIEW_INVALIDFLAGS", 2097358),
+    ("CREATEUNORDEREDACCESSVIEW_INVALIDFORMAT", 2097343),
+    ("CREATEUNORDEREDACCESSVIEW_INVALIDPLANEINDEX", 3146166),
+    ("CREATEUNORDEREDACCESSVIEW_INVALIDRESOURCE", 2097341),
+    ("CREATEUNORDEREDACCESSVIEW_INVALIDVIDEOPLANEINDEX", 3146167),
+    ("CREATEUNORDEREDACCESSVIEW_OUTOFMEMORY_RETURN", 2097352),
+    ("CREATEUNORDEREDACCESSVIEW_TOOMANYOBJECTS", 2097353),
+    ("CREATEUNORDEREDACCESSVIEW_UNRECOGNIZEDFORMAT", 2097345),
+    ("CREATEVERTEXSHADER_INVALIDCLASSLINKAGE", 2097154),
+    ("CREATEVERTEXSHADER_INVALIDSHADERBYTECODE", 166),
+    ("CREATEVERTEXSHADER_INVALIDSHADERTYPE", 167),
+    ("CREATEVERTEXSHADER_OUTOFMEMORY", 165),
+    ("CREATEVIDEODECODEROUTPUTVIEW_INVALIDARRAY", 3145915),
+    ("CREATEVIDEODECODEROUTPUTVIEW_INVALIDARRAYSIZE", 3145914),
+    ("CREATEVIDEODECODEROUTPUTVIEW_INVALIDBIND", 3145910),
+    ("CREATEVIDEODECODEROUTPUTVIEW_INVALIDDIMENSION", 3145916),
+    ("CREATEVIDEODECODEROUTPUTVIEW_INVALIDMIP", 3145912),
+    ("CREATEVIDEODECODEROUTPUTVIEW_INVALIDTYPE", 3145909),
+    ("CREATEVIDEODECODEROUTPUTVIEW_NULLPARAM", 3145908),
+    ("CREATEVIDEODECODEROUTPUTVIEW_OUTOFMEMORY_RETURN", 3145907),
+    ("CREATEVIDEODECODEROUTPUTVIEW_UNSUPPORTEDFORMAT", 3145911),
+    ("CREATEVIDEODECODEROUTPUTVIEW_UNSUPPORTEMIP", 3145913),
+    ("CREATEVIDEODECODER_DRIVER_INVALIDBUFFERSIZE", 3145762),
+    ("CREATEVIDEODECODER_DRIVER_INVALIDBUFFERUSAGE", 3145763),
+    ("CREATEVIDEODECODER_INVALIDFORMAT", 3145760),
+    ("CREATEVIDEODECODER_NULLPARAM", 3145759),
+    ("CREATEVIDEODECODER_OUTOFMEMORY_RETURN", 3145758),
+    ("CREATEVIDEODECODER_ZEROWIDTHHEIGHT", 3145761),
+    ("CREATEVIDEOPROCESSORENUMERATOR_INVALIDFRAMEFORMAT", 3145793),
+    ("CREATEVIDEOPROCESSORENUMERATOR_INVALIDINPUTFRAMERATE", 3145795),
+    ("CREATEVIDEOPROCESSORENUMERATOR_INVALIDOUTPUTFRAMERATE", 3145796),
+    ("CREATEVIDEOPROCESSORENUMERATOR_INVALIDUSAGE", 3145794),
+    ("CREATEVIDEOPROCESSORENUMERATOR_INVALIDWIDTHHEIGHT", 3145797),
+    ("CREATEVIDEOPROCESSORENUMERATOR_NULLPARAM", 3145792),
+    ("CREATEVIDEOPROCESSORENUMERATOR_OUTOFMEMORY_RETURN", 3145791),
+    ("CREATEVIDEOPROCESSORINPUTVIEW_INVALIDARRAY", 3145928),
+    ("CREATEVIDEOPROCESSORINPUTVIEW_INVALIDARRAYSIZE", 3145927),
+    ("CREATEVIDEOPROCESSORINPUTVIEW_INVALIDBIND", 3145920),
+    ("CREATEVIDEOPROCESSORINPUTVIEW_INVALIDDIMENSION", 3145929),
+    ("CREATEVIDEOPROCESSORINPUTVIEW_INVALIDFORMAT", 3145923),
+    ("CREATEVIDEOPROCESSORINPUTVIEW_INVALIDFOURCC", 3145924),
+    ("CREATEVIDEOPROCESSORINPUTVIEW_INVALIDMIP", 3145925),
+    ("CREATEVIDEOPROCESSORINPUTVIEW_INVALIDMISC", 3145921),
+    ("CREATEVIDEOPROCESSORINPUTVIEW_INVALIDMSAA", 3146073),
+    ("CREATEVIDEOPROCESSORINPUTVIEW_INVALIDTYPE", 3145919),
+    ("CREATEVIDEOPROCESSORINPUTVIEW_INVALIDUSAGE", 3145922),
+    ("CREATEVIDEOPROCESSORINPUTVIEW_NULLPARAM", 3145918),
+    ("CREATEVIDEOPROCESSORINPUTVIEW_OUTOFMEMORY_RETURN", 3145917),
+    ("CREATEVIDEOPROCESSORINPUTVIEW_UNSUPPORTEDMIP", 3145926),
+    ("CREATEVIDEOPROCESSOROUTPUTVIEW_INVALIDARRAY", 3145938),
+    ("CREATEVIDEOPROCESSOROUTPUTVIEW_INVALIDBIND", 3145933),
+    ("CREATEVIDEOPROCESSOROUTPUTVIEW_INVALIDDIMENSION", 3145939),
+    ("CREATEVIDEOPROCESSOROUTPUTVIEW_INVALIDFORMAT", 3145934),
+    ("CREATEVIDEOPROCESSOROUTPUTVIEW_INVALIDMIP", 3145935),
+    ("CREATEVIDEOPROCESSOROUTPUTVIEW_INVALIDMSAA", 3146074),
+    ("CREATEVIDEOPROCESSOROUTPUTVIEW_INVALIDTYPE", 3145932),
+    ("CREATEVIDEOPROCESSOROUTPUTVIEW_NULLPARAM", 3145931),
+    ("CREATEVIDEOPROCESSOROUTPUTVIEW_OUTOFMEMORY_RETURN", 3145930),
+    ("CREATEVIDEOPROCESSOROUTPUTVIEW_UNSUPPORTEDARRAY", 3145937),
+    ("CREATEVIDEOPROCESSOROUTPUTVIEW_UNSUPPORTEDMIP", 3145936),
+    ("CREATEVIDEOPROCESSOR_NULLPARAM", 3145808),
+    ("CREATEVIDEOPROCESSOR_OUTOFMEMORY_RETURN", 3145807),
+    ("CREATE_AUTHENTICATEDCHANNEL", 3146148),
+    ("CREATE_BLENDSTATE", 2097270),
+    ("CREATE_BUFFER", 2097228),
+    ("CREATE_CLASSINSTANCE", 2097290),
+    ("CREATE_CLASSLINKAGE", 2097293),
+    ("CREATE_COMMANDLIST", 2097287),
+    ("CREATE_COMPUTESHADER", 2097298),
+    ("CREATE_CONTEXT", 2097225),
+    ("CREATE_COUNTER", 2097285),
+    ("CREATE_CRYPTOSESSION", 3146147),
+    ("CREATE_DECODEROUTPUTVIEW", 3145732),
+    ("CREATE_DEPTHSTENCILSTATE", 2097273),
+    ("CREATE_DEPTHSTENCILVIEW", 2097246),
+    ("CREATE_DEVICECONTEXTSTATE", 3145735),
+    ("CREATE_DOMAINSHADER", 2097255),
+    ("CREATE_FENCE", 3146250),
+    ("CREATE_GEOMETRYSHADER", 2097258),
+    ("CREATE_HULLSHADER", 2097252),
+    ("CREATE_INPUTLAYOUT", 2097264),
+    ("CREATE_PIXELSHADER", 2097261),
+    ("CREATE_PREDICATE", 2097282),
+    ("CREATE_PROCESSORINPUTVIEW", 3145733),
+    ("CREATE_PROCESSOROUTPUTVIEW", 3145734),
+    ("CREATE_QUERY", 2097279),
+    ("CREATE_RASTERIZERSTATE", 2097276),
+    ("CREATE_RENDERTARGETVIEW", 2097243),
+    ("CREATE_SAMPLER", 2097267),
+    ("CREATE_SHADERRESOURCEVIEW", 2097240),
+    ("CREATE_SYNCHRONIZEDCHANNEL", 3146253),
+    ("CREATE_TEXTURE1D", 2097231),
+    ("CREATE_TEXTURE2D", 2097234),
+    ("CREATE_TEXTURE3D", 2097237),
+    ("CREATE_TRACKEDWORKLOAD", 3146267),
+    ("CREATE_TRACKED_WORKLOAD_INVALID_DEADLINE_TYPE", 3146272),
+    ("CREATE_TRACKED_WORKLOAD_INVALID_ENGINE_TYPE", 3146273),
+    ("CREATE_TRACKED_WORKLOAD_INVALID_MAX_INSTANCES", 3146271),
+    ("CREATE_TRACKED_WORKLOAD_NULLPARAM", 3146270),
+    ("CREATE_UNORDEREDACCESSVIEW", 2097301),
+    ("CREATE_VERTEXSHADER", 2097249),
+    ("CREATE_VIDEODECODER", 3145729),
+    ("CREATE_VIDEOPROCESSOR", 3145731),
+    ("CREATE_VIDEOPROCESSORENUM", 3145730),
+    ("CSSETCONSTANTBUFFERS_INVALIDBUFFER", 2097326),
+    ("CSSETCONSTANTBUFFERS_INVALIDBUFFEROFFSETORCOUNT", 3146021),
+    ("CSSETCONSTANTBUFFERS_UNBINDDELETINGOBJECT", 2097319),
+    ("CSSETSAMPLERS_UNBINDDELETINGOBJECT", 2097415),
+    ("CSSETSHADERRESOURCES_UNBINDDELETINGOBJECT", 2097318),
+    ("CSSETSHADER_UNBINDDELETINGOBJECT", 2097418),
+    ("CSSETUNORDEREDACCESSVIEWS_UNBINDDELETINGOBJECT", 2097349),
+    ("D3D10L9_MESSAGES_END", 1048638),
+    ("D3D10L9_MESSAGES_START", 1048576),
+    ("D3D10_MESSAGES_END", 443),
+    ("D3D11_1_MESSAGES_END", 3146118),
+    ("D3D11_1_MESSAGES_START", 3145728),
+    ("D3D11_2_MESSAGES_END", 3146153),
+    ("D3D11_2_MESSAGES_START", 3146119),
+    ("D3D11_3_MESSAGES_END", 3146257),
+    ("D3D11_3_MESSAGES_START", 3146154),
+    ("D3D11_5_MESSAGES_END", 3146284),
+    ("D3D11_5_MESSAGES_START", 3146258),
+    ("D3D11_MESSAGES_END", 2097424),
+    ("D3D11_MESSAGES_START", 2097152),
+    ("DECODERBEGINFRAME_HAZARD", 3145785),
+    ("DECODERBEGINFRAME_INVALID_HISTOGRAM_BUFFER_MISC_FLAGS", 3146265),
+    ("DECODERBEGINFRAME_INVALID_HISTOGRAM_BUFFER_OFFSET", 3146266),
+    ("DECODERBEGINFRAME_INVALID_HISTOGRAM_BUFFER_SIZE", 3146263),
+    ("DECODERBEGINFRAME_INVALID_HISTOGRAM_BUFFER_USAGE", 3146264),
+    ("DECODERBEGINFRAME_INVALID_HISTOGRAM_COMPONENT", 3146262),
+    ("DECODERBEGINFRAME_INVALID_HISTOGRAM_COMPONENT_COUNT", 3146261),
+    ("DECODERBEGINFRAME_NULLPARAM", 3145784),
+    ("DECODERENDFRAME_NULLPARAM", 3145786),
+    ("DECODEREXTENSION_INVALIDRESOURCE", 3145790),
+    ("DECODEREXTENSION_NULLPARAM", 3145789),
+    ("DECRYPTIONBLT_DST_MAPPED", 3145983),
+    ("DECRYPTIONBLT_DST_MULTISAMPLED", 3145979),
+    ("DECRYPTIONBLT_DST_NOT_RENDER_TARGET", 3145981),
+    ("DECRYPTIONBLT_DST_OFFERED", 3145985),
+    ("DECRYPTIONBLT_DST_WRONGDEVICE", 3145976),
+    ("DECRYPTIONBLT_FORMAT_MISMATCH", 3145977),
+    ("DECRYPTIONBLT_NULLPARAM", 3145974),
+    ("DECRYPTIONBLT_SIZE_MISMATCH", 3145978),
+    ("DECRYPTIONBLT_SRC_CONTENT_UNDEFINED", 3145986),
+    ("DECRYPTIONBLT_SRC_MAPPED", 3145982),
+    ("DECRYPTIONBLT_SRC_NOT_STAGING", 3145980),
+    ("DECRYPTIONBLT_SRC_OFFERED", 3145984),
+    ("DECRYPTIONBLT_SRC_WRONGDEVICE", 3145975),
+    ("DECRYPTIONBLT_UNSUPPORTED", 3145973),
+    ("DEFERRED_CONTEXT_REMOVAL_PROCESS_AT_FAULT", 2097206),
+    ("DESTROY_AUTHENTICATEDCHANNEL", 3146152),
+    ("DESTROY_BLENDSTATE", 2097272),
+    ("DESTROY_BUFFER", 2097230),
+    ("DESTROY_CLASSINSTANCE", 2097292),
+    ("DESTROY_CLASSLINKAGE", 2097295),
+    ("DESTROY_COMMANDLIST", 2097289),
+    ("DESTROY_COMPUTESHADER", 2097300),
+    ("DESTROY_CONTEXT", 2097227),
+    ("DESTROY_COUNTER", 2097286),
+    ("DESTROY_CRYPTOSESSION", 3146151),
+    ("DESTROY_DECODEROUTPUTVIEW", 3145746),
+    ("DESTROY_DEPTHSTENCILSTATE", 2097275),
+    ("DESTROY_DEPTHSTENCILVIEW", 2097248),
+    ("DESTROY_DEVICECONTEXTSTATE", 3145749),
+    ("DESTROY_DOMAINSHADER", 2097257),
+    ("DESTROY_FENCE", 3146252),
+    ("DESTROY_GEOMETRYSHADER", 2097260),
+    ("DESTROY_HULLSHADER", 2097254),
+    ("DESTROY_INPUTLAYOUT", 2097266),
+    ("DESTROY_PIXELSHADER", 2097263),
+    ("DESTROY_PREDICATE", 2097284),
+    ("DESTROY_PROCESSORINPUTVIEW", 3145747),
+    ("DESTROY_PROCESSOROUTPUTVIEW", 3145748),
+    ("DESTROY_QUERY", 2097281),
+    ("DESTROY_RASTERIZERSTATE", 2097278),
+    ("DESTROY_RENDERTARGETVIEW", 2097245),
+    ("DESTROY_SAMPLER", 2097269),
+    ("DESTROY_SHADERRESOURCEVIEW", 2097242),
+    ("DESTROY_SYNCHRONIZEDCHANNEL", 3146255),
+    ("DESTROY_TEXTURE1D", 2097233),
+    ("DESTROY_TEXTURE2D", 2097236),
+    ("DESTROY_TEXTURE3D", 2097239),
+    ("DESTROY_TRACKEDWORKLOAD", 3146269),
+    ("DESTROY_UNORDEREDACCESSVIEW", 2097303),
+    ("DESTROY_VERTEXSHADER", 2097251),
+    ("DESTROY_VIDEODECODER", 3145743),
+    ("DESTROY_VIDEOPROCESSOR", 3145745),
+    ("DESTROY_VIDEOPROCESSORENUM", 3145744),
+    ("DEVICE_CHECKFEATURESUPPORT_INVALIDARG_RETURN", 2097315),
+    ("DEVICE_CHECKFEATURESUPPORT_MISMATCHED_DATA_SIZE", 2097314),
+    ("DEVICE_CHECKFEATURESUPPORT_UNRECOGNIZED_FEATURE", 2097313),
+    ("DEVICE_CLEARVIEW_EMPTYRECT", 3146076),
+    ("DEVICE_CLEARVIEW_INVALIDRECT", 3146066),
+    ("DEVICE_CLEARVIEW_INVALIDSOURCERECT", 3146075),
+    ("DEVICE_CLEARVIEW_INVALIDVIEW", 3146035),
+    ("DEVICE_CLEARVIEW_NOTSUPPORTED", 3146062),
+    ("DEVICE_CREATECOMPUTESHADER_DOUBLEEXTENSIONSNOTSUPPORTED", 3146048),
+    ("DEVICE_CREATECOMPUTESHADER_DOUBLEFLOATOPSNOTSUPPORTED", 2097338),
+    ("DEVICE_CREATECOMPUTESHADER_SHADEREXTENSIONSNOTSUPPORTED", 3146049),
+    ("DEVICE_CREATECOMPUTESHADER_UAVSNOTSUPPORTED", 3146059),
+    ("DEVICE_CREATEDOMAINSHADER_DOUBLEEXTENSIONSNOTSUPPORTED", 3146040),
+    ("DEVICE_CREATEDOMAINSHADER_DOUBLEFLOATOPSNOTSUPPORTED", 2097334),
+    ("DEVICE_CREATEDOMAINSHADER_SHADEREXTENSIONSNOTSUPPORTED", 3146041),
+    ("DEVICE_CREATEDOMAINSHADER_UAVSNOTSUPPORTED", 3146055),
+    ("DEVICE_CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_DOUBLEEXTENSIONSNOTSUPPORTED", 3146044),
+    ("DEVICE_CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_DOUBLEFLOATOPSNOTSUPPORTED", 2097336),
+    ("DEVICE_CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_SHADEREXTENSIONSNOTSUPPORTED", 3146045),
+    ("DEVICE_CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_UAVSNOTSUPPORTED", 3146057),
+    ("DEVICE_CREATEGEOMETRYSHADER_DOUBLEEXTENSIONSNOTSUPPORTED", 3146042),
+    ("DEVICE_CREATEGEOMETRYSHADER_DOUBLEFLOATOPSNOTSUPPORTED", 2097335),
+    ("DEVICE_CREATEGEOMETRYSHADER_SHADEREXTENSIONSNOTSUPPORTED", 3146043),
+    ("DEVICE_CREATEGEOMETRYSHADER_UAVSNOTSUPPORTED", 3146056),
+    ("DEVICE_CREATEHULLSHADER_DOUBLEEXTENSIONSNOTSUPPORTED", 3146038),
+    ("DEVICE_CREATEHULLSHADER_DOUBLEFLOATOPSNOTSUPPORTED", 2097333),
+    ("DEVICE_CREATEHULLSHADER_SHADEREXTENSIONSNOTSUPPORTED", 3146039),
+    ("DEVICE_CREATEHULLSHADER_UAVSNOTSUPPORTED", 3146054),
+    ("DEVICE_CREATEPIXELSHADER_DOUBLEEXTENSIONSNOTSUPPORTED", 3146046),
+    ("DEVICE_CREATEPIXELSHADER_DOUBLEFLOATOPSNOTSUPPORTED", 2097337),
+    ("DEVICE_CREATEPIXELSHADER_SHADEREXTENSIONSNOTSUPPORTED", 3146047),
+    ("DEVICE_CREATEPIXELSHADER_UAVSNOTSUPPORTED", 3146058),
+    ("DEVICE_CREATESHADER_CLASSLINKAGE_FULL", 2097312),
+    ("DEVICE_CREATEVERTEXSHADER_DOUBLEEXTENSIONSNOTSUPPORTED", 3146036),
+    ("DEVICE_CREATEVERTEXSHADER_DOUBLEFLOATOPSNOTSUPPORTED", 2097332),
+    ("DEVICE_CREATEVERTEXSHADER_SHADEREXTENSIONSNOTSUPPORTED", 3146037),
+    ("DEVICE_CREATEVERTEXSHADER_UAVSNOTSUPPORTED", 3146053),
+    ("DEVICE_CSGETCONSTANTBUFFERS_BUFFERS_EMPTY", 2097330),
+    ("DEVICE_CSGETSAMPLERS_SAMPLERS_EMPTY", 2097331),
+    ("DEVICE_CSGETSHADERRESOURCES_VIEWS_EMPTY", 2097329),
+    ("DEVICE_CSGETUNORDEREDACCESSS_VIEWS_EMPTY", 2097357),
+    ("DEVICE_CSSETCONSTANTBUFFERS_BUFFERS_EMPTY", 2097327),
+    ("DEVICE_CSSETCONSTANTBUFFERS_HAZARD", 2097317),
+    ("DEVICE_CSSETSAMPLERS_SAMPLERS_EMPTY", 2097328),
+    ("DEVICE_CSSETSHADERRESOURCES_HAZARD", 2097316),
+    ("DEVICE_CSSETSHADERRESOURCES_VIEWS_EMPTY", 2097325),
+    ("DEVICE_CSSETUNORDEREDACCESSS_VIEWS_EMPTY", 2097356),
+    ("DEVICE_CSSETUNORDEREDACCESSVIEWS_HAZARD", 2097354),
+    ("DEVICE_CSSETUNORDEREDACCESSVIEWS_INVALIDOFFSET", 2097403),
+    ("DEVICE_CSSETUNORDEREDACCESSVIEWS_INVALIDVIEW", 2097402),
+    ("DEVICE_CSSETUNORDEREDACCESSVIEWS_TOOMANYVIEWS", 2097404),
+    ("DEVICE_DISCARDVIEW_INVALIDVIEW", 3145754),
+    ("DEVICE_DISPATCHINDIRECT_INVALID_ARG_BUFFER", 2097360),
+    ("DEVICE_DISPATCHINDIRECT_OFFSET_OVERFLOW", 2097362),
+    ("DEVICE_DISPATCHINDIRECT_OFFSET_UNALIGNED", 2097361),
+    ("DEVICE_DISPATCHINDIRECT_UNSUPPORTED", 2097396),
+    ("DEVICE_DISPATCH_BOUND_RESOURCE_MAPPED", 2097389),
+    ("DEVICE_DISPATCH_THREADGROUPCOUNT_OVERFLOW", 2097390),
+    ("DEVICE_DISPATCH_THREADGROUPCOUNT_ZERO", 2097391),
+    ("DEVICE_DISPATCH_UNSUPPORTED", 2097395),
+    ("DEVICE_DRAWINDEXEDINSTANCED_INDEXPOS_OVERFLOW", 340),
+    ("DEVICE_DRAWINDEXEDINSTANCED_INSTANCEPOS_OVERFLOW", 339),
+    ("DEVICE_DRAWINDEXED_INDEXPOS_OVERFLOW", 336),
+    ("DEVICE_DRAWINDIRECT_INVALID_ARG_BUFFER", 2097207),
+    ("DEVICE_DRAWINDIRECT_OFFSET_OVERFLOW", 2097209),
+    ("DEVICE_DRAWINDIRECT_OFFSET_UNALIGNED", 2097208),
+    ("DEVICE_DRAWINSTANCED_INSTANCEPOS_OVERFLOW", 338),
+    ("DEVICE_DRAWINSTANCED_VERTEXPOS_OVERFLOW", 337),
+    ("DEVICE_DRAW_BOUND_RESOURCE_MAPPED", 364),
+    ("DEVICE_DRAW_CONSTANT_BUFFER_NOT_SET", 350),
+    ("DEVICE_DRAW_CONSTANT_BUFFER_TOO_SMALL", 351),
+    ("DEVICE_DRAW_DEPTHSTENCILVIEW_NOT_SET", 3146080),
+    ("DEVICE_DRAW_GS_INPUT_PRIMITIVE_MISMATCH", 360),
+    ("DEVICE_DRAW_HS_DS_CONTROL_POINT_COUNT_MISMATCH", 2097223),
+    ("DEVICE_DRAW_HS_DS_SIGNATURE_MISMATCH", 2097221),
+    ("DEVICE_DRAW_HS_DS_TESSELLATOR_DOMAIN_MISMATCH", 2097224),
+    ("DEVICE_DRAW_HS_XOR_DS_MISMATCH", 2097205),
+    ("DEVICE_DRAW_HULL_SHADER_INPUT_TOPOLOGY_MISMATCH", 2097222),
+    ("DEVICE_DRAW_INDEX_BUFFER_FORMAT_INVALID", 358),
+    ("DEVICE_DRAW_INDEX_BUFFER_NOT_SET", 357),
+    ("DEVICE_DRAW_INDEX_BUFFER_TOO_SMALL", 359),
+    ("DEVICE_DRAW_INDEX_OFFSET_UNALIGNED", 368),
+    ("DEVICE_DRAW_INPUTLAYOUT_NOT_SET", 349),
+    ("DEVICE_DRAW_INVALID_PRIMITIVETOPOLOGY", 365),
+    ("DEVICE_DRAW_INVALID_SYSTEMVALUE", 3146156),
+    ("DEVICE_DRAW_INVALID_USE_OF_CENTER_MULTISAMPLE_PATTERN", 417),
+    ("DEVICE_DRAW_INVALID_USE_OF_FORCED_SAMPLE_COUNT", 3145940),
+    ("DEVICE_DRAW_OM_DUAL_SOURCE_BLENDING_CAN_ONLY_HAVE_RENDER_TARGET_0", 377),
+    ("DEVICE_DRAW_OM_RENDER_TARGET_DOES_NOT_SUPPORT_BLENDING", 376),
+    ("DEVICE_DRAW_OM_RENDER_TARGET_DOES_NOT_SUPPORT_LOGIC_OPS", 3146079),
+    ("DEVICE_DRAW_OUTPUT_STREAM_NOT_SET", 363),
+    ("DEVICE_DRAW_OUTPUT_STREAM_OFFSET_UNALIGNED", 369),
+    ("DEVICE_DRAW_PIXEL_SHADER_WITHOUT_RTV_OR_DSV", 2097408),
+    ("DEVICE_DRAW_POSITION_NOT_PRESENT", 362),
+    ("DEVICE_DRAW_PS_OUTPUT_TYPE_MISMATCH", 415),
+    ("DEVICE_DRAW_RASTERIZING_CONTROL_POINTS", 2097219),
+    ("DEVICE_DRAW_RENDERTARGETVIEW_NOT_SET", 3146081),
+    ("DEVICE_DRAW_RENDERTARGETVIEW_NOT_SET_DUE_TO_FLIP_PRESENT", 3146082),
+    ("DEVICE_DRAW_RESOURCE_FORMAT_GATHER_UNSUPPORTED", 416),
+    ("DEVICE_DRAW_RESOURCE_FORMAT_LD_UNSUPPORTED", 370),
+    ("DEVICE_DRAW_RESOURCE_FORMAT_SAMPLE_C_UNSUPPORTED", 372),
+    ("DEVICE_DRAW_RESOURCE_FORMAT_SAMPLE_UNSUPPORTED", 371),
+    ("DEVICE_DRAW_RESOURCE_MULTISAMPLE_UNSUPPORTED", 373),
+    ("DEVICE_DRAW_RESOURCE_RETURN_TYPE_MISMATCH", 361),
+    ("DEVICE_DRAW_RESOURCE_SAMPLE_COUNT_MISMATCH", 421),
+    ("DEVICE_DRAW_SAMPLER_MISMATCH", 390),
+    ("DEVICE_DRAW_SAMPLER_NOT_SET", 352),
+    ("DEVICE_DRAW_SAMPLE_MASK_IGNORED_ON_FL9", 3146067),
+    ("DEVICE_DRAW_SHADERRESOURCEVIEW_NOT_SET", 353),
+    ("DEVICE_DRAW_SO_STRIDE_LARGER_THAN_BUFFER", 375),
+    ("DEVICE_DRAW_SO_TARGETS_BOUND_WITHOUT_SOURCE", 374),
+    ("DEVICE_DRAW_UNORDEREDACCESSVIEW_RENDERTARGETVIEW_OVERLAP", 2097374),
+    ("DEVICE_DRAW_VERTEXPOS_OVERFLOW", 335),
+    ("DEVICE_DRAW_VERTEX_BUFFER_NOT_SET", 348),
+    ("DEVICE_DRAW_VERTEX_BUFFER_STRIDE_TOO_SMALL", 355),
+    ("DEVICE_DRAW_VERTEX_BUFFER_TOO_SMALL", 356),
+    ("DEVICE_DRAW_VERTEX_OFFSET_UNALIGNED", 366),
+    ("DEVICE_DRAW_VERTEX_SHADER_NOT_SET", 341),
+    ("DEVICE_DRAW_VERTEX_STRIDE_UNALIGNED", 367),
+    ("DEVICE_DRAW_VIEWPORT_NOT_SET", 384),
+    ("DEVICE_DRAW_VIEW_DIMENSION_MISMATCH", 354),
+    ("DEVICE_DSGETCONSTANTBUFFERS_BUFFERS_EMPTY", 2097203),
+    ("DEVICE_DSGETSAMPLERS_SAMPLERS_EMPTY", 2097204),
+    ("DEVICE_DSGETSHADERRESOURCES_VIEWS_EMPTY", 2097202),
+    ("DEVICE_DSSETCONSTANTBUFFERS_BUFFERS_EMPTY", 2097200),
+    ("DEVICE_DSSETCONSTANTBUFFERS_HAZARD", 2097190),
+    ("DEVICE_DSSETSAMPLERS_SAMPLERS_EMPTY", 2097201),
+    ("DEVICE_DSSETSHADERRESOURCES_HAZARD", 2097189),
+    ("DEVICE_DSSETSHADERRESOURCES_VIEWS_EMPTY", 2097198),
+    ("DEVICE_GENERATEMIPS_RESOURCE_INVALID", 277),
+    ("DEVICE_GETRESOURCEMINLOD_INVALIDCONTEXT", 2097366),
+    ("DEVICE_GETRESOURCEMINLOD_INVALIDRESOURCE", 2097367),
+    ("DEVICE_GSGETCONSTANTBUFFERS_BUFFERS_EMPTY", 269),
+    ("DEVICE_GSGETSAMPLERS_SAMPLERS_EMPTY", 270),
+    ("DEVICE_GSGETSHADERRESOURCES_VIEWS_EMPTY", 268),
+    ("DEVICE_GSSETCONSTANTBUFFERS_BUFFERS_EMPTY", 251),
+    ("DEVICE_GSSETCONSTANTBUFFERS_HAZARD", 6),
+    ("DEVICE_GSSETSAMPLERS_SAMPLERS_EMPTY", 252),
+    ("DEVICE_GSSETSHADERRESOURCES_HAZARD", 5),
+    ("DEVICE_GSSETSHADERRESOURCES_VIEWS_EMPTY", 249),
+    ("DEVICE_HSGETCONSTANTBUFFERS_BUFFERS_EMPTY", 2097187),
+    ("DEVICE_HSGETSAMPLERS_SAMPLERS_EMPTY", 2097188),
+    ("DEVICE_HSGETSHADERRESOURCES_VIEWS_EMPTY", 2097186),
+    ("DEVICE_HSSETCONSTANTBUFFERS_BUFFERS_EMPTY", 2097184),
+    ("DEVICE_HSSETCONSTANTBUFFERS_HAZARD", 2097174),
+    ("DEVICE_HSSETSAMPLERS_SAMPLERS_EMPTY", 2097185),
+    ("DEVICE_HSSETSHADERRESOURCES_HAZARD", 2097173),
+    ("DEVICE_HSSETSHADERRESOURCES_VIEWS_EMPTY", 2097182),
+    ("DEVICE_IAGETVERTEXBUFFERS_BUFFERS_EMPTY", 264),
+    ("DEVICE_IASETINDEXBUFFER_FORMAT_INVALID", 242),
+    ("DEVICE_IASETINDEXBUFFER_HAZARD", 2),
+    ("DEVICE_IASETINDEXBUFFER_OFFSET_TOO_LARGE", 243),
+    ("DEVICE_IASETINDEXBUFFER_OFFSET_UNALIGNED", 244),
+    ("DEVICE_IASETPRIMITIVETOPOLOGY_ADJACENCY_UNSUPPORTED", 1048594),
+    ("DEVICE_IASETPRIMITIVETOPOLOGY_TOPOLOGY_UNDEFINED", 237),
+    ("DEVICE_IASETPRIMITIVETOPOLOGY_TOPOLOGY_UNRECOGNIZED", 236),
+    ("DEVICE_IASETPRIMITIVETOPOLOGY_TOPOLOGY_UNSUPPORTED", 2097220),
+    ("DEVICE_IASETVERTEXBUFFERS_BUFFERS_EMPTY", 240),
+    ("DEVICE_IASETVERTEXBUFFERS_HAZARD", 1),
+    ("DEVICE_IASETVERTEXBUFFERS_INVALIDRANGE", 419),
+    ("DEVICE_IASETVERTEXBUFFERS_OFFSET_TOO_LARGE", 239),
+    ("DEVICE_IASETVERTEXBUFFERS_STRIDE_TOO_LARGE", 418),
+    ("DEVICE_LOCKEDOUT_INTERFACE", 3145945),
+    ("DEVICE_OMSETRENDERTARGETSANDUNORDEREDACCESSVIEWS_HAZARD", 2097346),
+    ("DEVICE_OMSETRENDERTARGETSANDUNORDEREDACCESSVIEWS_INVALIDOFFSET", 3146060),
+    ("DEVICE_OMSETRENDERTARGETSANDUNORDEREDACCESSVIEWS_NO_OP", 2097348),
+    ("DEVICE_OMSETRENDERTARGETSANDUNORDEREDACCESSVIEWS_NUMUAVS_INVALIDRANGE", 2097422),
+    ("DEVICE_OMSETRENDERTARGETSANDUNORDEREDACCESSVIEWS_OVERLAPPING_OLD_SLOTS", 2097347),
+    ("DEVICE_OMSETRENDERTARGETSANDUNORDEREDACCESSVIEWS_TOOMANYVIEWS", 3146061),
+    ("DEVICE_OMSETRENDERTARGETS_HAZARD", 9),
+    ("DEVICE_OPEN_SHARED_RESOURCE1_ACCESS_DENIED", 3146117),
+    ("DEVICE_OPEN_SHARED_RESOURCE1_NOT_SUPPORTED", 3146068),
+    ("DEVICE_OPEN_SHARED_RESOURCE_BADINTERFACE_RETURN", 383),
+    ("DEVICE_OPEN_SHARED_RESOURCE_BY_NAME_NOT_SUPPORTED", 3146069),
+    ("DEVICE_OPEN_SHARED_RESOURCE_INVALIDARG_RETURN", 381),
+    ("DEVICE_OPEN_SHARED_RESOURCE_OUTOFMEMORY_RETURN", 382),
+    ("DEVICE_PSGETCONSTANTBUFFERS_BUFFERS_EMPTY", 273),
+    ("DEVICE_PSGETSAMPLERS_SAMPLERS_EMPTY", 274),
+    ("DEVICE_PSGETSHADERRESOURCES_VIEWS_EMPTY", 272),
+    ("DEVICE_PSSETCONSTANTBUFFERS_BUFFERS_EMPTY", 257),
+    ("DEVICE_PSSETCONSTANTBUFFERS_HAZARD", 8),
+    ("DEVICE_PSSETSAMPLERS_SAMPLERS_EMPTY", 258),
+    ("DEVICE_PSSETSHADERRESOURCES_HAZARD", 7),
+    ("DEVICE_PSSETSHADERRESOURCES_VIEWS_EMPTY", 255),
+    ("DEVICE_REMOVAL_PROCESS_AT_FAULT", 378),
+    ("DEVICE_REMOVAL_PROCESS_NOT_AT_FAULT", 380),
+    ("DEVICE_REMOVAL_PROCESS_POSSIBLY_AT_FAULT", 379),
+    ("DEVICE_RESOLVESUBRESOURCE_DESTINATION_INVALID", 290),
+    ("DEVICE_RESOLVESUBRESOURCE_DESTINATION_SUBRESOURCE_INVALID", 291),
+    ("DEVICE_RESOLVESUBRESOURCE_FORMAT_INVALID", 294),
+    ("DEVICE_RESOLVESUBRESOURCE_SOURCE_INVALID", 292),
+    ("DEVICE_RESOLVESUBRESOURCE_SOURCE_SUBRESOURCE_INVALID", 293),
+    ("DEVICE_RSGETSCISSORRECTS_RECTS_EMPTY", 276),
+    ("DEVICE_RSGETVIEWPORTS_VIEWPORTS_EMPTY", 275),
+    ("DEVICE_RSSETSCISSORRECTS_INVALIDSCISSOR", 260),
+    ("DEVICE_RSSETSCISSORRECTS_NEGATIVESCISSOR", 1048632),
+    ("DEVICE_RSSETSCISSORRECTS_TOO_MANY_SCISSORS", 1048595),
+    ("DEVICE_RSSETVIEWPORTS_DENORMFLUSH", 387),
+    ("DEVICE_RSSETVIEWPORTS_INVALIDVIEWPORT", 259),
+    ("DEVICE_RSSETVIEWPORTS_TOO_MANY_VIEWPORTS", 1048593),
+    ("DEVICE_SETHARDWAREPROTECTION_INVALIDCONTEXT", 3146224),
+    ("DEVICE_SETRESOURCEMINLOD_INVALIDCONTEXT", 2097363),
+    ("DEVICE_SETRESOURCEMINLOD_INVALIDMINLOD", 2097365),
+    ("DEVICE_SETRESOURCEMINLOD_INVALIDRESOURCE", 2097364),
+    ("DEVICE_SETSHADER_INSTANCE_DATA_BINDINGS", 2097311),
+    ("DEVICE_SETSHADER_INTERFACES_FEATURELEVEL", 2097304),
+    ("DEVICE_SETSHADER_INTERFACE_COUNT_MISMATCH", 2097305),
+    ("DEVICE_SETSHADER_INVALID_INSTANCE", 2097306),
+    ("DEVICE_SETSHADER_INVALID_INSTANCE_DATA", 2097309),
+    ("DEVICE_SETSHADER_INVALID_INSTANCE_INDEX", 2097307),
+    ("DEVICE_SETSHADER_INVALID_INSTANCE_TYPE", 2097308),
+    ("DEVICE_SETSHADER_UNBOUND_INSTANCE_DATA", 2097310),
+    ("DEVICE_SETTEXTFILTERSIZE_INVALIDDIMENSIONS", 389),
+    ("DEVICE_SHADERRESOURCEVIEW_BUFFER_TYPE_MISMATCH", 2097393),
+    ("DEVICE_SHADERRESOURCEVIEW_RAW_UNSUPPORTED", 2097394),
+    ("DEVICE_SHADERRESOURCEVIEW_STRUCTURE_STRIDE_MISMATCH", 2097392),
+    ("DEVICE_SHADER_LINKAGE_COMPONENTTYPE", 344),
+    ("DEVICE_SHADER_LINKAGE_MINPRECISION", 3146050),
+    ("DEVICE_SHADER_LINKAGE_NEVERWRITTEN_ALWAYSREADS", 347),
+    ("DEVICE_SHADER_LINKAGE_REGISTERINDEX", 343),
+    ("DEVICE_SHADER_LINKAGE_REGISTERMASK", 345),
+    ("DEVICE_SHADER_LINKAGE_SEMANTICNAME_NOT_FOUND", 342),
+    ("DEVICE_SHADER_LINKAGE_SYSTEMVALUE", 346),
+    ("DEVICE_SOGETTARGETS_BUFFERS_EMPTY", 271),
+    ("DEVICE_SOSETTARGETS_HAZARD", 10),
+    ("DEVICE_SOSETTARGETS_OFFSET_UNALIGNED", 254),
+    ("DEVICE_UNORDEREDACCESSVIEW_APPEND_UNSUPPORTED", 2097376),
+    ("DEVICE_UNORDEREDACCESSVIEW_ATOMICS_UNSUPPORTED", 2097377),
+    ("DEVICE_UNORDEREDACCESSVIEW_ATOMIC_ADD_UNSUPPORTED", 2097383),
+    ("DEVICE_UNORDEREDACCESSVIEW_ATOMIC_BITWISE_OPS_UNSUPPORTED", 2097384),
+    ("DEVICE_UNORDEREDACCESSVIEW_ATOMIC_CMPSTORE_CMPEXCHANGE_UNSUPPORTED", 2097385),
+    ("DEVICE_UNORDEREDACCESSVIEW_ATOMIC_EXCHANGE_UNSUPPORTED", 2097386),
+    ("DEVICE_UNORDEREDACCESSVIEW_ATOMIC_SIGNED_MINMAX_UNSUPPORTED", 2097387),
+    ("DEVICE_UNORDEREDACCESSVIEW_ATOMIC_UNSIGNED_MINMAX_UNSUPPORTED", 2097388),
+    ("DEVICE_UNORDEREDACCESSVIEW_BUFFER_TYPE_MISMATCH", 2097379),
+    ("DEVICE_UNORDEREDACCESSVIEW_COUNTER_UNSUPPORTED", 2097406),
+    ("DEVICE_UNORDEREDACCESSVIEW_DIMENSION_MISMATCH", 2097375),
+    ("DEVICE_UNORDEREDACCESSVIEW_FORMAT_LD_UNSUPPORTED", 2097381),
+    ("DEVICE_UNORDEREDACCESSVIEW_FORMAT_STORE_UNSUPPORTED", 2097382),
+    ("DEVICE_UNORDEREDACCESSVIEW_NOT_SET", 2097373),
+    ("DEVICE_UNORDEREDACCESSVIEW_NOT_SET_DUE_TO_FLIP_PRESENT", 3146083),
+    ("DEVICE_UNORDEREDACCESSVIEW_RAW_UNSUPPORTED", 2097380),
+    ("DEVICE_UNORDEREDACCESSVIEW_RETURN_TYPE_MISMATCH", 2097372),
+    ("DEVICE_UNORDEREDACCESSVIEW_STRUCTURE_STRIDE_MISMATCH", 2097378),
+    ("DEVICE_VSGETCONSTANTBUFFERS_BUFFERS_EMPTY", 266),
+    ("DEVICE_VSGETSAMPLERS_SAMPLERS_EMPTY", 267),
+    ("DEVICE_VSGETSHADERRESOURCES_VIEWS_EMPTY", 265),
+    ("DEVICE_VSSETCONSTANTBUFFERS_BUFFERS_EMPTY", 247),
+    ("DEVICE_VSSETCONSTANTBUFFERS_HAZARD", 4),
+    ("DEVICE_VSSETSAMPLERS_SAMPLERS_EMPTY", 248),
+    ("DEVICE_VSSETSHADERRESOURCES_HAZARD", 3),
+    ("DEVICE_VSSETSHADERRESOURCES_VIEWS_EMPTY", 245),
+    ("DIRTY_TILE_MAPPING_ACCESS", 3146133),
+    ("DRAWINDEXEDINSTANCED_NOT_SUPPORTED_BELOW_9_3", 1048627),
+    ("DRAWINDEXED_POINTLIST_UNSUPPORTED", 1048628),
+    ("DRAWINDEXED_STARTINDEXLOCATION_MUST_BE_POSITIVE", 1048602),
+    ("DRAWINSTANCED_NOT_SUPPORTED", 1048626),
+    ("DSSETCONSTANTBUFFERS_INVALIDBUFFER", 2097199),
+    ("DSSETCONSTANTBUFFERS_INVALIDBUFFEROFFSETORCOUNT", 3146017),
+    ("DSSETCONSTANTBUFFERS_UNBINDDELETINGOBJECT", 2097192),
+    ("DSSETSAMPLERS_UNBINDDELETINGOBJECT", 2097414),
+    ("DSSETSHADERRESOURCES_UNBINDDELETINGOBJECT", 2097191),
+    ("DSSETSHADER_UNBINDDELETINGOBJECT", 2097417),
+    ("DUPLICATE_TILE_MAPPINGS_IN_COVERED_AREA", 3146134),
+    ("ENCRYPTIONBLT_DST_MAPPED", 3145969),
+    ("ENCRYPTIONBLT_DST_NOT_STAGING", 3145967),
+    ("ENCRYPTIONBLT_DST_OFFERED", 3145971),
+    ("ENCRYPTIONBLT_DST_WRONGDEVICE", 3145963),
+    ("ENCRYPTIONBLT_FORMAT_MISMATCH", 3145964),
+    ("ENCRYPTIONBLT_NULLPARAM", 3145961),
+    ("ENCRYPTIONBLT_SIZE_MISMATCH", 3145965),
+    ("ENCRYPTIONBLT_SRC_CONTENT_UNDEFINED", 3145972),
+    ("ENCRYPTIONBLT_SRC_MAPPED", 3145968),
+    ("ENCRYPTIONBLT_SRC_MULTISAMPLED", 3145966),
+    ("ENCRYPTIONBLT_SRC_OFFERED", 3145970),
+    ("ENCRYPTIONBLT_SRC_WRONGDEVICE", 3145962),
+    ("ENCRYPTIONBLT_UNSUPPORTED", 3145960),
+    ("END_TRACKED_WORKLOAD_INVALID_ARG", 3146282),
+    ("ENQUEUESETEVENT_ACCESSDENIED_RETURN", 2097421),
+    ("ENQUEUESETEVENT_INVALIDARG_RETURN", 2097419),
+    ("ENQUEUESETEVENT_NOT_SUPPORTED", 3146070),
+    ("ENQUEUESETEVENT_OUTOFMEMORY_RETURN", 2097420),
+    ("FINISHDISPLAYLIST_INVALID_CALL_RETURN", 2097168),
+    ("FINISHDISPLAYLIST_ONIMMEDIATECONTEXT", 2097166),
+    ("FINISHDISPLAYLIST_OUTOFMEMORY_RETURN", 2097167),
+    ("FINISHSESSIONKEYREFRESH_NULLPARAM", 3145989),
+    ("FLUSH1_INVALIDCONTEXTTYPE", 3146223),
+    ("GEOMETRY_SHADER_NOT_SUPPORTED", 1048619),
+    ("GETAUTHENTICATEDCHANNELCERTIFICATESIZE_INVALIDCHANNEL", 3145999),
+    ("GETAUTHENTICATEDCHANNELCERTIFICATESIZE_NULLPARAM", 3146000),
+    ("GETAUTHENTICATEDCHANNELCERTIFICATE_INVALIDCHANNEL", 3146001),
+    ("GETAUTHENTICATEDCHANNELCERTIFICATE_NULLPARAM", 3146002),
+    ("GETAUTHENTICATEDCHANNELCERTIFICATE_WRONGSIZE", 3146003),
+    ("GETCONTENTPROTECTIONCAPS_NULLPARAM", 3145992),
+    ("GETCRYPTOSESSIONCERTIFICATESIZE_NULLPARAM", 3145955),
+    ("GETCRYPTOSESSIONCERTIFICATE_NULLPARAM", 3145956),
+    ("GETCRYPTOSESSIONCERTIFICATE_WRONGSIZE", 3145957),
+    ("GETCRYPTOSESSIONHANDLE_OUTOFMEMORY", 3146025),
+    ("GETCRYPTOSESSIONHANDLE_WRONGSIZE", 3145958),
+    ("GETCRYPTOSESSIONPRIVATEDATASIZE_INVALID_KEY_EXCHANGE_TYPE", 3146116),
+    ("GETCRYPTOSESSIONPRIVATEDATASIZE_NULLPARAM", 3146086),
+    ("GETCRYPTOTYPE_NULLPARAM", 3145953),
+    ("GETDATAFORNEWHARDWAREKEY_NULLPARAM", 3146084),
+    ("GETDC_INACCESSIBLE", 3146065),
+    ("GETDECODERBUFFER_INVALIDBUFFER", 3145778),
+    ("GETDECODERBUFFER_INVALIDTYPE", 3145779),
+    ("GETDECODERBUFFER_LOCKED", 3145780),
+    ("GETDECODERBUFFER_NULLPARAM", 3145777),
+    ("GETDECODERCREATIONPARAMS_NULLPARAM", 3145775),
+    ("GETDECODERDRIVERHANDLE_NULLPARAM", 3145776),
+    ("GETDECODERPROFILE_NULLPARAM", 3145954),
+    ("GETENCRYPTIONBLTKEY_INVALIDSIZE", 3145991),
+    ("GETENCRYPTIONBLTKEY_NULLPARAM", 3145990),
+    ("GETPRIVATEDATA_MOREDATA", 51),
+    ("GETRESOURCETILING_NONTILED_RESOURCE", 3146139),
+    ("GETVIDEODECODERCAPS_NULLPARAM", 3146087),
+    ("GETVIDEODECODERCAPS_ZEROWIDTHHEIGHT", 3146088),
+    ("GETVIDEODECODERCONFIGCOUNT_NULLPARAM", 3145770),
+    ("GETVIDEODECODERCONFIGCOUNT_OUTOFMEMORY_RETURN", 3145771),
+    ("GETVIDEODECODERCONFIG_INVALIDINDEX", 3145773),
+    ("GETVIDEODECODERCONFIG_NULLPARAM", 3145772),
+    ("GETVIDEODECODERCONFIG_OUTOFMEMORY_RETURN", 3145774),
+    ("GETVIDEODECODERPROFILECOUNT_OUTOFMEMORY", 3145764),
+    ("GETVIDEODECODERPROFILE_INVALIDINDEX", 3145766),
+    ("GETVIDEODECODERPROFILE_NULLPARAM", 3145765),
+    ("GETVIDEODECODERPROFILE_OUTOFMEMORY_RETURN", 3145767),
+    ("GETVIDEOPROCESSORCAPS_NULLPARAM", 3145800),
+    ("GETVIDEOPROCESSORCONTENTDESC_NULLPARAM", 3145798),
+    ("GETVIDEOPROCESSORCUSTOMRATE_INVALIDINDEX", 3145804),
+    ("GETVIDEOPROCESSORCUSTOMRATE_NULLPARAM", 3145803),
+    ("GETVIDEOPROCESSORFILTERRANGE_NULLPARAM", 3145805),
+    ("GETVIDEOPROCESSORFILTERRANGE_UNSUPPORTED", 3145806),
+    ("GETVIDEOPROCESSORRATECONVERSIONCAPS_INVALIDINDEX", 3145802),
+    ("GETVIDEOPROCESSORRATECONVERSIONCAPS_NULLPARAM", 3145801),
+    ("GSSETCONSTANTBUFFERS_INVALIDBUFFER", 250),
+    ("GSSETCONSTANTBUFFERS_INVALIDBUFFEROFFSETORCOUNT", 3146019),
+    ("GSSETCONSTANTBUFFERS_UNBINDDELETINGOBJECT", 39),
+    ("GSSETSAMPLERS_UNBINDDELETINGOBJECT", 40),
+    ("GSSETSHADERRESOURCES_UNBINDDELETINGOBJECT", 38),
+    ("GSSETSHADER_UNBINDDELETINGOBJECT", 37),
+    ("HSSETCONSTANTBUFFERS_INVALIDBUFFER", 2097183),
+    ("HSSETCONSTANTBUFFERS_INVALIDBUFFEROFFSETORCOUNT", 3146018),
+    ("HSSETCONSTANTBUFFERS_UNBINDDELETINGOBJECT", 2097176),
+    ("HSSETSAMPLERS_UNBINDDELETINGOBJECT", 2097413),
+    ("HSSETSHADERRESOURCES_UNBINDDELETINGOBJECT", 2097175),
+    ("HSSETSHADER_UNBINDDELETINGOBJECT", 2097416),
+    ("IASETINDEXBUFFER_INVALIDBUFFER", 241),
+    ("IASETINDEXBUFFER_UNBINDDELETINGOBJECT", 32),
+    ("IASETINPUTLAYOUT_UNBINDDELETINGOBJECT", 30),
+    ("IASETVERTEXBUFFERS_BAD_BUFFER_INDEX", 1048592),
+    ("IASETVERTEXBUFFERS_INVALIDBUFFER", 238),
+    ("IASETVERTEXBUFFERS_UNBINDDELETINGOBJECT", 31),
+    ("INCOMPLETE_TRACKED_WORKLOAD_PAIR", 3146276),
+    ("JPEGDECODE_1DESTUNSUPPORTEDFORMAT", 3146194),
+    ("JPEGDECODE_3DESTUNSUPPORTEDFORMAT", 3146195),
+    ("JPEGDECODE_BACKBUFFERNOTSUPPORTED", 3146203),
+    ("JPEGDECODE_CHROMASIZEMISMATCH", 3146190),
+    ("JPEGDECODE_DESTBOXESINTERSECT", 3146182),
+    ("JPEGDECODE_DESTBOXNOT2D", 3146180),
+    ("JPEGDECODE_DESTBOXNOTSUB", 3146181),
+    ("JPEGDECODE_DESTINATIONNOT2D", 3146173),
+    ("JPEGDECODE_DIMENSIONSTOOLARGE", 3146171),
+    ("JPEGDECODE_EMPTYDESTBOX", 3146179),
+    ("JPEGDECODE_FORMATUNSUPPORTED", 3146176),
+    ("JPEGDECODE_FRACTIONALDOWNSCALETOLARGE", 3146189),
+    ("JPEGDECODE_GUARDRECTSUNSUPPORTED", 3146175),
+    ("JPEGDECODE_HAZARD", 3146199),
+    ("JPEGDECODE_INVALIDCOMPONENTS", 3146172),
+    ("JPEGDECODE_INVALIDCOPYFLAGS", 3146198),
+    ("JPEGDECODE_INVALIDMIPLEVEL", 3146178),
+    ("JPEGDECODE_INVALIDNUMDESTINATIONS", 3146192),
+    ("JPEGDECODE_INVALIDSCANDATAOFFSET", 3146169),
+    ("JPEGDECODE_INVALIDSOURCESIZE", 3146197),
+    ("JPEGDECODE_INVALIDSUBRESOURCE", 3146177),
+    ("JPEGDECODE_LUMACHROMASIZEMISMATCH", 3146191),
+    ("JPEGDECODE_NONPOW2SCALEUNSUPPORTED", 3146188),
+    ("JPEGDECODE_NOTSUPPORTED", 3146170),
+    ("JPEGDECODE_OUTPUTDIMENSIONSTOOLARGE", 3146187),
+    ("JPEGDECODE_SCALEUNSUPPORTED", 3146196),
+    ("JPEGDECODE_SUBBOXUNSUPPORTED", 3146193),
+    ("JPEGDECODE_TILEDRESOURCESUNSUPPORTED", 3146174),
+    ("JPEGDECODE_UNSUPPORTEDDSTTEXTUREUSAGE", 3146202),
+    ("JPEGDECODE_UNSUPPORTEDSRCBUFFERMISCFLAGS", 3146201),
+    ("JPEGDECODE_UNSUPPORTEDSRCBUFFERUSAGE", 3146200),
+    ("JPEGDECODE_UNSUPPRTEDCOPYFLAGS", 3146204),
+    ("JPEGDECODE_XSUBSAMPLEMISMATCH", 3146183),
+    ("JPEGDECODE_XSUBSAMPLEODD", 3146185),
+    ("JPEGDECODE_YSUBSAMPLEMISMATCH", 3146184),
+    ("JPEGDECODE_YSUBSAMPLEODD", 3146186),
+    ("JPEGENCODE_BACKBUFFERNOTSUPPORTED", 3146221),
+    ("JPEGENCODE_DIMENSIONSTOOLARGE", 3146216),
+    ("JPEGENCODE_FORMATUNSUPPORTED", 3146213),
+    ("JPEGENCODE_GUARDRECTSUNSUPPORTED", 3146210),
+    ("JPEGENCODE_HAZARD", 3146217),
+    ("JPEGENCODE_INVALIDCOMPONENTS", 3146207),
+    ("JPEGENCODE_INVALIDMIPLEVEL", 3146215),
+    ("JPEGENCODE_INVALIDSCANDATAOFFSET", 3146206),
+    ("JPEGENCODE_INVALIDSUBRESOURCE", 3146214),
+    ("JPEGENCODE_NOTSUPPORTED", 3146205),
+    ("JPEGENCODE_SOURCENOT2D", 3146208),
+    ("JPEGENCODE_TILEDRESOURCESUNSUPPORTED", 3146209),
+    ("JPEGENCODE_UNSUPPORTEDDSTBUFFERMISCFLAGS", 3146219),
+    ("JPEGENCODE_UNSUPPORTEDDSTBUFFERUSAGE", 3146218),
+    ("JPEGENCODE_UNSUPPORTEDSRCTEXTUREUSAGE", 3146220),
+    ("JPEGENCODE_XSUBSAMPLEMISMATCH", 3146211),
+    ("JPEGENCODE_YSUBSAMPLEMISMATCH", 3146212),
+    ("LIVE_AUTHENTICATEDCHANNEL", 3146150),
+    ("LIVE_BLENDSTATE", 435),
+    ("LIVE_BLENDSTATE_WIN7", 2097271),
+    ("LIVE_BUFFER", 423),
+    ("LIVE_BUFFER_WIN7", 2097229),
+    ("LIVE_CLASSINSTANCE", 2097291),
+    ("LIVE_CLASSLINKAGE", 2097294),
+    ("LIVE_COMMANDLIST", 2097288),
+    ("LIVE_COMPUTESHADER", 2097299),
+    ("LIVE_CONTEXT", 2097226),
+    ("LIVE_COUNTER", 440),
+    ("LIVE_CRYPTOSESSION", 3146149),
+    ("LIVE_DECODEROUTPUTVIEW", 3145739),
+    ("LIVE_DEPTHSTENCILSTATE", 436),
+    ("LIVE_DEPTHSTENCILSTATE_WIN7", 2097274),
+    ("LIVE_DEPTHSTENCILVIEW", 429),
+    ("LIVE_DEPTHSTENCILVIEW_WIN7", 2097247),
+    ("LIVE_DEVICE", 441),
+    ("LIVE_DEVICECONTEXTSTATE", 3145742),
+    ("LIVE_DEVICE_WIN7", 2097296),
+    ("LIVE_DOMAINSHADER", 2097256),
+    ("LIVE_FENCE", 3146251),
+    ("LIVE_GEOMETRYSHADER", 431),
+    ("LIVE_GEOMETRYSHADER_WIN7", 2097259),
+    ("LIVE_HULLSHADER", 2097253),
+    ("LIVE_INPUTLAYOUT", 433),
+    ("LIVE_INPUTLAYOUT_WIN7", 2097265),
+    ("LIVE_OBJECT_SUMMARY", 422),
+    ("LIVE_OBJECT_SUMMARY_WIN7", 2097297),
+    ("LIVE_PIXELSHADER", 432),
+    ("LIVE_PIXELSHADER_WIN7", 2097262),
+    ("LIVE_PREDICATE", 439),
+    ("LIVE_PREDICATE_WIN7", 2097283),
+    ("LIVE_PROCESSORINPUTVIEW", 3145740),
+    ("LIVE_PROCESSOROUTPUTVIEW", 3145741),
+    ("LIVE_QUERY", 438),
+    ("LIVE_QUERY_WIN7", 2097280),
+    ("LIVE_RASTERIZERSTATE", 437),
+    ("LIVE_RASTERIZERSTATE_WIN7", 2097277),
+    ("LIVE_RENDERTARGETVIEW", 428),
+    ("LIVE_RENDERTARGETVIEW_WIN7", 2097244),
+    ("LIVE_SAMPLER", 434),
+    ("LIVE_SAMPLER_WIN7", 2097268),
+    ("LIVE_SHADERRESOURCEVIEW", 427),
+    ("LIVE_SHADERRESOURCEVIEW_WIN7", 2097241),
+    ("LIVE_SWAPCHAIN", 442),
+    ("LIVE_SYNCHRONIZEDCHANNEL", 3146254),
+    ("LIVE_TEXTURE1D", 424),
+    ("LIVE_TEXTURE1D_WIN7", 2097232),
+    ("LIVE_TEXTURE2D", 425),
+    ("LIVE_TEXTURE2D_WIN7", 2097235),
+    ("LIVE_TEXTURE3D", 426),
+    ("LIVE_TEXTURE3D_WIN7", 2097238),
+    ("LIVE_TRACKEDWORKLOAD", 3146268),
+    ("LIVE_UNORDEREDACCESSVIEW", 2097302),
+    ("LIVE_VERTEXSHADER", 430),
+    ("LIVE_VERTEXSHADER_WIN7", 2097250),
+    ("LIVE_VIDEODECODER", 3145736),
+    ("LIVE_VIDEOPROCESSOR", 3145738),
+    ("LIVE_VIDEOPROCESSORENUM", 3145737),
+    ("MESSAGE_REPORTING_OUTOFMEMORY", 29),
+    ("MULTIPLE_TRACKED_WORKLOADS", 3146274),
+    ("MULTIPLE_TRACKED_WORKLOAD_PAIRS", 3146275),
+    ("NEED_TO_CALL_TILEDRESOURCEBARRIER", 3146141),
+    ("NEGOTIATEAUTHENTICATEDCHANNELKEYEXCHANGE_INVALIDCHANNEL", 3146004),
+    ("NEGOTIATEAUTHENTICATEDCHANNELKEYEXCHANGE_INVALIDSIZE", 3146023),
+    ("NEGOTIATEAUTHENTICATEDCHANNELKEYEXCHANGE_NULLPARAM", 3146005),
+    ("NEGOTIATECRPYTOSESSIONKEYEXCHANGE_INVALIDSIZE", 3146022),
+    ("NEGOTIATECRPYTOSESSIONKEYEXCHANGE_NULLPARAM", 3145959),
+    ("NEGOTIATECRYPTOSESSIONKEYEXCHANGEMT_INVALIDKEYEXCHANGETYPE", 3146259),
+    ("NEGOTIATECRYPTOSESSIONKEYEXCHANGEMT_NOT_SUPPORTED", 3146260),
+    ("NO_TRACKED_WORKLOAD_SLOT_AVAILABLE", 3146281),
+    ("NULL_TILE_MAPPING_ACCESS_ERROR", 3146132),
+    ("NULL_TILE_MAPPING_ACCESS_WARNING", 3146131),
+    ("OFFERRELEASE_NOT_SUPPORTED", 3146071),
+    ("OFFERRESOURCES_INACCESSIBLE", 3146072),
+    ("OFFERRESOURCES_INVALIDPRIORITY", 3146024),
+    ("OFFERRESOURCES_INVALIDRESOURCE", 2097412),
+    ("OMSETBLENDSTATE_UNBINDDELETINGOBJECT", 47),
+    ("OMSETDEPTHSTENCILSTATE_UNBINDDELETINGOBJECT", 48),
+    ("OMSETDEPTHSTENCIL_UNBINDDELETINGOBJECT", 2097368),
+    ("OMSETRENDERTARGETS_INVALIDVIEW", 388),
+    ("OMSETRENDERTARGETS_NO_DIFFERING_BIT_DEPTHS", 1048591),
+    ("OMSETRENDERTARGETS_NO_SRGB_MRT", 1048636),
+    ("OMSETRENDERTARGETS_TOO_MANY_RENDER_TARGETS", 1048590),
+    ("OMSETRENDERTARGETS_UNBINDDELETINGOBJECT", 49),
+    ("OUT_OF_ORDER_TRACKED_WORKLOAD_PAIR", 3146277),
+    ("PREDICATE_BEGIN_DURING_PREDICATION", 406),
+    ("PREDICATE_END_DURING_PREDICATION", 409),
+    ("PSSETCONSTANTBUFFERS_INVALIDBUFFER", 256),
+    ("PSSETCONSTANTBUFFERS_INVALIDBUFFEROFFSETORCOUNT", 3146020),
+    ("PSSETCONSTANTBUFFERS_UNBINDDELETINGOBJECT", 44),
+    ("PSSETSAMPLERS_TOO_MANY_SAMPLERS", 1048584),
+    ("PSSETSAMPLERS_UNBINDDELETINGOBJECT", 45),
+    ("PSSETSHADERRESOURCES_UNBINDDELETINGOBJECT", 43),
+    ("PSSETSHADER_UNBINDDELETINGOBJECT", 42),
+    ("PSSETUNORDEREDACCESSVIEWS_UNBINDDELETINGOBJECT", 2097350),
+    ("QUERYAUTHENTICATEDCHANNEL_INVALIDPROCESSINDEX", 3146010),
+    ("QUERYAUTHENTICATEDCHANNEL_NULLPARAM", 3146006),
+    ("QUERYAUTHENTICATEDCHANNEL_UNSUPPORTEDQUERY", 3146008),
+    ("QUERYAUTHENTICATEDCHANNEL_WRONGCHANNEL", 3146007),
+    ("QUERYAUTHENTICATEDCHANNEL_WRONGSIZE", 3146009),
+    ("QUERY_BEGIN_ABANDONING_PREVIOUS_RESULTS", 408),
+    ("QUERY_BEGIN_DUPLICATE", 407),
+    ("QUERY_BEGIN_UNSUPPORTED", 405),
+    ("QUERY_END_ABANDONING_PREVIOUS_RESULTS", 410),
+    ("QUERY_END_WITHOUT_BEGIN", 411),
+    ("QUERY_GETDATA_INVALID_CALL", 414),
+    ("QUERY_GETDATA_INVALID_DATASIZE", 412),
+    ("QUERY_GETDATA_INVALID_FLAGS", 413),
+    ("RECOMMENDVIDEODECODERDOWNSAMPLING_INVALIDCOLORSPACE", 3146107),
+    ("RECOMMENDVIDEODECODERDOWNSAMPLING_NULLPARAM", 3146106),
+    ("RECOMMENDVIDEODECODERDOWNSAMPLING_ZEROWIDTHHEIGHT", 3146108),
+    ("REF_ACCESSING_INDEXABLE_TEMP_OUT_OF_RANGE", 331),
+    ("REF_HARDWARE_EXCEPTION", 330),
+    ("REF_INFO", 334),
+    ("REF_KMDRIVER_EXCEPTION", 329),
+    ("REF_OUT_OF_MEMORY", 333),
+    ("REF_PROBLEM_PARSING_SHADER", 332),
+    ("REF_SIMULATING_INFINITELY_FAST_HARDWARE", 326),
+    ("REF_THREADING_MODE", 327),
+    ("REF_UMDRIVER_EXCEPTION", 328),
+    ("REF_WARNING", 2097407),
+    ("REF_WARNING_ATOMIC_INCONSISTENT", 3145946),
+    ("REF_WARNING_RAW_HAZARD", 3145948),
+    ("REF_WARNING_READING_UNINITIALIZED_RESOURCE", 3145947),
+    ("REF_WARNING_WAR_HAZARD", 3145949),
+    ("REF_WARNING_WAW_HAZARD", 3145950),
+    ("RELEASEDECODERBUFFER_INVALIDTYPE", 3145782),
+    ("RELEASEDECODERBUFFER_NOTLOCKED", 3145783),
+    ("RELEASEDECODERBUFFER_NULLPARAM", 3145781),
+    ("RESIZETILEPOOL_INVALID_PARAMETER", 3146129),
+    ("RESIZETILEPOOL_SHRINK_WITH_MAPPINGS_STILL_DEFINED_PAST_END", 3146140),
+    ("RESOURCE_MAP_ALREADYMAPPED", 2097213),
+    ("RESOURCE_MAP_DEVICEREMOVED_RETURN", 2097214),
+    ("RESOURCE_MAP_INVALIDFLAGS", 2097212),
+    ("RESOURCE_MAP_INVALIDMAPTYPE", 2097210),
+    ("RESOURCE_MAP_INVALIDSUBRESOURCE", 2097211),
+    ("RESOURCE_MAP_OUTOFMEMORY_RETURN", 2097215),
+    ("RESOURCE_MAP_WITHOUT_INITIAL_DISCARD", 2097216),
+    ("RESOURCE_UNMAP_INVALIDSUBRESOURCE", 2097217),
+    ("RESOURCE_UNMAP_NOTMAPPED", 2097218),
+    ("RSSETSTATE_UNBINDDELETINGOBJECT", 46),
+    ("SETBLENDSTATE_SAMPLE_MASK_CANNOT_BE_ZERO", 1048629),
+    ("SETEXCEPTIONMODE_DEVICEREMOVED_RETURN", 325),
+    ("SETEXCEPTIONMODE_INVALIDARG_RETURN", 324),
+    ("SETEXCEPTIONMODE_UNRECOGNIZEDFLAGS", 323),
+    ("SETPREDICATION_INVALID_PREDICATE_STATE", 404),
+    ("SETPREDICATION_UNBINDDELETINGOBJECT", 50),
+    ("SETPRIVATEDATA_CHANGINGPARAMS", 55),
+    ("SETPRIVATEDATA_INVALIDFLAGS", 54),
+    ("SETPRIVATEDATA_INVALIDFREEDATA", 52),
+    ("SETPRIVATEDATA_INVALIDIUNKNOWN", 53),
+    ("SETPRIVATEDATA_OUTOFMEMORY", 56),
+    ("SHADERRESOURCEVIEW_GETDESC_LEGACY", 393),
+    ("SHADER_ABORT", 2097409),
+    ("SHADER_ERROR", 2097411),
+    ("SHADER_MESSAGE", 2097410),
+    ("SLOT_ZERO_MUST_BE_D3D10_INPUT_PER_VERTEX_DATA", 1048633),
+    ("SOSETTARGETS_INVALIDBUFFER", 253),
+    ("SOSETTARGETS_UNBINDDELETINGOBJECT", 41),
+    ("STARTSESSIONKEYREFRESH_INVALIDSIZE", 3145988),
+    ("STARTSESSIONKEYREFRESH_NULLPARAM", 3145987),
+    ("STREAM_OUT_NOT_SUPPORTED", 1048620),
+    ("STRING_FROM_APPLICATION", 11),
+    ("SUBMITDECODERBUFFERS_INVALIDTYPE", 3145788),
+    ("SUBMITDECODERBUFFERS_NULLPARAM", 3145787),
+    ("SWAPDEVICECONTEXTSTATE_NOTSUPPORTED", 3146063),
+    ("TEXTURE1D_MAP_ALREADYMAPPED", 303),
+    ("TEXTURE1D_MAP_DEVICEREMOVED_RETURN", 304),
+    ("TEXTURE1D_MAP_INVALIDFLAGS", 302),
+    ("TEXTURE1D_MAP_INVALIDMAPTYPE", 300),
+    ("TEXTURE1D_MAP_INVALIDSUBRESOURCE", 301),
+    ("TEXTURE1D_UNMAP_INVALIDSUBRESOURCE", 305),
+    ("TEXTURE1D_UNMAP_NOTMAPPED", 306),
+    ("TEXTURE2D_MAP_ALREADYMAPPED", 310),
+    ("TEXTURE2D_MAP_DEVICEREMOVED_RETURN", 311),
+    ("TEXTURE2D_MAP_INVALIDFLAGS", 309),
+    ("TEXTURE2D_MAP_INVALIDMAPTYPE", 307),
+    ("TEXTURE2D_MAP_INVALIDSUBRESOURCE", 308),
+    ("TEXTURE2D_UNMAP_INVALIDSUBRESOURCE", 312),
+    ("TEXTURE2D_UNMAP_NOTMAPPED", 313),
+    ("TEXTURE3D_MAP_ALREADYMAPPED", 317),
+    ("TEXTURE3D_MAP_DEVICEREMOVED_RETURN", 318),
+    ("TEXTURE3D_MAP_INVALIDFLAGS", 316),
+    ("TEXTURE3D_MAP_INVALIDMAPTYPE", 314),
+    ("TEXTURE3D_MAP_INVALIDSUBRESOURCE", 315),
+    ("TEXTURE3D_UNMAP_INVALIDSUBRESOURCE", 319),
+    ("TEXTURE3D_UNMAP_NOTMAPPED", 320),
+    ("TEXT_FILTER_NOT_SUPPORTED", 1048621),
+    ("TILEDRESOURCEBARRIER_INVALID_PARAMETER", 3146130),
+    ("TILED_RESOURCE_TIER_1_BUFFER_TEXTURE_MISMATCH", 3146146),
+    ("TILE_MAPPINGS_IN_COVERED_AREA_DUPLICATED_OUTSIDE", 3146135),
+    ("TILE_MAPPINGS_SHARED_BETWEEN_INCOMPATIBLE_RESOURCES", 3146136),
+    ("TILE_MAPPINGS_SHARED_BETWEEN_INPUT_AND_OUTPUT", 3146137),
+    ("TRACKED_WORKLOAD_DISJOINT_FAILURE", 3146283),
+    ("TRACKED_WORKLOAD_ENGINE_TYPE_NOT_FOUND", 3146280),
+    ("TRACKED_WORKLOAD_NOT_SUPPORTED", 3146279),
+    ("UNKNOWN", 0),
+    ("UPDATESUBRESOURCE1_INVALIDCOPYFLAGS", 3145756),
+    ("UPDATESUBRESOURCE_EMPTYDESTBOX", 3146077),
+    ("UPDATESUBRESOURCE_INVALIDDESTINATIONBOX", 288),
+    ("UPDATESUBRESOURCE_INVALIDDESTINATIONSTATE", 289),
+    ("UPDATESUBRESOURCE_INVALIDDESTINATIONSUBRESOURCE", 287),
+    ("UPDATESUBRESOURCE_PREFERUPDATESUBRESOURCE1", 3146064),
+    ("UPDATETILEMAPPINGS_INVALID_PARAMETER", 3146125),
+    ("UPDATETILES_INVALID_PARAMETER", 3146128),
+    ("USE_OF_ZERO_REFCOUNT_OBJECT", 2097423),
+    ("VIDEODECODERENABLEDOWNSAMPLING_NULLPARAM", 3146092),
+    ("VIDEODECODERENABLEDOWNSAMPLING_UNSUPPORTED", 3146093),
+    ("VIDEODECODERUPDATEDOWNSAMPLING_NULLPARAM", 3146094),
+    ("VIDEODECODERUPDATEDOWNSAMPLING_UNSUPPORTED", 3146095),
+    ("VIDEOPROCESSORBLT_INPUTHAZARD", 3145905),
+    ("VIDEOPROCESSORBLT_INVALIDARRAY", 3145899),
+    ("VIDEOPROCESSORBLT_INVALIDARRAYSIZE", 3145898),
+    ("VIDEOPROCESSORBLT_INVALIDDESTRECT", 3145896),
+    ("VIDEOPROCESSORBLT_INVALIDFUTUREFRAMES", 3145894),
+    ("VIDEOPROCESSORBLT_INVALIDINPUTRESOURCE", 3145897),
+    ("VIDEOPROCESSORBLT_INVALIDOUTPUT", 3145892),
+    ("VIDEOPROCESSORBLT_INVALIDPASTFRAMES", 3145893),
+    ("VIDEOPROCESSORBLT_INVALIDRIGHTRESOURCE", 3145903),
+    ("VIDEOPROCESSORBLT_INVALIDSOURCERECT", 3145895),
+    ("VIDEOPROCESSORBLT_INVALIDSTREAMCOUNT", 3145890),
+    ("VIDEOPROCESSORBLT_NOSTEREOSTREAMS", 3145904),
+    ("VIDEOPROCESSORBLT_NULLPARAM", 3145889),
+    ("VIDEOPROCESSORBLT_OUTPUTHAZARD", 3145906),
+    ("VIDEOPROCESSORBLT_RIGHTEXPECTED", 3145900),
+    ("VIDEOPROCESSORBLT_RIGHTNOTEXPECTED", 3145901),
+    ("VIDEOPROCESSORBLT_STEREONOTENABLED", 3145902),
+    ("VIDEOPROCESSORBLT_TARGETRECT", 3145891),
+    ("VIDEOPROCESSORGETBEHAVIORHINTS_INVALIDDESTRECT", 3146115),
+    ("VIDEOPROCESSORGETBEHAVIORHINTS_INVALIDSOURCERECT", 3146114),
+    ("VIDEOPROCESSORGETBEHAVIORHINTS_INVALIDSTREAMCOUNT", 3146112),
+    ("VIDEOPROCESSORGETBEHAVIORHINTS_NULLPARAM", 3146111),
+    ("VIDEOPROCESSORGETBEHAVIORHINTS_TARGETRECT", 3146113),
+    ("VIDEOPROCESSORGETOUTPUTALPHAFILLMODE_NULLPARAM", 3145824),
+    ("VIDEOPROCESSORGETOUTPUTBACKGROUNDCOLOR_NULLPARAM", 3145822),
+    ("VIDEOPROCESSORGETOUTPUTCOLORSPACE1_NULLPARAM", 3146098),
+    ("VIDEOPROCESSORGETOUTPUTCOLORSPACE_NULLPARAM", 3145823),
+    ("VIDEOPROCESSORGETOUTPUTCONSTRICTION_NULLPARAM", 3145825),
+    ("VIDEOPROCESSORGETOUTPUTEXTENSION_NULLPARAM", 3145829),
+    ("VIDEOPROCESSORGETOUTPUTHDRMETADATA_INVALIDSIZE", 3146228),
+    ("VIDEOPROCESSORGETOUTPUTHDRMETADATA_NULLPARAM", 3146227),
+    ("VIDEOPROCESSORGETOUTPUTSHADERUSAGE_NULLPARAM", 3146110),
+    ("VIDEOPROCESSORGETOUTPUTSTEREOMODE_NULLPARAM", 3145828),
+    ("VIDEOPROCESSORGETOUTPUTTARGETRECT_NULLPARAM", 3145821),
+    ("VIDEOPROCESSORGETSTREAMALPHA_INVALIDSTREAM", 3146240),
+    ("VIDEOPROCESSORGETSTREAMALPHA_NULLPARAM", 3145880),
+    ("VIDEOPROCESSORGETSTREAMAUTOPROCESSINGMODE_INVALIDSTREAM", 3146245),
+    ("VIDEOPROCESSORGETSTREAMAUTOPROCESSINGMODE_NULLPARAM", 3145885),
+    ("VIDEOPROCESSORGETSTREAMCOLORSPACE1_INVALIDSTREAM", 3146248),
+    ("VIDEOPROCESSORGETSTREAMCOLORSPACE1_NULLPARAM", 3146104),
+    ("VIDEOPROCESSORGETSTREAMCOLORSPACE_INVALIDSTREAM", 3146236),
+    ("VIDEOPROCESSORGETSTREAMCOLORSPACE_NULLPARAM", 3145876),
+    ("VIDEOPROCESSORGETSTREAMDESTRECT_INVALIDSTREAM", 3146239),
+    ("VIDEOPROCESSORGETSTREAMDESTRECT_NULLPARAM", 3145879),
+    ("VIDEOPROCESSORGETSTREAMEXTENSION_INVALIDSTREAM", 3145888),
+    ("VIDEOPROCESSORGETSTREAMEXTENSION_NULLPARAM", 3145887),
+    ("VIDEOPROCESSORGETSTREAMFILTER_INVALIDSTREAM", 3146246),
+    ("VIDEOPROCESSORGETSTREAMFILTER_NULLPARAM", 3145886),
+    ("VIDEOPROCESSORGETSTREAMFRAMEFORMAT_INVALIDSTREAM", 3146235),
+    ("VIDEOPROCESSORGETSTREAMFRAMEFORMAT_NULLPARAM", 3145875),
+    ("VIDEOPROCESSORGETSTREAMHDRMETADATA_INVALIDSIZE", 3146234),
+    ("VIDEOPROCESSORGETSTREAMHDRMETADATA_INVALIDSTREAM", 3146233),
+    ("VIDEOPROCESSORGETSTREAMHDRMETADATA_NULLPARAM", 3146232),
+    ("VIDEOPROCESSORGETSTREAMLUMAKEY_INVALIDSTREAM", 3146243),
+    ("VIDEOPROCESSORGETSTREAMLUMAKEY_NULLPARAM", 3145883),
+    ("VIDEOPROCESSORGETSTREAMMIRROR_INVALIDSTREAM", 3146249),
+    ("VIDEOPROCESSORGETSTREAMMIRROR_NULLPARAM", 3146105),
+    ("VIDEOPROCESSORGETSTREAMOUTPUTRATE_INVALIDSTREAM", 3146237),
+    ("VIDEOPROCESSORGETSTREAMOUTPUTRATE_NULLPARAM", 3145877),
+    ("VIDEOPROCESSORGETSTREAMPALETTE_INVALIDSTREAM", 3146241),
+    ("VIDEOPROCESSORGETSTREAMPALETTE_NULLPARAM", 3145881),
+    ("VIDEOPROCESSORGETSTREAMPIXELASPECTRATIO_INVALIDSTREAM", 3146242),
+    ("VIDEOPROCESSORGETSTREAMPIXELASPECTRATIO_NULLPARAM", 3145882),
+    ("VIDEOPROCESSORGETSTREAMROTATION_INVALIDSTREAM", 3146247),
+    ("VIDEOPROCESSORGETSTREAMROTATION_NULLPARAM", 3146034),
+    ("VIDEOPROCESSORGETSTREAMSOURCERECT_INVALIDSTREAM", 3146238),
+    ("VIDEOPROCESSORGETSTREAMSOURCERECT_NULLPARAM", 3145878),
+    ("VIDEOPROCESSORGETSTREAMSTEREOFORMAT_INVALIDSTREAM", 3146244),
+    ("VIDEOPROCESSORGETSTREAMSTEREOFORMAT_NULLPARAM", 3145884),
+    ("VIDEOPROCESSORSETOUTPUTALPHAFILLMODE_INVALIDFILLMODE", 3145816),
+    ("VIDEOPROCESSORSETOUTPUTALPHAFILLMODE_INVALIDSTREAM", 3145815),
+    ("VIDEOPROCESSORSETOUTPUTALPHAFILLMODE_NULLPARAM", 3145813),
+    ("VIDEOPROCESSORSETOUTPUTALPHAFILLMODE_UNSUPPORTED", 3145814),
+    ("VIDEOPROCESSORSETOUTPUTBACKGROUNDCOLOR_INVALIDALPHA", 3145811),
+    ("VIDEOPROCESSORSETOUTPUTBACKGROUNDCOLOR_NULLPARAM", 3145810),
+    ("VIDEOPROCESSORSETOUTPUTCOLORSPACE1_NULLPARAM", 3146097),
+    ("VIDEOPROCESSORSETOUTPUTCOLORSPACE_NULLPARAM", 3145812),
+    ("VIDEOPROCESSORSETOUTPUTCONSTRICTION_INVALIDSIZE", 3145827),
+    ("VIDEOPROCESSORSETOUTPUTCONSTRICTION_NULLPARAM", 3145817),
+    ("VIDEOPROCESSORSETOUTPUTCONSTRICTION_UNSUPPORTED", 3145826),
+    ("VIDEOPROCESSORSETOUTPUTEXTENSION_NULLPARAM", 3145820),
+    ("VIDEOPROCESSORSETOUTPUTHDRMETADATA_INVALIDSIZE", 3146226),
+    ("VIDEOPROCESSORSETOUTPUTHDRMETADATA_NULLPARAM", 3146225),
+    ("VIDEOPROCESSORSETOUTPUTSHADERUSAGE_NULLPARAM", 3146109),
+    ("VIDEOPROCESSORSETOUTPUTSTEREOMODE_NULLPARAM", 3145818),
+    ("VIDEOPROCESSORSETOUTPUTSTEREOMODE_UNSUPPORTED", 3145819),
+    ("VIDEOPROCESSORSETOUTPUTTARGETRECT_NULLPARAM", 3145809),
+    ("VIDEOPROCESSORSETSTREAMALPHA_INVALIDALPHA", 3145847),
+    ("VIDEOPROCESSORSETSTREAMALPHA_INVALIDSTREAM", 3145846),
+    ("VIDEOPROCESSORSETSTREAMALPHA_NULLPARAM", 3145845),
+    ("VIDEOPROCESSORSETSTREAMALPHA_UNSUPPORTED", 3146051),
+    ("VIDEOPROCESSORSETSTREAMAUTOPROCESSINGMODE_INVALIDSTREAM", 3145867),
+    ("VIDEOPROCESSORSETSTREAMAUTOPROCESSINGMODE_NULLPARAM", 3145866),
+    ("VIDEOPROCESSORSETSTREAMCOLORSPACE1_INVALIDSTREAM", 3146100),
+    ("VIDEOPROCESSORSETSTREAMCOLORSPACE1_NULLPARAM", 3146099),
+    ("VIDEOPROCESSORSETSTREAMCOLORSPACE_INVALIDSTREAM", 3145834),
+    ("VIDEOPROCESSORSETSTREAMCOLORSPACE_NULLPARAM", 3145833),
+    ("VIDEOPROCESSORSETSTREAMDESTRECT_INVALIDRECT", 3145844),
+    ("VIDEOPROCESSORSETSTREAMDESTRECT_INVALIDSTREAM", 3145843),
+    ("VIDEOPROCESSORSETSTREAMDESTRECT_NULLPARAM", 3145842),
+    ("VIDEOPROCESSORSETSTREAMEXTENSION_INVALIDSTREAM", 3145874),
+    ("VIDEOPROCESSORSETSTREAMEXTENSION_NULLPARAM", 3145873),
+    ("VIDEOPROCESSORSETSTREAMFILTER_INVALIDFILTER", 3145870),
+    ("VIDEOPROCESSORSETSTREAMFILTER_INVALIDLEVEL", 3145872),
+    ("VIDEOPROCESSORSETSTREAMFILTER_INVALIDSTREAM", 3145869),
+    ("VIDEOPROCESSORSETSTREAMFILTER_NULLPARAM", 3145868),
+    ("VIDEOPROCESSORSETSTREAMFILTER_UNSUPPORTED", 3145871),
+    ("VIDEOPROCESSORSETSTREAMFRAMEFORMAT_INVALIDFORMAT", 3145831),
+    ("VIDEOPROCESSORSETSTREAMFRAMEFORMAT_INVALIDSTREAM", 3145832),
+    ("VIDEOPROCESSORSETSTREAMFRAMEFORMAT_NULLPARAM", 3145830),
+    ("VIDEOPROCESSORSETSTREAMHDRMETADATA_INVALIDSIZE", 3146231),
+    ("VIDEOPROCESSORSETSTREAMHDRMETADATA_INVALIDSTREAM", 3146230),
+    ("VIDEOPROCESSORSETSTREAMHDRMETADATA_NULLPARAM", 3146229),
+    ("VIDEOPROCESSORSETSTREAMLUMAKEY_INVALIDRANGE", 3145857),
+    ("VIDEOPROCESSORSETSTREAMLUMAKEY_INVALIDSTREAM", 3145856),
+    ("VIDEOPROCESSORSETSTREAMLUMAKEY_NULLPARAM", 3145855),
+    ("VIDEOPROCESSORSETSTREAMLUMAKEY_UNSUPPORTED", 3145858),
+    ("VIDEOPROCESSORSETSTREAMMIRROR_INVALIDSTREAM", 3146102),
+    ("VIDEOPROCESSORSETSTREAMMIRROR_NULLPARAM", 3146101),
+    ("VIDEOPROCESSORSETSTREAMMIRROR_UNSUPPORTED", 3146103),
+    ("VIDEOPROCESSORSETSTREAMOUTPUTRATE_INVALIDFLAG", 3145837),
+    ("VIDEOPROCESSORSETSTREAMOUTPUTRATE_INVALIDRATE", 3145836),
+    ("VIDEOPROCESSORSETSTREAMOUTPUTRATE_INVALIDSTREAM", 3145838),
+    ("VIDEOPROCESSORSETSTREAMOUTPUTRATE_NULLPARAM", 3145835),
+    ("VIDEOPROCESSORSETSTREAMPALETTE_INVALIDALPHA", 3145851),
+    ("VIDEOPROCESSORSETSTREAMPALETTE_INVALIDCOUNT", 3145850),
+    ("VIDEOPROCESSORSETSTREAMPALETTE_INVALIDSTREAM", 3145849),
+    ("VIDEOPROCESSORSETSTREAMPALETTE_NULLPARAM", 3145848),
+    ("VIDEOPROCESSORSETSTREAMPIXELASPECTRATIO_INVALIDRATIO", 3145854),
+    ("VIDEOPROCESSORSETSTREAMPIXELASPECTRATIO_INVALIDSTREAM", 3145853),
+    ("VIDEOPROCESSORSETSTREAMPIXELASPECTRATIO_NULLPARAM", 3145852),
+    ("VIDEOPROCESSORSETSTREAMPIXELASPECTRATIO_UNSUPPORTED", 3146052),
+    ("VIDEOPROCESSORSETSTREAMROTATION_INVALID", 3146032),
+    ("VIDEOPROCESSORSETSTREAMROTATION_INVALIDSTREAM", 3146031),
+    ("VIDEOPROCESSORSETSTREAMROTATION_NULLPARAM", 3146030),
+    ("VIDEOPROCESSORSETSTREAMROTATION_UNSUPPORTED", 3146033),
+    ("VIDEOPROCESSORSETSTREAMSOURCERECT_INVALIDRECT", 3145841),
+    ("VIDEOPROCESSORSETSTREAMSOURCERECT_INVALIDSTREAM", 3145840),
+    ("VIDEOPROCESSORSETSTREAMSOURCERECT_NULLPARAM", 3145839),
+    ("VIDEOPROCESSORSETSTREAMSTEREOFORMAT_FLIPUNSUPPORTED", 3145862),
+    ("VIDEOPROCESSORSETSTREAMSTEREOFORMAT_FORMATUNSUPPORTED", 3145864),
+    ("VIDEOPROCESSORSETSTREAMSTEREOFORMAT_INVALIDFORMAT", 3145865),
+    ("VIDEOPROCESSORSETSTREAMSTEREOFORMAT_INVALIDSTREAM", 3145860),
+    ("VIDEOPROCESSORSETSTREAMSTEREOFORMAT_MONOOFFSETUNSUPPORTED", 3145863),
+    ("VIDEOPROCESSORSETSTREAMSTEREOFORMAT_NULLPARAM", 3145859),
+    ("VIDEOPROCESSORSETSTREAMSTEREOFORMAT_UNSUPPORTED", 3145861),
+    ("VSSETCONSTANTBUFFERS_INVALIDBUFFER", 246),
+    ("VSSETCONSTANTBUFFERS_INVALIDBUFFEROFFSETORCOUNT", 3146016),
+    ("VSSETCONSTANTBUFFERS_UNBINDDELETINGOBJECT", 35),
+    ("VSSETSAMPLERS_NOT_SUPPORTED", 1048582),
+    ("VSSETSAMPLERS_TOO_MANY_SAMPLERS", 1048583),
+    ("VSSETSAMPLERS_UNBINDDELETINGOBJECT", 36),
+    ("VSSETSHADERRESOURCES_UNBINDDELETINGOBJECT", 34),
+    ("VSSETSHADER_UNBINDDELETINGOBJECT", 33),
+    ("VSSHADERRESOURCES_NOT_SUPPORTED", 1048618),
+];
+
+/// Get the name of a D3D11 message id without the `D3D11_MESSAGE_ID_` prefix,
+/// like `OMSETRENDERTARGETS_INVALIDVIEW`
+pub fn id_to_name(id: i32) -> Option<&'static str> {
+    return lookup(id).map(|(_, name, _)| name);
+}
+
+/// Get the id of a D3D11 message name, with or without the `D3D11_MESSAGE_ID_` prefix
+pub fn name_to_id(name: &str) -> Option<i32> {
+    let name: &str = name.strip_prefix("D3D11_MESSAGE_ID_").unwrap_or(name);
+    return MESSAGE_IDS_BY_NAME
+        .binary_search_by(|(id_name, _)| (*id_name).cmp(name))
+        .ok()
+        .map(|index| MESSAGE_IDS_BY_NAME[index].1);
+}
+
+/// Get the API group of a D3D11 message id. Unknown ids are in [`ApiGroup::Other`]
+pub fn id_to_group(id: i32) -> ApiGroup {
+    return lookup(id).map_or(ApiGroup::Other, |(_, _, group)| group);
+}
+
+fn lookup(id: i32) -> Option<(i32, &'static str, ApiGroup)> {
+    return MESSAGE_IDS
+        .binary_search_by_key(&id, |(msg_id, _, _)| *msg_id)
+        .ok()
+        .map(|index| MESSAGE_IDS[index]);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tables_are_sorted_and_unique() {
+        for pair in MESSAGE_IDS.windows(2) {
+            assert!(pair[0].0 < pair[1].0, "{} isn't before {}", pair[0].1, pair[1].1);
+        }
+        for pair in MESSAGE_IDS_BY_NAME.windows(2) {
+            assert!(pair[0].0 < pair[1].0, "{} isn't before {}", pair[0].0, pair[1].0);
+        }
+    }
+
+    #[test]
+    fn tables_hold_the_same_ids() {
+        for (id, name, _) in MESSAGE_IDS {
+            assert_eq!(name_to_id(name), Some(id), "{}", name);
+        }
+        for (name, id) in MESSAGE_IDS_BY_NAME {
+            assert_eq!(id_to_name(id), Some(name), "{}", id);
+        }
+    }
+
+    #[test]
+    fn object_tracking_messages_are_grouped_together() {
+        for (id, name, group) in MESSAGE_IDS {
+            if ["CREATE_", "LIVE_", "DESTROY_"].iter().any(|prefix| name.starts_with(prefix)) {
+                assert_eq!(group, ApiGroup::ObjectLifetime, "{} {}", id, name);
+            }
+        }
+    }
+
+    #[test]
+    fn looks_up_ids_and_groups() {
+        assert_eq!(id_to_name(388), Some("OMSETRENDERTARGETS_INVALIDVIEW"));
+        assert_eq!(id_to_group(388), ApiGroup::OutputMerger);
+        assert_eq!(id_to_group(1), ApiGroup::InputAssembler);
+        assert_eq!(name_to_id("D3D11_MESSAGE_ID_OMSETRENDERTARGETS_INVALIDVIEW"), Some(388));
+        assert_eq!(id_to_name(-1), None);
+        assert_eq!(id_to_group(-1), ApiGroup::Other);
+        assert_eq!(name_to_id("NOT_A_MESSAGE"), None);
+    }
+}
@@ -1,1469 +1,282 @@
-use windows::Win32::Graphics::Dxgi::{DXGI_DEBUG_ALL, DXGI_INFO_QUEUE_MESSAGE};
+use std::{fs::File, io::BufWriter, path::Path};
 
-use crate::{loc, window::message};
+use crate::{log_warn, logger};
+
+use super::{
+    info_message::{self, InfoMessage, MessageFilter, Severity},
+    info_queue::{DrainCursor, Drained, DxgiInfoQueue, InfoQueue, InfoQueueConfig},
+    Error,
+};
 
 pub struct Manager {
-    pub start_index: u64,
-    pub info_queue: windows::Win32::Graphics::Dxgi::IDXGIInfoQueue,
+    pub queue: Box<dyn InfoQueue>,
+    /// Where [`Manager::mark()`] was last called
+    pub mark: DrainCursor,
+    /// The messages that were already forwarded to the log, see [`Manager::check()`]
+    pub cursor: DrainCursor,
+    /// Messages that don't pass this filter are left out of [`Manager::since_mark()`]
+    pub filter: MessageFilter,
+    /// See [`InfoQueueConfig::break_on`]
+    pub break_on: Option<Severity>,
 }
 
 impl Manager {
-    /// Create a new Manager
-    pub fn new() -> Manager {
-        let info_queue: windows::Win32::Graphics::Dxgi::IDXGIInfoQueue = unsafe {
-            windows::Win32::Graphics::Dxgi::DXGIGetDebugInterface1(0).unwrap_or_else(|e| {
-                super::graphics::HResultError::new(
-                    e.code(),
-                    loc!(),
-                    "Unable to get debugInterface",
-                );
-            })
-        };
+    /// Create a new Manager for the DXGI info queue
+    pub fn new() -> Result<Manager, Error> {
+        return Ok(Manager::with_queue(Box::new(DxgiInfoQueue::new()?)));
+    }
 
+    /// Create a Manager for another queue, like a [`super::info_queue::FakeInfoQueue`]
+    pub fn with_queue(queue: Box<dyn InfoQueue>) -> Manager {
         return Manager {
-            start_index: 0,
-            info_queue,
+            queue,
+            mark: DrainCursor::default(),
+            cursor: DrainCursor::default(),
+            filter: MessageFilter::all(),
+            break_on: None,
         };
     }
 
-    /// Sets msg_index to capture all of the errors that follow after this function is called
-    #[allow(dead_code)]
+    /// Set the message limit and deny lists of the queue and the severity to break on
+    pub fn configure(&mut self, config: &InfoQueueConfig) -> Result<(), Error> {
+        config.apply(self.queue.as_mut())?;
+        self.break_on = config.break_on;
+        return Ok(());
+    }
+
+    /// Sets the mark to capture all of the errors that follow after this function is called
     pub fn mark(&mut self) {
-        self.start_index = unsafe { self.info_queue.GetNumStoredMessages(DXGI_DEBUG_ALL) };
+        self.mark = DrainCursor::at_end(self.queue.as_ref());
     }
 
-    // https://learn.microsoft.com/en-us/windows/win32/api/d3d11sdklayers/ne-d3d11sdklayers-d3d11_message_id ???
-    pub fn get_messages(&self) -> Vec<String> {
-        let size: u64 = unsafe { self.info_queue.GetNumStoredMessages(DXGI_DEBUG_ALL) };
-        let mut messages: Vec<String> = Vec::new();
-        for i in self.start_index..size {
-            let info_msg_buffer = &mut DXGI_INFO_QUEUE_MESSAGE::default();
-            let mut message_length: usize = 0;
-            unsafe {
-                self.info_queue
-                    .GetMessage(DXGI_DEBUG_ALL, i, None, &mut message_length)
-                    .unwrap_or_else(|e| {
-                        super::graphics::HResultError::new(
-                            e.code(),
-                            loc!(),
-                            &e.message().to_string(),
-                        )
-                    })
-            };
+    /// All the messages that were stored since the last call to [`Manager::mark()`] and pass the filter.
+    /// Messages the queue already threw away are missing.
+    pub fn since_mark(&self) -> Result<Vec<InfoMessage>, Error> {
+        let drained: Drained = self.mark.peek(self.queue.as_ref())?;
+        return Ok(self.filter.apply(drained.messages));
+    }
 
-            // between these two 'GetMessage()' calls you have to use message_length or else 
-            // your error description will be complete fucking garbage
-            
-            messages.insert(messages.len(), format!("[MSG_LEN] {}", message_length));     
+    /// The messages of [`Manager::since_mark()`] formatted the way the debug layer prints them
+    pub fn get_messages(&self) -> Result<Vec<String>, Error> {
+        return Ok(self.since_mark()?.iter().map(|message| message.to_string()).collect());
+    }
 
-            unsafe {
-                self.info_queue
-                    .GetMessage(
-                        DXGI_DEBUG_ALL,
-                        i,
-                        Some(info_msg_buffer),
-                        &mut message_length,
-                    )
-                    .unwrap_or_else(|e| {
-                        super::graphics::HResultError::new(
-                            e.code(),
-                            loc!(),
-                            &e.message().to_string(),
-                        )
-                    })
-            };
+    /// Write the messages of [`Manager::since_mark()`] to a file as JSON lines
+    pub fn export_json_lines(&self, path: &Path) -> Result<(), Error> {
+        let messages: Vec<InfoMessage> = self.since_mark()?;
+        let mut out: BufWriter<File> = File::create(path)
+            .map(BufWriter::new)
+            .map_err(|e| Error::io(&format!("Unable to create {}", path.display()), e))?;
+        return info_message::export_json_lines(&messages, &mut out)
+            .map_err(|e| Error::io(&format!("Unable to write {}", path.display()), e));
+    }
 
-            messages.insert(messages.len(), format!("[MSG_ID] {}", info_msg_buffer.ID));
-            messages.insert(messages.len(), format!("[MSG_ID_INFO] {}", Self::d3d11_message_id_to_info(info_msg_buffer.ID)));
-            messages.insert(
-                messages.len(),
-                format!(
-                    "[CATEGORY_ID] {}",
-                    message::dx_category_id_to_str(info_msg_buffer.Category)
-                        .unwrap_or(info_msg_buffer.Category.0.to_string())
-                ),
+    /// Forward the messages that were stored since the last check to the logger, at the level that matches
+    /// their severity. The filter of the manager doesn't apply, the log has its own.
+    ///
+    /// When one of the messages is as bad as [`Manager::break_on`], the first one of them is returned as
+    /// an [`Error::DebugLayer`] that points at the caller, so check right after the calls that can fail.
+    #[track_caller]
+    pub fn check(&mut self) -> Result<(), Error> {
+        let drained: Drained = self.cursor.drain(self.queue.as_ref())?;
+        if drained.lost > 0 {
+            log_warn!(
+                "{} debug layer messages were thrown away before they could be read, raise the message limit",
+                drained.lost
             );
+        }
 
-            if message_length > 0 {
-                let char_vec = unsafe {
-                    std::slice::from_raw_parts(
-                        info_msg_buffer.pDescription,
-                        info_msg_buffer.DescriptionByteLength,
-                    )
-                };
-
-                messages.insert(
-                    messages.len(),
-                    format!(
-                        "[SEVERITY] {}",
-                        message::dx_severity_id_to_str(info_msg_buffer.Severity)
-                            .unwrap_or(info_msg_buffer.Severity.0.to_string())
-                    ),
-                );
+        for message in drained.messages.iter() {
+            logger::log(
+                message.severity.log_level(),
+                &message.producer.to_string().to_ascii_lowercase(),
+                format_args!("{}", message),
+            );
+        }
 
-                messages.insert(
-                    messages.len(),
-                    format!(
-                        "[DESCRIPTION]\n{}",
-                        &String::from_utf8(char_vec.to_vec()).unwrap_or(format!(
-                            "Message found but is corrupted [msglength: {}]\n{}",
-                            info_msg_buffer.DescriptionByteLength,
-                            String::from_utf8_lossy(char_vec)
-                        ))
-                    ),
-                );
-            } else {
-                messages.insert(
-                    messages.len(),
-                    "[DESCRIPTION] No message was found".to_string(),
-                );
+        if let Some(break_on) = self.break_on {
+            if let Some(message) = drained
+                .messages
+                .into_iter()
+                .find(|message| message.severity <= break_on)
+            {
+                return Err(Error::debug_layer(message));
             }
-            messages.insert(messages.len(), "\n".to_string());
         }
-        return messages;
+        return Ok(());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use super::*;
+    use crate::window::errors::{
+        info_message::{Category, Producer},
+        info_queue::FakeInfoQueue,
+    };
+
+    fn message(id: i32, severity: Severity) -> InfoMessage {
+        return InfoMessage {
+            producer: Producer::D3D11,
+            id,
+            name: String::new(),
+            category: Category::Execution,
+            severity,
+            description: format!("message {}", id),
+        };
+    }
+
+    /// A manager with a fake queue that the test can keep pushing to
+    fn fake_manager() -> (Manager, Rc<RefCell<FakeInfoQueue>>) {
+        let queue: Rc<RefCell<FakeInfoQueue>> = Rc::new(RefCell::new(FakeInfoQueue::new()));
+        return (Manager::with_queue(Box::new(queue.clone())), queue);
+    }
+
+    fn push(queue: &Rc<RefCell<FakeInfoQueue>>, messages: &[InfoMessage]) {
+        for message in messages {
+            queue.borrow_mut().push(message.clone());
+        }
+    }
+
+    fn ids(messages: &[InfoMessage]) -> Vec<i32> {
+        return messages.iter().map(|message| message.id).collect();
+    }
+
+    #[test]
+    fn since_mark_skips_the_messages_before_the_mark() {
+        let (mut manager, queue) = fake_manager();
+        push(&queue, &[message(1, Severity::Warning)]);
+        assert_eq!(ids(&manager.since_mark().unwrap()), vec![1]);
+
+        manager.mark();
+        assert!(manager.since_mark().unwrap().is_empty());
+        push(
+            &queue,
+            &[message(2, Severity::Error), message(3, Severity::Info)],
+        );
+        assert_eq!(ids(&manager.since_mark().unwrap()), vec![2, 3]);
+        // Looking at the messages doesn't move the mark
+        assert_eq!(ids(&manager.since_mark().unwrap()), vec![2, 3]);
+    }
+
+    #[test]
+    fn since_mark_applies_the_filter() {
+        let (mut manager, queue) = fake_manager();
+        manager.filter.deny_severities = vec![Severity::Info];
+        push(
+            &queue,
+            &[message(2, Severity::Error), message(3, Severity::Info)],
+        );
+        assert_eq!(ids(&manager.since_mark().unwrap()), vec![2]);
+        assert_eq!(
+            manager.get_messages().unwrap(),
+            vec!["D3D11 ERROR: message 2 [ EXECUTION ERROR #2:  ]"]
+        );
+    }
+
+    #[test]
+    fn exports_the_messages_since_the_mark() {
+        let (mut manager, queue) = fake_manager();
+        push(&queue, &[message(1, Severity::Warning)]);
+        manager.mark();
+        push(&queue, &[message(2, Severity::Error)]);
+
+        let path: std::path::PathBuf =
+            std::env::temp_dir().join(format!("dx_info_export_{}.jsonl", std::process::id()));
+        manager.export_json_lines(&path).unwrap();
+        let text: String = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(text.lines().count(), 1);
+        assert!(text.starts_with("{\"producer\":\"D3D11\",\"id\":2,"));
+    }
+
+    #[test]
+    fn check_reads_every_message_once() {
+        let (mut manager, queue) = fake_manager();
+        manager.break_on = Some(Severity::Error);
+        push(
+            &queue,
+            &[message(1, Severity::Warning), message(2, Severity::Error)],
+        );
+
+        let error: Error = manager.check().unwrap_err();
+        assert!(matches!(&error, Error::DebugLayer { message, .. } if message.id == 2));
+        // The error points at the check
+        assert_eq!(error.origin().file, file!());
+
+        // The error was already reported, the next check only sees new messages
+        assert!(manager.check().is_ok());
+        push(&queue, &[message(3, Severity::Warning)]);
+        assert!(manager.check().is_ok());
+        push(
+            &queue,
+            &[
+                message(4, Severity::Corruption),
+                message(5, Severity::Error),
+            ],
+        );
+        assert!(matches!(
+            manager.check(),
+            Err(Error::DebugLayer { message, .. }) if message.id == 4
+        ));
     }
 
-    pub fn d3d11_message_id_to_info(id: i32) -> String {
-        let map: std::collections::HashMap<i32, &str> = std::collections::hash_map::HashMap::from([
-            (0, "D3D11_MESSAGE_ID_UNKNOWN"),
-            (1, "D3D11_MESSAGE_ID_DEVICE_IASETVERTEXBUFFERS_HAZARD"),
-            (2, "D3D11_MESSAGE_ID_DEVICE_IASETINDEXBUFFER_HAZARD"),
-            (3, "D3D11_MESSAGE_ID_DEVICE_VSSETSHADERRESOURCES_HAZARD"),
-            (4, "D3D11_MESSAGE_ID_DEVICE_VSSETCONSTANTBUFFERS_HAZARD"),
-            (5, "D3D11_MESSAGE_ID_DEVICE_GSSETSHADERRESOURCES_HAZARD"),
-            (6, "D3D11_MESSAGE_ID_DEVICE_GSSETCONSTANTBUFFERS_HAZARD"),
-            (7, "D3D11_MESSAGE_ID_DEVICE_PSSETSHADERRESOURCES_HAZARD"),
-            (8, "D3D11_MESSAGE_ID_DEVICE_PSSETCONSTANTBUFFERS_HAZARD"),
-            (9, "D3D11_MESSAGE_ID_DEVICE_OMSETRENDERTARGETS_HAZARD"),
-            (10, "D3D11_MESSAGE_ID_DEVICE_SOSETTARGETS_HAZARD"),
-            (11, "D3D11_MESSAGE_ID_STRING_FROM_APPLICATION"),
-            (12, "D3D11_MESSAGE_ID_CORRUPTED_THIS"),
-            (13, "D3D11_MESSAGE_ID_CORRUPTED_PARAMETER1"),
-            (14, "D3D11_MESSAGE_ID_CORRUPTED_PARAMETER2"),
-            (15, "D3D11_MESSAGE_ID_CORRUPTED_PARAMETER3"),
-            (16, "D3D11_MESSAGE_ID_CORRUPTED_PARAMETER4"),
-            (17, "D3D11_MESSAGE_ID_CORRUPTED_PARAMETER5"),
-            (18, "D3D11_MESSAGE_ID_CORRUPTED_PARAMETER6"),
-            (19, "D3D11_MESSAGE_ID_CORRUPTED_PARAMETER7"),
-            (20, "D3D11_MESSAGE_ID_CORRUPTED_PARAMETER8"),
-            (21, "D3D11_MESSAGE_ID_CORRUPTED_PARAMETER9"),
-            (22, "D3D11_MESSAGE_ID_CORRUPTED_PARAMETER10"),
-            (23, "D3D11_MESSAGE_ID_CORRUPTED_PARAMETER11"),
-            (24, "D3D11_MESSAGE_ID_CORRUPTED_PARAMETER12"),
-            (25, "D3D11_MESSAGE_ID_CORRUPTED_PARAMETER13"),
-            (26, "D3D11_MESSAGE_ID_CORRUPTED_PARAMETER14"),
-            (27, "D3D11_MESSAGE_ID_CORRUPTED_PARAMETER15"),
-            (28, "D3D11_MESSAGE_ID_CORRUPTED_MULTITHREADING"),
-            (29, "D3D11_MESSAGE_ID_MESSAGE_REPORTING_OUTOFMEMORY"),
-            (30, "D3D11_MESSAGE_ID_IASETINPUTLAYOUT_UNBINDDELETINGOBJECT"),
-            (31, "D3D11_MESSAGE_ID_IASETVERTEXBUFFERS_UNBINDDELETINGOBJECT"),
-            (32, "D3D11_MESSAGE_ID_IASETINDEXBUFFER_UNBINDDELETINGOBJECT"),
-            (33, "D3D11_MESSAGE_ID_VSSETSHADER_UNBINDDELETINGOBJECT"),
-            (34, "D3D11_MESSAGE_ID_VSSETSHADERRESOURCES_UNBINDDELETINGOBJECT"),
-            (35, "D3D11_MESSAGE_ID_VSSETCONSTANTBUFFERS_UNBINDDELETINGOBJECT"),
-            (36, "D3D11_MESSAGE_ID_VSSETSAMPLERS_UNBINDDELETINGOBJECT"),
-            (37, "D3D11_MESSAGE_ID_GSSETSHADER_UNBINDDELETINGOBJECT"),
-            (38, "D3D11_MESSAGE_ID_GSSETSHADERRESOURCES_UNBINDDELETINGOBJECT"),
-            (39, "D3D11_MESSAGE_ID_GSSETCONSTANTBUFFERS_UNBINDDELETINGOBJECT"),
-            (40, "D3D11_MESSAGE_ID_GSSETSAMPLERS_UNBINDDELETINGOBJECT"),
-            (41, "D3D11_MESSAGE_ID_SOSETTARGETS_UNBINDDELETINGOBJECT"),
-            (42, "D3D11_MESSAGE_ID_PSSETSHADER_UNBINDDELETINGOBJECT"),
-            (43, "D3D11_MESSAGE_ID_PSSETSHADERRESOURCES_UNBINDDELETINGOBJECT"),
-            (44, "D3D11_MESSAGE_ID_PSSETCONSTANTBUFFERS_UNBINDDELETINGOBJECT"),
-            (45, "D3D11_MESSAGE_ID_PSSETSAMPLERS_UNBINDDELETINGOBJECT"),
-            (46, "D3D11_MESSAGE_ID_RSSETSTATE_UNBINDDELETINGOBJECT"),
-            (47, "D3D11_MESSAGE_ID_OMSETBLENDSTATE_UNBINDDELETINGOBJECT"),
-            (48, "D3D11_MESSAGE_ID_OMSETDEPTHSTENCILSTATE_UNBINDDELETINGOBJECT"),
-            (49, "D3D11_MESSAGE_ID_OMSETRENDERTARGETS_UNBINDDELETINGOBJECT"),
-            (50, "D3D11_MESSAGE_ID_SETPREDICATION_UNBINDDELETINGOBJECT"),
-            (51, "D3D11_MESSAGE_ID_GETPRIVATEDATA_MOREDATA"),
-            (52, "D3D11_MESSAGE_ID_SETPRIVATEDATA_INVALIDFREEDATA"),
-            (53, "D3D11_MESSAGE_ID_SETPRIVATEDATA_INVALIDIUNKNOWN"),
-            (54, "D3D11_MESSAGE_ID_SETPRIVATEDATA_INVALIDFLAGS"),
-            (55, "D3D11_MESSAGE_ID_SETPRIVATEDATA_CHANGINGPARAMS"),
-            (56, "D3D11_MESSAGE_ID_SETPRIVATEDATA_OUTOFMEMORY"),
-            (57, "D3D11_MESSAGE_ID_CREATEBUFFER_UNRECOGNIZEDFORMAT"),
-            (58, "D3D11_MESSAGE_ID_CREATEBUFFER_INVALIDSAMPLES"),
-            (59, "D3D11_MESSAGE_ID_CREATEBUFFER_UNRECOGNIZEDUSAGE"),
-            (60, "D3D11_MESSAGE_ID_CREATEBUFFER_UNRECOGNIZEDBINDFLAGS"),
-            (61, "D3D11_MESSAGE_ID_CREATEBUFFER_UNRECOGNIZEDCPUACCESSFLAGS"),
-            (62, "D3D11_MESSAGE_ID_CREATEBUFFER_UNRECOGNIZEDMISCFLAGS"),
-            (63, "D3D11_MESSAGE_ID_CREATEBUFFER_INVALIDCPUACCESSFLAGS"),
-            (64, "D3D11_MESSAGE_ID_CREATEBUFFER_INVALIDBINDFLAGS"),
-            (65, "D3D11_MESSAGE_ID_CREATEBUFFER_INVALIDINITIALDATA"),
-            (66, "D3D11_MESSAGE_ID_CREATEBUFFER_INVALIDDIMENSIONS"),
-            (67, "D3D11_MESSAGE_ID_CREATEBUFFER_INVALIDMIPLEVELS"),
-            (68, "D3D11_MESSAGE_ID_CREATEBUFFER_INVALIDMISCFLAGS"),
-            (69, "D3D11_MESSAGE_ID_CREATEBUFFER_INVALIDARG_RETURN"),
-            (70, "D3D11_MESSAGE_ID_CREATEBUFFER_OUTOFMEMORY_RETURN"),
-            (71, "D3D11_MESSAGE_ID_CREATEBUFFER_NULLDESC"),
-            (72, "D3D11_MESSAGE_ID_CREATEBUFFER_INVALIDCONSTANTBUFFERBINDINGS"),
-            (73, "D3D11_MESSAGE_ID_CREATEBUFFER_LARGEALLOCATION"),
-            (74, "D3D11_MESSAGE_ID_CREATETEXTURE1D_UNRECOGNIZEDFORMAT"),
-            (75, "D3D11_MESSAGE_ID_CREATETEXTURE1D_UNSUPPORTEDFORMAT"),
-            (76, "D3D11_MESSAGE_ID_CREATETEXTURE1D_INVALIDSAMPLES"),
-            (77, "D3D11_MESSAGE_ID_CREATETEXTURE1D_UNRECOGNIZEDUSAGE"),
-            (78, "D3D11_MESSAGE_ID_CREATETEXTURE1D_UNRECOGNIZEDBINDFLAGS"),
-            (79, "D3D11_MESSAGE_ID_CREATETEXTURE1D_UNRECOGNIZEDCPUACCESSFLAGS"),
-            (80, "D3D11_MESSAGE_ID_CREATETEXTURE1D_UNRECOGNIZEDMISCFLAGS"),
-            (81, "D3D11_MESSAGE_ID_CREATETEXTURE1D_INVALIDCPUACCESSFLAGS"),
-            (82, "D3D11_MESSAGE_ID_CREATETEXTURE1D_INVALIDBINDFLAGS"),
-            (83, "D3D11_MESSAGE_ID_CREATETEXTURE1D_INVALIDINITIALDATA"),
-            (84, "D3D11_MESSAGE_ID_CREATETEXTURE1D_INVALIDDIMENSIONS"),
-            (85, "D3D11_MESSAGE_ID_CREATETEXTURE1D_INVALIDMIPLEVELS"),
-            (86, "D3D11_MESSAGE_ID_CREATETEXTURE1D_INVALIDMISCFLAGS"),
-            (87, "D3D11_MESSAGE_ID_CREATETEXTURE1D_INVALIDARG_RETURN"),
-            (88, "D3D11_MESSAGE_ID_CREATETEXTURE1D_OUTOFMEMORY_RETURN"),
-            (89, "D3D11_MESSAGE_ID_CREATETEXTURE1D_NULLDESC"),
-            (90, "D3D11_MESSAGE_ID_CREATETEXTURE1D_LARGEALLOCATION"),
-            (91, "D3D11_MESSAGE_ID_CREATETEXTURE2D_UNRECOGNIZEDFORMAT"),
-            (92, "D3D11_MESSAGE_ID_CREATETEXTURE2D_UNSUPPORTEDFORMAT"),
-            (93, "D3D11_MESSAGE_ID_CREATETEXTURE2D_INVALIDSAMPLES"),
-            (94, "D3D11_MESSAGE_ID_CREATETEXTURE2D_UNRECOGNIZEDUSAGE"),
-            (95, "D3D11_MESSAGE_ID_CREATETEXTURE2D_UNRECOGNIZEDBINDFLAGS"),
-            (96, "D3D11_MESSAGE_ID_CREATETEXTURE2D_UNRECOGNIZEDCPUACCESSFLAGS"),
-            (97, "D3D11_MESSAGE_ID_CREATETEXTURE2D_UNRECOGNIZEDMISCFLAGS"),
-            (98, "D3D11_MESSAGE_ID_CREATETEXTURE2D_INVALIDCPUACCESSFLAGS"),
-            (99, "D3D11_MESSAGE_ID_CREATETEXTURE2D_INVALIDBINDFLAGS"),
-            (100, "D3D11_MESSAGE_ID_CREATETEXTURE2D_INVALIDINITIALDATA"),
-            (101, "D3D11_MESSAGE_ID_CREATETEXTURE2D_INVALIDDIMENSIONS"),
-            (102, "D3D11_MESSAGE_ID_CREATETEXTURE2D_INVALIDMIPLEVELS"),
-            (103, "D3D11_MESSAGE_ID_CREATETEXTURE2D_INVALIDMISCFLAGS"),
-            (104, "D3D11_MESSAGE_ID_CREATETEXTURE2D_INVALIDARG_RETURN"),
-            (105, "D3D11_MESSAGE_ID_CREATETEXTURE2D_OUTOFMEMORY_RETURN"),
-            (106, "D3D11_MESSAGE_ID_CREATETEXTURE2D_NULLDESC"),
-            (107, "D3D11_MESSAGE_ID_CREATETEXTURE2D_LARGEALLOCATION"),
-            (108, "D3D11_MESSAGE_ID_CREATETEXTURE3D_UNRECOGNIZEDFORMAT"),
-            (109, "D3D11_MESSAGE_ID_CREATETEXTURE3D_UNSUPPORTEDFORMAT"),
-            (110, "D3D11_MESSAGE_ID_CREATETEXTURE3D_INVALIDSAMPLES"),
-            (111, "D3D11_MESSAGE_ID_CREATETEXTURE3D_UNRECOGNIZEDUSAGE"),
-            (112, "D3D11_MESSAGE_ID_CREATETEXTURE3D_UNRECOGNIZEDBINDFLAGS"),
-            (113, "D3D11_MESSAGE_ID_CREATETEXTURE3D_UNRECOGNIZEDCPUACCESSFLAGS"),
-            (114, "D3D11_MESSAGE_ID_CREATETEXTURE3D_UNRECOGNIZEDMISCFLAGS"),
-            (115, "D3D11_MESSAGE_ID_CREATETEXTURE3D_INVALIDCPUACCESSFLAGS"),
-            (116, "D3D11_MESSAGE_ID_CREATETEXTURE3D_INVALIDBINDFLAGS"),
-            (117, "D3D11_MESSAGE_ID_CREATETEXTURE3D_INVALIDINITIALDATA"),
-            (118, "D3D11_MESSAGE_ID_CREATETEXTURE3D_INVALIDDIMENSIONS"),
-            (119, "D3D11_MESSAGE_ID_CREATETEXTURE3D_INVALIDMIPLEVELS"),
-            (120, "D3D11_MESSAGE_ID_CREATETEXTURE3D_INVALIDMISCFLAGS"),
-            (121, "D3D11_MESSAGE_ID_CREATETEXTURE3D_INVALIDARG_RETURN"),
-            (122, "D3D11_MESSAGE_ID_CREATETEXTURE3D_OUTOFMEMORY_RETURN"),
-            (123, "D3D11_MESSAGE_ID_CREATETEXTURE3D_NULLDESC"),
-            (124, "D3D11_MESSAGE_ID_CREATETEXTURE3D_LARGEALLOCATION"),
-            (125, "D3D11_MESSAGE_ID_CREATESHADERRESOURCEVIEW_UNRECOGNIZEDFORMAT"),
-            (126, "D3D11_MESSAGE_ID_CREATESHADERRESOURCEVIEW_INVALIDDESC"),
-            (127, "D3D11_MESSAGE_ID_CREATESHADERRESOURCEVIEW_INVALIDFORMAT"),
-            (128, "D3D11_MESSAGE_ID_CREATESHADERRESOURCEVIEW_INVALIDDIMENSIONS"),
-            (129, "D3D11_MESSAGE_ID_CREATESHADERRESOURCEVIEW_INVALIDRESOURCE"),
-            (130, "D3D11_MESSAGE_ID_CREATESHADERRESOURCEVIEW_TOOMANYOBJECTS"),
-            (131, "D3D11_MESSAGE_ID_CREATESHADERRESOURCEVIEW_INVALIDARG_RETURN"),
-            (132, "D3D11_MESSAGE_ID_CREATESHADERRESOURCEVIEW_OUTOFMEMORY_RETURN"),
-            (133, "D3D11_MESSAGE_ID_CREATERENDERTARGETVIEW_UNRECOGNIZEDFORMAT"),
-            (134, "D3D11_MESSAGE_ID_CREATERENDERTARGETVIEW_UNSUPPORTEDFORMAT"),
-            (135, "D3D11_MESSAGE_ID_CREATERENDERTARGETVIEW_INVALIDDESC"),
-            (136, "D3D11_MESSAGE_ID_CREATERENDERTARGETVIEW_INVALIDFORMAT"),
-            (137, "D3D11_MESSAGE_ID_CREATERENDERTARGETVIEW_INVALIDDIMENSIONS"),
-            (138, "D3D11_MESSAGE_ID_CREATERENDERTARGETVIEW_INVALIDRESOURCE"),
-            (139, "D3D11_MESSAGE_ID_CREATERENDERTARGETVIEW_TOOMANYOBJECTS"),
-            (140, "D3D11_MESSAGE_ID_CREATERENDERTARGETVIEW_INVALIDARG_RETURN"),
-            (141, "D3D11_MESSAGE_ID_CREATERENDERTARGETVIEW_OUTOFMEMORY_RETURN"),
-            (142, "D3D11_MESSAGE_ID_CREATEDEPTHSTENCILVIEW_UNRECOGNIZEDFORMAT"),
-            (143, "D3D11_MESSAGE_ID_CREATEDEPTHSTENCILVIEW_INVALIDDESC"),
-            (144, "D3D11_MESSAGE_ID_CREATEDEPTHSTENCILVIEW_INVALIDFORMAT"),
-            (145, "D3D11_MESSAGE_ID_CREATEDEPTHSTENCILVIEW_INVALIDDIMENSIONS"),
-            (146, "D3D11_MESSAGE_ID_CREATEDEPTHSTENCILVIEW_INVALIDRESOURCE"),
-            (147, "D3D11_MESSAGE_ID_CREATEDEPTHSTENCILVIEW_TOOMANYOBJECTS"),
-            (148, "D3D11_MESSAGE_ID_CREATEDEPTHSTENCILVIEW_INVALIDARG_RETURN"),
-            (149, "D3D11_MESSAGE_ID_CREATEDEPTHSTENCILVIEW_OUTOFMEMORY_RETURN"),
-            (150, "D3D11_MESSAGE_ID_CREATEINPUTLAYOUT_OUTOFMEMORY"),
-            (151, "D3D11_MESSAGE_ID_CREATEINPUTLAYOUT_TOOMANYELEMENTS"),
-            (152, "D3D11_MESSAGE_ID_CREATEINPUTLAYOUT_INVALIDFORMAT"),
-            (153, "D3D11_MESSAGE_ID_CREATEINPUTLAYOUT_INCOMPATIBLEFORMAT"),
-            (154, "D3D11_MESSAGE_ID_CREATEINPUTLAYOUT_INVALIDSLOT"),
-            (155, "D3D11_MESSAGE_ID_CREATEINPUTLAYOUT_INVALIDINPUTSLOTCLASS"),
-            (156, "D3D11_MESSAGE_ID_CREATEINPUTLAYOUT_STEPRATESLOTCLASSMISMATCH"),
-            (157, "D3D11_MESSAGE_ID_CREATEINPUTLAYOUT_INVALIDSLOTCLASSCHANGE"),
-            (158, "D3D11_MESSAGE_ID_CREATEINPUTLAYOUT_INVALIDSTEPRATECHANGE"),
-            (159, "D3D11_MESSAGE_ID_CREATEINPUTLAYOUT_INVALIDALIGNMENT"),
-            (160, "D3D11_MESSAGE_ID_CREATEINPUTLAYOUT_DUPLICATESEMANTIC"),
-            (161, "D3D11_MESSAGE_ID_CREATEINPUTLAYOUT_UNPARSEABLEINPUTSIGNATURE"),
-            (162, "D3D11_MESSAGE_ID_CREATEINPUTLAYOUT_NULLSEMANTIC"),
-            (163, "D3D11_MESSAGE_ID_CREATEINPUTLAYOUT_MISSINGELEMENT"),
-            (164, "D3D11_MESSAGE_ID_CREATEINPUTLAYOUT_NULLDESC"),
-            (165, "D3D11_MESSAGE_ID_CREATEVERTEXSHADER_OUTOFMEMORY"),
-            (166, "D3D11_MESSAGE_ID_CREATEVERTEXSHADER_INVALIDSHADERBYTECODE"),
-            (167, "D3D11_MESSAGE_ID_CREATEVERTEXSHADER_INVALIDSHADERTYPE"),
-            (168, "D3D11_MESSAGE_ID_CREATEGEOMETRYSHADER_OUTOFMEMORY"),
-            (169, "D3D11_MESSAGE_ID_CREATEGEOMETRYSHADER_INVALIDSHADERBYTECODE"),
-            (170, "D3D11_MESSAGE_ID_CREATEGEOMETRYSHADER_INVALIDSHADERTYPE"),
-            (171, "D3D11_MESSAGE_ID_CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_OUTOFMEMORY"),
-            (172, "D3D11_MESSAGE_ID_CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_INVALIDSHADERBYTECODE"),
-            (173, "D3D11_MESSAGE_ID_CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_INVALIDSHADERTYPE"),
-            (174, "D3D11_MESSAGE_ID_CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_INVALIDNUMENTRIES"),
-            (175, "D3D11_MESSAGE_ID_CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_OUTPUTSTREAMSTRIDEUNUSED"),
-            (176, "D3D11_MESSAGE_ID_CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_UNEXPECTEDDECL"),
-            (177, "D3D11_MESSAGE_ID_CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_EXPECTEDDECL"),
-            (178, "D3D11_MESSAGE_ID_CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_OUTPUTSLOT0EXPECTED"),
-            (179, "D3D11_MESSAGE_ID_CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_INVALIDOUTPUTSLOT"),
-            (180, "D3D11_MESSAGE_ID_CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_ONLYONEELEMENTPERSLOT"),
-            (181, "D3D11_MESSAGE_ID_CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_INVALIDCOMPONENTCOUNT"),
-            (182, "D3D11_MESSAGE_ID_CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_INVALIDSTARTCOMPONENTANDCOMPONENTCOUNT"),
-            (183, "D3D11_MESSAGE_ID_CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_INVALIDGAPDEFINITION"),
-            (184, "D3D11_MESSAGE_ID_CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_REPEATEDOUTPUT"),
-            (185, "D3D11_MESSAGE_ID_CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_INVALIDOUTPUTSTREAMSTRIDE"),
-            (186, "D3D11_MESSAGE_ID_CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_MISSINGSEMANTIC"),
-            (187, "D3D11_MESSAGE_ID_CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_MASKMISMATCH"),
-            (188, "D3D11_MESSAGE_ID_CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_CANTHAVEONLYGAPS"),
-            (189, "D3D11_MESSAGE_ID_CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_DECLTOOCOMPLEX"),
-            (190, "D3D11_MESSAGE_ID_CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_MISSINGOUTPUTSIGNATURE"),
-            (191, "D3D11_MESSAGE_ID_CREATEPIXELSHADER_OUTOFMEMORY"),
-            (192, "D3D11_MESSAGE_ID_CREATEPIXELSHADER_INVALIDSHADERBYTECODE"),
-            (193, "D3D11_MESSAGE_ID_CREATEPIXELSHADER_INVALIDSHADERTYPE"),
-            (194, "D3D11_MESSAGE_ID_CREATERASTERIZERSTATE_INVALIDFILLMODE"),
-            (195, "D3D11_MESSAGE_ID_CREATERASTERIZERSTATE_INVALIDCULLMODE"),
-            (196, "D3D11_MESSAGE_ID_CREATERASTERIZERSTATE_INVALIDDEPTHBIASCLAMP"),
-            (197, "D3D11_MESSAGE_ID_CREATERASTERIZERSTATE_INVALIDSLOPESCALEDDEPTHBIAS"),
-            (198, "D3D11_MESSAGE_ID_CREATERASTERIZERSTATE_TOOMANYOBJECTS"),
-            (199, "D3D11_MESSAGE_ID_CREATERASTERIZERSTATE_NULLDESC"),
-            (200, "D3D11_MESSAGE_ID_CREATEDEPTHSTENCILSTATE_INVALIDDEPTHWRITEMASK"),
-            (201, "D3D11_MESSAGE_ID_CREATEDEPTHSTENCILSTATE_INVALIDDEPTHFUNC"),
-            (202, "D3D11_MESSAGE_ID_CREATEDEPTHSTENCILSTATE_INVALIDFRONTFACESTENCILFAILOP"),
-            (203, "D3D11_MESSAGE_ID_CREATEDEPTHSTENCILSTATE_INVALIDFRONTFACESTENCILZFAILOP"),
-            (204, "D3D11_MESSAGE_ID_CREATEDEPTHSTENCILSTATE_INVALIDFRONTFACESTENCILPASSOP"),
-            (205, "D3D11_MESSAGE_ID_CREATEDEPTHSTENCILSTATE_INVALIDFRONTFACESTENCILFUNC"),
-            (206, "D3D11_MESSAGE_ID_CREATEDEPTHSTENCILSTATE_INVALIDBACKFACESTENCILFAILOP"),
-            (207, "D3D11_MESSAGE_ID_CREATEDEPTHSTENCILSTATE_INVALIDBACKFACESTENCILZFAILOP"),
-            (208, "D3D11_MESSAGE_ID_CREATEDEPTHSTENCILSTATE_INVALIDBACKFACESTENCILPASSOP"),
-            (209, "D3D11_MESSAGE_ID_CREATEDEPTHSTENCILSTATE_INVALIDBACKFACESTENCILFUNC"),
-            (210, "D3D11_MESSAGE_ID_CREATEDEPTHSTENCILSTATE_TOOMANYOBJECTS"),
-            (211, "D3D11_MESSAGE_ID_CREATEDEPTHSTENCILSTATE_NULLDESC"),
-            (212, "D3D11_MESSAGE_ID_CREATEBLENDSTATE_INVALIDSRCBLEND"),
-            (213, "D3D11_MESSAGE_ID_CREATEBLENDSTATE_INVALIDDESTBLEND"),
-            (214, "D3D11_MESSAGE_ID_CREATEBLENDSTATE_INVALIDBLENDOP"),
-            (215, "D3D11_MESSAGE_ID_CREATEBLENDSTATE_INVALIDSRCBLENDALPHA"),
-            (216, "D3D11_MESSAGE_ID_CREATEBLENDSTATE_INVALIDDESTBLENDALPHA"),
-            (217, "D3D11_MESSAGE_ID_CREATEBLENDSTATE_INVALIDBLENDOPALPHA"),
-            (218, "D3D11_MESSAGE_ID_CREATEBLENDSTATE_INVALIDRENDERTARGETWRITEMASK"),
-            (219, "D3D11_MESSAGE_ID_CREATEBLENDSTATE_TOOMANYOBJECTS"),
-            (220, "D3D11_MESSAGE_ID_CREATEBLENDSTATE_NULLDESC"),
-            (221, "D3D11_MESSAGE_ID_CREATESAMPLERSTATE_INVALIDFILTER"),
-            (222, "D3D11_MESSAGE_ID_CREATESAMPLERSTATE_INVALIDADDRESSU"),
-            (223, "D3D11_MESSAGE_ID_CREATESAMPLERSTATE_INVALIDADDRESSV"),
-            (224, "D3D11_MESSAGE_ID_CREATESAMPLERSTATE_INVALIDADDRESSW"),
-            (225, "D3D11_MESSAGE_ID_CREATESAMPLERSTATE_INVALIDMIPLODBIAS"),
-            (226, "D3D11_MESSAGE_ID_CREATESAMPLERSTATE_INVALIDMAXANISOTROPY"),
-            (227, "D3D11_MESSAGE_ID_CREATESAMPLERSTATE_INVALIDCOMPARISONFUNC"),
-            (228, "D3D11_MESSAGE_ID_CREATESAMPLERSTATE_INVALIDMINLOD"),
-            (229, "D3D11_MESSAGE_ID_CREATESAMPLERSTATE_INVALIDMAXLOD"),
-            (230, "D3D11_MESSAGE_ID_CREATESAMPLERSTATE_TOOMANYOBJECTS"),
-            (231, "D3D11_MESSAGE_ID_CREATESAMPLERSTATE_NULLDESC"),
-            (232, "D3D11_MESSAGE_ID_CREATEQUERYORPREDICATE_INVALIDQUERY"),
-            (233, "D3D11_MESSAGE_ID_CREATEQUERYORPREDICATE_INVALIDMISCFLAGS"),
-            (234, "D3D11_MESSAGE_ID_CREATEQUERYORPREDICATE_UNEXPECTEDMISCFLAG"),
-            (235, "D3D11_MESSAGE_ID_CREATEQUERYORPREDICATE_NULLDESC"),
-            (236, "D3D11_MESSAGE_ID_DEVICE_IASETPRIMITIVETOPOLOGY_TOPOLOGY_UNRECOGNIZED"),
-            (237, "D3D11_MESSAGE_ID_DEVICE_IASETPRIMITIVETOPOLOGY_TOPOLOGY_UNDEFINED"),
-            (238, "D3D11_MESSAGE_ID_IASETVERTEXBUFFERS_INVALIDBUFFER"),
-            (239, "D3D11_MESSAGE_ID_DEVICE_IASETVERTEXBUFFERS_OFFSET_TOO_LARGE"),
-            (240, "D3D11_MESSAGE_ID_DEVICE_IASETVERTEXBUFFERS_BUFFERS_EMPTY"),
-            (241, "D3D11_MESSAGE_ID_IASETINDEXBUFFER_INVALIDBUFFER"),
-            (242, "D3D11_MESSAGE_ID_DEVICE_IASETINDEXBUFFER_FORMAT_INVALID"),
-            (243, "D3D11_MESSAGE_ID_DEVICE_IASETINDEXBUFFER_OFFSET_TOO_LARGE"),
-            (244, "D3D11_MESSAGE_ID_DEVICE_IASETINDEXBUFFER_OFFSET_UNALIGNED"),
-            (245, "D3D11_MESSAGE_ID_DEVICE_VSSETSHADERRESOURCES_VIEWS_EMPTY"),
-            (246, "D3D11_MESSAGE_ID_VSSETCONSTANTBUFFERS_INVALIDBUFFER"),
-            (247, "D3D11_MESSAGE_ID_DEVICE_VSSETCONSTANTBUFFERS_BUFFERS_EMPTY"),
-            (248, "D3D11_MESSAGE_ID_DEVICE_VSSETSAMPLERS_SAMPLERS_EMPTY"),
-            (249, "D3D11_MESSAGE_ID_DEVICE_GSSETSHADERRESOURCES_VIEWS_EMPTY"),
-            (250, "D3D11_MESSAGE_ID_GSSETCONSTANTBUFFERS_INVALIDBUFFER"),
-            (251, "D3D11_MESSAGE_ID_DEVICE_GSSETCONSTANTBUFFERS_BUFFERS_EMPTY"),
-            (252, "D3D11_MESSAGE_ID_DEVICE_GSSETSAMPLERS_SAMPLERS_EMPTY"),
-            (253, "D3D11_MESSAGE_ID_SOSETTARGETS_INVALIDBUFFER"),
-            (254, "D3D11_MESSAGE_ID_DEVICE_SOSETTARGETS_OFFSET_UNALIGNED"),
-            (255, "D3D11_MESSAGE_ID_DEVICE_PSSETSHADERRESOURCES_VIEWS_EMPTY"),
-            (256, "D3D11_MESSAGE_ID_PSSETCONSTANTBUFFERS_INVALIDBUFFER"),
-            (257, "D3D11_MESSAGE_ID_DEVICE_PSSETCONSTANTBUFFERS_BUFFERS_EMPTY"),
-            (258, "D3D11_MESSAGE_ID_DEVICE_PSSETSAMPLERS_SAMPLERS_EMPTY"),
-            (259, "D3D11_MESSAGE_ID_DEVICE_RSSETVIEWPORTS_INVALIDVIEWPORT"),
-            (260, "D3D11_MESSAGE_ID_DEVICE_RSSETSCISSORRECTS_INVALIDSCISSOR"),
-            (261, "D3D11_MESSAGE_ID_CLEARRENDERTARGETVIEW_DENORMFLUSH"),
-            (262, "D3D11_MESSAGE_ID_CLEARDEPTHSTENCILVIEW_DENORMFLUSH"),
-            (263, "D3D11_MESSAGE_ID_CLEARDEPTHSTENCILVIEW_INVALID"),
-            (264, "D3D11_MESSAGE_ID_DEVICE_IAGETVERTEXBUFFERS_BUFFERS_EMPTY"),
-            (265, "D3D11_MESSAGE_ID_DEVICE_VSGETSHADERRESOURCES_VIEWS_EMPTY"),
-            (266, "D3D11_MESSAGE_ID_DEVICE_VSGETCONSTANTBUFFERS_BUFFERS_EMPTY"),
-            (267, "D3D11_MESSAGE_ID_DEVICE_VSGETSAMPLERS_SAMPLERS_EMPTY"),
-            (268, "D3D11_MESSAGE_ID_DEVICE_GSGETSHADERRESOURCES_VIEWS_EMPTY"),
-            (269, "D3D11_MESSAGE_ID_DEVICE_GSGETCONSTANTBUFFERS_BUFFERS_EMPTY"),
-            (270, "D3D11_MESSAGE_ID_DEVICE_GSGETSAMPLERS_SAMPLERS_EMPTY"),
-            (271, "D3D11_MESSAGE_ID_DEVICE_SOGETTARGETS_BUFFERS_EMPTY"),
-            (272, "D3D11_MESSAGE_ID_DEVICE_PSGETSHADERRESOURCES_VIEWS_EMPTY"),
-            (273, "D3D11_MESSAGE_ID_DEVICE_PSGETCONSTANTBUFFERS_BUFFERS_EMPTY"),
-            (274, "D3D11_MESSAGE_ID_DEVICE_PSGETSAMPLERS_SAMPLERS_EMPTY"),
-            (275, "D3D11_MESSAGE_ID_DEVICE_RSGETVIEWPORTS_VIEWPORTS_EMPTY"),
-            (276, "D3D11_MESSAGE_ID_DEVICE_RSGETSCISSORRECTS_RECTS_EMPTY"),
-            (277, "D3D11_MESSAGE_ID_DEVICE_GENERATEMIPS_RESOURCE_INVALID"),
-            (278, "D3D11_MESSAGE_ID_COPYSUBRESOURCEREGION_INVALIDDESTINATIONSUBRESOURCE"),
-            (279, "D3D11_MESSAGE_ID_COPYSUBRESOURCEREGION_INVALIDSOURCESUBRESOURCE"),
-            (280, "D3D11_MESSAGE_ID_COPYSUBRESOURCEREGION_INVALIDSOURCEBOX"),
-            (281, "D3D11_MESSAGE_ID_COPYSUBRESOURCEREGION_INVALIDSOURCE"),
-            (282, "D3D11_MESSAGE_ID_COPYSUBRESOURCEREGION_INVALIDDESTINATIONSTATE"),
-            (283, "D3D11_MESSAGE_ID_COPYSUBRESOURCEREGION_INVALIDSOURCESTATE"),
-            (284, "D3D11_MESSAGE_ID_COPYRESOURCE_INVALIDSOURCE"),
-            (285, "D3D11_MESSAGE_ID_COPYRESOURCE_INVALIDDESTINATIONSTATE"),
-            (286, "D3D11_MESSAGE_ID_COPYRESOURCE_INVALIDSOURCESTATE"),
-            (287, "D3D11_MESSAGE_ID_UPDATESUBRESOURCE_INVALIDDESTINATIONSUBRESOURCE"),
-            (288, "D3D11_MESSAGE_ID_UPDATESUBRESOURCE_INVALIDDESTINATIONBOX"),
-            (289, "D3D11_MESSAGE_ID_UPDATESUBRESOURCE_INVALIDDESTINATIONSTATE"),
-            (290, "D3D11_MESSAGE_ID_DEVICE_RESOLVESUBRESOURCE_DESTINATION_INVALID"),
-            (291, "D3D11_MESSAGE_ID_DEVICE_RESOLVESUBRESOURCE_DESTINATION_SUBRESOURCE_INVALID"),
-            (292, "D3D11_MESSAGE_ID_DEVICE_RESOLVESUBRESOURCE_SOURCE_INVALID"),
-            (293, "D3D11_MESSAGE_ID_DEVICE_RESOLVESUBRESOURCE_SOURCE_SUBRESOURCE_INVALID"),
-            (294, "D3D11_MESSAGE_ID_DEVICE_RESOLVESUBRESOURCE_FORMAT_INVALID"),
-            (295, "D3D11_MESSAGE_ID_BUFFER_MAP_INVALIDMAPTYPE"),
-            (296, "D3D11_MESSAGE_ID_BUFFER_MAP_INVALIDFLAGS"),
-            (297, "D3D11_MESSAGE_ID_BUFFER_MAP_ALREADYMAPPED"),
-            (298, "D3D11_MESSAGE_ID_BUFFER_MAP_DEVICEREMOVED_RETURN"),
-            (299, "D3D11_MESSAGE_ID_BUFFER_UNMAP_NOTMAPPED"),
-            (300, "D3D11_MESSAGE_ID_TEXTURE1D_MAP_INVALIDMAPTYPE"),
-            (301, "D3D11_MESSAGE_ID_TEXTURE1D_MAP_INVALIDSUBRESOURCE"),
-            (302, "D3D11_MESSAGE_ID_TEXTURE1D_MAP_INVALIDFLAGS"),
-            (303, "D3D11_MESSAGE_ID_TEXTURE1D_MAP_ALREADYMAPPED"),
-            (304, "D3D11_MESSAGE_ID_TEXTURE1D_MAP_DEVICEREMOVED_RETURN"),
-            (305, "D3D11_MESSAGE_ID_TEXTURE1D_UNMAP_INVALIDSUBRESOURCE"),
-            (306, "D3D11_MESSAGE_ID_TEXTURE1D_UNMAP_NOTMAPPED"),
-            (307, "D3D11_MESSAGE_ID_TEXTURE2D_MAP_INVALIDMAPTYPE"),
-            (308, "D3D11_MESSAGE_ID_TEXTURE2D_MAP_INVALIDSUBRESOURCE"),
-            (309, "D3D11_MESSAGE_ID_TEXTURE2D_MAP_INVALIDFLAGS"),
-            (310, "D3D11_MESSAGE_ID_TEXTURE2D_MAP_ALREADYMAPPED"),
-            (311, "D3D11_MESSAGE_ID_TEXTURE2D_MAP_DEVICEREMOVED_RETURN"),
-            (312, "D3D11_MESSAGE_ID_TEXTURE2D_UNMAP_INVALIDSUBRESOURCE"),
-            (313, "D3D11_MESSAGE_ID_TEXTURE2D_UNMAP_NOTMAPPED"),
-            (314, "D3D11_MESSAGE_ID_TEXTURE3D_MAP_INVALIDMAPTYPE"),
-            (315, "D3D11_MESSAGE_ID_TEXTURE3D_MAP_INVALIDSUBRESOURCE"),
-            (316, "D3D11_MESSAGE_ID_TEXTURE3D_MAP_INVALIDFLAGS"),
-            (317, "D3D11_MESSAGE_ID_TEXTURE3D_MAP_ALREADYMAPPED"),
-            (318, "D3D11_MESSAGE_ID_TEXTURE3D_MAP_DEVICEREMOVED_RETURN"),
-            (319, "D3D11_MESSAGE_ID_TEXTURE3D_UNMAP_INVALIDSUBRESOURCE"),
-            (320, "D3D11_MESSAGE_ID_TEXTURE3D_UNMAP_NOTMAPPED"),
-            (321, "D3D11_MESSAGE_ID_CHECKFORMATSUPPORT_FORMAT_DEPRECATED"),
-            (322, "D3D11_MESSAGE_ID_CHECKMULTISAMPLEQUALITYLEVELS_FORMAT_DEPRECATED"),
-            (323, "D3D11_MESSAGE_ID_SETEXCEPTIONMODE_UNRECOGNIZEDFLAGS"),
-            (324, "D3D11_MESSAGE_ID_SETEXCEPTIONMODE_INVALIDARG_RETURN"),
-            (325, "D3D11_MESSAGE_ID_SETEXCEPTIONMODE_DEVICEREMOVED_RETURN"),
-            (326, "D3D11_MESSAGE_ID_REF_SIMULATING_INFINITELY_FAST_HARDWARE"),
-            (327, "D3D11_MESSAGE_ID_REF_THREADING_MODE"),
-            (328, "D3D11_MESSAGE_ID_REF_UMDRIVER_EXCEPTION"),
-            (329, "D3D11_MESSAGE_ID_REF_KMDRIVER_EXCEPTION"),
-            (330, "D3D11_MESSAGE_ID_REF_HARDWARE_EXCEPTION"),
-            (331, "D3D11_MESSAGE_ID_REF_ACCESSING_INDEXABLE_TEMP_OUT_OF_RANGE"),
-            (332, "D3D11_MESSAGE_ID_REF_PROBLEM_PARSING_SHADER"),
-            (333, "D3D11_MESSAGE_ID_REF_OUT_OF_MEMORY"),
-            (334, "D3D11_MESSAGE_ID_REF_INFO"),
-            (335, "D3D11_MESSAGE_ID_DEVICE_DRAW_VERTEXPOS_OVERFLOW"),
-            (336, "D3D11_MESSAGE_ID_DEVICE_DRAWINDEXED_INDEXPOS_OVERFLOW"),
-            (337, "D3D11_MESSAGE_ID_DEVICE_DRAWINSTANCED_VERTEXPOS_OVERFLOW"),
-            (338, "D3D11_MESSAGE_ID_DEVICE_DRAWINSTANCED_INSTANCEPOS_OVERFLOW"),
-            (339, "D3D11_MESSAGE_ID_DEVICE_DRAWINDEXEDINSTANCED_INSTANCEPOS_OVERFLOW"),
-            (340, "D3D11_MESSAGE_ID_DEVICE_DRAWINDEXEDINSTANCED_INDEXPOS_OVERFLOW"),
-            (341, "D3D11_MESSAGE_ID_DEVICE_DRAW_VERTEX_SHADER_NOT_SET"),
-            (342, "D3D11_MESSAGE_ID_DEVICE_SHADER_LINKAGE_SEMANTICNAME_NOT_FOUND"),
-            (343, "D3D11_MESSAGE_ID_DEVICE_SHADER_LINKAGE_REGISTERINDEX"),
-            (344, "D3D11_MESSAGE_ID_DEVICE_SHADER_LINKAGE_COMPONENTTYPE"),
-            (345, "D3D11_MESSAGE_ID_DEVICE_SHADER_LINKAGE_REGISTERMASK"),
-            (346, "D3D11_MESSAGE_ID_DEVICE_SHADER_LINKAGE_SYSTEMVALUE"),
-            (347, "D3D11_MESSAGE_ID_DEVICE_SHADER_LINKAGE_NEVERWRITTEN_ALWAYSREADS"),
-            (348, "D3D11_MESSAGE_ID_DEVICE_DRAW_VERTEX_BUFFER_NOT_SET"),
-            (349, "D3D11_MESSAGE_ID_DEVICE_DRAW_INPUTLAYOUT_NOT_SET"),
-            (350, "D3D11_MESSAGE_ID_DEVICE_DRAW_CONSTANT_BUFFER_NOT_SET"),
-            (351, "D3D11_MESSAGE_ID_DEVICE_DRAW_CONSTANT_BUFFER_TOO_SMALL"),
-            (352, "D3D11_MESSAGE_ID_DEVICE_DRAW_SAMPLER_NOT_SET"),
-            (353, "D3D11_MESSAGE_ID_DEVICE_DRAW_SHADERRESOURCEVIEW_NOT_SET"),
-            (354, "D3D11_MESSAGE_ID_DEVICE_DRAW_VIEW_DIMENSION_MISMATCH"),
-            (355, "D3D11_MESSAGE_ID_DEVICE_DRAW_VERTEX_BUFFER_STRIDE_TOO_SMALL"),
-            (356, "D3D11_MESSAGE_ID_DEVICE_DRAW_VERTEX_BUFFER_TOO_SMALL"),
-            (357, "D3D11_MESSAGE_ID_DEVICE_DRAW_INDEX_BUFFER_NOT_SET"),
-            (358, "D3D11_MESSAGE_ID_DEVICE_DRAW_INDEX_BUFFER_FORMAT_INVALID"),
-            (359, "D3D11_MESSAGE_ID_DEVICE_DRAW_INDEX_BUFFER_TOO_SMALL"),
-            (360, "D3D11_MESSAGE_ID_DEVICE_DRAW_GS_INPUT_PRIMITIVE_MISMATCH"),
-            (361, "D3D11_MESSAGE_ID_DEVICE_DRAW_RESOURCE_RETURN_TYPE_MISMATCH"),
-            (362, "D3D11_MESSAGE_ID_DEVICE_DRAW_POSITION_NOT_PRESENT"),
-            (363, "D3D11_MESSAGE_ID_DEVICE_DRAW_OUTPUT_STREAM_NOT_SET"),
-            (364, "D3D11_MESSAGE_ID_DEVICE_DRAW_BOUND_RESOURCE_MAPPED"),
-            (365, "D3D11_MESSAGE_ID_DEVICE_DRAW_INVALID_PRIMITIVETOPOLOGY"),
-            (366, "D3D11_MESSAGE_ID_DEVICE_DRAW_VERTEX_OFFSET_UNALIGNED"),
-            (367, "D3D11_MESSAGE_ID_DEVICE_DRAW_VERTEX_STRIDE_UNALIGNED"),
-            (368, "D3D11_MESSAGE_ID_DEVICE_DRAW_INDEX_OFFSET_UNALIGNED"),
-            (369, "D3D11_MESSAGE_ID_DEVICE_DRAW_OUTPUT_STREAM_OFFSET_UNALIGNED"),
-            (370, "D3D11_MESSAGE_ID_DEVICE_DRAW_RESOURCE_FORMAT_LD_UNSUPPORTED"),
-            (371, "D3D11_MESSAGE_ID_DEVICE_DRAW_RESOURCE_FORMAT_SAMPLE_UNSUPPORTED"),
-            (372, "D3D11_MESSAGE_ID_DEVICE_DRAW_RESOURCE_FORMAT_SAMPLE_C_UNSUPPORTED"),
-            (373, "D3D11_MESSAGE_ID_DEVICE_DRAW_RESOURCE_MULTISAMPLE_UNSUPPORTED"),
-            (374, "D3D11_MESSAGE_ID_DEVICE_DRAW_SO_TARGETS_BOUND_WITHOUT_SOURCE"),
-            (375, "D3D11_MESSAGE_ID_DEVICE_DRAW_SO_STRIDE_LARGER_THAN_BUFFER"),
-            (376, "D3D11_MESSAGE_ID_DEVICE_DRAW_OM_RENDER_TARGET_DOES_NOT_SUPPORT_BLENDING"),
-            (377, "D3D11_MESSAGE_ID_DEVICE_DRAW_OM_DUAL_SOURCE_BLENDING_CAN_ONLY_HAVE_RENDER_TARGET_0"),
-            (378, "D3D11_MESSAGE_ID_DEVICE_REMOVAL_PROCESS_AT_FAULT"),
-            (379, "D3D11_MESSAGE_ID_DEVICE_REMOVAL_PROCESS_POSSIBLY_AT_FAULT"),
-            (380, "D3D11_MESSAGE_ID_DEVICE_REMOVAL_PROCESS_NOT_AT_FAULT"),
-            (381, "D3D11_MESSAGE_ID_DEVICE_OPEN_SHARED_RESOURCE_INVALIDARG_RETURN"),
-            (382, "D3D11_MESSAGE_ID_DEVICE_OPEN_SHARED_RESOURCE_OUTOFMEMORY_RETURN"),
-            (383, "D3D11_MESSAGE_ID_DEVICE_OPEN_SHARED_RESOURCE_BADINTERFACE_RETURN"),
-            (384, "D3D11_MESSAGE_ID_DEVICE_DRAW_VIEWPORT_NOT_SET"),
-            (385, "D3D11_MESSAGE_ID_CREATEINPUTLAYOUT_TRAILING_DIGIT_IN_SEMANTIC"),
-            (386, "D3D11_MESSAGE_ID_CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_TRAILING_DIGIT_IN_SEMANTIC"),
-            (387, "D3D11_MESSAGE_ID_DEVICE_RSSETVIEWPORTS_DENORMFLUSH"),
-            (388, "D3D11_MESSAGE_ID_OMSETRENDERTARGETS_INVALIDVIEW"),
-            (389, "D3D11_MESSAGE_ID_DEVICE_SETTEXTFILTERSIZE_INVALIDDIMENSIONS"),
-            (390, "D3D11_MESSAGE_ID_DEVICE_DRAW_SAMPLER_MISMATCH"),
-            (391, "D3D11_MESSAGE_ID_CREATEINPUTLAYOUT_TYPE_MISMATCH"),
-            (392, "D3D11_MESSAGE_ID_BLENDSTATE_GETDESC_LEGACY"),
-            (393, "D3D11_MESSAGE_ID_SHADERRESOURCEVIEW_GETDESC_LEGACY"),
-            (394, "D3D11_MESSAGE_ID_CREATEQUERY_OUTOFMEMORY_RETURN"),
-            (395, "D3D11_MESSAGE_ID_CREATEPREDICATE_OUTOFMEMORY_RETURN"),
-            (396, "D3D11_MESSAGE_ID_CREATECOUNTER_OUTOFRANGE_COUNTER"),
-            (397, "D3D11_MESSAGE_ID_CREATECOUNTER_SIMULTANEOUS_ACTIVE_COUNTERS_EXHAUSTED"),
-            (398, "D3D11_MESSAGE_ID_CREATECOUNTER_UNSUPPORTED_WELLKNOWN_COUNTER"),
-            (399, "D3D11_MESSAGE_ID_CREATECOUNTER_OUTOFMEMORY_RETURN"),
-            (400, "D3D11_MESSAGE_ID_CREATECOUNTER_NONEXCLUSIVE_RETURN"),
-            (401, "D3D11_MESSAGE_ID_CREATECOUNTER_NULLDESC"),
-            (402, "D3D11_MESSAGE_ID_CHECKCOUNTER_OUTOFRANGE_COUNTER"),
-            (403, "D3D11_MESSAGE_ID_CHECKCOUNTER_UNSUPPORTED_WELLKNOWN_COUNTER"),
-            (404, "D3D11_MESSAGE_ID_SETPREDICATION_INVALID_PREDICATE_STATE"),
-            (405, "D3D11_MESSAGE_ID_QUERY_BEGIN_UNSUPPORTED"),
-            (406, "D3D11_MESSAGE_ID_PREDICATE_BEGIN_DURING_PREDICATION"),
-            (407, "D3D11_MESSAGE_ID_QUERY_BEGIN_DUPLICATE"),
-            (408, "D3D11_MESSAGE_ID_QUERY_BEGIN_ABANDONING_PREVIOUS_RESULTS"),
-            (409, "D3D11_MESSAGE_ID_PREDICATE_END_DURING_PREDICATION"),
-            (410, "D3D11_MESSAGE_ID_QUERY_END_ABANDONING_PREVIOUS_RESULTS"),
-            (411, "D3D11_MESSAGE_ID_QUERY_END_WITHOUT_BEGIN"),
-            (412, "D3D11_MESSAGE_ID_QUERY_GETDATA_INVALID_DATASIZE"),
-            (413, "D3D11_MESSAGE_ID_QUERY_GETDATA_INVALID_FLAGS"),
-            (414, "D3D11_MESSAGE_ID_QUERY_GETDATA_INVALID_CALL"),
-            (415, "D3D11_MESSAGE_ID_DEVICE_DRAW_PS_OUTPUT_TYPE_MISMATCH"),
-            (416, "D3D11_MESSAGE_ID_DEVICE_DRAW_RESOURCE_FORMAT_GATHER_UNSUPPORTED"),
-            (417, "D3D11_MESSAGE_ID_DEVICE_DRAW_INVALID_USE_OF_CENTER_MULTISAMPLE_PATTERN"),
-            (418, "D3D11_MESSAGE_ID_DEVICE_IASETVERTEXBUFFERS_STRIDE_TOO_LARGE"),
-            (419, "D3D11_MESSAGE_ID_DEVICE_IASETVERTEXBUFFERS_INVALIDRANGE"),
-            (420, "D3D11_MESSAGE_ID_CREATEINPUTLAYOUT_EMPTY_LAYOUT"),
-            (421, "D3D11_MESSAGE_ID_DEVICE_DRAW_RESOURCE_SAMPLE_COUNT_MISMATCH"),
-            (422, "D3D11_MESSAGE_ID_LIVE_OBJECT_SUMMARY"),
-            (423, "D3D11_MESSAGE_ID_LIVE_BUFFER"),
-            (424, "D3D11_MESSAGE_ID_LIVE_TEXTURE1D"),
-            (425, "D3D11_MESSAGE_ID_LIVE_TEXTURE2D"),
-            (426, "D3D11_MESSAGE_ID_LIVE_TEXTURE3D"),
-            (427, "D3D11_MESSAGE_ID_LIVE_SHADERRESOURCEVIEW"),
-            (428, "D3D11_MESSAGE_ID_LIVE_RENDERTARGETVIEW"),
-            (429, "D3D11_MESSAGE_ID_LIVE_DEPTHSTENCILVIEW"),
-            (430, "D3D11_MESSAGE_ID_LIVE_VERTEXSHADER"),
-            (431, "D3D11_MESSAGE_ID_LIVE_GEOMETRYSHADER"),
-            (432, "D3D11_MESSAGE_ID_LIVE_PIXELSHADER"),
-            (433, "D3D11_MESSAGE_ID_LIVE_INPUTLAYOUT"),
-            (434, "D3D11_MESSAGE_ID_LIVE_SAMPLER"),
-            (435, "D3D11_MESSAGE_ID_LIVE_BLENDSTATE"),
-            (436, "D3D11_MESSAGE_ID_LIVE_DEPTHSTENCILSTATE"),
-            (437, "D3D11_MESSAGE_ID_LIVE_RASTERIZERSTATE"),
-            (438, "D3D11_MESSAGE_ID_LIVE_QUERY"),
-            (439, "D3D11_MESSAGE_ID_LIVE_PREDICATE"),
-            (440, "D3D11_MESSAGE_ID_LIVE_COUNTER"),
-            (441, "D3D11_MESSAGE_ID_LIVE_DEVICE"),
-            (442, "D3D11_MESSAGE_ID_LIVE_SWAPCHAIN"),
-            (443, "D3D11_MESSAGE_ID_D3D10_MESSAGES_END"),
-            (1048576, "D3D11_MESSAGE_ID_D3D10L9_MESSAGES_START"),
-            (1048577, "D3D11_MESSAGE_ID_CREATEDEPTHSTENCILSTATE_STENCIL_NO_TWO_SIDED"),
-            (1048578, "D3D11_MESSAGE_ID_CREATERASTERIZERSTATE_DepthBiasClamp_NOT_SUPPORTED"),
-            (1048579, "D3D11_MESSAGE_ID_CREATESAMPLERSTATE_NO_COMPARISON_SUPPORT"),
-            (1048580, "D3D11_MESSAGE_ID_CREATESAMPLERSTATE_EXCESSIVE_ANISOTROPY"),
-            (1048581, "D3D11_MESSAGE_ID_CREATESAMPLERSTATE_BORDER_OUT_OF_RANGE"),
-            (1048582, "D3D11_MESSAGE_ID_VSSETSAMPLERS_NOT_SUPPORTED"),
-            (1048583, "D3D11_MESSAGE_ID_VSSETSAMPLERS_TOO_MANY_SAMPLERS"),
-            (1048584, "D3D11_MESSAGE_ID_PSSETSAMPLERS_TOO_MANY_SAMPLERS"),
-            (1048585, "D3D11_MESSAGE_ID_CREATERESOURCE_NO_ARRAYS"),
-            (1048586, "D3D11_MESSAGE_ID_CREATERESOURCE_NO_VB_AND_IB_BIND"),
-            (1048587, "D3D11_MESSAGE_ID_CREATERESOURCE_NO_TEXTURE_1D"),
-            (1048588, "D3D11_MESSAGE_ID_CREATERESOURCE_DIMENSION_OUT_OF_RANGE"),
-            (1048589, "D3D11_MESSAGE_ID_CREATERESOURCE_NOT_BINDABLE_AS_SHADER_RESOURCE"),
-            (1048590, "D3D11_MESSAGE_ID_OMSETRENDERTARGETS_TOO_MANY_RENDER_TARGETS"),
-            (1048591, "D3D11_MESSAGE_ID_OMSETRENDERTARGETS_NO_DIFFERING_BIT_DEPTHS"),
-            (1048592, "D3D11_MESSAGE_ID_IASETVERTEXBUFFERS_BAD_BUFFER_INDEX"),
-            (1048593, "D3D11_MESSAGE_ID_DEVICE_RSSETVIEWPORTS_TOO_MANY_VIEWPORTS"),
-            (1048594, "D3D11_MESSAGE_ID_DEVICE_IASETPRIMITIVETOPOLOGY_ADJACENCY_UNSUPPORTED"),
-            (1048595, "D3D11_MESSAGE_ID_DEVICE_RSSETSCISSORRECTS_TOO_MANY_SCISSORS"),
-            (1048596, "D3D11_MESSAGE_ID_COPYRESOURCE_ONLY_TEXTURE_2D_WITHIN_GPU_MEMORY"),
-            (1048597, "D3D11_MESSAGE_ID_COPYRESOURCE_NO_TEXTURE_3D_READBACK"),
-            (1048598, "D3D11_MESSAGE_ID_COPYRESOURCE_NO_TEXTURE_ONLY_READBACK"),
-            (1048599, "D3D11_MESSAGE_ID_CREATEINPUTLAYOUT_UNSUPPORTED_FORMAT"),
-            (1048600, "D3D11_MESSAGE_ID_CREATEBLENDSTATE_NO_ALPHA_TO_COVERAGE"),
-            (1048601, "D3D11_MESSAGE_ID_CREATERASTERIZERSTATE_DepthClipEnable_MUST_BE_TRUE"),
-            (1048602, "D3D11_MESSAGE_ID_DRAWINDEXED_STARTINDEXLOCATION_MUST_BE_POSITIVE"),
-            (1048603, "D3D11_MESSAGE_ID_CREATESHADERRESOURCEVIEW_MUST_USE_LOWEST_LOD"),
-            (1048604, "D3D11_MESSAGE_ID_CREATESAMPLERSTATE_MINLOD_MUST_NOT_BE_FRACTIONAL"),
-            (1048605, "D3D11_MESSAGE_ID_CREATESAMPLERSTATE_MAXLOD_MUST_BE_FLT_MAX"),
-            (1048606, "D3D11_MESSAGE_ID_CREATESHADERRESOURCEVIEW_FIRSTARRAYSLICE_MUST_BE_ZERO"),
-            (1048607, "D3D11_MESSAGE_ID_CREATESHADERRESOURCEVIEW_CUBES_MUST_HAVE_6_SIDES"),
-            (1048608, "D3D11_MESSAGE_ID_CREATERESOURCE_NOT_BINDABLE_AS_RENDER_TARGET"),
-            (1048609, "D3D11_MESSAGE_ID_CREATERESOURCE_NO_DWORD_INDEX_BUFFER"),
-            (1048610, "D3D11_MESSAGE_ID_CREATERESOURCE_MSAA_PRECLUDES_SHADER_RESOURCE"),
-            (1048611, "D3D11_MESSAGE_ID_CREATERESOURCE_PRESENTATION_PRECLUDES_SHADER_RESOURCE"),
-            (1048612, "D3D11_MESSAGE_ID_CREATEBLENDSTATE_NO_INDEPENDENT_BLEND_ENABLE"),
-            (1048613, "D3D11_MESSAGE_ID_CREATEBLENDSTATE_NO_INDEPENDENT_WRITE_MASKS"),
-            (1048614, "D3D11_MESSAGE_ID_CREATERESOURCE_NO_STREAM_OUT"),
-            (1048615, "D3D11_MESSAGE_ID_CREATERESOURCE_ONLY_VB_IB_FOR_BUFFERS"),
-            (1048616, "D3D11_MESSAGE_ID_CREATERESOURCE_NO_AUTOGEN_FOR_VOLUMES"),
-            (1048617, "D3D11_MESSAGE_ID_CREATERESOURCE_DXGI_FORMAT_R8G8B8A8_CANNOT_BE_SHARED"),
-            (1048618, "D3D11_MESSAGE_ID_VSSHADERRESOURCES_NOT_SUPPORTED"),
-            (1048619, "D3D11_MESSAGE_ID_GEOMETRY_SHADER_NOT_SUPPORTED"),
-            (1048620, "D3D11_MESSAGE_ID_STREAM_OUT_NOT_SUPPORTED"),
-            (1048621, "D3D11_MESSAGE_ID_TEXT_FILTER_NOT_SUPPORTED"),
-            (1048622, "D3D11_MESSAGE_ID_CREATEBLENDSTATE_NO_SEPARATE_ALPHA_BLEND"),
-            (1048623, "D3D11_MESSAGE_ID_CREATEBLENDSTATE_NO_MRT_BLEND"),
-            (1048624, "D3D11_MESSAGE_ID_CREATEBLENDSTATE_OPERATION_NOT_SUPPORTED"),
-            (1048625, "D3D11_MESSAGE_ID_CREATESAMPLERSTATE_NO_MIRRORONCE"),
-            (1048626, "D3D11_MESSAGE_ID_DRAWINSTANCED_NOT_SUPPORTED"),
-            (1048627, "D3D11_MESSAGE_ID_DRAWINDEXEDINSTANCED_NOT_SUPPORTED_BELOW_9_3"),
-            (1048628, "D3D11_MESSAGE_ID_DRAWINDEXED_POINTLIST_UNSUPPORTED"),
-            (1048629, "D3D11_MESSAGE_ID_SETBLENDSTATE_SAMPLE_MASK_CANNOT_BE_ZERO"),
-            (1048630, "D3D11_MESSAGE_ID_CREATERESOURCE_DIMENSION_EXCEEDS_FEATURE_LEVEL_DEFINITION"),
-            (1048631, "D3D11_MESSAGE_ID_CREATERESOURCE_ONLY_SINGLE_MIP_LEVEL_DEPTH_STENCIL_SUPPORTED"),
-            (1048632, "D3D11_MESSAGE_ID_DEVICE_RSSETSCISSORRECTS_NEGATIVESCISSOR"),
-            (1048633, "D3D11_MESSAGE_ID_SLOT_ZERO_MUST_BE_D3D10_INPUT_PER_VERTEX_DATA"),
-            (1048634, "D3D11_MESSAGE_ID_CREATERESOURCE_NON_POW_2_MIPMAP"),
-            (1048635, "D3D11_MESSAGE_ID_CREATESAMPLERSTATE_BORDER_NOT_SUPPORTED"),
-            (1048636, "D3D11_MESSAGE_ID_OMSETRENDERTARGETS_NO_SRGB_MRT"),
-            (1048637, "D3D11_MESSAGE_ID_COPYRESOURCE_NO_3D_MISMATCHED_UPDATES"),
-            (1048638, "D3D11_MESSAGE_ID_D3D10L9_MESSAGES_END"),
-            (2097152, "D3D11_MESSAGE_ID_D3D11_MESSAGES_START"),
-            (2097153, "D3D11_MESSAGE_ID_CREATEDEPTHSTENCILVIEW_INVALIDFLAGS"),
-            (2097154, "D3D11_MESSAGE_ID_CREATEVERTEXSHADER_INVALIDCLASSLINKAGE"),
-            (2097155, "D3D11_MESSAGE_ID_CREATEGEOMETRYSHADER_INVALIDCLASSLINKAGE"),
-            (2097156, "D3D11_MESSAGE_ID_CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_INVALIDNUMSTREAMS"),
-            (2097157, "D3D11_MESSAGE_ID_CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_INVALIDSTREAMTORASTERIZER"),
-            (2097158, "D3D11_MESSAGE_ID_CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_UNEXPECTEDSTREAMS"),
-            (2097159, "D3D11_MESSAGE_ID_CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_INVALIDCLASSLINKAGE"),
-            (2097160, "D3D11_MESSAGE_ID_CREATEPIXELSHADER_INVALIDCLASSLINKAGE"),
-            (2097161, "D3D11_MESSAGE_ID_CREATEDEFERREDCONTEXT_INVALID_COMMANDLISTFLAGS"),
-            (2097162, "D3D11_MESSAGE_ID_CREATEDEFERREDCONTEXT_SINGLETHREADED"),
-            (2097163, "D3D11_MESSAGE_ID_CREATEDEFERREDCONTEXT_INVALIDARG_RETURN"),
-            (2097164, "D3D11_MESSAGE_ID_CREATEDEFERREDCONTEXT_INVALID_CALL_RETURN"),
-            (2097165, "D3D11_MESSAGE_ID_CREATEDEFERREDCONTEXT_OUTOFMEMORY_RETURN"),
-            (2097166, "D3D11_MESSAGE_ID_FINISHDISPLAYLIST_ONIMMEDIATECONTEXT"),
-            (2097167, "D3D11_MESSAGE_ID_FINISHDISPLAYLIST_OUTOFMEMORY_RETURN"),
-            (2097168, "D3D11_MESSAGE_ID_FINISHDISPLAYLIST_INVALID_CALL_RETURN"),
-            (2097169, "D3D11_MESSAGE_ID_CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_INVALIDSTREAM"),
-            (2097170, "D3D11_MESSAGE_ID_CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_UNEXPECTEDENTRIES"),
-            (2097171, "D3D11_MESSAGE_ID_CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_UNEXPECTEDSTRIDES"),
-            (2097172, "D3D11_MESSAGE_ID_CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_INVALIDNUMSTRIDES"),
-            (2097173, "D3D11_MESSAGE_ID_DEVICE_HSSETSHADERRESOURCES_HAZARD"),
-            (2097174, "D3D11_MESSAGE_ID_DEVICE_HSSETCONSTANTBUFFERS_HAZARD"),
-            (2097175, "D3D11_MESSAGE_ID_HSSETSHADERRESOURCES_UNBINDDELETINGOBJECT"),
-            (2097176, "D3D11_MESSAGE_ID_HSSETCONSTANTBUFFERS_UNBINDDELETINGOBJECT"),
-            (2097177, "D3D11_MESSAGE_ID_CREATEHULLSHADER_INVALIDCALL"),
-            (2097178, "D3D11_MESSAGE_ID_CREATEHULLSHADER_OUTOFMEMORY"),
-            (2097179, "D3D11_MESSAGE_ID_CREATEHULLSHADER_INVALIDSHADERBYTECODE"),
-            (2097180, "D3D11_MESSAGE_ID_CREATEHULLSHADER_INVALIDSHADERTYPE"),
-            (2097181, "D3D11_MESSAGE_ID_CREATEHULLSHADER_INVALIDCLASSLINKAGE"),
-            (2097182, "D3D11_MESSAGE_ID_DEVICE_HSSETSHADERRESOURCES_VIEWS_EMPTY"),
-            (2097183, "D3D11_MESSAGE_ID_HSSETCONSTANTBUFFERS_INVALIDBUFFER"),
-            (2097184, "D3D11_MESSAGE_ID_DEVICE_HSSETCONSTANTBUFFERS_BUFFERS_EMPTY"),
-            (2097185, "D3D11_MESSAGE_ID_DEVICE_HSSETSAMPLERS_SAMPLERS_EMPTY"),
-            (2097186, "D3D11_MESSAGE_ID_DEVICE_HSGETSHADERRESOURCES_VIEWS_EMPTY"),
-            (2097187, "D3D11_MESSAGE_ID_DEVICE_HSGETCONSTANTBUFFERS_BUFFERS_EMPTY"),
-            (2097188, "D3D11_MESSAGE_ID_DEVICE_HSGETSAMPLERS_SAMPLERS_EMPTY"),
-            (2097189, "D3D11_MESSAGE_ID_DEVICE_DSSETSHADERRESOURCES_HAZARD"),
-            (2097190, "D3D11_MESSAGE_ID_DEVICE_DSSETCONSTANTBUFFERS_HAZARD"),
-            (2097191, "D3D11_MESSAGE_ID_DSSETSHADERRESOURCES_UNBINDDELETINGOBJECT"),
-            (2097192, "D3D11_MESSAGE_ID_DSSETCONSTANTBUFFERS_UNBINDDELETINGOBJECT"),
-            (2097193, "D3D11_MESSAGE_ID_CREATEDOMAINSHADER_INVALIDCALL"),
-            (2097194, "D3D11_MESSAGE_ID_CREATEDOMAINSHADER_OUTOFMEMORY"),
-            (2097195, "D3D11_MESSAGE_ID_CREATEDOMAINSHADER_INVALIDSHADERBYTECODE"),
-            (2097196, "D3D11_MESSAGE_ID_CREATEDOMAINSHADER_INVALIDSHADERTYPE"),
-            (2097197, "D3D11_MESSAGE_ID_CREATEDOMAINSHADER_INVALIDCLASSLINKAGE"),
-            (2097198, "D3D11_MESSAGE_ID_DEVICE_DSSETSHADERRESOURCES_VIEWS_EMPTY"),
-            (2097199, "D3D11_MESSAGE_ID_DSSETCONSTANTBUFFERS_INVALIDBUFFER"),
-            (2097200, "D3D11_MESSAGE_ID_DEVICE_DSSETCONSTANTBUFFERS_BUFFERS_EMPTY"),
-            (2097201, "D3D11_MESSAGE_ID_DEVICE_DSSETSAMPLERS_SAMPLERS_EMPTY"),
-            (2097202, "D3D11_MESSAGE_ID_DEVICE_DSGETSHADERRESOURCES_VIEWS_EMPTY"),
-            (2097203, "D3D11_MESSAGE_ID_DEVICE_DSGETCONSTANTBUFFERS_BUFFERS_EMPTY"),
-            (2097204, "D3D11_MESSAGE_ID_DEVICE_DSGETSAMPLERS_SAMPLERS_EMPTY"),
-            (2097205, "D3D11_MESSAGE_ID_DEVICE_DRAW_HS_XOR_DS_MISMATCH"),
-            (2097206, "D3D11_MESSAGE_ID_DEFERRED_CONTEXT_REMOVAL_PROCESS_AT_FAULT"),
-            (2097207, "D3D11_MESSAGE_ID_DEVICE_DRAWINDIRECT_INVALID_ARG_BUFFER"),
-            (2097208, "D3D11_MESSAGE_ID_DEVICE_DRAWINDIRECT_OFFSET_UNALIGNED"),
-            (2097209, "D3D11_MESSAGE_ID_DEVICE_DRAWINDIRECT_OFFSET_OVERFLOW"),
-            (2097210, "D3D11_MESSAGE_ID_RESOURCE_MAP_INVALIDMAPTYPE"),
-            (2097211, "D3D11_MESSAGE_ID_RESOURCE_MAP_INVALIDSUBRESOURCE"),
-            (2097212, "D3D11_MESSAGE_ID_RESOURCE_MAP_INVALIDFLAGS"),
-            (2097213, "D3D11_MESSAGE_ID_RESOURCE_MAP_ALREADYMAPPED"),
-            (2097214, "D3D11_MESSAGE_ID_RESOURCE_MAP_DEVICEREMOVED_RETURN"),
-            (2097215, "D3D11_MESSAGE_ID_RESOURCE_MAP_OUTOFMEMORY_RETURN"),
-            (2097216, "D3D11_MESSAGE_ID_RESOURCE_MAP_WITHOUT_INITIAL_DISCARD"),
-            (2097217, "D3D11_MESSAGE_ID_RESOURCE_UNMAP_INVALIDSUBRESOURCE"),
-            (2097218, "D3D11_MESSAGE_ID_RESOURCE_UNMAP_NOTMAPPED"),
-            (2097219, "D3D11_MESSAGE_ID_DEVICE_DRAW_RASTERIZING_CONTROL_POINTS"),
-            (2097220, "D3D11_MESSAGE_ID_DEVICE_IASETPRIMITIVETOPOLOGY_TOPOLOGY_UNSUPPORTED"),
-            (2097221, "D3D11_MESSAGE_ID_DEVICE_DRAW_HS_DS_SIGNATURE_MISMATCH"),
-            (2097222, "D3D11_MESSAGE_ID_DEVICE_DRAW_HULL_SHADER_INPUT_TOPOLOGY_MISMATCH"),
-            (2097223, "D3D11_MESSAGE_ID_DEVICE_DRAW_HS_DS_CONTROL_POINT_COUNT_MISMATCH"),
-            (2097224, "D3D11_MESSAGE_ID_DEVICE_DRAW_HS_DS_TESSELLATOR_DOMAIN_MISMATCH"),
-            (2097225, "D3D11_MESSAGE_ID_CREATE_CONTEXT"),
-            (2097226, "D3D11_MESSAGE_ID_LIVE_CONTEXT"),
-            (2097227, "D3D11_MESSAGE_ID_DESTROY_CONTEXT"),
-            (2097228, "D3D11_MESSAGE_ID_CREATE_BUFFER"),
-            (2097229, "D3D11_MESSAGE_ID_LIVE_BUFFER_WIN7"),
-            (2097230, "D3D11_MESSAGE_ID_DESTROY_BUFFER"),
-            (2097231, "D3D11_MESSAGE_ID_CREATE_TEXTURE1D"),
-            (2097232, "D3D11_MESSAGE_ID_LIVE_TEXTURE1D_WIN7"),
-            (2097233, "D3D11_MESSAGE_ID_DESTROY_TEXTURE1D"),
-            (2097234, "D3D11_MESSAGE_ID_CREATE_TEXTURE2D"),
-            (2097235, "D3D11_MESSAGE_ID_LIVE_TEXTURE2D_WIN7"),
-            (2097236, "D3D11_MESSAGE_ID_DESTROY_TEXTURE2D"),
-            (2097237, "D3D11_MESSAGE_ID_CREATE_TEXTURE3D"),
-            (2097238, "D3D11_MESSAGE_ID_LIVE_TEXTURE3D_WIN7"),
-            (2097239, "D3D11_MESSAGE_ID_DESTROY_TEXTURE3D"),
-            (2097240, "D3D11_MESSAGE_ID_CREATE_SHADERRESOURCEVIEW"),
-            (2097241, "D3D11_MESSAGE_ID_LIVE_SHADERRESOURCEVIEW_WIN7"),
-            (2097242, "D3D11_MESSAGE_ID_DESTROY_SHADERRESOURCEVIEW"),
-            (2097243, "D3D11_MESSAGE_ID_CREATE_RENDERTARGETVIEW"),
-            (2097244, "D3D11_MESSAGE_ID_LIVE_RENDERTARGETVIEW_WIN7"),
-            (2097245, "D3D11_MESSAGE_ID_DESTROY_RENDERTARGETVIEW"),
-            (2097246, "D3D11_MESSAGE_ID_CREATE_DEPTHSTENCILVIEW"),
-            (2097247, "D3D11_MESSAGE_ID_LIVE_DEPTHSTENCILVIEW_WIN7"),
-            (2097248, "D3D11_MESSAGE_ID_DESTROY_DEPTHSTENCILVIEW"),
-            (2097249, "D3D11_MESSAGE_ID_CREATE_VERTEXSHADER"),
-            (2097250, "D3D11_MESSAGE_ID_LIVE_VERTEXSHADER_WIN7"),
-            (2097251, "D3D11_MESSAGE_ID_DESTROY_VERTEXSHADER"),
-            (2097252, "D3D11_MESSAGE_ID_CREATE_HULLSHADER"),
-            (2097253, "D3D11_MESSAGE_ID_LIVE_HULLSHADER"),
-            (2097254, "D3D11_MESSAGE_ID_DESTROY_HULLSHADER"),
-            (2097255, "D3D11_MESSAGE_ID_CREATE_DOMAINSHADER"),
-            (2097256, "D3D11_MESSAGE_ID_LIVE_DOMAINSHADER"),
-            (2097257, "D3D11_MESSAGE_ID_DESTROY_DOMAINSHADER"),
-            (2097258, "D3D11_MESSAGE_ID_CREATE_GEOMETRYSHADER"),
-            (2097259, "D3D11_MESSAGE_ID_LIVE_GEOMETRYSHADER_WIN7"),
-            (2097260, "D3D11_MESSAGE_ID_DESTROY_GEOMETRYSHADER"),
-            (2097261, "D3D11_MESSAGE_ID_CREATE_PIXELSHADER"),
-            (2097262, "D3D11_MESSAGE_ID_LIVE_PIXELSHADER_WIN7"),
-            (2097263, "D3D11_MESSAGE_ID_DESTROY_PIXELSHADER"),
-            (2097264, "D3D11_MESSAGE_ID_CREATE_INPUTLAYOUT"),
-            (2097265, "D3D11_MESSAGE_ID_LIVE_INPUTLAYOUT_WIN7"),
-            (2097266, "D3D11_MESSAGE_ID_DESTROY_INPUTLAYOUT"),
-            (2097267, "D3D11_MESSAGE_ID_CREATE_SAMPLER"),
-            (2097268, "D3D11_MESSAGE_ID_LIVE_SAMPLER_WIN7"),
-            (2097269, "D3D11_MESSAGE_ID_DESTROY_SAMPLER"),
-            (2097270, "D3D11_MESSAGE_ID_CREATE_BLENDSTATE"),
-            (2097271, "D3D11_MESSAGE_ID_LIVE_BLENDSTATE_WIN7"),
-            (2097272, "D3D11_MESSAGE_ID_DESTROY_BLENDSTATE"),
-            (2097273, "D3D11_MESSAGE_ID_CREATE_DEPTHSTENCILSTATE"),
-            (2097274, "D3D11_MESSAGE_ID_LIVE_DEPTHSTENCILSTATE_WIN7"),
-            (2097275, "D3D11_MESSAGE_ID_DESTROY_DEPTHSTENCILSTATE"),
-            (2097276, "D3D11_MESSAGE_ID_CREATE_RASTERIZERSTATE"),
-            (2097277, "D3D11_MESSAGE_ID_LIVE_RASTERIZERSTATE_WIN7"),
-            (2097278, "D3D11_MESSAGE_ID_DESTROY_RASTERIZERSTATE"),
-            (2097279, "D3D11_MESSAGE_ID_CREATE_QUERY"),
-            (2097280, "D3D11_MESSAGE_ID_LIVE_QUERY_WIN7"),
-            (2097281, "D3D11_MESSAGE_ID_DESTROY_QUERY"),
-            (2097282, "D3D11_MESSAGE_ID_CREATE_PREDICATE"),
-            (2097283, "D3D11_MESSAGE_ID_LIVE_PREDICATE_WIN7"),
-            (2097284, "D3D11_MESSAGE_ID_DESTROY_PREDICATE"),
-            (2097285, "D3D11_MESSAGE_ID_CREATE_COUNTER"),
-            (2097286, "D3D11_MESSAGE_ID_DESTROY_COUNTER"),
-            (2097287, "D3D11_MESSAGE_ID_CREATE_COMMANDLIST"),
-            (2097288, "D3D11_MESSAGE_ID_LIVE_COMMANDLIST"),
-            (2097289, "D3D11_MESSAGE_ID_DESTROY_COMMANDLIST"),
-            (2097290, "D3D11_MESSAGE_ID_CREATE_CLASSINSTANCE"),
-            (2097291, "D3D11_MESSAGE_ID_LIVE_CLASSINSTANCE"),
-            (2097292, "D3D11_MESSAGE_ID_DESTROY_CLASSINSTANCE"),
-            (2097293, "D3D11_MESSAGE_ID_CREATE_CLASSLINKAGE"),
-            (2097294, "D3D11_MESSAGE_ID_LIVE_CLASSLINKAGE"),
-            (2097295, "D3D11_MESSAGE_ID_DESTROY_CLASSLINKAGE"),
-            (2097296, "D3D11_MESSAGE_ID_LIVE_DEVICE_WIN7"),
-            (2097297, "D3D11_MESSAGE_ID_LIVE_OBJECT_SUMMARY_WIN7"),
-            (2097298, "D3D11_MESSAGE_ID_CREATE_COMPUTESHADER"),
-            (2097299, "D3D11_MESSAGE_ID_LIVE_COMPUTESHADER"),
-            (2097300, "D3D11_MESSAGE_ID_DESTROY_COMPUTESHADER"),
-            (2097301, "D3D11_MESSAGE_ID_CREATE_UNORDEREDACCESSVIEW"),
-            (2097302, "D3D11_MESSAGE_ID_LIVE_UNORDEREDACCESSVIEW"),
-            (2097303, "D3D11_MESSAGE_ID_DESTROY_UNORDEREDACCESSVIEW"),
-            (2097304, "D3D11_MESSAGE_ID_DEVICE_SETSHADER_INTERFACES_FEATURELEVEL"),
-            (2097305, "D3D11_MESSAGE_ID_DEVICE_SETSHADER_INTERFACE_COUNT_MISMATCH"),
-            (2097306, "D3D11_MESSAGE_ID_DEVICE_SETSHADER_INVALID_INSTANCE"),
-            (2097307, "D3D11_MESSAGE_ID_DEVICE_SETSHADER_INVALID_INSTANCE_INDEX"),
-            (2097308, "D3D11_MESSAGE_ID_DEVICE_SETSHADER_INVALID_INSTANCE_TYPE"),
-            (2097309, "D3D11_MESSAGE_ID_DEVICE_SETSHADER_INVALID_INSTANCE_DATA"),
-            (2097310, "D3D11_MESSAGE_ID_DEVICE_SETSHADER_UNBOUND_INSTANCE_DATA"),
-            (2097311, "D3D11_MESSAGE_ID_DEVICE_SETSHADER_INSTANCE_DATA_BINDINGS"),
-            (2097312, "D3D11_MESSAGE_ID_DEVICE_CREATESHADER_CLASSLINKAGE_FULL"),
-            (2097313, "D3D11_MESSAGE_ID_DEVICE_CHECKFEATURESUPPORT_UNRECOGNIZED_FEATURE"),
-            (2097314, "D3D11_MESSAGE_ID_DEVICE_CHECKFEATURESUPPORT_MISMATCHED_DATA_SIZE"),
-            (2097315, "D3D11_MESSAGE_ID_DEVICE_CHECKFEATURESUPPORT_INVALIDARG_RETURN"),
-            (2097316, "D3D11_MESSAGE_ID_DEVICE_CSSETSHADERRESOURCES_HAZARD"),
-            (2097317, "D3D11_MESSAGE_ID_DEVICE_CSSETCONSTANTBUFFERS_HAZARD"),
-            (2097318, "D3D11_MESSAGE_ID_CSSETSHADERRESOURCES_UNBINDDELETINGOBJECT"),
-            (2097319, "D3D11_MESSAGE_ID_CSSETCONSTANTBUFFERS_UNBINDDELETINGOBJECT"),
-            (2097320, "D3D11_MESSAGE_ID_CREATECOMPUTESHADER_INVALIDCALL"),
-            (2097321, "D3D11_MESSAGE_ID_CREATECOMPUTESHADER_OUTOFMEMORY"),
-            (2097322, "D3D11_MESSAGE_ID_CREATECOMPUTESHADER_INVALIDSHADERBYTECODE"),
-            (2097323, "D3D11_MESSAGE_ID_CREATECOMPUTESHADER_INVALIDSHADERTYPE"),
-            (2097324, "D3D11_MESSAGE_ID_CREATECOMPUTESHADER_INVALIDCLASSLINKAGE"),
-            (2097325, "D3D11_MESSAGE_ID_DEVICE_CSSETSHADERRESOURCES_VIEWS_EMPTY"),
-            (2097326, "D3D11_MESSAGE_ID_CSSETCONSTANTBUFFERS_INVALIDBUFFER"),
-            (2097327, "D3D11_MESSAGE_ID_DEVICE_CSSETCONSTANTBUFFERS_BUFFERS_EMPTY"),
-            (2097328, "D3D11_MESSAGE_ID_DEVICE_CSSETSAMPLERS_SAMPLERS_EMPTY"),
-            (2097329, "D3D11_MESSAGE_ID_DEVICE_CSGETSHADERRESOURCES_VIEWS_EMPTY"),
-            (2097330, "D3D11_MESSAGE_ID_DEVICE_CSGETCONSTANTBUFFERS_BUFFERS_EMPTY"),
-            (2097331, "D3D11_MESSAGE_ID_DEVICE_CSGETSAMPLERS_SAMPLERS_EMPTY"),
-            (2097332, "D3D11_MESSAGE_ID_DEVICE_CREATEVERTEXSHADER_DOUBLEFLOATOPSNOTSUPPORTED"),
-            (2097333, "D3D11_MESSAGE_ID_DEVICE_CREATEHULLSHADER_DOUBLEFLOATOPSNOTSUPPORTED"),
-            (2097334, "D3D11_MESSAGE_ID_DEVICE_CREATEDOMAINSHADER_DOUBLEFLOATOPSNOTSUPPORTED"),
-            (2097335, "D3D11_MESSAGE_ID_DEVICE_CREATEGEOMETRYSHADER_DOUBLEFLOATOPSNOTSUPPORTED"),
-            (2097336, "D3D11_MESSAGE_ID_DEVICE_CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_DOUBLEFLOATOPSNOTSUPPORTED"),
-            (2097337, "D3D11_MESSAGE_ID_DEVICE_CREATEPIXELSHADER_DOUBLEFLOATOPSNOTSUPPORTED"),
-            (2097338, "D3D11_MESSAGE_ID_DEVICE_CREATECOMPUTESHADER_DOUBLEFLOATOPSNOTSUPPORTED"),
-            (2097339, "D3D11_MESSAGE_ID_CREATEBUFFER_INVALIDSTRUCTURESTRIDE"),
-            (2097340, "D3D11_MESSAGE_ID_CREATESHADERRESOURCEVIEW_INVALIDFLAGS"),
-            (2097341, "D3D11_MESSAGE_ID_CREATEUNORDEREDACCESSVIEW_INVALIDRESOURCE"),
-            (2097342, "D3D11_MESSAGE_ID_CREATEUNORDEREDACCESSVIEW_INVALIDDESC"),
-            (2097343, "D3D11_MESSAGE_ID_CREATEUNORDEREDACCESSVIEW_INVALIDFORMAT"),
-            (2097344, "D3D11_MESSAGE_ID_CREATEUNORDEREDACCESSVIEW_INVALIDDIMENSIONS"),
-            (2097345, "D3D11_MESSAGE_ID_CREATEUNORDEREDACCESSVIEW_UNRECOGNIZEDFORMAT"),
-            (2097346, "D3D11_MESSAGE_ID_DEVICE_OMSETRENDERTARGETSANDUNORDEREDACCESSVIEWS_HAZARD"),
-            (2097347, "D3D11_MESSAGE_ID_DEVICE_OMSETRENDERTARGETSANDUNORDEREDACCESSVIEWS_OVERLAPPING_OLD_SLOTS"),
-            (2097348, "D3D11_MESSAGE_ID_DEVICE_OMSETRENDERTARGETSANDUNORDEREDACCESSVIEWS_NO_OP"),
-            (2097349, "D3D11_MESSAGE_ID_CSSETUNORDEREDACCESSVIEWS_UNBINDDELETINGOBJECT"),
-            (2097350, "D3D11_MESSAGE_ID_PSSETUNORDEREDACCESSVIEWS_UNBINDDELETINGOBJECT"),
-            (2097351, "D3D11_MESSAGE_ID_CREATEUNORDEREDACCESSVIEW_INVALIDARG_RETURN"),
-            (2097352, "D3D11_MESSAGE_ID_CREATEUNORDEREDACCESSVIEW_OUTOFMEMORY_RETURN"),
-            (2097353, "D3D11_MESSAGE_ID_CREATEUNORDEREDACCESSVIEW_TOOMANYOBJECTS"),
-            (2097354, "D3D11_MESSAGE_ID_DEVICE_CSSETUNORDEREDACCESSVIEWS_HAZARD"),
-            (2097355, "D3D11_MESSAGE_ID_CLEARUNORDEREDACCESSVIEW_DENORMFLUSH"),
-            (2097356, "D3D11_MESSAGE_ID_DEVICE_CSSETUNORDEREDACCESSS_VIEWS_EMPTY"),
-            (2097357, "D3D11_MESSAGE_ID_DEVICE_CSGETUNORDEREDACCESSS_VIEWS_EMPTY"),
-            (2097358, "D3D11_MESSAGE_ID_CREATEUNORDEREDACCESSVIEW_INVALIDFLAGS"),
-            (2097359, "D3D11_MESSAGE_ID_CREATESHADERRESESOURCEVIEW_TOOMANYOBJECTS"),
-            (2097360, "D3D11_MESSAGE_ID_DEVICE_DISPATCHINDIRECT_INVALID_ARG_BUFFER"),
-            (2097361, "D3D11_MESSAGE_ID_DEVICE_DISPATCHINDIRECT_OFFSET_UNALIGNED"),
-            (2097362, "D3D11_MESSAGE_ID_DEVICE_DISPATCHINDIRECT_OFFSET_OVERFLOW"),
-            (2097363, "D3D11_MESSAGE_ID_DEVICE_SETRESOURCEMINLOD_INVALIDCONTEXT"),
-            (2097364, "D3D11_MESSAGE_ID_DEVICE_SETRESOURCEMINLOD_INVALIDRESOURCE"),
-            (2097365, "D3D11_MESSAGE_ID_DEVICE_SETRESOURCEMINLOD_INVALIDMINLOD"),
-            (2097366, "D3D11_MESSAGE_ID_DEVICE_GETRESOURCEMINLOD_INVALIDCONTEXT"),
-            (2097367, "D3D11_MESSAGE_ID_DEVICE_GETRESOURCEMINLOD_INVALIDRESOURCE"),
-            (2097368, "D3D11_MESSAGE_ID_OMSETDEPTHSTENCIL_UNBINDDELETINGOBJECT"),
-            (2097369, "D3D11_MESSAGE_ID_CLEARDEPTHSTENCILVIEW_DEPTH_READONLY"),
-            (2097370, "D3D11_MESSAGE_ID_CLEARDEPTHSTENCILVIEW_STENCIL_READONLY"),
-            (2097371, "D3D11_MESSAGE_ID_CHECKFEATURESUPPORT_FORMAT_DEPRECATED"),
-            (2097372, "D3D11_MESSAGE_ID_DEVICE_UNORDEREDACCESSVIEW_RETURN_TYPE_MISMATCH"),
-            (2097373, "D3D11_MESSAGE_ID_DEVICE_UNORDEREDACCESSVIEW_NOT_SET"),
-            (2097374, "D3D11_MESSAGE_ID_DEVICE_DRAW_UNORDEREDACCESSVIEW_RENDERTARGETVIEW_OVERLAP"),
-            (2097375, "D3D11_MESSAGE_ID_DEVICE_UNORDEREDACCESSVIEW_DIMENSION_MISMATCH"),
-            (2097376, "D3D11_MESSAGE_ID_DEVICE_UNORDEREDACCESSVIEW_APPEND_UNSUPPORTED"),
-            (2097377, "D3D11_MESSAGE_ID_DEVICE_UNORDEREDACCESSVIEW_ATOMICS_UNSUPPORTED"),
-            (2097378, "D3D11_MESSAGE_ID_DEVICE_UNORDEREDACCESSVIEW_STRUCTURE_STRIDE_MISMATCH"),
-            (2097379, "D3D11_MESSAGE_ID_DEVICE_UNORDEREDACCESSVIEW_BUFFER_TYPE_MISMATCH"),
-            (2097380, "D3D11_MESSAGE_ID_DEVICE_UNORDEREDACCESSVIEW_RAW_UNSUPPORTED"),
-            (2097381, "D3D11_MESSAGE_ID_DEVICE_UNORDEREDACCESSVIEW_FORMAT_LD_UNSUPPORTED"),
-            (2097382, "D3D11_MESSAGE_ID_DEVICE_UNORDEREDACCESSVIEW_FORMAT_STORE_UNSUPPORTED"),
-            (2097383, "D3D11_MESSAGE_ID_DEVICE_UNORDEREDACCESSVIEW_ATOMIC_ADD_UNSUPPORTED"),
-            (2097384, "D3D11_MESSAGE_ID_DEVICE_UNORDEREDACCESSVIEW_ATOMIC_BITWISE_OPS_UNSUPPORTED"),
-            (2097385, "D3D11_MESSAGE_ID_DEVICE_UNORDEREDACCESSVIEW_ATOMIC_CMPSTORE_CMPEXCHANGE_UNSUPPORTED"),
-            (2097386, "D3D11_MESSAGE_ID_DEVICE_UNORDEREDACCESSVIEW_ATOMIC_EXCHANGE_UNSUPPORTED"),
-            (2097387, "D3D11_MESSAGE_ID_DEVICE_UNORDEREDACCESSVIEW_ATOMIC_SIGNED_MINMAX_UNSUPPORTED"),
-            (2097388, "D3D11_MESSAGE_ID_DEVICE_UNORDEREDACCESSVIEW_ATOMIC_UNSIGNED_MINMAX_UNSUPPORTED"),
-            (2097389, "D3D11_MESSAGE_ID_DEVICE_DISPATCH_BOUND_RESOURCE_MAPPED"),
-            (2097390, "D3D11_MESSAGE_ID_DEVICE_DISPATCH_THREADGROUPCOUNT_OVERFLOW"),
-            (2097391, "D3D11_MESSAGE_ID_DEVICE_DISPATCH_THREADGROUPCOUNT_ZERO"),
-            (2097392, "D3D11_MESSAGE_ID_DEVICE_SHADERRESOURCEVIEW_STRUCTURE_STRIDE_MISMATCH"),
-            (2097393, "D3D11_MESSAGE_ID_DEVICE_SHADERRESOURCEVIEW_BUFFER_TYPE_MISMATCH"),
-            (2097394, "D3D11_MESSAGE_ID_DEVICE_SHADERRESOURCEVIEW_RAW_UNSUPPORTED"),
-            (2097395, "D3D11_MESSAGE_ID_DEVICE_DISPATCH_UNSUPPORTED"),
-            (2097396, "D3D11_MESSAGE_ID_DEVICE_DISPATCHINDIRECT_UNSUPPORTED"),
-            (2097397, "D3D11_MESSAGE_ID_COPYSTRUCTURECOUNT_INVALIDOFFSET"),
-            (2097398, "D3D11_MESSAGE_ID_COPYSTRUCTURECOUNT_LARGEOFFSET"),
-            (2097399, "D3D11_MESSAGE_ID_COPYSTRUCTURECOUNT_INVALIDDESTINATIONSTATE"),
-            (2097400, "D3D11_MESSAGE_ID_COPYSTRUCTURECOUNT_INVALIDSOURCESTATE"),
-            (2097401, "D3D11_MESSAGE_ID_CHECKFORMATSUPPORT_FORMAT_NOT_SUPPORTED"),
-            (2097402, "D3D11_MESSAGE_ID_DEVICE_CSSETUNORDEREDACCESSVIEWS_INVALIDVIEW"),
-            (2097403, "D3D11_MESSAGE_ID_DEVICE_CSSETUNORDEREDACCESSVIEWS_INVALIDOFFSET"),
-            (2097404, "D3D11_MESSAGE_ID_DEVICE_CSSETUNORDEREDACCESSVIEWS_TOOMANYVIEWS"),
-            (2097405, "D3D11_MESSAGE_ID_CLEARUNORDEREDACCESSVIEWFLOAT_INVALIDFORMAT"),
-            (2097406, "D3D11_MESSAGE_ID_DEVICE_UNORDEREDACCESSVIEW_COUNTER_UNSUPPORTED"),
-            (2097407, "D3D11_MESSAGE_ID_REF_WARNING"),
-            (2097408, "D3D11_MESSAGE_ID_DEVICE_DRAW_PIXEL_SHADER_WITHOUT_RTV_OR_DSV"),
-            (2097409, "D3D11_MESSAGE_ID_SHADER_ABORT"),
-            (2097410, "D3D11_MESSAGE_ID_SHADER_MESSAGE"),
-            (2097411, "D3D11_MESSAGE_ID_SHADER_ERROR"),
-            (2097412, "D3D11_MESSAGE_ID_OFFERRESOURCES_INVALIDRESOURCE"),
-            (2097413, "D3D11_MESSAGE_ID_HSSETSAMPLERS_UNBINDDELETINGOBJECT"),
-            (2097414, "D3D11_MESSAGE_ID_DSSETSAMPLERS_UNBINDDELETINGOBJECT"),
-            (2097415, "D3D11_MESSAGE_ID_CSSETSAMPLERS_UNBINDDELETINGOBJECT"),
-            (2097416, "D3D11_MESSAGE_ID_HSSETSHADER_UNBINDDELETINGOBJECT"),
-            (2097417, "D3D11_MESSAGE_ID_DSSETSHADER_UNBINDDELETINGOBJECT"),
-            (2097418, "D3D11_MESSAGE_ID_CSSETSHADER_UNBINDDELETINGOBJECT"),
-            (2097419, "D3D11_MESSAGE_ID_ENQUEUESETEVENT_INVALIDARG_RETURN"),
-            (2097420, "D3D11_MESSAGE_ID_ENQUEUESETEVENT_OUTOFMEMORY_RETURN"),
-            (2097421, "D3D11_MESSAGE_ID_ENQUEUESETEVENT_ACCESSDENIED_RETURN"),
-            (2097422, "D3D11_MESSAGE_ID_DEVICE_OMSETRENDERTARGETSANDUNORDEREDACCESSVIEWS_NUMUAVS_INVALIDRANGE"),
-            (2097423, "D3D11_MESSAGE_ID_USE_OF_ZERO_REFCOUNT_OBJECT"),
-            (2097424, "D3D11_MESSAGE_ID_D3D11_MESSAGES_END"),
-            (3145728, "D3D11_MESSAGE_ID_D3D11_1_MESSAGES_START"),
-            (3145729, "D3D11_MESSAGE_ID_CREATE_VIDEODECODER"),
-            (3145730, "D3D11_MESSAGE_ID_CREATE_VIDEOPROCESSORENUM"),
-            (3145731, "D3D11_MESSAGE_ID_CREATE_VIDEOPROCESSOR"),
-            (3145732, "D3D11_MESSAGE_ID_CREATE_DECODEROUTPUTVIEW"),
-            (3145733, "D3D11_MESSAGE_ID_CREATE_PROCESSORINPUTVIEW"),
-            (3145734, "D3D11_MESSAGE_ID_CREATE_PROCESSOROUTPUTVIEW"),
-            (3145735, "D3D11_MESSAGE_ID_CREATE_DEVICECONTEXTSTATE"),
-            (3145736, "D3D11_MESSAGE_ID_LIVE_VIDEODECODER"),
-            (3145737, "D3D11_MESSAGE_ID_LIVE_VIDEOPROCESSORENUM"),
-            (3145738, "D3D11_MESSAGE_ID_LIVE_VIDEOPROCESSOR"),
-            (3145739, "D3D11_MESSAGE_ID_LIVE_DECODEROUTPUTVIEW"),
-            (3145740, "D3D11_MESSAGE_ID_LIVE_PROCESSORINPUTVIEW"),
-            (3145741, "D3D11_MESSAGE_ID_LIVE_PROCESSOROUTPUTVIEW"),
-            (3145742, "D3D11_MESSAGE_ID_LIVE_DEVICECONTEXTSTATE"),
-            (3145743, "D3D11_MESSAGE_ID_DESTROY_VIDEODECODER"),
-            (3145744, "D3D11_MESSAGE_ID_DESTROY_VIDEOPROCESSORENUM"),
-            (3145745, "D3D11_MESSAGE_ID_DESTROY_VIDEOPROCESSOR"),
-            (3145746, "D3D11_MESSAGE_ID_DESTROY_DECODEROUTPUTVIEW"),
-            (3145747, "D3D11_MESSAGE_ID_DESTROY_PROCESSORINPUTVIEW"),
-            (3145748, "D3D11_MESSAGE_ID_DESTROY_PROCESSOROUTPUTVIEW"),
-            (3145749, "D3D11_MESSAGE_ID_DESTROY_DEVICECONTEXTSTATE"),
-            (3145750, "D3D11_MESSAGE_ID_CREATEDEVICECONTEXTSTATE_INVALIDFLAGS"),
-            (3145751, "D3D11_MESSAGE_ID_CREATEDEVICECONTEXTSTATE_INVALIDFEATURELEVEL"),
-            (3145752, "D3D11_MESSAGE_ID_CREATEDEVICECONTEXTSTATE_FEATURELEVELS_NOT_SUPPORTED"),
-            (3145753, "D3D11_MESSAGE_ID_CREATEDEVICECONTEXTSTATE_INVALIDREFIID"),
-            (3145754, "D3D11_MESSAGE_ID_DEVICE_DISCARDVIEW_INVALIDVIEW"),
-            (3145755, "D3D11_MESSAGE_ID_COPYSUBRESOURCEREGION1_INVALIDCOPYFLAGS"),
-            (3145756, "D3D11_MESSAGE_ID_UPDATESUBRESOURCE1_INVALIDCOPYFLAGS"),
-            (3145757, "D3D11_MESSAGE_ID_CREATERASTERIZERSTATE_INVALIDFORCEDSAMPLECOUNT"),
-            (3145758, "D3D11_MESSAGE_ID_CREATEVIDEODECODER_OUTOFMEMORY_RETURN"),
-            (3145759, "D3D11_MESSAGE_ID_CREATEVIDEODECODER_NULLPARAM"),
-            (3145760, "D3D11_MESSAGE_ID_CREATEVIDEODECODER_INVALIDFORMAT"),
-            (3145761, "D3D11_MESSAGE_ID_CREATEVIDEODECODER_ZEROWIDTHHEIGHT"),
-            (3145762, "D3D11_MESSAGE_ID_CREATEVIDEODECODER_DRIVER_INVALIDBUFFERSIZE"),
-            (3145763, "D3D11_MESSAGE_ID_CREATEVIDEODECODER_DRIVER_INVALIDBUFFERUSAGE"),
-            (3145764, "D3D11_MESSAGE_ID_GETVIDEODECODERPROFILECOUNT_OUTOFMEMORY"),
-            (3145765, "D3D11_MESSAGE_ID_GETVIDEODECODERPROFILE_NULLPARAM"),
-            (3145766, "D3D11_MESSAGE_ID_GETVIDEODECODERPROFILE_INVALIDINDEX"),
-            (3145767, "D3D11_MESSAGE_ID_GETVIDEODECODERPROFILE_OUTOFMEMORY_RETURN"),
-            (3145768, "D3D11_MESSAGE_ID_CHECKVIDEODECODERFORMAT_NULLPARAM"),
-            (3145769, "D3D11_MESSAGE_ID_CHECKVIDEODECODERFORMAT_OUTOFMEMORY_RETURN"),
-            (3145770, "D3D11_MESSAGE_ID_GETVIDEODECODERCONFIGCOUNT_NULLPARAM"),
-            (3145771, "D3D11_MESSAGE_ID_GETVIDEODECODERCONFIGCOUNT_OUTOFMEMORY_RETURN"),
-            (3145772, "D3D11_MESSAGE_ID_GETVIDEODECODERCONFIG_NULLPARAM"),
-            (3145773, "D3D11_MESSAGE_ID_GETVIDEODECODERCONFIG_INVALIDINDEX"),
-            (3145774, "D3D11_MESSAGE_ID_GETVIDEODECODERCONFIG_OUTOFMEMORY_RETURN"),
-            (3145775, "D3D11_MESSAGE_ID_GETDECODERCREATIONPARAMS_NULLPARAM"),
-            (3145776, "D3D11_MESSAGE_ID_GETDECODERDRIVERHANDLE_NULLPARAM"),
-            (3145777, "D3D11_MESSAGE_ID_GETDECODERBUFFER_NULLPARAM"),
-            (3145778, "D3D11_MESSAGE_ID_GETDECODERBUFFER_INVALIDBUFFER"),
-            (3145779, "D3D11_MESSAGE_ID_GETDECODERBUFFER_INVALIDTYPE"),
-            (3145780, "D3D11_MESSAGE_ID_GETDECODERBUFFER_LOCKED"),
-            (3145781, "D3D11_MESSAGE_ID_RELEASEDECODERBUFFER_NULLPARAM"),
-            (3145782, "D3D11_MESSAGE_ID_RELEASEDECODERBUFFER_INVALIDTYPE"),
-            (3145783, "D3D11_MESSAGE_ID_RELEASEDECODERBUFFER_NOTLOCKED"),
-            (3145784, "D3D11_MESSAGE_ID_DECODERBEGINFRAME_NULLPARAM"),
-            (3145785, "D3D11_MESSAGE_ID_DECODERBEGINFRAME_HAZARD"),
-            (3145786, "D3D11_MESSAGE_ID_DECODERENDFRAME_NULLPARAM"),
-            (3145787, "D3D11_MESSAGE_ID_SUBMITDECODERBUFFERS_NULLPARAM"),
-            (3145788, "D3D11_MESSAGE_ID_SUBMITDECODERBUFFERS_INVALIDTYPE"),
-            (3145789, "D3D11_MESSAGE_ID_DECODEREXTENSION_NULLPARAM"),
-            (3145790, "D3D11_MESSAGE_ID_DECODEREXTENSION_INVALIDRESOURCE"),
-            (3145791, "D3D11_MESSAGE_ID_CREATEVIDEOPROCESSORENUMERATOR_OUTOFMEMORY_RETURN"),
-            (3145792, "D3D11_MESSAGE_ID_CREATEVIDEOPROCESSORENUMERATOR_NULLPARAM"),
-            (3145793, "D3D11_MESSAGE_ID_CREATEVIDEOPROCESSORENUMERATOR_INVALIDFRAMEFORMAT"),
-            (3145794, "D3D11_MESSAGE_ID_CREATEVIDEOPROCESSORENUMERATOR_INVALIDUSAGE"),
-            (3145795, "D3D11_MESSAGE_ID_CREATEVIDEOPROCESSORENUMERATOR_INVALIDINPUTFRAMERATE"),
-            (3145796, "D3D11_MESSAGE_ID_CREATEVIDEOPROCESSORENUMERATOR_INVALIDOUTPUTFRAMERATE"),
-            (3145797, "D3D11_MESSAGE_ID_CREATEVIDEOPROCESSORENUMERATOR_INVALIDWIDTHHEIGHT"),
-            (3145798, "D3D11_MESSAGE_ID_GETVIDEOPROCESSORCONTENTDESC_NULLPARAM"),
-            (3145799, "D3D11_MESSAGE_ID_CHECKVIDEOPROCESSORFORMAT_NULLPARAM"),
-            (3145800, "D3D11_MESSAGE_ID_GETVIDEOPROCESSORCAPS_NULLPARAM"),
-            (3145801, "D3D11_MESSAGE_ID_GETVIDEOPROCESSORRATECONVERSIONCAPS_NULLPARAM"),
-            (3145802, "D3D11_MESSAGE_ID_GETVIDEOPROCESSORRATECONVERSIONCAPS_INVALIDINDEX"),
-            (3145803, "D3D11_MESSAGE_ID_GETVIDEOPROCESSORCUSTOMRATE_NULLPARAM"),
-            (3145804, "D3D11_MESSAGE_ID_GETVIDEOPROCESSORCUSTOMRATE_INVALIDINDEX"),
-            (3145805, "D3D11_MESSAGE_ID_GETVIDEOPROCESSORFILTERRANGE_NULLPARAM"),
-            (3145806, "D3D11_MESSAGE_ID_GETVIDEOPROCESSORFILTERRANGE_UNSUPPORTED"),
-            (3145807, "D3D11_MESSAGE_ID_CREATEVIDEOPROCESSOR_OUTOFMEMORY_RETURN"),
-            (3145808, "D3D11_MESSAGE_ID_CREATEVIDEOPROCESSOR_NULLPARAM"),
-            (3145809, "D3D11_MESSAGE_ID_VIDEOPROCESSORSETOUTPUTTARGETRECT_NULLPARAM"),
-            (3145810, "D3D11_MESSAGE_ID_VIDEOPROCESSORSETOUTPUTBACKGROUNDCOLOR_NULLPARAM"),
-            (3145811, "D3D11_MESSAGE_ID_VIDEOPROCESSORSETOUTPUTBACKGROUNDCOLOR_INVALIDALPHA"),
-            (3145812, "D3D11_MESSAGE_ID_VIDEOPROCESSORSETOUTPUTCOLORSPACE_NULLPARAM"),
-            (3145813, "D3D11_MESSAGE_ID_VIDEOPROCESSORSETOUTPUTALPHAFILLMODE_NULLPARAM"),
-            (3145814, "D3D11_MESSAGE_ID_VIDEOPROCESSORSETOUTPUTALPHAFILLMODE_UNSUPPORTED"),
-            (3145815, "D3D11_MESSAGE_ID_VIDEOPROCESSORSETOUTPUTALPHAFILLMODE_INVALIDSTREAM"),
-            (3145816, "D3D11_MESSAGE_ID_VIDEOPROCESSORSETOUTPUTALPHAFILLMODE_INVALIDFILLMODE"),
-            (3145817, "D3D11_MESSAGE_ID_VIDEOPROCESSORSETOUTPUTCONSTRICTION_NULLPARAM"),
-            (3145818, "D3D11_MESSAGE_ID_VIDEOPROCESSORSETOUTPUTSTEREOMODE_NULLPARAM"),
-            (3145819, "D3D11_MESSAGE_ID_VIDEOPROCESSORSETOUTPUTSTEREOMODE_UNSUPPORTED"),
-            (3145820, "D3D11_MESSAGE_ID_VIDEOPROCESSORSETOUTPUTEXTENSION_NULLPARAM"),
-            (3145821, "D3D11_MESSAGE_ID_VIDEOPROCESSORGETOUTPUTTARGETRECT_NULLPARAM"),
-            (3145822, "D3D11_MESSAGE_ID_VIDEOPROCESSORGETOUTPUTBACKGROUNDCOLOR_NULLPARAM"),
-            (3145823, "D3D11_MESSAGE_ID_VIDEOPROCESSORGETOUTPUTCOLORSPACE_NULLPARAM"),
-            (3145824, "D3D11_MESSAGE_ID_VIDEOPROCESSORGETOUTPUTALPHAFILLMODE_NULLPARAM"),
-            (3145825, "D3D11_MESSAGE_ID_VIDEOPROCESSORGETOUTPUTCONSTRICTION_NULLPARAM"),
-            (3145826, "D3D11_MESSAGE_ID_VIDEOPROCESSORSETOUTPUTCONSTRICTION_UNSUPPORTED"),
-            (3145827, "D3D11_MESSAGE_ID_VIDEOPROCESSORSETOUTPUTCONSTRICTION_INVALIDSIZE"),
-            (3145828, "D3D11_MESSAGE_ID_VIDEOPROCESSORGETOUTPUTSTEREOMODE_NULLPARAM"),
-            (3145829, "D3D11_MESSAGE_ID_VIDEOPROCESSORGETOUTPUTEXTENSION_NULLPARAM"),
-            (3145830, "D3D11_MESSAGE_ID_VIDEOPROCESSORSETSTREAMFRAMEFORMAT_NULLPARAM"),
-            (3145831, "D3D11_MESSAGE_ID_VIDEOPROCESSORSETSTREAMFRAMEFORMAT_INVALIDFORMAT"),
-            (3145832, "D3D11_MESSAGE_ID_VIDEOPROCESSORSETSTREAMFRAMEFORMAT_INVALIDSTREAM"),
-            (3145833, "D3D11_MESSAGE_ID_VIDEOPROCESSORSETSTREAMCOLORSPACE_NULLPARAM"),
-            (3145834, "D3D11_MESSAGE_ID_VIDEOPROCESSORSETSTREAMCOLORSPACE_INVALIDSTREAM"),
-            (3145835, "D3D11_MESSAGE_ID_VIDEOPROCESSORSETSTREAMOUTPUTRATE_NULLPARAM"),
-            (3145836, "D3D11_MESSAGE_ID_VIDEOPROCESSORSETSTREAMOUTPUTRATE_INVALIDRATE"),
-            (3145837, "D3D11_MESSAGE_ID_VIDEOPROCESSORSETSTREAMOUTPUTRATE_INVALIDFLAG"),
-            (3145838, "D3D11_MESSAGE_ID_VIDEOPROCESSORSETSTREAMOUTPUTRATE_INVALIDSTREAM"),
-            (3145839, "D3D11_MESSAGE_ID_VIDEOPROCESSORSETSTREAMSOURCERECT_NULLPARAM"),
-            (3145840, "D3D11_MESSAGE_ID_VIDEOPROCESSORSETSTREAMSOURCERECT_INVALIDSTREAM"),
-            (3145841, "D3D11_MESSAGE_ID_VIDEOPROCESSORSETSTREAMSOURCERECT_INVALIDRECT"),
-            (3145842, "D3D11_MESSAGE_ID_VIDEOPROCESSORSETSTREAMDESTRECT_NULLPARAM"),
-            (3145843, "D3D11_MESSAGE_ID_VIDEOPROCESSORSETSTREAMDESTRECT_INVALIDSTREAM"),
-            (3145844, "D3D11_MESSAGE_ID_VIDEOPROCESSORSETSTREAMDESTRECT_INVALIDRECT"),
-            (3145845, "D3D11_MESSAGE_ID_VIDEOPROCESSORSETSTREAMALPHA_NULLPARAM"),
-            (3145846, "D3D11_MESSAGE_ID_VIDEOPROCESSORSETSTREAMALPHA_INVALIDSTREAM"),
-            (3145847, "D3D11_MESSAGE_ID_VIDEOPROCESSORSETSTREAMALPHA_INVALIDALPHA"),
-            (3145848, "D3D11_MESSAGE_ID_VIDEOPROCESSORSETSTREAMPALETTE_NULLPARAM"),
-            (3145849, "D3D11_MESSAGE_ID_VIDEOPROCESSORSETSTREAMPALETTE_INVALIDSTREAM"),
-            (3145850, "D3D11_MESSAGE_ID_VIDEOPROCESSORSETSTREAMPALETTE_INVALIDCOUNT"),
-            (3145851, "D3D11_MESSAGE_ID_VIDEOPROCESSORSETSTREAMPALETTE_INVALIDALPHA"),
-            (3145852, "D3D11_MESSAGE_ID_VIDEOPROCESSORSETSTREAMPIXELASPECTRATIO_NULLPARAM"),
-            (3145853, "D3D11_MESSAGE_ID_VIDEOPROCESSORSETSTREAMPIXELASPECTRATIO_INVALIDSTREAM"),
-            (3145854, "D3D11_MESSAGE_ID_VIDEOPROCESSORSETSTREAMPIXELASPECTRATIO_INVALIDRATIO"),
-            (3145855, "D3D11_MESSAGE_ID_VIDEOPROCESSORSETSTREAMLUMAKEY_NULLPARAM"),
-            (3145856, "D3D11_MESSAGE_ID_VIDEOPROCESSORSETSTREAMLUMAKEY_INVALIDSTREAM"),
-            (3145857, "D3D11_MESSAGE_ID_VIDEOPROCESSORSETSTREAMLUMAKEY_INVALIDRANGE"),
-            (3145858, "D3D11_MESSAGE_ID_VIDEOPROCESSORSETSTREAMLUMAKEY_UNSUPPORTED"),
-            (3145859, "D3D11_MESSAGE_ID_VIDEOPROCESSORSETSTREAMSTEREOFORMAT_NULLPARAM"),
-            (3145860, "D3D11_MESSAGE_ID_VIDEOPROCESSORSETSTREAMSTEREOFORMAT_INVALIDSTREAM"),
-            (3145861, "D3D11_MESSAGE_ID_VIDEOPROCESSORSETSTREAMSTEREOFORMAT_UNSUPPORTED"),
-            (3145862, "D3D11_MESSAGE_ID_VIDEOPROCESSORSETSTREAMSTEREOFORMAT_FLIPUNSUPPORTED"),
-            (3145863, "D3D11_MESSAGE_ID_VIDEOPROCESSORSETSTREAMSTEREOFORMAT_MONOOFFSETUNSUPPORTED"),
-            (3145864, "D3D11_MESSAGE_ID_VIDEOPROCESSORSETSTREAMSTEREOFORMAT_FORMATUNSUPPORTED"),
-            (3145865, "D3D11_MESSAGE_ID_VIDEOPROCESSORSETSTREAMSTEREOFORMAT_INVALIDFORMAT"),
-            (3145866, "D3D11_MESSAGE_ID_VIDEOPROCESSORSETSTREAMAUTOPROCESSINGMODE_NULLPARAM"),
-            (3145867, "D3D11_MESSAGE_ID_VIDEOPROCESSORSETSTREAMAUTOPROCESSINGMODE_INVALIDSTREAM"),
-            (3145868, "D3D11_MESSAGE_ID_VIDEOPROCESSORSETSTREAMFILTER_NULLPARAM"),
-            (3145869, "D3D11_MESSAGE_ID_VIDEOPROCESSORSETSTREAMFILTER_INVALIDSTREAM"),
-            (3145870, "D3D11_MESSAGE_ID_VIDEOPROCESSORSETSTREAMFILTER_INVALIDFILTER"),
-            (3145871, "D3D11_MESSAGE_ID_VIDEOPROCESSORSETSTREAMFILTER_UNSUPPORTED"),
-            (3145872, "D3D11_MESSAGE_ID_VIDEOPROCESSORSETSTREAMFILTER_INVALIDLEVEL"),
-            (3145873, "D3D11_MESSAGE_ID_VIDEOPROCESSORSETSTREAMEXTENSION_NULLPARAM"),
-            (3145874, "D3D11_MESSAGE_ID_VIDEOPROCESSORSETSTREAMEXTENSION_INVALIDSTREAM"),
-            (3145875, "D3D11_MESSAGE_ID_VIDEOPROCESSORGETSTREAMFRAMEFORMAT_NULLPARAM"),
-            (3145876, "D3D11_MESSAGE_ID_VIDEOPROCESSORGETSTREAMCOLORSPACE_NULLPARAM"),
-            (3145877, "D3D11_MESSAGE_ID_VIDEOPROCESSORGETSTREAMOUTPUTRATE_NULLPARAM"),
-            (3145878, "D3D11_MESSAGE_ID_VIDEOPROCESSORGETSTREAMSOURCERECT_NULLPARAM"),
-            (3145879, "D3D11_MESSAGE_ID_VIDEOPROCESSORGETSTREAMDESTRECT_NULLPARAM"),
-            (3145880, "D3D11_MESSAGE_ID_VIDEOPROCESSORGETSTREAMALPHA_NULLPARAM"),
-            (3145881, "D3D11_MESSAGE_ID_VIDEOPROCESSORGETSTREAMPALETTE_NULLPARAM"),
-            (3145882, "D3D11_MESSAGE_ID_VIDEOPROCESSORGETSTREAMPIXELASPECTRATIO_NULLPARAM"),
-            (3145883, "D3D11_MESSAGE_ID_VIDEOPROCESSORGETSTREAMLUMAKEY_NULLPARAM"),
-            (3145884, "D3D11_MESSAGE_ID_VIDEOPROCESSORGETSTREAMSTEREOFORMAT_NULLPARAM"),
-            (3145885, "D3D11_MESSAGE_ID_VIDEOPROCESSORGETSTREAMAUTOPROCESSINGMODE_NULLPARAM"),
-            (3145886, "D3D11_MESSAGE_ID_VIDEOPROCESSORGETSTREAMFILTER_NULLPARAM"),
-            (3145887, "D3D11_MESSAGE_ID_VIDEOPROCESSORGETSTREAMEXTENSION_NULLPARAM"),
-            (3145888, "D3D11_MESSAGE_ID_VIDEOPROCESSORGETSTREAMEXTENSION_INVALIDSTREAM"),
-            (3145889, "D3D11_MESSAGE_ID_VIDEOPROCESSORBLT_NULLPARAM"),
-            (3145890, "D3D11_MESSAGE_ID_VIDEOPROCESSORBLT_INVALIDSTREAMCOUNT"),
-            (3145891, "D3D11_MESSAGE_ID_VIDEOPROCESSORBLT_TARGETRECT"),
-            (3145892, "D3D11_MESSAGE_ID_VIDEOPROCESSORBLT_INVALIDOUTPUT"),
-            (3145893, "D3D11_MESSAGE_ID_VIDEOPROCESSORBLT_INVALIDPASTFRAMES"),
-            (3145894, "D3D11_MESSAGE_ID_VIDEOPROCESSORBLT_INVALIDFUTUREFRAMES"),
-            (3145895, "D3D11_MESSAGE_ID_VIDEOPROCESSORBLT_INVALIDSOURCERECT"),
-            (3145896, "D3D11_MESSAGE_ID_VIDEOPROCESSORBLT_INVALIDDESTRECT"),
-            (3145897, "D3D11_MESSAGE_ID_VIDEOPROCESSORBLT_INVALIDINPUTRESOURCE"),
-            (3145898, "D3D11_MESSAGE_ID_VIDEOPROCESSORBLT_INVALIDARRAYSIZE"),
-            (3145899, "D3D11_MESSAGE_ID_VIDEOPROCESSORBLT_INVALIDARRAY"),
-            (3145900, "D3D11_MESSAGE_ID_VIDEOPROCESSORBLT_RIGHTEXPECTED"),
-            (3145901, "D3D11_MESSAGE_ID_VIDEOPROCESSORBLT_RIGHTNOTEXPECTED"),
-            (3145902, "D3D11_MESSAGE_ID_VIDEOPROCESSORBLT_STEREONOTENABLED"),
-            (3145903, "D3D11_MESSAGE_ID_VIDEOPROCESSORBLT_INVALIDRIGHTRESOURCE"),
-            (3145904, "D3D11_MESSAGE_ID_VIDEOPROCESSORBLT_NOSTEREOSTREAMS"),
-            (3145905, "D3D11_MESSAGE_ID_VIDEOPROCESSORBLT_INPUTHAZARD"),
-            (3145906, "D3D11_MESSAGE_ID_VIDEOPROCESSORBLT_OUTPUTHAZARD"),
-            (3145907, "D3D11_MESSAGE_ID_CREATEVIDEODECODEROUTPUTVIEW_OUTOFMEMORY_RETURN"),
-            (3145908, "D3D11_MESSAGE_ID_CREATEVIDEODECODEROUTPUTVIEW_NULLPARAM"),
-            (3145909, "D3D11_MESSAGE_ID_CREATEVIDEODECODEROUTPUTVIEW_INVALIDTYPE"),
-            (3145910, "D3D11_MESSAGE_ID_CREATEVIDEODECODEROUTPUTVIEW_INVALIDBIND"),
-            (3145911, "D3D11_MESSAGE_ID_CREATEVIDEODECODEROUTPUTVIEW_UNSUPPORTEDFORMAT"),
-            (3145912, "D3D11_MESSAGE_ID_CREATEVIDEODECODEROUTPUTVIEW_INVALIDMIP"),
-            (3145913, "D3D11_MESSAGE_ID_CREATEVIDEODECODEROUTPUTVIEW_UNSUPPORTEMIP"),
-            (3145914, "D3D11_MESSAGE_ID_CREATEVIDEODECODEROUTPUTVIEW_INVALIDARRAYSIZE"),
-            (3145915, "D3D11_MESSAGE_ID_CREATEVIDEODECODEROUTPUTVIEW_INVALIDARRAY"),
-            (3145916, "D3D11_MESSAGE_ID_CREATEVIDEODECODEROUTPUTVIEW_INVALIDDIMENSION"),
-            (3145917, "D3D11_MESSAGE_ID_CREATEVIDEOPROCESSORINPUTVIEW_OUTOFMEMORY_RETURN"),
-            (3145918, "D3D11_MESSAGE_ID_CREATEVIDEOPROCESSORINPUTVIEW_NULLPARAM"),
-            (3145919, "D3D11_MESSAGE_ID_CREATEVIDEOPROCESSORINPUTVIEW_INVALIDTYPE"),
-            (3145920, "D3D11_MESSAGE_ID_CREATEVIDEOPROCESSORINPUTVIEW_INVALIDBIND"),
-            (3145921, "D3D11_MESSAGE_ID_CREATEVIDEOPROCESSORINPUTVIEW_INVALIDMISC"),
-            (3145922, "D3D11_MESSAGE_ID_CREATEVIDEOPROCESSORINPUTVIEW_INVALIDUSAGE"),
-            (3145923, "D3D11_MESSAGE_ID_CREATEVIDEOPROCESSORINPUTVIEW_INVALIDFORMAT"),
-            (3145924, "D3D11_MESSAGE_ID_CREATEVIDEOPROCESSORINPUTVIEW_INVALIDFOURCC"),
-            (3145925, "D3D11_MESSAGE_ID_CREATEVIDEOPROCESSORINPUTVIEW_INVALIDMIP"),
-            (3145926, "D3D11_MESSAGE_ID_CREATEVIDEOPROCESSORINPUTVIEW_UNSUPPORTEDMIP"),
-            (3145927, "D3D11_MESSAGE_ID_CREATEVIDEOPROCESSORINPUTVIEW_INVALIDARRAYSIZE"),
-            (3145928, "D3D11_MESSAGE_ID_CREATEVIDEOPROCESSORINPUTVIEW_INVALIDARRAY"),
-            (3145929, "D3D11_MESSAGE_ID_CREATEVIDEOPROCESSORINPUTVIEW_INVALIDDIMENSION"),
-            (3145930, "D3D11_MESSAGE_ID_CREATEVIDEOPROCESSOROUTPUTVIEW_OUTOFMEMORY_RETURN"),
-            (3145931, "D3D11_MESSAGE_ID_CREATEVIDEOPROCESSOROUTPUTVIEW_NULLPARAM"),
-            (3145932, "D3D11_MESSAGE_ID_CREATEVIDEOPROCESSOROUTPUTVIEW_INVALIDTYPE"),
-            (3145933, "D3D11_MESSAGE_ID_CREATEVIDEOPROCESSOROUTPUTVIEW_INVALIDBIND"),
-            (3145934, "D3D11_MESSAGE_ID_CREATEVIDEOPROCESSOROUTPUTVIEW_INVALIDFORMAT"),
-            (3145935, "D3D11_MESSAGE_ID_CREATEVIDEOPROCESSOROUTPUTVIEW_INVALIDMIP"),
-            (3145936, "D3D11_MESSAGE_ID_CREATEVIDEOPROCESSOROUTPUTVIEW_UNSUPPORTEDMIP"),
-            (3145937, "D3D11_MESSAGE_ID_CREATEVIDEOPROCESSOROUTPUTVIEW_UNSUPPORTEDARRAY"),
-            (3145938, "D3D11_MESSAGE_ID_CREATEVIDEOPROCESSOROUTPUTVIEW_INVALIDARRAY"),
-            (3145939, "D3D11_MESSAGE_ID_CREATEVIDEOPROCESSOROUTPUTVIEW_INVALIDDIMENSION"),
-            (3145940, "D3D11_MESSAGE_ID_DEVICE_DRAW_INVALID_USE_OF_FORCED_SAMPLE_COUNT"),
-            (3145941, "D3D11_MESSAGE_ID_CREATEBLENDSTATE_INVALIDLOGICOPS"),
-            (3145942, "D3D11_MESSAGE_ID_CREATESHADERRESOURCEVIEW_INVALIDDARRAYWITHDECODER"),
-            (3145943, "D3D11_MESSAGE_ID_CREATEUNORDEREDACCESSVIEW_INVALIDDARRAYWITHDECODER"),
-            (3145944, "D3D11_MESSAGE_ID_CREATERENDERTARGETVIEW_INVALIDDARRAYWITHDECODER"),
-            (3145945, "D3D11_MESSAGE_ID_DEVICE_LOCKEDOUT_INTERFACE"),
-            (3145946, "D3D11_MESSAGE_ID_REF_WARNING_ATOMIC_INCONSISTENT"),
-            (3145947, "D3D11_MESSAGE_ID_REF_WARNING_READING_UNINITIALIZED_RESOURCE"),
-            (3145948, "D3D11_MESSAGE_ID_REF_WARNING_RAW_HAZARD"),
-            (3145949, "D3D11_MESSAGE_ID_REF_WARNING_WAR_HAZARD"),
-            (3145950, "D3D11_MESSAGE_ID_REF_WARNING_WAW_HAZARD"),
-            (3145951, "D3D11_MESSAGE_ID_CREATECRYPTOSESSION_NULLPARAM"),
-            (3145952, "D3D11_MESSAGE_ID_CREATECRYPTOSESSION_OUTOFMEMORY_RETURN"),
-            (3145953, "D3D11_MESSAGE_ID_GETCRYPTOTYPE_NULLPARAM"),
-            (3145954, "D3D11_MESSAGE_ID_GETDECODERPROFILE_NULLPARAM"),
-            (3145955, "D3D11_MESSAGE_ID_GETCRYPTOSESSIONCERTIFICATESIZE_NULLPARAM"),
-            (3145956, "D3D11_MESSAGE_ID_GETCRYPTOSESSIONCERTIFICATE_NULLPARAM"),
-            (3145957, "D3D11_MESSAGE_ID_GETCRYPTOSESSIONCERTIFICATE_WRONGSIZE"),
-            (3145958, "D3D11_MESSAGE_ID_GETCRYPTOSESSIONHANDLE_WRONGSIZE"),
-            (3145959, "D3D11_MESSAGE_ID_NEGOTIATECRPYTOSESSIONKEYEXCHANGE_NULLPARAM"),
-            (3145960, "D3D11_MESSAGE_ID_ENCRYPTIONBLT_UNSUPPORTED"),
-            (3145961, "D3D11_MESSAGE_ID_ENCRYPTIONBLT_NULLPARAM"),
-            (3145962, "D3D11_MESSAGE_ID_ENCRYPTIONBLT_SRC_WRONGDEVICE"),
-            (3145963, "D3D11_MESSAGE_ID_ENCRYPTIONBLT_DST_WRONGDEVICE"),
-            (3145964, "D3D11_MESSAGE_ID_ENCRYPTIONBLT_FORMAT_MISMATCH"),
-            (3145965, "D3D11_MESSAGE_ID_ENCRYPTIONBLT_SIZE_MISMATCH"),
-            (3145966, "D3D11_MESSAGE_ID_ENCRYPTIONBLT_SRC_MULTISAMPLED"),
-            (3145967, "D3D11_MESSAGE_ID_ENCRYPTIONBLT_DST_NOT_STAGING"),
-            (3145968, "D3D11_MESSAGE_ID_ENCRYPTIONBLT_SRC_MAPPED"),
-            (3145969, "D3D11_MESSAGE_ID_ENCRYPTIONBLT_DST_MAPPED"),
-            (3145970, "D3D11_MESSAGE_ID_ENCRYPTIONBLT_SRC_OFFERED"),
-            (3145971, "D3D11_MESSAGE_ID_ENCRYPTIONBLT_DST_OFFERED"),
-            (3145972, "D3D11_MESSAGE_ID_ENCRYPTIONBLT_SRC_CONTENT_UNDEFINED"),
-            (3145973, "D3D11_MESSAGE_ID_DECRYPTIONBLT_UNSUPPORTED"),
-            (3145974, "D3D11_MESSAGE_ID_DECRYPTIONBLT_NULLPARAM"),
-            (3145975, "D3D11_MESSAGE_ID_DECRYPTIONBLT_SRC_WRONGDEVICE"),
-            (3145976, "D3D11_MESSAGE_ID_DECRYPTIONBLT_DST_WRONGDEVICE"),
-            (3145977, "D3D11_MESSAGE_ID_DECRYPTIONBLT_FORMAT_MISMATCH"),
-            (3145978, "D3D11_MESSAGE_ID_DECRYPTIONBLT_SIZE_MISMATCH"),
-            (3145979, "D3D11_MESSAGE_ID_DECRYPTIONBLT_DST_MULTISAMPLED"),
-            (3145980, "D3D11_MESSAGE_ID_DECRYPTIONBLT_SRC_NOT_STAGING"),
-            (3145981, "D3D11_MESSAGE_ID_DECRYPTIONBLT_DST_NOT_RENDER_TARGET"),
-            (3145982, "D3D11_MESSAGE_ID_DECRYPTIONBLT_SRC_MAPPED"),
-            (3145983, "D3D11_MESSAGE_ID_DECRYPTIONBLT_DST_MAPPED"),
-            (3145984, "D3D11_MESSAGE_ID_DECRYPTIONBLT_SRC_OFFERED"),
-            (3145985, "D3D11_MESSAGE_ID_DECRYPTIONBLT_DST_OFFERED"),
-            (3145986, "D3D11_MESSAGE_ID_DECRYPTIONBLT_SRC_CONTENT_UNDEFINED"),
-            (3145987, "D3D11_MESSAGE_ID_STARTSESSIONKEYREFRESH_NULLPARAM"),
-            (3145988, "D3D11_MESSAGE_ID_STARTSESSIONKEYREFRESH_INVALIDSIZE"),
-            (3145989, "D3D11_MESSAGE_ID_FINISHSESSIONKEYREFRESH_NULLPARAM"),
-            (3145990, "D3D11_MESSAGE_ID_GETENCRYPTIONBLTKEY_NULLPARAM"),
-            (3145991, "D3D11_MESSAGE_ID_GETENCRYPTIONBLTKEY_INVALIDSIZE"),
-            (3145992, "D3D11_MESSAGE_ID_GETCONTENTPROTECTIONCAPS_NULLPARAM"),
-            (3145993, "D3D11_MESSAGE_ID_CHECKCRYPTOKEYEXCHANGE_NULLPARAM"),
-            (3145994, "D3D11_MESSAGE_ID_CHECKCRYPTOKEYEXCHANGE_INVALIDINDEX"),
-            (3145995, "D3D11_MESSAGE_ID_CREATEAUTHENTICATEDCHANNEL_NULLPARAM"),
-            (3145996, "D3D11_MESSAGE_ID_CREATEAUTHENTICATEDCHANNEL_UNSUPPORTED"),
-            (3145997, "D3D11_MESSAGE_ID_CREATEAUTHENTICATEDCHANNEL_INVALIDTYPE"),
-            (3145998, "D3D11_MESSAGE_ID_CREATEAUTHENTICATEDCHANNEL_OUTOFMEMORY_RETURN"),
-            (3145999, "D3D11_MESSAGE_ID_GETAUTHENTICATEDCHANNELCERTIFICATESIZE_INVALIDCHANNEL"),
-            (3146000, "D3D11_MESSAGE_ID_GETAUTHENTICATEDCHANNELCERTIFICATESIZE_NULLPARAM"),
-            (3146001, "D3D11_MESSAGE_ID_GETAUTHENTICATEDCHANNELCERTIFICATE_INVALIDCHANNEL"),
-            (3146002, "D3D11_MESSAGE_ID_GETAUTHENTICATEDCHANNELCERTIFICATE_NULLPARAM"),
-            (3146003, "D3D11_MESSAGE_ID_GETAUTHENTICATEDCHANNELCERTIFICATE_WRONGSIZE"),
-            (3146004, "D3D11_MESSAGE_ID_NEGOTIATEAUTHENTICATEDCHANNELKEYEXCHANGE_INVALIDCHANNEL"),
-            (3146005, "D3D11_MESSAGE_ID_NEGOTIATEAUTHENTICATEDCHANNELKEYEXCHANGE_NULLPARAM"),
-            (3146006, "D3D11_MESSAGE_ID_QUERYAUTHENTICATEDCHANNEL_NULLPARAM"),
-            (3146007, "D3D11_MESSAGE_ID_QUERYAUTHENTICATEDCHANNEL_WRONGCHANNEL"),
-            (3146008, "D3D11_MESSAGE_ID_QUERYAUTHENTICATEDCHANNEL_UNSUPPORTEDQUERY"),
-            (3146009, "D3D11_MESSAGE_ID_QUERYAUTHENTICATEDCHANNEL_WRONGSIZE"),
-            (3146010, "D3D11_MESSAGE_ID_QUERYAUTHENTICATEDCHANNEL_INVALIDPROCESSINDEX"),
-            (3146011, "D3D11_MESSAGE_ID_CONFIGUREAUTHENTICATEDCHANNEL_NULLPARAM"),
-            (3146012, "D3D11_MESSAGE_ID_CONFIGUREAUTHENTICATEDCHANNEL_WRONGCHANNEL"),
-            (3146013, "D3D11_MESSAGE_ID_CONFIGUREAUTHENTICATEDCHANNEL_UNSUPPORTEDCONFIGURE"),
-            (3146014, "D3D11_MESSAGE_ID_CONFIGUREAUTHENTICATEDCHANNEL_WRONGSIZE"),
-            (3146015, "D3D11_MESSAGE_ID_CONFIGUREAUTHENTICATEDCHANNEL_INVALIDPROCESSIDTYPE"),
-            (3146016, "D3D11_MESSAGE_ID_VSSETCONSTANTBUFFERS_INVALIDBUFFEROFFSETORCOUNT"),
-            (3146017, "D3D11_MESSAGE_ID_DSSETCONSTANTBUFFERS_INVALIDBUFFEROFFSETORCOUNT"),
-            (3146018, "D3D11_MESSAGE_ID_HSSETCONSTANTBUFFERS_INVALIDBUFFEROFFSETORCOUNT"),
-            (3146019, "D3D11_MESSAGE_ID_GSSETCONSTANTBUFFERS_INVALIDBUFFEROFFSETORCOUNT"),
-            (3146020, "D3D11_MESSAGE_ID_PSSETCONSTANTBUFFERS_INVALIDBUFFEROFFSETORCOUNT"),
-            (3146021, "D3D11_MESSAGE_ID_CSSETCONSTANTBUFFERS_INVALIDBUFFEROFFSETORCOUNT"),
-            (3146022, "D3D11_MESSAGE_ID_NEGOTIATECRPYTOSESSIONKEYEXCHANGE_INVALIDSIZE"),
-            (3146023, "D3D11_MESSAGE_ID_NEGOTIATEAUTHENTICATEDCHANNELKEYEXCHANGE_INVALIDSIZE"),
-            (3146024, "D3D11_MESSAGE_ID_OFFERRESOURCES_INVALIDPRIORITY"),
-            (3146025, "D3D11_MESSAGE_ID_GETCRYPTOSESSIONHANDLE_OUTOFMEMORY"),
-            (3146026, "D3D11_MESSAGE_ID_ACQUIREHANDLEFORCAPTURE_NULLPARAM"),
-            (3146027, "D3D11_MESSAGE_ID_ACQUIREHANDLEFORCAPTURE_INVALIDTYPE"),
-            (3146028, "D3D11_MESSAGE_ID_ACQUIREHANDLEFORCAPTURE_INVALIDBIND"),
-            (3146029, "D3D11_MESSAGE_ID_ACQUIREHANDLEFORCAPTURE_INVALIDARRAY"),
-            (3146030, "D3D11_MESSAGE_ID_VIDEOPROCESSORSETSTREAMROTATION_NULLPARAM"),
-            (3146031, "D3D11_MESSAGE_ID_VIDEOPROCESSORSETSTREAMROTATION_INVALIDSTREAM"),
-            (3146032, "D3D11_MESSAGE_ID_VIDEOPROCESSORSETSTREAMROTATION_INVALID"),
-            (3146033, "D3D11_MESSAGE_ID_VIDEOPROCESSORSETSTREAMROTATION_UNSUPPORTED"),
-            (3146034, "D3D11_MESSAGE_ID_VIDEOPROCESSORGETSTREAMROTATION_NULLPARAM"),
-            (3146035, "D3D11_MESSAGE_ID_DEVICE_CLEARVIEW_INVALIDVIEW"),
-            (3146036, "D3D11_MESSAGE_ID_DEVICE_CREATEVERTEXSHADER_DOUBLEEXTENSIONSNOTSUPPORTED"),
-            (3146037, "D3D11_MESSAGE_ID_DEVICE_CREATEVERTEXSHADER_SHADEREXTENSIONSNOTSUPPORTED"),
-            (3146038, "D3D11_MESSAGE_ID_DEVICE_CREATEHULLSHADER_DOUBLEEXTENSIONSNOTSUPPORTED"),
-            (3146039, "D3D11_MESSAGE_ID_DEVICE_CREATEHULLSHADER_SHADEREXTENSIONSNOTSUPPORTED"),
-            (3146040, "D3D11_MESSAGE_ID_DEVICE_CREATEDOMAINSHADER_DOUBLEEXTENSIONSNOTSUPPORTED"),
-            (3146041, "D3D11_MESSAGE_ID_DEVICE_CREATEDOMAINSHADER_SHADEREXTENSIONSNOTSUPPORTED"),
-            (3146042, "D3D11_MESSAGE_ID_DEVICE_CREATEGEOMETRYSHADER_DOUBLEEXTENSIONSNOTSUPPORTED"),
-            (3146043, "D3D11_MESSAGE_ID_DEVICE_CREATEGEOMETRYSHADER_SHADEREXTENSIONSNOTSUPPORTED"),
-            (3146044, "D3D11_MESSAGE_ID_DEVICE_CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_DOUBLEEXTENSIONSNOTSUPPORTED"),
-            (3146045, "D3D11_MESSAGE_ID_DEVICE_CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_SHADEREXTENSIONSNOTSUPPORTED"),
-            (3146046, "D3D11_MESSAGE_ID_DEVICE_CREATEPIXELSHADER_DOUBLEEXTENSIONSNOTSUPPORTED"),
-            (3146047, "D3D11_MESSAGE_ID_DEVICE_CREATEPIXELSHADER_SHADEREXTENSIONSNOTSUPPORTED"),
-            (3146048, "D3D11_MESSAGE_ID_DEVICE_CREATECOMPUTESHADER_DOUBLEEXTENSIONSNOTSUPPORTED"),
-            (3146049, "D3D11_MESSAGE_ID_DEVICE_CREATECOMPUTESHADER_SHADEREXTENSIONSNOTSUPPORTED"),
-            (3146050, "D3D11_MESSAGE_ID_DEVICE_SHADER_LINKAGE_MINPRECISION"),
-            (3146051, "D3D11_MESSAGE_ID_VIDEOPROCESSORSETSTREAMALPHA_UNSUPPORTED"),
-            (3146052, "D3D11_MESSAGE_ID_VIDEOPROCESSORSETSTREAMPIXELASPECTRATIO_UNSUPPORTED"),
-            (3146053, "D3D11_MESSAGE_ID_DEVICE_CREATEVERTEXSHADER_UAVSNOTSUPPORTED"),
-            (3146054, "D3D11_MESSAGE_ID_DEVICE_CREATEHULLSHADER_UAVSNOTSUPPORTED"),
-            (3146055, "D3D11_MESSAGE_ID_DEVICE_CREATEDOMAINSHADER_UAVSNOTSUPPORTED"),
-            (3146056, "D3D11_MESSAGE_ID_DEVICE_CREATEGEOMETRYSHADER_UAVSNOTSUPPORTED"),
-            (3146057, "D3D11_MESSAGE_ID_DEVICE_CREATEGEOMETRYSHADERWITHSTREAMOUTPUT_UAVSNOTSUPPORTED"),
-            (3146058, "D3D11_MESSAGE_ID_DEVICE_CREATEPIXELSHADER_UAVSNOTSUPPORTED"),
-            (3146059, "D3D11_MESSAGE_ID_DEVICE_CREATECOMPUTESHADER_UAVSNOTSUPPORTED"),
-            (3146060, "D3D11_MESSAGE_ID_DEVICE_OMSETRENDERTARGETSANDUNORDEREDACCESSVIEWS_INVALIDOFFSET"),
-            (3146061, "D3D11_MESSAGE_ID_DEVICE_OMSETRENDERTARGETSANDUNORDEREDACCESSVIEWS_TOOMANYVIEWS"),
-            (3146062, "D3D11_MESSAGE_ID_DEVICE_CLEARVIEW_NOTSUPPORTED"),
-            (3146063, "D3D11_MESSAGE_ID_SWAPDEVICECONTEXTSTATE_NOTSUPPORTED"),
-            (3146064, "D3D11_MESSAGE_ID_UPDATESUBRESOURCE_PREFERUPDATESUBRESOURCE1"),
-            (3146065, "D3D11_MESSAGE_ID_GETDC_INACCESSIBLE"),
-            (3146066, "D3D11_MESSAGE_ID_DEVICE_CLEARVIEW_INVALIDRECT"),
-            (3146067, "D3D11_MESSAGE_ID_DEVICE_DRAW_SAMPLE_MASK_IGNORED_ON_FL9"),
-            (3146068, "D3D11_MESSAGE_ID_DEVICE_OPEN_SHARED_RESOURCE1_NOT_SUPPORTED"),
-            (3146069, "D3D11_MESSAGE_ID_DEVICE_OPEN_SHARED_RESOURCE_BY_NAME_NOT_SUPPORTED"),
-            (3146070, "D3D11_MESSAGE_ID_ENQUEUESETEVENT_NOT_SUPPORTED"),
-            (3146071, "D3D11_MESSAGE_ID_OFFERRELEASE_NOT_SUPPORTED"),
-            (3146072, "D3D11_MESSAGE_ID_OFFERRESOURCES_INACCESSIBLE"),
-            (3146073, "D3D11_MESSAGE_ID_CREATEVIDEOPROCESSORINPUTVIEW_INVALIDMSAA"),
-            (3146074, "D3D11_MESSAGE_ID_CREATEVIDEOPROCESSOROUTPUTVIEW_INVALIDMSAA"),
-            (3146075, "D3D11_MESSAGE_ID_DEVICE_CLEARVIEW_INVALIDSOURCERECT"),
-            (3146076, "D3D11_MESSAGE_ID_DEVICE_CLEARVIEW_EMPTYRECT"),
-            (3146077, "D3D11_MESSAGE_ID_UPDATESUBRESOURCE_EMPTYDESTBOX"),
-            (3146078, "D3D11_MESSAGE_ID_COPYSUBRESOURCEREGION_EMPTYSOURCEBOX"),
-            (3146079, "D3D11_MESSAGE_ID_DEVICE_DRAW_OM_RENDER_TARGET_DOES_NOT_SUPPORT_LOGIC_OPS"),
-            (3146080, "D3D11_MESSAGE_ID_DEVICE_DRAW_DEPTHSTENCILVIEW_NOT_SET"),
-            (3146081, "D3D11_MESSAGE_ID_DEVICE_DRAW_RENDERTARGETVIEW_NOT_SET"),
-            (3146082, "D3D11_MESSAGE_ID_DEVICE_DRAW_RENDERTARGETVIEW_NOT_SET_DUE_TO_FLIP_PRESENT"),
-            (3146083, "D3D11_MESSAGE_ID_DEVICE_UNORDEREDACCESSVIEW_NOT_SET_DUE_TO_FLIP_PRESENT"),
-            (3146084, "D3D11_MESSAGE_ID_GETDATAFORNEWHARDWAREKEY_NULLPARAM"),
-            (3146085, "D3D11_MESSAGE_ID_CHECKCRYPTOSESSIONSTATUS_NULLPARAM"),
-            (3146086, "D3D11_MESSAGE_ID_GETCRYPTOSESSIONPRIVATEDATASIZE_NULLPARAM"),
-            (3146087, "D3D11_MESSAGE_ID_GETVIDEODECODERCAPS_NULLPARAM"),
-            (3146088, "D3D11_MESSAGE_ID_GETVIDEODECODERCAPS_ZEROWIDTHHEIGHT"),
-            (3146089, "D3D11_MESSAGE_ID_CHECKVIDEODECODERDOWNSAMPLING_NULLPARAM"),
-            (3146090, "D3D11_MESSAGE_ID_CHECKVIDEODECODERDOWNSAMPLING_INVALIDCOLORSPACE"),
-            (3146091, "D3D11_MESSAGE_ID_CHECKVIDEODECODERDOWNSAMPLING_ZEROWIDTHHEIGHT"),
-            (3146092, "D3D11_MESSAGE_ID_VIDEODECODERENABLEDOWNSAMPLING_NULLPARAM"),
-            (3146093, "D3D11_MESSAGE_ID_VIDEODECODERENABLEDOWNSAMPLING_UNSUPPORTED"),
-            (3146094, "D3D11_MESSAGE_ID_VIDEODECODERUPDATEDOWNSAMPLING_NULLPARAM"),
-            (3146095, "D3D11_MESSAGE_ID_VIDEODECODERUPDATEDOWNSAMPLING_UNSUPPORTED"),
-            (3146096, "D3D11_MESSAGE_ID_CHECKVIDEOPROCESSORFORMATCONVERSION_NULLPARAM"),
-            (3146097, "D3D11_MESSAGE_ID_VIDEOPROCESSORSETOUTPUTCOLORSPACE1_NULLPARAM"),
-            (3146098, "D3D11_MESSAGE_ID_VIDEOPROCESSORGETOUTPUTCOLORSPACE1_NULLPARAM"),
-            (3146099, "D3D11_MESSAGE_ID_VIDEOPROCESSORSETSTREAMCOLORSPACE1_NULLPARAM"),
-            (3146100, "D3D11_MESSAGE_ID_VIDEOPROCESSORSETSTREAMCOLORSPACE1_INVALIDSTREAM"),
-            (3146101, "D3D11_MESSAGE_ID_VIDEOPROCESSORSETSTREAMMIRROR_NULLPARAM"),
-            (3146102, "D3D11_MESSAGE_ID_VIDEOPROCESSORSETSTREAMMIRROR_INVALIDSTREAM"),
-            (3146103, "D3D11_MESSAGE_ID_VIDEOPROCESSORSETSTREAMMIRROR_UNSUPPORTED"),
-            (3146104, "D3D11_MESSAGE_ID_VIDEOPROCESSORGETSTREAMCOLORSPACE1_NULLPARAM"),
-            (3146105, "D3D11_MESSAGE_ID_VIDEOPROCESSORGETSTREAMMIRROR_NULLPARAM"),
-            (3146106, "D3D11_MESSAGE_ID_RECOMMENDVIDEODECODERDOWNSAMPLING_NULLPARAM"),
-            (3146107, "D3D11_MESSAGE_ID_RECOMMENDVIDEODECODERDOWNSAMPLING_INVALIDCOLORSPACE"),
-            (3146108, "D3D11_MESSAGE_ID_RECOMMENDVIDEODECODERDOWNSAMPLING_ZEROWIDTHHEIGHT"),
-            (3146109, "D3D11_MESSAGE_ID_VIDEOPROCESSORSETOUTPUTSHADERUSAGE_NULLPARAM"),
-            (3146110, "D3D11_MESSAGE_ID_VIDEOPROCESSORGETOUTPUTSHADERUSAGE_NULLPARAM"),
-            (3146111, "D3D11_MESSAGE_ID_VIDEOPROCESSORGETBEHAVIORHINTS_NULLPARAM"),
-            (3146112, "D3D11_MESSAGE_ID_VIDEOPROCESSORGETBEHAVIORHINTS_INVALIDSTREAMCOUNT"),
-            (3146113, "D3D11_MESSAGE_ID_VIDEOPROCESSORGETBEHAVIORHINTS_TARGETRECT"),
-            (3146114, "D3D11_MESSAGE_ID_VIDEOPROCESSORGETBEHAVIORHINTS_INVALIDSOURCERECT"),
-            (3146115, "D3D11_MESSAGE_ID_VIDEOPROCESSORGETBEHAVIORHINTS_INVALIDDESTRECT"),
-            (3146116, "D3D11_MESSAGE_ID_GETCRYPTOSESSIONPRIVATEDATASIZE_INVALID_KEY_EXCHANGE_TYPE"),
-            (3146117, "D3D11_MESSAGE_ID_DEVICE_OPEN_SHARED_RESOURCE1_ACCESS_DENIED"),
-            (3146118, "D3D11_MESSAGE_ID_D3D11_1_MESSAGES_END"),
-            (3146119, "D3D11_MESSAGE_ID_D3D11_2_MESSAGES_START"),
-            (3146120, "D3D11_MESSAGE_ID_CREATEBUFFER_INVALIDUSAGE"),
-            (3146121, "D3D11_MESSAGE_ID_CREATETEXTURE1D_INVALIDUSAGE"),
-            (3146122, "D3D11_MESSAGE_ID_CREATETEXTURE2D_INVALIDUSAGE"),
-            (3146123, "D3D11_MESSAGE_ID_CREATEINPUTLAYOUT_LEVEL9_STEPRATE_NOT_1"),
-            (3146124, "D3D11_MESSAGE_ID_CREATEINPUTLAYOUT_LEVEL9_INSTANCING_NOT_SUPPORTED"),
-            (3146125, "D3D11_MESSAGE_ID_UPDATETILEMAPPINGS_INVALID_PARAMETER"),
-            (3146126, "D3D11_MESSAGE_ID_COPYTILEMAPPINGS_INVALID_PARAMETER"),
-            (3146127, "D3D11_MESSAGE_ID_COPYTILES_INVALID_PARAMETER"),
-            (3146128, "D3D11_MESSAGE_ID_UPDATETILES_INVALID_PARAMETER"),
-            (3146129, "D3D11_MESSAGE_ID_RESIZETILEPOOL_INVALID_PARAMETER"),
-            (3146130, "D3D11_MESSAGE_ID_TILEDRESOURCEBARRIER_INVALID_PARAMETER"),
-            (3146131, "D3D11_MESSAGE_ID_NULL_TILE_MAPPING_ACCESS_WARNING"),
-            (3146132, "D3D11_MESSAGE_ID_NULL_TILE_MAPPING_ACCESS_ERROR"),
-            (3146133, "D3D11_MESSAGE_ID_DIRTY_TILE_MAPPING_ACCESS"),
-            (3146134, "D3D11_MESSAGE_ID_DUPLICATE_TILE_MAPPINGS_IN_COVERED_AREA"),
-            (3146135, "D3D11_MESSAGE_ID_TILE_MAPPINGS_IN_COVERED_AREA_DUPLICATED_OUTSIDE"),
-            (3146136, "D3D11_MESSAGE_ID_TILE_MAPPINGS_SHARED_BETWEEN_INCOMPATIBLE_RESOURCES"),
-            (3146137, "D3D11_MESSAGE_ID_TILE_MAPPINGS_SHARED_BETWEEN_INPUT_AND_OUTPUT"),
-            (3146138, "D3D11_MESSAGE_ID_CHECKMULTISAMPLEQUALITYLEVELS_INVALIDFLAGS"),
-            (3146139, "D3D11_MESSAGE_ID_GETRESOURCETILING_NONTILED_RESOURCE"),
-            (3146140, "D3D11_MESSAGE_ID_RESIZETILEPOOL_SHRINK_WITH_MAPPINGS_STILL_DEFINED_PAST_END"),
-            (3146141, "D3D11_MESSAGE_ID_NEED_TO_CALL_TILEDRESOURCEBARRIER"),
-            (3146142, "D3D11_MESSAGE_ID_CREATEDEVICE_INVALIDARGS"),
-            (3146143, "D3D11_MESSAGE_ID_CREATEDEVICE_WARNING"),
-            (3146144, "D3D11_MESSAGE_ID_CLEARUNORDEREDACCESSVIEWUINT_HAZARD"),
-            (3146145, "D3D11_MESSAGE_ID_CLEARUNORDEREDACCESSVIEWFLOAT_HAZARD"),
-            (3146146, "D3D11_MESSAGE_ID_TILED_RESOURCE_TIER_1_BUFFER_TEXTURE_MISMATCH"),
-            (3146147, "D3D11_MESSAGE_ID_CREATE_CRYPTOSESSION"),
-            (3146148, "D3D11_MESSAGE_ID_CREATE_AUTHENTICATEDCHANNEL"),
-            (3146149, "D3D11_MESSAGE_ID_LIVE_CRYPTOSESSION"),
-            (3146150, "D3D11_MESSAGE_ID_LIVE_AUTHENTICATEDCHANNEL"),
-            (3146151, "D3D11_MESSAGE_ID_DESTROY_CRYPTOSESSION"),
-            (3146152, "D3D11_MESSAGE_ID_DESTROY_AUTHENTICATEDCHANNEL"),
-            (3146153, "D3D11_MESSAGE_ID_D3D11_2_MESSAGES_END"),
-            (3146154, "D3D11_MESSAGE_ID_D3D11_3_MESSAGES_START"),
-            (3146155, "D3D11_MESSAGE_ID_CREATERASTERIZERSTATE_INVALID_CONSERVATIVERASTERMODE"),
-            (3146156, "D3D11_MESSAGE_ID_DEVICE_DRAW_INVALID_SYSTEMVALUE"),
-            (3146157, "D3D11_MESSAGE_ID_CREATEQUERYORPREDICATE_INVALIDCONTEXTTYPE"),
-            (3146158, "D3D11_MESSAGE_ID_CREATEQUERYORPREDICATE_DECODENOTSUPPORTED"),
-            (3146159, "D3D11_MESSAGE_ID_CREATEQUERYORPREDICATE_ENCODENOTSUPPORTED"),
-            (3146160, "D3D11_MESSAGE_ID_CREATESHADERRESOURCEVIEW_INVALIDPLANEINDEX"),
-            (3146161, "D3D11_MESSAGE_ID_CREATESHADERRESOURCEVIEW_INVALIDVIDEOPLANEINDEX"),
-            (3146162, "D3D11_MESSAGE_ID_CREATESHADERRESOURCEVIEW_AMBIGUOUSVIDEOPLANEINDEX"),
-            (3146163, "D3D11_MESSAGE_ID_CREATERENDERTARGETVIEW_INVALIDPLANEINDEX"),
-            (3146164, "D3D11_MESSAGE_ID_CREATERENDERTARGETVIEW_INVALIDVIDEOPLANEINDEX"),
-            (3146165, "D3D11_MESSAGE_ID_CREATERENDERTARGETVIEW_AMBIGUOUSVIDEOPLANEINDEX"),
-            (3146166, "D3D11_MESSAGE_ID_CREATEUNORDEREDACCESSVIEW_INVALIDPLANEINDEX"),
-            (3146167, "D3D11_MESSAGE_ID_CREATEUNORDEREDACCESSVIEW_INVALIDVIDEOPLANEINDEX"),
-            (3146168, "D3D11_MESSAGE_ID_CREATEUNORDEREDACCESSVIEW_AMBIGUOUSVIDEOPLANEINDEX"),
-            (3146169, "D3D11_MESSAGE_ID_JPEGDECODE_INVALIDSCANDATAOFFSET"),
-            (3146170, "D3D11_MESSAGE_ID_JPEGDECODE_NOTSUPPORTED"),
-            (3146171, "D3D11_MESSAGE_ID_JPEGDECODE_DIMENSIONSTOOLARGE"),
-            (3146172, "D3D11_MESSAGE_ID_JPEGDECODE_INVALIDCOMPONENTS"),
-            (3146173, "D3D11_MESSAGE_ID_JPEGDECODE_DESTINATIONNOT2D"),
-            (3146174, "D3D11_MESSAGE_ID_JPEGDECODE_TILEDRESOURCESUNSUPPORTED"),
-            (3146175, "D3D11_MESSAGE_ID_JPEGDECODE_GUARDRECTSUNSUPPORTED"),
-            (3146176, "D3D11_MESSAGE_ID_JPEGDECODE_FORMATUNSUPPORTED"),
-            (3146177, "D3D11_MESSAGE_ID_JPEGDECODE_INVALIDSUBRESOURCE"),
-            (3146178, "D3D11_MESSAGE_ID_JPEGDECODE_INVALIDMIPLEVEL"),
-            (3146179, "D3D11_MESSAGE_ID_JPEGDECODE_EMPTYDESTBOX"),
-            (3146180, "D3D11_MESSAGE_ID_JPEGDECODE_DESTBOXNOT2D"),
-            (3146181, "D3D11_MESSAGE_ID_JPEGDECODE_DESTBOXNOTSUB"),
-            (3146182, "D3D11_MESSAGE_ID_JPEGDECODE_DESTBOXESINTERSECT"),
-            (3146183, "D3D11_MESSAGE_ID_JPEGDECODE_XSUBSAMPLEMISMATCH"),
-            (3146184, "D3D11_MESSAGE_ID_JPEGDECODE_YSUBSAMPLEMISMATCH"),
-            (3146185, "D3D11_MESSAGE_ID_JPEGDECODE_XSUBSAMPLEODD"),
-            (3146186, "D3D11_MESSAGE_ID_JPEGDECODE_YSUBSAMPLEODD"),
-            (3146187, "D3D11_MESSAGE_ID_JPEGDECODE_OUTPUTDIMENSIONSTOOLARGE"),
-            (3146188, "D3D11_MESSAGE_ID_JPEGDECODE_NONPOW2SCALEUNSUPPORTED"),
-            (3146189, "D3D11_MESSAGE_ID_JPEGDECODE_FRACTIONALDOWNSCALETOLARGE"),
-            (3146190, "D3D11_MESSAGE_ID_JPEGDECODE_CHROMASIZEMISMATCH"),
-            (3146191, "D3D11_MESSAGE_ID_JPEGDECODE_LUMACHROMASIZEMISMATCH"),
-            (3146192, "D3D11_MESSAGE_ID_JPEGDECODE_INVALIDNUMDESTINATIONS"),
-            (3146193, "D3D11_MESSAGE_ID_JPEGDECODE_SUBBOXUNSUPPORTED"),
-            (3146194, "D3D11_MESSAGE_ID_JPEGDECODE_1DESTUNSUPPORTEDFORMAT"),
-            (3146195, "D3D11_MESSAGE_ID_JPEGDECODE_3DESTUNSUPPORTEDFORMAT"),
-            (3146196, "D3D11_MESSAGE_ID_JPEGDECODE_SCALEUNSUPPORTED"),
-            (3146197, "D3D11_MESSAGE_ID_JPEGDECODE_INVALIDSOURCESIZE"),
-            (3146198, "D3D11_MESSAGE_ID_JPEGDECODE_INVALIDCOPYFLAGS"),
-            (3146199, "D3D11_MESSAGE_ID_JPEGDECODE_HAZARD"),
-            (3146200, "D3D11_MESSAGE_ID_JPEGDECODE_UNSUPPORTEDSRCBUFFERUSAGE"),
-            (3146201, "D3D11_MESSAGE_ID_JPEGDECODE_UNSUPPORTEDSRCBUFFERMISCFLAGS"),
-            (3146202, "D3D11_MESSAGE_ID_JPEGDECODE_UNSUPPORTEDDSTTEXTUREUSAGE"),
-            (3146203, "D3D11_MESSAGE_ID_JPEGDECODE_BACKBUFFERNOTSUPPORTED"),
-            (3146204, "D3D11_MESSAGE_ID_JPEGDECODE_UNSUPPRTEDCOPYFLAGS"),
-            (3146205, "D3D11_MESSAGE_ID_JPEGENCODE_NOTSUPPORTED"),
-            (3146206, "D3D11_MESSAGE_ID_JPEGENCODE_INVALIDSCANDATAOFFSET"),
-            (3146207, "D3D11_MESSAGE_ID_JPEGENCODE_INVALIDCOMPONENTS"),
-            (3146208, "D3D11_MESSAGE_ID_JPEGENCODE_SOURCENOT2D"),
-            (3146209, "D3D11_MESSAGE_ID_JPEGENCODE_TILEDRESOURCESUNSUPPORTED"),
-            (3146210, "D3D11_MESSAGE_ID_JPEGENCODE_GUARDRECTSUNSUPPORTED"),
-            (3146211, "D3D11_MESSAGE_ID_JPEGENCODE_XSUBSAMPLEMISMATCH"),
-            (3146212, "D3D11_MESSAGE_ID_JPEGENCODE_YSUBSAMPLEMISMATCH"),
-            (3146213, "D3D11_MESSAGE_ID_JPEGENCODE_FORMATUNSUPPORTED"),
-            (3146214, "D3D11_MESSAGE_ID_JPEGENCODE_INVALIDSUBRESOURCE"),
-            (3146215, "D3D11_MESSAGE_ID_JPEGENCODE_INVALIDMIPLEVEL"),
-            (3146216, "D3D11_MESSAGE_ID_JPEGENCODE_DIMENSIONSTOOLARGE"),
-            (3146217, "D3D11_MESSAGE_ID_JPEGENCODE_HAZARD"),
-            (3146218, "D3D11_MESSAGE_ID_JPEGENCODE_UNSUPPORTEDDSTBUFFERUSAGE"),
-            (3146219, "D3D11_MESSAGE_ID_JPEGENCODE_UNSUPPORTEDDSTBUFFERMISCFLAGS"),
-            (3146220, "D3D11_MESSAGE_ID_JPEGENCODE_UNSUPPORTEDSRCTEXTUREUSAGE"),
-            (3146221, "D3D11_MESSAGE_ID_JPEGENCODE_BACKBUFFERNOTSUPPORTED"),
-            (3146222, "D3D11_MESSAGE_ID_CREATEQUERYORPREDICATE_UNSUPPORTEDCONTEXTTTYPEFORQUERY"),
-            (3146223, "D3D11_MESSAGE_ID_FLUSH1_INVALIDCONTEXTTYPE"),
-            (3146224, "D3D11_MESSAGE_ID_DEVICE_SETHARDWAREPROTECTION_INVALIDCONTEXT"),
-            (3146225, "D3D11_MESSAGE_ID_VIDEOPROCESSORSETOUTPUTHDRMETADATA_NULLPARAM"),
-            (3146226, "D3D11_MESSAGE_ID_VIDEOPROCESSORSETOUTPUTHDRMETADATA_INVALIDSIZE"),
-            (3146227, "D3D11_MESSAGE_ID_VIDEOPROCESSORGETOUTPUTHDRMETADATA_NULLPARAM"),
-            (3146228, "D3D11_MESSAGE_ID_VIDEOPROCESSORGETOUTPUTHDRMETADATA_INVALIDSIZE"),
-            (3146229, "D3D11_MESSAGE_ID_VIDEOPROCESSORSETSTREAMHDRMETADATA_NULLPARAM"),
-            (3146230, "D3D11_MESSAGE_ID_VIDEOPROCESSORSETSTREAMHDRMETADATA_INVALIDSTREAM"),
-            (3146231, "D3D11_MESSAGE_ID_VIDEOPROCESSORSETSTREAMHDRMETADATA_INVALIDSIZE"),
-            (3146232, "D3D11_MESSAGE_ID_VIDEOPROCESSORGETSTREAMHDRMETADATA_NULLPARAM"),
-            (3146233, "D3D11_MESSAGE_ID_VIDEOPROCESSORGETSTREAMHDRMETADATA_INVALIDSTREAM"),
-            (3146234, "D3D11_MESSAGE_ID_VIDEOPROCESSORGETSTREAMHDRMETADATA_INVALIDSIZE"),
-            (3146235, "D3D11_MESSAGE_ID_VIDEOPROCESSORGETSTREAMFRAMEFORMAT_INVALIDSTREAM"),
-            (3146236, "D3D11_MESSAGE_ID_VIDEOPROCESSORGETSTREAMCOLORSPACE_INVALIDSTREAM"),
-            (3146237, "D3D11_MESSAGE_ID_VIDEOPROCESSORGETSTREAMOUTPUTRATE_INVALIDSTREAM"),
-            (3146238, "D3D11_MESSAGE_ID_VIDEOPROCESSORGETSTREAMSOURCERECT_INVALIDSTREAM"),
-            (3146239, "D3D11_MESSAGE_ID_VIDEOPROCESSORGETSTREAMDESTRECT_INVALIDSTREAM"),
-            (3146240, "D3D11_MESSAGE_ID_VIDEOPROCESSORGETSTREAMALPHA_INVALIDSTREAM"),
-            (3146241, "D3D11_MESSAGE_ID_VIDEOPROCESSORGETSTREAMPALETTE_INVALIDSTREAM"),
-            (3146242, "D3D11_MESSAGE_ID_VIDEOPROCESSORGETSTREAMPIXELASPECTRATIO_INVALIDSTREAM"),
-            (3146243, "D3D11_MESSAGE_ID_VIDEOPROCESSORGETSTREAMLUMAKEY_INVALIDSTREAM"),
-            (3146244, "D3D11_MESSAGE_ID_VIDEOPROCESSORGETSTREAMSTEREOFORMAT_INVALIDSTREAM"),
-            (3146245, "D3D11_MESSAGE_ID_VIDEOPROCESSORGETSTREAMAUTOPROCESSINGMODE_INVALIDSTREAM"),
-            (3146246, "D3D11_MESSAGE_ID_VIDEOPROCESSORGETSTREAMFILTER_INVALIDSTREAM"),
-            (3146247, "D3D11_MESSAGE_ID_VIDEOPROCESSORGETSTREAMROTATION_INVALIDSTREAM"),
-            (3146248, "D3D11_MESSAGE_ID_VIDEOPROCESSORGETSTREAMCOLORSPACE1_INVALIDSTREAM"),
-            (3146249, "D3D11_MESSAGE_ID_VIDEOPROCESSORGETSTREAMMIRROR_INVALIDSTREAM"),
-            (3146250, "D3D11_MESSAGE_ID_CREATE_FENCE"),
-            (3146251, "D3D11_MESSAGE_ID_LIVE_FENCE"),
-            (3146252, "D3D11_MESSAGE_ID_DESTROY_FENCE"),
-            (3146253, "D3D11_MESSAGE_ID_CREATE_SYNCHRONIZEDCHANNEL"),
-            (3146254, "D3D11_MESSAGE_ID_LIVE_SYNCHRONIZEDCHANNEL"),
-            (3146255, "D3D11_MESSAGE_ID_DESTROY_SYNCHRONIZEDCHANNEL"),
-            (3146256, "D3D11_MESSAGE_ID_CREATEFENCE_INVALIDFLAGS"),
-            (3146257, "D3D11_MESSAGE_ID_D3D11_3_MESSAGES_END"),
-            (3146258, "D3D11_MESSAGE_ID_D3D11_5_MESSAGES_START"),
-            (3146259, "D3D11_MESSAGE_ID_NEGOTIATECRYPTOSESSIONKEYEXCHANGEMT_INVALIDKEYEXCHANGETYPE"),
-            (3146260, "D3D11_MESSAGE_ID_NEGOTIATECRYPTOSESSIONKEYEXCHANGEMT_NOT_SUPPORTED"),
-            (3146261, "D3D11_MESSAGE_ID_DECODERBEGINFRAME_INVALID_HISTOGRAM_COMPONENT_COUNT"),
-            (3146262, "D3D11_MESSAGE_ID_DECODERBEGINFRAME_INVALID_HISTOGRAM_COMPONENT"),
-            (3146263, "D3D11_MESSAGE_ID_DECODERBEGINFRAME_INVALID_HISTOGRAM_BUFFER_SIZE"),
-            (3146264, "D3D11_MESSAGE_ID_DECODERBEGINFRAME_INVALID_HISTOGRAM_BUFFER_USAGE"),
-            (3146265, "D3D11_MESSAGE_ID_DECODERBEGINFRAME_INVALID_HISTOGRAM_BUFFER_MISC_FLAGS"),
-            (3146266, "D3D11_MESSAGE_ID_DECODERBEGINFRAME_INVALID_HISTOGRAM_BUFFER_OFFSET"),
-            (3146267, "D3D11_MESSAGE_ID_CREATE_TRACKEDWORKLOAD"),
-            (3146268, "D3D11_MESSAGE_ID_LIVE_TRACKEDWORKLOAD"),
-            (3146269, "D3D11_MESSAGE_ID_DESTROY_TRACKEDWORKLOAD"),
-            (3146270, "D3D11_MESSAGE_ID_CREATE_TRACKED_WORKLOAD_NULLPARAM"),
-            (3146271, "D3D11_MESSAGE_ID_CREATE_TRACKED_WORKLOAD_INVALID_MAX_INSTANCES"),
-            (3146272, "D3D11_MESSAGE_ID_CREATE_TRACKED_WORKLOAD_INVALID_DEADLINE_TYPE"),
-            (3146273, "D3D11_MESSAGE_ID_CREATE_TRACKED_WORKLOAD_INVALID_ENGINE_TYPE"),
-            (3146274, "D3D11_MESSAGE_ID_MULTIPLE_TRACKED_WORKLOADS"),
-            (3146275, "D3D11_MESSAGE_ID_MULTIPLE_TRACKED_WORKLOAD_PAIRS"),
-            (3146276, "D3D11_MESSAGE_ID_INCOMPLETE_TRACKED_WORKLOAD_PAIR"),
-            (3146277, "D3D11_MESSAGE_ID_OUT_OF_ORDER_TRACKED_WORKLOAD_PAIR"),
-            (3146278, "D3D11_MESSAGE_ID_CANNOT_ADD_TRACKED_WORKLOAD"),
-            (3146279, "D3D11_MESSAGE_ID_TRACKED_WORKLOAD_NOT_SUPPORTED"),
-            (3146280, "D3D11_MESSAGE_ID_TRACKED_WORKLOAD_ENGINE_TYPE_NOT_FOUND"),
-            (3146281, "D3D11_MESSAGE_ID_NO_TRACKED_WORKLOAD_SLOT_AVAILABLE"),
-            (3146282, "D3D11_MESSAGE_ID_END_TRACKED_WORKLOAD_INVALID_ARG"),
-            (3146283, "D3D11_MESSAGE_ID_TRACKED_WORKLOAD_DISJOINT_FAILURE"),
-            (3146284, "D3D11_MESSAGE_ID_D3D11_5_MESSAGES_END"),
-        ]);
-        
-        return map.get(&id).unwrap_or(&"No description found").to_string();
+    #[test]
+    fn check_survives_messages_being_thrown_away() {
+        let (mut manager, queue) = fake_manager();
+        manager
+            .configure(&InfoQueueConfig {
+                message_limit: 2,
+                break_on: Some(Severity::Error),
+                ..InfoQueueConfig::default()
+            })
+            .unwrap();
+
+        // The error is thrown away before it is checked, the manager can only see the last two
+        push(
+            &queue,
+            &[
+                message(1, Severity::Error),
+                message(2, Severity::Warning),
+                message(3, Severity::Info),
+            ],
+        );
+        assert_eq!(queue.borrow().discarded, 1);
+        assert!(manager.check().is_ok());
+        assert_eq!(manager.cursor.position, 3);
+
+        // The indices of the queue shifted, the cursor still finds the new message
+        push(&queue, &[message(4, Severity::Error)]);
+        assert!(matches!(
+            manager.check(),
+            Err(Error::DebugLayer { message, .. }) if message.id == 4
+        ));
+        assert!(manager.check().is_ok());
+    }
+
+    #[test]
+    fn configure_sets_the_deny_lists_of_the_queue() {
+        let (mut manager, queue) = fake_manager();
+        manager
+            .configure(&InfoQueueConfig {
+                deny_ids: vec![2],
+                deny_severities: vec![Severity::Info],
+                break_on: None,
+                ..InfoQueueConfig::default()
+            })
+            .unwrap();
+        push(
+            &queue,
+            &[
+                message(1, Severity::Error),
+                message(2, Severity::Error),
+                message(3, Severity::Info),
+            ],
+        );
+        assert_eq!(ids(&manager.since_mark().unwrap()), vec![1]);
+        // Without a severity to break on, errors are only logged
+        assert!(manager.check().is_ok());
     }
 }
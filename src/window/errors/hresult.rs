@@ -0,0 +1,194 @@
+use windows::core::HRESULT;
+
+/// A known error code with its symbolic name and a description.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ErrorCode {
+    pub code: u32,
+    pub name: &'static str,
+    pub description: &'static str,
+}
+
+/// HRESULT codes sorted on code, so they can be binary searched. The descriptions are taken from the
+/// [DXGI_ERROR](https://learn.microsoft.com/en-us/windows/win32/direct3ddxgi/dxgi-error),
+/// [DXGI_STATUS](https://learn.microsoft.com/en-us/windows/win32/direct3ddxgi/dxgi-status) and
+/// [D3D11_ERROR](https://learn.microsoft.com/en-us/windows/win32/direct3d11/d3d11-graphics-reference-returnvalues)
+/// pages. Win32 errors wrapped in a HRESULT (`0x8007xxxx`) that aren't in here are looked up in [`WIN32_ERRORS`].
+static HRESULTS: [(u32, &str, &str); 64] = [
+    (0x0000_0000, "S_OK", "The operation completed successfully."),
+    (0x0000_0001, "S_FALSE", "The operation completed successfully but returned a false result."),
+    (0x087A_0001, "DXGI_STATUS_OCCLUDED", "The window content is not visible. When receiving this status, an application can stop rendering and use DXGI_PRESENT_TEST to determine when to resume rendering."),
+    (0x087A_0002, "DXGI_STATUS_CLIPPED", "The window is clipped."),
+    (0x087A_0004, "DXGI_STATUS_NO_REDIRECTION", "The present succeeded but the window is not redirected."),
+    (0x087A_0005, "DXGI_STATUS_NO_DESKTOP_ACCESS", "The desktop could not be accessed, for example because the computer is locked."),
+    (0x087A_0006, "DXGI_STATUS_GRAPHICS_VIDPN_SOURCE_IN_USE", "The output is in use by another application."),
+    (0x087A_0007, "DXGI_STATUS_MODE_CHANGED", "The display mode was changed during the present."),
+    (0x087A_0008, "DXGI_STATUS_MODE_CHANGE_IN_PROGRESS", "A display mode change is in progress."),
+    (0x087A_0009, "DXGI_STATUS_UNOCCLUDED", "The window content became visible again."),
+    (0x087A_000A, "DXGI_STATUS_DDA_WAS_STILL_DRAWING", "The desktop duplication was still drawing."),
+    (0x087A_002F, "DXGI_STATUS_PRESENT_REQUIRED", "The swap chain must be presented before it can be used again."),
+    (0x8000_000A, "E_PENDING", "The data necessary to complete this operation is not yet available."),
+    (0x8000_000B, "E_BOUNDS", "The operation attempted to access data outside the valid range."),
+    (0x8000_4001, "E_NOTIMPL", "The method or operation is not implemented."),
+    (0x8000_4002, "E_NOINTERFACE", "No such interface is supported."),
+    (0x8000_4003, "E_POINTER", "An invalid pointer was used."),
+    (0x8000_4004, "E_ABORT", "The operation was aborted."),
+    (0x8000_4005, "E_FAIL", "An unspecified failure has occurred."),
+    (0x8000_FFFF, "E_UNEXPECTED", "A catastrophic failure has occurred."),
+    (0x8007_0005, "E_ACCESSDENIED", "General access denied error."),
+    (0x8007_0006, "E_HANDLE", "An invalid handle was used."),
+    (0x8007_000E, "E_OUTOFMEMORY", "The operation failed to allocate the necessary memory."),
+    (0x8007_0057, "E_INVALIDARG", "One or more arguments are invalid."),
+    (0x8876_021C, "D3DERR_WASSTILLDRAWING", "The previous blit operation that is transferring information to or from this surface is incomplete."),
+    (0x8876_086C, "D3DERR_INVALIDCALL", "The method call is invalid. For example, a method's parameter may not be a valid pointer."),
+    (0x8879_0001, "D3D10_ERROR_TOO_MANY_UNIQUE_STATE_OBJECTS", "There are too many unique instances of a particular type of state object."),
+    (0x8879_0002, "D3D10_ERROR_FILE_NOT_FOUND", "The file was not found."),
+    (0x887A_0001, "DXGI_ERROR_INVALID_CALL", "The application provided invalid parameter data; this must be debugged and fixed before the application is released."),
+    (0x887A_0002, "DXGI_ERROR_NOT_FOUND", "When calling IDXGIObject::GetPrivateData, the GUID passed in is not recognized as one previously passed to IDXGIObject::SetPrivateData or IDXGIObject::SetPrivateDataInterface. When calling IDXGIFactory::EnumAdapters or IDXGIAdapter::EnumOutputs, the enumerated ordinal is out of range."),
+    (0x887A_0003, "DXGI_ERROR_MORE_DATA", "The buffer supplied by the application is not big enough to hold the requested data."),
+    (0x887A_0004, "DXGI_ERROR_UNSUPPORTED", "The requested functionality is not supported by the device or the driver."),
+    (0x887A_0005, "DXGI_ERROR_DEVICE_REMOVED", "The video card has been physically removed from the system, or a driver upgrade for the video card has occurred. The application should destroy and recreate the device."),
+    (0x887A_0006, "DXGI_ERROR_DEVICE_HUNG", "The application's device failed due to badly formed commands sent by the application. This is an design-time issue that should be investigated and fixed."),
+    (0x887A_0007, "DXGI_ERROR_DEVICE_RESET", "The device failed due to a badly formed command. This is a run-time issue; The application should destroy and recreate the device."),
+    (0x887A_000A, "DXGI_ERROR_WAS_STILL_DRAWING", "The GPU was busy at the moment when a call was made to perform an operation, and did not execute or schedule the operation."),
+    (0x887A_000B, "DXGI_ERROR_FRAME_STATISTICS_DISJOINT", "An event (for example, a power cycle) interrupted the gathering of presentation statistics."),
+    (0x887A_000C, "DXGI_ERROR_GRAPHICS_VIDPN_SOURCE_IN_USE", "The application attempted to acquire exclusive ownership of an output, but failed because some other application (or device within the application) already acquired ownership."),
+    (0x887A_0020, "DXGI_ERROR_DRIVER_INTERNAL_ERROR", "The driver encountered a problem and was put into the device removed state."),
+    (0x887A_0021, "DXGI_ERROR_NONEXCLUSIVE", "A global counter resource is in use, and the Direct3D device can't currently use the counter resource."),
+    (0x887A_0022, "DXGI_ERROR_NOT_CURRENTLY_AVAILABLE", "The resource or request is not currently available, but it might become available later."),
+    (0x887A_0023, "DXGI_ERROR_REMOTE_CLIENT_DISCONNECTED", "The remote device has been removed due to a session or network disconnect. Call IDXGIFactory1::IsCurrent to find out when it becomes available again."),
+    (0x887A_0024, "DXGI_ERROR_REMOTE_OUTOFMEMORY", "Reserved."),
+    (0x887A_0025, "DXGI_ERROR_MODE_CHANGE_IN_PROGRESS", "The display mode change is still in progress. Try again later."),
+    (0x887A_0026, "DXGI_ERROR_ACCESS_LOST", "The desktop duplication interface is invalid. The desktop duplication interface typically becomes invalid when a different type of image is displayed on the desktop."),
+    (0x887A_0027, "DXGI_ERROR_WAIT_TIMEOUT", "The time-out interval elapsed before the next desktop frame was available."),
+    (0x887A_0028, "DXGI_ERROR_SESSION_DISCONNECTED", "The Remote Desktop Services session is currently disconnected."),
+    (0x887A_0029, "DXGI_ERROR_RESTRICT_TO_OUTPUT_STALE", "The DXGI output (monitor) to which the swap chain content was restricted is now disconnected or changed."),
+    (0x887A_002A, "DXGI_ERROR_CANNOT_PROTECT_CONTENT", "DXGI can't provide content protection on the swap chain. This error is typically caused by an older driver, or when you use a swap chain that is incompatible with content protection."),
+    (0x887A_002B, "DXGI_ERROR_ACCESS_DENIED", "You tried to use a resource to which you did not have the required access privileges."),
+    (0x887A_002C, "DXGI_ERROR_NAME_ALREADY_EXISTS", "The supplied name of a resource in a call to IDXGIResource1::CreateSharedHandle is already associated with some other resource."),
+    (0x887A_002D, "DXGI_ERROR_SDK_COMPONENT_MISSING", "The operation depends on an SDK component that is missing or mismatched."),
+    (0x887A_002E, "DXGI_ERROR_NOT_CURRENT", "The DXGI objects that the application has created are no longer current and need to be recreated for this operation to be performed."),
+    (0x887A_0030, "DXGI_ERROR_HW_PROTECTION_OUTOFMEMORY", "Insufficient HW protected memory exits for proper function."),
+    (0x887A_0031, "DXGI_ERROR_DYNAMIC_CODE_POLICY_VIOLATION", "Creating this device would violate the process's dynamic code policy."),
+    (0x887A_0032, "DXGI_ERROR_NON_COMPOSITED_UI", "The operation failed because the compositor is not in control of the output."),
+    (0x887A_0033, "DXGI_ERROR_CACHE_CORRUPT", "The application's pipeline cache is corrupt."),
+    (0x887A_0034, "DXGI_ERROR_CACHE_FULL", "The application's pipeline cache is full."),
+    (0x887A_0035, "DXGI_ERROR_CACHE_HASH_COLLISION", "The cache key has a hash collision."),
+    (0x887A_0036, "DXGI_ERROR_ALREADY_EXISTS", "The desired element already exists."),
+    (0x887C_0001, "D3D11_ERROR_TOO_MANY_UNIQUE_STATE_OBJECTS", "There are too many unique instances of a particular type of state object."),
+    (0x887C_0002, "D3D11_ERROR_FILE_NOT_FOUND", "The file was not found."),
+    (0x887C_0003, "D3D11_ERROR_TOO_MANY_UNIQUE_VIEW_OBJECTS", "There are too many unique instances of a particular type of view object."),
+    (0x887C_0004, "D3D11_ERROR_DEFERRED_CONTEXT_MAP_WITHOUT_INITIAL_DISCARD", "The first call to ID3D11DeviceContext::Map after either ID3D11Device::CreateDeferredContext or ID3D11DeviceContext::FinishCommandList per Resource was not D3D11_MAP_WRITE_DISCARD."),
+];
+
+/// Common [win32 error codes](https://learn.microsoft.com/en-us/windows/win32/debug/system-error-codes--0-499-)
+/// sorted on code. These are returned by `GetLastError()` or wrapped in a HRESULT with the `FACILITY_WIN32` facility.
+static WIN32_ERRORS: [(u32, &str, &str); 25] = [
+    (0, "ERROR_SUCCESS", "The operation completed successfully."),
+    (1, "ERROR_INVALID_FUNCTION", "Incorrect function."),
+    (2, "ERROR_FILE_NOT_FOUND", "The system cannot find the file specified."),
+    (3, "ERROR_PATH_NOT_FOUND", "The system cannot find the path specified."),
+    (5, "ERROR_ACCESS_DENIED", "Access is denied."),
+    (6, "ERROR_INVALID_HANDLE", "The handle is invalid."),
+    (8, "ERROR_NOT_ENOUGH_MEMORY", "Not enough memory resources are available to process this command."),
+    (13, "ERROR_INVALID_DATA", "The data is invalid."),
+    (14, "ERROR_OUTOFMEMORY", "Not enough memory resources are available to complete this operation."),
+    (32, "ERROR_SHARING_VIOLATION", "The process cannot access the file because it is being used by another process."),
+    (50, "ERROR_NOT_SUPPORTED", "The request is not supported."),
+    (87, "ERROR_INVALID_PARAMETER", "The parameter is incorrect."),
+    (122, "ERROR_INSUFFICIENT_BUFFER", "The data area passed to a system call is too small."),
+    (126, "ERROR_MOD_NOT_FOUND", "The specified module could not be found."),
+    (127, "ERROR_PROC_NOT_FOUND", "The specified procedure could not be found."),
+    (183, "ERROR_ALREADY_EXISTS", "Cannot create a file when that file already exists."),
+    (1400, "ERROR_INVALID_WINDOW_HANDLE", "Invalid window handle."),
+    (1401, "ERROR_INVALID_MENU_HANDLE", "Invalid menu handle."),
+    (1402, "ERROR_INVALID_CURSOR_HANDLE", "Invalid cursor handle."),
+    (1407, "ERROR_CANNOT_FIND_WND_CLASS", "Cannot find window class."),
+    (1410, "ERROR_CLASS_ALREADY_EXISTS", "Class already exists."),
+    (1411, "ERROR_CLASS_DOES_NOT_EXIST", "Class does not exist."),
+    (1412, "ERROR_CLASS_HAS_WINDOWS", "Class still has open windows."),
+    (1413, "ERROR_INVALID_INDEX", "Invalid index."),
+    (1460, "ERROR_TIMEOUT", "This operation returned because the timeout period expired."),
+];
+
+/// The facility that win32 errors get when they are wrapped in a HRESULT
+const FACILITY_WIN32: u32 = 7;
+
+fn find(table: &'static [(u32, &'static str, &'static str)], code: u32) -> Option<ErrorCode> {
+    let index: usize = table.binary_search_by_key(&code, |(table_code, _, _)| *table_code).ok()?;
+    let (code, name, description) = table[index];
+    return Some(ErrorCode {
+        code,
+        name,
+        description,
+    });
+}
+
+/// Look up a HRESULT. Win32 errors that were wrapped in a HRESULT are found as well.
+pub fn lookup_hresult(hr: HRESULT) -> Option<ErrorCode> {
+    let code: u32 = hr.0 as u32;
+    if let Some(known) = find(&HRESULTS, code) {
+        return Some(known);
+    }
+
+    // HRESULT_FROM_WIN32 puts the win32 code in the low 16 bits
+    if code & 0xFFFF_0000 == 0x8000_0000 | (FACILITY_WIN32 << 16) {
+        return lookup_win32(code & 0xFFFF).map(|known| ErrorCode { code, ..known });
+    }
+
+    return None;
+}
+
+/// Look up an error code returned by `GetLastError()`.
+pub fn lookup_win32(code: u32) -> Option<ErrorCode> {
+    return find(&WIN32_ERRORS, code);
+}
+
+/// Describe a HRESULT like `DXGI_ERROR_DEVICE_HUNG (0x887A0006): The application's device failed...`.
+pub fn describe_hresult(hr: HRESULT) -> String {
+    return match lookup_hresult(hr) {
+        Some(known) => format!("{} (0x{:08X}): {}", known.name, hr.0, known.description),
+        None => format!("0x{:08X}: Unknown error code", hr.0),
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tables_are_sorted_and_unique() {
+        for table in [&HRESULTS[..], &WIN32_ERRORS[..]] {
+            for pair in table.windows(2) {
+                assert!(pair[0].0 < pair[1].0, "{} isn't before {}", pair[0].1, pair[1].1);
+            }
+        }
+    }
+
+    #[test]
+    fn common_codes_have_their_names() {
+        assert_eq!(lookup_hresult(HRESULT(0x8007_0057_u32 as i32)).unwrap().name, "E_INVALIDARG");
+        assert_eq!(
+            lookup_hresult(HRESULT(0x887A_0005_u32 as i32)).unwrap().name,
+            "DXGI_ERROR_DEVICE_REMOVED"
+        );
+        assert_eq!(lookup_win32(5).unwrap().name, "ERROR_ACCESS_DENIED");
+    }
+
+    #[test]
+    fn wrapped_win32_errors_are_found() {
+        let known: ErrorCode = lookup_hresult(HRESULT(0x8007_0002_u32 as i32)).unwrap();
+        assert_eq!(known.name, "ERROR_FILE_NOT_FOUND");
+        assert_eq!(known.code, 0x8007_0002);
+    }
+
+    #[test]
+    fn unknown_codes_are_described_by_their_number() {
+        assert_eq!(lookup_hresult(HRESULT(0x8123_4567_u32 as i32)), None);
+        assert_eq!(
+            describe_hresult(HRESULT(0x8123_4567_u32 as i32)),
+            "0x81234567: Unknown error code"
+        );
+        assert!(describe_hresult(HRESULT(0x887A_0006_u32 as i32))
+            .starts_with("DXGI_ERROR_DEVICE_HUNG (0x887A0006): "));
+    }
+}
@@ -0,0 +1,418 @@
+use std::{fmt, io::Write};
+
+use windows::{
+    core::GUID,
+    Win32::Graphics::{
+        Direct3D11::DXGI_DEBUG_D3D11,
+        Dxgi::{
+            DXGI_DEBUG_APP, DXGI_DEBUG_DX, DXGI_DEBUG_DXGI, DXGI_INFO_QUEUE_MESSAGE_CATEGORY,
+            DXGI_INFO_QUEUE_MESSAGE_SEVERITY,
+        },
+    },
+};
+
+use super::d3d11_message_ids::{self, ApiGroup};
+use crate::logger::Level;
+
+/// The part of DirectX that produced a message.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Producer {
+    D3D11,
+    Dxgi,
+    Dx,
+    /// Messages that were added by the app itself with `AddApplicationMessage`
+    App,
+    Other(GUID),
+}
+
+impl Producer {
+    pub fn from_guid(guid: GUID) -> Producer {
+        return match guid {
+            guid if guid == DXGI_DEBUG_D3D11 => Producer::D3D11,
+            guid if guid == DXGI_DEBUG_DXGI => Producer::Dxgi,
+            guid if guid == DXGI_DEBUG_DX => Producer::Dx,
+            guid if guid == DXGI_DEBUG_APP => Producer::App,
+            guid => Producer::Other(guid),
+        };
+    }
+
+    /// Parse the name the debug layer starts its messages with, like `D3D11`
+    pub fn from_name(name: &str) -> Option<Producer> {
+        return match name {
+            "D3D11" => Some(Producer::D3D11),
+            "DXGI" => Some(Producer::Dxgi),
+            "DX" => Some(Producer::Dx),
+            "APP" => Some(Producer::App),
+            _ => None,
+        };
+    }
+}
+
+impl fmt::Display for Producer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Producer::D3D11 => write!(f, "D3D11"),
+            Producer::Dxgi => write!(f, "DXGI"),
+            Producer::Dx => write!(f, "DX"),
+            Producer::App => write!(f, "APP"),
+            Producer::Other(guid) => write!(f, "{:?}", guid),
+        }
+    }
+}
+
+/// How bad a message is, from bad to informational. The order matches `DXGI_INFO_QUEUE_MESSAGE_SEVERITY`,
+/// so `Severity::Error < Severity::Warning`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    Corruption,
+    Error,
+    Warning,
+    Info,
+    Message,
+}
+
+impl Severity {
+    pub const ALL: [Severity; 5] = [
+        Severity::Corruption,
+        Severity::Error,
+        Severity::Warning,
+        Severity::Info,
+        Severity::Message,
+    ];
+
+    pub fn from_raw(severity: DXGI_INFO_QUEUE_MESSAGE_SEVERITY) -> Option<Severity> {
+        return Severity::ALL.get(severity.0 as usize).copied();
+    }
+
+    pub fn to_raw(self) -> DXGI_INFO_QUEUE_MESSAGE_SEVERITY {
+        return DXGI_INFO_QUEUE_MESSAGE_SEVERITY(self as i32);
+    }
+
+    /// The log level messages of this severity are forwarded at
+    pub fn log_level(self) -> Level {
+        return match self {
+            Severity::Corruption | Severity::Error => Level::Error,
+            Severity::Warning => Level::Warn,
+            Severity::Info => Level::Info,
+            Severity::Message => Level::Debug,
+        };
+    }
+
+    /// The name the debug layer uses in its output, like `ERROR`
+    pub fn name(self) -> &'static str {
+        return match self {
+            Severity::Corruption => "CORRUPTION",
+            Severity::Error => "ERROR",
+            Severity::Warning => "WARNING",
+            Severity::Info => "INFO",
+            Severity::Message => "MESSAGE",
+        };
+    }
+
+    pub fn from_name(name: &str) -> Option<Severity> {
+        return Severity::ALL
+            .into_iter()
+            .find(|severity| severity.name() == name);
+    }
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// The kind of operation a message is about. The order matches `DXGI_INFO_QUEUE_MESSAGE_CATEGORY`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Category {
+    Unknown,
+    Miscellaneous,
+    Initialization,
+    Cleanup,
+    Compilation,
+    StateCreation,
+    StateSetting,
+    StateGetting,
+    ResourceManipulation,
+    Execution,
+    Shader,
+}
+
+impl Category {
+    pub const ALL: [Category; 11] = [
+        Category::Unknown,
+        Category::Miscellaneous,
+        Category::Initialization,
+        Category::Cleanup,
+        Category::Compilation,
+        Category::StateCreation,
+        Category::StateSetting,
+        Category::StateGetting,
+        Category::ResourceManipulation,
+        Category::Execution,
+        Category::Shader,
+    ];
+
+    pub fn from_raw(category: DXGI_INFO_QUEUE_MESSAGE_CATEGORY) -> Option<Category> {
+        return Category::ALL.get(category.0 as usize).copied();
+    }
+
+    pub fn to_raw(self) -> DXGI_INFO_QUEUE_MESSAGE_CATEGORY {
+        return DXGI_INFO_QUEUE_MESSAGE_CATEGORY(self as i32);
+    }
+
+    /// The name the debug layer uses in its output, like `STATE_SETTING`
+    pub fn name(self) -> &'static str {
+        return match self {
+            Category::Unknown => "UNKNOWN",
+            Category::Miscellaneous => "MISCELLANEOUS",
+            Category::Initialization => "INITIALIZATION",
+            Category::Cleanup => "CLEANUP",
+            Category::Compilation => "COMPILATION",
+            Category::StateCreation => "STATE_CREATION",
+            Category::StateSetting => "STATE_SETTING",
+            Category::StateGetting => "STATE_GETTING",
+            Category::ResourceManipulation => "RESOURCE_MANIPULATION",
+            Category::Execution => "EXECUTION",
+            Category::Shader => "SHADER",
+        };
+    }
+
+    pub fn from_name(name: &str) -> Option<Category> {
+        return Category::ALL
+            .into_iter()
+            .find(|category| category.name() == name);
+    }
+}
+
+impl fmt::Display for Category {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// A single message of the DXGI info queue.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InfoMessage {
+    pub producer: Producer,
+    pub id: i32,
+    /// The name of the id without the `D3D11_MESSAGE_ID_` prefix, like `OMSETRENDERTARGETS_INVALIDVIEW`.
+    /// Empty if the id is unknown.
+    pub name: String,
+    pub category: Category,
+    pub severity: Severity,
+    pub description: String,
+}
+
+impl InfoMessage {
+    /// The API group of the message id. Only D3D11 ids are grouped, other producers are in [`ApiGroup::Other`]
+    pub fn group(&self) -> ApiGroup {
+        return match self.producer {
+            Producer::D3D11 => d3d11_message_ids::id_to_group(self.id),
+            _ => ApiGroup::Other,
+        };
+    }
+
+    /// Write the message as a single line of JSON
+    pub fn to_json(&self) -> String {
+        return format!(
+            "{{\"producer\":{},\"id\":{},\"name\":{},\"group\":{},\"category\":{},\"severity\":{},\"description\":{}}}",
+            json_string(&self.producer.to_string()),
+            self.id,
+            json_string(&self.name),
+            json_string(self.group().name()),
+            json_string(self.category.name()),
+            json_string(self.severity.name()),
+            json_string(&self.description)
+        );
+    }
+}
+
+/// Formats the message the same way the debug layer prints it in the output window of the debugger:
+/// `D3D11 ERROR: <description> [ STATE_SETTING ERROR #388: OMSETRENDERTARGETS_INVALIDVIEW ]`
+impl fmt::Display for InfoMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {}: {} [ {} {} #{}: {} ]",
+            self.producer,
+            self.severity,
+            self.description.trim_end(),
+            self.category,
+            self.severity,
+            self.id,
+            self.name
+        )
+    }
+}
+
+/// Decides which messages are kept. A message passes if it isn't denied and, when there are allow lists,
+/// it is in one of them.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MessageFilter {
+    pub allow_ids: Vec<i32>,
+    pub deny_ids: Vec<i32>,
+    pub allow_severities: Vec<Severity>,
+    pub deny_severities: Vec<Severity>,
+    pub allow_groups: Vec<ApiGroup>,
+    pub deny_groups: Vec<ApiGroup>,
+}
+
+impl MessageFilter {
+    /// A filter that keeps every message
+    pub fn all() -> MessageFilter {
+        return MessageFilter::default();
+    }
+
+    pub fn allows(&self, message: &InfoMessage) -> bool {
+        let group: ApiGroup = message.group();
+        if self.deny_ids.contains(&message.id)
+            || self.deny_severities.contains(&message.severity)
+            || self.deny_groups.contains(&group)
+        {
+            return false;
+        }
+
+        if !self.allow_ids.is_empty() && !self.allow_ids.contains(&message.id) {
+            return false;
+        }
+
+        if !self.allow_groups.is_empty() && !self.allow_groups.contains(&group) {
+            return false;
+        }
+
+        return self.allow_severities.is_empty()
+            || self.allow_severities.contains(&message.severity);
+    }
+
+    pub fn apply(&self, messages: Vec<InfoMessage>) -> Vec<InfoMessage> {
+        return messages
+            .into_iter()
+            .filter(|message| self.allows(message))
+            .collect();
+    }
+}
+
+/// Write the messages as JSON lines, one message per line.
+pub fn export_json_lines(messages: &[InfoMessage], out: &mut dyn Write) -> std::io::Result<()> {
+    for message in messages {
+        writeln!(out, "{}", message.to_json())?;
+    }
+    return Ok(());
+}
+
+/// Quote and escape a string for JSON
+pub fn json_string(value: &str) -> String {
+    let mut escaped: String = String::with_capacity(value.len() + 2);
+    escaped.push('"');
+    for ch in value.chars() {
+        match ch {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            ch if (ch as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", ch as u32)),
+            ch => escaped.push(ch),
+        }
+    }
+    escaped.push('"');
+    return escaped;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn message(id: i32, severity: Severity) -> InfoMessage {
+        return InfoMessage {
+            producer: Producer::D3D11,
+            id,
+            name: d3d11_message_ids::id_to_name(id)
+                .unwrap_or_default()
+                .to_string(),
+            category: Category::StateSetting,
+            severity,
+            description: "The view is \"bad\".\n".to_string(),
+        };
+    }
+
+    #[test]
+    fn display_matches_the_debug_layer() {
+        assert_eq!(
+            message(388, Severity::Error).to_string(),
+            "D3D11 ERROR: The view is \"bad\". [ STATE_SETTING ERROR #388: OMSETRENDERTARGETS_INVALIDVIEW ]"
+        );
+    }
+
+    #[test]
+    fn filter_denies_before_it_allows() {
+        let messages: Vec<InfoMessage> = vec![
+            message(388, Severity::Error),
+            message(1, Severity::Warning),
+            message(388, Severity::Info),
+        ];
+        assert_eq!(MessageFilter::all().apply(messages.clone()), messages);
+
+        let errors: MessageFilter = MessageFilter {
+            allow_severities: vec![Severity::Corruption, Severity::Error],
+            ..MessageFilter::default()
+        };
+        assert_eq!(errors.apply(messages.clone()), vec![messages[0].clone()]);
+
+        let output_merger: MessageFilter = MessageFilter {
+            allow_groups: vec![ApiGroup::OutputMerger],
+            deny_severities: vec![Severity::Info],
+            ..MessageFilter::default()
+        };
+        assert_eq!(
+            output_merger.apply(messages.clone()),
+            vec![messages[0].clone()]
+        );
+
+        let ids: MessageFilter = MessageFilter {
+            allow_ids: vec![1, 388],
+            deny_ids: vec![388],
+            ..MessageFilter::default()
+        };
+        assert_eq!(ids.apply(messages.clone()), vec![messages[1].clone()]);
+    }
+
+    #[test]
+    fn exports_one_json_line_per_message() {
+        let mut out: Vec<u8> = vec![];
+        export_json_lines(
+            &[message(388, Severity::Error), message(1, Severity::Warning)],
+            &mut out,
+        )
+        .unwrap();
+        let text: String = String::from_utf8(out).unwrap();
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(
+            lines[0],
+            "{\"producer\":\"D3D11\",\"id\":388,\"name\":\"OMSETRENDERTARGETS_INVALIDVIEW\",\"group\":\"OM\",\
+             \"category\":\"STATE_SETTING\",\"severity\":\"ERROR\",\"description\":\"The view is \\\"bad\\\".\\n\"}"
+        );
+        assert!(lines[1].contains("\"group\":\"IA\""));
+        assert_eq!(lines.len(), 2);
+    }
+
+    #[test]
+    fn json_strings_escape_control_characters() {
+        assert_eq!(json_string("a\tb\u{1}\\"), "\"a\\tb\\u0001\\\\\"");
+    }
+
+    #[test]
+    fn names_round_trip() {
+        for severity in Severity::ALL {
+            assert_eq!(Severity::from_name(severity.name()), Some(severity));
+            assert_eq!(Severity::from_raw(severity.to_raw()), Some(severity));
+        }
+        for category in Category::ALL {
+            assert_eq!(Category::from_name(category.name()), Some(category));
+            assert_eq!(Category::from_raw(category.to_raw()), Some(category));
+        }
+        assert_eq!(Producer::from_name("DXGI"), Some(Producer::Dxgi));
+        assert_eq!(Producer::from_guid(DXGI_DEBUG_APP), Producer::App);
+    }
+}
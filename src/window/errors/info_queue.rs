@@ -0,0 +1,490 @@
+#[cfg(test)]
+use std::collections::VecDeque;
+
+use windows::Win32::Graphics::{
+    Direct3D11::DXGI_DEBUG_D3D11,
+    Dxgi::{
+        IDXGIInfoQueue, DXGI_DEBUG_ALL, DXGI_INFO_QUEUE_FILTER, DXGI_INFO_QUEUE_FILTER_DESC,
+        DXGI_INFO_QUEUE_MESSAGE, DXGI_INFO_QUEUE_MESSAGE_SEVERITY,
+    },
+};
+
+use super::{
+    d3d11_message_ids,
+    info_message::{Category, InfoMessage, Producer, Severity},
+    Context, Error,
+};
+
+/// The amount of messages the debug layer stores before it starts to throw away the oldest ones
+pub const DEFAULT_MESSAGE_LIMIT: u64 = 1024;
+
+/// The queue the debug layer stores its messages in. Implemented by [`DxgiInfoQueue`] and by
+/// [`FakeInfoQueue`], which holds messages that were pushed to it by hand.
+pub trait InfoQueue {
+    /// Amount of messages that are stored right now
+    fn stored_count(&self) -> u64;
+    /// Amount of messages that were thrown away because the queue was full
+    fn discarded_count(&self) -> u64;
+    /// The stored message at `index`, the oldest message has index 0
+    fn message(&self, index: u64) -> Result<InfoMessage, Error>;
+    fn set_message_limit(&mut self, limit: u64) -> Result<(), Error>;
+    /// Stop storing D3D11 messages with one of the `ids`, and messages of any producer with one of the `severities`
+    fn push_deny_filter(&mut self, ids: &[i32], severities: &[Severity]) -> Result<(), Error>;
+    fn clear(&mut self);
+}
+
+/// The info queue of the DXGI debug layer
+pub struct DxgiInfoQueue {
+    pub queue: IDXGIInfoQueue,
+}
+
+impl DxgiInfoQueue {
+    pub fn new() -> Result<DxgiInfoQueue, Error> {
+        let queue: IDXGIInfoQueue = unsafe {
+            windows::Win32::Graphics::Dxgi::DXGIGetDebugInterface1(0)
+                .context("getting the DXGI info queue")?
+        };
+        return Ok(DxgiInfoQueue { queue });
+    }
+}
+
+impl InfoQueue for DxgiInfoQueue {
+    fn stored_count(&self) -> u64 {
+        return unsafe { self.queue.GetNumStoredMessages(DXGI_DEBUG_ALL) };
+    }
+
+    fn discarded_count(&self) -> u64 {
+        return unsafe {
+            self.queue
+                .GetNumMessagesDiscardedByMessageCountLimit(DXGI_DEBUG_ALL)
+        };
+    }
+
+    // https://learn.microsoft.com/en-us/windows/win32/api/d3d11sdklayers/ne-d3d11sdklayers-d3d11_message_id
+    fn message(&self, index: u64) -> Result<InfoMessage, Error> {
+        let mut message_length: usize = 0;
+        unsafe {
+            self.queue
+                .GetMessage(DXGI_DEBUG_ALL, index, None, &mut message_length)
+                .context("getting the length of an info queue message")?
+        };
+
+        // The description is stored right behind the struct, so the buffer has to be message_length
+        // bytes big. A u64 buffer keeps it aligned for the struct.
+        let byte_count: usize = message_length.max(std::mem::size_of::<DXGI_INFO_QUEUE_MESSAGE>());
+        let mut buffer: Vec<u64> = vec![0; byte_count.div_ceil(8)];
+        let info_msg_buffer: *mut DXGI_INFO_QUEUE_MESSAGE = buffer.as_mut_ptr().cast();
+
+        unsafe {
+            self.queue
+                .GetMessage(
+                    DXGI_DEBUG_ALL,
+                    index,
+                    Some(info_msg_buffer),
+                    &mut message_length,
+                )
+                .context("getting an info queue message")?
+        };
+
+        let info_msg: &DXGI_INFO_QUEUE_MESSAGE = unsafe { &*info_msg_buffer };
+        let description: String = if info_msg.pDescription.is_null()
+            || info_msg.DescriptionByteLength == 0
+        {
+            String::new()
+        } else {
+            let bytes: &[u8] = unsafe {
+                std::slice::from_raw_parts(info_msg.pDescription, info_msg.DescriptionByteLength)
+            };
+            // The length includes the null terminator
+            String::from_utf8_lossy(bytes)
+                .trim_end_matches('\0')
+                .to_string()
+        };
+
+        let producer: Producer = Producer::from_guid(info_msg.Producer);
+        let name: String = match producer {
+            Producer::D3D11 => d3d11_message_ids::id_to_name(info_msg.ID)
+                .unwrap_or_default()
+                .to_string(),
+            _ => String::new(),
+        };
+
+        return Ok(InfoMessage {
+            producer,
+            id: info_msg.ID,
+            name,
+            category: Category::from_raw(info_msg.Category).unwrap_or(Category::Unknown),
+            severity: Severity::from_raw(info_msg.Severity).unwrap_or(Severity::Message),
+            description,
+        });
+    }
+
+    fn set_message_limit(&mut self, limit: u64) -> Result<(), Error> {
+        return unsafe { self.queue.SetMessageCountLimit(DXGI_DEBUG_ALL, limit) }
+            .context("setting the info queue message limit");
+    }
+
+    fn push_deny_filter(&mut self, ids: &[i32], severities: &[Severity]) -> Result<(), Error> {
+        // The filter only reads the lists, but the struct wants mutable pointers
+        let mut ids: Vec<i32> = ids.to_vec();
+        let mut severities: Vec<DXGI_INFO_QUEUE_MESSAGE_SEVERITY> = severities
+            .iter()
+            .map(|severity| severity.to_raw())
+            .collect();
+
+        // Message ids are only unique within a producer, so they are denied for D3D11 only
+        if !ids.is_empty() {
+            let filter: DXGI_INFO_QUEUE_FILTER = DXGI_INFO_QUEUE_FILTER {
+                DenyList: DXGI_INFO_QUEUE_FILTER_DESC {
+                    NumIDs: ids.len() as u32,
+                    pIDList: ids.as_mut_ptr(),
+                    ..Default::default()
+                },
+                ..Default::default()
+            };
+            unsafe { self.queue.PushStorageFilter(DXGI_DEBUG_D3D11, &filter) }
+                .context("denying info queue message ids")?;
+        }
+
+        if !severities.is_empty() {
+            let filter: DXGI_INFO_QUEUE_FILTER = DXGI_INFO_QUEUE_FILTER {
+                DenyList: DXGI_INFO_QUEUE_FILTER_DESC {
+                    NumSeverities: severities.len() as u32,
+                    pSeverityList: severities.as_mut_ptr(),
+                    ..Default::default()
+                },
+                ..Default::default()
+            };
+            unsafe { self.queue.PushStorageFilter(DXGI_DEBUG_ALL, &filter) }
+                .context("denying info queue message severities")?;
+        }
+        return Ok(());
+    }
+
+    fn clear(&mut self) {
+        unsafe { self.queue.ClearStoredMessages(DXGI_DEBUG_ALL) };
+    }
+}
+
+/// An [`InfoQueue`] without a debug layer behind it. It stores the messages that are pushed to it
+/// the same way the debug layer does: denied messages are dropped and the oldest messages are thrown
+/// away when the limit is reached.
+#[cfg(test)]
+#[derive(Debug, Clone)]
+pub struct FakeInfoQueue {
+    pub messages: VecDeque<InfoMessage>,
+    pub limit: u64,
+    pub discarded: u64,
+    pub deny_ids: Vec<i32>,
+    pub deny_severities: Vec<Severity>,
+}
+
+#[cfg(test)]
+impl FakeInfoQueue {
+    pub fn new() -> FakeInfoQueue {
+        return FakeInfoQueue {
+            messages: VecDeque::new(),
+            limit: DEFAULT_MESSAGE_LIMIT,
+            discarded: 0,
+            deny_ids: vec![],
+            deny_severities: vec![],
+        };
+    }
+
+    /// Store a message like the debug layer would
+    pub fn push(&mut self, message: InfoMessage) {
+        let denied: bool = (message.producer == Producer::D3D11
+            && self.deny_ids.contains(&message.id))
+            || self.deny_severities.contains(&message.severity);
+        if denied {
+            return;
+        }
+
+        self.messages.push_back(message);
+        while self.messages.len() as u64 > self.limit {
+            self.messages.pop_front();
+            self.discarded += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+impl InfoQueue for FakeInfoQueue {
+    fn stored_count(&self) -> u64 {
+        return self.messages.len() as u64;
+    }
+
+    fn discarded_count(&self) -> u64 {
+        return self.discarded;
+    }
+
+    fn message(&self, index: u64) -> Result<InfoMessage, Error> {
+        return match self.messages.get(index as usize) {
+            Some(message) => Ok(message.clone()),
+            None => Err(Error::hresult_code(
+                windows::Win32::Foundation::E_INVALIDARG,
+                &format!("There is no info queue message {}", index),
+            )),
+        };
+    }
+
+    fn set_message_limit(&mut self, limit: u64) -> Result<(), Error> {
+        self.limit = limit;
+        while self.messages.len() as u64 > self.limit {
+            self.messages.pop_front();
+            self.discarded += 1;
+        }
+        return Ok(());
+    }
+
+    fn push_deny_filter(&mut self, ids: &[i32], severities: &[Severity]) -> Result<(), Error> {
+        self.deny_ids.extend_from_slice(ids);
+        self.deny_severities.extend_from_slice(severities);
+        return Ok(());
+    }
+
+    fn clear(&mut self) {
+        self.messages.clear();
+        self.discarded = 0;
+    }
+}
+
+/// A queue that is shared, so a test can keep pushing messages after handing it to a
+/// [`super::dx_info_module::Manager`]
+#[cfg(test)]
+impl InfoQueue for std::rc::Rc<std::cell::RefCell<FakeInfoQueue>> {
+    fn stored_count(&self) -> u64 {
+        return self.borrow().stored_count();
+    }
+
+    fn discarded_count(&self) -> u64 {
+        return self.borrow().discarded_count();
+    }
+
+    fn message(&self, index: u64) -> Result<InfoMessage, Error> {
+        return self.borrow().message(index);
+    }
+
+    fn set_message_limit(&mut self, limit: u64) -> Result<(), Error> {
+        return self.borrow_mut().set_message_limit(limit);
+    }
+
+    fn push_deny_filter(&mut self, ids: &[i32], severities: &[Severity]) -> Result<(), Error> {
+        return self.borrow_mut().push_deny_filter(ids, severities);
+    }
+
+    fn clear(&mut self) {
+        self.borrow_mut().clear();
+    }
+}
+
+/// What the debug layer stores and when a message becomes an error
+#[derive(Debug, Clone, PartialEq)]
+pub struct InfoQueueConfig {
+    /// Amount of messages that are stored before the oldest ones are thrown away
+    pub message_limit: u64,
+    /// D3D11 message ids that are never stored, for messages that show up every frame
+    pub deny_ids: Vec<i32>,
+    pub deny_severities: Vec<Severity>,
+    /// The first message of this severity or worse is turned into an [`Error::DebugLayer`] when the
+    /// queue is checked. Only [`Severity::Corruption`] and [`Severity::Error`] make sense here.
+    pub break_on: Option<Severity>,
+}
+
+impl Default for InfoQueueConfig {
+    fn default() -> InfoQueueConfig {
+        return InfoQueueConfig {
+            message_limit: DEFAULT_MESSAGE_LIMIT,
+            deny_ids: vec![],
+            deny_severities: vec![],
+            break_on: None,
+        };
+    }
+}
+
+impl InfoQueueConfig {
+    /// Set the limit and the deny lists on a queue. The break severity is handled by
+    /// [`super::dx_info_module::Manager`], the debug layer itself would stop in the debugger.
+    pub fn apply(&self, queue: &mut dyn InfoQueue) -> Result<(), Error> {
+        queue.set_message_limit(self.message_limit)?;
+        if !self.deny_ids.is_empty() || !self.deny_severities.is_empty() {
+            queue.push_deny_filter(&self.deny_ids, &self.deny_severities)?;
+        }
+        return Ok(());
+    }
+
+    /// Parse the severity to break on: `corruption`, `error` or `off`
+    pub fn parse_break_on(name: &str) -> Result<Option<Severity>, String> {
+        return match name.trim().to_ascii_lowercase().as_str() {
+            "off" => Ok(None),
+            "corruption" => Ok(Some(Severity::Corruption)),
+            "error" => Ok(Some(Severity::Error)),
+            other => Err(format!(
+                "Can only break on corruption or error, got {:?}",
+                other
+            )),
+        };
+    }
+
+    /// Parse a list of D3D11 message ids like `388,DEVICE_DRAW_INDEX_BUFFER_TOO_SMALL`. Ids can be numbers or names.
+    pub fn parse_deny_ids(spec: &str) -> Result<Vec<i32>, String> {
+        return spec
+            .split(',')
+            .map(str::trim)
+            .filter(|part| !part.is_empty())
+            .map(|part| match part.parse::<i32>() {
+                Ok(id) => Ok(id),
+                Err(_) => d3d11_message_ids::name_to_id(part)
+                    .ok_or_else(|| format!("Unknown D3D11 message id {:?}", part)),
+            })
+            .collect();
+    }
+}
+
+/// The messages [`DrainCursor`] found
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Drained {
+    pub messages: Vec<InfoMessage>,
+    /// Amount of new messages that were thrown away by the queue before they could be read
+    pub lost: u64,
+}
+
+/// Remembers which messages of a queue were already read. Message indices shift when the queue throws
+/// away old messages, so the cursor counts every message the queue ever stored instead: the stored
+/// messages plus the discarded ones.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct DrainCursor {
+    /// Amount of messages the queue had stored in total at the last drain
+    pub position: u64,
+}
+
+impl DrainCursor {
+    /// A cursor that skips the messages that are in the queue right now
+    pub fn at_end(queue: &dyn InfoQueue) -> DrainCursor {
+        return DrainCursor {
+            position: queue.stored_count() + queue.discarded_count(),
+        };
+    }
+
+    /// The messages that were stored since the last drain, without moving the cursor
+    pub fn peek(&self, queue: &dyn InfoQueue) -> Result<Drained, Error> {
+        let stored: u64 = queue.stored_count();
+        let total: u64 = stored + queue.discarded_count();
+
+        // The counts go down when the queue is cleared, everything in it is new then
+        let position: u64 = if total < self.position {
+            total - stored
+        } else {
+            self.position
+        };
+
+        let new: u64 = total - position;
+        let available: u64 = new.min(stored);
+        let mut messages: Vec<InfoMessage> = Vec::with_capacity(available as usize);
+        for index in stored - available..stored {
+            messages.push(queue.message(index)?);
+        }
+
+        return Ok(Drained {
+            messages,
+            lost: new - available,
+        });
+    }
+
+    /// The messages that were stored since the last drain
+    pub fn drain(&mut self, queue: &dyn InfoQueue) -> Result<Drained, Error> {
+        let drained: Drained = self.peek(queue)?;
+        self.position = queue.stored_count() + queue.discarded_count();
+        return Ok(drained);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn message(id: i32, severity: Severity) -> InfoMessage {
+        return InfoMessage {
+            producer: Producer::D3D11,
+            id,
+            name: String::new(),
+            category: Category::Execution,
+            severity,
+            description: String::new(),
+        };
+    }
+
+    fn ids(drained: &Drained) -> Vec<i32> {
+        return drained.messages.iter().map(|message| message.id).collect();
+    }
+
+    #[test]
+    fn fake_queue_throws_away_the_oldest_messages() {
+        let mut queue: FakeInfoQueue = FakeInfoQueue::new();
+        queue.set_message_limit(2).unwrap();
+        for id in 1..=3 {
+            queue.push(message(id, Severity::Warning));
+        }
+        assert_eq!(queue.stored_count(), 2);
+        assert_eq!(queue.discarded_count(), 1);
+        assert_eq!(queue.message(0).unwrap().id, 2);
+        assert!(queue.message(2).is_err());
+
+        // Denied ids only apply to D3D11 messages
+        queue.push_deny_filter(&[4], &[]).unwrap();
+        queue.push(message(4, Severity::Warning));
+        queue.push(InfoMessage {
+            producer: Producer::Dxgi,
+            ..message(4, Severity::Warning)
+        });
+        assert_eq!(queue.message(1).unwrap().producer, Producer::Dxgi);
+    }
+
+    #[test]
+    fn cursor_counts_the_messages_it_missed() {
+        let mut queue: FakeInfoQueue = FakeInfoQueue::new();
+        queue.set_message_limit(3).unwrap();
+        let mut cursor: DrainCursor = DrainCursor::default();
+        queue.push(message(1, Severity::Info));
+        assert_eq!(ids(&cursor.drain(&queue).unwrap()), vec![1]);
+
+        for id in 2..=6 {
+            queue.push(message(id, Severity::Info));
+        }
+        assert_eq!(cursor.peek(&queue).unwrap().lost, 2);
+        let drained: Drained = cursor.drain(&queue).unwrap();
+        assert_eq!(ids(&drained), vec![4, 5, 6]);
+        assert_eq!(drained.lost, 2);
+        assert_eq!(cursor.drain(&queue).unwrap(), Drained::default());
+    }
+
+    #[test]
+    fn cursor_starts_over_when_the_queue_is_cleared() {
+        let mut queue: FakeInfoQueue = FakeInfoQueue::new();
+        for id in 1..=3 {
+            queue.push(message(id, Severity::Info));
+        }
+        let mut cursor: DrainCursor = DrainCursor::at_end(&queue);
+        queue.clear();
+        queue.push(message(7, Severity::Info));
+        assert_eq!(ids(&cursor.drain(&queue).unwrap()), vec![7]);
+    }
+
+    #[test]
+    fn config_parses_the_command_line() {
+        assert_eq!(
+            InfoQueueConfig::parse_deny_ids("388, DEVICE_DRAW_RENDERTARGETVIEW_NOT_SET,"),
+            Ok(vec![388, 3146081])
+        );
+        assert_eq!(
+            InfoQueueConfig::parse_deny_ids("LOUD"),
+            Err("Unknown D3D11 message id \"LOUD\"".to_string())
+        );
+        assert_eq!(
+            InfoQueueConfig::parse_break_on("Error"),
+            Ok(Some(Severity::Error))
+        );
+        assert_eq!(InfoQueueConfig::parse_break_on("off"), Ok(None));
+        assert!(InfoQueueConfig::parse_break_on("warning").is_err());
+    }
+}
@@ -0,0 +1,360 @@
+use std::fmt;
+
+use super::info_message::{Category, Severity};
+
+/// A debug layer message that was parsed from the text the debug layer prints, like:
+///
+/// `D3D11 ERROR: ID3D11DeviceContext::OMSetRenderTargets: The RenderTargetView at slot 0 ... has (w:400,h:300,as:1) ...
+/// [ STATE_SETTING ERROR #388: OMSETRENDERTARGETS_INVALIDVIEW ]`
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParsedMessage {
+    /// The name before the severity, like `D3D11` or `DXGI`
+    pub producer: String,
+    pub severity: Option<Severity>,
+    /// `ID3D11DeviceContext` in `ID3D11DeviceContext::OMSetRenderTargets`
+    pub interface: Option<String>,
+    /// `OMSetRenderTargets` in `ID3D11DeviceContext::OMSetRenderTargets`
+    pub method: Option<String>,
+    /// The text of the message without the prefix, the method and the trailer
+    pub description: String,
+    pub category: Option<Category>,
+    pub id: Option<i32>,
+    /// The name of the id like `OMSETRENDERTARGETS_INVALIDVIEW`. Some messages have an empty name.
+    pub id_name: Option<String>,
+    /// Every `(w:400,h:300,as:1)` tuple in the description, in order
+    pub tuples: Vec<Vec<(String, String)>>,
+}
+
+impl fmt::Display for ParsedMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.producer)?;
+        if let Some(severity) = self.severity {
+            write!(f, " {}", severity)?;
+        }
+        write!(f, ":")?;
+        if let (Some(interface), Some(method)) = (&self.interface, &self.method) {
+            write!(f, " {}::{}:", interface, method)?;
+        }
+        write!(f, " {}", self.description)?;
+        if let (Some(category), Some(severity), Some(id)) = (self.category, self.severity, self.id)
+        {
+            write!(
+                f,
+                " [ {} {} #{}: {} ]",
+                category,
+                severity,
+                id,
+                self.id_name.as_deref().unwrap_or("")
+            )?;
+        }
+        return Ok(());
+    }
+}
+
+/// The `[ STATE_SETTING ERROR #388: OMSETRENDERTARGETS_INVALIDVIEW ]` part at the end of a message
+struct Trailer {
+    category: Option<Category>,
+    severity: Option<Severity>,
+    id: i32,
+    id_name: Option<String>,
+}
+
+/// Parse a single debug layer message. Line breaks and runs of spaces in the text are treated as a single space,
+/// so messages that were copied from a wrapped output window can be parsed as well.
+/// Returns `None` if the text doesn't look like a debug layer message.
+pub fn parse_message(text: &str) -> Option<ParsedMessage> {
+    let text: String = text.split_whitespace().collect::<Vec<&str>>().join(" ");
+
+    let (head, mut rest) = text.split_once(':')?;
+    let head: &str = head.trim();
+    let (producer, mut severity) = match head.split_once(' ') {
+        // D3D11 ERROR: ...
+        Some((producer, severity)) => (producer, Some(Severity::from_name(severity)?)),
+        // D3D11: ERROR: ...
+        None => match rest.split_once(':') {
+            Some((severity, after)) if Severity::from_name(severity.trim()).is_some() => {
+                rest = after;
+                (head, Severity::from_name(severity.trim()))
+            }
+            _ => (head, None),
+        },
+    };
+
+    if producer.is_empty() || !producer.chars().all(|ch| ch.is_ascii_alphanumeric()) {
+        return None;
+    }
+
+    let mut body: &str = rest.trim();
+    let trailer: Option<Trailer> = match body.rfind('[') {
+        Some(start) if body.ends_with(']') => {
+            let trailer: Option<Trailer> = parse_trailer(&body[start + 1..body.len() - 1]);
+            if trailer.is_some() {
+                body = body[..start].trim_end();
+            }
+            trailer
+        }
+        _ => None,
+    };
+
+    if severity.is_none() && trailer.is_none() {
+        return None;
+    }
+
+    let (interface, method, description) = match body.split_once(": ") {
+        Some((call, after)) if !call.contains(' ') && call.contains("::") => {
+            let (interface, method) = call.split_once("::").unwrap();
+            (
+                Some(interface.to_string()),
+                Some(method.to_string()),
+                after.trim(),
+            )
+        }
+        _ => (None, None, body),
+    };
+
+    let mut category: Option<Category> = None;
+    let mut id: Option<i32> = None;
+    let mut id_name: Option<String> = None;
+    if let Some(trailer) = trailer {
+        category = trailer.category;
+        severity = trailer.severity.or(severity);
+        id = Some(trailer.id);
+        id_name = trailer.id_name;
+    }
+
+    return Some(ParsedMessage {
+        producer: producer.to_string(),
+        severity,
+        interface,
+        method,
+        description: description.to_string(),
+        category,
+        id,
+        id_name,
+        tuples: parse_tuples(description),
+    });
+}
+
+/// Parse all the messages in a log, like the output window of the debugger or a file with messages.
+/// Each message ends with its trailer, text that can't be parsed is skipped.
+pub fn parse_log(text: &str) -> Vec<ParsedMessage> {
+    let mut messages: Vec<ParsedMessage> = Vec::new();
+    let mut start: usize = 0;
+    for (end, _) in text.match_indices(']') {
+        let Some(open) = text[start..end].rfind('[') else {
+            continue;
+        };
+        if parse_trailer(&text[start + open + 1..end]).is_none() {
+            continue;
+        }
+
+        let chunk: &str = &text[start..=end];
+        // Skip anything in front of the first message, like a timestamp
+        let begin: usize = message_start(chunk).unwrap_or(0);
+        if let Some(message) = parse_message(&chunk[begin..]) {
+            messages.push(message);
+        }
+        start = end + 1;
+    }
+    return messages;
+}
+
+/// Count how often every message id shows up, most common first. Messages without an id are left out.
+pub fn count_by_id(messages: &[ParsedMessage]) -> Vec<(i32, String, usize)> {
+    let mut counts: Vec<(i32, String, usize)> = Vec::new();
+    for message in messages {
+        let Some(id) = message.id else {
+            continue;
+        };
+        match counts.iter_mut().find(|(counted, _, _)| *counted == id) {
+            Some((_, _, count)) => *count += 1,
+            None => counts.push((id, message.id_name.clone().unwrap_or_default(), 1)),
+        }
+    }
+    counts.sort_by(|a, b| b.2.cmp(&a.2).then(a.0.cmp(&b.0)));
+    return counts;
+}
+
+fn parse_trailer(inner: &str) -> Option<Trailer> {
+    let mut words = inner.split_whitespace();
+    let category: &str = words.next()?;
+    let severity: &str = words.next()?;
+    let number: &str = words.next()?;
+    let id_name: Option<String> = words.next().map(|name| name.to_string());
+    if words.next().is_some() {
+        return None;
+    }
+
+    let number: &str = number.strip_prefix('#')?.strip_suffix(':')?;
+    return Some(Trailer {
+        category: Category::from_name(category),
+        severity: Some(Severity::from_name(severity)?),
+        id: number.parse().ok()?,
+        id_name,
+    });
+}
+
+/// Find where a message starts in a chunk of text, which is the producer in front of the severity
+fn message_start(chunk: &str) -> Option<usize> {
+    let mut best: Option<usize> = None;
+    for severity in Severity::ALL {
+        for pattern in [
+            format!(" {}:", severity.name()),
+            format!(": {}:", severity.name()),
+        ] {
+            if let Some(found) = chunk.find(&pattern) {
+                let producer_start: usize = chunk[..found]
+                    .rfind(|ch: char| !ch.is_ascii_alphanumeric())
+                    .map_or(0, |index| index + 1);
+                if producer_start < found && best.is_none_or(|best| producer_start < best) {
+                    best = Some(producer_start);
+                }
+            }
+        }
+    }
+    return best;
+}
+
+/// Find all the `(key:value,key:value)` tuples in a description. Parentheses that contain anything else,
+/// like `GetFeatureLevel()`, are skipped.
+fn parse_tuples(description: &str) -> Vec<Vec<(String, String)>> {
+    let mut tuples: Vec<Vec<(String, String)>> = Vec::new();
+    let mut rest: &str = description;
+    while let Some(open) = rest.find('(') {
+        let Some(close) = rest[open..].find(')').map(|close| open + close) else {
+            break;
+        };
+
+        let inner: &str = &rest[open + 1..close];
+        let pairs: Option<Vec<(String, String)>> = inner
+            .split(',')
+            .map(|pair| {
+                let (key, value) = pair.split_once(':')?;
+                let key: &str = key.trim();
+                if key.is_empty()
+                    || !key
+                        .chars()
+                        .all(|ch| ch.is_ascii_alphanumeric() || ch == '_')
+                {
+                    return None;
+                }
+                return Some((key.to_string(), value.trim().to_string()));
+            })
+            .collect();
+
+        if let Some(pairs) = pairs {
+            tuples.push(pairs);
+        }
+        rest = &rest[close + 1..];
+    }
+    return tuples;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Messages copied from the output window of the debugger, with a line that isn't a message
+    const CORPUS: &str = include_str!("../../../tests/data/debug_layer_messages.txt");
+
+    #[test]
+    fn parses_the_saved_message() {
+        // Copied from a wrapped output window, with the `D3D11: ERROR:` prefix of older runtimes
+        let message: ParsedMessage = parse_message(include_str!("../../../t.txt")).unwrap();
+        assert_eq!(message.producer, "D3D11");
+        assert_eq!(message.severity, Some(Severity::Error));
+        assert_eq!(message.interface.as_deref(), Some("ID3D11DeviceContext"));
+        assert_eq!(message.method.as_deref(), Some("OMSetRenderTargets"));
+        assert_eq!(message.category, Some(Category::StateSetting));
+        assert_eq!(message.id, Some(388));
+        assert_eq!(
+            message.id_name.as_deref(),
+            Some("OMSETRENDERTARGETS_INVALIDVIEW")
+        );
+        assert!(message
+            .description
+            .starts_with("The RenderTargetView at slot 0 is not compatable"));
+        assert!(message
+            .description
+            .ends_with("D3D_FEATURE_LEVEL_10_1 or greater."));
+
+        let pair = |key: &str, value: &str| (key.to_string(), value.to_string());
+        assert_eq!(
+            message.tuples,
+            vec![
+                vec![pair("w", "400"), pair("h", "300"), pair("as", "1")],
+                vec![pair("mc", "1"), pair("mq", "0")],
+                vec![pair("w", "1280"), pair("h", "720"), pair("as", "1")],
+                vec![pair("mc", "1"), pair("mq", "0")],
+            ]
+        );
+    }
+
+    #[test]
+    fn parses_every_message_of_the_corpus() {
+        let messages: Vec<ParsedMessage> = parse_log(CORPUS);
+        let ids: Vec<Option<i32>> = messages.iter().map(|message| message.id).collect();
+        assert_eq!(
+            ids,
+            [2097228, 163, 294, 3146081, 3146081, 441]
+                .map(Some)
+                .to_vec()
+        );
+
+        let create: &ParsedMessage = &messages[0];
+        assert_eq!(create.severity, Some(Severity::Info));
+        assert_eq!(create.interface, None);
+        assert_eq!(create.id_name.as_deref(), Some("CREATE_BUFFER"));
+
+        let layout: &ParsedMessage = &messages[1];
+        assert_eq!(layout.method.as_deref(), Some("CreateInputLayout"));
+        assert_eq!(layout.category, Some(Category::StateCreation));
+
+        // DXGI messages can have an id without a name, and parentheses that aren't tuples
+        let swap_chain: &ParsedMessage = &messages[2];
+        assert_eq!(swap_chain.producer, "DXGI");
+        assert_eq!(swap_chain.interface.as_deref(), Some("IDXGIFactory"));
+        assert_eq!(swap_chain.id_name, None);
+        assert!(swap_chain.tuples.is_empty());
+        assert!(swap_chain
+            .description
+            .ends_with("http://aka.ms/dxgiflipmodel."));
+
+        assert_eq!(messages[5].producer, "D3D11");
+        assert_eq!(messages[5].severity, Some(Severity::Warning));
+    }
+
+    #[test]
+    fn counts_the_most_common_messages_first() {
+        let counts: Vec<(i32, String, usize)> = count_by_id(&parse_log(CORPUS));
+        assert_eq!(
+            counts[0],
+            (
+                3146081,
+                "DEVICE_DRAW_RENDERTARGETVIEW_NOT_SET".to_string(),
+                2
+            )
+        );
+        assert_eq!(counts.len(), 5);
+        assert!(counts[1..].iter().all(|(_, _, count)| *count == 1));
+    }
+
+    #[test]
+    fn display_rebuilds_the_message() {
+        let line: &str = CORPUS.lines().nth(1).unwrap();
+        assert_eq!(
+            parse_message(line).unwrap().to_string(),
+            line.replace("MISSINGELEMENT]", "MISSINGELEMENT ]")
+        );
+    }
+
+    #[test]
+    fn rejects_text_that_isnt_a_message() {
+        assert_eq!(
+            parse_message("Exception thrown at 0x00007FFC3F6A4F69: oops"),
+            None
+        );
+        assert_eq!(parse_message("no colon here"), None);
+        assert_eq!(parse_message("D3D11 LOUD: not a severity"), None);
+    }
+}
@@ -0,0 +1,190 @@
+use std::{
+    any::Any,
+    backtrace::Backtrace,
+    panic::{AssertUnwindSafe, PanicHookInfo},
+    path::PathBuf,
+    sync::{Arc, Mutex},
+};
+
+use super::{crash_report::CrashReport, reporter::Reporter, CallLocation, Error};
+use crate::logger;
+
+/// What happens to a panic once the hook is installed with [`install()`]. The hook does all the reporting
+/// itself, because a panic in the window procedure can't unwind into the frame loop and aborts the app.
+#[derive(Debug, Clone)]
+pub struct PanicHook {
+    pub reporter: Reporter,
+    pub crash_dir: PathBuf,
+    /// Add the messages of the DXGI debug layer to the report. Only works on windows.
+    pub debug_layer: bool,
+}
+
+/// The last panic the hook reported, so [`catch()`] can return it
+static LAST_PANIC: Mutex<Option<Error>> = Mutex::new(None);
+
+/// Replace the default panic hook, which only prints the panic to stderr
+pub fn install(hook: PanicHook) {
+    std::panic::set_hook(Box::new(move |info| hook.handle(info)));
+}
+
+impl PanicHook {
+    /// Turn the panic into an [`Error::Panic`], write a crash report for it and show it with the reporter
+    pub fn handle(&self, info: &PanicHookInfo) {
+        let error: Error = panic_error(info);
+        let debug_messages: Vec<String> = self.debug_messages();
+
+        // Get the log that led up to the panic on disk, nothing is logged after this
+        logger::flush();
+
+        let mut report: CrashReport = CrashReport::from_error(&error);
+        report.debug_messages = debug_messages.clone();
+        match report.write_to_dir(&self.crash_dir) {
+            Ok(path) => eprintln!("Crash report written to {}", path.display()),
+            Err(e) => eprintln!(
+                "Unable to write a crash report to {}: {}",
+                self.crash_dir.display(),
+                e
+            ),
+        }
+
+        self.reporter.report(&error, &debug_messages);
+
+        if let Ok(mut last) = LAST_PANIC.lock() {
+            *last = Some(error);
+        }
+    }
+
+    /// The info queue of the debug layer belongs to the process, so the messages can be read without the
+    /// [`super::dx_info_module::Manager`] of the graphics
+    fn debug_messages(&self) -> Vec<String> {
+        if !self.debug_layer {
+            return vec![];
+        }
+
+        #[cfg(windows)]
+        {
+            return super::dx_info_module::Manager::new()
+                .and_then(|manager| manager.get_messages())
+                .unwrap_or_else(|e| vec![format!("Unable to read the debug messages: {}", e)]);
+        }
+
+        #[cfg(not(windows))]
+        return vec![];
+    }
+}
+
+/// Run `f` and return a panic in it as an [`Error::Panic`]. A panic that was reported by the hook is
+/// returned as it was reported, so the caller shouldn't report it again.
+pub fn catch<T>(f: impl FnOnce() -> Result<T, Error>) -> Result<T, Error> {
+    return match std::panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(result) => result,
+        Err(payload) => Err(take_last_panic().unwrap_or_else(|| {
+            Error::panic(&payload_message(payload.as_ref()), CallLocation::caller())
+        })),
+    };
+}
+
+/// The last panic that was reported by the hook
+pub fn take_last_panic() -> Option<Error> {
+    return LAST_PANIC.lock().ok()?.take();
+}
+
+fn panic_error(info: &PanicHookInfo) -> Error {
+    // Panics always get a backtrace, there is nothing else to find them with
+    let backtrace: Option<Arc<Backtrace>> = Some(Arc::new(Backtrace::force_capture()));
+    let origin: CallLocation = match info.location() {
+        Some(location) => CallLocation {
+            file: location.file().to_string(),
+            line: location.line(),
+            column: location.column(),
+            backtrace,
+        },
+        None => CallLocation {
+            file: "<unknown>".to_string(),
+            line: 0,
+            column: 0,
+            backtrace,
+        },
+    };
+    return Error::panic(&payload_message(info.payload()), origin);
+}
+
+/// The message of `panic!()` is a `&str` or a `String`, depending on whether it was formatted
+fn payload_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        return message.to_string();
+    }
+    if let Some(message) = payload.downcast_ref::<String>() {
+        return message.clone();
+    }
+    return "Box<dyn Any>".to_string();
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+
+    #[test]
+    fn catch_passes_results_through() {
+        assert_eq!(catch(|| Ok(3)).unwrap(), 3);
+        let error: Error =
+            catch(|| -> Result<(), Error> { Err(Error::window("gone")) }).unwrap_err();
+        assert_eq!(error.to_string(), "Window error: gone");
+    }
+
+    #[test]
+    fn payloads_become_messages() {
+        assert_eq!(payload_message(&"static"), "static");
+        assert_eq!(payload_message(&format!("formatted {}", 1)), "formatted 1");
+        assert_eq!(payload_message(&3_u32), "Box<dyn Any>");
+    }
+
+    #[test]
+    fn hook_reports_the_panic_and_writes_a_crash_report() {
+        let dir: PathBuf =
+            std::env::temp_dir().join(format!("panic_hook_test_{}", std::process::id()));
+        let log: PathBuf = dir.join("errors.log");
+        let crash_dir: PathBuf = dir.join("crashes");
+        fs::create_dir_all(&dir).unwrap();
+
+        // The hook belongs to the process, the one of the test harness is put back right away
+        let previous = std::panic::take_hook();
+        install(PanicHook {
+            reporter: Reporter::LogFile(log.clone()),
+            crash_dir: crash_dir.clone(),
+            debug_layer: false,
+        });
+        let line: u32 = line!() + 1;
+        let result: Result<(), Error> = catch(|| panic!("boom {}", 3));
+        std::panic::set_hook(previous);
+
+        let error: Error = result.unwrap_err();
+        assert!(matches!(&error, Error::Panic { message, .. } if message == "boom 3"));
+        assert_eq!(error.origin().file, file!());
+        assert_eq!(error.origin().line, line);
+        assert!(error.backtrace().is_some());
+        assert_eq!(error.exit_code(), 101);
+        // catch() took the panic the hook reported
+        assert!(take_last_panic().is_none());
+
+        let logged: String = fs::read_to_string(&log).unwrap();
+        assert!(logged.starts_with(&format!(
+            "Error in {}\nPanicked: boom 3\n\nBacktrace:\n",
+            error.origin()
+        )));
+
+        let reports: Vec<PathBuf> = fs::read_dir(&crash_dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .collect();
+        assert_eq!(reports.len(), 1);
+        let report: CrashReport =
+            CrashReport::parse(&fs::read_to_string(&reports[0]).unwrap()).unwrap();
+        assert_eq!(report.error, "Panicked: boom 3");
+        assert_eq!(report.origin, error.origin().to_string());
+        assert!(report.debug_messages.is_empty());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,105 @@
+use std::{fs::OpenOptions, io::Write, path::PathBuf};
+
+use super::Error;
+
+/// Decides how an error that stopped the app is shown to the user.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Reporter {
+    /// Show the error in a message box and print the debug layer messages to the console
+    MessageBox,
+    /// Print the error and the debug layer messages to stderr
+    Stderr,
+    /// Append the error and the debug layer messages to a log file
+    LogFile(PathBuf),
+}
+
+impl Default for Reporter {
+    fn default() -> Reporter {
+        if cfg!(windows) {
+            return Reporter::MessageBox;
+        }
+        return Reporter::Stderr;
+    }
+}
+
+impl Reporter {
+    /// Parse a reporter from the command line. `messagebox` and `stderr` pick those reporters,
+    /// everything else is used as the path of a log file.
+    pub fn parse(value: &str) -> Reporter {
+        return match value {
+            "messagebox" => Reporter::MessageBox,
+            "stderr" => Reporter::Stderr,
+            path => Reporter::LogFile(PathBuf::from(path)),
+        };
+    }
+
+    pub fn report(&self, error: &Error, debug_messages: &[String]) {
+        match self {
+            Reporter::MessageBox => {
+                #[cfg(windows)]
+                {
+                    let text: String = format!("{}\0", format_report(error, &[]));
+                    crate::window::create_message_box(
+                        windows::core::PCSTR::from_raw(text.as_ptr()),
+                        windows::Win32::UI::WindowsAndMessaging::MB_ICONERROR
+                            | windows::Win32::UI::WindowsAndMessaging::MB_OK,
+                        0,
+                    );
+
+                    for msg in debug_messages {
+                        crate::log_error!("{}", msg);
+                    }
+                }
+
+                // There are no message boxes without win32
+                #[cfg(not(windows))]
+                Reporter::Stderr.report(error, debug_messages);
+            }
+            Reporter::Stderr => {
+                eprintln!("{}", format_report(error, debug_messages));
+            }
+            Reporter::LogFile(path) => {
+                let written = OpenOptions::new()
+                    .create(true)
+                    .append(true)
+                    .open(path)
+                    .and_then(|mut file| writeln!(file, "{}\n", format_report(error, debug_messages)));
+
+                // If we can't write the log the error should at least end up somewhere
+                if let Err(e) = written {
+                    eprintln!("Unable to write error log {}: {}", path.display(), e);
+                    Reporter::Stderr.report(error, debug_messages);
+                }
+            }
+        }
+    }
+}
+
+/// Format an error with its location, the errors that caused it and the messages of the debug layer.
+pub fn format_report(error: &Error, debug_messages: &[String]) -> String {
+    let mut report: String = format!("Error in {}\n{}", error.origin(), error);
+
+    let mut source: Option<&dyn std::error::Error> = std::error::Error::source(error);
+    while let Some(cause) = source {
+        let cause_details: String = cause.to_string();
+        // A windows error is often the source of an error with the same message, no need to print it twice
+        if !report.contains(cause_details.trim_end()) {
+            report.push_str(&format!("\nCaused by: {}", cause_details.trim_end()));
+        }
+        source = cause.source();
+    }
+
+    if let Some(backtrace) = error.backtrace() {
+        report.push_str(&format!("\n\nBacktrace:\n{}", backtrace.to_string().trim_end()));
+    }
+
+    if !debug_messages.is_empty() {
+        report.push_str("\n\nDebug layer messages:");
+        for msg in debug_messages {
+            report.push('\n');
+            report.push_str(msg);
+        }
+    }
+
+    return report;
+}
@@ -0,0 +1,337 @@
+use std::{borrow::Cow, path::PathBuf};
+
+use crate::window::errors::Error;
+
+pub mod d3d11;
+#[cfg(test)]
+pub mod recording;
+pub mod software;
+
+/// A buffer that was created by a [`RenderBackend`]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct BufferId(pub u32);
+
+/// A compiled shader that was created by a [`RenderBackend`]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ShaderId(pub u32);
+
+/// An input layout that was created by a [`RenderBackend`]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct LayoutId(pub u32);
+
+/// A depth stencil or rasterizer state that was created by a [`RenderBackend`]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct StateId(pub u32);
+
+/// Anything a [`RenderBackend`] created, for [`RenderBackend::release()`]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Resource {
+    Buffer(BufferId),
+    Shader(ShaderId),
+    Layout(LayoutId),
+    State(StateId),
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum BufferKind {
+    Vertex,
+    Index,
+    Constant,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BufferDesc<'a> {
+    pub kind: BufferKind,
+    /// The initial contents, the size of the buffer is the length of the data
+    pub data: &'a [u8],
+    /// The CPU writes the buffer with [`RenderBackend::update_buffer()`] often, like a constant buffer
+    /// that changes every frame
+    pub dynamic: bool,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ShaderStage {
+    Vertex,
+    Pixel,
+}
+
+impl ShaderStage {
+    /// The shader model 5 profile of the stage, like `vs_5_0`
+    pub fn default_profile(self) -> &'static str {
+        return match self {
+            ShaderStage::Vertex => "vs_5_0",
+            ShaderStage::Pixel => "ps_5_0",
+        };
+    }
+}
+
+/// Where the code of a shader comes from, see [`crate::window::graphics::shaderlocator`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ShaderCode {
+    /// The HLSL file at the path of the shader
+    File,
+    /// HLSL source that is already loaded, the path of the shader is only its name in errors
+    Source(Cow<'static, str>),
+    /// Bytecode that was compiled before, like a `.cso` file. The entry point, profile and defines are
+    /// the ones it was compiled with.
+    Bytecode(Cow<'static, [u8]>),
+}
+
+/// A HLSL shader and how it should be compiled
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ShaderDesc {
+    pub stage: ShaderStage,
+    pub path: PathBuf,
+    pub code: ShaderCode,
+    pub entry_point: String,
+    pub profile: String,
+    /// Macros that are defined while compiling, like `/D NAME=VALUE`
+    pub defines: Vec<(String, String)>,
+}
+
+impl ShaderDesc {
+    /// A shader with a `main` entry point and the default profile of its stage
+    pub fn new(stage: ShaderStage, path: PathBuf) -> ShaderDesc {
+        return ShaderDesc {
+            stage,
+            path,
+            code: ShaderCode::File,
+            entry_point: "main".to_string(),
+            profile: stage.default_profile().to_string(),
+            defines: vec![],
+        };
+    }
+}
+
+/// The format of a vertex attribute
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum VertexFormat {
+    Float,
+    Float2,
+    Float3,
+    Float4,
+    /// Four bytes that are read as integers, like bone indices
+    UByte4,
+    /// Four bytes that are read as 0 to 1, like a compact color
+    UByte4Norm,
+}
+
+impl VertexFormat {
+    /// Amount of components in the attribute
+    pub fn components(self) -> usize {
+        return match self {
+            VertexFormat::Float => 1,
+            VertexFormat::Float2 => 2,
+            VertexFormat::Float3 => 3,
+            VertexFormat::Float4 | VertexFormat::UByte4 | VertexFormat::UByte4Norm => 4,
+        };
+    }
+
+    /// Size of a single component in bytes
+    pub fn component_size(self) -> u32 {
+        return match self {
+            VertexFormat::UByte4 | VertexFormat::UByte4Norm => 1,
+            _ => 4,
+        };
+    }
+
+    pub fn size(self) -> u32 {
+        return self.components() as u32 * self.component_size();
+    }
+}
+
+/// A single attribute of a vertex, like `D3D11_INPUT_ELEMENT_DESC`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct InputElement {
+    pub semantic: String,
+    pub semantic_index: u32,
+    pub format: VertexFormat,
+    /// Offset of the attribute from the start of the vertex in bytes
+    pub offset: u32,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum IndexFormat {
+    U16,
+    U32,
+}
+
+impl IndexFormat {
+    pub fn size(self) -> u32 {
+        return match self {
+            IndexFormat::U16 => 2,
+            IndexFormat::U32 => 4,
+        };
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Topology {
+    TriangleList,
+    TriangleStrip,
+}
+
+/// The part of the render target that is drawn to, like `D3D11_VIEWPORT`
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Viewport {
+    pub top_left_x: f32,
+    pub top_left_y: f32,
+    pub width: f32,
+    pub height: f32,
+    pub min_depth: f32,
+    pub max_depth: f32,
+}
+
+/// How a new depth is compared with the depth in the depth buffer, like `D3D11_COMPARISON_FUNC`
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Comparison {
+    Never,
+    Less,
+    Equal,
+    LessEqual,
+    Greater,
+    NotEqual,
+    GreaterEqual,
+    Always,
+}
+
+impl Comparison {
+    /// Whether `new` passes against `current`
+    pub fn test(self, new: f32, current: f32) -> bool {
+        return match self {
+            Comparison::Never => false,
+            Comparison::Less => new < current,
+            Comparison::Equal => new == current,
+            Comparison::LessEqual => new <= current,
+            Comparison::Greater => new > current,
+            Comparison::NotEqual => new != current,
+            Comparison::GreaterEqual => new >= current,
+            Comparison::Always => true,
+        };
+    }
+}
+
+/// The depth part of `D3D11_DEPTH_STENCIL_DESC`. The default is the default of D3D11.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct DepthStencilDesc {
+    pub depth_enable: bool,
+    pub depth_write: bool,
+    pub depth_func: Comparison,
+}
+
+impl Default for DepthStencilDesc {
+    fn default() -> DepthStencilDesc {
+        return DepthStencilDesc {
+            depth_enable: true,
+            depth_write: true,
+            depth_func: Comparison::Less,
+        };
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum CullMode {
+    None,
+    Front,
+    Back,
+}
+
+/// The culling part of `D3D11_RASTERIZER_DESC`. The default is the default of D3D11: triangles that are
+/// clockwise on the screen are the front and the back is culled.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct RasterizerDesc {
+    pub cull_mode: CullMode,
+    pub front_counter_clockwise: bool,
+}
+
+impl Default for RasterizerDesc {
+    fn default() -> RasterizerDesc {
+        return RasterizerDesc {
+            cull_mode: CullMode::Back,
+            front_counter_clockwise: false,
+        };
+    }
+}
+
+/// Everything the graphics need from a GPU API: creating buffers, shaders and states, binding them and
+/// drawing. Implemented by [`d3d11::D3D11Backend`] and by [`software::SoftwareBackend`], which renders
+/// on the CPU so the drawing code can run without a GPU.
+///
+/// The render target and the depth buffer are owned by the backend and are as big as the window.
+pub trait RenderBackend {
+    fn width(&self) -> u32;
+    fn height(&self) -> u32;
+
+    fn create_buffer(&mut self, desc: &BufferDesc) -> Result<BufferId, Error>;
+    /// Replace the contents of a buffer, the data has to be as big as the buffer
+    fn update_buffer(&mut self, buffer: BufferId, data: &[u8]) -> Result<(), Error>;
+    fn create_shader(&mut self, desc: &ShaderDesc) -> Result<ShaderId, Error>;
+    /// Compile `desc` again and put it in the place of `shader`, so everything that uses the id gets the new
+    /// one. When compiling fails, the old shader stays.
+    fn replace_shader(&mut self, shader: ShaderId, desc: &ShaderDesc) -> Result<(), Error>;
+    /// Create an input layout for the input signature of `vertex_shader`
+    fn create_input_layout(
+        &mut self,
+        elements: &[InputElement],
+        vertex_shader: ShaderId,
+    ) -> Result<LayoutId, Error>;
+    fn create_depth_stencil_state(&mut self, desc: &DepthStencilDesc) -> Result<StateId, Error>;
+    fn create_rasterizer_state(&mut self, desc: &RasterizerDesc) -> Result<StateId, Error>;
+    /// Free a resource. Resources that are still bound stay usable until they are replaced.
+    fn release(&mut self, resource: Resource);
+
+    fn set_vertex_buffer(
+        &mut self,
+        slot: u32,
+        buffer: BufferId,
+        stride: u32,
+        offset: u32,
+    ) -> Result<(), Error>;
+    fn set_index_buffer(
+        &mut self,
+        buffer: BufferId,
+        format: IndexFormat,
+        offset: u32,
+    ) -> Result<(), Error>;
+    fn set_constant_buffer(
+        &mut self,
+        stage: ShaderStage,
+        slot: u32,
+        buffer: BufferId,
+    ) -> Result<(), Error>;
+    /// Bind a shader to the stage it was created for
+    fn set_shader(&mut self, shader: ShaderId) -> Result<(), Error>;
+    fn set_input_layout(&mut self, layout: LayoutId) -> Result<(), Error>;
+    fn set_topology(&mut self, topology: Topology);
+    fn set_viewport(&mut self, viewport: &Viewport);
+    fn set_depth_stencil_state(&mut self, state: StateId) -> Result<(), Error>;
+    fn set_rasterizer_state(&mut self, state: StateId) -> Result<(), Error>;
+
+    /// Clear the render target to a color and the depth buffer to 1.0
+    fn clear(&mut self, rgba: [f32; 4]);
+    fn draw_indexed(&mut self, index_count: u32, start_index: u32, base_vertex: i32) -> Result<(), Error>;
+    /// Show the frame
+    fn present(&mut self) -> Result<(), Error>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn comparisons_pass_like_d3d11() {
+        // A smaller, the same and a bigger depth than the one in the depth buffer
+        let passes = |comparison: Comparison| {
+            [0.25, 0.5, 0.75].map(|new: f32| comparison.test(new, 0.5))
+        };
+
+        assert_eq!(passes(Comparison::Never), [false, false, false]);
+        assert_eq!(passes(Comparison::Less), [true, false, false]);
+        assert_eq!(passes(Comparison::Equal), [false, true, false]);
+        assert_eq!(passes(Comparison::LessEqual), [true, true, false]);
+        assert_eq!(passes(Comparison::Greater), [false, false, true]);
+        assert_eq!(passes(Comparison::NotEqual), [true, false, true]);
+        assert_eq!(passes(Comparison::GreaterEqual), [false, true, true]);
+        assert_eq!(passes(Comparison::Always), [true, true, true]);
+    }
+}
@@ -0,0 +1,828 @@
+use std::{collections::HashMap, ffi::CString, slice::from_raw_parts};
+
+use windows::{
+    core::{ComInterface, PCSTR},
+    Win32::{
+        Foundation::{E_FAIL, FALSE, HWND, S_OK, TRUE},
+        Graphics::{
+            Direct3D::{
+                Fxc::{D3DCompile, D3DCreateBlob, D3DCOMPILE_DEBUG, D3DCOMPILE_SKIP_OPTIMIZATION},
+                ID3DBlob, D3D_DRIVER_TYPE_HARDWARE, D3D_PRIMITIVE_TOPOLOGY_TRIANGLELIST,
+                D3D_PRIMITIVE_TOPOLOGY_TRIANGLESTRIP,
+            },
+            Direct3D11::{
+                D3D11CreateDevice, ID3D11Buffer, ID3D11DepthStencilState, ID3D11DepthStencilView,
+                ID3D11Device, ID3D11DeviceContext, ID3D11InputLayout, ID3D11PixelShader,
+                ID3D11RasterizerState, ID3D11RenderTargetView, ID3D11Resource, ID3D11Texture2D,
+                ID3D11VertexShader, D3D11_BIND_CONSTANT_BUFFER, D3D11_BIND_DEPTH_STENCIL,
+                D3D11_BIND_FLAG, D3D11_BIND_INDEX_BUFFER, D3D11_BIND_VERTEX_BUFFER,
+                D3D11_BUFFER_DESC, D3D11_CLEAR_DEPTH, D3D11_COMPARISON_ALWAYS,
+                D3D11_COMPARISON_EQUAL, D3D11_COMPARISON_FUNC, D3D11_COMPARISON_GREATER,
+                D3D11_COMPARISON_GREATER_EQUAL, D3D11_COMPARISON_LESS, D3D11_COMPARISON_LESS_EQUAL,
+                D3D11_COMPARISON_NEVER, D3D11_COMPARISON_NOT_EQUAL, D3D11_CPU_ACCESS_FLAG,
+                D3D11_CPU_ACCESS_WRITE, D3D11_CREATE_DEVICE_DEBUG, D3D11_CULL_BACK,
+                D3D11_CULL_FRONT, D3D11_CULL_NONE, D3D11_DEPTH_STENCILOP_DESC,
+                D3D11_DEPTH_STENCIL_DESC, D3D11_DEPTH_STENCIL_VIEW_DESC,
+                D3D11_DEPTH_STENCIL_VIEW_DESC_0, D3D11_DEPTH_WRITE_MASK_ALL,
+                D3D11_DEPTH_WRITE_MASK_ZERO, D3D11_DSV_DIMENSION_TEXTURE2D, D3D11_FILL_SOLID,
+                D3D11_INPUT_ELEMENT_DESC, D3D11_INPUT_PER_VERTEX_DATA, D3D11_MAPPED_SUBRESOURCE,
+                D3D11_MAP_WRITE_DISCARD, D3D11_RASTERIZER_DESC, D3D11_RESOURCE_MISC_FLAG,
+                D3D11_SDK_VERSION, D3D11_SUBRESOURCE_DATA, D3D11_TEX2D_DSV, D3D11_TEXTURE2D_DESC,
+                D3D11_USAGE_DEFAULT, D3D11_USAGE_DYNAMIC, D3D11_VIEWPORT,
+            },
+            Dxgi::{
+                Common::{
+                    DXGI_ALPHA_MODE_UNSPECIFIED, DXGI_FORMAT, DXGI_FORMAT_D32_FLOAT,
+                    DXGI_FORMAT_R16_UINT, DXGI_FORMAT_R32G32B32A32_FLOAT,
+                    DXGI_FORMAT_R32G32B32_FLOAT, DXGI_FORMAT_R32G32_FLOAT, DXGI_FORMAT_R32_FLOAT,
+                    DXGI_FORMAT_R32_UINT, DXGI_FORMAT_R8G8B8A8_UINT, DXGI_FORMAT_R8G8B8A8_UNORM,
+                    DXGI_SAMPLE_DESC,
+                },
+                CreateDXGIFactory2, IDXGIDevice, IDXGIFactory4, IDXGISwapChain1, DXGI_ADAPTER_DESC,
+                DXGI_ERROR_DEVICE_REMOVED, DXGI_SCALING_STRETCH, DXGI_SWAP_CHAIN_DESC1,
+                DXGI_SWAP_EFFECT_FLIP_DISCARD, DXGI_USAGE_RENDER_TARGET_OUTPUT,
+            },
+        },
+    },
+};
+
+use super::{
+    BufferDesc, BufferId, BufferKind, Comparison, CullMode, DepthStencilDesc, IndexFormat,
+    InputElement, LayoutId, RasterizerDesc, RenderBackend, Resource, ShaderCode, ShaderDesc,
+    ShaderId, ShaderStage, StateId, Topology, VertexFormat, Viewport,
+};
+use crate::window::{
+    errors::{Context, Error, WindowsError},
+    graphics::{
+        preprocessor::{preprocess_shader, Preprocessed},
+        shaderlocator::ShaderLocator,
+    },
+};
+
+struct D3D11Buffer {
+    buffer: ID3D11Buffer,
+    size: usize,
+    dynamic: bool,
+}
+
+enum D3D11Shader {
+    /// The bytecode is kept for the input layouts that are created for the shader
+    Vertex(ID3D11VertexShader, ID3DBlob),
+    Pixel(ID3D11PixelShader),
+}
+
+enum D3D11State {
+    DepthStencil(ID3D11DepthStencilState),
+    Rasterizer(ID3D11RasterizerState),
+}
+
+/// The [`RenderBackend`] that draws with D3D11 into the swap chain of a window
+pub struct D3D11Backend {
+    pub device: ID3D11Device,
+    pub context: ID3D11DeviceContext,
+    pub swap_chain: IDXGISwapChain1,
+    pub target: ID3D11RenderTargetView,
+    pub depth_stencil_view: ID3D11DepthStencilView,
+    _dxgi_factory: IDXGIFactory4,
+    width: u32,
+    height: u32,
+    next_id: u32,
+    buffers: HashMap<u32, D3D11Buffer>,
+    shaders: HashMap<u32, D3D11Shader>,
+    layouts: HashMap<u32, ID3D11InputLayout>,
+    states: HashMap<u32, D3D11State>,
+    /// Where the includes of the shaders are read from, the same place the shaders were found in
+    shader_files: ShaderLocator,
+}
+
+impl D3D11Backend {
+    /// Create a device with the debug layer and a swap chain with a depth buffer for the window. The
+    /// includes of shaders are read through `shader_files`.
+    pub fn new(
+        hwnd: HWND,
+        width: u32,
+        height: u32,
+        shader_files: ShaderLocator,
+    ) -> Result<D3D11Backend, Error> {
+        let (dxgi_factory, device) = D3D11Backend::create_device()?;
+
+        let swap_chain_desc = DXGI_SWAP_CHAIN_DESC1 {
+            Width: width,
+            Height: height,
+            Format: DXGI_FORMAT_R8G8B8A8_UNORM,
+            SampleDesc: DXGI_SAMPLE_DESC {
+                Count: 1,
+                Quality: 0,
+            },
+            BufferUsage: DXGI_USAGE_RENDER_TARGET_OUTPUT,
+            BufferCount: 2,
+            SwapEffect: DXGI_SWAP_EFFECT_FLIP_DISCARD,
+            Stereo: windows::Win32::Foundation::BOOL(0),
+            AlphaMode: DXGI_ALPHA_MODE_UNSPECIFIED,
+            Flags: 0,
+            Scaling: DXGI_SCALING_STRETCH,
+        };
+
+        let swap_chain: IDXGISwapChain1 = unsafe {
+            dxgi_factory
+                .CreateSwapChainForHwnd(&device, hwnd, &swap_chain_desc, None, None)
+                .context("creating swap chain")?
+        };
+
+        let context =
+            unsafe { device.GetImmediateContext() }.context("getting immediate context")?;
+
+        let back_buffer: ID3D11Resource =
+            unsafe { swap_chain.GetBuffer(0).context("getting back buffer")? };
+
+        let mut target: Option<ID3D11RenderTargetView> = None;
+        unsafe {
+            device
+                .CreateRenderTargetView(&back_buffer, None, Some(&mut target))
+                .context("creating render target view")?
+        };
+
+        let mut depth_stencil: Option<ID3D11Texture2D> = None;
+        let desc_depth: D3D11_TEXTURE2D_DESC = D3D11_TEXTURE2D_DESC {
+            Width: width,
+            Height: height,
+            MipLevels: 1,
+            ArraySize: 1,
+            Format: DXGI_FORMAT_D32_FLOAT,
+            SampleDesc: DXGI_SAMPLE_DESC {
+                Count: 1,
+                Quality: 0,
+            },
+            Usage: D3D11_USAGE_DEFAULT,
+            BindFlags: D3D11_BIND_DEPTH_STENCIL,
+            CPUAccessFlags: D3D11_CPU_ACCESS_FLAG::default(),
+            MiscFlags: D3D11_RESOURCE_MISC_FLAG::default(),
+        };
+
+        unsafe {
+            device
+                .CreateTexture2D(&desc_depth, None, Some(&mut depth_stencil))
+                .context("creating depth buffer")?;
+        };
+
+        let mut depth_stencil_view: Option<ID3D11DepthStencilView> = None;
+        let depth_stencil_view_desc: D3D11_DEPTH_STENCIL_VIEW_DESC =
+            D3D11_DEPTH_STENCIL_VIEW_DESC {
+                Format: DXGI_FORMAT_D32_FLOAT,
+                ViewDimension: D3D11_DSV_DIMENSION_TEXTURE2D,
+                Flags: 0,
+                Anonymous: D3D11_DEPTH_STENCIL_VIEW_DESC_0 {
+                    Texture2D: D3D11_TEX2D_DSV { MipSlice: 0 },
+                },
+            };
+
+        unsafe {
+            device
+                .CreateDepthStencilView(
+                    &depth_stencil.unwrap(),
+                    Some(&depth_stencil_view_desc),
+                    Some(&mut depth_stencil_view),
+                )
+                .context("creating depth stencil view")?;
+        };
+
+        let mut backend: D3D11Backend = D3D11Backend {
+            device,
+            context,
+            swap_chain,
+            target: target.unwrap(),
+            depth_stencil_view: depth_stencil_view.unwrap(),
+            _dxgi_factory: dxgi_factory,
+            width,
+            height,
+            next_id: 0,
+            buffers: HashMap::new(),
+            shaders: HashMap::new(),
+            layouts: HashMap::new(),
+            states: HashMap::new(),
+            shader_files,
+        };
+
+        // Depth test with LESS, which is also what the software backend starts with
+        let ds_state: StateId = backend.create_depth_stencil_state(&DepthStencilDesc::default())?;
+        backend.set_depth_stencil_state(ds_state)?;
+
+        return Ok(backend);
+    }
+
+    fn create_device() -> Result<(IDXGIFactory4, ID3D11Device), Error> {
+        let dxgi_factory: IDXGIFactory4 =
+            unsafe { CreateDXGIFactory2(0) }.context("creating DXGI factory")?;
+
+        let mut device: Option<ID3D11Device> = None;
+        unsafe {
+            D3D11CreateDevice(
+                None,
+                D3D_DRIVER_TYPE_HARDWARE,
+                None,
+                D3D11_CREATE_DEVICE_DEBUG,
+                None,
+                D3D11_SDK_VERSION,
+                Some(&mut device),
+                None,
+                None,
+            )
+        }
+        .context("creating D3D11 device")?;
+
+        return Ok((dxgi_factory, device.unwrap()));
+    }
+
+    /// Name and details of the GPU the device runs on
+    pub fn adapter_description(&self) -> String {
+        let mut desc: DXGI_ADAPTER_DESC = DXGI_ADAPTER_DESC::default();
+        let desc: windows::core::Result<DXGI_ADAPTER_DESC> = unsafe {
+            self.device
+                .cast::<IDXGIDevice>()
+                .and_then(|device| device.GetAdapter())
+                .and_then(|adapter| adapter.GetDesc(&mut desc))
+                .map(|_| desc)
+        };
+
+        return match desc {
+            Ok(desc) => format!(
+                "{} (vendor 0x{:04X}, device 0x{:04X}, {} MB video memory)",
+                String::from_utf16_lossy(&desc.Description).trim_end_matches('\0'),
+                desc.VendorId,
+                desc.DeviceId,
+                desc.DedicatedVideoMemory / (1024 * 1024)
+            ),
+            Err(e) => format!("unknown ({})", WindowsError::from(e)),
+        };
+    }
+
+    /// Compile the HLSL file of `desc` and create the shader. `#include` is resolved from the folder of
+    /// the file that includes, in the files of the [`ShaderLocator`] of the backend.
+    fn compile_shader(&self, desc: &ShaderDesc) -> Result<D3D11Shader, Error> {
+        let compile_flags = if cfg!(debug_assertions) {
+            D3DCOMPILE_DEBUG | D3DCOMPILE_SKIP_OPTIMIZATION
+        } else {
+            0
+        };
+
+        let entry_point: CString = CString::new(desc.entry_point.as_str()).unwrap_or_default();
+        let profile: CString = CString::new(desc.profile.as_str()).unwrap_or_default();
+        let mut blob: Option<ID3DBlob> = None;
+        let mut errors: Option<ID3DBlob> = None;
+
+        let compiled: windows::core::Result<()> = match &desc.code {
+            // The includes and defines are handled by the preprocessor of the crate, its `#line`
+            // directives make the compiler report the original files
+            ShaderCode::File | ShaderCode::Source(_) => {
+                let preprocessed: Preprocessed = preprocess_shader(desc, &self.shader_files)
+                    .map_err(|e| {
+                        Error::shader_compile(
+                            &desc.path.display().to_string(),
+                            E_FAIL,
+                            &e.to_string(),
+                        )
+                    })?;
+                let name: CString =
+                    CString::new(desc.path.to_string_lossy().as_ref()).unwrap_or_default();
+                unsafe {
+                    D3DCompile(
+                        preprocessed.code.as_ptr().cast(),
+                        preprocessed.code.len(),
+                        PCSTR::from_raw(name.as_ptr().cast()),
+                        None,
+                        None,
+                        PCSTR::from_raw(entry_point.as_ptr().cast()),
+                        PCSTR::from_raw(profile.as_ptr().cast()),
+                        compile_flags,
+                        0,
+                        &mut blob,
+                        Some(&mut errors),
+                    )
+                }
+            }
+            ShaderCode::Bytecode(bytecode) => {
+                unsafe { D3DCreateBlob(bytecode.len()) }.map(|created| {
+                    unsafe {
+                        std::ptr::copy_nonoverlapping(
+                            bytecode.as_ptr(),
+                            created.GetBufferPointer().cast(),
+                            bytecode.len(),
+                        )
+                    };
+                    blob = Some(created);
+                })
+            }
+        };
+        compiled.map_err(|e| {
+            Error::shader_compile(
+                &desc.path.display().to_string(),
+                e.code(),
+                &blob_to_string(&errors),
+            )
+        })?;
+        let blob: ID3DBlob = blob.unwrap();
+
+        return Ok(match desc.stage {
+            ShaderStage::Vertex => {
+                let mut shader: Option<ID3D11VertexShader> = None;
+                unsafe {
+                    self.device
+                        .CreateVertexShader(blob_bytes(&blob), None, Some(&mut shader))
+                }
+                .context("creating vertex shader")?;
+                D3D11Shader::Vertex(shader.unwrap(), blob)
+            }
+            ShaderStage::Pixel => {
+                let mut shader: Option<ID3D11PixelShader> = None;
+                unsafe {
+                    self.device
+                        .CreatePixelShader(blob_bytes(&blob), None, Some(&mut shader))
+                }
+                .context("creating pixel shader")?;
+                D3D11Shader::Pixel(shader.unwrap())
+            }
+        });
+    }
+
+    fn next_id(&mut self) -> u32 {
+        self.next_id += 1;
+        return self.next_id;
+    }
+
+    #[track_caller]
+    fn buffer(&self, buffer: BufferId) -> Result<&D3D11Buffer, Error> {
+        return self
+            .buffers
+            .get(&buffer.0)
+            .ok_or_else(|| unknown_resource(Resource::Buffer(buffer)));
+    }
+}
+
+/// Error for an id that doesn't belong to a live resource of the backend
+#[track_caller]
+pub fn unknown_resource(resource: Resource) -> Error {
+    return Error::hresult_code(
+        windows::Win32::Foundation::E_INVALIDARG,
+        &format!("{:?} doesn't exist", resource),
+    );
+}
+
+/// The D3D11 description of a buffer. Building it doesn't need a device.
+pub fn buffer_desc(desc: &BufferDesc) -> D3D11_BUFFER_DESC {
+    let bind_flags: D3D11_BIND_FLAG = match desc.kind {
+        BufferKind::Vertex => D3D11_BIND_VERTEX_BUFFER,
+        BufferKind::Index => D3D11_BIND_INDEX_BUFFER,
+        BufferKind::Constant => D3D11_BIND_CONSTANT_BUFFER,
+    };
+
+    return D3D11_BUFFER_DESC {
+        ByteWidth: desc.data.len() as u32,
+        Usage: if desc.dynamic {
+            D3D11_USAGE_DYNAMIC
+        } else {
+            D3D11_USAGE_DEFAULT
+        },
+        BindFlags: bind_flags,
+        CPUAccessFlags: if desc.dynamic {
+            D3D11_CPU_ACCESS_WRITE
+        } else {
+            D3D11_CPU_ACCESS_FLAG::default()
+        },
+        MiscFlags: D3D11_RESOURCE_MISC_FLAG::default(),
+        StructureByteStride: 0,
+    };
+}
+
+/// The D3D11 description of the elements of an input layout. `semantics` are the semantic names of the
+/// elements as C strings, they have to outlive the description.
+pub fn input_element_descs(
+    elements: &[InputElement],
+    semantics: &[CString],
+) -> Vec<D3D11_INPUT_ELEMENT_DESC> {
+    return elements
+        .iter()
+        .zip(semantics.iter())
+        .map(|(element, semantic)| D3D11_INPUT_ELEMENT_DESC {
+            SemanticName: PCSTR::from_raw(semantic.as_ptr().cast()),
+            SemanticIndex: element.semantic_index,
+            Format: dxgi_format(element.format),
+            InputSlot: 0,
+            AlignedByteOffset: element.offset,
+            InputSlotClass: D3D11_INPUT_PER_VERTEX_DATA,
+            InstanceDataStepRate: 0,
+        })
+        .collect();
+}
+
+pub fn depth_stencil_desc(desc: &DepthStencilDesc) -> D3D11_DEPTH_STENCIL_DESC {
+    return D3D11_DEPTH_STENCIL_DESC {
+        DepthEnable: if desc.depth_enable { TRUE } else { FALSE },
+        DepthWriteMask: if desc.depth_write {
+            D3D11_DEPTH_WRITE_MASK_ALL
+        } else {
+            D3D11_DEPTH_WRITE_MASK_ZERO
+        },
+        DepthFunc: comparison_func(desc.depth_func),
+        StencilEnable: FALSE,
+        StencilReadMask: 0,
+        StencilWriteMask: 0,
+        FrontFace: D3D11_DEPTH_STENCILOP_DESC::default(),
+        BackFace: D3D11_DEPTH_STENCILOP_DESC::default(),
+    };
+}
+
+pub fn rasterizer_desc(desc: &RasterizerDesc) -> D3D11_RASTERIZER_DESC {
+    return D3D11_RASTERIZER_DESC {
+        FillMode: D3D11_FILL_SOLID,
+        CullMode: match desc.cull_mode {
+            CullMode::None => D3D11_CULL_NONE,
+            CullMode::Front => D3D11_CULL_FRONT,
+            CullMode::Back => D3D11_CULL_BACK,
+        },
+        FrontCounterClockwise: if desc.front_counter_clockwise {
+            TRUE
+        } else {
+            FALSE
+        },
+        DepthBias: 0,
+        DepthBiasClamp: 0.0,
+        SlopeScaledDepthBias: 0.0,
+        DepthClipEnable: TRUE,
+        ScissorEnable: FALSE,
+        MultisampleEnable: FALSE,
+        AntialiasedLineEnable: FALSE,
+    };
+}
+
+pub fn dxgi_format(format: VertexFormat) -> DXGI_FORMAT {
+    return match format {
+        VertexFormat::Float => DXGI_FORMAT_R32_FLOAT,
+        VertexFormat::Float2 => DXGI_FORMAT_R32G32_FLOAT,
+        VertexFormat::Float3 => DXGI_FORMAT_R32G32B32_FLOAT,
+        VertexFormat::Float4 => DXGI_FORMAT_R32G32B32A32_FLOAT,
+        VertexFormat::UByte4 => DXGI_FORMAT_R8G8B8A8_UINT,
+        VertexFormat::UByte4Norm => DXGI_FORMAT_R8G8B8A8_UNORM,
+    };
+}
+
+fn comparison_func(comparison: Comparison) -> D3D11_COMPARISON_FUNC {
+    return match comparison {
+        Comparison::Never => D3D11_COMPARISON_NEVER,
+        Comparison::Less => D3D11_COMPARISON_LESS,
+        Comparison::Equal => D3D11_COMPARISON_EQUAL,
+        Comparison::LessEqual => D3D11_COMPARISON_LESS_EQUAL,
+        Comparison::Greater => D3D11_COMPARISON_GREATER,
+        Comparison::NotEqual => D3D11_COMPARISON_NOT_EQUAL,
+        Comparison::GreaterEqual => D3D11_COMPARISON_GREATER_EQUAL,
+        Comparison::Always => D3D11_COMPARISON_ALWAYS,
+    };
+}
+
+/// Read the text out of a blob, like the error messages of the shader compiler
+pub fn blob_to_string(blob: &Option<ID3DBlob>) -> String {
+    return match blob {
+        Some(blob) => {
+            let bytes: &[u8] =
+                unsafe { from_raw_parts(blob.GetBufferPointer().cast(), blob.GetBufferSize()) };
+            String::from_utf8_lossy(bytes)
+                .trim_end_matches('\0')
+                .to_string()
+        }
+        None => String::new(),
+    };
+}
+
+fn blob_bytes(blob: &ID3DBlob) -> &[u8] {
+    return unsafe { from_raw_parts(blob.GetBufferPointer().cast(), blob.GetBufferSize()) };
+}
+
+impl RenderBackend for D3D11Backend {
+    fn width(&self) -> u32 {
+        return self.width;
+    }
+
+    fn height(&self) -> u32 {
+        return self.height;
+    }
+
+    fn create_buffer(&mut self, desc: &BufferDesc) -> Result<BufferId, Error> {
+        let buff_desc: D3D11_BUFFER_DESC = buffer_desc(desc);
+
+        let data: D3D11_SUBRESOURCE_DATA = D3D11_SUBRESOURCE_DATA {
+            pSysMem: desc.data.as_ptr() as *const _,
+            SysMemPitch: 0,
+            SysMemSlicePitch: 0,
+        };
+
+        let mut buffer: Option<ID3D11Buffer> = None;
+        unsafe {
+            self.device
+                .CreateBuffer(&buff_desc, Some(&data), Some(&mut buffer))
+        }
+        .with_context(|| format!("creating {:?} buffer", desc.kind))?;
+
+        let id: u32 = self.next_id();
+        self.buffers.insert(
+            id,
+            D3D11Buffer {
+                buffer: buffer.unwrap(),
+                size: desc.data.len(),
+                dynamic: desc.dynamic,
+            },
+        );
+        return Ok(BufferId(id));
+    }
+
+    fn update_buffer(&mut self, buffer: BufferId, data: &[u8]) -> Result<(), Error> {
+        let target: &D3D11Buffer = self.buffer(buffer)?;
+        if data.len() != target.size {
+            return Err(Error::hresult_code(
+                windows::Win32::Foundation::E_INVALIDARG,
+                &format!(
+                    "Updating a buffer of {} bytes with {} bytes",
+                    target.size,
+                    data.len()
+                ),
+            ));
+        }
+
+        if target.dynamic {
+            let mut mapped: D3D11_MAPPED_SUBRESOURCE = D3D11_MAPPED_SUBRESOURCE::default();
+            unsafe {
+                self.context
+                    .Map(
+                        &target.buffer,
+                        0,
+                        D3D11_MAP_WRITE_DISCARD,
+                        0,
+                        Some(&mut mapped),
+                    )
+                    .context("mapping a buffer")?;
+                std::ptr::copy_nonoverlapping(data.as_ptr(), mapped.pData.cast(), data.len());
+                self.context.Unmap(&target.buffer, 0);
+            };
+        } else {
+            unsafe {
+                self.context
+                    .UpdateSubresource(&target.buffer, 0, None, data.as_ptr().cast(), 0, 0)
+            };
+        }
+        return Ok(());
+    }
+
+    fn create_shader(&mut self, desc: &ShaderDesc) -> Result<ShaderId, Error> {
+        let shader: D3D11Shader = self.compile_shader(desc)?;
+        let id: u32 = self.next_id();
+        self.shaders.insert(id, shader);
+        return Ok(ShaderId(id));
+    }
+
+    fn replace_shader(&mut self, shader: ShaderId, desc: &ShaderDesc) -> Result<(), Error> {
+        let stage: ShaderStage = match self.shaders.get(&shader.0) {
+            Some(D3D11Shader::Vertex(..)) => ShaderStage::Vertex,
+            Some(D3D11Shader::Pixel(_)) => ShaderStage::Pixel,
+            None => return Err(unknown_resource(Resource::Shader(shader))),
+        };
+        if stage != desc.stage {
+            return Err(Error::hresult_code(
+                windows::Win32::Foundation::E_INVALIDARG,
+                &format!(
+                    "Replacing a {:?} shader with a {:?} shader",
+                    stage, desc.stage
+                ),
+            ));
+        }
+
+        let compiled: D3D11Shader = self.compile_shader(desc)?;
+        self.shaders.insert(shader.0, compiled);
+        return Ok(());
+    }
+
+    fn create_input_layout(
+        &mut self,
+        elements: &[InputElement],
+        vertex_shader: ShaderId,
+    ) -> Result<LayoutId, Error> {
+        let blob: &ID3DBlob = match self.shaders.get(&vertex_shader.0) {
+            Some(D3D11Shader::Vertex(_, blob)) => blob,
+            _ => return Err(unknown_resource(Resource::Shader(vertex_shader))),
+        };
+
+        // The names have to live until the layout is created
+        let semantics: Vec<CString> = elements
+            .iter()
+            .map(|element| CString::new(element.semantic.as_str()).unwrap_or_default())
+            .collect();
+        let input_element_desc: Vec<D3D11_INPUT_ELEMENT_DESC> =
+            input_element_descs(elements, &semantics);
+
+        let mut layout: Option<ID3D11InputLayout> = None;
+        unsafe {
+            self.device
+                .CreateInputLayout(&input_element_desc, blob_bytes(blob), Some(&mut layout))
+        }
+        .context("creating input layout")?;
+
+        let id: u32 = self.next_id();
+        self.layouts.insert(id, layout.unwrap());
+        return Ok(LayoutId(id));
+    }
+
+    fn create_depth_stencil_state(&mut self, desc: &DepthStencilDesc) -> Result<StateId, Error> {
+        let ds_desc: D3D11_DEPTH_STENCIL_DESC = depth_stencil_desc(desc);
+
+        let mut state: Option<ID3D11DepthStencilState> = None;
+        unsafe {
+            self.device
+                .CreateDepthStencilState(&ds_desc, Some(&mut state))
+        }
+        .context("creating depth stencil state")?;
+
+        let id: u32 = self.next_id();
+        self.states
+            .insert(id, D3D11State::DepthStencil(state.unwrap()));
+        return Ok(StateId(id));
+    }
+
+    fn create_rasterizer_state(&mut self, desc: &RasterizerDesc) -> Result<StateId, Error> {
+        let rs_desc: D3D11_RASTERIZER_DESC = rasterizer_desc(desc);
+
+        let mut state: Option<ID3D11RasterizerState> = None;
+        unsafe {
+            self.device
+                .CreateRasterizerState(&rs_desc, Some(&mut state))
+        }
+        .context("creating rasterizer state")?;
+
+        let id: u32 = self.next_id();
+        self.states
+            .insert(id, D3D11State::Rasterizer(state.unwrap()));
+        return Ok(StateId(id));
+    }
+
+    fn release(&mut self, resource: Resource) {
+        // Dropping the interfaces releases them, the context keeps what is still bound alive
+        match resource {
+            Resource::Buffer(id) => drop(self.buffers.remove(&id.0)),
+            Resource::Shader(id) => drop(self.shaders.remove(&id.0)),
+            Resource::Layout(id) => drop(self.layouts.remove(&id.0)),
+            Resource::State(id) => drop(self.states.remove(&id.0)),
+        }
+    }
+
+    fn set_vertex_buffer(
+        &mut self,
+        slot: u32,
+        buffer: BufferId,
+        stride: u32,
+        offset: u32,
+    ) -> Result<(), Error> {
+        let buffer: Option<ID3D11Buffer> = Some(self.buffer(buffer)?.buffer.clone());
+        unsafe {
+            self.context
+                .IASetVertexBuffers(slot, 1, Some(&buffer), Some(&stride), Some(&offset))
+        };
+        return Ok(());
+    }
+
+    fn set_index_buffer(
+        &mut self,
+        buffer: BufferId,
+        format: IndexFormat,
+        offset: u32,
+    ) -> Result<(), Error> {
+        let format: DXGI_FORMAT = match format {
+            IndexFormat::U16 => DXGI_FORMAT_R16_UINT,
+            IndexFormat::U32 => DXGI_FORMAT_R32_UINT,
+        };
+        unsafe {
+            self.context
+                .IASetIndexBuffer(&self.buffer(buffer)?.buffer, format, offset)
+        };
+        return Ok(());
+    }
+
+    fn set_constant_buffer(
+        &mut self,
+        stage: ShaderStage,
+        slot: u32,
+        buffer: BufferId,
+    ) -> Result<(), Error> {
+        let buffers: [Option<ID3D11Buffer>; 1] = [Some(self.buffer(buffer)?.buffer.clone())];
+        match stage {
+            ShaderStage::Vertex => unsafe {
+                self.context.VSSetConstantBuffers(slot, Some(&buffers))
+            },
+            ShaderStage::Pixel => unsafe {
+                self.context.PSSetConstantBuffers(slot, Some(&buffers))
+            },
+        }
+        return Ok(());
+    }
+
+    fn set_shader(&mut self, shader: ShaderId) -> Result<(), Error> {
+        match self.shaders.get(&shader.0) {
+            Some(D3D11Shader::Vertex(vertex_shader, _)) => unsafe {
+                self.context.VSSetShader(vertex_shader, None)
+            },
+            Some(D3D11Shader::Pixel(pixel_shader)) => unsafe {
+                self.context.PSSetShader(pixel_shader, None)
+            },
+            None => return Err(unknown_resource(Resource::Shader(shader))),
+        }
+        return Ok(());
+    }
+
+    fn set_input_layout(&mut self, layout: LayoutId) -> Result<(), Error> {
+        let input_layout: &ID3D11InputLayout = self
+            .layouts
+            .get(&layout.0)
+            .ok_or_else(|| unknown_resource(Resource::Layout(layout)))?;
+        unsafe { self.context.IASetInputLayout(input_layout) };
+        return Ok(());
+    }
+
+    fn set_topology(&mut self, topology: Topology) {
+        let topology = match topology {
+            Topology::TriangleList => D3D_PRIMITIVE_TOPOLOGY_TRIANGLELIST,
+            Topology::TriangleStrip => D3D_PRIMITIVE_TOPOLOGY_TRIANGLESTRIP,
+        };
+        unsafe { self.context.IASetPrimitiveTopology(topology) };
+    }
+
+    fn set_viewport(&mut self, viewport: &Viewport) {
+        let view_port: D3D11_VIEWPORT = D3D11_VIEWPORT {
+            TopLeftX: viewport.top_left_x,
+            TopLeftY: viewport.top_left_y,
+            Width: viewport.width,
+            Height: viewport.height,
+            MinDepth: viewport.min_depth,
+            MaxDepth: viewport.max_depth,
+        };
+        unsafe { self.context.RSSetViewports(Some(&[view_port])) };
+    }
+
+    fn set_depth_stencil_state(&mut self, state: StateId) -> Result<(), Error> {
+        match self.states.get(&state.0) {
+            Some(D3D11State::DepthStencil(ds_state)) => unsafe {
+                self.context.OMSetDepthStencilState(ds_state, 1)
+            },
+            _ => return Err(unknown_resource(Resource::State(state))),
+        }
+        return Ok(());
+    }
+
+    fn set_rasterizer_state(&mut self, state: StateId) -> Result<(), Error> {
+        match self.states.get(&state.0) {
+            Some(D3D11State::Rasterizer(rs_state)) => unsafe { self.context.RSSetState(rs_state) },
+            _ => return Err(unknown_resource(Resource::State(state))),
+        }
+        return Ok(());
+    }
+
+    fn clear(&mut self, rgba: [f32; 4]) {
+        unsafe {
+            self.context.ClearRenderTargetView(&self.target, &rgba[0]);
+            self.context.ClearDepthStencilView(
+                &self.depth_stencil_view,
+                D3D11_CLEAR_DEPTH.0,
+                1.0,
+                0,
+            );
+        };
+    }
+
+    fn draw_indexed(
+        &mut self,
+        index_count: u32,
+        start_index: u32,
+        base_vertex: i32,
+    ) -> Result<(), Error> {
+        // The flip model unbinds the render target when presenting, so it's bound before every draw
+        let rendertarget: [Option<ID3D11RenderTargetView>; 1] = [Some(self.target.clone())];
+        unsafe {
+            self.context
+                .OMSetRenderTargets(Some(&rendertarget), &self.depth_stencil_view);
+            self.context
+                .DrawIndexed(index_count, start_index, base_vertex);
+        };
+        return Ok(());
+    }
+
+    fn present(&mut self) -> Result<(), Error> {
+        let h_result = unsafe { self.swap_chain.Present(1, 0) };
+        if h_result == DXGI_ERROR_DEVICE_REMOVED {
+            let reason = unsafe { self.device.GetDeviceRemovedReason() }
+                .err()
+                .map(|e| e.code())
+                .unwrap_or(h_result);
+            return Err(Error::device_removed(reason));
+        } else if h_result != S_OK {
+            return Err(Error::hresult_code(
+                h_result,
+                "Presenting scene to swapchain failed",
+            ));
+        }
+        return Ok(());
+    }
+}
@@ -0,0 +1,228 @@
+use super::{
+    BufferDesc, BufferId, BufferKind, DepthStencilDesc, IndexFormat, InputElement, LayoutId,
+    RasterizerDesc, RenderBackend, Resource, ShaderDesc, ShaderId, ShaderStage, StateId, Topology,
+    Viewport,
+};
+use crate::window::errors::Error;
+
+/// A call to a [`RecordingBackend`], with the arguments that matter to the tests
+#[derive(Debug, Clone, PartialEq)]
+pub enum Call {
+    CreateBuffer {
+        id: BufferId,
+        kind: BufferKind,
+        size: usize,
+        dynamic: bool,
+    },
+    UpdateBuffer(BufferId, Vec<u8>),
+    CreateShader(ShaderId, ShaderStage),
+    ReplaceShader(ShaderId),
+    CreateInputLayout(LayoutId, Vec<InputElement>, ShaderId),
+    CreateDepthStencilState(StateId),
+    CreateRasterizerState(StateId),
+    Release(Resource),
+    SetVertexBuffer {
+        slot: u32,
+        buffer: BufferId,
+        stride: u32,
+        offset: u32,
+    },
+    SetIndexBuffer(BufferId, IndexFormat),
+    SetConstantBuffer(ShaderStage, u32, BufferId),
+    SetShader(ShaderId),
+    SetInputLayout(LayoutId),
+    SetTopology(Topology),
+    SetViewport(Viewport),
+    SetDepthStencilState(StateId),
+    SetRasterizerState(StateId),
+    Clear,
+    DrawIndexed {
+        index_count: u32,
+        start_index: u32,
+        base_vertex: i32,
+    },
+    Present,
+}
+
+/// A [`RenderBackend`] for tests that doesn't draw anything, it only writes down what it was asked to
+/// do in [`RecordingBackend::calls`]. Every resource gets a new id and every call succeeds.
+#[derive(Debug, Default)]
+pub struct RecordingBackend {
+    pub calls: Vec<Call>,
+    next_id: u32,
+}
+
+impl RecordingBackend {
+    pub fn new() -> RecordingBackend {
+        return RecordingBackend::default();
+    }
+
+    /// The calls that create a buffer
+    pub fn created_buffers(&self) -> Vec<&Call> {
+        return self
+            .calls
+            .iter()
+            .filter(|call| matches!(call, Call::CreateBuffer { .. }))
+            .collect();
+    }
+
+    fn next_id(&mut self) -> u32 {
+        self.next_id += 1;
+        return self.next_id;
+    }
+}
+
+impl RenderBackend for RecordingBackend {
+    fn width(&self) -> u32 {
+        return 800;
+    }
+
+    fn height(&self) -> u32 {
+        return 600;
+    }
+
+    fn create_buffer(&mut self, desc: &BufferDesc) -> Result<BufferId, Error> {
+        let id: BufferId = BufferId(self.next_id());
+        self.calls.push(Call::CreateBuffer {
+            id,
+            kind: desc.kind,
+            size: desc.data.len(),
+            dynamic: desc.dynamic,
+        });
+        return Ok(id);
+    }
+
+    fn update_buffer(&mut self, buffer: BufferId, data: &[u8]) -> Result<(), Error> {
+        self.calls.push(Call::UpdateBuffer(buffer, data.to_vec()));
+        return Ok(());
+    }
+
+    fn create_shader(&mut self, desc: &ShaderDesc) -> Result<ShaderId, Error> {
+        let id: ShaderId = ShaderId(self.next_id());
+        self.calls.push(Call::CreateShader(id, desc.stage));
+        return Ok(id);
+    }
+
+    fn replace_shader(&mut self, shader: ShaderId, _desc: &ShaderDesc) -> Result<(), Error> {
+        self.calls.push(Call::ReplaceShader(shader));
+        return Ok(());
+    }
+
+    fn create_input_layout(
+        &mut self,
+        elements: &[InputElement],
+        vertex_shader: ShaderId,
+    ) -> Result<LayoutId, Error> {
+        let id: LayoutId = LayoutId(self.next_id());
+        self.calls.push(Call::CreateInputLayout(
+            id,
+            elements.to_vec(),
+            vertex_shader,
+        ));
+        return Ok(id);
+    }
+
+    fn create_depth_stencil_state(&mut self, _desc: &DepthStencilDesc) -> Result<StateId, Error> {
+        let id: StateId = StateId(self.next_id());
+        self.calls.push(Call::CreateDepthStencilState(id));
+        return Ok(id);
+    }
+
+    fn create_rasterizer_state(&mut self, _desc: &RasterizerDesc) -> Result<StateId, Error> {
+        let id: StateId = StateId(self.next_id());
+        self.calls.push(Call::CreateRasterizerState(id));
+        return Ok(id);
+    }
+
+    fn release(&mut self, resource: Resource) {
+        self.calls.push(Call::Release(resource));
+    }
+
+    fn set_vertex_buffer(
+        &mut self,
+        slot: u32,
+        buffer: BufferId,
+        stride: u32,
+        offset: u32,
+    ) -> Result<(), Error> {
+        self.calls.push(Call::SetVertexBuffer {
+            slot,
+            buffer,
+            stride,
+            offset,
+        });
+        return Ok(());
+    }
+
+    fn set_index_buffer(
+        &mut self,
+        buffer: BufferId,
+        format: IndexFormat,
+        _offset: u32,
+    ) -> Result<(), Error> {
+        self.calls.push(Call::SetIndexBuffer(buffer, format));
+        return Ok(());
+    }
+
+    fn set_constant_buffer(
+        &mut self,
+        stage: ShaderStage,
+        slot: u32,
+        buffer: BufferId,
+    ) -> Result<(), Error> {
+        self.calls
+            .push(Call::SetConstantBuffer(stage, slot, buffer));
+        return Ok(());
+    }
+
+    fn set_shader(&mut self, shader: ShaderId) -> Result<(), Error> {
+        self.calls.push(Call::SetShader(shader));
+        return Ok(());
+    }
+
+    fn set_input_layout(&mut self, layout: LayoutId) -> Result<(), Error> {
+        self.calls.push(Call::SetInputLayout(layout));
+        return Ok(());
+    }
+
+    fn set_topology(&mut self, topology: Topology) {
+        self.calls.push(Call::SetTopology(topology));
+    }
+
+    fn set_viewport(&mut self, viewport: &Viewport) {
+        self.calls.push(Call::SetViewport(*viewport));
+    }
+
+    fn set_depth_stencil_state(&mut self, state: StateId) -> Result<(), Error> {
+        self.calls.push(Call::SetDepthStencilState(state));
+        return Ok(());
+    }
+
+    fn set_rasterizer_state(&mut self, state: StateId) -> Result<(), Error> {
+        self.calls.push(Call::SetRasterizerState(state));
+        return Ok(());
+    }
+
+    fn clear(&mut self, _rgba: [f32; 4]) {
+        self.calls.push(Call::Clear);
+    }
+
+    fn draw_indexed(
+        &mut self,
+        index_count: u32,
+        start_index: u32,
+        base_vertex: i32,
+    ) -> Result<(), Error> {
+        self.calls.push(Call::DrawIndexed {
+            index_count,
+            start_index,
+            base_vertex,
+        });
+        return Ok(());
+    }
+
+    fn present(&mut self) -> Result<(), Error> {
+        self.calls.push(Call::Present);
+        return Ok(());
+    }
+}
@@ -0,0 +1,1264 @@
+use std::{collections::HashMap, rc::Rc};
+
+use windows::Win32::Foundation::{E_INVALIDARG, E_NOTIMPL};
+
+use super::{
+    BufferDesc, BufferId, CullMode, DepthStencilDesc, IndexFormat, InputElement, LayoutId,
+    RasterizerDesc, RenderBackend, Resource, ShaderDesc, ShaderId, ShaderStage, StateId, Topology,
+    VertexFormat, Viewport,
+};
+use crate::window::{
+    errors::Error,
+    graphics::{bindable::cache::CacheStats, shaderlocator::ShaderLocator, Renderer, TestScene},
+};
+
+pub mod golden;
+
+/// The amount of constant buffer slots of every stage, like `D3D11_COMMONSHADER_CONSTANT_BUFFER_API_SLOT_COUNT`
+const CONSTANT_BUFFER_SLOTS: usize = 14;
+
+/// Vertex positions are snapped to 1/256th of a pixel before they are rasterized, like the 8 bits of
+/// sub-pixel precision of D3D11
+const SUBPIXEL_STEPS: f32 = 256.0;
+
+/// A vertex shader written in Rust
+pub type VertexShaderFn = dyn Fn(&VertexInput) -> VertexOutput;
+/// A pixel shader written in Rust. Returns the color, like `SV_Target`.
+pub type PixelShaderFn = dyn Fn(&PixelInput) -> [f32; 4];
+
+/// The Rust version of a HLSL shader, see [`SoftwareBackend::register_shader()`]
+#[derive(Clone)]
+pub enum SoftwareShader {
+    Vertex(Rc<VertexShaderFn>),
+    Pixel(Rc<PixelShaderFn>),
+}
+
+impl SoftwareShader {
+    pub fn stage(&self) -> ShaderStage {
+        return match self {
+            SoftwareShader::Vertex(_) => ShaderStage::Vertex,
+            SoftwareShader::Pixel(_) => ShaderStage::Pixel,
+        };
+    }
+}
+
+/// The constant buffers that are bound to the stage of a shader
+pub struct Constants<'a> {
+    buffers: &'a [Option<&'a [u8]>],
+}
+
+impl Constants<'_> {
+    /// The float at byte `offset` of the buffer in `slot`. Reading outside of a buffer returns 0, like on the GPU.
+    pub fn float(&self, slot: usize, offset: usize) -> f32 {
+        return self
+            .buffers
+            .get(slot)
+            .copied()
+            .flatten()
+            .and_then(|buffer| buffer.get(offset..offset + 4))
+            .map_or(0.0, |bytes| {
+                f32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])
+            });
+    }
+
+    /// The four floats at byte `offset` of the buffer in `slot`, like a `float4` in a cbuffer
+    pub fn float4(&self, slot: usize, offset: usize) -> [f32; 4] {
+        return [0, 1, 2, 3].map(|component| self.float(slot, offset + component * 4));
+    }
+}
+
+/// What a [`VertexShaderFn`] gets for a single vertex
+pub struct VertexInput<'a> {
+    /// The bytes of the vertex in the vertex buffer
+    pub vertex: &'a [u8],
+    pub layout: &'a [InputElement],
+    pub constants: Constants<'a>,
+}
+
+impl VertexInput<'_> {
+    /// An attribute of the vertex. Missing components are filled in with `(0, 0, 0, 1)`, the same as the
+    /// input assembler does, so a `float3` position has a w of 1.
+    pub fn attribute(&self, semantic: &str, semantic_index: u32) -> [f32; 4] {
+        let mut value: [f32; 4] = [0.0, 0.0, 0.0, 1.0];
+        let element: Option<&InputElement> = self.layout.iter().find(|element| {
+            element.semantic.eq_ignore_ascii_case(semantic)
+                && element.semantic_index == semantic_index
+        });
+
+        if let Some(element) = element {
+            let size: usize = element.format.component_size() as usize;
+            for (component, value) in value
+                .iter_mut()
+                .enumerate()
+                .take(element.format.components())
+            {
+                let offset: usize = element.offset as usize + component * size;
+                let Some(bytes) = self.vertex.get(offset..offset + size) else {
+                    continue;
+                };
+                *value = match element.format {
+                    VertexFormat::UByte4 => bytes[0] as f32,
+                    VertexFormat::UByte4Norm => bytes[0] as f32 / 255.0,
+                    _ => f32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]),
+                };
+            }
+        }
+        return value;
+    }
+}
+
+/// What a [`VertexShaderFn`] returns for a single vertex
+#[derive(Debug, Clone, PartialEq)]
+pub struct VertexOutput {
+    /// The clip space position, like `SV_Position`
+    pub position: [f32; 4],
+    /// Everything else the vertex shader outputs, like a color or texture coordinates. They are
+    /// interpolated over the triangle with perspective correction and passed to the pixel shader in
+    /// [`PixelInput::varyings`].
+    pub varyings: Vec<f32>,
+}
+
+impl VertexOutput {
+    /// A vertex shader output with only a position
+    pub fn position(position: [f32; 4]) -> VertexOutput {
+        return VertexOutput {
+            position,
+            varyings: vec![],
+        };
+    }
+}
+
+/// What a [`PixelShaderFn`] gets for a single pixel
+pub struct PixelInput<'a> {
+    /// Like `SV_Position`: the center of the pixel, the depth and the w of the clip space position
+    pub position: [f32; 4],
+    /// The [`VertexOutput::varyings`] of the vertices of the triangle, interpolated for this pixel
+    pub varyings: &'a [f32],
+    /// Like `SV_PrimitiveID`: the index of the triangle in the draw call
+    pub primitive_id: u32,
+    pub constants: Constants<'a>,
+}
+
+/// `VertexShader.hlsl`: transform the position with the matrix in constant buffer 0
+pub fn transform_vertex_shader(input: &VertexInput) -> VertexOutput {
+    let position: [f32; 4] = input.attribute("Position", 0);
+
+    // A `matrix` in a cbuffer is column major, so every column of the transform is a row in the buffer
+    let mut out: [f32; 4] = [0.0; 4];
+    for (column, value) in out.iter_mut().enumerate() {
+        let row: [f32; 4] = input.constants.float4(0, column * 16);
+        *value = position[0] * row[0]
+            + position[1] * row[1]
+            + position[2] * row[2]
+            + position[3] * row[3];
+    }
+    return VertexOutput::position(out);
+}
+
+/// `PixelShader.hlsl`: give every face of the cube the color in constant buffer 0, a face is two triangles
+pub fn face_color_pixel_shader(input: &PixelInput) -> [f32; 4] {
+    return input
+        .constants
+        .float4(0, (input.primitive_id / 2) as usize * 16);
+}
+
+/// The render target of the [`SoftwareBackend`], in the `R8G8B8A8_UNORM` format of the swap chain
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Framebuffer {
+    pub width: u32,
+    pub height: u32,
+    /// The pixels row by row, starting at the top left
+    pub pixels: Vec<[u8; 4]>,
+}
+
+impl Framebuffer {
+    pub fn new(width: u32, height: u32) -> Framebuffer {
+        return Framebuffer {
+            width,
+            height,
+            pixels: vec![[0; 4]; width as usize * height as usize],
+        };
+    }
+
+    pub fn pixel(&self, x: u32, y: u32) -> [u8; 4] {
+        return self.pixels[(y * self.width + x) as usize];
+    }
+}
+
+/// Convert a color to `R8G8B8A8_UNORM` the way the GPU does: clamp and round to the nearest value
+pub fn to_unorm(rgba: [f32; 4]) -> [u8; 4] {
+    return rgba.map(|channel| (channel.clamp(0.0, 1.0) * 255.0 + 0.5) as u8);
+}
+
+struct SoftwareBuffer {
+    data: Vec<u8>,
+}
+
+#[derive(Clone, Copy)]
+enum SoftwareState {
+    DepthStencil(DepthStencilDesc),
+    Rasterizer(RasterizerDesc),
+}
+
+#[derive(Clone, Copy)]
+struct VertexBinding {
+    buffer: BufferId,
+    stride: u32,
+    offset: u32,
+}
+
+#[derive(Clone, Copy)]
+struct IndexBinding {
+    buffer: BufferId,
+    format: IndexFormat,
+    offset: u32,
+}
+
+/// Clip a triangle against the near plane, `z >= 0` in clip space like D3D11. Returns the corners of
+/// the part in front of it, which are 3 or 4, or none when the whole triangle is behind it.
+fn clip_near(vertices: [&VertexOutput; 3]) -> Vec<VertexOutput> {
+    let mut clipped: Vec<VertexOutput> = Vec::with_capacity(4);
+    for i in 0..3 {
+        let (a, b): (&VertexOutput, &VertexOutput) = (vertices[i], vertices[(i + 1) % 3]);
+        let (distance_a, distance_b): (f32, f32) = (a.position[2], b.position[2]);
+        if distance_a >= 0.0 {
+            clipped.push(a.clone());
+        }
+        if (distance_a >= 0.0) != (distance_b >= 0.0) {
+            let t: f32 = distance_a / (distance_a - distance_b);
+            let lerp = |from: f32, to: f32| from + (to - from) * t;
+            clipped.push(VertexOutput {
+                position: std::array::from_fn(|j| lerp(a.position[j], b.position[j])),
+                varyings: a
+                    .varyings
+                    .iter()
+                    .zip(&b.varyings)
+                    .map(|(&from, &to)| lerp(from, to))
+                    .collect(),
+            });
+        }
+    }
+    return clipped;
+}
+
+/// A vertex after the vertex shader, in render target coordinates
+#[derive(Debug, Clone)]
+struct ScreenVertex {
+    x: f32,
+    y: f32,
+    z: f32,
+    /// The w of the clip space position
+    w: f32,
+    varyings: Vec<f32>,
+}
+
+impl ScreenVertex {
+    fn xy(&self) -> [f32; 2] {
+        return [self.x, self.y];
+    }
+}
+
+/// A [`RenderBackend`] that renders on the CPU into a [`Framebuffer`], so drawing code can run and be
+/// checked without a GPU. It follows the D3D11 rules for the parts it supports: indexed triangle lists
+/// and strips, perspective correct interpolation, the depth test, back face culling and the top-left
+/// fill rule. Every run renders the same pixels, so frames can be compared with reference images, see
+/// [`golden`]. Triangles are clipped against the near plane, the other planes are left to the viewport
+/// and the depth test.
+///
+/// HLSL can't run on the CPU, so every shader needs a Rust version that is registered under the file
+/// name of the HLSL file with [`SoftwareBackend::register_shader()`]. The shaders of the test cube are
+/// registered by default, the test scene finds them with the [`ShaderLocator`] that is passed to
+/// [`SoftwareBackend::new()`].
+pub struct SoftwareBackend {
+    pub framebuffer: Framebuffer,
+    pub depth: Vec<f32>,
+    /// Amount of frames that were presented
+    pub frames_presented: u64,
+    shader_sources: HashMap<String, SoftwareShader>,
+    next_id: u32,
+    buffers: HashMap<u32, SoftwareBuffer>,
+    shaders: HashMap<u32, SoftwareShader>,
+    layouts: HashMap<u32, Vec<InputElement>>,
+    states: HashMap<u32, SoftwareState>,
+    vertex_buffer: Option<VertexBinding>,
+    index_buffer: Option<IndexBinding>,
+    vs_constant_buffers: [Option<BufferId>; CONSTANT_BUFFER_SLOTS],
+    ps_constant_buffers: [Option<BufferId>; CONSTANT_BUFFER_SLOTS],
+    vertex_shader: Option<ShaderId>,
+    pixel_shader: Option<ShaderId>,
+    layout: Option<LayoutId>,
+    topology: Topology,
+    viewport: Viewport,
+    depth_stencil: DepthStencilDesc,
+    rasterizer: RasterizerDesc,
+    test_scene: Option<TestScene>,
+}
+
+impl SoftwareBackend {
+    pub fn new(width: u32, height: u32, shaders: ShaderLocator) -> SoftwareBackend {
+        let mut backend: SoftwareBackend = SoftwareBackend {
+            framebuffer: Framebuffer::new(width, height),
+            depth: vec![1.0; width as usize * height as usize],
+            frames_presented: 0,
+            shader_sources: HashMap::new(),
+            next_id: 0,
+            buffers: HashMap::new(),
+            shaders: HashMap::new(),
+            layouts: HashMap::new(),
+            states: HashMap::new(),
+            vertex_buffer: None,
+            index_buffer: None,
+            vs_constant_buffers: [None; CONSTANT_BUFFER_SLOTS],
+            ps_constant_buffers: [None; CONSTANT_BUFFER_SLOTS],
+            vertex_shader: None,
+            pixel_shader: None,
+            layout: None,
+            topology: Topology::TriangleList,
+            viewport: Viewport {
+                top_left_x: 0.0,
+                top_left_y: 0.0,
+                width: width as f32,
+                height: height as f32,
+                min_depth: 0.0,
+                max_depth: 1.0,
+            },
+            depth_stencil: DepthStencilDesc::default(),
+            rasterizer: RasterizerDesc::default(),
+            test_scene: Some(TestScene::new(false, shaders)),
+        };
+
+        backend.register_shader(
+            "VertexShader.hlsl",
+            SoftwareShader::Vertex(Rc::new(transform_vertex_shader)),
+        );
+        backend.register_shader(
+            "PixelShader.hlsl",
+            SoftwareShader::Pixel(Rc::new(face_color_pixel_shader)),
+        );
+        return backend;
+    }
+
+    /// Use `shader` for the HLSL file with this file name, like `PixelShader.hlsl`
+    pub fn register_shader(&mut self, file_name: &str, shader: SoftwareShader) {
+        self.shader_sources.insert(file_name.to_string(), shader);
+    }
+
+    /// The registered shader for the file of `desc`, the compiling step of this backend
+    fn find_shader(&self, desc: &ShaderDesc) -> Result<SoftwareShader, Error> {
+        // The path can come from windows, so both kinds of separators are split on
+        let path: String = desc.path.to_string_lossy().to_string();
+        let file_name: &str = path.rsplit(['/', '\\']).next().unwrap_or_default();
+
+        return match self.shader_sources.get(file_name) {
+            Some(shader) if shader.stage() == desc.stage => Ok(shader.clone()),
+            Some(shader) => Err(Error::shader_compile(
+                &path,
+                E_INVALIDARG,
+                &format!(
+                    "The software shader is a {:?} shader, not a {:?} shader",
+                    shader.stage(),
+                    desc.stage
+                ),
+            )),
+            None => Err(Error::shader_compile(
+                &path,
+                E_NOTIMPL,
+                &format!("There is no software version of {}", file_name),
+            )),
+        };
+    }
+
+    fn next_id(&mut self) -> u32 {
+        self.next_id += 1;
+        return self.next_id;
+    }
+
+    #[track_caller]
+    fn buffer(&self, buffer: BufferId) -> Result<&SoftwareBuffer, Error> {
+        return self
+            .buffers
+            .get(&buffer.0)
+            .ok_or_else(|| unknown_resource(Resource::Buffer(buffer)));
+    }
+
+    /// The contents of the constant buffers of a stage
+    fn constant_buffers(
+        &self,
+        bound: &[Option<BufferId>; CONSTANT_BUFFER_SLOTS],
+    ) -> Vec<Option<&[u8]>> {
+        return bound
+            .iter()
+            .map(|buffer| {
+                buffer
+                    .and_then(|buffer| self.buffers.get(&buffer.0))
+                    .map(|buffer| buffer.data.as_slice())
+            })
+            .collect();
+    }
+
+    /// The indices of the draw call with the base vertex added
+    fn read_indices(
+        &self,
+        index_count: u32,
+        start_index: u32,
+        base_vertex: i32,
+    ) -> Result<Vec<u32>, Error> {
+        let binding: IndexBinding = self
+            .index_buffer
+            .ok_or_else(|| invalid_draw("No index buffer is bound"))?;
+        let data: &[u8] = &self.buffer(binding.buffer)?.data;
+        let size: usize = binding.format.size() as usize;
+
+        let mut indices: Vec<u32> = Vec::with_capacity(index_count as usize);
+        for i in start_index..start_index + index_count {
+            let offset: usize = binding.offset as usize + i as usize * size;
+            let bytes: &[u8] = data.get(offset..offset + size).ok_or_else(|| {
+                invalid_draw(&format!("Index {} is outside of the index buffer", i))
+            })?;
+            let index: u32 = match binding.format {
+                IndexFormat::U16 => u16::from_le_bytes([bytes[0], bytes[1]]) as u32,
+                IndexFormat::U32 => u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]),
+            };
+            let index: u32 = u32::try_from(index as i64 + base_vertex as i64)
+                .map_err(|_| invalid_draw("The base vertex makes an index negative"))?;
+            indices.push(index);
+        }
+        return Ok(indices);
+    }
+
+    /// The triangles of the draw call as indices into `indices`, in the order of their primitive id
+    fn triangles(&self, index_count: usize) -> Vec<[usize; 3]> {
+        return match self.topology {
+            Topology::TriangleList => (0..index_count / 3)
+                .map(|triangle| [triangle * 3, triangle * 3 + 1, triangle * 3 + 2])
+                .collect(),
+            // Every other triangle of a strip is flipped, so they all have the same winding
+            Topology::TriangleStrip => (0..index_count.saturating_sub(2))
+                .map(|triangle| match triangle % 2 {
+                    0 => [triangle, triangle + 1, triangle + 2],
+                    _ => [triangle + 1, triangle, triangle + 2],
+                })
+                .collect(),
+        };
+    }
+
+    /// Map a clip space position to the viewport, the position is snapped to the sub-pixel grid
+    fn to_screen(&self, output: VertexOutput) -> ScreenVertex {
+        let position: [f32; 4] = output.position;
+        let viewport: &Viewport = &self.viewport;
+        let (x, y, z) = (
+            position[0] / position[3],
+            position[1] / position[3],
+            position[2] / position[3],
+        );
+        let snap = |value: f32| (value * SUBPIXEL_STEPS).round() / SUBPIXEL_STEPS;
+        return ScreenVertex {
+            x: snap(viewport.top_left_x + (x + 1.0) * 0.5 * viewport.width),
+            y: snap(viewport.top_left_y + (1.0 - y) * 0.5 * viewport.height),
+            z: viewport.min_depth + z * (viewport.max_depth - viewport.min_depth),
+            w: position[3],
+            varyings: output.varyings,
+        };
+    }
+
+    fn rasterize(
+        &mut self,
+        vertices: [&ScreenVertex; 3],
+        primitive_id: u32,
+        pixel_shader: &PixelShaderFn,
+        constants: &[Option<Vec<u8>>],
+    ) {
+        let [a, mut b, mut c] = vertices;
+        let area: f32 = edge(a.xy(), b.xy(), c.xy());
+        if area == 0.0 {
+            return;
+        }
+
+        // Clockwise on the screen has a positive area, because y points down
+        let front: bool = (area > 0.0) != self.rasterizer.front_counter_clockwise;
+        match self.rasterizer.cull_mode {
+            CullMode::Back if !front => return,
+            CullMode::Front if front => return,
+            _ => {}
+        }
+
+        // Rasterize everything as clockwise, the order of the vertices doesn't matter after culling
+        if area < 0.0 {
+            std::mem::swap(&mut b, &mut c);
+        }
+        let area: f32 = area.abs();
+
+        let viewport: Viewport = self.viewport;
+        let width: u32 = self.framebuffer.width;
+        let height: u32 = self.framebuffer.height;
+        let min_x: u32 =
+            a.x.min(b.x)
+                .min(c.x)
+                .max(viewport.top_left_x)
+                .max(0.0)
+                .floor() as u32;
+        let min_y: u32 =
+            a.y.min(b.y)
+                .min(c.y)
+                .max(viewport.top_left_y)
+                .max(0.0)
+                .floor() as u32;
+        let max_x: u32 =
+            (a.x.max(b.x)
+                .max(c.x)
+                .min(viewport.top_left_x + viewport.width)
+                .ceil()
+                .max(0.0) as u32)
+                .min(width);
+        let max_y: u32 =
+            (a.y.max(b.y)
+                .max(c.y)
+                .min(viewport.top_left_y + viewport.height)
+                .ceil()
+                .max(0.0) as u32)
+                .min(height);
+
+        let buffers: Vec<Option<&[u8]>> =
+            constants.iter().map(|buffer| buffer.as_deref()).collect();
+        let depth_stencil: DepthStencilDesc = self.depth_stencil;
+        let varying_count: usize = a.varyings.len().min(b.varyings.len()).min(c.varyings.len());
+        let mut varyings: Vec<f32> = vec![0.0; varying_count];
+
+        for y in min_y..max_y {
+            for x in min_x..max_x {
+                let p: [f32; 2] = [x as f32 + 0.5, y as f32 + 0.5];
+                let w0: f32 = edge(b.xy(), c.xy(), p);
+                let w1: f32 = edge(c.xy(), a.xy(), p);
+                let w2: f32 = edge(a.xy(), b.xy(), p);
+                if !covers(w0, b.xy(), c.xy())
+                    || !covers(w1, c.xy(), a.xy())
+                    || !covers(w2, a.xy(), b.xy())
+                {
+                    continue;
+                }
+
+                let (l0, l1, l2) = (w0 / area, w1 / area, w2 / area);
+                let z: f32 = l0 * a.z + l1 * b.z + l2 * c.z;
+
+                // Depth clipping
+                if z < viewport.min_depth || z > viewport.max_depth {
+                    continue;
+                }
+
+                let index: usize = (y * width + x) as usize;
+                if depth_stencil.depth_enable {
+                    if !depth_stencil.depth_func.test(z, self.depth[index]) {
+                        continue;
+                    }
+                    if depth_stencil.depth_write {
+                        self.depth[index] = z;
+                    }
+                }
+
+                // The depth is linear on the screen, but the varyings are only linear before the divide
+                // by w. They are interpolated as varying / w and multiplied by the interpolated w.
+                let (p0, p1, p2) = (l0 / a.w, l1 / b.w, l2 / c.w);
+                let w: f32 = 1.0 / (p0 + p1 + p2);
+                for (i, varying) in varyings.iter_mut().enumerate() {
+                    *varying = (p0 * a.varyings[i] + p1 * b.varyings[i] + p2 * c.varyings[i]) * w;
+                }
+
+                let color: [f32; 4] = pixel_shader(&PixelInput {
+                    position: [p[0], p[1], z, w],
+                    varyings: &varyings,
+                    primitive_id,
+                    constants: Constants { buffers: &buffers },
+                });
+                self.framebuffer.pixels[index] = to_unorm(color);
+            }
+        }
+    }
+}
+
+/// Twice the signed area of the triangle `a`, `b`, `p`. Positive when `p` is right of the edge from `a`
+/// to `b`, on the screen.
+fn edge(a: [f32; 2], b: [f32; 2], p: [f32; 2]) -> f32 {
+    return (b[0] - a[0]) * (p[1] - a[1]) - (b[1] - a[1]) * (p[0] - a[0]);
+}
+
+/// Whether a pixel is inside an edge of a clockwise triangle. Pixels exactly on the edge only belong to
+/// top and left edges, so pixels on an edge that is shared by two triangles are drawn once.
+fn covers(weight: f32, from: [f32; 2], to: [f32; 2]) -> bool {
+    if weight != 0.0 {
+        return weight > 0.0;
+    }
+    let (dx, dy) = (to[0] - from[0], to[1] - from[1]);
+    let top: bool = dy == 0.0 && dx > 0.0;
+    let left: bool = dy < 0.0;
+    return top || left;
+}
+
+#[track_caller]
+fn unknown_resource(resource: Resource) -> Error {
+    return Error::hresult_code(E_INVALIDARG, &format!("{:?} doesn't exist", resource));
+}
+
+#[track_caller]
+fn invalid_draw(details: &str) -> Error {
+    return Error::hresult_code(E_INVALIDARG, details);
+}
+
+impl RenderBackend for SoftwareBackend {
+    fn width(&self) -> u32 {
+        return self.framebuffer.width;
+    }
+
+    fn height(&self) -> u32 {
+        return self.framebuffer.height;
+    }
+
+    fn create_buffer(&mut self, desc: &BufferDesc) -> Result<BufferId, Error> {
+        let id: u32 = self.next_id();
+        self.buffers.insert(
+            id,
+            SoftwareBuffer {
+                data: desc.data.to_vec(),
+            },
+        );
+        return Ok(BufferId(id));
+    }
+
+    fn update_buffer(&mut self, buffer: BufferId, data: &[u8]) -> Result<(), Error> {
+        let target: &mut SoftwareBuffer = self
+            .buffers
+            .get_mut(&buffer.0)
+            .ok_or_else(|| unknown_resource(Resource::Buffer(buffer)))?;
+        if target.data.len() != data.len() {
+            return Err(Error::hresult_code(
+                E_INVALIDARG,
+                &format!(
+                    "Updating a buffer of {} bytes with {} bytes",
+                    target.data.len(),
+                    data.len()
+                ),
+            ));
+        }
+        target.data.copy_from_slice(data);
+        return Ok(());
+    }
+
+    fn create_shader(&mut self, desc: &ShaderDesc) -> Result<ShaderId, Error> {
+        let shader: SoftwareShader = self.find_shader(desc)?;
+        let id: u32 = self.next_id();
+        self.shaders.insert(id, shader);
+        return Ok(ShaderId(id));
+    }
+
+    fn replace_shader(&mut self, shader: ShaderId, desc: &ShaderDesc) -> Result<(), Error> {
+        if !self.shaders.contains_key(&shader.0) {
+            return Err(unknown_resource(Resource::Shader(shader)));
+        }
+        let replacement: SoftwareShader = self.find_shader(desc)?;
+        self.shaders.insert(shader.0, replacement);
+        return Ok(());
+    }
+
+    fn create_input_layout(
+        &mut self,
+        elements: &[InputElement],
+        vertex_shader: ShaderId,
+    ) -> Result<LayoutId, Error> {
+        if !matches!(
+            self.shaders.get(&vertex_shader.0),
+            Some(SoftwareShader::Vertex(_))
+        ) {
+            return Err(unknown_resource(Resource::Shader(vertex_shader)));
+        }
+
+        let id: u32 = self.next_id();
+        self.layouts.insert(id, elements.to_vec());
+        return Ok(LayoutId(id));
+    }
+
+    fn create_depth_stencil_state(&mut self, desc: &DepthStencilDesc) -> Result<StateId, Error> {
+        let id: u32 = self.next_id();
+        self.states.insert(id, SoftwareState::DepthStencil(*desc));
+        return Ok(StateId(id));
+    }
+
+    fn create_rasterizer_state(&mut self, desc: &RasterizerDesc) -> Result<StateId, Error> {
+        let id: u32 = self.next_id();
+        self.states.insert(id, SoftwareState::Rasterizer(*desc));
+        return Ok(StateId(id));
+    }
+
+    fn release(&mut self, resource: Resource) {
+        // Bound ids are checked when they are used, so releasing them is enough
+        match resource {
+            Resource::Buffer(id) => drop(self.buffers.remove(&id.0)),
+            Resource::Shader(id) => drop(self.shaders.remove(&id.0)),
+            Resource::Layout(id) => drop(self.layouts.remove(&id.0)),
+            Resource::State(id) => drop(self.states.remove(&id.0)),
+        }
+    }
+
+    fn set_vertex_buffer(
+        &mut self,
+        slot: u32,
+        buffer: BufferId,
+        stride: u32,
+        offset: u32,
+    ) -> Result<(), Error> {
+        if slot != 0 {
+            return Err(Error::hresult_code(
+                E_NOTIMPL,
+                "The software backend only has vertex buffer slot 0",
+            ));
+        }
+        self.buffer(buffer)?;
+        self.vertex_buffer = Some(VertexBinding {
+            buffer,
+            stride,
+            offset,
+        });
+        return Ok(());
+    }
+
+    fn set_index_buffer(
+        &mut self,
+        buffer: BufferId,
+        format: IndexFormat,
+        offset: u32,
+    ) -> Result<(), Error> {
+        self.buffer(buffer)?;
+        self.index_buffer = Some(IndexBinding {
+            buffer,
+            format,
+            offset,
+        });
+        return Ok(());
+    }
+
+    fn set_constant_buffer(
+        &mut self,
+        stage: ShaderStage,
+        slot: u32,
+        buffer: BufferId,
+    ) -> Result<(), Error> {
+        self.buffer(buffer)?;
+        let slots: &mut [Option<BufferId>; CONSTANT_BUFFER_SLOTS] = match stage {
+            ShaderStage::Vertex => &mut self.vs_constant_buffers,
+            ShaderStage::Pixel => &mut self.ps_constant_buffers,
+        };
+        match slots.get_mut(slot as usize) {
+            Some(bound) => *bound = Some(buffer),
+            None => {
+                return Err(Error::hresult_code(
+                    E_INVALIDARG,
+                    &format!("There is no constant buffer slot {}", slot),
+                ))
+            }
+        }
+        return Ok(());
+    }
+
+    fn set_shader(&mut self, shader: ShaderId) -> Result<(), Error> {
+        match self.shaders.get(&shader.0) {
+            Some(SoftwareShader::Vertex(_)) => self.vertex_shader = Some(shader),
+            Some(SoftwareShader::Pixel(_)) => self.pixel_shader = Some(shader),
+            None => return Err(unknown_resource(Resource::Shader(shader))),
+        }
+        return Ok(());
+    }
+
+    fn set_input_layout(&mut self, layout: LayoutId) -> Result<(), Error> {
+        if !self.layouts.contains_key(&layout.0) {
+            return Err(unknown_resource(Resource::Layout(layout)));
+        }
+        self.layout = Some(layout);
+        return Ok(());
+    }
+
+    fn set_topology(&mut self, topology: Topology) {
+        self.topology = topology;
+    }
+
+    fn set_viewport(&mut self, viewport: &Viewport) {
+        self.viewport = *viewport;
+    }
+
+    fn set_depth_stencil_state(&mut self, state: StateId) -> Result<(), Error> {
+        match self.states.get(&state.0) {
+            Some(SoftwareState::DepthStencil(desc)) => self.depth_stencil = *desc,
+            _ => return Err(unknown_resource(Resource::State(state))),
+        }
+        return Ok(());
+    }
+
+    fn set_rasterizer_state(&mut self, state: StateId) -> Result<(), Error> {
+        match self.states.get(&state.0) {
+            Some(SoftwareState::Rasterizer(desc)) => self.rasterizer = *desc,
+            _ => return Err(unknown_resource(Resource::State(state))),
+        }
+        return Ok(());
+    }
+
+    fn clear(&mut self, rgba: [f32; 4]) {
+        self.framebuffer.pixels.fill(to_unorm(rgba));
+        self.depth.fill(1.0);
+    }
+
+    fn draw_indexed(
+        &mut self,
+        index_count: u32,
+        start_index: u32,
+        base_vertex: i32,
+    ) -> Result<(), Error> {
+        let vertex_shader: Rc<VertexShaderFn> =
+            match self.vertex_shader.and_then(|id| self.shaders.get(&id.0)) {
+                Some(SoftwareShader::Vertex(shader)) => shader.clone(),
+                _ => return Err(invalid_draw("No vertex shader is bound")),
+            };
+        let pixel_shader: Rc<PixelShaderFn> =
+            match self.pixel_shader.and_then(|id| self.shaders.get(&id.0)) {
+                Some(SoftwareShader::Pixel(shader)) => shader.clone(),
+                _ => return Err(invalid_draw("No pixel shader is bound")),
+            };
+        let layout: &[InputElement] = self
+            .layout
+            .and_then(|id| self.layouts.get(&id.0))
+            .ok_or_else(|| invalid_draw("No input layout is bound"))?;
+        let binding: VertexBinding = self
+            .vertex_buffer
+            .ok_or_else(|| invalid_draw("No vertex buffer is bound"))?;
+        let vertex_data: &[u8] = &self.buffer(binding.buffer)?.data;
+
+        let indices: Vec<u32> = self.read_indices(index_count, start_index, base_vertex)?;
+
+        // Run the vertex shader once for every vertex that is used
+        let vs_buffers: Vec<Option<&[u8]>> = self.constant_buffers(&self.vs_constant_buffers);
+        let mut shaded: HashMap<u32, VertexOutput> = HashMap::new();
+        for index in indices.iter().copied() {
+            if shaded.contains_key(&index) {
+                continue;
+            }
+            let start: usize = binding.offset as usize + index as usize * binding.stride as usize;
+            let vertex: &[u8] = vertex_data
+                .get(start..start + binding.stride as usize)
+                .ok_or_else(|| {
+                    invalid_draw(&format!("Vertex {} is outside of the vertex buffer", index))
+                })?;
+            let output: VertexOutput = vertex_shader(&VertexInput {
+                vertex,
+                layout,
+                constants: Constants {
+                    buffers: &vs_buffers,
+                },
+            });
+            shaded.insert(index, output);
+        }
+
+        // The constant buffers are copied, so the pixels can be written while they are read
+        let ps_buffers: Vec<Option<Vec<u8>>> = self
+            .constant_buffers(&self.ps_constant_buffers)
+            .into_iter()
+            .map(|buffer| buffer.map(<[u8]>::to_vec))
+            .collect();
+
+        for (primitive_id, triangle) in self.triangles(indices.len()).into_iter().enumerate() {
+            let outputs: [&VertexOutput; 3] = triangle.map(|i| &shaded[&indices[i]]);
+            let polygon: Vec<ScreenVertex> = clip_near(outputs)
+                .into_iter()
+                .map(|output| self.to_screen(output))
+                .collect();
+            // Without a near plane in the projection the polygon can still reach w = 0
+            if polygon.len() < 3 || polygon.iter().any(|vertex| vertex.w <= 0.0) {
+                continue;
+            }
+            // The clipped polygon is convex, so it's drawn as a fan of triangles
+            for corner in 1..polygon.len() - 1 {
+                self.rasterize(
+                    [&polygon[0], &polygon[corner], &polygon[corner + 1]],
+                    primitive_id as u32,
+                    pixel_shader.as_ref(),
+                    &ps_buffers,
+                );
+            }
+        }
+        return Ok(());
+    }
+
+    fn present(&mut self) -> Result<(), Error> {
+        self.frames_presented += 1;
+        return Ok(());
+    }
+}
+
+impl Renderer for SoftwareBackend {
+    fn clear_buffer(&mut self, rgba: [f32; 4]) -> Result<(), Error> {
+        self.clear(rgba);
+        return Ok(());
+    }
+
+    fn test_triangle(&mut self, angle: f32, x: f32, z: f32) -> Result<(), Error> {
+        // The scene draws with the backend it is in, so it is taken out while it does
+        let Some(mut scene) = self.test_scene.take() else {
+            return Ok(());
+        };
+        let result: Result<(), Error> = scene.draw_cube(self, angle, x, z);
+        self.test_scene = Some(scene);
+        return result;
+    }
+
+    fn end_frame(&mut self) -> Result<(), Error> {
+        return self.present();
+    }
+
+    fn read_frame(&self) -> Option<&Framebuffer> {
+        return Some(&self.framebuffer);
+    }
+
+    fn adapter_description(&self) -> String {
+        return format!(
+            "software rasterizer ({}x{})",
+            self.framebuffer.width, self.framebuffer.height
+        );
+    }
+
+    fn cache_stats(&self) -> CacheStats {
+        return self
+            .test_scene
+            .as_ref()
+            .map(|scene| scene.cache.stats)
+            .unwrap_or_default();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+    use crate::window::graphics::{backend::Comparison, shaderlocator::ShaderSet};
+
+    fn clip_position(position: [f32; 4], varying: f32) -> VertexOutput {
+        return VertexOutput {
+            position,
+            varyings: vec![varying],
+        };
+    }
+
+    #[test]
+    fn clip_near_keeps_triangles_in_front() {
+        let a: VertexOutput = clip_position([0.0, 0.0, 0.5, 1.0], 1.0);
+        let b: VertexOutput = clip_position([1.0, 0.0, 0.0, 1.0], 2.0);
+        let c: VertexOutput = clip_position([0.0, 1.0, 0.25, 1.0], 3.0);
+
+        assert_eq!(
+            clip_near([&a, &b, &c]),
+            vec![a.clone(), b.clone(), c.clone()]
+        );
+        let behind: VertexOutput = clip_position([0.0, 0.0, -0.5, 1.0], 0.0);
+        assert!(clip_near([&behind, &behind, &behind]).is_empty());
+    }
+
+    #[test]
+    fn clip_near_cuts_off_the_corner_behind_the_camera() {
+        let a: VertexOutput = clip_position([0.0, 0.0, 0.5, 1.0], 0.0);
+        let b: VertexOutput = clip_position([1.0, 0.0, 0.5, 1.0], 0.0);
+        let c: VertexOutput = clip_position([0.0, 1.0, -1.5, -1.0], 4.0);
+
+        let clipped: Vec<VertexOutput> = clip_near([&a, &b, &c]);
+        assert_eq!(clipped.len(), 4);
+        assert_eq!(clipped[0], a);
+        assert_eq!(clipped[1], b);
+        // A quarter of the way from b to c and from c to a
+        assert_eq!(clipped[2], clip_position([0.75, 0.25, 0.0, 0.5], 1.0));
+        assert_eq!(clipped[3], clip_position([0.0, 0.25, 0.0, 0.5], 1.0));
+    }
+
+    /// Draw a single triangle with the clip space positions in `positions` and a white pixel shader
+    fn draw_triangle(positions: [[f32; 4]; 3]) -> SoftwareBackend {
+        return draw_triangles(
+            &positions.map(|position| (position, 0.0)),
+            RasterizerDesc {
+                cull_mode: CullMode::None,
+                front_counter_clockwise: false,
+            },
+            Rc::new(|_: &PixelInput| [1.0; 4]),
+        );
+    }
+
+    /// Draw a triangle list, every vertex is a clip space position and a varying
+    fn draw_triangles(
+        vertices: &[([f32; 4], f32)],
+        rasterizer: RasterizerDesc,
+        pixel_shader: Rc<PixelShaderFn>,
+    ) -> SoftwareBackend {
+        let mut backend: SoftwareBackend = bind_vertices(vertices, pixel_shader);
+        let rasterizer: StateId = backend.create_rasterizer_state(&rasterizer).unwrap();
+        backend.set_rasterizer_state(rasterizer).unwrap();
+        backend.draw_indexed(vertices.len() as u32, 0, 0).unwrap();
+        return backend;
+    }
+
+    /// A backend with the vertices, their indices in order and the shaders bound, ready to draw
+    fn bind_vertices(
+        vertices: &[([f32; 4], f32)],
+        pixel_shader: Rc<PixelShaderFn>,
+    ) -> SoftwareBackend {
+        let mut backend: SoftwareBackend =
+            SoftwareBackend::new(16, 16, ShaderLocator::new(ShaderSet::default()));
+        backend.register_shader(
+            "Passthrough.hlsl",
+            SoftwareShader::Vertex(Rc::new(|input: &VertexInput| VertexOutput {
+                position: input.attribute("POSITION", 0),
+                varyings: vec![input.attribute("COLOR", 0)[0]],
+            })),
+        );
+        backend.register_shader("Test.hlsl", SoftwareShader::Pixel(pixel_shader));
+
+        let data: Vec<u8> = vertices
+            .iter()
+            .flat_map(|(position, varying)| position.iter().chain([varying]))
+            .flat_map(|value| value.to_le_bytes())
+            .collect();
+        let indices: Vec<u8> = (0..vertices.len() as u16)
+            .flat_map(|i| i.to_le_bytes())
+            .collect();
+        let vertex_buffer: BufferId = backend
+            .create_buffer(&BufferDesc {
+                kind: super::super::BufferKind::Vertex,
+                data: &data,
+                dynamic: false,
+            })
+            .unwrap();
+        let index_buffer: BufferId = backend
+            .create_buffer(&BufferDesc {
+                kind: super::super::BufferKind::Index,
+                data: &indices,
+                dynamic: false,
+            })
+            .unwrap();
+        let vertex_shader: ShaderId = backend
+            .create_shader(&ShaderDesc::new(
+                ShaderStage::Vertex,
+                PathBuf::from("Passthrough.hlsl"),
+            ))
+            .unwrap();
+        let pixel_shader: ShaderId = backend
+            .create_shader(&ShaderDesc::new(
+                ShaderStage::Pixel,
+                PathBuf::from("Test.hlsl"),
+            ))
+            .unwrap();
+        let layout: LayoutId = backend
+            .create_input_layout(
+                &[
+                    InputElement {
+                        semantic: "POSITION".to_string(),
+                        semantic_index: 0,
+                        format: VertexFormat::Float4,
+                        offset: 0,
+                    },
+                    InputElement {
+                        semantic: "COLOR".to_string(),
+                        semantic_index: 0,
+                        format: VertexFormat::Float,
+                        offset: 16,
+                    },
+                ],
+                vertex_shader,
+            )
+            .unwrap();
+
+        backend.set_vertex_buffer(0, vertex_buffer, 20, 0).unwrap();
+        backend
+            .set_index_buffer(index_buffer, IndexFormat::U16, 0)
+            .unwrap();
+        backend.set_shader(vertex_shader).unwrap();
+        backend.set_shader(pixel_shader).unwrap();
+        backend.set_input_layout(layout).unwrap();
+        return backend;
+    }
+
+    /// A pixel shader that outputs the varying as a gray color
+    fn varying_pixel_shader(input: &PixelInput) -> [f32; 4] {
+        let value: f32 = input.varyings[0];
+        return [value, value, value, 1.0];
+    }
+
+    /// A triangle that covers the top left half of the render target and is clockwise on the screen
+    const CLOCKWISE: [([f32; 4], f32); 3] = [
+        ([-1.0, 1.0, 0.5, 1.0], 1.0),
+        ([1.0, 1.0, 0.5, 1.0], 1.0),
+        ([-1.0, -1.0, 0.5, 1.0], 1.0),
+    ];
+
+    #[test]
+    fn triangles_through_the_near_plane_are_clipped() {
+        // The top corner is behind the camera, the part in front of the near plane is a trapezoid from
+        // y = 12 to y = 10.67 on the screen
+        let backend: SoftwareBackend = draw_triangle([
+            [-0.5, -0.5, 0.5, 1.0],
+            [0.5, -0.5, 0.5, 1.0],
+            [0.0, 0.5, -1.0, -0.5],
+        ]);
+        let white: [u8; 4] = [255; 4];
+
+        assert_eq!(backend.framebuffer.pixel(8, 11), white);
+        assert_eq!(backend.framebuffer.pixel(5, 11), white);
+        assert_eq!(backend.framebuffer.pixel(8, 9), [0; 4]);
+        assert_eq!(backend.framebuffer.pixel(1, 11), [0; 4]);
+        // Between the depth of the bottom corners and the near plane
+        let depth: f32 = backend.depth[11 * 16 + 8];
+        assert!(depth > 0.0 && depth < 0.5, "{}", depth);
+    }
+
+    #[test]
+    fn triangles_behind_the_camera_are_not_drawn() {
+        let backend: SoftwareBackend = draw_triangle([
+            [-0.5, -0.5, -0.5, -1.0],
+            [0.5, -0.5, -0.5, -1.0],
+            [0.0, 0.5, -0.5, -1.0],
+        ]);
+
+        assert!(backend
+            .framebuffer
+            .pixels
+            .iter()
+            .all(|pixel| *pixel == [0; 4]));
+    }
+
+    #[test]
+    fn varyings_are_interpolated_with_perspective_correction() {
+        // On the screen the corners are (0.5, 0.5), (14.5, 0.5) and (7.5, 14.5). The center of pixel
+        // (7, 7) has the barycentric coordinates (0.25, 0.25, 0.5). Linear interpolation would give
+        // 0.25 * 1 = 0.25, but the second corner is 9 times further away:
+        // (0.25 / 9) / (0.25 / 1 + 0.25 / 9 + 0.5 / 1) = 1 / 28, which is 9 as a unorm.
+        let backend: SoftwareBackend = draw_triangles(
+            &[
+                ([-0.9375, 0.9375, 0.5, 1.0], 0.0),
+                ([7.3125, 8.4375, 4.5, 9.0], 1.0),
+                ([-0.0625, -0.8125, 0.5, 1.0], 0.0),
+            ],
+            RasterizerDesc::default(),
+            Rc::new(varying_pixel_shader),
+        );
+
+        assert_eq!(backend.framebuffer.pixel(7, 7), [9, 9, 9, 255]);
+    }
+
+    #[test]
+    fn back_faces_are_culled() {
+        let [a, b, c] = CLOCKWISE;
+        let back: SoftwareBackend = draw_triangles(
+            &[a, c, b],
+            RasterizerDesc::default(),
+            Rc::new(varying_pixel_shader),
+        );
+        let front: SoftwareBackend = draw_triangles(
+            &CLOCKWISE,
+            RasterizerDesc::default(),
+            Rc::new(varying_pixel_shader),
+        );
+        let front_culled: SoftwareBackend = draw_triangles(
+            &CLOCKWISE,
+            RasterizerDesc {
+                cull_mode: CullMode::Front,
+                front_counter_clockwise: false,
+            },
+            Rc::new(varying_pixel_shader),
+        );
+
+        assert_eq!(back.framebuffer.pixel(2, 2), [0; 4]);
+        assert_eq!(front.framebuffer.pixel(2, 2), [255; 4]);
+        assert_eq!(front_culled.framebuffer.pixel(2, 2), [0; 4]);
+    }
+
+    #[test]
+    fn front_faces_can_be_counter_clockwise() {
+        let front_counter_clockwise: RasterizerDesc = RasterizerDesc {
+            cull_mode: CullMode::Back,
+            front_counter_clockwise: true,
+        };
+        let [a, b, c] = CLOCKWISE;
+        let counter_clockwise: SoftwareBackend = draw_triangles(
+            &[a, c, b],
+            front_counter_clockwise,
+            Rc::new(varying_pixel_shader),
+        );
+        let clockwise: SoftwareBackend = draw_triangles(
+            &CLOCKWISE,
+            front_counter_clockwise,
+            Rc::new(varying_pixel_shader),
+        );
+
+        assert_eq!(counter_clockwise.framebuffer.pixel(2, 2), [255; 4]);
+        assert_eq!(clockwise.framebuffer.pixel(2, 2), [0; 4]);
+    }
+
+    #[test]
+    fn pixels_get_the_index_of_their_triangle() {
+        // The top left and the bottom right half of the render target
+        let backend: SoftwareBackend = draw_triangles(
+            &[
+                CLOCKWISE[0],
+                CLOCKWISE[1],
+                CLOCKWISE[2],
+                ([1.0, 1.0, 0.5, 1.0], 0.0),
+                ([1.0, -1.0, 0.5, 1.0], 0.0),
+                ([-1.0, -1.0, 0.5, 1.0], 0.0),
+            ],
+            RasterizerDesc::default(),
+            Rc::new(|input: &PixelInput| [input.primitive_id as f32 / 255.0, 0.0, 0.0, 1.0]),
+        );
+
+        assert_eq!(backend.framebuffer.pixel(1, 1), [0, 0, 0, 255]);
+        assert_eq!(backend.framebuffer.pixel(14, 14), [1, 0, 0, 255]);
+    }
+
+    #[test]
+    fn every_other_triangle_of_a_strip_is_flipped() {
+        // The corners of the render target, the second triangle is counter clockwise in the strip
+        let mut backend: SoftwareBackend = bind_vertices(
+            &[
+                ([-1.0, 1.0, 0.5, 1.0], 0.0),
+                ([1.0, 1.0, 0.5, 1.0], 0.0),
+                ([-1.0, -1.0, 0.5, 1.0], 0.0),
+                ([1.0, -1.0, 0.5, 1.0], 0.0),
+            ],
+            Rc::new(|input: &PixelInput| [input.primitive_id as f32 / 255.0, 0.0, 0.0, 1.0]),
+        );
+        backend.set_topology(Topology::TriangleStrip);
+        backend.draw_indexed(4, 0, 0).unwrap();
+
+        // Both are front faces, so the default state doesn't cull them
+        assert_eq!(backend.framebuffer.pixel(1, 1), [0, 0, 0, 255]);
+        assert_eq!(backend.framebuffer.pixel(14, 14), [1, 0, 0, 255]);
+    }
+
+    #[test]
+    fn depth_stencil_states_change_the_depth_test() {
+        // The same triangle at a depth of 0.25, then at 0.75. The color is the depth of `SV_Position`.
+        let [a, b, c] =
+            CLOCKWISE.map(|(position, varying)| ([position[0], position[1], 0.25, 1.0], varying));
+        let [d, e, f] =
+            CLOCKWISE.map(|(position, varying)| ([position[0], position[1], 0.75, 1.0], varying));
+        let depth_color =
+            |input: &PixelInput| [input.position[2], input.position[2], input.position[2], 1.0];
+
+        let mut backend: SoftwareBackend = bind_vertices(&[a, b, c, d, e, f], Rc::new(depth_color));
+        backend.draw_indexed(6, 0, 0).unwrap();
+        assert_eq!(backend.framebuffer.pixel(2, 2), [64, 64, 64, 255]);
+        assert_eq!(backend.depth[2 * 16 + 2], 0.25);
+
+        let mut backend: SoftwareBackend = bind_vertices(&[a, b, c, d, e, f], Rc::new(depth_color));
+        let always: StateId = backend
+            .create_depth_stencil_state(&DepthStencilDesc {
+                depth_enable: true,
+                depth_write: false,
+                depth_func: Comparison::Always,
+            })
+            .unwrap();
+        backend.set_depth_stencil_state(always).unwrap();
+        backend.draw_indexed(6, 0, 0).unwrap();
+        assert_eq!(backend.framebuffer.pixel(2, 2), [191, 191, 191, 255]);
+        assert_eq!(backend.depth[2 * 16 + 2], 1.0);
+    }
+}
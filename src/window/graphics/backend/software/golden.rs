@@ -0,0 +1,523 @@
+use std::{
+    fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+use super::Framebuffer;
+
+/// Set this environment variable to replace the reference images with the frames that are checked
+pub const UPDATE_ENV: &str = "UPDATE_GOLDEN";
+
+/// How different a frame can be from its reference image and still pass
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Tolerance {
+    /// The biggest difference of a color channel that is still the same pixel
+    pub channel: u8,
+    /// Amount of pixels that can be different
+    pub pixels: usize,
+}
+
+impl Default for Tolerance {
+    /// Allow rounding differences, but not a single pixel more
+    fn default() -> Tolerance {
+        return Tolerance {
+            channel: 1,
+            pixels: 0,
+        };
+    }
+}
+
+impl Tolerance {
+    /// Parse `<channel>` or `<channel>,<pixels>`, like `2` or `2,10`
+    pub fn parse(value: &str) -> Result<Tolerance, String> {
+        let (channel, pixels) = match value.split_once(',') {
+            Some((channel, pixels)) => (channel, Some(pixels)),
+            None => (value, None),
+        };
+
+        let channel: u8 = channel.trim().parse().map_err(|_| {
+            format!(
+                "Invalid tolerance {}, expected <channel>[,<pixels>] with a channel difference of 0 to 255",
+                value
+            )
+        })?;
+        let pixels: usize = match pixels {
+            Some(pixels) => pixels.trim().parse().map_err(|_| {
+                format!(
+                    "Invalid tolerance {}, expected <channel>[,<pixels>] with an amount of pixels",
+                    value
+                )
+            })?,
+            None => 0,
+        };
+        return Ok(Tolerance { channel, pixels });
+    }
+}
+
+/// How two frames of the same size differ
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImageDiff {
+    /// Amount of pixels with a channel that is more than the tolerance apart
+    pub mismatched: usize,
+    /// The biggest difference of a color channel in the whole frame
+    pub max_difference: u8,
+    /// The first mismatched pixel, row by row
+    pub first_mismatch: Option<(u32, u32)>,
+    /// Whether every pixel is within the channel tolerance, per pixel
+    pub mask: Vec<bool>,
+}
+
+impl ImageDiff {
+    pub fn passes(&self, tolerance: &Tolerance) -> bool {
+        return self.mismatched <= tolerance.pixels;
+    }
+}
+
+/// Compare the color of two frames. Alpha is left out: it isn't stored in the reference images and the
+/// swap chain ignores it when the frame is shown.
+pub fn diff(
+    actual: &Framebuffer,
+    expected: &Framebuffer,
+    tolerance: &Tolerance,
+) -> Result<ImageDiff, String> {
+    if actual.width != expected.width || actual.height != expected.height {
+        return Err(format!(
+            "The frame is {}x{}, but the reference image is {}x{}",
+            actual.width, actual.height, expected.width, expected.height
+        ));
+    }
+
+    let mut result: ImageDiff = ImageDiff {
+        mismatched: 0,
+        max_difference: 0,
+        first_mismatch: None,
+        mask: Vec::with_capacity(actual.pixels.len()),
+    };
+    for (i, (a, e)) in actual.pixels.iter().zip(expected.pixels.iter()).enumerate() {
+        let difference: u8 = (0..3)
+            .map(|channel| a[channel].abs_diff(e[channel]))
+            .max()
+            .unwrap_or(0);
+        result.max_difference = result.max_difference.max(difference);
+
+        let matches: bool = difference <= tolerance.channel;
+        if !matches {
+            result.mismatched += 1;
+            if result.first_mismatch.is_none() {
+                result.first_mismatch = Some((i as u32 % actual.width, i as u32 / actual.width));
+            }
+        }
+        result.mask.push(matches);
+    }
+    return Ok(result);
+}
+
+impl Framebuffer {
+    /// Write the frame as a binary PPM image (P6). Alpha is dropped.
+    pub fn write_ppm(&self, path: &Path) -> io::Result<()> {
+        let mut bytes: Vec<u8> = format!("P6\n{} {}\n255\n", self.width, self.height).into_bytes();
+        bytes.reserve(self.pixels.len() * 3);
+        for pixel in &self.pixels {
+            bytes.extend_from_slice(&pixel[..3]);
+        }
+
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        return fs::write(path, bytes);
+    }
+
+    /// Read a binary PPM image (P6) with 8 bits per channel. Every pixel gets an alpha of 255.
+    pub fn read_ppm(path: &Path) -> io::Result<Framebuffer> {
+        let bytes: Vec<u8> = fs::read(path)?;
+        return Framebuffer::parse_ppm(&bytes).map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{} is not a PPM image: {}", path.display(), e),
+            )
+        });
+    }
+
+    pub fn parse_ppm(bytes: &[u8]) -> Result<Framebuffer, String> {
+        // The header is 4 fields that are separated by whitespace and can have # comments in between.
+        // Exactly one whitespace character separates the header from the pixels.
+        let mut fields: Vec<String> = vec![];
+        let mut position: usize = 0;
+        while fields.len() < 4 {
+            match bytes.get(position) {
+                None => return Err("the header ends early".to_string()),
+                Some(b'#') => {
+                    while bytes.get(position).is_some_and(|byte| *byte != b'\n') {
+                        position += 1;
+                    }
+                }
+                Some(byte) if byte.is_ascii_whitespace() => position += 1,
+                Some(_) => {
+                    let start: usize = position;
+                    while bytes
+                        .get(position)
+                        .is_some_and(|byte| !byte.is_ascii_whitespace())
+                    {
+                        position += 1;
+                    }
+                    fields.push(String::from_utf8_lossy(&bytes[start..position]).to_string());
+                }
+            }
+        }
+        position += 1;
+
+        if fields[0] != "P6" {
+            return Err(format!("expected the P6 format, got {}", fields[0]));
+        }
+        let number = |field: &String| -> Result<u32, String> {
+            return field
+                .parse()
+                .map_err(|_| format!("{} is not a number", field));
+        };
+        let (width, height, max) = (
+            number(&fields[1])?,
+            number(&fields[2])?,
+            number(&fields[3])?,
+        );
+        if max != 255 {
+            return Err(format!(
+                "only 8 bits per channel are supported, the max value is {}",
+                max
+            ));
+        }
+
+        let size: usize = width as usize * height as usize * 3;
+        let data: &[u8] = bytes
+            .get(position..position + size)
+            .ok_or_else(|| format!("expected {} bytes of pixels for {}x{}", size, width, height))?;
+
+        return Ok(Framebuffer {
+            width,
+            height,
+            pixels: data
+                .chunks_exact(3)
+                .map(|rgb| [rgb[0], rgb[1], rgb[2], 255])
+                .collect(),
+        });
+    }
+}
+
+/// What [`GoldenImage::check()`] did with the frame
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GoldenOutcome {
+    /// The frame is the same as the reference image, within the tolerance
+    Matched(ImageDiff),
+    /// [`UPDATE_ENV`] was set, so the frame is the new reference image
+    Written,
+}
+
+#[derive(Debug)]
+pub enum GoldenError {
+    Io {
+        path: PathBuf,
+        error: io::Error,
+    },
+    /// There is no reference image and [`UPDATE_ENV`] isn't set
+    Missing(PathBuf),
+    /// The frame and the reference image have a different size
+    Size(String),
+    /// The frame is too different. The frame and an image of the mismatched pixels are written next to
+    /// the reference image.
+    Mismatch {
+        reference: PathBuf,
+        diff: ImageDiff,
+        actual: PathBuf,
+        diff_image: PathBuf,
+    },
+}
+
+impl fmt::Display for GoldenError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            GoldenError::Io { path, error } => write!(f, "{}: {}", path.display(), error),
+            GoldenError::Missing(path) => write!(
+                f,
+                "The reference image {} doesn't exist, set {} to write it",
+                path.display(),
+                UPDATE_ENV
+            ),
+            GoldenError::Size(details) => write!(f, "{}", details),
+            GoldenError::Mismatch {
+                reference,
+                diff,
+                actual,
+                diff_image,
+            } => write!(
+                f,
+                "The frame doesn't match {}: {} pixels are different, the first at {:?}, the biggest difference is {}. \
+                 The frame was written to {} and the different pixels to {}",
+                reference.display(),
+                diff.mismatched,
+                diff.first_mismatch.unwrap_or_default(),
+                diff.max_difference,
+                actual.display(),
+                diff_image.display()
+            ),
+        };
+    }
+}
+
+/// A reference image that frames are checked against
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GoldenImage {
+    pub path: PathBuf,
+    pub tolerance: Tolerance,
+}
+
+impl GoldenImage {
+    pub fn new(path: PathBuf, tolerance: Tolerance) -> GoldenImage {
+        return GoldenImage { path, tolerance };
+    }
+
+    /// Compare the frame with the reference image. While [`UPDATE_ENV`] is set the frame is written as
+    /// the reference image instead, which is also the only way to create a new one.
+    pub fn check(&self, frame: &Framebuffer) -> Result<GoldenOutcome, GoldenError> {
+        return self.check_or_update(frame, std::env::var_os(UPDATE_ENV).is_some());
+    }
+
+    fn check_or_update(
+        &self,
+        frame: &Framebuffer,
+        update: bool,
+    ) -> Result<GoldenOutcome, GoldenError> {
+        if update {
+            frame
+                .write_ppm(&self.path)
+                .map_err(|error| self.io_error(&self.path, error))?;
+            return Ok(GoldenOutcome::Written);
+        }
+        if !self.path.exists() {
+            return Err(GoldenError::Missing(self.path.clone()));
+        }
+
+        let expected: Framebuffer =
+            Framebuffer::read_ppm(&self.path).map_err(|error| self.io_error(&self.path, error))?;
+        let result: ImageDiff =
+            diff(frame, &expected, &self.tolerance).map_err(GoldenError::Size)?;
+        if result.passes(&self.tolerance) {
+            return Ok(GoldenOutcome::Matched(result));
+        }
+
+        let actual: PathBuf = self.path.with_extension("actual.ppm");
+        frame
+            .write_ppm(&actual)
+            .map_err(|error| self.io_error(&actual, error))?;
+        let diff_image: PathBuf = self.path.with_extension("diff.ppm");
+        diff_image_of(&expected, &result)
+            .write_ppm(&diff_image)
+            .map_err(|error| self.io_error(&diff_image, error))?;
+
+        return Err(GoldenError::Mismatch {
+            reference: self.path.clone(),
+            diff: result,
+            actual,
+            diff_image,
+        });
+    }
+
+    fn io_error(&self, path: &Path, error: io::Error) -> GoldenError {
+        return GoldenError::Io {
+            path: path.to_path_buf(),
+            error,
+        };
+    }
+}
+
+/// The reference image dimmed, with the mismatched pixels in red
+fn diff_image_of(expected: &Framebuffer, diff: &ImageDiff) -> Framebuffer {
+    return Framebuffer {
+        width: expected.width,
+        height: expected.height,
+        pixels: expected
+            .pixels
+            .iter()
+            .zip(diff.mask.iter())
+            .map(|(pixel, matches)| match matches {
+                true => [pixel[0] / 4, pixel[1] / 4, pixel[2] / 4, 255],
+                false => [255, 0, 0, 255],
+            })
+            .collect(),
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::window::graphics::{
+        backend::software::SoftwareBackend,
+        shaderlocator::{ShaderLocator, ShaderSet},
+        Renderer,
+    };
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir: PathBuf =
+            std::env::temp_dir().join(format!("golden_{}_{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        return dir;
+    }
+
+    fn frame(pixels: &[[u8; 4]]) -> Framebuffer {
+        return Framebuffer {
+            width: 2,
+            height: pixels.len() as u32 / 2,
+            pixels: pixels.to_vec(),
+        };
+    }
+
+    #[test]
+    fn the_test_cube_matches_its_reference_image() {
+        let mut backend: SoftwareBackend =
+            SoftwareBackend::new(160, 160, ShaderLocator::new(ShaderSet::embedded()));
+        backend.clear_buffer([0.0; 4]).unwrap();
+        backend.test_triangle(70.0, 0.0, 0.0).unwrap();
+        backend.test_triangle(0.5, 0.4, -0.3).unwrap();
+
+        let golden: GoldenImage = GoldenImage::new(
+            Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/data/golden/test_cube.ppm"),
+            Tolerance::default(),
+        );
+        match golden.check(&backend.framebuffer) {
+            Ok(GoldenOutcome::Matched(_) | GoldenOutcome::Written) => {}
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    #[test]
+    fn ppm_round_trip() {
+        let image: Framebuffer = frame(&[[1, 2, 3, 255], [4, 5, 6, 255], [7, 8, 9, 255], [0; 4]]);
+        let dir: PathBuf = temp_dir("round_trip");
+        let path: PathBuf = dir.join("nested").join("image.ppm");
+
+        image.write_ppm(&path).unwrap();
+        let read: Framebuffer = Framebuffer::read_ppm(&path).unwrap();
+        assert_eq!(read.width, 2);
+        assert_eq!(read.height, 2);
+        // Alpha isn't stored
+        assert_eq!(read.pixels[3], [0, 0, 0, 255]);
+        assert_eq!(read.pixels[..3], image.pixels[..3]);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn ppm_header_with_comments() {
+        let mut bytes: Vec<u8> = b"P6 # a comment\n1\n# another one\n1 255\n".to_vec();
+        bytes.extend_from_slice(&[10, 20, 30]);
+        let image: Framebuffer = Framebuffer::parse_ppm(&bytes).unwrap();
+        assert_eq!(image.pixels, vec![[10, 20, 30, 255]]);
+
+        assert!(Framebuffer::parse_ppm(b"P3\n1 1\n255\n").is_err());
+        assert!(Framebuffer::parse_ppm(b"P6\n1 1\n65535\n").is_err());
+        assert!(Framebuffer::parse_ppm(b"P6\n2 2\n255\n\x01\x02").is_err());
+        assert!(Framebuffer::parse_ppm(b"P6\n1").is_err());
+    }
+
+    #[test]
+    fn tolerance_parse() {
+        assert_eq!(
+            Tolerance::parse("2"),
+            Ok(Tolerance {
+                channel: 2,
+                pixels: 0
+            })
+        );
+        assert_eq!(
+            Tolerance::parse(" 3 , 10 "),
+            Ok(Tolerance {
+                channel: 3,
+                pixels: 10
+            })
+        );
+        assert!(Tolerance::parse("256").is_err());
+        assert!(Tolerance::parse("1,many").is_err());
+    }
+
+    #[test]
+    fn diff_counts_pixels_outside_the_tolerance() {
+        let expected: Framebuffer = frame(&[
+            [10, 10, 10, 255],
+            [10, 10, 10, 255],
+            [10, 10, 10, 255],
+            [0; 4],
+        ]);
+        // Within the tolerance, different alpha, too different
+        let actual: Framebuffer = frame(&[
+            [11, 9, 10, 255],
+            [10, 10, 10, 0],
+            [10, 10, 10, 255],
+            [0, 5, 0, 255],
+        ]);
+
+        let result: ImageDiff = diff(&actual, &expected, &Tolerance::default()).unwrap();
+        assert_eq!(result.mismatched, 1);
+        assert_eq!(result.max_difference, 5);
+        assert_eq!(result.first_mismatch, Some((1, 1)));
+        assert_eq!(result.mask, vec![true, true, true, false]);
+        assert!(!result.passes(&Tolerance::default()));
+        assert!(result.passes(&Tolerance {
+            channel: 1,
+            pixels: 1
+        }));
+
+        let small: Framebuffer = frame(&[[0; 4], [0; 4]]);
+        assert!(diff(&small, &expected, &Tolerance::default()).is_err());
+    }
+
+    #[test]
+    fn a_missing_reference_image_is_an_error_unless_updating() {
+        let dir: PathBuf = temp_dir("missing");
+        let golden: GoldenImage = GoldenImage::new(dir.join("missing.ppm"), Tolerance::default());
+        let image: Framebuffer = frame(&[[1, 2, 3, 255], [4, 5, 6, 255]]);
+
+        let error: GoldenError = golden.check_or_update(&image, false).unwrap_err();
+        assert!(matches!(&error, GoldenError::Missing(path) if path == &golden.path));
+        assert!(error.to_string().contains(UPDATE_ENV));
+        assert!(!golden.path.exists());
+
+        assert_eq!(
+            golden.check_or_update(&image, true).unwrap(),
+            GoldenOutcome::Written
+        );
+        assert!(matches!(
+            golden.check_or_update(&image, false),
+            Ok(GoldenOutcome::Matched(_))
+        ));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn a_mismatch_writes_the_frame_and_the_diff_image() {
+        let dir: PathBuf = temp_dir("mismatch");
+        let golden: GoldenImage = GoldenImage::new(dir.join("reference.ppm"), Tolerance::default());
+        frame(&[[40, 40, 40, 255], [40, 40, 40, 255]])
+            .write_ppm(&golden.path)
+            .unwrap();
+
+        let actual: Framebuffer = frame(&[[40, 40, 40, 255], [200, 40, 40, 255]]);
+        let Err(GoldenError::Mismatch {
+            diff,
+            actual: actual_path,
+            diff_image,
+            ..
+        }) = golden.check_or_update(&actual, false)
+        else {
+            panic!("expected a mismatch");
+        };
+        assert_eq!(diff.mismatched, 1);
+        assert_eq!(actual_path, dir.join("reference.actual.ppm"));
+        assert_eq!(
+            Framebuffer::read_ppm(&actual_path).unwrap().pixels[1],
+            [200, 40, 40, 255]
+        );
+        // The matching pixel is dimmed and the other one is red
+        assert_eq!(
+            Framebuffer::read_ppm(&diff_image).unwrap().pixels,
+            vec![[10, 10, 10, 255], [255, 0, 0, 255]]
+        );
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,354 @@
+use std::{
+    collections::hash_map::DefaultHasher,
+    collections::HashMap,
+    fmt,
+    hash::{Hash, Hasher},
+    ops::Deref,
+};
+
+use super::Bindable;
+use crate::window::{
+    errors::Error,
+    graphics::backend::{
+        BufferDesc, BufferId, BufferKind, InputElement, LayoutId, RenderBackend, Resource,
+        ShaderDesc, ShaderId,
+    },
+};
+
+/// How often a kind of resource was found in the cache and how often it had to be created
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub struct CacheCounters {
+    pub hits: u64,
+    pub misses: u64,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub struct CacheStats {
+    pub shaders: CacheCounters,
+    pub buffers: CacheCounters,
+    pub layouts: CacheCounters,
+}
+
+impl CacheStats {
+    pub fn total(&self) -> CacheCounters {
+        return CacheCounters {
+            hits: self.shaders.hits + self.buffers.hits + self.layouts.hits,
+            misses: self.shaders.misses + self.buffers.misses + self.layouts.misses,
+        };
+    }
+}
+
+impl fmt::Display for CacheStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let total: CacheCounters = self.total();
+        return write!(
+            f,
+            "{} hits, {} misses (shaders {}/{}, buffers {}/{}, layouts {}/{})",
+            total.hits,
+            total.misses,
+            self.shaders.hits,
+            self.shaders.misses,
+            self.buffers.hits,
+            self.buffers.misses,
+            self.layouts.hits,
+            self.layouts.misses
+        );
+    }
+}
+
+/// Buffers are found by a hash of their contents. Different contents can have the same hash, so the
+/// cache keeps a copy of the contents of every buffer with the hash to compare.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+struct BufferKey {
+    kind: BufferKind,
+    size: usize,
+    contents: u64,
+}
+
+impl BufferKey {
+    fn new(desc: &BufferDesc) -> BufferKey {
+        let mut hasher: DefaultHasher = DefaultHasher::new();
+        desc.data.hash(&mut hasher);
+        return BufferKey {
+            kind: desc.kind,
+            size: desc.data.len(),
+            contents: hasher.finish(),
+        };
+    }
+}
+
+/// An input layout is made for the input signature of a vertex shader, so the shader is part of the key
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct LayoutKey {
+    elements: Vec<InputElement>,
+    vertex_shader: ShaderId,
+}
+
+/// Resources that are the same when they are made from the same description: shaders by their path,
+/// entry point, profile and defines, buffers by their contents and input layouts by their elements and
+/// vertex shader. Asking for one that was made before returns the same handle, so shaders are compiled
+/// once and meshes are uploaded once.
+///
+/// The cache owns what it creates. Bindables get them as [`Shared`], which doesn't release them.
+#[derive(Debug, Default)]
+pub struct ResourceCache {
+    shaders: HashMap<ShaderDesc, ShaderId>,
+    buffers: HashMap<BufferKey, Vec<(Box<[u8]>, BufferId)>>,
+    layouts: HashMap<LayoutKey, LayoutId>,
+    pub stats: CacheStats,
+}
+
+impl ResourceCache {
+    pub fn shader(
+        &mut self,
+        backend: &mut dyn RenderBackend,
+        desc: &ShaderDesc,
+    ) -> Result<ShaderId, Error> {
+        if let Some(shader) = self.shaders.get(desc) {
+            self.stats.shaders.hits += 1;
+            return Ok(*shader);
+        }
+
+        let shader: ShaderId = backend.create_shader(desc)?;
+        self.stats.shaders.misses += 1;
+        self.shaders.insert(desc.clone(), shader);
+        return Ok(shader);
+    }
+
+    /// A buffer with the contents of `desc`. Dynamic buffers are changed by whoever has them, so those
+    /// are created every time and aren't kept in the cache.
+    pub fn buffer(
+        &mut self,
+        backend: &mut dyn RenderBackend,
+        desc: &BufferDesc,
+    ) -> Result<BufferId, Error> {
+        if desc.dynamic {
+            return backend.create_buffer(desc);
+        }
+
+        let cached: &mut Vec<(Box<[u8]>, BufferId)> =
+            self.buffers.entry(BufferKey::new(desc)).or_default();
+        if let Some((_, buffer)) = cached.iter().find(|(contents, _)| **contents == *desc.data) {
+            self.stats.buffers.hits += 1;
+            return Ok(*buffer);
+        }
+
+        let buffer: BufferId = backend.create_buffer(desc)?;
+        self.stats.buffers.misses += 1;
+        cached.push((Box::from(desc.data), buffer));
+        return Ok(buffer);
+    }
+
+    pub fn input_layout(
+        &mut self,
+        backend: &mut dyn RenderBackend,
+        elements: &[InputElement],
+        vertex_shader: ShaderId,
+    ) -> Result<LayoutId, Error> {
+        let key: LayoutKey = LayoutKey {
+            elements: elements.to_vec(),
+            vertex_shader,
+        };
+        if let Some(layout) = self.layouts.get(&key) {
+            self.stats.layouts.hits += 1;
+            return Ok(*layout);
+        }
+
+        let layout: LayoutId = backend.create_input_layout(elements, vertex_shader)?;
+        self.stats.layouts.misses += 1;
+        self.layouts.insert(key, layout);
+        return Ok(layout);
+    }
+
+    /// The shaders in the cache and what they were compiled from
+    pub fn shaders(&self) -> impl Iterator<Item = (&ShaderDesc, ShaderId)> {
+        return self.shaders.iter().map(|(desc, shader)| (desc, *shader));
+    }
+
+    /// Release everything in the cache. Bindables that still have one of the resources can't be used
+    /// anymore.
+    pub fn clear(&mut self, backend: &mut dyn RenderBackend) {
+        for (_, layout) in self.layouts.drain() {
+            backend.release(Resource::Layout(layout));
+        }
+        for (_, shader) in self.shaders.drain() {
+            backend.release(Resource::Shader(shader));
+        }
+        for (_, buffer) in self.buffers.drain().flat_map(|(_, buffers)| buffers) {
+            backend.release(Resource::Buffer(buffer));
+        }
+    }
+}
+
+/// A bindable with resources that belong to a [`ResourceCache`]. Releasing it does nothing, the cache
+/// releases them.
+pub struct Shared<B>(pub B);
+
+impl<B> Deref for Shared<B> {
+    type Target = B;
+
+    fn deref(&self) -> &B {
+        return &self.0;
+    }
+}
+
+impl<B: Bindable> Bindable for Shared<B> {
+    fn bind(&self, backend: &mut dyn RenderBackend) -> Result<(), Error> {
+        return self.0.bind(backend);
+    }
+
+    fn release(&self, _backend: &mut dyn RenderBackend) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+    use crate::window::graphics::backend::{
+        recording::{Call, RecordingBackend},
+        ShaderStage, VertexFormat,
+    };
+
+    fn vertex_buffer(data: &[u8]) -> BufferDesc<'_> {
+        return BufferDesc {
+            kind: BufferKind::Vertex,
+            data,
+            dynamic: false,
+        };
+    }
+
+    #[test]
+    fn identical_buffers_are_created_once() {
+        let mut backend: RecordingBackend = RecordingBackend::new();
+        let mut cache: ResourceCache = ResourceCache::default();
+
+        let first: BufferId = cache
+            .buffer(&mut backend, &vertex_buffer(&[1, 2, 3, 4]))
+            .unwrap();
+        let second: BufferId = cache
+            .buffer(&mut backend, &vertex_buffer(&[1, 2, 3, 4]))
+            .unwrap();
+        assert_eq!(first, second);
+        assert_eq!(backend.created_buffers().len(), 1);
+        assert_eq!(cache.stats.buffers, CacheCounters { hits: 1, misses: 1 });
+    }
+
+    #[test]
+    fn different_buffers_are_created_twice() {
+        let mut backend: RecordingBackend = RecordingBackend::new();
+        let mut cache: ResourceCache = ResourceCache::default();
+
+        let first: BufferId = cache
+            .buffer(&mut backend, &vertex_buffer(&[1, 2, 3, 4]))
+            .unwrap();
+        let second: BufferId = cache
+            .buffer(&mut backend, &vertex_buffer(&[4, 3, 2, 1]))
+            .unwrap();
+        // The same bytes as another kind of buffer are another buffer
+        let index: BufferId = cache
+            .buffer(
+                &mut backend,
+                &BufferDesc {
+                    kind: BufferKind::Index,
+                    data: &[1, 2, 3, 4],
+                    dynamic: false,
+                },
+            )
+            .unwrap();
+        assert_ne!(first, second);
+        assert_ne!(first, index);
+        assert_eq!(backend.created_buffers().len(), 3);
+        assert_eq!(cache.stats.buffers, CacheCounters { hits: 0, misses: 3 });
+    }
+
+    #[test]
+    fn dynamic_buffers_bypass_the_cache() {
+        let mut backend: RecordingBackend = RecordingBackend::new();
+        let mut cache: ResourceCache = ResourceCache::default();
+        let desc: BufferDesc = BufferDesc {
+            kind: BufferKind::Constant,
+            data: &[0; 16],
+            dynamic: true,
+        };
+
+        let first: BufferId = cache.buffer(&mut backend, &desc).unwrap();
+        let second: BufferId = cache.buffer(&mut backend, &desc).unwrap();
+        assert_ne!(first, second);
+        assert_eq!(backend.created_buffers().len(), 2);
+        assert_eq!(cache.stats.buffers, CacheCounters::default());
+
+        // Releasing the cache leaves them to their owners
+        cache.clear(&mut backend);
+        assert_eq!(backend.calls.len(), 2);
+    }
+
+    #[test]
+    fn contents_are_compared_when_the_hash_is_the_same() {
+        let mut backend: RecordingBackend = RecordingBackend::new();
+        let mut cache: ResourceCache = ResourceCache::default();
+        let desc: BufferDesc = vertex_buffer(&[1, 2, 3, 4]);
+        // Other contents with the same hash
+        cache.buffers.insert(
+            BufferKey::new(&desc),
+            vec![(Box::from([9, 9, 9, 9].as_slice()), BufferId(100))],
+        );
+
+        let first: BufferId = cache.buffer(&mut backend, &desc).unwrap();
+        let second: BufferId = cache.buffer(&mut backend, &desc).unwrap();
+        assert_ne!(first, BufferId(100));
+        assert_eq!(first, second);
+        assert_eq!(backend.created_buffers().len(), 1);
+        assert_eq!(cache.stats.buffers, CacheCounters { hits: 1, misses: 1 });
+
+        // Both buffers with the hash belong to the cache
+        backend.calls.clear();
+        cache.clear(&mut backend);
+        assert_eq!(
+            backend.calls,
+            vec![
+                Call::Release(Resource::Buffer(BufferId(100))),
+                Call::Release(Resource::Buffer(first))
+            ]
+        );
+    }
+
+    #[test]
+    fn shaders_and_layouts_are_found_by_their_description() {
+        let mut backend: RecordingBackend = RecordingBackend::new();
+        let mut cache: ResourceCache = ResourceCache::default();
+        let desc: ShaderDesc = ShaderDesc::new(ShaderStage::Vertex, PathBuf::from("Cube.hlsl"));
+        let mut defined: ShaderDesc = desc.clone();
+        defined
+            .defines
+            .push(("SKINNED".to_string(), "1".to_string()));
+
+        let shader: ShaderId = cache.shader(&mut backend, &desc).unwrap();
+        assert_eq!(cache.shader(&mut backend, &desc).unwrap(), shader);
+        assert_ne!(cache.shader(&mut backend, &defined).unwrap(), shader);
+        assert_eq!(cache.stats.shaders, CacheCounters { hits: 1, misses: 2 });
+        assert_eq!(cache.shaders().count(), 2);
+
+        let elements: Vec<InputElement> = vec![InputElement {
+            semantic: "Position".to_string(),
+            semantic_index: 0,
+            format: VertexFormat::Float3,
+            offset: 0,
+        }];
+        let layout: LayoutId = cache.input_layout(&mut backend, &elements, shader).unwrap();
+        assert_eq!(
+            cache.input_layout(&mut backend, &elements, shader).unwrap(),
+            layout
+        );
+        assert_eq!(cache.stats.layouts, CacheCounters { hits: 1, misses: 1 });
+        assert_eq!(cache.stats.total(), CacheCounters { hits: 2, misses: 3 });
+
+        backend.calls.clear();
+        cache.clear(&mut backend);
+        assert_eq!(backend.calls.len(), 3);
+        assert!(backend
+            .calls
+            .contains(&Call::Release(Resource::Layout(layout))));
+        assert!(cache.shaders().next().is_none());
+    }
+}
@@ -0,0 +1,830 @@
+use std::fmt;
+
+use directx_math::XMFLOAT4X4;
+
+use super::Pod;
+
+/// HLSL reads constant buffers in registers of 16 bytes (4 components)
+pub const REGISTER_SIZE: u32 = 16;
+/// Every scalar in a constant buffer is 4 bytes, `bool` too
+pub const SCALAR_SIZE: u32 = 4;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ScalarType {
+    Float,
+    Int,
+    Uint,
+    Bool,
+}
+
+impl ScalarType {
+    pub fn name(&self) -> &str {
+        return match self {
+            ScalarType::Float => "float",
+            ScalarType::Int => "int",
+            ScalarType::Uint => "uint",
+            ScalarType::Bool => "bool",
+        };
+    }
+}
+
+/// The type of a variable in an HLSL `cbuffer`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum HlslType {
+    Scalar(ScalarType),
+    /// Like `float3`, with 2 to 4 components
+    Vector(ScalarType, u32),
+    /// A `floatRxC`. HLSL stores matrices column by column unless they are declared `row_major`.
+    Matrix {
+        rows: u32,
+        columns: u32,
+        row_major: bool,
+    },
+    Array(Box<HlslType>, u32),
+    Struct(Vec<(String, HlslType)>),
+}
+
+impl HlslType {
+    pub fn float_n(components: u32) -> HlslType {
+        return HlslType::Vector(ScalarType::Float, components);
+    }
+
+    pub fn array(element: HlslType, length: u32) -> HlslType {
+        return HlslType::Array(Box::new(element), length);
+    }
+
+    /// Arrays, matrices and structs always start at a new register. Scalars and vectors only do when they
+    /// don't fit in what is left of the current one.
+    pub fn starts_register(&self) -> bool {
+        return matches!(
+            self,
+            HlslType::Matrix { .. } | HlslType::Array(..) | HlslType::Struct(_)
+        );
+    }
+
+    /// Size in bytes without the padding after the last component, following values can be packed there
+    pub fn size(&self) -> u32 {
+        return match self {
+            HlslType::Scalar(_) => SCALAR_SIZE,
+            HlslType::Vector(_, components) => components * SCALAR_SIZE,
+            HlslType::Matrix {
+                rows,
+                columns,
+                row_major,
+            } => {
+                let (registers, components) = match row_major {
+                    true => (*rows, *columns),
+                    false => (*columns, *rows),
+                };
+                (registers - 1) * REGISTER_SIZE + components * SCALAR_SIZE
+            }
+            HlslType::Array(_, 0) => 0,
+            HlslType::Array(element, length) => {
+                HlslType::array_stride(element) * (length - 1) + element.size()
+            }
+            HlslType::Struct(members) => HlslType::member_offsets(members).1,
+        };
+    }
+
+    /// Where a value of this type goes when the previous value ends at `end`
+    pub fn place(&self, end: u32) -> u32 {
+        let first_register: u32 = end / REGISTER_SIZE;
+        let last_register: u32 = (end + self.size().max(1) - 1) / REGISTER_SIZE;
+        if self.starts_register() || first_register != last_register {
+            return end.next_multiple_of(REGISTER_SIZE);
+        }
+        return end;
+    }
+
+    /// Every element of an array starts at a new register
+    fn array_stride(element: &HlslType) -> u32 {
+        return element.size().next_multiple_of(REGISTER_SIZE);
+    }
+
+    /// Offsets of struct members from the start of the struct, and where the last one ends
+    fn member_offsets(members: &[(String, HlslType)]) -> (Vec<u32>, u32) {
+        let mut offsets: Vec<u32> = Vec::with_capacity(members.len());
+        let mut end: u32 = 0;
+        for (_, ty) in members {
+            let offset: u32 = ty.place(end);
+            offsets.push(offset);
+            end = offset + ty.size();
+        }
+        return (offsets, end);
+    }
+
+    /// Every scalar of a value of this type at `offset`, in the order [`HlslValue::write_scalars()`] writes
+    /// them: members and elements in order, vectors by component and matrices row by row.
+    pub fn leaves(&self, name: &str, offset: u32, out: &mut Vec<Leaf>) {
+        match self {
+            HlslType::Scalar(scalar) => out.push(Leaf {
+                name: name.to_string(),
+                offset,
+                scalar: *scalar,
+            }),
+            HlslType::Vector(scalar, components) => {
+                for component in 0..*components {
+                    out.push(Leaf {
+                        name: component_name(name, component),
+                        offset: offset + component * SCALAR_SIZE,
+                        scalar: *scalar,
+                    });
+                }
+            }
+            HlslType::Matrix {
+                rows,
+                columns,
+                row_major,
+            } => {
+                for row in 0..*rows {
+                    for column in 0..*columns {
+                        let (register, component) = match row_major {
+                            true => (row, column),
+                            false => (column, row),
+                        };
+                        out.push(Leaf {
+                            name: format!("{}._m{}{}", name, row, column),
+                            offset: offset + register * REGISTER_SIZE + component * SCALAR_SIZE,
+                            scalar: ScalarType::Float,
+                        });
+                    }
+                }
+            }
+            HlslType::Array(element, length) => {
+                let stride: u32 = HlslType::array_stride(element);
+                for i in 0..*length {
+                    element.leaves(&format!("{}[{}]", name, i), offset + i * stride, out);
+                }
+            }
+            HlslType::Struct(members) => {
+                let (offsets, _) = HlslType::member_offsets(members);
+                for ((member, ty), member_offset) in members.iter().zip(offsets) {
+                    ty.leaves(&member_name(name, member), offset + member_offset, out);
+                }
+            }
+        }
+    }
+}
+
+impl fmt::Display for HlslType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            HlslType::Scalar(scalar) => write!(f, "{}", scalar.name()),
+            HlslType::Vector(scalar, components) => write!(f, "{}{}", scalar.name(), components),
+            HlslType::Matrix {
+                rows,
+                columns,
+                row_major,
+            } => {
+                if *row_major {
+                    write!(f, "row_major ")?;
+                }
+                write!(f, "float{}x{}", rows, columns)
+            }
+            HlslType::Array(element, length) => write!(f, "{}[{}]", element, length),
+            HlslType::Struct(members) => {
+                write!(f, "struct {{ ")?;
+                for (name, ty) in members {
+                    write!(f, "{} {}; ", ty, name)?;
+                }
+                write!(f, "}}")
+            }
+        };
+    }
+}
+
+/// The name of a member of a struct, members of the cbuffer itself have no parent
+pub fn member_name(parent: &str, member: &str) -> String {
+    return match parent.is_empty() {
+        true => member.to_string(),
+        false => format!("{}.{}", parent, member),
+    };
+}
+
+fn component_name(vector: &str, component: u32) -> String {
+    return format!("{}.{}", vector, ["x", "y", "z", "w"][component as usize]);
+}
+
+/// A single scalar in a constant buffer or in the memory of a Rust value, like `lights[1].color.z`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Leaf {
+    pub name: String,
+    /// Offset from the start of the buffer in bytes
+    pub offset: u32,
+    pub scalar: ScalarType,
+}
+
+/// A variable that is declared in the cbuffer
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Member {
+    pub name: String,
+    pub ty: HlslType,
+    pub offset: u32,
+    pub size: u32,
+}
+
+/// Bytes that no variable uses
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Padding {
+    pub offset: u32,
+    pub size: u32,
+}
+
+/// Where HLSL puts the variables of a `cbuffer`. Declare the members in the order of the shader with
+/// [`CbufferLayout::new()`], or get them from a Rust type with [`CbufferLayout::of()`], see
+/// [`crate::cbuffer_struct!`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CbufferLayout {
+    pub members: Vec<Member>,
+    pub leaves: Vec<Leaf>,
+    /// Size of the buffer in bytes, a multiple of 16 like D3D11 needs
+    pub size: u32,
+}
+
+impl CbufferLayout {
+    pub fn new(members: &[(&str, HlslType)]) -> CbufferLayout {
+        let mut layout: CbufferLayout = CbufferLayout {
+            members: vec![],
+            leaves: vec![],
+            size: 0,
+        };
+        let mut end: u32 = 0;
+        for (name, ty) in members {
+            let offset: u32 = ty.place(end);
+            end = offset + ty.size();
+            ty.leaves(name, offset, &mut layout.leaves);
+            layout.members.push(Member {
+                name: name.to_string(),
+                ty: ty.clone(),
+                offset,
+                size: ty.size(),
+            });
+        }
+        layout.size = end.next_multiple_of(REGISTER_SIZE);
+        return layout;
+    }
+
+    /// The layout of a cbuffer with the members of `T`. A `T` that isn't a struct is the only member.
+    pub fn of<T: HlslValue>() -> CbufferLayout {
+        return match T::hlsl_type() {
+            HlslType::Struct(members) => CbufferLayout::new(
+                &members
+                    .iter()
+                    .map(|(name, ty)| (name.as_str(), ty.clone()))
+                    .collect::<Vec<(&str, HlslType)>>(),
+            ),
+            ty => CbufferLayout::new(&[("value", ty)]),
+        };
+    }
+
+    /// The bytes that no scalar uses, between the variables and at the end
+    pub fn padding(&self) -> Vec<Padding> {
+        let mut used: Vec<bool> = vec![false; (self.size / SCALAR_SIZE) as usize];
+        for leaf in &self.leaves {
+            used[(leaf.offset / SCALAR_SIZE) as usize] = true;
+        }
+
+        let mut padding: Vec<Padding> = vec![];
+        for (slot, used) in used.into_iter().enumerate() {
+            if used {
+                continue;
+            }
+            let offset: u32 = slot as u32 * SCALAR_SIZE;
+            match padding.last_mut() {
+                Some(last) if last.offset + last.size == offset => last.size += SCALAR_SIZE,
+                _ => padding.push(Padding {
+                    offset,
+                    size: SCALAR_SIZE,
+                }),
+            }
+        }
+        return padding;
+    }
+
+    /// Check that `T` has the same scalars at the same offsets as the cbuffer, so packing it fills the
+    /// cbuffer. The names don't have to match, a struct of 4 floats is the same as a `float4`.
+    pub fn validate<T: HlslValue>(&self) -> Result<(), String> {
+        let other: CbufferLayout = CbufferLayout::of::<T>();
+        return self.compare(&other.leaves, other.size, std::any::type_name::<T>());
+    }
+
+    /// Check that the memory of `T` already is the cbuffer, so it can be uploaded as it is without
+    /// packing. This needs a `#[repr(C)]` `T` that has the HLSL padding as fields.
+    pub fn validate_memory<T: HlslValue>(&self) -> Result<(), String> {
+        self.validate::<T>()?;
+        let mut leaves: Vec<Leaf> = vec![];
+        T::rust_leaves("", 0, &mut leaves);
+        return self.compare(
+            &leaves,
+            std::mem::size_of::<T>() as u32,
+            &format!("the memory of {}", std::any::type_name::<T>()),
+        );
+    }
+
+    fn compare(&self, leaves: &[Leaf], size: u32, what: &str) -> Result<(), String> {
+        for (expected, actual) in self.leaves.iter().zip(leaves) {
+            if expected.offset != actual.offset || expected.scalar != actual.scalar {
+                return Err(format!(
+                    "{} has a {} {} at byte {}, but the cbuffer has a {} {} at byte {}",
+                    what,
+                    actual.scalar.name(),
+                    actual.name,
+                    actual.offset,
+                    expected.scalar.name(),
+                    expected.name,
+                    expected.offset
+                ));
+            }
+        }
+        if self.leaves.len() != leaves.len() {
+            return Err(format!(
+                "{} has {} scalars, but the cbuffer has {}",
+                what,
+                leaves.len(),
+                self.leaves.len()
+            ));
+        }
+        if size != self.size {
+            return Err(format!(
+                "{} is {} bytes, but the cbuffer is {} bytes",
+                what, size, self.size
+            ));
+        }
+        return Ok(());
+    }
+
+    /// The bytes of the cbuffer with `value` in it, the padding is zero
+    pub fn pack<T: HlslValue>(&self, value: &T) -> Result<Vec<u8>, String> {
+        self.validate::<T>()?;
+
+        let mut scalars: Vec<[u8; 4]> = Vec::with_capacity(self.leaves.len());
+        value.write_scalars(&mut scalars);
+        let mut bytes: Vec<u8> = vec![0; self.size as usize];
+        for (leaf, scalar) in self.leaves.iter().zip(scalars) {
+            let offset: usize = leaf.offset as usize;
+            bytes[offset..offset + SCALAR_SIZE as usize].copy_from_slice(&scalar);
+        }
+        return Ok(bytes);
+    }
+}
+
+impl fmt::Display for CbufferLayout {
+    /// Every variable and every gap with its offset and size, like the output of `fxc`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "cbuffer, {} bytes", self.size)?;
+        for member in &self.members {
+            writeln!(
+                f,
+                "  {} {}: offset {}, size {}",
+                member.ty, member.name, member.offset, member.size
+            )?;
+        }
+        for padding in self.padding() {
+            writeln!(
+                f,
+                "  padding: offset {}, size {}",
+                padding.offset, padding.size
+            )?;
+        }
+        return Ok(());
+    }
+}
+
+/// A Rust value that can be put in a constant buffer
+pub trait HlslValue {
+    fn hlsl_type() -> HlslType;
+    /// Where the scalars are in the memory of the Rust value at `offset`, in the order of
+    /// [`HlslType::leaves()`]
+    fn rust_leaves(name: &str, offset: u32, out: &mut Vec<Leaf>);
+    /// The scalars as the bytes the shader reads, in the order of [`HlslType::leaves()`]
+    fn write_scalars(&self, out: &mut Vec<[u8; 4]>);
+}
+
+/// Arrays of 2 to 4 scalars are vectors, use [`Array`] for an HLSL array. Other lengths have no
+/// [`HlslValue`], so they don't compile.
+macro_rules! hlsl_vector {
+    ($ty:ty, $scalar:expr, $components:literal) => {
+        impl HlslValue for [$ty; $components] {
+            fn hlsl_type() -> HlslType {
+                return HlslType::Vector($scalar, $components);
+            }
+
+            fn rust_leaves(name: &str, offset: u32, out: &mut Vec<Leaf>) {
+                for component in 0..$components {
+                    out.push(Leaf {
+                        name: component_name(name, component),
+                        offset: offset + component * SCALAR_SIZE,
+                        scalar: $scalar,
+                    });
+                }
+            }
+
+            fn write_scalars(&self, out: &mut Vec<[u8; 4]>) {
+                out.extend(self.iter().map(|value| value.to_le_bytes()));
+            }
+        }
+    };
+}
+
+macro_rules! hlsl_scalar {
+    ($ty:ty, $scalar:expr) => {
+        impl HlslValue for $ty {
+            fn hlsl_type() -> HlslType {
+                return HlslType::Scalar($scalar);
+            }
+
+            fn rust_leaves(name: &str, offset: u32, out: &mut Vec<Leaf>) {
+                out.push(Leaf {
+                    name: name.to_string(),
+                    offset,
+                    scalar: $scalar,
+                });
+            }
+
+            fn write_scalars(&self, out: &mut Vec<[u8; 4]>) {
+                out.push(self.to_le_bytes());
+            }
+        }
+
+        hlsl_vector!($ty, $scalar, 2);
+        hlsl_vector!($ty, $scalar, 3);
+        hlsl_vector!($ty, $scalar, 4);
+    };
+}
+
+hlsl_scalar!(f32, ScalarType::Float);
+hlsl_scalar!(i32, ScalarType::Int);
+hlsl_scalar!(u32, ScalarType::Uint);
+
+/// An HLSL `bool`, which is 4 bytes unlike a Rust `bool`
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+#[repr(transparent)]
+pub struct Bool(pub u32);
+
+unsafe impl Pod for Bool {}
+
+impl From<bool> for Bool {
+    fn from(value: bool) -> Bool {
+        return Bool(value as u32);
+    }
+}
+
+impl HlslValue for Bool {
+    fn hlsl_type() -> HlslType {
+        return HlslType::Scalar(ScalarType::Bool);
+    }
+
+    fn rust_leaves(name: &str, offset: u32, out: &mut Vec<Leaf>) {
+        out.push(Leaf {
+            name: name.to_string(),
+            offset,
+            scalar: ScalarType::Bool,
+        });
+    }
+
+    fn write_scalars(&self, out: &mut Vec<[u8; 4]>) {
+        out.push(self.0.to_le_bytes());
+    }
+}
+
+/// A `floatRxC` with its rows, in the cbuffer it is column major like `matrix`. Packing it transposes it,
+/// so the rows can be used as they are.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[repr(transparent)]
+pub struct Matrix<const R: usize, const C: usize>(pub [[f32; C]; R]);
+
+unsafe impl<const R: usize, const C: usize> Pod for Matrix<R, C> {}
+
+/// A `row_major floatRxC` with its rows
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[repr(transparent)]
+pub struct RowMajorMatrix<const R: usize, const C: usize>(pub [[f32; C]; R]);
+
+unsafe impl<const R: usize, const C: usize> Pod for RowMajorMatrix<R, C> {}
+
+impl From<XMFLOAT4X4> for Matrix<4, 4> {
+    fn from(matrix: XMFLOAT4X4) -> Matrix<4, 4> {
+        return Matrix(matrix.m);
+    }
+}
+
+macro_rules! hlsl_matrix {
+    ($ty:ident, $row_major:expr) => {
+        impl<const R: usize, const C: usize> HlslValue for $ty<R, C> {
+            fn hlsl_type() -> HlslType {
+                return HlslType::Matrix {
+                    rows: R as u32,
+                    columns: C as u32,
+                    row_major: $row_major,
+                };
+            }
+
+            fn rust_leaves(name: &str, offset: u32, out: &mut Vec<Leaf>) {
+                for row in 0..R as u32 {
+                    for column in 0..C as u32 {
+                        out.push(Leaf {
+                            name: format!("{}._m{}{}", name, row, column),
+                            offset: offset + (row * C as u32 + column) * SCALAR_SIZE,
+                            scalar: ScalarType::Float,
+                        });
+                    }
+                }
+            }
+
+            fn write_scalars(&self, out: &mut Vec<[u8; 4]>) {
+                for row in &self.0 {
+                    out.extend(row.iter().map(|value| value.to_le_bytes()));
+                }
+            }
+        }
+    };
+}
+
+hlsl_matrix!(Matrix, false);
+hlsl_matrix!(RowMajorMatrix, true);
+
+/// An HLSL array like `float4 colors[6]`, every element starts at a new register
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[repr(transparent)]
+pub struct Array<T, const N: usize>(pub [T; N]);
+
+unsafe impl<T: Pod, const N: usize> Pod for Array<T, N> {}
+
+impl<T: HlslValue, const N: usize> HlslValue for Array<T, N> {
+    fn hlsl_type() -> HlslType {
+        return HlslType::array(T::hlsl_type(), N as u32);
+    }
+
+    fn rust_leaves(name: &str, offset: u32, out: &mut Vec<Leaf>) {
+        for i in 0..N {
+            T::rust_leaves(
+                &format!("{}[{}]", name, i),
+                offset + (i * std::mem::size_of::<T>()) as u32,
+                out,
+            );
+        }
+    }
+
+    fn write_scalars(&self, out: &mut Vec<[u8; 4]>) {
+        for element in &self.0 {
+            element.write_scalars(out);
+        }
+    }
+}
+
+/// Declare a `#[repr(C)]` struct that can be put in a constant buffer. Every field has to be an
+/// [`HlslValue`], the struct is an HLSL struct with the same members. Its bytes for the shader come from
+/// [`CbufferLayout::pack()`].
+#[macro_export]
+macro_rules! cbuffer_struct {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident {
+            $($field_vis:vis $field:ident : $ty:ty),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Copy, Clone, PartialEq)]
+        #[repr(C)]
+        $vis struct $name {
+            $($field_vis $field: $ty),*
+        }
+
+        impl $crate::window::graphics::bindable::cbufferlayout::HlslValue for $name {
+            fn hlsl_type() -> $crate::window::graphics::bindable::cbufferlayout::HlslType {
+                return $crate::window::graphics::bindable::cbufferlayout::HlslType::Struct(vec![
+                    $((
+                        stringify!($field).to_string(),
+                        <$ty as $crate::window::graphics::bindable::cbufferlayout::HlslValue>::hlsl_type(),
+                    )),*
+                ]);
+            }
+
+            fn rust_leaves(
+                name: &str,
+                offset: u32,
+                out: &mut Vec<$crate::window::graphics::bindable::cbufferlayout::Leaf>,
+            ) {
+                $(<$ty as $crate::window::graphics::bindable::cbufferlayout::HlslValue>::rust_leaves(
+                    &$crate::window::graphics::bindable::cbufferlayout::member_name(name, stringify!($field)),
+                    offset + std::mem::offset_of!($name, $field) as u32,
+                    out,
+                );)*
+            }
+
+            fn write_scalars(&self, out: &mut Vec<[u8; 4]>) {
+                $($crate::window::graphics::bindable::cbufferlayout::HlslValue::write_scalars(&self.$field, out);)*
+            }
+        }
+
+        // Padding between the fields would be bytes that aren't initialized
+        const _: () = assert!(
+            std::mem::size_of::<$name>() == 0 $(+ std::mem::size_of::<$ty>())*,
+            "A cbuffer_struct can't have padding between its fields"
+        );
+        unsafe impl $crate::window::graphics::bindable::Pod for $name
+        where
+            $($ty: $crate::window::graphics::bindable::Pod),*
+        {}
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn float() -> HlslType {
+        return HlslType::Scalar(ScalarType::Float);
+    }
+
+    fn matrix(rows: u32, columns: u32, row_major: bool) -> HlslType {
+        return HlslType::Matrix {
+            rows,
+            columns,
+            row_major,
+        };
+    }
+
+    /// The offset of every member
+    fn offsets(layout: &CbufferLayout) -> Vec<u32> {
+        return layout.members.iter().map(|member| member.offset).collect();
+    }
+
+    #[test]
+    fn a_float_is_packed_after_a_float3() {
+        // cbuffer CBuf { float3 direction; float intensity; matrix transform; }
+        let layout: CbufferLayout = CbufferLayout::new(&[
+            ("direction", HlslType::float_n(3)),
+            ("intensity", float()),
+            ("transform", matrix(4, 4, false)),
+        ]);
+        assert_eq!(offsets(&layout), vec![0, 12, 16]);
+        assert_eq!(layout.size, 80);
+        assert!(layout.padding().is_empty());
+    }
+
+    #[test]
+    fn vectors_dont_cross_registers() {
+        // cbuffer CBuf { float2 uv; float3 normal; float scale; }
+        let layout: CbufferLayout = CbufferLayout::new(&[
+            ("uv", HlslType::float_n(2)),
+            ("normal", HlslType::float_n(3)),
+            ("scale", float()),
+        ]);
+        assert_eq!(offsets(&layout), vec![0, 16, 28]);
+        assert_eq!(layout.size, 32);
+        assert_eq!(layout.padding(), vec![Padding { offset: 8, size: 8 }]);
+    }
+
+    #[test]
+    fn array_elements_start_at_a_new_register() {
+        // cbuffer CBuf { float weights[3]; float bias; }
+        let layout: CbufferLayout =
+            CbufferLayout::new(&[("weights", HlslType::array(float(), 3)), ("bias", float())]);
+        assert_eq!(layout.members[0].size, 36);
+        assert_eq!(offsets(&layout), vec![0, 36]);
+        assert_eq!(layout.size, 48);
+
+        let weights: Vec<u32> = layout.leaves[..3].iter().map(|leaf| leaf.offset).collect();
+        assert_eq!(weights, vec![0, 16, 32]);
+        assert_eq!(layout.leaves[1].name, "weights[1]");
+    }
+
+    #[test]
+    fn a_float3x3_is_44_bytes() {
+        for row_major in [false, true] {
+            let layout: CbufferLayout =
+                CbufferLayout::new(&[("rotation", matrix(3, 3, row_major)), ("scale", float())]);
+            assert_eq!(layout.members[0].size, 44);
+            assert_eq!(offsets(&layout), vec![0, 44]);
+            assert_eq!(layout.size, 48);
+        }
+
+        // A column major float3x2 is 2 registers of 3 components, a row major one 3 registers of 2
+        assert_eq!(matrix(3, 2, false).size(), 28);
+        assert_eq!(matrix(3, 2, true).size(), 40);
+    }
+
+    #[test]
+    fn column_major_matrices_are_stored_by_column() {
+        let layout: CbufferLayout = CbufferLayout::new(&[("m", matrix(2, 2, false))]);
+        let leaves: Vec<(&str, u32)> = layout
+            .leaves
+            .iter()
+            .map(|leaf| (leaf.name.as_str(), leaf.offset))
+            .collect();
+        assert_eq!(
+            leaves,
+            vec![("m._m00", 0), ("m._m01", 16), ("m._m10", 4), ("m._m11", 20)]
+        );
+    }
+
+    cbuffer_struct! {
+        struct Rotation {
+            rotation: RowMajorMatrix<2, 2>,
+        }
+    }
+
+    #[test]
+    fn row_major_matrices_are_packed_by_row() {
+        let layout: CbufferLayout = CbufferLayout::of::<Rotation>();
+        assert_eq!(layout.members[0].ty, matrix(2, 2, true));
+
+        let bytes: Vec<u8> = layout
+            .pack(&Rotation {
+                rotation: RowMajorMatrix([[1.0, 2.0], [3.0, 4.0]]),
+            })
+            .unwrap();
+        let float_at =
+            |offset: usize| f32::from_le_bytes(bytes[offset..offset + 4].try_into().unwrap());
+        assert_eq!(bytes.len(), 32);
+        assert_eq!([float_at(0), float_at(4)], [1.0, 2.0]);
+        assert_eq!([float_at(16), float_at(20)], [3.0, 4.0]);
+    }
+
+    #[test]
+    fn a_struct_starts_at_a_new_register() {
+        // struct Light { float3 color; }; cbuffer CBuf { float intensity; Light light; }
+        let light: HlslType = HlslType::Struct(vec![("color".to_string(), HlslType::float_n(3))]);
+        let layout: CbufferLayout = CbufferLayout::new(&[("intensity", float()), ("light", light)]);
+        assert_eq!(offsets(&layout), vec![0, 16]);
+        assert_eq!(layout.members[1].size, 12);
+        assert_eq!(layout.size, 32);
+        assert_eq!(layout.leaves[1].name, "light.color.x");
+    }
+
+    cbuffer_struct! {
+        struct Light {
+            color: [f32; 3],
+            intensity: f32,
+        }
+    }
+
+    cbuffer_struct! {
+        struct Lights {
+            ambient: f32,
+            lights: Array<Light, 2>,
+            enabled: Bool,
+        }
+    }
+
+    #[test]
+    fn of_follows_the_rust_struct() {
+        let layout: CbufferLayout = CbufferLayout::of::<Lights>();
+        assert_eq!(offsets(&layout), vec![0, 16, 48]);
+        assert_eq!(layout.size, 64);
+        assert_eq!(
+            layout.members[1].ty,
+            HlslType::array(
+                HlslType::Struct(vec![
+                    ("color".to_string(), HlslType::float_n(3)),
+                    ("intensity".to_string(), float()),
+                ]),
+                2
+            )
+        );
+        assert_eq!(layout.to_string().lines().next(), Some("cbuffer, 64 bytes"));
+    }
+
+    #[test]
+    fn pack_puts_every_scalar_at_its_offset() {
+        let value: Lights = Lights {
+            ambient: 0.25,
+            lights: Array([
+                Light {
+                    color: [1.0, 2.0, 3.0],
+                    intensity: 4.0,
+                },
+                Light {
+                    color: [5.0, 6.0, 7.0],
+                    intensity: 8.0,
+                },
+            ]),
+            enabled: Bool::from(true),
+        };
+        let bytes: Vec<u8> = CbufferLayout::of::<Lights>().pack(&value).unwrap();
+        let float_at =
+            |offset: usize| f32::from_le_bytes(bytes[offset..offset + 4].try_into().unwrap());
+
+        assert_eq!(bytes.len(), 64);
+        assert_eq!(float_at(0), 0.25);
+        // The padding after ambient is zero
+        assert_eq!(bytes[4..16], [0; 12]);
+        assert_eq!(float_at(16 + 8), 3.0);
+        assert_eq!(float_at(32 + 12), 8.0);
+        assert_eq!(bytes[48..52], 1u32.to_le_bytes());
+    }
+
+    #[test]
+    fn validate_compares_the_scalars() {
+        let float4: CbufferLayout = CbufferLayout::new(&[("color", HlslType::float_n(4))]);
+        // A struct of 4 floats is the same as a float4
+        assert!(float4.validate::<Light>().is_ok());
+        assert!(float4.validate::<[f32; 4]>().is_ok());
+
+        let error: String = float4.validate::<[u32; 4]>().unwrap_err();
+        assert!(error.contains("uint"), "{}", error);
+        assert!(float4.validate::<[f32; 3]>().is_err());
+        assert!(CbufferLayout::of::<Lights>().validate::<Light>().is_err());
+    }
+}
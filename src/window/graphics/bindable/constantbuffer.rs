@@ -0,0 +1,216 @@
+use std::marker::PhantomData;
+
+use windows::Win32::Foundation::E_INVALIDARG;
+
+use super::{
+    as_bytes,
+    cbufferlayout::{CbufferLayout, HlslValue},
+    Bindable, Pod,
+};
+use crate::window::{
+    errors::Error,
+    graphics::backend::{BufferDesc, BufferId, BufferKind, RenderBackend, Resource, ShaderStage},
+};
+
+/// Constant buffers are read in registers of 16 bytes, so their size has to be a multiple of it
+pub const CONSTANT_BUFFER_ALIGNMENT: usize = 16;
+
+/// A constant buffer with a `T`, bound to a slot of the vertex or pixel shader. It can be updated every
+/// frame with [`ConstantBuffer::update()`].
+///
+/// [`ConstantBuffer::new()`] uploads the memory of `T` as it is, so `T` has to be `#[repr(C)]` and match
+/// the HLSL packing rules, which is checked. [`ConstantBuffer::packed()`] packs a [`HlslValue`] by those
+/// rules instead.
+pub struct ConstantBuffer<T> {
+    pub buffer: BufferId,
+    pub stage: ShaderStage,
+    pub slot: u32,
+    data: PhantomData<T>,
+}
+
+impl<T: Pod + HlslValue> ConstantBuffer<T> {
+    #[track_caller]
+    pub fn new(
+        backend: &mut dyn RenderBackend,
+        stage: ShaderStage,
+        slot: u32,
+        data: &T,
+    ) -> Result<ConstantBuffer<T>, Error> {
+        return Ok(ConstantBuffer {
+            buffer: backend.create_buffer(&ConstantBuffer::desc(data)?)?,
+            stage,
+            slot,
+            data: PhantomData,
+        });
+    }
+
+    /// The description of the buffer. Fails when `T` isn't a multiple of 16 bytes, D3D11 doesn't create
+    /// those, or when its memory isn't laid out like the cbuffer, see [`CbufferLayout::validate_memory()`].
+    #[track_caller]
+    pub fn desc(data: &T) -> Result<BufferDesc<'_>, Error> {
+        let size: usize = std::mem::size_of::<T>();
+        if size == 0 || !size.is_multiple_of(CONSTANT_BUFFER_ALIGNMENT) {
+            return Err(Error::hresult_code(
+                E_INVALIDARG,
+                &format!(
+                    "The constant buffer {} is {} bytes, it has to be a multiple of {} bytes",
+                    std::any::type_name::<T>(),
+                    size,
+                    CONSTANT_BUFFER_ALIGNMENT
+                ),
+            ));
+        }
+
+        CbufferLayout::of::<T>()
+            .validate_memory::<T>()
+            .map_err(|e| Error::hresult_code(E_INVALIDARG, &e))?;
+
+        return Ok(BufferDesc {
+            kind: BufferKind::Constant,
+            data: as_bytes(std::slice::from_ref(data)),
+            dynamic: true,
+        });
+    }
+
+    /// Replace the contents of the buffer
+    pub fn update(&self, backend: &mut dyn RenderBackend, data: &T) -> Result<(), Error> {
+        return backend.update_buffer(self.buffer, as_bytes(std::slice::from_ref(data)));
+    }
+}
+
+impl<T: HlslValue> ConstantBuffer<T> {
+    /// A constant buffer with `data` packed like HLSL lays out a cbuffer with the members of `T`
+    #[track_caller]
+    pub fn packed(
+        backend: &mut dyn RenderBackend,
+        stage: ShaderStage,
+        slot: u32,
+        data: &T,
+    ) -> Result<ConstantBuffer<T>, Error> {
+        let bytes: Vec<u8> = ConstantBuffer::pack(data)?;
+        return Ok(ConstantBuffer {
+            buffer: backend.create_buffer(&BufferDesc {
+                kind: BufferKind::Constant,
+                data: &bytes,
+                dynamic: true,
+            })?,
+            stage,
+            slot,
+            data: PhantomData,
+        });
+    }
+
+    /// Replace the contents of the buffer with `data` packed like [`ConstantBuffer::packed()`] does
+    pub fn update_packed(&self, backend: &mut dyn RenderBackend, data: &T) -> Result<(), Error> {
+        return backend.update_buffer(self.buffer, &ConstantBuffer::pack(data)?);
+    }
+
+    #[track_caller]
+    fn pack(data: &T) -> Result<Vec<u8>, Error> {
+        return CbufferLayout::of::<T>()
+            .pack(data)
+            .map_err(|e| Error::hresult_code(E_INVALIDARG, &e));
+    }
+}
+
+impl<T> Bindable for ConstantBuffer<T> {
+    fn bind(&self, backend: &mut dyn RenderBackend) -> Result<(), Error> {
+        return backend.set_constant_buffer(self.stage, self.slot, self.buffer);
+    }
+
+    fn release(&self, backend: &mut dyn RenderBackend) {
+        backend.release(Resource::Buffer(self.buffer));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        cbuffer_struct,
+        window::graphics::backend::recording::{Call, RecordingBackend},
+    };
+
+    cbuffer_struct! {
+        /// `cbuffer Light { float4 color; float intensity; float3 padding; }`
+        struct Light {
+            color: [f32; 4],
+            intensity: f32,
+            padding: [f32; 3],
+        }
+    }
+
+    cbuffer_struct! {
+        /// HLSL moves `color` to the next register, the Rust struct doesn't
+        struct Unaligned {
+            intensity: f32,
+            color: [f32; 4],
+            padding: [f32; 3],
+        }
+    }
+
+    cbuffer_struct! {
+        struct Small {
+            intensity: f32,
+        }
+    }
+
+    const LIGHT: Light = Light {
+        color: [1.0, 0.5, 0.25, 1.0],
+        intensity: 2.0,
+        padding: [0.0; 3],
+    };
+
+    #[test]
+    fn desc_uploads_the_memory_as_it_is() {
+        let desc: BufferDesc = ConstantBuffer::desc(&LIGHT).unwrap();
+        assert_eq!(desc.kind, BufferKind::Constant);
+        assert!(desc.dynamic);
+        assert_eq!(desc.data.len(), 32);
+        assert_eq!(desc.data[16..20], 2.0f32.to_le_bytes());
+        assert_eq!(
+            desc.data,
+            CbufferLayout::of::<Light>().pack(&LIGHT).unwrap()
+        );
+    }
+
+    #[test]
+    fn desc_refuses_memory_that_isnt_the_cbuffer() {
+        let small: Error = ConstantBuffer::desc(&Small { intensity: 1.0 }).unwrap_err();
+        assert!(small.to_string().contains("multiple of 16"), "{}", small);
+
+        let unaligned: Error = ConstantBuffer::desc(&Unaligned {
+            intensity: 1.0,
+            color: [0.0; 4],
+            padding: [0.0; 3],
+        })
+        .unwrap_err();
+        assert!(unaligned.to_string().contains("color"), "{}", unaligned);
+    }
+
+    #[test]
+    fn update_and_bind_use_the_slot_of_the_stage() {
+        let mut backend: RecordingBackend = RecordingBackend::new();
+        let buffer: ConstantBuffer<Light> =
+            ConstantBuffer::new(&mut backend, ShaderStage::Pixel, 2, &LIGHT).unwrap();
+        buffer.update(&mut backend, &LIGHT).unwrap();
+        buffer.bind(&mut backend).unwrap();
+        buffer.release(&mut backend);
+
+        let bytes: Vec<u8> = as_bytes(std::slice::from_ref(&LIGHT)).to_vec();
+        assert_eq!(
+            backend.calls,
+            vec![
+                Call::CreateBuffer {
+                    id: buffer.buffer,
+                    kind: BufferKind::Constant,
+                    size: 32,
+                    dynamic: true
+                },
+                Call::UpdateBuffer(buffer.buffer, bytes),
+                Call::SetConstantBuffer(ShaderStage::Pixel, 2, buffer.buffer),
+                Call::Release(Resource::Buffer(buffer.buffer)),
+            ]
+        );
+    }
+}
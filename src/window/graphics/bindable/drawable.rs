@@ -0,0 +1,38 @@
+use super::{cache::Shared, indexbuffer::IndexBuffer, Bindable};
+use crate::window::{errors::Error, graphics::backend::RenderBackend};
+
+/// Everything that is needed to draw something: the bindables that are set before the draw call and the
+/// amount of indices that are drawn.
+pub struct Drawable {
+    pub bindables: Vec<Box<dyn Bindable>>,
+    pub index_count: u32,
+}
+
+impl Drawable {
+    /// A drawable that draws all indices of an `index_buffer` from a [`super::cache::ResourceCache`]
+    pub fn new_shared(index_buffer: Shared<IndexBuffer>) -> Drawable {
+        return Drawable {
+            index_count: index_buffer.count,
+            bindables: vec![Box::new(index_buffer)],
+        };
+    }
+
+    pub fn add(&mut self, bindable: impl Bindable + 'static) {
+        self.bindables.push(Box::new(bindable));
+    }
+
+    /// Bind everything in the order it was added and draw
+    pub fn draw(&self, backend: &mut dyn RenderBackend) -> Result<(), Error> {
+        for bindable in &self.bindables {
+            bindable.bind(backend)?;
+        }
+        return backend.draw_indexed(self.index_count, 0, 0);
+    }
+
+    /// Free the resources of all bindables
+    pub fn release(self, backend: &mut dyn RenderBackend) {
+        for bindable in &self.bindables {
+            bindable.release(backend);
+        }
+    }
+}
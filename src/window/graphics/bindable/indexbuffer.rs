@@ -0,0 +1,62 @@
+use super::{
+    as_bytes,
+    cache::{ResourceCache, Shared},
+    Bindable, Pod,
+};
+use crate::window::{
+    errors::Error,
+    graphics::backend::{BufferDesc, BufferId, BufferKind, IndexFormat, RenderBackend, Resource},
+};
+
+/// A type that can be used as an index, `u16` or `u32`
+pub trait Index: Pod {
+    const FORMAT: IndexFormat;
+}
+
+impl Index for u16 {
+    const FORMAT: IndexFormat = IndexFormat::U16;
+}
+
+impl Index for u32 {
+    const FORMAT: IndexFormat = IndexFormat::U32;
+}
+
+pub struct IndexBuffer {
+    pub buffer: BufferId,
+    /// Amount of indices in the buffer
+    pub count: u32,
+    pub format: IndexFormat,
+}
+
+impl IndexBuffer {
+    /// The buffer from the cache, indices that were uploaded before aren't uploaded again
+    pub fn cached<I: Index>(
+        backend: &mut dyn RenderBackend,
+        cache: &mut ResourceCache,
+        indices: &[I],
+    ) -> Result<Shared<IndexBuffer>, Error> {
+        return Ok(Shared(IndexBuffer {
+            buffer: cache.buffer(backend, &IndexBuffer::desc(indices))?,
+            count: indices.len() as u32,
+            format: I::FORMAT,
+        }));
+    }
+
+    pub fn desc<I: Index>(indices: &[I]) -> BufferDesc<'_> {
+        return BufferDesc {
+            kind: BufferKind::Index,
+            data: as_bytes(indices),
+            dynamic: false,
+        };
+    }
+}
+
+impl Bindable for IndexBuffer {
+    fn bind(&self, backend: &mut dyn RenderBackend) -> Result<(), Error> {
+        return backend.set_index_buffer(self.buffer, self.format, 0);
+    }
+
+    fn release(&self, backend: &mut dyn RenderBackend) {
+        backend.release(Resource::Buffer(self.buffer));
+    }
+}
@@ -0,0 +1,41 @@
+use super::{
+    cache::{ResourceCache, Shared},
+    shader::VertexShader,
+    vertexlayout::VertexLayout,
+    Bindable,
+};
+use crate::window::{
+    errors::Error,
+    graphics::backend::{InputElement, LayoutId, RenderBackend, Resource},
+};
+
+/// How the input assembler reads the vertices of a vertex buffer
+pub struct InputLayout {
+    pub layout: LayoutId,
+}
+
+impl InputLayout {
+    /// The layout for the input signature of `vertex_shader` from the cache, there is one for every
+    /// layout and vertex shader
+    pub fn cached(
+        backend: &mut dyn RenderBackend,
+        cache: &mut ResourceCache,
+        layout: &VertexLayout,
+        vertex_shader: &VertexShader,
+    ) -> Result<Shared<InputLayout>, Error> {
+        let elements: Vec<InputElement> = layout.input_elements();
+        return Ok(Shared(InputLayout {
+            layout: cache.input_layout(backend, &elements, vertex_shader.shader)?,
+        }));
+    }
+}
+
+impl Bindable for InputLayout {
+    fn bind(&self, backend: &mut dyn RenderBackend) -> Result<(), Error> {
+        return backend.set_input_layout(self.layout);
+    }
+
+    fn release(&self, backend: &mut dyn RenderBackend) {
+        backend.release(Resource::Layout(self.layout));
+    }
+}
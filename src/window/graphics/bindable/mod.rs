@@ -1 +1,50 @@
-pub mod vertexbuffer;
\ No newline at end of file
+use super::backend::RenderBackend;
+use crate::window::errors::Error;
+
+pub mod cache;
+pub mod cbufferlayout;
+pub mod constantbuffer;
+pub mod drawable;
+pub mod indexbuffer;
+pub mod inputlayout;
+pub mod shader;
+pub mod state;
+pub mod topology;
+pub mod vertexbuffer;
+pub mod vertexlayout;
+pub mod viewport;
+
+/// Something that is set on the pipeline before a draw call, like a vertex buffer or a shader. The
+/// resources are created by the constructors, binding only sets them. See [`drawable::Drawable`].
+///
+/// The buffers also have a `desc()` that builds their description without a [`RenderBackend`], so what
+/// is uploaded can be checked without a device.
+pub trait Bindable {
+    fn bind(&self, backend: &mut dyn RenderBackend) -> Result<(), Error>;
+    /// Free the resources that were created for the bindable
+    fn release(&self, backend: &mut dyn RenderBackend);
+}
+
+/// Plain data that can be viewed as bytes, see [`as_bytes()`]
+///
+/// # Safety
+///
+/// Every byte of the type has to be initialized, so it can't have padding, and it can't have pointers
+/// or references. [`crate::vertex_struct!`] and [`crate::cbuffer_struct!`] implement it for their structs
+/// and fail to compile when a struct has padding.
+pub unsafe trait Pod: Copy + 'static {}
+
+unsafe impl Pod for u8 {}
+unsafe impl Pod for u16 {}
+unsafe impl Pod for u32 {}
+unsafe impl Pod for i32 {}
+unsafe impl Pod for f32 {}
+unsafe impl<T: Pod, const N: usize> Pod for [T; N] {}
+
+/// View a slice of plain data as the bytes that are uploaded to a buffer
+pub fn as_bytes<T: Pod>(data: &[T]) -> &[u8] {
+    // Every byte of a `Pod` is initialized
+    return unsafe {
+        std::slice::from_raw_parts(data.as_ptr().cast(), std::mem::size_of_val(data))
+    };
+}
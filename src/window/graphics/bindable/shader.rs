@@ -0,0 +1,63 @@
+use super::{
+    cache::{ResourceCache, Shared},
+    Bindable,
+};
+use crate::window::{
+    errors::Error,
+    graphics::backend::{RenderBackend, Resource, ShaderDesc, ShaderId},
+};
+
+/// A compiled vertex shader. The input layout is created for it, see [`super::inputlayout::InputLayout`].
+pub struct VertexShader {
+    pub shader: ShaderId,
+}
+
+impl VertexShader {
+    /// The shader from the cache, it is only compiled the first time
+    pub fn cached(
+        backend: &mut dyn RenderBackend,
+        cache: &mut ResourceCache,
+        desc: ShaderDesc,
+    ) -> Result<Shared<VertexShader>, Error> {
+        return Ok(Shared(VertexShader {
+            shader: cache.shader(backend, &desc)?,
+        }));
+    }
+}
+
+impl Bindable for VertexShader {
+    fn bind(&self, backend: &mut dyn RenderBackend) -> Result<(), Error> {
+        return backend.set_shader(self.shader);
+    }
+
+    fn release(&self, backend: &mut dyn RenderBackend) {
+        backend.release(Resource::Shader(self.shader));
+    }
+}
+
+pub struct PixelShader {
+    pub shader: ShaderId,
+}
+
+impl PixelShader {
+    /// The shader from the cache, it is only compiled the first time
+    pub fn cached(
+        backend: &mut dyn RenderBackend,
+        cache: &mut ResourceCache,
+        desc: ShaderDesc,
+    ) -> Result<Shared<PixelShader>, Error> {
+        return Ok(Shared(PixelShader {
+            shader: cache.shader(backend, &desc)?,
+        }));
+    }
+}
+
+impl Bindable for PixelShader {
+    fn bind(&self, backend: &mut dyn RenderBackend) -> Result<(), Error> {
+        return backend.set_shader(self.shader);
+    }
+
+    fn release(&self, backend: &mut dyn RenderBackend) {
+        backend.release(Resource::Shader(self.shader));
+    }
+}
@@ -0,0 +1,107 @@
+use super::Bindable;
+use crate::window::{
+    errors::Error,
+    graphics::backend::{DepthStencilDesc, RasterizerDesc, RenderBackend, Resource, StateId},
+};
+
+pub struct DepthStencilState {
+    pub state: StateId,
+    pub desc: DepthStencilDesc,
+}
+
+impl DepthStencilState {
+    pub fn new(
+        backend: &mut dyn RenderBackend,
+        desc: DepthStencilDesc,
+    ) -> Result<DepthStencilState, Error> {
+        return Ok(DepthStencilState {
+            state: backend.create_depth_stencil_state(&desc)?,
+            desc,
+        });
+    }
+}
+
+impl Bindable for DepthStencilState {
+    fn bind(&self, backend: &mut dyn RenderBackend) -> Result<(), Error> {
+        return backend.set_depth_stencil_state(self.state);
+    }
+
+    fn release(&self, backend: &mut dyn RenderBackend) {
+        backend.release(Resource::State(self.state));
+    }
+}
+
+pub struct RasterizerState {
+    pub state: StateId,
+    pub desc: RasterizerDesc,
+}
+
+impl RasterizerState {
+    pub fn new(
+        backend: &mut dyn RenderBackend,
+        desc: RasterizerDesc,
+    ) -> Result<RasterizerState, Error> {
+        return Ok(RasterizerState {
+            state: backend.create_rasterizer_state(&desc)?,
+            desc,
+        });
+    }
+}
+
+impl Bindable for RasterizerState {
+    fn bind(&self, backend: &mut dyn RenderBackend) -> Result<(), Error> {
+        return backend.set_rasterizer_state(self.state);
+    }
+
+    fn release(&self, backend: &mut dyn RenderBackend) {
+        backend.release(Resource::State(self.state));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::window::graphics::backend::{
+        recording::{Call, RecordingBackend},
+        Comparison, CullMode,
+    };
+
+    #[test]
+    fn states_are_created_then_bound_then_released() {
+        let mut backend: RecordingBackend = RecordingBackend::new();
+        let depth_stencil: DepthStencilState = DepthStencilState::new(
+            &mut backend,
+            DepthStencilDesc {
+                depth_func: Comparison::LessEqual,
+                ..DepthStencilDesc::default()
+            },
+        )
+        .unwrap();
+        let rasterizer: RasterizerState = RasterizerState::new(
+            &mut backend,
+            RasterizerDesc {
+                cull_mode: CullMode::None,
+                front_counter_clockwise: false,
+            },
+        )
+        .unwrap();
+        assert_eq!(depth_stencil.desc.depth_func, Comparison::LessEqual);
+        assert_eq!(rasterizer.desc.cull_mode, CullMode::None);
+
+        depth_stencil.bind(&mut backend).unwrap();
+        rasterizer.bind(&mut backend).unwrap();
+        depth_stencil.release(&mut backend);
+        rasterizer.release(&mut backend);
+        assert_eq!(
+            backend.calls,
+            vec![
+                Call::CreateDepthStencilState(depth_stencil.state),
+                Call::CreateRasterizerState(rasterizer.state),
+                Call::SetDepthStencilState(depth_stencil.state),
+                Call::SetRasterizerState(rasterizer.state),
+                Call::Release(Resource::State(depth_stencil.state)),
+                Call::Release(Resource::State(rasterizer.state)),
+            ]
+        );
+    }
+}
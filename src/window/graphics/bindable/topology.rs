@@ -0,0 +1,14 @@
+use super::Bindable;
+use crate::window::{
+    errors::Error,
+    graphics::backend::{RenderBackend, Topology},
+};
+
+impl Bindable for Topology {
+    fn bind(&self, backend: &mut dyn RenderBackend) -> Result<(), Error> {
+        backend.set_topology(*self);
+        return Ok(());
+    }
+
+    fn release(&self, _backend: &mut dyn RenderBackend) {}
+}
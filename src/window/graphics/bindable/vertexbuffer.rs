@@ -1,53 +1,110 @@
-use crate::loc;
-use crate::window::errors;
-use crate::window::graphics::FLOAT3;
-use windows::Win32::Graphics::Direct3D11::D3D11_BIND_VERTEX_BUFFER;
-use windows::Win32::Graphics::Direct3D11::D3D11_CPU_ACCESS_FLAG;
-use windows::Win32::Graphics::Direct3D11::D3D11_RESOURCE_MISC_FLAG;
-use windows::Win32::Graphics::Direct3D11::D3D11_USAGE_DEFAULT;
-use windows::Win32::Graphics::Direct3D11::ID3D11Buffer;
-use windows::Win32::Graphics::Direct3D11::D3D11_BUFFER_DESC;
-use windows::Win32::Graphics::Direct3D11::D3D11_SUBRESOURCE_DATA;
-
-pub struct VertexBuffer {
-    buffer: *mut Option<ID3D11Buffer>,
-    buff_desc: D3D11_BUFFER_DESC,
-    data: D3D11_SUBRESOURCE_DATA
+use std::marker::PhantomData;
+
+use super::{
+    as_bytes,
+    cache::{ResourceCache, Shared},
+    vertexlayout::VertexData,
+    Bindable, Pod,
+};
+use crate::window::{
+    errors::Error,
+    graphics::backend::{BufferDesc, BufferId, BufferKind, RenderBackend, Resource},
+};
+
+/// A buffer with vertices of type `V`, bound to input slot 0
+pub struct VertexBuffer<V> {
+    pub buffer: BufferId,
+    /// Size of a single vertex in bytes
+    pub stride: u32,
+    vertex: PhantomData<V>,
+}
+
+impl<V: Pod> VertexBuffer<V> {
+    /// The buffer from the cache, vertices that were uploaded before aren't uploaded again
+    pub fn cached(
+        backend: &mut dyn RenderBackend,
+        cache: &mut ResourceCache,
+        vertices: &[V],
+    ) -> Result<Shared<VertexBuffer<V>>, Error> {
+        return Ok(Shared(VertexBuffer {
+            buffer: cache.buffer(backend, &VertexBuffer::desc(vertices))?,
+            stride: std::mem::size_of::<V>() as u32,
+            vertex: PhantomData,
+        }));
+    }
+
+    pub fn desc(vertices: &[V]) -> BufferDesc<'_> {
+        return BufferDesc {
+            kind: BufferKind::Vertex,
+            data: as_bytes(vertices),
+            dynamic: false,
+        };
+    }
+}
+
+impl VertexBuffer<VertexData> {
+    /// A buffer with vertices that were packed at runtime, the stride comes from their layout
+    pub fn from_data(
+        backend: &mut dyn RenderBackend,
+        data: &VertexData,
+    ) -> Result<VertexBuffer<VertexData>, Error> {
+        return Ok(VertexBuffer {
+            buffer: backend.create_buffer(&BufferDesc {
+                kind: BufferKind::Vertex,
+                data: &data.bytes,
+                dynamic: false,
+            })?,
+            stride: data.layout.stride,
+            vertex: PhantomData,
+        });
+    }
 }
 
-impl VertexBuffer {
-    // pub fn new(vertices: Vec<FLOAT3>) -> VertexBuffer {
-    //     let vertex = VertexBuffer { 
-    //         buff_desc: D3D11_BUFFER_DESC {
-    //             ByteWidth: (vertices.len() * std::mem::size_of::<FLOAT3>()) as u32,
-    //             Usage: D3D11_USAGE_DEFAULT,
-    //             BindFlags: D3D11_BIND_VERTEX_BUFFER,
-    //             CPUAccessFlags: D3D11_CPU_ACCESS_FLAG::default(),
-    //             MiscFlags: D3D11_RESOURCE_MISC_FLAG::default(),
-    //             StructureByteStride: std::mem::size_of::<FLOAT3>() as u32,
-    //         },
-    //         data: D3D11_SUBRESOURCE_DATA {
-    //             pSysMem: vertices.as_ptr() as *const _,
-    //             SysMemPitch: 0,
-    //             SysMemSlicePitch: 0,
-    //         },
-    //         buffer: &mut None
-    //     };
-
-    //     unsafe {
-    //         &self
-    //             .device
-    //             .CreateBuffer(&buff_desc, Some(&vertex.data), Some(vertex.buffer))
-    //     }
-    //     .as_ref()
-    //     .unwrap_or_else(|e| {
-    //         errors::graphics::GraphicsError::new(
-    //             &e.message().to_string(),
-    //             Some(e.code().0),
-    //             loc!(),
-    //             Some(self),
-    //         )
-    //     });
-    //     return vertex;
-    // }
-}
\ No newline at end of file
+impl<V> Bindable for VertexBuffer<V> {
+    fn bind(&self, backend: &mut dyn RenderBackend) -> Result<(), Error> {
+        return backend.set_vertex_buffer(0, self.buffer, self.stride, 0);
+    }
+
+    fn release(&self, backend: &mut dyn RenderBackend) {
+        backend.release(Resource::Buffer(self.buffer));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::window::graphics::{
+        backend::recording::{Call, RecordingBackend},
+        bindable::vertexlayout::VertexLayout,
+    };
+
+    #[test]
+    fn vertex_data_is_uploaded_with_the_stride_of_its_layout() {
+        let mut backend: RecordingBackend = RecordingBackend::new();
+        let mut data: VertexData = VertexData::new(VertexLayout::new().position().texcoord(0));
+        data.push();
+        data.push();
+
+        let buffer: VertexBuffer<VertexData> =
+            VertexBuffer::from_data(&mut backend, &data).unwrap();
+        buffer.bind(&mut backend).unwrap();
+        assert_eq!(buffer.stride, 20);
+        assert_eq!(
+            backend.calls,
+            vec![
+                Call::CreateBuffer {
+                    id: buffer.buffer,
+                    kind: BufferKind::Vertex,
+                    size: 40,
+                    dynamic: false,
+                },
+                Call::SetVertexBuffer {
+                    slot: 0,
+                    buffer: buffer.buffer,
+                    stride: 20,
+                    offset: 0,
+                },
+            ]
+        );
+    }
+}
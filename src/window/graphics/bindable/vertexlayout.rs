@@ -0,0 +1,501 @@
+use super::{as_bytes, Pod};
+use crate::window::graphics::backend::{InputElement, VertexFormat};
+
+/// Elements are aligned to 4 bytes, like `D3D11_APPEND_ALIGNED_ELEMENT` does
+const ELEMENT_ALIGNMENT: u32 = 4;
+
+/// What an element of a vertex is, which decides its HLSL semantic
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ElementKind {
+    Position,
+    Normal,
+    Tangent,
+    /// Texture coordinates, the number is the semantic index like in `TEXCOORD1`
+    TexCoord(u32),
+    Color,
+    BoneIndices,
+    BoneWeights,
+    Custom {
+        semantic: String,
+        index: u32,
+    },
+}
+
+impl ElementKind {
+    pub fn semantic(&self) -> &str {
+        return match self {
+            ElementKind::Position => "Position",
+            ElementKind::Normal => "Normal",
+            ElementKind::Tangent => "Tangent",
+            ElementKind::TexCoord(_) => "TexCoord",
+            ElementKind::Color => "Color",
+            ElementKind::BoneIndices => "BlendIndices",
+            ElementKind::BoneWeights => "BlendWeight",
+            ElementKind::Custom { semantic, .. } => semantic,
+        };
+    }
+
+    pub fn semantic_index(&self) -> u32 {
+        return match self {
+            ElementKind::TexCoord(index) => *index,
+            ElementKind::Custom { index, .. } => *index,
+            _ => 0,
+        };
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LayoutElement {
+    pub kind: ElementKind,
+    pub format: VertexFormat,
+    /// Offset from the start of the vertex in bytes
+    pub offset: u32,
+}
+
+/// The elements of a vertex, where they are and in which format. Build it element by element, every
+/// element is put after the previous one:
+///
+/// ```ignore
+/// let layout = VertexLayout::new().position().normal().texcoord(0);
+/// assert_eq!(layout.stride, 32);
+/// ```
+///
+/// For a `#[repr(C)]` struct, [`crate::vertex_struct!`] builds the layout from the fields.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct VertexLayout {
+    pub elements: Vec<LayoutElement>,
+    /// Size of a vertex in bytes
+    pub stride: u32,
+}
+
+impl VertexLayout {
+    pub fn new() -> VertexLayout {
+        return VertexLayout::default();
+    }
+
+    /// Add an element at an offset, the stride grows to fit it
+    pub fn element_at(
+        mut self,
+        kind: ElementKind,
+        format: VertexFormat,
+        offset: u32,
+    ) -> VertexLayout {
+        self.stride = self.stride.max(offset + format.size());
+        self.elements.push(LayoutElement {
+            kind,
+            format,
+            offset,
+        });
+        return self;
+    }
+
+    /// Set the size of a vertex, for vertices with padding at the end
+    pub fn with_stride(mut self, stride: u32) -> VertexLayout {
+        self.stride = stride;
+        return self;
+    }
+
+    /// The elements for [`super::inputlayout::InputLayout`]
+    pub fn input_elements(&self) -> Vec<InputElement> {
+        return self
+            .elements
+            .iter()
+            .map(|element| InputElement {
+                semantic: element.kind.semantic().to_string(),
+                semantic_index: element.kind.semantic_index(),
+                format: element.format,
+                offset: element.offset,
+            })
+            .collect();
+    }
+}
+
+/// The builder for layouts that are made at runtime
+impl VertexLayout {
+    /// Add an element after the last one
+    pub fn element(self, kind: ElementKind, format: VertexFormat) -> VertexLayout {
+        let offset: u32 = self.stride.next_multiple_of(ELEMENT_ALIGNMENT);
+        return self.element_at(kind, format, offset);
+    }
+
+    pub fn position(self) -> VertexLayout {
+        return self.element(ElementKind::Position, VertexFormat::Float3);
+    }
+
+    pub fn normal(self) -> VertexLayout {
+        return self.element(ElementKind::Normal, VertexFormat::Float3);
+    }
+
+    pub fn tangent(self) -> VertexLayout {
+        return self.element(ElementKind::Tangent, VertexFormat::Float3);
+    }
+
+    pub fn texcoord(self, index: u32) -> VertexLayout {
+        return self.element(ElementKind::TexCoord(index), VertexFormat::Float2);
+    }
+
+    pub fn color(self) -> VertexLayout {
+        return self.element(ElementKind::Color, VertexFormat::Float4);
+    }
+
+    /// The indices of 4 bones and their weights
+    pub fn bones(self) -> VertexLayout {
+        return self
+            .element(ElementKind::BoneIndices, VertexFormat::UByte4)
+            .element(ElementKind::BoneWeights, VertexFormat::Float4);
+    }
+
+    pub fn custom(self, semantic: &str, index: u32, format: VertexFormat) -> VertexLayout {
+        return self.element(
+            ElementKind::Custom {
+                semantic: semantic.to_string(),
+                index,
+            },
+            format,
+        );
+    }
+
+    pub fn find(&self, kind: &ElementKind) -> Option<&LayoutElement> {
+        return self.elements.iter().find(|element| element.kind == *kind);
+    }
+}
+
+/// A Rust type that can be an element of a vertex
+pub trait Attribute: Pod {
+    const FORMAT: VertexFormat;
+}
+
+impl Attribute for f32 {
+    const FORMAT: VertexFormat = VertexFormat::Float;
+}
+
+impl Attribute for [f32; 2] {
+    const FORMAT: VertexFormat = VertexFormat::Float2;
+}
+
+impl Attribute for [f32; 3] {
+    const FORMAT: VertexFormat = VertexFormat::Float3;
+}
+
+impl Attribute for [f32; 4] {
+    const FORMAT: VertexFormat = VertexFormat::Float4;
+}
+
+impl Attribute for [u8; 4] {
+    const FORMAT: VertexFormat = VertexFormat::UByte4;
+}
+
+/// Four bytes that the shader reads as 0 to 1, like a compact color
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+#[repr(transparent)]
+pub struct Unorm4(pub [u8; 4]);
+
+unsafe impl Pod for Unorm4 {}
+
+impl Attribute for Unorm4 {
+    const FORMAT: VertexFormat = VertexFormat::UByte4Norm;
+}
+
+/// A vertex type with a known layout, see [`crate::vertex_struct!`]
+pub trait Vertex: Pod {
+    fn layout() -> VertexLayout;
+}
+
+/// Vertices with a layout that is only known at runtime, packed into bytes like a vertex buffer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VertexData {
+    pub layout: VertexLayout,
+    pub bytes: Vec<u8>,
+}
+
+impl VertexData {
+    pub fn new(layout: VertexLayout) -> VertexData {
+        return VertexData {
+            layout,
+            bytes: vec![],
+        };
+    }
+
+    /// Amount of vertices
+    pub fn len(&self) -> usize {
+        return match self.layout.stride {
+            0 => 0,
+            stride => self.bytes.len() / stride as usize,
+        };
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.len() == 0;
+    }
+
+    /// Add a vertex with every element set to zero and return its index
+    pub fn push(&mut self) -> usize {
+        self.bytes
+            .resize(self.bytes.len() + self.layout.stride as usize, 0);
+        return self.len() - 1;
+    }
+
+    /// Set an element of a vertex. Fails when the layout doesn't have the element or has it in another
+    /// format.
+    pub fn set<A: Attribute>(
+        &mut self,
+        vertex: usize,
+        kind: &ElementKind,
+        value: A,
+    ) -> Result<(), String> {
+        let element: &LayoutElement = self
+            .layout
+            .find(kind)
+            .ok_or_else(|| format!("The vertex layout doesn't have a {:?} element", kind))?;
+        if element.format != A::FORMAT {
+            return Err(format!(
+                "The {:?} element is {:?}, not {:?}",
+                kind,
+                element.format,
+                A::FORMAT
+            ));
+        }
+        if vertex >= self.len() {
+            return Err(format!(
+                "There are only {} vertices, not {}",
+                self.len(),
+                vertex + 1
+            ));
+        }
+
+        let start: usize = vertex * self.layout.stride as usize + element.offset as usize;
+        let value: &[u8] = as_bytes(std::slice::from_ref(&value));
+        self.bytes[start..start + value.len()].copy_from_slice(value);
+        return Ok(());
+    }
+}
+
+/// Declare a `#[repr(C)]` vertex struct and implement [`Vertex`] for it. Every field gets the element
+/// kind after the `=>`, the format comes from the [`Attribute`] type of the field and the offset from
+/// the struct itself, so the layout always matches the struct. This takes the place of a derive macro.
+///
+/// ```ignore
+/// vertex_struct! {
+///     pub struct MeshVertex {
+///         pub position: [f32; 3] => ElementKind::Position,
+///         pub normal: [f32; 3] => ElementKind::Normal,
+///         pub uv: [f32; 2] => ElementKind::TexCoord(0),
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! vertex_struct {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident {
+            $($field_vis:vis $field:ident : $ty:ty => $kind:expr),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Copy, Clone, PartialEq, Default)]
+        #[repr(C)]
+        $vis struct $name {
+            $($field_vis $field: $ty),*
+        }
+
+        impl $crate::window::graphics::bindable::vertexlayout::Vertex for $name {
+            fn layout() -> $crate::window::graphics::bindable::vertexlayout::VertexLayout {
+                return $crate::window::graphics::bindable::vertexlayout::VertexLayout::new()
+                    $(.element_at(
+                        $kind,
+                        <$ty as $crate::window::graphics::bindable::vertexlayout::Attribute>::FORMAT,
+                        std::mem::offset_of!($name, $field) as u32,
+                    ))*
+                    .with_stride(std::mem::size_of::<$name>() as u32);
+            }
+        }
+
+        // Padding between the fields would be bytes that aren't initialized
+        const _: () = assert!(
+            std::mem::size_of::<$name>() == 0 $(+ std::mem::size_of::<$ty>())*,
+            "A vertex_struct can't have padding between its fields"
+        );
+        unsafe impl $crate::window::graphics::bindable::Pod for $name
+        where
+            $($ty: $crate::window::graphics::bindable::Pod),*
+        {}
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    vertex_struct! {
+        struct SkinnedVertex {
+            position: [f32; 3] => ElementKind::Position,
+            normal: [f32; 3] => ElementKind::Normal,
+            uv: [f32; 2] => ElementKind::TexCoord(1),
+            color: Unorm4 => ElementKind::Color,
+            bones: [u8; 4] => ElementKind::BoneIndices,
+            weights: [f32; 4] => ElementKind::BoneWeights,
+        }
+    }
+
+    /// The same fields as `SkinnedVertex`, declared without the macro
+    #[repr(C)]
+    struct PlainVertex {
+        position: [f32; 3],
+        normal: [f32; 3],
+        uv: [f32; 2],
+        color: [u8; 4],
+        bones: [u8; 4],
+        weights: [f32; 4],
+    }
+
+    #[test]
+    fn vertex_struct_layout_matches_the_struct() {
+        let layout: VertexLayout = SkinnedVertex::layout();
+        let offsets: Vec<u32> = layout
+            .elements
+            .iter()
+            .map(|element| element.offset)
+            .collect();
+
+        assert_eq!(offsets, vec![0, 12, 24, 32, 36, 40]);
+        assert_eq!(
+            offsets,
+            [
+                std::mem::offset_of!(PlainVertex, position),
+                std::mem::offset_of!(PlainVertex, normal),
+                std::mem::offset_of!(PlainVertex, uv),
+                std::mem::offset_of!(PlainVertex, color),
+                std::mem::offset_of!(PlainVertex, bones),
+                std::mem::offset_of!(PlainVertex, weights),
+            ]
+            .map(|offset| offset as u32)
+        );
+        assert_eq!(layout.stride, 56);
+        assert_eq!(layout.stride as usize, std::mem::size_of::<PlainVertex>());
+    }
+
+    #[test]
+    fn vertex_struct_input_elements() {
+        let element =
+            |semantic: &str, semantic_index: u32, format: VertexFormat, offset: u32| InputElement {
+                semantic: semantic.to_string(),
+                semantic_index,
+                format,
+                offset,
+            };
+
+        assert_eq!(
+            SkinnedVertex::layout().input_elements(),
+            vec![
+                element("Position", 0, VertexFormat::Float3, 0),
+                element("Normal", 0, VertexFormat::Float3, 12),
+                element("TexCoord", 1, VertexFormat::Float2, 24),
+                element("Color", 0, VertexFormat::UByte4Norm, 32),
+                element("BlendIndices", 0, VertexFormat::UByte4, 36),
+                element("BlendWeight", 0, VertexFormat::Float4, 40),
+            ]
+        );
+    }
+
+    #[test]
+    fn builder_puts_every_element_after_the_previous_one() {
+        let layout: VertexLayout = VertexLayout::new()
+            .position()
+            .normal()
+            .tangent()
+            .texcoord(0)
+            .color()
+            .bones()
+            .custom("Instance", 2, VertexFormat::Float);
+
+        let offsets: Vec<(ElementKind, u32)> = layout
+            .elements
+            .iter()
+            .map(|element| (element.kind.clone(), element.offset))
+            .collect();
+        assert_eq!(
+            offsets,
+            vec![
+                (ElementKind::Position, 0),
+                (ElementKind::Normal, 12),
+                (ElementKind::Tangent, 24),
+                (ElementKind::TexCoord(0), 36),
+                (ElementKind::Color, 44),
+                (ElementKind::BoneIndices, 60),
+                (ElementKind::BoneWeights, 64),
+                (
+                    ElementKind::Custom {
+                        semantic: "Instance".to_string(),
+                        index: 2
+                    },
+                    80
+                ),
+            ]
+        );
+        assert_eq!(layout.stride, 84);
+        assert_eq!(layout.input_elements()[2].semantic, "Tangent");
+        assert_eq!(layout.input_elements()[7].semantic, "Instance");
+        assert_eq!(layout.input_elements()[7].semantic_index, 2);
+    }
+
+    #[test]
+    fn builder_matches_vertex_struct() {
+        let built: VertexLayout = VertexLayout::new()
+            .position()
+            .normal()
+            .element(ElementKind::TexCoord(1), VertexFormat::Float2)
+            .element(ElementKind::Color, VertexFormat::UByte4Norm)
+            .bones();
+        assert_eq!(built, SkinnedVertex::layout());
+    }
+
+    #[test]
+    fn element_at_and_with_stride() {
+        let layout: VertexLayout = VertexLayout::new()
+            .element_at(ElementKind::Normal, VertexFormat::Float3, 16)
+            .element_at(ElementKind::Position, VertexFormat::Float3, 0);
+        assert_eq!(layout.stride, 28);
+        assert_eq!(layout.with_stride(32).stride, 32);
+    }
+
+    #[test]
+    fn vertex_data_packs_the_same_bytes_as_the_struct() {
+        let vertex: SkinnedVertex = SkinnedVertex {
+            position: [1.0, 2.0, 3.0],
+            normal: [0.0, 1.0, 0.0],
+            uv: [0.5, 0.25],
+            color: Unorm4([255, 128, 0, 255]),
+            bones: [1, 2, 3, 4],
+            weights: [0.5, 0.5, 0.0, 0.0],
+        };
+
+        let mut data: VertexData = VertexData::new(SkinnedVertex::layout());
+        assert!(data.is_empty());
+        assert_eq!(data.push(), 0);
+        assert_eq!(data.push(), 1);
+        data.set(1, &ElementKind::Position, vertex.position)
+            .unwrap();
+        data.set(1, &ElementKind::Normal, vertex.normal).unwrap();
+        data.set(1, &ElementKind::TexCoord(1), vertex.uv).unwrap();
+        data.set(1, &ElementKind::Color, vertex.color).unwrap();
+        data.set(1, &ElementKind::BoneIndices, vertex.bones)
+            .unwrap();
+        data.set(1, &ElementKind::BoneWeights, vertex.weights)
+            .unwrap();
+
+        assert_eq!(data.len(), 2);
+        assert_eq!(data.bytes[..56], [0; 56]);
+        assert_eq!(data.bytes[56..], *as_bytes(&[vertex]));
+    }
+
+    #[test]
+    fn vertex_data_refuses_elements_it_cant_set() {
+        let mut data: VertexData = VertexData::new(VertexLayout::new().position());
+        data.push();
+
+        assert!(data.set(0, &ElementKind::Normal, [0.0f32; 3]).is_err());
+        assert!(data.set(0, &ElementKind::Position, [0.0f32; 4]).is_err());
+        assert!(data.set(1, &ElementKind::Position, [0.0f32; 3]).is_err());
+        assert_eq!(data.bytes, vec![0; 12]);
+    }
+}
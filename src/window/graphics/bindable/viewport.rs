@@ -0,0 +1,14 @@
+use super::Bindable;
+use crate::window::{
+    errors::Error,
+    graphics::backend::{RenderBackend, Viewport},
+};
+
+impl Bindable for Viewport {
+    fn bind(&self, backend: &mut dyn RenderBackend) -> Result<(), Error> {
+        backend.set_viewport(self);
+        return Ok(());
+    }
+
+    fn release(&self, _backend: &mut dyn RenderBackend) {}
+}
@@ -0,0 +1,485 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fs,
+    path::{Path, PathBuf},
+    time::{Duration, Instant, SystemTime},
+};
+
+use super::backend::{RenderBackend, ShaderCode, ShaderDesc, ShaderId};
+use crate::{log_error, log_info, window::errors::Error};
+
+/// How long a changed file has to stay the same before its shaders are compiled again. Editors often
+/// save a file in more than one write.
+pub const DEFAULT_DEBOUNCE: Duration = Duration::from_millis(200);
+
+/// What is compared to see that a file changed. A file that doesn't exist has no stamp.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+struct Stamp {
+    modified: Option<SystemTime>,
+    len: u64,
+}
+
+impl Stamp {
+    fn of(path: &Path) -> Option<Stamp> {
+        return fs::metadata(path).ok().map(|metadata| Stamp {
+            modified: metadata.modified().ok(),
+            len: metadata.len(),
+        });
+    }
+}
+
+#[derive(Debug)]
+struct WatchedFile {
+    stamp: Option<Stamp>,
+    /// When the file last changed, until the change is reported
+    changed_at: Option<Instant>,
+}
+
+/// Finds files that changed by polling their modification time and size. A change is reported once the
+/// file stayed the same for the debounce time, so a file that is written in parts is reported once.
+#[derive(Debug)]
+pub struct FileWatcher {
+    pub debounce: Duration,
+    files: HashMap<PathBuf, WatchedFile>,
+}
+
+impl FileWatcher {
+    pub fn new(debounce: Duration) -> FileWatcher {
+        return FileWatcher {
+            debounce,
+            files: HashMap::new(),
+        };
+    }
+
+    /// Start watching a file, it doesn't have to exist yet
+    pub fn watch(&mut self, path: &Path) {
+        if !self.files.contains_key(path) {
+            self.files.insert(
+                path.to_path_buf(),
+                WatchedFile {
+                    stamp: Stamp::of(path),
+                    changed_at: None,
+                },
+            );
+        }
+    }
+
+    pub fn unwatch(&mut self, path: &Path) {
+        self.files.remove(path);
+    }
+
+    /// The files that changed and then stayed the same for the debounce time, sorted
+    pub fn poll(&mut self, now: Instant) -> Vec<PathBuf> {
+        let mut settled: Vec<PathBuf> = vec![];
+        for (path, file) in self.files.iter_mut() {
+            let stamp: Option<Stamp> = Stamp::of(path);
+            if stamp != file.stamp {
+                file.stamp = stamp;
+                file.changed_at = Some(now);
+            }
+
+            let settled_at: Option<Instant> = file
+                .changed_at
+                .filter(|changed_at| now.saturating_duration_since(*changed_at) >= self.debounce);
+            if settled_at.is_some() {
+                file.changed_at = None;
+                settled.push(path.clone());
+            }
+        }
+        settled.sort();
+        return settled;
+    }
+}
+
+/// The files that the `#include` lines of HLSL source name, relative to the folder `dir` of the source
+pub fn parse_includes(source: &str, dir: &Path) -> Vec<PathBuf> {
+    return source
+        .lines()
+        .filter_map(|line| {
+            let directive: &str = line.trim_start().strip_prefix('#')?.trim_start();
+            let name: &str = directive.strip_prefix("include")?.trim();
+            let (open, close) = match name.chars().next()? {
+                '"' => ('"', '"'),
+                '<' => ('<', '>'),
+                _ => return None,
+            };
+            let name: &str = name.strip_prefix(open)?;
+            return Some(dir.join(&name[..name.find(close)?]));
+        })
+        .collect();
+}
+
+/// `path` and every file that it includes, directly or through other includes. Files that can't be read
+/// are in the list too, so they are watched and are picked up when they show up.
+pub fn dependencies(path: &Path) -> Vec<PathBuf> {
+    let mut found: Vec<PathBuf> = vec![];
+    let mut pending: Vec<PathBuf> = vec![path.to_path_buf()];
+    while let Some(file) = pending.pop() {
+        if found.contains(&file) {
+            continue;
+        }
+        if let Ok(source) = fs::read_to_string(&file) {
+            let dir: &Path = file.parent().unwrap_or(Path::new(""));
+            pending.extend(parse_includes(&source, dir));
+        }
+        found.push(file);
+    }
+    return found;
+}
+
+/// Compiles a shader again in the place of the old one, see [`RenderBackend::replace_shader()`]. Tests
+/// use a fake one.
+pub trait ShaderCompiler {
+    fn recompile(&mut self, shader: ShaderId, desc: &ShaderDesc) -> Result<(), Error>;
+}
+
+impl<B: RenderBackend + ?Sized> ShaderCompiler for B {
+    fn recompile(&mut self, shader: ShaderId, desc: &ShaderDesc) -> Result<(), Error> {
+        return self.replace_shader(shader, desc);
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WatchedShader {
+    pub shader: ShaderId,
+    pub desc: ShaderDesc,
+    /// The source file and the files it includes
+    pub files: Vec<PathBuf>,
+}
+
+/// A shader that was compiled again because one of its files changed
+#[derive(Debug)]
+pub struct Reload {
+    pub shader: ShaderId,
+    pub path: PathBuf,
+    /// When compiling failed, the old shader is still used
+    pub result: Result<(), Error>,
+}
+
+/// Compiles shaders again when their HLSL file or one of its includes changes. A shader is only swapped
+/// when the new version compiles, otherwise the old one stays and the error is logged.
+#[derive(Debug)]
+pub struct ShaderManager {
+    pub watcher: FileWatcher,
+    shaders: Vec<WatchedShader>,
+}
+
+impl ShaderManager {
+    pub fn new(debounce: Duration) -> ShaderManager {
+        return ShaderManager {
+            watcher: FileWatcher::new(debounce),
+            shaders: vec![],
+        };
+    }
+
+    /// Watch the files of a shader. Watching a shader again does nothing and shaders that weren't read
+    /// from a file, like embedded ones, aren't watched.
+    pub fn watch(&mut self, shader: ShaderId, desc: &ShaderDesc) {
+        if desc.code != ShaderCode::File
+            || self.shaders.iter().any(|watched| watched.shader == shader)
+        {
+            return;
+        }
+
+        let files: Vec<PathBuf> = dependencies(&desc.path);
+        for file in &files {
+            self.watcher.watch(file);
+        }
+        self.shaders.push(WatchedShader {
+            shader,
+            desc: desc.clone(),
+            files,
+        });
+    }
+
+    /// Compile the shaders with changed files again. Call it every frame.
+    pub fn update<C: ShaderCompiler + ?Sized>(
+        &mut self,
+        compiler: &mut C,
+        now: Instant,
+    ) -> Vec<Reload> {
+        let changed: Vec<PathBuf> = self.watcher.poll(now);
+        if changed.is_empty() {
+            return vec![];
+        }
+
+        let mut reloads: Vec<Reload> = vec![];
+        for watched in self.shaders.iter_mut() {
+            if !watched.files.iter().any(|file| changed.contains(file)) {
+                continue;
+            }
+
+            let result: Result<(), Error> = compiler.recompile(watched.shader, &watched.desc);
+            match &result {
+                Ok(()) => log_info!("Reloaded shader {}", watched.desc.path.display()),
+                Err(e) => log_error!(
+                    "Unable to reload shader {}, the old one is still used: {}",
+                    watched.desc.path.display(),
+                    e
+                ),
+            }
+            // The includes can change with the source
+            watched.files = dependencies(&watched.desc.path);
+            reloads.push(Reload {
+                shader: watched.shader,
+                path: watched.desc.path.clone(),
+                result,
+            });
+        }
+
+        self.watch_files();
+        return reloads;
+    }
+
+    /// Watch the files that shaders use now and stop watching the files that none use anymore
+    fn watch_files(&mut self) {
+        let used: HashSet<&PathBuf> = self
+            .shaders
+            .iter()
+            .flat_map(|watched| watched.files.iter())
+            .collect();
+        let unused: Vec<PathBuf> = self
+            .watcher
+            .files
+            .keys()
+            .filter(|file| !used.contains(file))
+            .cloned()
+            .collect();
+        for file in unused {
+            self.watcher.unwatch(&file);
+        }
+        for file in used {
+            self.watcher.watch(file);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use windows::core::HRESULT;
+
+    use super::*;
+    use crate::window::graphics::backend::ShaderStage;
+
+    /// A folder in the temp dir that is removed when the test ends
+    struct TempDir(PathBuf);
+
+    impl TempDir {
+        fn new(name: &str) -> TempDir {
+            let dir: PathBuf =
+                std::env::temp_dir().join(format!("hotreload_{}_{}", name, std::process::id()));
+            let _ = fs::remove_dir_all(&dir);
+            fs::create_dir_all(&dir).unwrap();
+            return TempDir(dir);
+        }
+
+        fn write(&self, name: &str, contents: &str) -> PathBuf {
+            let path: PathBuf = self.0.join(name);
+            fs::write(&path, contents).unwrap();
+            return path;
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    /// Compiles a shader by reading its source, a source with `error` in it doesn't compile
+    #[derive(Default)]
+    struct FakeCompiler {
+        sources: HashMap<ShaderId, String>,
+        compiles: u32,
+    }
+
+    impl ShaderCompiler for FakeCompiler {
+        fn recompile(&mut self, shader: ShaderId, desc: &ShaderDesc) -> Result<(), Error> {
+            self.compiles += 1;
+            let source: String = fs::read_to_string(&desc.path).unwrap();
+            if source.contains("error") {
+                return Err(Error::shader_compile(
+                    &desc.path.display().to_string(),
+                    HRESULT(-1),
+                    "error X3000: syntax error",
+                ));
+            }
+            self.sources.insert(shader, source);
+            return Ok(());
+        }
+    }
+
+    #[test]
+    fn several_writes_within_the_debounce_time_are_one_change() {
+        let dir: TempDir = TempDir::new("debounce");
+        let path: PathBuf = dir.write("Cube.hlsl", "a");
+        let mut watcher: FileWatcher = FileWatcher::new(DEFAULT_DEBOUNCE);
+        watcher.watch(&path);
+        let start: Instant = Instant::now();
+        assert!(watcher.poll(start).is_empty());
+
+        // The length changes with every write, the modification time might not
+        let step: Duration = DEFAULT_DEBOUNCE / 4;
+        dir.write("Cube.hlsl", "ab");
+        assert!(watcher.poll(start).is_empty());
+        dir.write("Cube.hlsl", "abc");
+        assert!(watcher.poll(start + step).is_empty());
+        dir.write("Cube.hlsl", "abcd");
+        assert!(watcher.poll(start + step * 2).is_empty());
+        assert!(watcher.poll(start + step * 5).is_empty());
+
+        assert_eq!(
+            watcher.poll(start + step * 2 + DEFAULT_DEBOUNCE),
+            vec![path.clone()]
+        );
+        assert!(watcher.poll(start + step * 20).is_empty());
+    }
+
+    #[test]
+    fn files_that_show_up_or_go_away_changed() {
+        let dir: TempDir = TempDir::new("missing");
+        let path: PathBuf = dir.0.join("Common.hlsli");
+        let mut watcher: FileWatcher = FileWatcher::new(Duration::ZERO);
+        watcher.watch(&path);
+        let now: Instant = Instant::now();
+        assert!(watcher.poll(now).is_empty());
+
+        dir.write("Common.hlsli", "");
+        assert_eq!(watcher.poll(now), vec![path.clone()]);
+        fs::remove_file(&path).unwrap();
+        assert_eq!(watcher.poll(now), vec![path.clone()]);
+
+        watcher.unwatch(&path);
+        dir.write("Common.hlsli", "");
+        assert!(watcher.poll(now).is_empty());
+    }
+
+    #[test]
+    fn includes_are_found_through_other_includes() {
+        let dir: TempDir = TempDir::new("includes");
+        let cube: PathBuf = dir.write(
+            "Cube.hlsl",
+            "#include \"Common.hlsli\"\n  #  include <Lights.hlsli>\n// #include \"Comment.hlsli\"\n",
+        );
+        dir.write(
+            "Common.hlsli",
+            "#include \"Cube.hlsl\"\n#include \"Missing.hlsli\"\n",
+        );
+        dir.write("Lights.hlsli", "");
+
+        assert_eq!(
+            dependencies(&cube),
+            vec![
+                cube.clone(),
+                dir.0.join("Lights.hlsli"),
+                dir.0.join("Common.hlsli"),
+                dir.0.join("Missing.hlsli"),
+            ]
+        );
+    }
+
+    #[test]
+    fn changed_includes_reload_the_shaders_that_use_them() {
+        let dir: TempDir = TempDir::new("reload");
+        let cube: PathBuf = dir.write("Cube.hlsl", "#include \"Common.hlsli\"\n");
+        let sky: PathBuf = dir.write("Sky.hlsl", "");
+        dir.write("Common.hlsli", "");
+        let mut manager: ShaderManager = ShaderManager::new(DEFAULT_DEBOUNCE);
+        manager.watch(
+            ShaderId(1),
+            &ShaderDesc::new(ShaderStage::Vertex, cube.clone()),
+        );
+        manager.watch(ShaderId(2), &ShaderDesc::new(ShaderStage::Pixel, sky));
+        // Shaders that weren't read from a file are left alone
+        let mut embedded: ShaderDesc =
+            ShaderDesc::new(ShaderStage::Pixel, dir.0.join("Embedded.hlsl"));
+        embedded.code = ShaderCode::Source("float4 main() : SV_Target;".into());
+        manager.watch(ShaderId(3), &embedded);
+        assert_eq!(manager.shaders.len(), 2);
+
+        let mut compiler: FakeCompiler = FakeCompiler::default();
+        let start: Instant = Instant::now();
+        assert!(manager.update(&mut compiler, start).is_empty());
+
+        dir.write("Common.hlsli", "float4 tint;");
+        assert!(manager.update(&mut compiler, start).is_empty());
+        let reloads: Vec<Reload> = manager.update(&mut compiler, start + DEFAULT_DEBOUNCE);
+        assert_eq!(reloads.len(), 1);
+        assert_eq!(reloads[0].shader, ShaderId(1));
+        assert_eq!(reloads[0].path, cube);
+        assert!(reloads[0].result.is_ok());
+        assert_eq!(compiler.compiles, 1);
+
+        // A new include is watched after the reload and the old one isn't anymore
+        dir.write("Cube.hlsl", "#include \"Lights.hlsli\"\n");
+        manager.update(&mut compiler, start + DEFAULT_DEBOUNCE);
+        assert_eq!(
+            manager
+                .update(&mut compiler, start + DEFAULT_DEBOUNCE * 2)
+                .len(),
+            1
+        );
+        assert_eq!(
+            manager.shaders[0].files,
+            vec![cube.clone(), dir.0.join("Lights.hlsli")]
+        );
+        assert!(manager
+            .watcher
+            .files
+            .contains_key(&dir.0.join("Lights.hlsli")));
+        assert!(!manager
+            .watcher
+            .files
+            .contains_key(&dir.0.join("Common.hlsli")));
+
+        dir.write("Lights.hlsli", "float3 sun;");
+        manager.update(&mut compiler, start + DEFAULT_DEBOUNCE * 2);
+        assert_eq!(
+            manager
+                .update(&mut compiler, start + DEFAULT_DEBOUNCE * 3)
+                .len(),
+            1
+        );
+        assert_eq!(compiler.compiles, 3);
+    }
+
+    #[test]
+    fn a_shader_that_fails_to_compile_keeps_the_old_one() {
+        let dir: TempDir = TempDir::new("failing");
+        let cube: PathBuf = dir.write("Cube.hlsl", "float4 main() : SV_Target;");
+        let mut manager: ShaderManager = ShaderManager::new(DEFAULT_DEBOUNCE);
+        manager.watch(
+            ShaderId(1),
+            &ShaderDesc::new(ShaderStage::Pixel, cube.clone()),
+        );
+        let mut compiler: FakeCompiler = FakeCompiler::default();
+        compiler
+            .sources
+            .insert(ShaderId(1), "float4 main() : SV_Target;".to_string());
+        let start: Instant = Instant::now();
+        manager.update(&mut compiler, start);
+
+        dir.write("Cube.hlsl", "float4 main() : SV_Target; syntax error");
+        manager.update(&mut compiler, start);
+        let reloads: Vec<Reload> = manager.update(&mut compiler, start + DEFAULT_DEBOUNCE);
+        assert_eq!(reloads.len(), 1);
+        match &reloads[0].result {
+            Err(Error::ShaderCompile { path, log, .. }) => {
+                assert_eq!(*path, cube.display().to_string());
+                assert!(log.contains("X3000"));
+            }
+            other => panic!("Expected a compile error, got {:?}", other),
+        }
+        assert_eq!(compiler.sources[&ShaderId(1)], "float4 main() : SV_Target;");
+
+        // It is still watched and picks up the fix
+        dir.write("Cube.hlsl", "float4 main() : SV_Target { return 1; }");
+        manager.update(&mut compiler, start + DEFAULT_DEBOUNCE);
+        let reloads: Vec<Reload> = manager.update(&mut compiler, start + DEFAULT_DEBOUNCE * 2);
+        assert!(reloads[0].result.is_ok());
+        assert_eq!(
+            compiler.sources[&ShaderId(1)],
+            "float4 main() : SV_Target { return 1; }"
+        );
+    }
+}
@@ -0,0 +1,191 @@
+use std::{
+    fmt,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use crate::{
+    vertex_struct,
+    window::graphics::{bindable::vertexlayout::ElementKind, preprocessor},
+};
+
+pub mod gltf;
+pub mod json;
+pub mod obj;
+
+vertex_struct! {
+    /// A vertex of a loaded mesh. Meshes without normals or texture coordinates get generated normals and
+    /// zero texture coordinates.
+    pub struct MeshVertex {
+        pub position: [f32; 3] => ElementKind::Position,
+        pub normal: [f32; 3] => ElementKind::Normal,
+        pub texcoord: [f32; 2] => ElementKind::TexCoord(0),
+    }
+}
+
+/// The image of a texture
+#[derive(Debug, Clone, PartialEq)]
+pub enum Texture {
+    /// A file, relative to the working directory
+    File(PathBuf),
+    /// An image that is stored in the mesh file, like the PNG of a `.glb`
+    Embedded { mime_type: String, bytes: Arc<[u8]> },
+}
+
+impl fmt::Display for Texture {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            Texture::File(path) => write!(f, "{}", path.display()),
+            Texture::Embedded { mime_type, bytes } => {
+                write!(f, "embedded {} of {} bytes", mime_type, bytes.len())
+            }
+        };
+    }
+}
+
+/// How the alpha of the base color is used
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AlphaMode {
+    /// Alpha is ignored
+    Opaque,
+    /// Pixels with an alpha below the cutoff aren't drawn, the others are opaque
+    Mask(f32),
+    /// Blended with what is behind it
+    Blend,
+}
+
+/// How a part of a mesh looks, as the file describes it. OBJ materials fill in the colors of the Phong
+/// model, glTF ones the metallic and roughness of PBR.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Material {
+    pub name: String,
+    /// The diffuse color with the opacity as alpha
+    pub base_color: [f32; 4],
+    pub ambient: [f32; 3],
+    pub specular: [f32; 3],
+    /// The specular exponent
+    pub shininess: f32,
+    pub metallic: f32,
+    pub roughness: f32,
+    pub emissive: [f32; 3],
+    pub alpha_mode: AlphaMode,
+    /// Back faces are drawn too
+    pub double_sided: bool,
+    pub base_color_texture: Option<Texture>,
+    pub normal_texture: Option<Texture>,
+    pub specular_texture: Option<Texture>,
+    /// Roughness in the green channel and metallic in the blue one
+    pub metallic_roughness_texture: Option<Texture>,
+    pub occlusion_texture: Option<Texture>,
+    pub emissive_texture: Option<Texture>,
+}
+
+impl Default for Material {
+    fn default() -> Material {
+        return Material {
+            name: String::new(),
+            base_color: [1.0, 1.0, 1.0, 1.0],
+            ambient: [0.0, 0.0, 0.0],
+            specular: [0.0, 0.0, 0.0],
+            shininess: 0.0,
+            metallic: 0.0,
+            roughness: 1.0,
+            emissive: [0.0, 0.0, 0.0],
+            alpha_mode: AlphaMode::Opaque,
+            double_sided: false,
+            base_color_texture: None,
+            normal_texture: None,
+            specular_texture: None,
+            metallic_roughness_texture: None,
+            occlusion_texture: None,
+            emissive_texture: None,
+        };
+    }
+}
+
+/// Indices of a mesh that are drawn with one material
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submesh {
+    /// The object and group names, like `car/wheel`
+    pub name: String,
+    /// Index in [`Mesh::materials`], `None` for the default material
+    pub material: Option<usize>,
+    pub first_index: u32,
+    pub index_count: u32,
+}
+
+/// Triangles with shared vertices, ready for a vertex and an index buffer. The layout of the vertices is
+/// `MeshVertex::layout()`.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Mesh {
+    pub vertices: Vec<MeshVertex>,
+    pub indices: Vec<u32>,
+    pub submeshes: Vec<Submesh>,
+    pub materials: Vec<Material>,
+}
+
+impl Mesh {
+    /// Load a mesh file, the format comes from the extension
+    pub fn load(path: &Path) -> Result<Mesh, String> {
+        let extension: String = path
+            .extension()
+            .map(|extension| extension.to_string_lossy().to_lowercase())
+            .unwrap_or_default();
+        return match extension.as_str() {
+            "obj" => obj::load_obj(path),
+            _ => Err(format!(
+                "{} isn't a mesh format that can be loaded",
+                path.display()
+            )),
+        };
+    }
+
+    pub fn triangle_count(&self) -> usize {
+        return self.indices.len() / 3;
+    }
+
+    /// The indices as `u16`, when there are few enough vertices for them
+    pub fn indices_u16(&self) -> Option<Vec<u16>> {
+        return self
+            .indices
+            .iter()
+            .map(|index| u16::try_from(*index).ok())
+            .collect();
+    }
+
+    pub fn material(&self, name: &str) -> Option<&Material> {
+        return self.materials.iter().find(|material| material.name == name);
+    }
+}
+
+pub fn subtract(a: [f32; 3], b: [f32; 3]) -> [f32; 3] {
+    return [a[0] - b[0], a[1] - b[1], a[2] - b[2]];
+}
+
+pub fn cross(a: [f32; 3], b: [f32; 3]) -> [f32; 3] {
+    return [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ];
+}
+
+/// The vector with a length of 1, or zero when it has no direction
+pub fn normalize(v: [f32; 3]) -> [f32; 3] {
+    let length: f32 = (v[0] * v[0] + v[1] * v[1] + v[2] * v[2]).sqrt();
+    if length <= f32::EPSILON {
+        return [0.0, 0.0, 0.0];
+    }
+    return [v[0] / length, v[1] / length, v[2] / length];
+}
+
+/// `path` relative to the folder of the file `from`, for the files a mesh file refers to
+pub fn relative_to(from: &str, path: &str) -> String {
+    let from: String = from.replace('\\', "/");
+    return match from.rfind('/') {
+        Some(end) if !Path::new(path).is_absolute() => {
+            preprocessor::normalize(&format!("{}/{}", &from[..end], path))
+        }
+        _ => preprocessor::normalize(path),
+    };
+}
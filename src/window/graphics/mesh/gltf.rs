@@ -0,0 +1,1445 @@
+use std::{path::Path, sync::Arc};
+
+use directx_math::XMFLOAT4X4;
+
+use super::{
+    cross, json::Json, normalize, relative_to, subtract, AlphaMode, Material, Mesh, MeshVertex,
+    Submesh, Texture,
+};
+use crate::{
+    log_warn,
+    window::graphics::{
+        preprocessor::{DiskFiles, FileSystem},
+        scene::{Camera, Light, LightKind, Node, Projection, Scene, Transform},
+    },
+};
+
+/// Start of a `.glb` file, `glTF` in ASCII
+const GLB_MAGIC: u32 = 0x4654_6C67;
+const GLB_VERSION: u32 = 2;
+const CHUNK_JSON: u32 = 0x4E4F_534A;
+const CHUNK_BIN: u32 = 0x004E_4942;
+
+const BYTE: usize = 5120;
+const UNSIGNED_BYTE: usize = 5121;
+const SHORT: usize = 5122;
+const UNSIGNED_SHORT: usize = 5123;
+const UNSIGNED_INT: usize = 5125;
+const FLOAT: usize = 5126;
+
+const MODE_TRIANGLES: usize = 4;
+const MODE_TRIANGLE_STRIP: usize = 5;
+const MODE_TRIANGLE_FAN: usize = 6;
+
+const LIGHTS_PUNCTUAL: &str = "KHR_lights_punctual";
+const EMISSIVE_STRENGTH: &str = "KHR_materials_emissive_strength";
+/// Extensions a file can require that are understood
+const SUPPORTED_EXTENSIONS: &[&str] = &[LIGHTS_PUNCTUAL, EMISSIVE_STRENGTH];
+
+/// Read a `.gltf` or `.glb` file and the buffers and images it refers to from disk
+pub fn load_gltf(path: &Path) -> Result<Scene, String> {
+    let bytes: Vec<u8> =
+        std::fs::read(path).map_err(|e| format!("Unable to read {}: {}", path.display(), e))?;
+    return parse_gltf(&path.to_string_lossy(), &bytes, &DiskFiles);
+}
+
+/// Turn a glTF 2.0 file into a scene. `bytes` are the JSON of a `.gltf` or a whole `.glb`, `name` is
+/// the path of the file and the external buffers and images are read from `files` relative to it.
+///
+/// Every glTF mesh becomes a [`Mesh`] with a submesh per primitive and its own copy of the materials
+/// it uses. Positions and winding are kept as they are, like [`super::obj::parse_obj()`] does, so the
+/// scene stays right handed with counterclockwise front faces. Primitives without normals get the
+/// normals of their faces and points and lines are skipped.
+pub fn parse_gltf(name: &str, bytes: &[u8], files: &dyn FileSystem) -> Result<Scene, String> {
+    let (text, bin): (&str, Option<&[u8]>) = match bytes.starts_with(&GLB_MAGIC.to_le_bytes()) {
+        true => read_glb(bytes).map_err(|e| format!("{}: {}", name, e))?,
+        false => (
+            std::str::from_utf8(bytes).map_err(|_| format!("{}: The JSON isn't UTF-8", name))?,
+            None,
+        ),
+    };
+    let json: Json =
+        Json::parse(text.trim_start_matches('\u{feff}')).map_err(|e| format!("{}:{}", name, e))?;
+    let document: Document = Document::new(name, &json, bin, files)?;
+    return document.scene().map_err(|e| format!("{}: {}", name, e));
+}
+
+/// The JSON and binary chunks of a `.glb`: a header with the magic, the version and the length, then
+/// chunks that start with their length and type
+fn read_glb(bytes: &[u8]) -> Result<(&str, Option<&[u8]>), String> {
+    let version: u32 = read_u32(bytes, 4)?;
+    if version != GLB_VERSION {
+        return Err(format!("GLB files of version {} aren't supported", version));
+    }
+    let length: usize = read_u32(bytes, 8)? as usize;
+    let bytes: &[u8] = bytes
+        .get(..length)
+        .ok_or_else(|| format!("The file has {} bytes, not {}", bytes.len(), length))?;
+
+    let mut json: Option<&[u8]> = None;
+    let mut bin: Option<&[u8]> = None;
+    let mut offset: usize = 12;
+    while offset < bytes.len() {
+        let chunk_length: usize = read_u32(bytes, offset)? as usize;
+        let chunk_type: u32 = read_u32(bytes, offset + 4)?;
+        let chunk: &[u8] = slice(bytes, offset + 8, chunk_length)
+            .map_err(|_| format!("The chunk at byte {} ends after the file", offset))?;
+        match chunk_type {
+            CHUNK_JSON if json.is_none() => json = Some(chunk),
+            CHUNK_BIN if json.is_some() && bin.is_none() => bin = Some(chunk),
+            CHUNK_JSON | CHUNK_BIN => {
+                return Err(format!("The chunk at byte {} is out of order", offset))
+            }
+            _ => {}
+        }
+        offset += 8 + chunk_length;
+    }
+
+    let json: &[u8] = json.ok_or("There is no JSON chunk")?;
+    let text: &str = std::str::from_utf8(json).map_err(|_| "The JSON chunk isn't UTF-8")?;
+    // The JSON is padded with spaces to 4 bytes
+    return Ok((text.trim_end_matches([' ', '\0']), bin));
+}
+
+fn read_u32(bytes: &[u8], offset: usize) -> Result<u32, String> {
+    let word: &[u8] = slice(bytes, offset, 4)?;
+    return Ok(u32::from_le_bytes([word[0], word[1], word[2], word[3]]));
+}
+
+/// `len` bytes from `start`, an error instead of a panic when they aren't there
+fn slice(bytes: &[u8], start: usize, len: usize) -> Result<&[u8], String> {
+    return start
+        .checked_add(len)
+        .and_then(|end| bytes.get(start..end))
+        .ok_or_else(|| {
+            format!(
+                "Bytes {} to {} are past the end at {}",
+                start,
+                start.saturating_add(len),
+                bytes.len()
+            )
+        });
+}
+
+/// The JSON of a glTF file with its buffers loaded
+struct Document<'a> {
+    name: &'a str,
+    json: &'a Json,
+    files: &'a dyn FileSystem,
+    buffers: Vec<Vec<u8>>,
+}
+
+impl<'a> Document<'a> {
+    fn new(
+        name: &'a str,
+        json: &'a Json,
+        bin: Option<&[u8]>,
+        files: &'a dyn FileSystem,
+    ) -> Result<Document<'a>, String> {
+        let at = |message: String| format!("{}: {}", name, message);
+        let version: &str = json.get("asset").get("version").as_str().unwrap_or("");
+        if !version.starts_with("2.") {
+            return Err(at(format!(
+                "glTF {} isn't supported, only 2.0 is",
+                match version {
+                    "" => "without a version",
+                    version => version,
+                }
+            )));
+        }
+        for extension in json.get("extensionsRequired").items() {
+            let extension: &str = extension.as_str().unwrap_or("");
+            if !SUPPORTED_EXTENSIONS.contains(&extension) {
+                return Err(at(format!("The extension {} isn't supported", extension)));
+            }
+        }
+
+        let mut buffers: Vec<Vec<u8>> = vec![];
+        for (index, buffer) in json.get("buffers").items().iter().enumerate() {
+            let length: usize = buffer
+                .get("byteLength")
+                .as_usize()
+                .ok_or_else(|| at(format!("buffers[{}] has no byteLength", index)))?;
+            // The first buffer of a `.glb` without a uri is the binary chunk
+            let mut bytes: Vec<u8> = match (buffer.get("uri").as_str(), bin) {
+                (Some(uri), _) => {
+                    read_uri(name, uri, files)
+                        .map_err(|e| at(format!("buffers[{}]: {}", index, e)))?
+                        .1
+                }
+                (None, Some(bin)) if index == 0 => bin.to_vec(),
+                (None, _) => return Err(at(format!("buffers[{}] has no uri", index))),
+            };
+            if bytes.len() < length {
+                return Err(at(format!(
+                    "buffers[{}] has {} bytes, not {}",
+                    index,
+                    bytes.len(),
+                    length
+                )));
+            }
+            bytes.truncate(length);
+            buffers.push(bytes);
+        }
+        return Ok(Document {
+            name,
+            json,
+            files,
+            buffers,
+        });
+    }
+
+    fn scene(&self) -> Result<Scene, String> {
+        let images: Vec<Option<Texture>> = self
+            .json
+            .get("images")
+            .items()
+            .iter()
+            .enumerate()
+            .map(|(index, image)| {
+                self.image(image)
+                    .map_err(|e| format!("images[{}]: {}", index, e))
+            })
+            .collect::<Result<Vec<Option<Texture>>, String>>()?;
+        let materials: Vec<Material> = self
+            .json
+            .get("materials")
+            .items()
+            .iter()
+            .enumerate()
+            .map(|(index, material)| {
+                self.material(index, material, &images)
+                    .map_err(|e| format!("materials[{}]: {}", index, e))
+            })
+            .collect::<Result<Vec<Material>, String>>()?;
+
+        let mut scene: Scene = Scene::default();
+        for (index, mesh) in self.json.get("meshes").items().iter().enumerate() {
+            scene.meshes.push(
+                self.mesh(index, mesh, &materials)
+                    .map_err(|e| format!("meshes[{}]: {}", index, e))?,
+            );
+        }
+        for (index, camera) in self.json.get("cameras").items().iter().enumerate() {
+            scene
+                .cameras
+                .push(camera_of(camera).map_err(|e| format!("cameras[{}]: {}", index, e))?);
+        }
+        let lights: &Json = self
+            .json
+            .get("extensions")
+            .get(LIGHTS_PUNCTUAL)
+            .get("lights");
+        for (index, light) in lights.items().iter().enumerate() {
+            scene.lights.push(
+                light_of(light)
+                    .map_err(|e| format!("{} lights[{}]: {}", LIGHTS_PUNCTUAL, index, e))?,
+            );
+        }
+        for (index, node) in self.json.get("nodes").items().iter().enumerate() {
+            scene
+                .nodes
+                .push(node_of(node).map_err(|e| format!("nodes[{}]: {}", index, e))?);
+        }
+
+        let scenes: &[Json] = self.json.get("scenes").items();
+        let default_scene: Option<usize> = index_of(self.json.get("scene"), "scene")?;
+        match scenes.get(default_scene.unwrap_or(0)) {
+            Some(root) => {
+                scene.name = root.get("name").as_str().unwrap_or("").to_string();
+                for node in root.get("nodes").items() {
+                    scene.roots.push(
+                        node.as_usize()
+                            .ok_or("The scene has a node that isn't an index")?,
+                    );
+                }
+            }
+            None if default_scene.is_some() || !scenes.is_empty() => {
+                return Err(format!("There is no scene {}", default_scene.unwrap_or(0)))
+            }
+            // Without scenes, the nodes that aren't children are drawn
+            None => {
+                let children: Vec<usize> = scene
+                    .nodes
+                    .iter()
+                    .flat_map(|node| node.children.iter().copied())
+                    .collect();
+                scene.roots = (0..scene.nodes.len())
+                    .filter(|node| !children.contains(node))
+                    .collect();
+            }
+        }
+        scene.validate()?;
+        return Ok(scene);
+    }
+
+    fn mesh(&self, index: usize, mesh: &Json, materials: &[Material]) -> Result<Mesh, String> {
+        let name: String = match mesh.get("name").as_str() {
+            Some(name) => name.to_string(),
+            None => format!("mesh{}", index),
+        };
+        let mut result: Mesh = Mesh::default();
+        // The materials of the file that the mesh has a copy of
+        let mut used: Vec<usize> = vec![];
+        for (primitive_index, primitive) in mesh.get("primitives").items().iter().enumerate() {
+            let at = |message: String| format!("primitives[{}]: {}", primitive_index, message);
+            let mode: usize = index_of(primitive.get("mode"), "mode")
+                .map_err(at)?
+                .unwrap_or(MODE_TRIANGLES);
+            match mode {
+                0..=3 => {
+                    log_warn!(
+                        "{}: skipping the points or lines of {} primitive {}",
+                        self.name,
+                        name,
+                        primitive_index
+                    );
+                    continue;
+                }
+                MODE_TRIANGLES | MODE_TRIANGLE_STRIP | MODE_TRIANGLE_FAN => {}
+                _ => return Err(at(format!("{} isn't a primitive mode", mode))),
+            }
+
+            let attributes: &Json = primitive.get("attributes");
+            let positions: Vec<[f32; 3]> =
+                match index_of(attributes.get("POSITION"), "POSITION").map_err(at)? {
+                    Some(accessor) => self.floats::<3>(accessor).map_err(at)?,
+                    None => return Err(at("There are no positions".to_string())),
+                };
+            let normals: Option<Vec<[f32; 3]>> =
+                match index_of(attributes.get("NORMAL"), "NORMAL").map_err(at)? {
+                    Some(accessor) => Some(self.floats::<3>(accessor).map_err(at)?),
+                    None => None,
+                };
+            let texcoords: Option<Vec<[f32; 2]>> =
+                match index_of(attributes.get("TEXCOORD_0"), "TEXCOORD_0").map_err(at)? {
+                    Some(accessor) => Some(self.floats::<2>(accessor).map_err(at)?),
+                    None => None,
+                };
+            let count: usize = positions.len();
+            for (attribute, len) in [
+                ("NORMAL", normals.as_ref().map(Vec::len)),
+                ("TEXCOORD_0", texcoords.as_ref().map(Vec::len)),
+            ] {
+                if len.is_some_and(|len| len != count) {
+                    return Err(at(format!(
+                        "{} has {} elements and POSITION {}",
+                        attribute,
+                        len.unwrap_or(0),
+                        count
+                    )));
+                }
+            }
+
+            let indices: Vec<u32> =
+                match index_of(primitive.get("indices"), "indices").map_err(at)? {
+                    Some(accessor) => self.indices(accessor).map_err(at)?,
+                    None => (0..count as u32).collect(),
+                };
+            if let Some(index) = indices.iter().find(|index| **index as usize >= count) {
+                return Err(at(format!(
+                    "Index {} is out of range, there are {} vertices",
+                    index, count
+                )));
+            }
+            let triangles: Vec<[u32; 3]> = triangles(&indices, mode);
+
+            let vertex = |index: u32| -> MeshVertex {
+                return MeshVertex {
+                    position: positions[index as usize],
+                    normal: normals
+                        .as_ref()
+                        .map(|normals| normals[index as usize])
+                        .unwrap_or([0.0, 0.0, 0.0]),
+                    texcoord: texcoords
+                        .as_ref()
+                        .map(|texcoords| texcoords[index as usize])
+                        .unwrap_or([0.0, 0.0]),
+                };
+            };
+            let first_index: u32 = result.indices.len() as u32;
+            let base: u32 = result.vertices.len() as u32;
+            match normals {
+                Some(_) => {
+                    result.vertices.extend((0..count as u32).map(vertex));
+                    result
+                        .indices
+                        .extend(triangles.iter().flatten().map(|index| base + index));
+                }
+                // Faces that don't share vertices can have their own normal
+                None => {
+                    for triangle in &triangles {
+                        let [a, b, c] = triangle.map(vertex);
+                        let normal: [f32; 3] = normalize(cross(
+                            subtract(b.position, a.position),
+                            subtract(c.position, a.position),
+                        ));
+                        for mut corner in [a, b, c] {
+                            corner.normal = normal;
+                            result.indices.push(result.vertices.len() as u32);
+                            result.vertices.push(corner);
+                        }
+                    }
+                }
+            }
+
+            let material: Option<usize> =
+                match index_of(primitive.get("material"), "material").map_err(at)? {
+                    Some(material) if material >= materials.len() => {
+                        return Err(at(format!(
+                            "There is no material {}, there are {}",
+                            material,
+                            materials.len()
+                        )))
+                    }
+                    Some(material) => Some(match used.iter().position(|used| *used == material) {
+                        Some(local) => local,
+                        None => {
+                            used.push(material);
+                            result.materials.push(materials[material].clone());
+                            used.len() - 1
+                        }
+                    }),
+                    None => None,
+                };
+            result.submeshes.push(Submesh {
+                name: name.clone(),
+                material,
+                first_index,
+                index_count: result.indices.len() as u32 - first_index,
+            });
+        }
+        return Ok(result);
+    }
+
+    fn material(
+        &self,
+        index: usize,
+        material: &Json,
+        images: &[Option<Texture>],
+    ) -> Result<Material, String> {
+        let pbr: &Json = material.get("pbrMetallicRoughness");
+        let emissive_strength: f32 = number_of(
+            material
+                .get("extensions")
+                .get(EMISSIVE_STRENGTH)
+                .get("emissiveStrength"),
+            1.0,
+            "emissiveStrength",
+        )?;
+        let alpha_mode: AlphaMode = match material.get("alphaMode").as_str() {
+            None | Some("OPAQUE") => AlphaMode::Opaque,
+            Some("MASK") => {
+                AlphaMode::Mask(number_of(material.get("alphaCutoff"), 0.5, "alphaCutoff")?)
+            }
+            Some("BLEND") => AlphaMode::Blend,
+            Some(mode) => return Err(format!("{} isn't an alpha mode", mode)),
+        };
+        return Ok(Material {
+            name: match material.get("name").as_str() {
+                Some(name) => name.to_string(),
+                None => format!("material{}", index),
+            },
+            base_color: numbers_of(pbr.get("baseColorFactor"), [1.0; 4], "baseColorFactor")?,
+            metallic: number_of(pbr.get("metallicFactor"), 1.0, "metallicFactor")?,
+            roughness: number_of(pbr.get("roughnessFactor"), 1.0, "roughnessFactor")?,
+            emissive: numbers_of(material.get("emissiveFactor"), [0.0; 3], "emissiveFactor")?
+                .map(|channel| channel * emissive_strength),
+            alpha_mode,
+            double_sided: material.get("doubleSided").as_bool().unwrap_or(false),
+            base_color_texture: self.texture(pbr.get("baseColorTexture"), images)?,
+            metallic_roughness_texture: self
+                .texture(pbr.get("metallicRoughnessTexture"), images)?,
+            normal_texture: self.texture(material.get("normalTexture"), images)?,
+            occlusion_texture: self.texture(material.get("occlusionTexture"), images)?,
+            emissive_texture: self.texture(material.get("emissiveTexture"), images)?,
+            ..Material::default()
+        });
+    }
+
+    /// The image of a texture reference like `{ "index": 2, "texCoord": 0 }`
+    fn texture(&self, info: &Json, images: &[Option<Texture>]) -> Result<Option<Texture>, String> {
+        let index: usize = match index_of(info.get("index"), "texture index")? {
+            Some(index) => index,
+            None if info.is_null() => return Ok(None),
+            None => return Err("The texture has no index".to_string()),
+        };
+        if info.get("texCoord").as_usize().unwrap_or(0) != 0 {
+            log_warn!(
+                "{}: texture {} uses a texture coordinate set other than TEXCOORD_0",
+                self.name,
+                index
+            );
+        }
+        let texture: &Json = self
+            .json
+            .get("textures")
+            .items()
+            .get(index)
+            .ok_or_else(|| format!("There is no texture {}", index))?;
+        // Images in formats of extensions, like KTX2 or WebP, have their source in the extension
+        let source: Option<usize> = match index_of(texture.get("source"), "source")? {
+            Some(source) => Some(source),
+            None => texture
+                .get("extensions")
+                .as_object()
+                .unwrap_or(&[])
+                .iter()
+                .find_map(|(_, extension)| extension.get("source").as_usize()),
+        };
+        return match source {
+            Some(source) => match images.get(source) {
+                Some(image) => Ok(image.clone()),
+                None => Err(format!("There is no image {}", source)),
+            },
+            None => Ok(None),
+        };
+    }
+
+    fn image(&self, image: &Json) -> Result<Option<Texture>, String> {
+        if let Some(uri) = image.get("uri").as_str() {
+            if !uri.starts_with("data:") {
+                return Ok(Some(Texture::File(
+                    relative_to(self.name, &decode_uri(uri)?).into(),
+                )));
+            }
+            let (mime_type, bytes): (String, Vec<u8>) = read_uri(self.name, uri, self.files)?;
+            return Ok(Some(Texture::Embedded {
+                mime_type,
+                bytes: Arc::from(bytes),
+            }));
+        }
+        return match index_of(image.get("bufferView"), "bufferView")? {
+            Some(view) => Ok(Some(Texture::Embedded {
+                mime_type: image.get("mimeType").as_str().unwrap_or("").to_string(),
+                bytes: Arc::from(self.view(view)?.0),
+            })),
+            None => Err("The image has no uri or bufferView".to_string()),
+        };
+    }
+
+    /// The bytes of a buffer view and the stride of its elements when they are interleaved
+    fn view(&self, index: usize) -> Result<(&[u8], Option<usize>), String> {
+        let view: &Json = self
+            .json
+            .get("bufferViews")
+            .items()
+            .get(index)
+            .ok_or_else(|| format!("There is no bufferView {}", index))?;
+        let buffer: usize = index_of(view.get("buffer"), "buffer")?
+            .ok_or_else(|| format!("bufferViews[{}] has no buffer", index))?;
+        let bytes: &[u8] = self
+            .buffers
+            .get(buffer)
+            .ok_or_else(|| format!("There is no buffer {}", buffer))?;
+        let offset: usize = index_of(view.get("byteOffset"), "byteOffset")?.unwrap_or(0);
+        let length: usize = index_of(view.get("byteLength"), "byteLength")?
+            .ok_or_else(|| format!("bufferViews[{}] has no byteLength", index))?;
+        let stride: Option<usize> = index_of(view.get("byteStride"), "byteStride")?;
+        let bytes: &[u8] =
+            slice(bytes, offset, length).map_err(|e| format!("bufferViews[{}]: {}", index, e))?;
+        return Ok((bytes, stride));
+    }
+
+    /// An accessor as vectors of `N` floats, normalized integers are mapped to 0 to 1 or -1 to 1
+    fn floats<const N: usize>(&self, index: usize) -> Result<Vec<[f32; N]>, String> {
+        let (components, values): (usize, Vec<f32>) = self.accessor(
+            index,
+            &[
+                BYTE,
+                UNSIGNED_BYTE,
+                SHORT,
+                UNSIGNED_SHORT,
+                UNSIGNED_INT,
+                FLOAT,
+            ],
+            |ty, normalized, bytes| {
+                return read_float(ty, normalized, bytes);
+            },
+        )?;
+        if components != N {
+            return Err(format!(
+                "accessors[{}] has {} components, not {}",
+                index, components, N
+            ));
+        }
+        return Ok(values
+            .chunks_exact(N)
+            .map(|chunk| std::array::from_fn(|component| chunk[component]))
+            .collect());
+    }
+
+    fn indices(&self, index: usize) -> Result<Vec<u32>, String> {
+        let (components, values): (usize, Vec<u32>) = self.accessor(
+            index,
+            &[UNSIGNED_BYTE, UNSIGNED_SHORT, UNSIGNED_INT],
+            |_, _, bytes| {
+                return read_uint(bytes);
+            },
+        )?;
+        if components != 1 {
+            return Err(format!(
+                "The indices of accessors[{}] aren't scalars",
+                index
+            ));
+        }
+        return Ok(values);
+    }
+
+    /// Decode every component of an accessor with `read`, which gets the component type, whether it
+    /// is normalized and its bytes. Sparse values replace the ones of the buffer view, an accessor
+    /// without one starts with zeros.
+    fn accessor<T: Copy>(
+        &self,
+        index: usize,
+        component_types: &[usize],
+        read: impl Fn(usize, bool, &[u8]) -> T,
+    ) -> Result<(usize, Vec<T>), String> {
+        let at = |message: String| format!("accessors[{}]: {}", index, message);
+        let accessor: &Json = self
+            .json
+            .get("accessors")
+            .items()
+            .get(index)
+            .ok_or_else(|| format!("There is no accessor {}", index))?;
+        let count: usize = index_of(accessor.get("count"), "count")
+            .map_err(at)?
+            .ok_or_else(|| at("There is no count".to_string()))?;
+        let component_type: usize = index_of(accessor.get("componentType"), "componentType")
+            .map_err(at)?
+            .unwrap_or(0);
+        if !component_types.contains(&component_type) {
+            return Err(at(format!(
+                "The component type {} can't be used here",
+                component_type
+            )));
+        }
+        let normalized: bool = accessor.get("normalized").as_bool().unwrap_or(false);
+        let size: usize = match component_type {
+            BYTE | UNSIGNED_BYTE => 1,
+            SHORT | UNSIGNED_SHORT => 2,
+            _ => 4,
+        };
+        let components: usize = match accessor.get("type").as_str() {
+            Some("SCALAR") => 1,
+            Some("VEC2") => 2,
+            Some("VEC3") => 3,
+            Some("VEC4") => 4,
+            // Smaller matrices have padded columns
+            Some("MAT2") if size == 4 => 4,
+            Some("MAT3") if size == 4 => 9,
+            Some("MAT4") => 16,
+            ty => {
+                return Err(at(format!(
+                    "The type {} isn't supported",
+                    ty.unwrap_or("(none)")
+                )))
+            }
+        };
+        let element: usize = size * components;
+
+        let mut values: Vec<T> =
+            match index_of(accessor.get("bufferView"), "bufferView").map_err(at)? {
+                Some(view) => {
+                    let (bytes, stride): (&[u8], Option<usize>) = self.view(view).map_err(at)?;
+                    let stride: usize = stride.unwrap_or(element);
+                    if stride < element {
+                        return Err(at(format!(
+                            "The stride {} is smaller than an element",
+                            stride
+                        )));
+                    }
+                    let offset: usize = index_of(accessor.get("byteOffset"), "byteOffset")
+                        .map_err(at)?
+                        .unwrap_or(0);
+                    let span: usize = match count {
+                        0 => 0,
+                        count => (count - 1)
+                            .checked_mul(stride)
+                            .and_then(|span| span.checked_add(element))
+                            .ok_or_else(|| at("The accessor is too big".to_string()))?,
+                    };
+                    let bytes: &[u8] = slice(bytes, offset, span).map_err(at)?;
+                    let mut values: Vec<T> = Vec::with_capacity(count * components);
+                    for element in 0..count {
+                        for component in 0..components {
+                            let start: usize = element * stride + component * size;
+                            values.push(read(
+                                component_type,
+                                normalized,
+                                &bytes[start..start + size],
+                            ));
+                        }
+                    }
+                    values
+                }
+                None => {
+                    let zero: T = read(component_type, normalized, &[0; 4][..size]);
+                    vec![
+                        zero;
+                        count
+                            .checked_mul(components)
+                            .ok_or_else(|| at("The accessor is too big".to_string()))?
+                    ]
+                }
+            };
+
+        let sparse: &Json = accessor.get("sparse");
+        if !sparse.is_null() {
+            let sparse_count: usize = index_of(sparse.get("count"), "sparse count")
+                .map_err(at)?
+                .unwrap_or(0);
+            let targets: &Json = sparse.get("indices");
+            let target_type: usize = index_of(targets.get("componentType"), "componentType")
+                .map_err(at)?
+                .unwrap_or(0);
+            let target_size: usize = match target_type {
+                UNSIGNED_BYTE => 1,
+                UNSIGNED_SHORT => 2,
+                UNSIGNED_INT => 4,
+                _ => {
+                    return Err(at(format!(
+                        "The sparse indices can't be of type {}",
+                        target_type
+                    )))
+                }
+            };
+            let target_bytes: &[u8] = self
+                .sparse_bytes(targets, sparse_count * target_size)
+                .map_err(at)?;
+            let value_bytes: &[u8] = self
+                .sparse_bytes(sparse.get("values"), sparse_count * element)
+                .map_err(at)?;
+            for sparse_index in 0..sparse_count {
+                let target: usize =
+                    read_uint(&target_bytes[sparse_index * target_size..][..target_size]) as usize;
+                if target >= count {
+                    return Err(at(format!("The sparse index {} is out of range", target)));
+                }
+                for component in 0..components {
+                    let start: usize = sparse_index * element + component * size;
+                    values[target * components + component] = read(
+                        component_type,
+                        normalized,
+                        &value_bytes[start..start + size],
+                    );
+                }
+            }
+        }
+        return Ok((components, values));
+    }
+
+    /// `len` bytes of the buffer view of the indices or values of a sparse accessor
+    fn sparse_bytes(&self, part: &Json, len: usize) -> Result<&[u8], String> {
+        let view: usize = index_of(part.get("bufferView"), "bufferView")?
+            .ok_or("The sparse accessor has no bufferView")?;
+        let offset: usize = index_of(part.get("byteOffset"), "byteOffset")?.unwrap_or(0);
+        return slice(self.view(view)?.0, offset, len);
+    }
+}
+
+fn node_of(node: &Json) -> Result<Node, String> {
+    let transform: Transform = match node.get("matrix").as_array() {
+        Some(_) => {
+            let values: [f32; 16] = numbers_of(node.get("matrix"), [0.0; 16], "matrix")?;
+            // The columns of glTF are the rows of DirectXMath, which multiplies row vectors
+            let matrix: XMFLOAT4X4 = XMFLOAT4X4 {
+                m: std::array::from_fn(|row| {
+                    std::array::from_fn(|column| values[row * 4 + column])
+                }),
+            };
+            Transform::from_matrix(&matrix)
+                .ok_or("The matrix can't be split into a translation, rotation and scale")?
+        }
+        None => Transform {
+            translation: numbers_of(node.get("translation"), [0.0; 3], "translation")?,
+            rotation: numbers_of(node.get("rotation"), [0.0, 0.0, 0.0, 1.0], "rotation")?,
+            scale: numbers_of(node.get("scale"), [1.0; 3], "scale")?,
+        },
+    };
+    return Ok(Node {
+        name: node.get("name").as_str().unwrap_or("").to_string(),
+        transform,
+        children: node
+            .get("children")
+            .items()
+            .iter()
+            .map(|child| child.as_usize().ok_or("A child isn't an index"))
+            .collect::<Result<Vec<usize>, &str>>()?,
+        mesh: index_of(node.get("mesh"), "mesh")?,
+        camera: index_of(node.get("camera"), "camera")?,
+        light: index_of(
+            node.get("extensions").get(LIGHTS_PUNCTUAL).get("light"),
+            "light",
+        )?,
+    });
+}
+
+fn camera_of(camera: &Json) -> Result<Camera, String> {
+    let required = |json: &Json, what: &str| -> Result<f32, String> {
+        return json.as_f32().ok_or_else(|| format!("There is no {}", what));
+    };
+    let projection: Projection = match camera.get("type").as_str() {
+        Some("perspective") => {
+            let perspective: &Json = camera.get("perspective");
+            Projection::Perspective {
+                fov_y: required(perspective.get("yfov"), "yfov")?,
+                aspect_ratio: perspective.get("aspectRatio").as_f32(),
+                near: required(perspective.get("znear"), "znear")?,
+                far: perspective.get("zfar").as_f32(),
+            }
+        }
+        Some("orthographic") => {
+            let orthographic: &Json = camera.get("orthographic");
+            Projection::Orthographic {
+                x_magnification: required(orthographic.get("xmag"), "xmag")?,
+                y_magnification: required(orthographic.get("ymag"), "ymag")?,
+                near: required(orthographic.get("znear"), "znear")?,
+                far: required(orthographic.get("zfar"), "zfar")?,
+            }
+        }
+        ty => return Err(format!("{} isn't a camera type", ty.unwrap_or("(none)"))),
+    };
+    return Ok(Camera {
+        name: camera.get("name").as_str().unwrap_or("").to_string(),
+        projection,
+    });
+}
+
+fn light_of(light: &Json) -> Result<Light, String> {
+    let kind: LightKind = match light.get("type").as_str() {
+        Some("directional") => LightKind::Directional,
+        Some("point") => LightKind::Point,
+        Some("spot") => {
+            let spot: &Json = light.get("spot");
+            LightKind::Spot {
+                inner_cone_angle: number_of(spot.get("innerConeAngle"), 0.0, "innerConeAngle")?,
+                outer_cone_angle: number_of(
+                    spot.get("outerConeAngle"),
+                    std::f32::consts::FRAC_PI_4,
+                    "outerConeAngle",
+                )?,
+            }
+        }
+        ty => return Err(format!("{} isn't a light type", ty.unwrap_or("(none)"))),
+    };
+    return Ok(Light {
+        name: light.get("name").as_str().unwrap_or("").to_string(),
+        kind,
+        color: numbers_of(light.get("color"), [1.0; 3], "color")?,
+        intensity: number_of(light.get("intensity"), 1.0, "intensity")?,
+        range: light.get("range").as_f32(),
+    });
+}
+
+/// The indices of the triangles of a primitive. Every other triangle of a strip is flipped so they all
+/// have the winding of the first one.
+fn triangles(indices: &[u32], mode: usize) -> Vec<[u32; 3]> {
+    let corners: usize = indices.len();
+    return match mode {
+        MODE_TRIANGLE_STRIP => (0..corners.saturating_sub(2))
+            .map(|i| match i % 2 {
+                0 => [indices[i], indices[i + 1], indices[i + 2]],
+                _ => [indices[i + 1], indices[i], indices[i + 2]],
+            })
+            .collect(),
+        MODE_TRIANGLE_FAN => (1..corners.saturating_sub(1))
+            .map(|i| [indices[0], indices[i], indices[i + 1]])
+            .collect(),
+        _ => indices
+            .chunks_exact(3)
+            .map(|triangle| [triangle[0], triangle[1], triangle[2]])
+            .collect(),
+    };
+}
+
+fn read_float(component_type: usize, normalized: bool, bytes: &[u8]) -> f32 {
+    return match (component_type, normalized) {
+        (FLOAT, _) => f32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]),
+        (BYTE, false) => bytes[0] as i8 as f32,
+        (BYTE, true) => (bytes[0] as i8 as f32 / 127.0).max(-1.0),
+        (UNSIGNED_BYTE, false) => bytes[0] as f32,
+        (UNSIGNED_BYTE, true) => bytes[0] as f32 / 255.0,
+        (SHORT, false) => i16::from_le_bytes([bytes[0], bytes[1]]) as f32,
+        (SHORT, true) => (i16::from_le_bytes([bytes[0], bytes[1]]) as f32 / 32767.0).max(-1.0),
+        (UNSIGNED_SHORT, false) => u16::from_le_bytes([bytes[0], bytes[1]]) as f32,
+        (UNSIGNED_SHORT, true) => u16::from_le_bytes([bytes[0], bytes[1]]) as f32 / 65535.0,
+        (_, false) => read_uint(bytes) as f32,
+        (_, true) => read_uint(bytes) as f32 / u32::MAX as f32,
+    };
+}
+
+/// A little endian unsigned integer of 1, 2 or 4 bytes
+fn read_uint(bytes: &[u8]) -> u32 {
+    return bytes
+        .iter()
+        .rev()
+        .fold(0, |value, byte| (value << 8) | *byte as u32);
+}
+
+/// An optional index, an error when it is there but isn't one
+fn index_of(json: &Json, what: &str) -> Result<Option<usize>, String> {
+    return match json {
+        Json::Null => Ok(None),
+        json => json
+            .as_usize()
+            .map(Some)
+            .ok_or_else(|| format!("The {} isn't an index", what)),
+    };
+}
+
+fn number_of(json: &Json, default: f32, what: &str) -> Result<f32, String> {
+    return match json {
+        Json::Null => Ok(default),
+        json => json
+            .as_f32()
+            .ok_or_else(|| format!("The {} isn't a number", what)),
+    };
+}
+
+/// An array of `N` numbers
+fn numbers_of<const N: usize>(
+    json: &Json,
+    default: [f32; N],
+    what: &str,
+) -> Result<[f32; N], String> {
+    if json.is_null() {
+        return Ok(default);
+    }
+    let values: Vec<f32> = json.items().iter().filter_map(Json::as_f32).collect();
+    if values.len() != N || json.items().len() != N {
+        return Err(format!("The {} has to be {} numbers", what, N));
+    }
+    return Ok(std::array::from_fn(|index| values[index]));
+}
+
+/// The MIME type and bytes of a `data:` URI, or the bytes of the file the URI names relative to `from`
+fn read_uri(from: &str, uri: &str, files: &dyn FileSystem) -> Result<(String, Vec<u8>), String> {
+    let data: &str = match uri.strip_prefix("data:") {
+        Some(data) => data,
+        None => {
+            let path: String = relative_to(from, &decode_uri(uri)?);
+            let bytes: Vec<u8> = files
+                .read_bytes(&path)
+                .ok_or_else(|| format!("Unable to read {}", path))?;
+            return Ok((String::new(), bytes));
+        }
+    };
+    let (header, payload): (&str, &str) = data.split_once(',').ok_or("The data URI has no ','")?;
+    return match header.strip_suffix(";base64") {
+        Some(mime_type) => Ok((mime_type.to_string(), decode_base64(payload)?)),
+        None => Ok((header.to_string(), decode_escapes(payload)?)),
+    };
+}
+
+/// Undo the `%20` escapes of a URI
+fn decode_uri(uri: &str) -> Result<String, String> {
+    return String::from_utf8(decode_escapes(uri)?).map_err(|_| format!("{} isn't UTF-8", uri));
+}
+
+/// The bytes of a URI with its escapes undone, which data URIs use for binary data
+fn decode_escapes(uri: &str) -> Result<Vec<u8>, String> {
+    let bytes: &[u8] = uri.as_bytes();
+    let mut decoded: Vec<u8> = Vec::with_capacity(bytes.len());
+    let mut index: usize = 0;
+    while index < bytes.len() {
+        match bytes[index] {
+            b'%' => {
+                let byte: u8 = uri
+                    .get(index + 1..index + 3)
+                    .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+                    .ok_or_else(|| format!("{} has a bad escape", uri))?;
+                decoded.push(byte);
+                index += 3;
+            }
+            byte => {
+                decoded.push(byte);
+                index += 1;
+            }
+        }
+    }
+    return Ok(decoded);
+}
+
+fn decode_base64(text: &str) -> Result<Vec<u8>, String> {
+    let mut bytes: Vec<u8> = Vec::with_capacity(text.len() / 4 * 3);
+    let mut bits: u32 = 0;
+    let mut bit_count: u32 = 0;
+    for byte in text.trim_end_matches('=').bytes() {
+        let value: u32 = match byte {
+            b'A'..=b'Z' => byte - b'A',
+            b'a'..=b'z' => byte - b'a' + 26,
+            b'0'..=b'9' => byte - b'0' + 52,
+            b'+' | b'-' => 62,
+            b'/' | b'_' => 63,
+            _ => return Err(format!("'{}' isn't base64", byte as char)),
+        } as u32;
+        bits = (bits << 6) | value;
+        bit_count += 6;
+        if bit_count >= 8 {
+            bit_count -= 8;
+            bytes.push((bits >> bit_count) as u8);
+        }
+    }
+    return Ok(bytes);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+    use crate::window::graphics::preprocessor::MemoryFiles;
+
+    fn fixture(name: &str) -> PathBuf {
+        return Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/data/mesh")
+            .join(name);
+    }
+
+    /// A buffer in a data URI without base64, every byte escaped
+    fn data_uri(bytes: &[u8]) -> String {
+        let escaped: String = bytes.iter().map(|byte| format!("%{:02X}", byte)).collect();
+        return format!("data:application/octet-stream,{}", escaped);
+    }
+
+    /// A glTF file with one buffer and the other members in `rest`
+    fn document(buffer: &[u8], rest: &str) -> Vec<u8> {
+        return format!(
+            r#"{{"asset": {{"version": "2.0"}}, "buffers": [{{"uri": "{}", "byteLength": {}}}], {}}}"#,
+            data_uri(buffer),
+            buffer.len(),
+            rest
+        )
+        .into_bytes();
+    }
+
+    /// Three positions of a triangle and then `extra`
+    fn triangle_buffer(extra: &[u8]) -> Vec<u8> {
+        let mut buffer: Vec<u8> = [0.0f32, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0]
+            .iter()
+            .flat_map(|value| value.to_le_bytes())
+            .collect();
+        buffer.extend(extra);
+        return buffer;
+    }
+
+    fn parse(bytes: &[u8]) -> Result<Scene, String> {
+        return parse_gltf("test.gltf", bytes, &MemoryFiles::new());
+    }
+
+    fn assert_close(matrix: &XMFLOAT4X4, expected: [[f32; 4]; 4]) {
+        for (row, expected) in matrix.m.iter().zip(expected) {
+            for (value, expected) in row.iter().zip(expected) {
+                assert!(
+                    (value - expected).abs() < 1e-5,
+                    "{:?} isn't {:?}",
+                    matrix.m,
+                    expected
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn external_and_binary_buffers_load_the_same_scene() {
+        let gltf: Scene = load_gltf(&fixture("scene.gltf")).unwrap();
+        assert_eq!(load_gltf(&fixture("scene.glb")), Ok(gltf.clone()));
+        assert_eq!(Scene::load(&fixture("scene.gltf")), Ok(gltf.clone()));
+        assert_eq!(gltf.name, "Main");
+        assert_eq!(gltf.roots, vec![0]);
+    }
+
+    #[test]
+    fn every_component_type_is_read() {
+        let text: String = std::fs::read_to_string(fixture("embedded.gltf")).unwrap();
+        let json: Json = Json::parse(&text).unwrap();
+        let files: MemoryFiles = MemoryFiles::new();
+        let document: Document = Document::new("embedded.gltf", &json, None, &files).unwrap();
+        let floats = |accessor: usize| -> Vec<f32> {
+            return document.floats::<1>(accessor).unwrap().concat();
+        };
+
+        assert_eq!(floats(0), vec![-128.0, -1.0, 0.0, 127.0]);
+        // -128 is below -1 and clamped
+        assert_eq!(floats(1), vec![-1.0, -1.0 / 127.0, 0.0, 1.0]);
+        assert_eq!(floats(2), vec![0.0, 51.0, 255.0, 1.0]);
+        assert_eq!(floats(3), vec![0.0, 51.0 / 255.0, 1.0, 1.0 / 255.0]);
+        assert_eq!(floats(4), vec![-32768.0, -1.0, 0.0, 32767.0]);
+        assert_eq!(floats(5), vec![-1.0, -1.0 / 32767.0, 0.0, 1.0]);
+        assert_eq!(floats(6), vec![0.0, 1.0, 65535.0, 2.0]);
+        assert_eq!(floats(7), vec![0.0, 1.0 / 65535.0, 1.0, 2.0 / 65535.0]);
+        assert_eq!(floats(8), vec![0.0, 1.0, u32::MAX as f32, 7.0]);
+        assert_eq!(floats(9), vec![0.5, -2.0, 3.0, 1000.0]);
+        // Every other float of the view
+        assert_eq!(floats(10), vec![0.5, 3.0]);
+        // Zeros with one sparse value
+        assert_eq!(floats(11), vec![0.0, 0.5, 0.0]);
+        assert_eq!(
+            document.floats::<3>(12).unwrap(),
+            vec![[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]]
+        );
+
+        assert_eq!(document.indices(2), Ok(vec![0, 51, 255, 1]));
+        assert_eq!(document.indices(6), Ok(vec![0, 1, 65535, 2]));
+        assert_eq!(document.indices(8), Ok(vec![0, 1, u32::MAX, 7]));
+        assert_eq!(
+            document.indices(0),
+            Err("accessors[0]: The component type 5120 can't be used here".to_string())
+        );
+        assert_eq!(
+            document.floats::<3>(9),
+            Err("accessors[9] has 1 components, not 3".to_string())
+        );
+        assert_eq!(
+            document.floats::<1>(13),
+            Err("There is no accessor 13".to_string())
+        );
+    }
+
+    #[test]
+    fn data_uris_embed_buffers_and_images() {
+        let scene: Scene = load_gltf(&fixture("embedded.gltf")).unwrap();
+        assert_eq!(scene.roots, vec![0]);
+        let mesh: &Mesh = &scene.meshes[0];
+        assert_eq!(mesh.submeshes[0].name, "mesh0");
+        assert_eq!(mesh.materials[0].name, "material0");
+        assert_eq!(mesh.materials[0].alpha_mode, AlphaMode::Blend);
+        assert_eq!(
+            mesh.materials[0].base_color_texture,
+            Some(Texture::Embedded {
+                mime_type: "image/png".to_string(),
+                bytes: Arc::from(b"\x89PNG".as_slice()),
+            })
+        );
+
+        assert_eq!(
+            read_uri("a.gltf", "data:text/plain,a%20b", &MemoryFiles::new()),
+            Ok(("text/plain".to_string(), b"a b".to_vec()))
+        );
+        assert_eq!(decode_base64("AAEC/w=="), Ok(vec![0, 1, 2, 255]));
+        assert!(decode_base64("AA*").is_err());
+        assert!(decode_uri("a%2").is_err());
+    }
+
+    #[test]
+    fn strips_and_fans_keep_the_winding_of_the_first_triangle() {
+        let scene: Scene = load_gltf(&fixture("scene.gltf")).unwrap();
+        let mesh: &Mesh = &scene.meshes[0];
+        // The lines are skipped
+        assert_eq!(mesh.submeshes.len(), 2);
+
+        let positions: Vec<[f32; 3]> = mesh.indices[..6]
+            .iter()
+            .map(|index| mesh.vertices[*index as usize].position)
+            .collect();
+        assert_eq!(
+            positions,
+            vec![
+                [0.0, 0.0, 0.0],
+                [1.0, 0.0, 0.0],
+                [0.0, 1.0, 0.0],
+                [0.0, 1.0, 0.0],
+                [1.0, 0.0, 0.0],
+                [1.0, 1.0, 0.0],
+            ]
+        );
+        // The strip has no normals, so every corner gets the normal of its face
+        assert!(mesh.vertices[..6]
+            .iter()
+            .all(|vertex| vertex.normal[2] == 1.0 && vertex.texcoord == [0.0, 0.0]));
+
+        // The fan shares its vertices and has normalized texture coordinates
+        assert_eq!(mesh.indices[6..], [6, 7, 9, 6, 9, 8]);
+        assert_eq!(mesh.vertices[9].texcoord, [1.0, 1.0]);
+        assert_eq!(mesh.submeshes[1].material, Some(0));
+
+        assert_eq!(
+            triangles(&[0, 1, 2, 3, 4], MODE_TRIANGLE_STRIP),
+            vec![[0, 1, 2], [2, 1, 3], [2, 3, 4]]
+        );
+        assert_eq!(
+            triangles(&[0, 1, 2, 3, 4], MODE_TRIANGLE_FAN),
+            vec![[0, 1, 2], [0, 2, 3], [0, 3, 4]]
+        );
+        assert_eq!(triangles(&[0, 1, 2, 3, 4], MODE_TRIANGLES), vec![[0, 1, 2]]);
+        assert!(triangles(&[0, 1], MODE_TRIANGLE_STRIP).is_empty());
+    }
+
+    #[test]
+    fn materials_read_the_pbr_factors() {
+        let scene: Scene = load_gltf(&fixture("scene.gltf")).unwrap();
+        assert_eq!(
+            scene.meshes[0].materials,
+            vec![Material {
+                name: "brick".to_string(),
+                base_color: [1.0, 0.5, 0.25, 1.0],
+                metallic: 0.0,
+                roughness: 0.75,
+                // Times the emissive strength
+                emissive: [2.0, 0.0, 0.0],
+                alpha_mode: AlphaMode::Mask(0.25),
+                double_sided: true,
+                base_color_texture: Some(Texture::File(fixture("textures/red brick.png"))),
+                ..Material::default()
+            }]
+        );
+    }
+
+    #[test]
+    fn nodes_place_their_children() {
+        let scene: Scene = load_gltf(&fixture("scene.gltf")).unwrap();
+        let names: Vec<&str> = scene.nodes.iter().map(|node| node.name.as_str()).collect();
+        assert_eq!(names, vec!["root", "child", "eye", "lamp", "unused"]);
+        assert_eq!(scene.nodes[0].children, vec![1, 2]);
+        assert_eq!(scene.nodes[1].children, vec![3]);
+        assert_eq!(scene.nodes[1].mesh, Some(0));
+        // The matrix of the eye is split
+        assert_eq!(scene.nodes[2].transform.translation, [0.0, 0.0, 5.0]);
+        assert_eq!(scene.nodes[2].transform.scale, [1.0, 1.0, 1.0]);
+
+        let world: Vec<Option<XMFLOAT4X4>> = scene.world_matrices();
+        // The child is turned a quarter around y and scaled by 2 below the root
+        assert_close(
+            world[1].as_ref().unwrap(),
+            [
+                [0.0, 0.0, -2.0, 0.0],
+                [0.0, 2.0, 0.0, 0.0],
+                [2.0, 0.0, 0.0, 0.0],
+                [1.0, 0.0, 0.0, 1.0],
+            ],
+        );
+        assert_close(
+            world[2].as_ref().unwrap(),
+            [
+                [1.0, 0.0, 0.0, 0.0],
+                [0.0, 1.0, 0.0, 0.0],
+                [0.0, 0.0, 1.0, 0.0],
+                [1.0, 0.0, 5.0, 1.0],
+            ],
+        );
+        // The lamp is 1 up in the scaled space of the child
+        assert_close(
+            world[3].as_ref().unwrap(),
+            [
+                [0.0, 0.0, -2.0, 0.0],
+                [0.0, 2.0, 0.0, 0.0],
+                [2.0, 0.0, 0.0, 0.0],
+                [1.0, 2.0, 0.0, 1.0],
+            ],
+        );
+        assert!(world[4].is_none());
+    }
+
+    #[test]
+    fn cameras_and_lights_are_read() {
+        let scene: Scene = load_gltf(&fixture("scene.gltf")).unwrap();
+        assert_eq!(
+            scene.cameras,
+            vec![
+                Camera {
+                    name: "perspective".to_string(),
+                    projection: Projection::Perspective {
+                        fov_y: 0.8,
+                        aspect_ratio: Some(1.5),
+                        near: 0.1,
+                        far: None,
+                    },
+                },
+                Camera {
+                    name: "orthographic".to_string(),
+                    projection: Projection::Orthographic {
+                        x_magnification: 2.0,
+                        y_magnification: 1.0,
+                        near: 0.5,
+                        far: 50.0,
+                    },
+                },
+            ]
+        );
+        assert_eq!(
+            scene.lights,
+            vec![
+                Light {
+                    name: "spot".to_string(),
+                    kind: LightKind::Spot {
+                        inner_cone_angle: 0.2,
+                        outer_cone_angle: 0.6,
+                    },
+                    color: [1.0, 0.5, 0.0],
+                    intensity: 20.0,
+                    range: Some(10.0),
+                },
+                Light {
+                    name: "sun".to_string(),
+                    kind: LightKind::Directional,
+                    color: [1.0, 1.0, 1.0],
+                    intensity: 1.0,
+                    range: None,
+                },
+            ]
+        );
+        assert_eq!(scene.nodes[2].camera, Some(0));
+        assert_eq!(scene.nodes[3].light, Some(0));
+        assert_eq!(scene.nodes[4].light, Some(1));
+    }
+
+    #[test]
+    fn out_of_range_indices_are_errors() {
+        let buffer: Vec<u8> = triangle_buffer(&[0, 1, 3, 0]);
+        let views: &str = r#""bufferViews": [{"buffer": 0, "byteLength": 36}, {"buffer": 0, "byteOffset": 36, "byteLength": 3}]"#;
+        let accessors: &str = r#""accessors": [
+            {"bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3"},
+            {"bufferView": 1, "componentType": 5121, "count": 3, "type": "SCALAR"}
+        ]"#;
+        let scene = |primitive: &str, nodes: &str| -> Result<Scene, String> {
+            return parse(&document(
+                &buffer,
+                &format!(
+                    r#"{}, {}, "meshes": [{{"primitives": [{}]}}], "nodes": {}"#,
+                    views, accessors, primitive, nodes
+                ),
+            ));
+        };
+
+        assert_eq!(
+            scene(r#"{"attributes": {"POSITION": 0}, "indices": 1}"#, "[]"),
+            Err("test.gltf: meshes[0]: primitives[0]: Index 3 is out of range, there are 3 vertices".to_string())
+        );
+        assert_eq!(
+            scene(r#"{"attributes": {"POSITION": 0}, "material": 0}"#, "[]"),
+            Err(
+                "test.gltf: meshes[0]: primitives[0]: There is no material 0, there are 0"
+                    .to_string()
+            )
+        );
+        assert_eq!(
+            scene(r#"{"attributes": {"POSITION": 2}}"#, "[]"),
+            Err("test.gltf: meshes[0]: primitives[0]: There is no accessor 2".to_string())
+        );
+        assert_eq!(
+            scene(r#"{"attributes": {"POSITION": 0}}"#, r#"[{"mesh": 1}]"#),
+            Err("test.gltf: Node 0 uses mesh 1, but there are 1".to_string())
+        );
+        assert_eq!(
+            scene(
+                r#"{"attributes": {"POSITION": 0}}"#,
+                r#"[{"children": [1]}, {"children": [0]}]"#
+            ),
+            Err("test.gltf: The parents of node 0 form a cycle".to_string())
+        );
+        // The view is 3 bytes, 4 don't fit
+        assert_eq!(
+            parse(&document(
+                &buffer,
+                &format!(
+                    r#"{}, "accessors": [{{"bufferView": 1, "componentType": 5121, "count": 4, "type": "SCALAR"}}],
+                    "meshes": [{{"primitives": [{{"attributes": {{"POSITION": 0}}}}]}}]"#,
+                    views
+                )
+            )),
+            Err("test.gltf: meshes[0]: primitives[0]: accessors[0]: Bytes 0 to 4 are past the end at 3".to_string())
+        );
+        assert!(scene(r#"{"attributes": {"POSITION": 0}, "mode": 7}"#, "[]")
+            .unwrap_err()
+            .ends_with("7 isn't a primitive mode"));
+    }
+
+    #[test]
+    fn attributes_need_as_many_elements_as_positions() {
+        let buffer: Vec<u8> = triangle_buffer(&[]);
+        let scene: Result<Scene, String> = parse(&document(
+            &buffer,
+            r#""bufferViews": [{"buffer": 0, "byteLength": 36}],
+            "accessors": [
+                {"bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3"},
+                {"bufferView": 0, "componentType": 5126, "count": 2, "type": "VEC3"},
+                {"bufferView": 0, "componentType": 5126, "count": 4, "type": "VEC2"}
+            ],
+            "meshes": [{"primitives": [{"attributes": {"POSITION": 0, "NORMAL": 1}}, {"attributes": {"POSITION": 0, "TEXCOORD_0": 2}}]}]"#,
+        ));
+        assert_eq!(
+            scene,
+            Err(
+                "test.gltf: meshes[0]: primitives[0]: NORMAL has 2 elements and POSITION 3"
+                    .to_string()
+            )
+        );
+
+        let too_long: Result<Scene, String> = parse(&document(
+            &buffer,
+            r#""bufferViews": [{"buffer": 0, "byteLength": 36}],
+            "accessors": [{"bufferView": 0, "componentType": 5126, "count": 4, "type": "VEC3"}],
+            "meshes": [{"primitives": [{"attributes": {"POSITION": 0}}]}]"#,
+        ));
+        assert_eq!(
+            too_long,
+            Err("test.gltf: meshes[0]: primitives[0]: accessors[0]: Bytes 0 to 48 are past the end at 36".to_string())
+        );
+    }
+
+    #[test]
+    fn truncated_glb_files_are_errors() {
+        let glb: Vec<u8> = std::fs::read(fixture("scene.glb")).unwrap();
+        let json_length: usize = read_u32(&glb, 12).unwrap() as usize;
+        let bin_chunk: usize = 20 + json_length;
+
+        let cut: &[u8] = &glb[..glb.len() - 8];
+        assert_eq!(
+            parse_gltf("scene.glb", cut, &MemoryFiles::new()),
+            Err(format!(
+                "scene.glb: The file has {} bytes, not {}",
+                cut.len(),
+                glb.len()
+            ))
+        );
+
+        // With a header that agrees, the binary chunk is too long
+        let mut cut: Vec<u8> = cut.to_vec();
+        let length: u32 = cut.len() as u32;
+        cut[8..12].copy_from_slice(&length.to_le_bytes());
+        assert_eq!(
+            parse_gltf("scene.glb", &cut, &MemoryFiles::new()),
+            Err(format!(
+                "scene.glb: The chunk at byte {} ends after the file",
+                bin_chunk
+            ))
+        );
+
+        assert!(parse_gltf("scene.glb", &glb[..10], &MemoryFiles::new()).is_err());
+        let mut version: Vec<u8> = glb.clone();
+        version[4] = 1;
+        assert_eq!(
+            parse_gltf("scene.glb", &version, &MemoryFiles::new()),
+            Err("scene.glb: GLB files of version 1 aren't supported".to_string())
+        );
+    }
+
+    #[test]
+    fn invalid_json_is_an_error_with_its_location() {
+        assert_eq!(
+            parse(b"{\"asset\": {\"version\": \"2.0\"},\n  \"nodes\": [}"),
+            Err("test.gltf:2:13: Expected a value".to_string())
+        );
+        assert_eq!(
+            parse(b"\xff"),
+            Err("test.gltf: The JSON isn't UTF-8".to_string())
+        );
+        assert_eq!(
+            parse(br#"{"asset": {"version": "1.0"}}"#),
+            Err("test.gltf: glTF 1.0 isn't supported, only 2.0 is".to_string())
+        );
+        assert_eq!(
+            parse(br#"{"asset": {"version": "2.0"}, "extensionsRequired": ["KHR_draco_mesh_compression"]}"#),
+            Err("test.gltf: The extension KHR_draco_mesh_compression isn't supported".to_string())
+        );
+        assert_eq!(
+            parse(br#"{"asset": {"version": "2.0"}, "buffers": [{"uri": "missing.bin", "byteLength": 4}]}"#),
+            Err("test.gltf: buffers[0]: Unable to read missing.bin".to_string())
+        );
+        assert_eq!(
+            parse(br#"{"asset": {"version": "2.0"}, "nodes": [{"mesh": -1}]}"#),
+            Err("test.gltf: nodes[0]: The mesh isn't an index".to_string())
+        );
+    }
+}
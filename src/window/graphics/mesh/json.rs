@@ -0,0 +1,461 @@
+use std::fmt;
+
+/// Objects deeper than this are rejected instead of overflowing the stack
+const MAX_DEPTH: usize = 128;
+
+/// A JSON value. Objects keep the order of their members.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Json {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+/// Where parsing JSON failed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JsonError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl fmt::Display for JsonError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return write!(f, "{}:{}: {}", self.line, self.column, self.message);
+    }
+}
+
+static NULL: Json = Json::Null;
+
+impl Json {
+    pub fn parse(text: &str) -> Result<Json, JsonError> {
+        let mut parser: Parser = Parser {
+            text,
+            bytes: text.as_bytes(),
+            offset: 0,
+        };
+        let value: Json = parser.value(0)?;
+        parser.whitespace();
+        if parser.offset != parser.bytes.len() {
+            return Err(parser.error("There is more after the value"));
+        }
+        return Ok(value);
+    }
+
+    /// The member of an object, [`Json::Null`] when there is none or this isn't an object. Lookups can
+    /// be chained like `json.get("a").get("b")`.
+    pub fn get(&self, key: &str) -> &Json {
+        return match self {
+            Json::Object(members) => members
+                .iter()
+                .find(|(name, _)| name == key)
+                .map(|(_, value)| value)
+                .unwrap_or(&NULL),
+            _ => &NULL,
+        };
+    }
+
+    pub fn is_null(&self) -> bool {
+        return matches!(self, Json::Null);
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        return match self {
+            Json::Bool(value) => Some(*value),
+            _ => None,
+        };
+    }
+
+    pub fn as_f64(&self) -> Option<f64> {
+        return match self {
+            Json::Number(value) => Some(*value),
+            _ => None,
+        };
+    }
+
+    pub fn as_f32(&self) -> Option<f32> {
+        return self.as_f64().map(|value| value as f32);
+    }
+
+    /// The number when it is a whole number that isn't negative
+    pub fn as_usize(&self) -> Option<usize> {
+        return self
+            .as_f64()
+            .filter(|value| value.fract() == 0.0 && *value >= 0.0 && *value <= u32::MAX as f64)
+            .map(|value| value as usize);
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        return match self {
+            Json::String(value) => Some(value),
+            _ => None,
+        };
+    }
+
+    pub fn as_array(&self) -> Option<&[Json]> {
+        return match self {
+            Json::Array(values) => Some(values),
+            _ => None,
+        };
+    }
+
+    /// The elements of an array, none when this isn't an array
+    pub fn items(&self) -> &[Json] {
+        return self.as_array().unwrap_or(&[]);
+    }
+
+    pub fn as_object(&self) -> Option<&[(String, Json)]> {
+        return match self {
+            Json::Object(members) => Some(members),
+            _ => None,
+        };
+    }
+}
+
+struct Parser<'a> {
+    text: &'a str,
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl Parser<'_> {
+    fn error(&self, message: &str) -> JsonError {
+        let before: &str = self.text.get(..self.offset).unwrap_or(self.text);
+        let line: usize = before.matches('\n').count() + 1;
+        let column: usize = before.len() - before.rfind('\n').map(|end| end + 1).unwrap_or(0) + 1;
+        return JsonError {
+            line,
+            column,
+            message: message.to_string(),
+        };
+    }
+
+    fn whitespace(&mut self) {
+        while self
+            .bytes
+            .get(self.offset)
+            .is_some_and(|byte| matches!(byte, b' ' | b'\t' | b'\n' | b'\r'))
+        {
+            self.offset += 1;
+        }
+    }
+
+    fn expect(&mut self, byte: u8) -> Result<(), JsonError> {
+        self.whitespace();
+        if self.bytes.get(self.offset) != Some(&byte) {
+            return Err(self.error(&format!("Expected '{}'", byte as char)));
+        }
+        self.offset += 1;
+        return Ok(());
+    }
+
+    fn value(&mut self, depth: usize) -> Result<Json, JsonError> {
+        if depth > MAX_DEPTH {
+            return Err(self.error("The values are nested too deep"));
+        }
+        self.whitespace();
+        return match self.bytes.get(self.offset) {
+            None => Err(self.error("Expected a value")),
+            Some(b'{') => self.object(depth),
+            Some(b'[') => self.array(depth),
+            Some(b'"') => self.string().map(Json::String),
+            Some(b'-' | b'0'..=b'9') => self.number(),
+            Some(_) => {
+                for (word, value) in [
+                    ("null", Json::Null),
+                    ("true", Json::Bool(true)),
+                    ("false", Json::Bool(false)),
+                ] {
+                    if self.bytes[self.offset..].starts_with(word.as_bytes()) {
+                        self.offset += word.len();
+                        return Ok(value);
+                    }
+                }
+                Err(self.error("Expected a value"))
+            }
+        };
+    }
+
+    fn object(&mut self, depth: usize) -> Result<Json, JsonError> {
+        self.offset += 1;
+        let mut members: Vec<(String, Json)> = vec![];
+        self.whitespace();
+        if self.bytes.get(self.offset) == Some(&b'}') {
+            self.offset += 1;
+            return Ok(Json::Object(members));
+        }
+        loop {
+            self.whitespace();
+            if self.bytes.get(self.offset) != Some(&b'"') {
+                return Err(self.error("Expected the name of a member"));
+            }
+            let name: String = self.string()?;
+            self.expect(b':')?;
+            members.push((name, self.value(depth + 1)?));
+            self.whitespace();
+            match self.bytes.get(self.offset) {
+                Some(b',') => self.offset += 1,
+                Some(b'}') => {
+                    self.offset += 1;
+                    return Ok(Json::Object(members));
+                }
+                _ => return Err(self.error("Expected ',' or '}'")),
+            }
+        }
+    }
+
+    fn array(&mut self, depth: usize) -> Result<Json, JsonError> {
+        self.offset += 1;
+        let mut values: Vec<Json> = vec![];
+        self.whitespace();
+        if self.bytes.get(self.offset) == Some(&b']') {
+            self.offset += 1;
+            return Ok(Json::Array(values));
+        }
+        loop {
+            values.push(self.value(depth + 1)?);
+            self.whitespace();
+            match self.bytes.get(self.offset) {
+                Some(b',') => self.offset += 1,
+                Some(b']') => {
+                    self.offset += 1;
+                    return Ok(Json::Array(values));
+                }
+                _ => return Err(self.error("Expected ',' or ']'")),
+            }
+        }
+    }
+
+    fn string(&mut self) -> Result<String, JsonError> {
+        self.offset += 1;
+        let mut string: String = String::new();
+        loop {
+            let start: usize = self.offset;
+            while self
+                .bytes
+                .get(self.offset)
+                .is_some_and(|byte| !matches!(byte, b'"' | b'\\') && *byte >= 0x20)
+            {
+                self.offset += 1;
+            }
+            string.push_str(&self.text[start..self.offset]);
+            match self.bytes.get(self.offset) {
+                Some(b'"') => {
+                    self.offset += 1;
+                    return Ok(string);
+                }
+                Some(b'\\') => {
+                    self.offset += 1;
+                    string.push(self.escape()?);
+                }
+                Some(_) => return Err(self.error("Control characters have to be escaped")),
+                None => return Err(self.error("The string doesn't end")),
+            }
+        }
+    }
+
+    fn escape(&mut self) -> Result<char, JsonError> {
+        let escaped: u8 = *self
+            .bytes
+            .get(self.offset)
+            .ok_or_else(|| self.error("The string doesn't end"))?;
+        self.offset += 1;
+        return match escaped {
+            b'"' => Ok('"'),
+            b'\\' => Ok('\\'),
+            b'/' => Ok('/'),
+            b'b' => Ok('\u{8}'),
+            b'f' => Ok('\u{c}'),
+            b'n' => Ok('\n'),
+            b'r' => Ok('\r'),
+            b't' => Ok('\t'),
+            b'u' => {
+                let high: u32 = self.hex4()?;
+                if !(0xD800..0xDC00).contains(&high) {
+                    return char::from_u32(high)
+                        .ok_or_else(|| self.error("The escape isn't a character"));
+                }
+                // A character outside the basic plane is written as two escaped halves
+                if !self.bytes[self.offset..].starts_with(b"\\u") {
+                    return Err(self.error("The second half of the character is missing"));
+                }
+                self.offset += 2;
+                let low: u32 = self.hex4()?;
+                if !(0xDC00..0xE000).contains(&low) {
+                    return Err(self.error("The second half of the character is wrong"));
+                }
+                char::from_u32(0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00))
+                    .ok_or_else(|| self.error("The escape isn't a character"))
+            }
+            _ => Err(self.error("Unknown escape")),
+        };
+    }
+
+    fn hex4(&mut self) -> Result<u32, JsonError> {
+        let digits: &str = self
+            .text
+            .get(self.offset..self.offset + 4)
+            .ok_or_else(|| self.error("Expected 4 hex digits"))?;
+        let value: u32 = u32::from_str_radix(digits, 16)
+            .ok()
+            .filter(|_| digits.bytes().all(|byte| byte.is_ascii_hexdigit()))
+            .ok_or_else(|| self.error("Expected 4 hex digits"))?;
+        self.offset += 4;
+        return Ok(value);
+    }
+
+    fn number(&mut self) -> Result<Json, JsonError> {
+        let start: usize = self.offset;
+        let digits = |parser: &mut Parser| -> usize {
+            let start: usize = parser.offset;
+            while parser
+                .bytes
+                .get(parser.offset)
+                .is_some_and(u8::is_ascii_digit)
+            {
+                parser.offset += 1;
+            }
+            return parser.offset - start;
+        };
+
+        if self.bytes[self.offset] == b'-' {
+            self.offset += 1;
+        }
+        let integer: usize = digits(self);
+        let leading_zero: bool = integer > 1 && self.bytes[self.offset - integer] == b'0';
+        if integer == 0 || leading_zero {
+            return Err(self.error("The number is malformed"));
+        }
+        if self.bytes.get(self.offset) == Some(&b'.') {
+            self.offset += 1;
+            if digits(self) == 0 {
+                return Err(self.error("The number is malformed"));
+            }
+        }
+        if matches!(self.bytes.get(self.offset), Some(b'e' | b'E')) {
+            self.offset += 1;
+            if matches!(self.bytes.get(self.offset), Some(b'+' | b'-')) {
+                self.offset += 1;
+            }
+            if digits(self) == 0 {
+                return Err(self.error("The number is malformed"));
+            }
+        }
+        return self.text[start..self.offset]
+            .parse()
+            .map(Json::Number)
+            .map_err(|_| self.error("The number is malformed"));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn error(text: &str) -> String {
+        return Json::parse(text).unwrap_err().to_string();
+    }
+
+    #[test]
+    fn values_are_parsed_in_order() {
+        let json: Json =
+            Json::parse(r#" {"b": [1, -2.5e2, 0.125], "a": {"c": true}, "n": null, "s": "x"} "#)
+                .unwrap();
+        let names: Vec<&str> = json
+            .as_object()
+            .unwrap()
+            .iter()
+            .map(|(name, _)| name.as_str())
+            .collect();
+        assert_eq!(names, vec!["b", "a", "n", "s"]);
+        assert_eq!(
+            json.get("b"),
+            &Json::Array(vec![
+                Json::Number(1.0),
+                Json::Number(-250.0),
+                Json::Number(0.125)
+            ])
+        );
+        assert_eq!(json.get("a").get("c").as_bool(), Some(true));
+        assert!(json.get("n").is_null());
+        assert_eq!(json.get("s").as_str(), Some("x"));
+        assert_eq!(Json::parse("[]"), Ok(Json::Array(Vec::new())));
+        assert_eq!(Json::parse("{}"), Ok(Json::Object(Vec::new())));
+    }
+
+    #[test]
+    fn lookups_that_miss_are_null() {
+        let json: Json = Json::parse(r#"{"a": [1, 2]}"#).unwrap();
+        assert!(json.get("b").get("c").is_null());
+        assert!(json.get("a").get("0").is_null());
+        assert_eq!(json.get("a").items().len(), 2);
+        assert!(json.get("b").items().is_empty());
+        assert_eq!(json.get("a").as_str(), None);
+    }
+
+    #[test]
+    fn indices_are_whole_numbers() {
+        let index = |text: &str| -> Option<usize> {
+            return Json::parse(text).unwrap().as_usize();
+        };
+        assert_eq!(index("0"), Some(0));
+        assert_eq!(index("4294967295"), Some(4294967295));
+        assert_eq!(index("3.0"), Some(3));
+        assert_eq!(index("3.5"), None);
+        assert_eq!(index("-1"), None);
+        assert_eq!(index("4294967296"), None);
+        assert_eq!(index("\"3\""), None);
+    }
+
+    #[test]
+    fn escapes_are_undone() {
+        assert_eq!(
+            Json::parse(r#""\"\\\/\b\f\n\r\t é 😀""#),
+            Ok(Json::String("\"\\/\u{8}\u{c}\n\r\t é 😀".to_string()))
+        );
+        assert_eq!(
+            Json::parse("\"é 😀\""),
+            Ok(Json::String("é 😀".to_string()))
+        );
+        assert_eq!(error(r#""\x""#), "1:4: Unknown escape");
+        assert_eq!(error(r#""\u12""#), "1:4: Expected 4 hex digits");
+        assert_eq!(
+            error(r#""\ud83d x""#),
+            "1:8: The second half of the character is missing"
+        );
+        assert_eq!(
+            error("\"a\nb\""),
+            "1:3: Control characters have to be escaped"
+        );
+        assert_eq!(error("\"abc"), "1:5: The string doesn't end");
+    }
+
+    #[test]
+    fn errors_point_at_where_parsing_stopped() {
+        assert_eq!(error(""), "1:1: Expected a value");
+        assert_eq!(error("[1,\n  2,]"), "2:5: Expected a value");
+        assert_eq!(error("{\"a\": 1 \"b\": 2}"), "1:9: Expected ',' or '}'");
+        assert_eq!(error("[1 2]"), "1:4: Expected ',' or ']'");
+        assert_eq!(error("{1: 2}"), "1:2: Expected the name of a member");
+        assert_eq!(error("1 2"), "1:3: There is more after the value");
+        assert_eq!(error("tru"), "1:1: Expected a value");
+        assert_eq!(error("01"), "1:3: The number is malformed");
+        assert_eq!(error("1."), "1:3: The number is malformed");
+        assert_eq!(error("1e"), "1:3: The number is malformed");
+    }
+
+    #[test]
+    fn deep_nesting_is_an_error() {
+        let nested = |depth: usize| -> String {
+            return "[".repeat(depth) + &"]".repeat(depth);
+        };
+        assert!(Json::parse(&nested(MAX_DEPTH)).is_ok());
+        assert_eq!(
+            Json::parse(&nested(MAX_DEPTH + 2)).unwrap_err().message,
+            "The values are nested too deep"
+        );
+    }
+}
@@ -0,0 +1,784 @@
+use std::{collections::HashMap, path::Path, path::PathBuf};
+
+use super::{
+    cross, normalize, relative_to, subtract, AlphaMode, Material, Mesh, MeshVertex, Submesh,
+    Texture,
+};
+use crate::{
+    log_warn,
+    window::graphics::preprocessor::{DiskFiles, FileSystem},
+};
+
+/// A corner of a face: indices of its position, texture coordinate and normal
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Corner {
+    position: usize,
+    texcoord: Option<usize>,
+    normal: Option<usize>,
+}
+
+/// Where the normal of a corner comes from. Corners with the same source and position share a vertex.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum NormalSource {
+    /// A `vn` of the file
+    File(usize),
+    /// The average of the faces of a smoothing group around the position
+    Smooth(u32),
+    /// The normal of one face, for faces that aren't smoothed
+    Flat(usize),
+}
+
+struct Triangle {
+    corners: [Corner; 3],
+    /// 0 when the face isn't smoothed
+    smoothing: u32,
+    /// The face the triangle was cut from
+    face: usize,
+}
+
+/// Triangles that are drawn with one material
+struct Group {
+    name: String,
+    material: Option<usize>,
+    triangles: Vec<Triangle>,
+}
+
+/// What the statements read so far describe
+#[derive(Default)]
+struct ObjState {
+    positions: Vec<[f32; 3]>,
+    texcoords: Vec<[f32; 2]>,
+    normals: Vec<[f32; 3]>,
+    /// The normal of every face, the length is the area times 2
+    face_normals: Vec<[f32; 3]>,
+    groups: Vec<Group>,
+    materials: Vec<Material>,
+    object: String,
+    group: String,
+    material: Option<usize>,
+    smoothing: u32,
+}
+
+/// Read an OBJ file and the material libraries it uses from disk
+pub fn load_obj(path: &Path) -> Result<Mesh, String> {
+    let name: String = path.to_string_lossy().to_string();
+    let source: String = std::fs::read_to_string(path)
+        .map_err(|e| format!("Unable to read {}: {}", path.display(), e))?;
+    return parse_obj(&name, &source, &DiskFiles);
+}
+
+/// Turn the source of an OBJ file into a mesh. `name` is the path of the file, the material libraries
+/// are read from `files` relative to it. Polygons are cut into triangles with the winding of the file,
+/// corners without a normal get the average normal of their smoothing group or the normal of their face,
+/// and texture coordinates are flipped so `v` points down like in Direct3D. Statements that aren't about
+/// triangles, like lines and curves, are skipped.
+pub fn parse_obj(name: &str, source: &str, files: &dyn FileSystem) -> Result<Mesh, String> {
+    let mut state: ObjState = ObjState::default();
+    for (line, statement) in statements(source) {
+        let at = |message: String| format!("{}({}): {}", name, line, message);
+        let mut words = statement.split_whitespace();
+        let keyword: &str = match words.next() {
+            Some(keyword) => keyword,
+            None => continue,
+        };
+        let args: Vec<&str> = words.collect();
+        match keyword {
+            "v" => {
+                let values: Vec<f32> = numbers(&args, 3, 7).map_err(at)?;
+                state.positions.push([values[0], values[1], values[2]]);
+            }
+            "vt" => {
+                let values: Vec<f32> = numbers(&args, 1, 3).map_err(at)?;
+                let v: f32 = values.get(1).copied().unwrap_or(0.0);
+                state.texcoords.push([values[0], 1.0 - v]);
+            }
+            "vn" => {
+                let values: Vec<f32> = numbers(&args, 3, 3).map_err(at)?;
+                state.normals.push([values[0], values[1], values[2]]);
+            }
+            "f" | "fo" => {
+                let corners: Vec<Corner> = args
+                    .iter()
+                    .map(|corner| state.corner(corner))
+                    .collect::<Result<Vec<Corner>, String>>()
+                    .map_err(at)?;
+                state.face(&corners).map_err(at)?;
+            }
+            "o" => {
+                state.object = args.join(" ");
+                state.group.clear();
+            }
+            "g" => state.group = args.join(" "),
+            "s" => {
+                state.smoothing = match args.first().copied() {
+                    Some("off") | None => 0,
+                    Some(group) => group
+                        .parse()
+                        .map_err(|_| at(format!("{} isn't a smoothing group", group)))?,
+                };
+            }
+            "usemtl" => {
+                let material: String = args.join(" ");
+                state.material = Some(match state.find_material(&material) {
+                    Some(index) => index,
+                    None => {
+                        log_warn!("{}({}): there is no material {}", name, line, material);
+                        state.materials.push(Material {
+                            name: material,
+                            ..Material::default()
+                        });
+                        state.materials.len() - 1
+                    }
+                });
+            }
+            "mtllib" => {
+                for library in args {
+                    let path: String = relative_to(name, library);
+                    let source: String = match files.read(&path) {
+                        Some(source) => source,
+                        None => {
+                            log_warn!("{}({}): unable to read {}", name, line, path);
+                            continue;
+                        }
+                    };
+                    for material in parse_mtl(&path, &source)? {
+                        match state.find_material(&material.name) {
+                            Some(index) => state.materials[index] = material,
+                            None => state.materials.push(material),
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    return Ok(state.into_mesh());
+}
+
+/// Read the materials of an MTL file. `name` is the path of the file, textures are relative to it.
+pub fn parse_mtl(name: &str, source: &str) -> Result<Vec<Material>, String> {
+    let mut materials: Vec<Material> = vec![];
+    for (line, statement) in statements(source) {
+        let at = |message: String| format!("{}({}): {}", name, line, message);
+        let mut words = statement.split_whitespace();
+        let keyword: &str = match words.next() {
+            Some(keyword) => keyword,
+            None => continue,
+        };
+        let args: Vec<&str> = words.collect();
+        if keyword == "newmtl" {
+            materials.push(Material {
+                name: args.join(" "),
+                ..Material::default()
+            });
+            continue;
+        }
+
+        let material: &mut Material = match materials.last_mut() {
+            Some(material) => material,
+            None => return Err(at(format!("{} comes before the first newmtl", keyword))),
+        };
+        match keyword {
+            "Kd" => {
+                let color: [f32; 3] = color(&args).map_err(at)?;
+                material.base_color[..3].copy_from_slice(&color);
+            }
+            "Ka" => material.ambient = color(&args).map_err(at)?,
+            "Ks" => material.specular = color(&args).map_err(at)?,
+            "Ke" => material.emissive = color(&args).map_err(at)?,
+            "Ns" => material.shininess = numbers(&args, 1, 1).map_err(at)?[0],
+            // `d -halo 0.5` has the factor last
+            "d" => {
+                material.base_color[3] =
+                    numbers(&args[args.len().saturating_sub(1)..], 1, 1).map_err(at)?[0]
+            }
+            "Tr" => material.base_color[3] = 1.0 - numbers(&args, 1, 1).map_err(at)?[0],
+            "map_Kd" => material.base_color_texture = Some(texture(name, &args).map_err(at)?),
+            "map_Bump" | "map_bump" | "bump" | "norm" => {
+                material.normal_texture = Some(texture(name, &args).map_err(at)?)
+            }
+            "map_Ks" => material.specular_texture = Some(texture(name, &args).map_err(at)?),
+            _ => {}
+        }
+    }
+    for material in &mut materials {
+        if material.base_color[3] < 1.0 {
+            material.alpha_mode = AlphaMode::Blend;
+        }
+    }
+    return Ok(materials);
+}
+
+impl ObjState {
+    /// A corner of a face like `3`, `3/1`, `3//2` or `3/1/2`
+    fn corner(&self, text: &str) -> Result<Corner, String> {
+        let parts: Vec<&str> = text.split('/').collect();
+        if parts.len() > 3 || parts[0].is_empty() {
+            return Err(format!("{} isn't a face corner", text));
+        }
+        let optional = |index: usize, count: usize| -> Result<Option<usize>, String> {
+            return match parts.get(index) {
+                None | Some(&"") => Ok(None),
+                Some(part) => resolve(part, count).map(Some),
+            };
+        };
+        return Ok(Corner {
+            position: resolve(parts[0], self.positions.len())?,
+            texcoord: optional(1, self.texcoords.len())?,
+            normal: optional(2, self.normals.len())?,
+        });
+    }
+
+    fn face(&mut self, corners: &[Corner]) -> Result<(), String> {
+        if corners.len() < 3 {
+            return Err(format!("A face needs 3 corners, not {}", corners.len()));
+        }
+        let points: Vec<[f32; 3]> = corners
+            .iter()
+            .map(|corner| self.positions[corner.position])
+            .collect();
+        let normal: [f32; 3] = newell_normal(&points);
+        let face: usize = self.face_normals.len();
+        self.face_normals.push(normal);
+
+        let key: (String, Option<usize>) = (self.group_name(), self.material);
+        let group: usize = match self
+            .groups
+            .iter()
+            .position(|group| group.name == key.0 && group.material == key.1)
+        {
+            Some(group) => group,
+            None => {
+                self.groups.push(Group {
+                    name: key.0,
+                    material: key.1,
+                    triangles: vec![],
+                });
+                self.groups.len() - 1
+            }
+        };
+        for [a, b, c] in triangulate(&points, normal) {
+            self.groups[group].triangles.push(Triangle {
+                corners: [corners[a], corners[b], corners[c]],
+                smoothing: self.smoothing,
+                face,
+            });
+        }
+        return Ok(());
+    }
+
+    fn group_name(&self) -> String {
+        return match (self.object.is_empty(), self.group.is_empty()) {
+            (_, true) => self.object.clone(),
+            (true, false) => self.group.clone(),
+            (false, false) => format!("{}/{}", self.object, self.group),
+        };
+    }
+
+    fn find_material(&self, name: &str) -> Option<usize> {
+        return self
+            .materials
+            .iter()
+            .position(|material| material.name == name);
+    }
+
+    fn into_mesh(self) -> Mesh {
+        // Every smoothing group sums the normals of its triangles around a position, so bigger faces
+        // count more
+        let mut smooth_normals: HashMap<(u32, usize), [f32; 3]> = HashMap::new();
+        for triangle in self.groups.iter().flat_map(|group| &group.triangles) {
+            if triangle.smoothing == 0 {
+                continue;
+            }
+            let [a, b, c] = triangle
+                .corners
+                .map(|corner| self.positions[corner.position]);
+            let normal: [f32; 3] = cross(subtract(b, a), subtract(c, a));
+            for corner in &triangle.corners {
+                let sum: &mut [f32; 3] = smooth_normals
+                    .entry((triangle.smoothing, corner.position))
+                    .or_insert([0.0, 0.0, 0.0]);
+                for axis in 0..3 {
+                    sum[axis] += normal[axis];
+                }
+            }
+        }
+
+        let mut mesh: Mesh = Mesh {
+            materials: self.materials,
+            ..Mesh::default()
+        };
+        let mut vertices: HashMap<(usize, Option<usize>, NormalSource), u32> = HashMap::new();
+        for group in self.groups {
+            let first_index: u32 = mesh.indices.len() as u32;
+            for triangle in &group.triangles {
+                for corner in &triangle.corners {
+                    let source: NormalSource = match (corner.normal, triangle.smoothing) {
+                        (Some(normal), _) => NormalSource::File(normal),
+                        (None, 0) => NormalSource::Flat(triangle.face),
+                        (None, smoothing) => NormalSource::Smooth(smoothing),
+                    };
+                    let index: u32 = *vertices
+                        .entry((corner.position, corner.texcoord, source))
+                        .or_insert_with(|| {
+                            let normal: [f32; 3] = match source {
+                                NormalSource::File(normal) => self.normals[normal],
+                                NormalSource::Flat(face) => self.face_normals[face],
+                                NormalSource::Smooth(smoothing) => {
+                                    smooth_normals[&(smoothing, corner.position)]
+                                }
+                            };
+                            mesh.vertices.push(MeshVertex {
+                                position: self.positions[corner.position],
+                                normal: normalize(normal),
+                                texcoord: corner
+                                    .texcoord
+                                    .map(|texcoord| self.texcoords[texcoord])
+                                    .unwrap_or([0.0, 0.0]),
+                            });
+                            return mesh.vertices.len() as u32 - 1;
+                        });
+                    mesh.indices.push(index);
+                }
+            }
+            mesh.submeshes.push(Submesh {
+                name: group.name,
+                material: group.material,
+                first_index,
+                index_count: mesh.indices.len() as u32 - first_index,
+            });
+        }
+        return mesh;
+    }
+}
+
+/// The statements of an OBJ or MTL file with the line they start on, without comments. A `\` at the end
+/// of a line continues the statement on the next one.
+fn statements(source: &str) -> Vec<(usize, String)> {
+    let mut statements: Vec<(usize, String)> = vec![];
+    let mut continued: Option<(usize, String)> = None;
+    for (index, line) in source.lines().enumerate() {
+        let line: &str = line.split('#').next().unwrap_or("");
+        let (start, mut statement) = continued.take().unwrap_or((index + 1, String::new()));
+        match line.trim_end().strip_suffix('\\') {
+            Some(part) => {
+                statement.push_str(part);
+                statement.push(' ');
+                continued = Some((start, statement));
+            }
+            None => {
+                statement.push_str(line);
+                statements.push((start, statement));
+            }
+        }
+    }
+    statements.extend(continued);
+    return statements;
+}
+
+/// From `min` to `max` numbers, the ones after `max` are ignored like the vertex colors after a position
+fn numbers(args: &[&str], min: usize, max: usize) -> Result<Vec<f32>, String> {
+    if args.len() < min {
+        return Err(format!(
+            "Expected {} {}, not {}",
+            min,
+            match min {
+                1 => "number",
+                _ => "numbers",
+            },
+            args.len()
+        ));
+    }
+    return args
+        .iter()
+        .take(max)
+        .map(|arg| {
+            arg.parse::<f32>()
+                .ok()
+                .filter(|value| value.is_finite())
+                .ok_or_else(|| format!("{} isn't a number", arg))
+        })
+        .collect();
+}
+
+/// An RGB color, one number is used for all channels
+fn color(args: &[&str]) -> Result<[f32; 3], String> {
+    if args
+        .first()
+        .is_some_and(|arg| *arg == "spectral" || *arg == "xyz")
+    {
+        return Err(format!("{} colors aren't supported", args[0]));
+    }
+    let values: Vec<f32> = numbers(args, 1, 3)?;
+    return match values.len() {
+        3 => Ok([values[0], values[1], values[2]]),
+        1 => Ok([values[0]; 3]),
+        count => Err(format!("Expected 1 or 3 numbers, not {}", count)),
+    };
+}
+
+/// The file of a texture statement, which comes after its options
+fn texture(mtl: &str, args: &[&str]) -> Result<Texture, String> {
+    return match args.last() {
+        Some(file) => Ok(Texture::File(PathBuf::from(relative_to(mtl, file)))),
+        None => Err("The texture has no file".to_string()),
+    };
+}
+
+/// The index of a 1-based OBJ index, negative indices count back from the end of the `count` elements
+/// read so far
+fn resolve(text: &str, count: usize) -> Result<usize, String> {
+    let index: i64 = text
+        .parse()
+        .map_err(|_| format!("{} isn't an index", text))?;
+    let resolved: i64 = match index {
+        0 => return Err("Indices start at 1, not 0".to_string()),
+        index if index < 0 => count as i64 + index,
+        index => index - 1,
+    };
+    if resolved < 0 || resolved >= count as i64 {
+        return Err(format!(
+            "{} is out of range, there are {} elements",
+            index, count
+        ));
+    }
+    return Ok(resolved as usize);
+}
+
+/// The normal of a polygon, which works for polygons that aren't flat or convex. The length is the area
+/// times 2.
+fn newell_normal(points: &[[f32; 3]]) -> [f32; 3] {
+    let mut normal: [f32; 3] = [0.0, 0.0, 0.0];
+    for (index, a) in points.iter().enumerate() {
+        let b: [f32; 3] = points[(index + 1) % points.len()];
+        normal[0] += (a[1] - b[1]) * (a[2] + b[2]);
+        normal[1] += (a[2] - b[2]) * (a[0] + b[0]);
+        normal[2] += (a[0] - b[0]) * (a[1] + b[1]);
+    }
+    return normal;
+}
+
+/// Cut a polygon into triangles by clipping ears on the plane of its normal. The triangles keep the
+/// winding of the polygon. When no ear is left, like for polygons that cross themselves, the rest
+/// becomes a fan.
+fn triangulate(points: &[[f32; 3]], normal: [f32; 3]) -> Vec<[usize; 3]> {
+    if points.len() == 3 {
+        return vec![[0, 1, 2]];
+    }
+
+    // Drop the axis the normal is closest to and turn the polygon counterclockwise
+    let axis: usize = (0..3)
+        .max_by(|a, b| normal[*a].abs().total_cmp(&normal[*b].abs()))
+        .unwrap_or(2);
+    let (u, v) = match normal[axis] >= 0.0 {
+        true => ((axis + 1) % 3, (axis + 2) % 3),
+        false => ((axis + 2) % 3, (axis + 1) % 3),
+    };
+    let flat: Vec<[f32; 2]> = points.iter().map(|point| [point[u], point[v]]).collect();
+    let area = |a: [f32; 2], b: [f32; 2], c: [f32; 2]| -> f32 {
+        return (b[0] - a[0]) * (c[1] - a[1]) - (b[1] - a[1]) * (c[0] - a[0]);
+    };
+
+    let mut triangles: Vec<[usize; 3]> = vec![];
+    let mut remaining: Vec<usize> = (0..points.len()).collect();
+    while remaining.len() > 3 {
+        let count: usize = remaining.len();
+        let ear: Option<usize> = (0..count).find(|index| {
+            let [a, b, c] = [
+                remaining[(index + count - 1) % count],
+                remaining[*index],
+                remaining[(index + 1) % count],
+            ];
+            if area(flat[a], flat[b], flat[c]) <= 0.0 {
+                return false;
+            }
+            return !remaining.iter().any(|point| {
+                let p: [f32; 2] = flat[*point];
+                if [a, b, c].contains(point) || [flat[a], flat[b], flat[c]].contains(&p) {
+                    return false;
+                }
+                return area(flat[a], flat[b], p) >= 0.0
+                    && area(flat[b], flat[c], p) >= 0.0
+                    && area(flat[c], flat[a], p) >= 0.0;
+            });
+        });
+        match ear {
+            Some(index) => {
+                triangles.push([
+                    remaining[(index + count - 1) % count],
+                    remaining[index],
+                    remaining[(index + 1) % count],
+                ]);
+                remaining.remove(index);
+            }
+            None => break,
+        }
+    }
+    for index in 1..remaining.len() - 1 {
+        triangles.push([remaining[0], remaining[index], remaining[index + 1]]);
+    }
+    return triangles;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::window::graphics::preprocessor::MemoryFiles;
+
+    fn fixture(name: &str) -> PathBuf {
+        return Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/data/mesh")
+            .join(name);
+    }
+
+    fn parse(source: &str) -> Result<Mesh, String> {
+        return parse_obj("mesh.obj", source, &MemoryFiles::new());
+    }
+
+    /// The corners of the triangles of a submesh
+    fn triangles(mesh: &Mesh, submesh: usize) -> Vec<[[f32; 3]; 3]> {
+        let submesh: &Submesh = &mesh.submeshes[submesh];
+        let indices: &[u32] = &mesh.indices
+            [submesh.first_index as usize..(submesh.first_index + submesh.index_count) as usize];
+        return indices
+            .chunks(3)
+            .map(|triangle| {
+                let position = |corner: usize| mesh.vertices[triangle[corner] as usize].position;
+                return [position(0), position(1), position(2)];
+            })
+            .collect();
+    }
+
+    fn assert_close(a: [f32; 3], b: [f32; 3]) {
+        assert!(
+            (0..3).all(|axis| (a[axis] - b[axis]).abs() < 1e-5),
+            "{:?} isn't {:?}",
+            a,
+            b
+        );
+    }
+
+    #[test]
+    fn the_groups_of_a_file_are_submeshes() {
+        let mesh: Mesh = load_obj(&fixture("shapes.obj")).unwrap();
+        let submeshes: Vec<(&str, Option<usize>, u32)> = mesh
+            .submeshes
+            .iter()
+            .map(|submesh| (submesh.name.as_str(), submesh.material, submesh.index_count))
+            .collect();
+        assert_eq!(
+            submeshes,
+            vec![
+                ("quad", Some(0), 6),
+                ("floor/corner", Some(1), 12),
+                ("pyramid", Some(2), 12),
+            ]
+        );
+        assert_eq!(mesh.triangle_count(), 10);
+        assert_eq!(mesh.vertices.len(), 15);
+        assert!(mesh.indices_u16().is_some());
+        assert_eq!(Mesh::load(&fixture("shapes.obj")), Ok(mesh));
+    }
+
+    #[test]
+    fn quads_keep_their_texture_coordinates_and_normals() {
+        let mesh: Mesh = load_obj(&fixture("shapes.obj")).unwrap();
+        assert_eq!(
+            triangles(&mesh, 0),
+            vec![
+                [[0.0, 1.0, 0.0], [0.0, 0.0, 0.0], [1.0, 0.0, 0.0]],
+                [[1.0, 0.0, 0.0], [1.0, 1.0, 0.0], [0.0, 1.0, 0.0]],
+            ]
+        );
+        for vertex in &mesh.vertices[..4] {
+            assert_eq!(vertex.normal, [0.0, 0.0, 1.0]);
+            // v is flipped
+            assert_eq!(
+                vertex.texcoord,
+                [vertex.position[0], 1.0 - vertex.position[1]]
+            );
+        }
+    }
+
+    #[test]
+    fn concave_polygons_are_cut_inside_their_outline() {
+        let mesh: Mesh = load_obj(&fixture("shapes.obj")).unwrap();
+        let floor: Vec<[[f32; 3]; 3]> = triangles(&mesh, 1);
+        assert_eq!(floor.len(), 4);
+        let mut area: f32 = 0.0;
+        for [a, b, c] in floor {
+            let normal: [f32; 3] = cross(subtract(b, a), subtract(c, a));
+            // The winding of the file faces +z
+            assert!(normal[2] > 0.0);
+            area += normal[2] / 2.0;
+            // No triangle covers the missing corner of the L
+            let center: [f32; 3] = [(a[0] + b[0] + c[0]) / 3.0, (a[1] + b[1] + c[1]) / 3.0, 1.0];
+            assert!(center[0] < 1.0 || center[1] < 1.0);
+        }
+        assert_eq!(area, 3.0);
+
+        let pentagon: Mesh =
+            parse("v 0 0 0\nv 2 0 0\nv 3 2 0\nv 1 3 0\nv -1 2 0\nf 1 2 3 4 5\n").unwrap();
+        assert_eq!(pentagon.triangle_count(), 3);
+    }
+
+    #[test]
+    fn normals_are_generated_for_flat_and_smooth_faces() {
+        let mesh: Mesh = load_obj(&fixture("shapes.obj")).unwrap();
+        for vertex in &mesh.vertices[4..10] {
+            assert_eq!(vertex.normal, [0.0, 0.0, 1.0]);
+        }
+
+        // The sides of the pyramid share the normals of their corners
+        let normal = |position: [f32; 3]| -> [f32; 3] {
+            return mesh.vertices[10..]
+                .iter()
+                .find(|vertex| vertex.position == position)
+                .unwrap()
+                .normal;
+        };
+        assert_close(normal([0.0, 1.0, 0.0]), [0.0, 1.0, 0.0]);
+        assert_close(normal([-1.0, 0.0, 1.0]), normalize([-1.0, 2.0, 1.0]));
+        assert_close(normal([1.0, 0.0, -1.0]), normalize([1.0, 2.0, -1.0]));
+
+        // Without smoothing every face has its own vertices
+        let flat: Mesh =
+            parse("v -1 0 -1\nv 1 0 -1\nv 1 0 1\nv -1 0 1\nv 0 1 0\nf 4 3 5\nf 3 2 5\n").unwrap();
+        assert_eq!(flat.vertices.len(), 6);
+        assert_close(flat.vertices[0].normal, normalize([0.0, 1.0, 1.0]));
+        assert_close(flat.vertices[3].normal, normalize([1.0, 1.0, 0.0]));
+    }
+
+    #[test]
+    fn negative_indices_count_back_from_the_last_element() {
+        let relative: Mesh =
+            parse("v 0 0 0\nv 1 0 0\nv 0 1 0\nvt 0 0\nvn 0 0 1\nf -3/-1/-1 -2/-1/-1 -1/-1/-1\n")
+                .unwrap();
+        let absolute: Mesh =
+            parse("v 0 0 0\nv 1 0 0\nv 0 1 0\nvt 0 0\nvn 0 0 1\nf 1/1/1 2/1/1 3/1/1\n").unwrap();
+        assert_eq!(relative, absolute);
+
+        // Only what was read before the face counts
+        assert_eq!(
+            parse("v 0 0 0\nv 1 0 0\nf -1 -2 -3\nv 0 1 0\n"),
+            Err("mesh.obj(3): -3 is out of range, there are 2 elements".to_string())
+        );
+    }
+
+    #[test]
+    fn materials_read_their_opacity_and_shininess() {
+        let mesh: Mesh = load_obj(&fixture("shapes.obj")).unwrap();
+        let glass: &Material = mesh.material("glass").unwrap();
+        assert_eq!(glass.base_color, [0.2, 0.4, 0.8, 0.5]);
+        assert_eq!(glass.shininess, 96.0);
+        assert_eq!(glass.alpha_mode, AlphaMode::Blend);
+        assert_eq!(
+            glass.base_color_texture,
+            Some(Texture::File(fixture("textures/glass.png")))
+        );
+
+        // Tr is the transparency, the opposite of d
+        let tinted: &Material = mesh.material("tinted").unwrap();
+        assert_eq!(tinted.base_color, [1.0, 1.0, 1.0, 0.75]);
+        assert_eq!(tinted.alpha_mode, AlphaMode::Blend);
+
+        let plain: &Material = mesh.material("plain").unwrap();
+        assert_eq!(plain.ambient, [0.1, 0.1, 0.1]);
+        assert_eq!(plain.specular, [0.5, 0.5, 0.5]);
+        assert_eq!(plain.alpha_mode, AlphaMode::Opaque);
+        assert_eq!(
+            plain.normal_texture,
+            Some(Texture::File(fixture("textures/bumps.png")))
+        );
+    }
+
+    #[test]
+    fn missing_libraries_and_materials_get_the_default_material() {
+        let mesh: Mesh =
+            parse("mtllib missing.mtl\nv 0 0 0\nv 1 0 0\nv 0 1 0\nusemtl metal\nf 1 2 3\n")
+                .unwrap();
+        assert_eq!(
+            mesh.materials,
+            vec![Material {
+                name: "metal".to_string(),
+                ..Material::default()
+            }]
+        );
+        assert_eq!(mesh.submeshes[0].material, Some(0));
+    }
+
+    #[test]
+    fn unknown_statements_are_ignored() {
+        let source: &str = "v 0 0 0\nv 1 0 0\nv 0 1 0\nl 1 2\ncstype bspline\nmg 1\nf 1 2 \\\n 3\n";
+        let mesh: Mesh = parse(source).unwrap();
+        assert_eq!(mesh.triangle_count(), 1);
+
+        let materials: Vec<Material> =
+            parse_mtl("mesh.mtl", "newmtl red\nillum 2\nsharpness 60\n").unwrap();
+        assert_eq!(materials.len(), 1);
+    }
+
+    #[test]
+    fn malformed_statements_are_errors_at_their_line() {
+        let error = |source: &str| -> String {
+            return parse(source).unwrap_err();
+        };
+        assert_eq!(
+            error("v 0 0 0\nv 1 2\n"),
+            "mesh.obj(2): Expected 3 numbers, not 2"
+        );
+        assert_eq!(
+            error("\n\nvn 0 zero 1\n"),
+            "mesh.obj(3): zero isn't a number"
+        );
+        assert_eq!(error("vt\n"), "mesh.obj(1): Expected 1 number, not 0");
+        assert_eq!(
+            error("v 0 0 0\nv 1 0 0\nv 0 1 0\nf 1 2 4\n"),
+            "mesh.obj(4): 4 is out of range, there are 3 elements"
+        );
+        assert_eq!(
+            error("v 0 0 0\nv 1 0 0\nv 0 1 0\nf 0 1 2\n"),
+            "mesh.obj(4): Indices start at 1, not 0"
+        );
+        assert_eq!(
+            error("v 0 0 0\nv 1 0 0\nv 0 1 0\nf 1/1 2/1 3/1\n"),
+            "mesh.obj(4): 1 is out of range, there are 0 elements"
+        );
+        assert_eq!(
+            error("v 0 0 0\nv 1 0 0\nf 1 2\n"),
+            "mesh.obj(3): A face needs 3 corners, not 2"
+        );
+        assert_eq!(
+            error("v 0 0 0\nf 1/2/3/4 1 1\n"),
+            "mesh.obj(2): 1/2/3/4 isn't a face corner"
+        );
+        assert_eq!(
+            error("s smooth\n"),
+            "mesh.obj(1): smooth isn't a smoothing group"
+        );
+
+        let mtl_error = |source: &str| -> String {
+            return parse_mtl("mesh.mtl", source).unwrap_err();
+        };
+        assert_eq!(
+            mtl_error("Kd 1 1 1\n"),
+            "mesh.mtl(1): Kd comes before the first newmtl"
+        );
+        assert_eq!(
+            mtl_error("newmtl red\nKd 1 0\n"),
+            "mesh.mtl(2): Expected 1 or 3 numbers, not 2"
+        );
+        assert_eq!(
+            mtl_error("newmtl red\nNs\n"),
+            "mesh.mtl(2): Expected 1 number, not 0"
+        );
+        assert_eq!(
+            mtl_error("newmtl red\nKs spectral red.spd\n"),
+            "mesh.mtl(2): spectral colors aren't supported"
+        );
+        assert_eq!(
+            mtl_error("newmtl red\nmap_Kd\n"),
+            "mesh.mtl(2): The texture has no file"
+        );
+    }
+}
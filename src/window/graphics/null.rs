@@ -0,0 +1,47 @@
+use super::{backend::software::Framebuffer, bindable::cache::CacheStats, Renderer};
+use crate::window::errors::Error;
+
+/// A [`Renderer`] that doesn't draw anything. It only counts what it was asked to do, so the frame loop
+/// can run without a GPU. See [`crate::window::headless::HeadlessWindow`].
+#[derive(Debug, Default, Clone)]
+pub struct NullGraphics {
+    /// The color of the last [`Renderer::clear_buffer()`] call
+    pub clear_color: [f32; 4],
+    /// Amount of draw calls since the last [`Renderer::end_frame()`]
+    pub draw_calls: u32,
+    /// Amount of draw calls in all frames
+    pub total_draw_calls: u64,
+    /// Amount of frames that were presented
+    pub frames_presented: u64,
+}
+
+impl Renderer for NullGraphics {
+    fn clear_buffer(&mut self, rgba: [f32; 4]) -> Result<(), Error> {
+        self.clear_color = rgba;
+        return Ok(());
+    }
+
+    fn test_triangle(&mut self, _angle: f32, _x: f32, _z: f32) -> Result<(), Error> {
+        self.draw_calls += 1;
+        self.total_draw_calls += 1;
+        return Ok(());
+    }
+
+    fn end_frame(&mut self) -> Result<(), Error> {
+        self.draw_calls = 0;
+        self.frames_presented += 1;
+        return Ok(());
+    }
+
+    fn read_frame(&self) -> Option<&Framebuffer> {
+        return None;
+    }
+
+    fn adapter_description(&self) -> String {
+        return "none (NullGraphics)".to_string();
+    }
+
+    fn cache_stats(&self) -> CacheStats {
+        return CacheStats::default();
+    }
+}
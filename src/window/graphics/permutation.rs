@@ -0,0 +1,619 @@
+use std::{collections::HashMap, fmt};
+
+use super::{
+    backend::{RenderBackend, ShaderDesc, ShaderId},
+    bindable::cache::ResourceCache,
+};
+use crate::window::errors::Error;
+
+/// A define that a shader is compiled with in more than one way
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Feature {
+    /// Defined as 1 when it is on and not defined when it is off, so `#ifdef` and `#if` both work
+    Flag(String),
+    /// Always defined, as one of the values from `min` to `max`
+    Range { name: String, min: i64, max: i64 },
+}
+
+impl Feature {
+    pub fn name(&self) -> &str {
+        return match self {
+            Feature::Flag(name) => name,
+            Feature::Range { name, .. } => name,
+        };
+    }
+
+    /// Amount of values the feature can have
+    pub fn count(&self) -> usize {
+        return match self {
+            Feature::Flag(_) => 2,
+            Feature::Range { min, max, .. } => (max - min + 1) as usize,
+        };
+    }
+
+    /// The value at an index from 0 to [`Feature::count()`]
+    fn value(&self, index: usize) -> i64 {
+        return match self {
+            Feature::Flag(_) => index as i64,
+            Feature::Range { min, .. } => min + index as i64,
+        };
+    }
+
+    fn index(&self, value: i64) -> Option<usize> {
+        let (min, max) = match self {
+            Feature::Flag(_) => (0, 1),
+            Feature::Range { min, max, .. } => (*min, *max),
+        };
+        return (min..=max).contains(&value).then(|| (value - min) as usize);
+    }
+}
+
+impl fmt::Display for Feature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            Feature::Flag(name) => write!(f, "{}", name),
+            Feature::Range { name, min, max } => write!(f, "{} in {}..={}", name, min, max),
+        };
+    }
+}
+
+/// The features a material asks for, like the flags of a material. Features that aren't set are off or
+/// the lowest value of their range.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct FeatureValues {
+    pub values: Vec<(String, i64)>,
+}
+
+impl FeatureValues {
+    pub fn new() -> FeatureValues {
+        return FeatureValues::default();
+    }
+
+    pub fn flag(self, name: &str, on: bool) -> FeatureValues {
+        return self.value(name, on as i64);
+    }
+
+    pub fn value(mut self, name: &str, value: i64) -> FeatureValues {
+        self.values.retain(|(existing, _)| existing != name);
+        self.values.push((name.to_string(), value));
+        return self;
+    }
+}
+
+/// A stable hash of a [`Permutation`]: the same features and values give the same key in every build,
+/// so it can name compiled shaders on disk
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct PermutationKey(pub u64);
+
+impl fmt::Display for PermutationKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return write!(f, "{:016x}", self.0);
+    }
+}
+
+/// One variant of a shader: a value for every feature, in the order of the [`PermutationSet`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Permutation {
+    pub values: Vec<(String, i64)>,
+}
+
+impl Permutation {
+    pub fn value(&self, feature: &str) -> Option<i64> {
+        return self
+            .values
+            .iter()
+            .find(|(name, _)| name == feature)
+            .map(|(_, value)| *value);
+    }
+
+    /// The defines to compile the variant with. Flags that are off aren't defined.
+    pub fn defines(&self, features: &[Feature]) -> Vec<(String, String)> {
+        return features
+            .iter()
+            .zip(&self.values)
+            .filter(|(feature, (_, value))| !matches!(feature, Feature::Flag(_)) || *value != 0)
+            .map(|(_, (name, value))| (name.clone(), value.to_string()))
+            .collect();
+    }
+
+    /// FNV-1a of the features sorted by name, so the order they are declared in doesn't change the key
+    pub fn key(&self) -> PermutationKey {
+        let mut values: Vec<&(String, i64)> = self.values.iter().collect();
+        values.sort();
+
+        let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+        for (name, value) in values {
+            for byte in name.bytes().chain([b'=']).chain(value.to_le_bytes()) {
+                hash ^= byte as u64;
+                hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+            }
+        }
+        return PermutationKey(hash);
+    }
+}
+
+impl fmt::Display for Permutation {
+    /// Like `HAS_NORMAL_MAP=1 SKINNED=0 LIGHT_COUNT=2`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let values: Vec<String> = self
+            .values
+            .iter()
+            .map(|(name, value)| format!("{}={}", name, value))
+            .collect();
+        return write!(f, "{}", values.join(" "));
+    }
+}
+
+/// The features of a shader and every combination of their values. Declare them in code or parse a list
+/// like `HAS_NORMAL_MAP, SKINNED, LIGHT_COUNT in 1..=4`, where `1..4` leaves out 4 like in Rust:
+///
+/// ```ignore
+/// let set = PermutationSet::new().flag("HAS_NORMAL_MAP").flag("SKINNED").range("LIGHT_COUNT", 1, 4);
+/// assert_eq!(set.count(), 16);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct PermutationSet {
+    pub features: Vec<Feature>,
+}
+
+impl PermutationSet {
+    pub fn new() -> PermutationSet {
+        return PermutationSet::default();
+    }
+
+    pub fn flag(mut self, name: &str) -> PermutationSet {
+        self.features.push(Feature::Flag(name.to_string()));
+        return self;
+    }
+
+    /// A feature with the values from `min` to `max`, both included
+    pub fn range(mut self, name: &str, min: i64, max: i64) -> PermutationSet {
+        self.features.push(Feature::Range {
+            name: name.to_string(),
+            min,
+            max,
+        });
+        return self;
+    }
+
+    /// Parse a comma separated list of flags and `NAME in MIN..MAX` or `NAME in MIN..=MAX` ranges
+    pub fn parse(declaration: &str) -> Result<PermutationSet, String> {
+        let mut set: PermutationSet = PermutationSet::new();
+        for part in declaration
+            .split(',')
+            .map(str::trim)
+            .filter(|part| !part.is_empty())
+        {
+            let feature: Feature = match part.split_once(" in ") {
+                None => Feature::Flag(part.to_string()),
+                Some((name, range)) => {
+                    let (min, max, inclusive) = match range.trim().split_once("..=") {
+                        Some((min, max)) => (min, max, true),
+                        None => {
+                            let (min, max) = range.split_once("..").ok_or_else(|| {
+                                format!("Expected a range like 1..=4 in {}", part)
+                            })?;
+                            (min, max, false)
+                        }
+                    };
+                    let parse = |number: &str| -> Result<i64, String> {
+                        return number
+                            .trim()
+                            .parse()
+                            .map_err(|_| format!("{} isn't a number in {}", number.trim(), part));
+                    };
+                    let max: i64 = parse(max)? - if inclusive { 0 } else { 1 };
+                    Feature::Range {
+                        name: name.trim().to_string(),
+                        min: parse(min)?,
+                        max,
+                    }
+                }
+            };
+            set.features.push(feature);
+        }
+        set.check()?;
+        return Ok(set);
+    }
+
+    /// Names are defines and have to be unique, ranges can't be empty
+    pub fn check(&self) -> Result<(), String> {
+        for (index, feature) in self.features.iter().enumerate() {
+            let name: &str = feature.name();
+            if !name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+                || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+            {
+                return Err(format!("{} isn't the name of a define", name));
+            }
+            if self.features[..index]
+                .iter()
+                .any(|other| other.name() == name)
+            {
+                return Err(format!("{} is declared twice", name));
+            }
+            if let Feature::Range { min, max, .. } = feature {
+                if max < min {
+                    return Err(format!("The range of {} is empty", feature));
+                }
+            }
+        }
+        return Ok(());
+    }
+
+    /// Amount of variants, every combination of the values of the features
+    pub fn count(&self) -> usize {
+        return self.features.iter().map(Feature::count).product();
+    }
+
+    /// The variant at an index from 0 to [`PermutationSet::count()`]. The first feature changes slowest.
+    pub fn permutation(&self, index: usize) -> Option<Permutation> {
+        if index >= self.count() {
+            return None;
+        }
+
+        let mut rest: usize = index;
+        let mut values: Vec<(String, i64)> = vec![(String::new(), 0); self.features.len()];
+        for (feature, value) in self.features.iter().zip(values.iter_mut()).rev() {
+            *value = (
+                feature.name().to_string(),
+                feature.value(rest % feature.count()),
+            );
+            rest /= feature.count();
+        }
+        return Some(Permutation { values });
+    }
+
+    pub fn index(&self, permutation: &Permutation) -> Option<usize> {
+        let mut index: usize = 0;
+        for feature in &self.features {
+            let value: i64 = permutation.value(feature.name())?;
+            index = index * feature.count() + feature.index(value)?;
+        }
+        return Some(index);
+    }
+
+    /// Every variant, in the order of their index
+    pub fn iter(&self) -> impl Iterator<Item = Permutation> + '_ {
+        return (0..self.count()).filter_map(|index| self.permutation(index));
+    }
+
+    /// The variant for what a material asks for. Asking for a feature the shader doesn't have or for a
+    /// value outside of its range is an error.
+    pub fn select(&self, values: &FeatureValues) -> Result<Permutation, String> {
+        if let Some((name, _)) = values
+            .values
+            .iter()
+            .find(|(name, _)| !self.features.iter().any(|feature| feature.name() == name))
+        {
+            return Err(format!("The shader has no feature {}", name));
+        }
+
+        let mut selected: Vec<(String, i64)> = vec![];
+        for feature in &self.features {
+            let value: i64 = match values
+                .values
+                .iter()
+                .find(|(name, _)| name == feature.name())
+            {
+                Some((_, value)) => *value,
+                None => feature.value(0),
+            };
+            if feature.index(value).is_none() {
+                return Err(format!(
+                    "{} can't be {}, it is declared as {}",
+                    feature.name(),
+                    value,
+                    feature
+                ));
+            }
+            selected.push((feature.name().to_string(), value));
+        }
+        return Ok(Permutation { values: selected });
+    }
+}
+
+/// The variants of one shader. They are compiled when a material first needs them, or all at once with
+/// [`ShaderVariants::compile_all()`], and come from a [`ResourceCache`] so a variant is compiled once.
+#[derive(Debug, Clone)]
+pub struct ShaderVariants {
+    /// The shader without the defines of the features
+    pub desc: ShaderDesc,
+    pub permutations: PermutationSet,
+    shaders: HashMap<PermutationKey, ShaderId>,
+}
+
+impl ShaderVariants {
+    pub fn new(desc: ShaderDesc, permutations: PermutationSet) -> ShaderVariants {
+        return ShaderVariants {
+            desc,
+            permutations,
+            shaders: HashMap::new(),
+        };
+    }
+
+    /// The description of a variant: the defines of the shader and those of the permutation
+    pub fn variant_desc(&self, permutation: &Permutation) -> ShaderDesc {
+        let mut desc: ShaderDesc = self.desc.clone();
+        desc.defines
+            .extend(permutation.defines(&self.permutations.features));
+        return desc;
+    }
+
+    /// The variant for the features of a material, compiled the first time it is asked for
+    pub fn shader(
+        &mut self,
+        backend: &mut dyn RenderBackend,
+        cache: &mut ResourceCache,
+        values: &FeatureValues,
+    ) -> Result<ShaderId, Error> {
+        let permutation: Permutation = self.permutations.select(values).map_err(|e| {
+            Error::shader_compile(
+                &self.desc.path.display().to_string(),
+                windows::Win32::Foundation::E_INVALIDARG,
+                &e,
+            )
+        })?;
+        return self.compile(backend, cache, &permutation);
+    }
+
+    /// Set the variant for the features of a material on the pipeline, at draw time
+    pub fn bind(
+        &mut self,
+        backend: &mut dyn RenderBackend,
+        cache: &mut ResourceCache,
+        values: &FeatureValues,
+    ) -> Result<(), Error> {
+        let shader: ShaderId = self.shader(backend, cache, values)?;
+        return backend.set_shader(shader);
+    }
+
+    /// Compile every variant ahead of time, like at a loading screen
+    pub fn compile_all(
+        &mut self,
+        backend: &mut dyn RenderBackend,
+        cache: &mut ResourceCache,
+    ) -> Result<(), Error> {
+        let permutations: Vec<Permutation> = self.permutations.iter().collect();
+        for permutation in permutations {
+            self.compile(backend, cache, &permutation)?;
+        }
+        return Ok(());
+    }
+
+    /// Amount of variants that are compiled
+    pub fn compiled(&self) -> usize {
+        return self.shaders.len();
+    }
+
+    fn compile(
+        &mut self,
+        backend: &mut dyn RenderBackend,
+        cache: &mut ResourceCache,
+        permutation: &Permutation,
+    ) -> Result<ShaderId, Error> {
+        let key: PermutationKey = permutation.key();
+        if let Some(shader) = self.shaders.get(&key) {
+            return Ok(*shader);
+        }
+
+        let shader: ShaderId = cache
+            .shader(backend, &self.variant_desc(permutation))
+            .map_err(|e| e.context(&format!("compiling the variant {}", permutation)))?;
+        self.shaders.insert(key, shader);
+        return Ok(shader);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+    use crate::window::graphics::backend::{
+        recording::{Call, RecordingBackend},
+        ShaderStage,
+    };
+
+    fn material_set() -> PermutationSet {
+        return PermutationSet::new()
+            .flag("HAS_NORMAL_MAP")
+            .flag("SKINNED")
+            .range("LIGHT_COUNT", 1, 4);
+    }
+
+    fn values(values: &[(&str, i64)]) -> Vec<(String, i64)> {
+        return values
+            .iter()
+            .map(|(name, value)| (name.to_string(), *value))
+            .collect();
+    }
+
+    #[test]
+    fn selecting_fills_in_the_features_that_arent_set() {
+        let set: PermutationSet = material_set();
+        let selected: Permutation = set
+            .select(
+                &FeatureValues::new()
+                    .value("LIGHT_COUNT", 3)
+                    .flag("SKINNED", true),
+            )
+            .unwrap();
+        assert_eq!(
+            selected.values,
+            values(&[("HAS_NORMAL_MAP", 0), ("SKINNED", 1), ("LIGHT_COUNT", 3)])
+        );
+        assert_eq!(
+            set.select(&FeatureValues::new()).unwrap().values,
+            values(&[("HAS_NORMAL_MAP", 0), ("SKINNED", 0), ("LIGHT_COUNT", 1)])
+        );
+        // The last value that is set wins
+        let twice: FeatureValues = FeatureValues::new()
+            .flag("SKINNED", true)
+            .flag("SKINNED", false);
+        assert_eq!(set.select(&twice).unwrap().value("SKINNED"), Some(0));
+    }
+
+    #[test]
+    fn selecting_what_the_shader_doesnt_have_is_an_error() {
+        let set: PermutationSet = material_set();
+        assert_eq!(
+            set.select(&FeatureValues::new().flag("FOG", true)),
+            Err("The shader has no feature FOG".to_string())
+        );
+        assert_eq!(
+            set.select(&FeatureValues::new().value("LIGHT_COUNT", 5)),
+            Err("LIGHT_COUNT can't be 5, it is declared as LIGHT_COUNT in 1..=4".to_string())
+        );
+        assert!(set
+            .select(&FeatureValues::new().value("SKINNED", 2))
+            .is_err());
+    }
+
+    #[test]
+    fn keys_dont_depend_on_the_order_of_the_features() {
+        let set: PermutationSet = material_set();
+        let selected: Permutation = set
+            .select(
+                &FeatureValues::new()
+                    .flag("HAS_NORMAL_MAP", true)
+                    .value("LIGHT_COUNT", 2),
+            )
+            .unwrap();
+        let reordered: Permutation =
+            PermutationSet::parse("LIGHT_COUNT in 1..5, SKINNED, HAS_NORMAL_MAP")
+                .unwrap()
+                .select(
+                    &FeatureValues::new()
+                        .value("LIGHT_COUNT", 2)
+                        .flag("HAS_NORMAL_MAP", true),
+                )
+                .unwrap();
+        assert_ne!(selected, reordered);
+        assert_eq!(selected.key(), reordered.key());
+
+        // Every variant has its own key
+        let mut keys: Vec<PermutationKey> =
+            set.iter().map(|permutation| permutation.key()).collect();
+        keys.sort();
+        keys.dedup();
+        assert_eq!(keys.len(), set.count());
+
+        // The key names files, so it can't change between builds
+        let empty: Permutation = Permutation { values: vec![] };
+        assert_eq!(empty.key().to_string(), "cbf29ce484222325");
+    }
+
+    #[test]
+    fn every_variant_has_an_index() {
+        let set: PermutationSet = material_set();
+        assert_eq!(set.count(), 16);
+        assert_eq!(
+            set.permutation(0).unwrap().values,
+            values(&[("HAS_NORMAL_MAP", 0), ("SKINNED", 0), ("LIGHT_COUNT", 1)])
+        );
+        // The first feature changes slowest
+        assert_eq!(
+            set.permutation(9).unwrap().values,
+            values(&[("HAS_NORMAL_MAP", 1), ("SKINNED", 0), ("LIGHT_COUNT", 2)])
+        );
+        assert_eq!(set.permutation(16), None);
+        for (index, permutation) in set.iter().enumerate() {
+            assert_eq!(set.index(&permutation), Some(index));
+        }
+        assert_eq!(PermutationSet::new().count(), 1);
+    }
+
+    #[test]
+    fn flags_that_are_off_arent_defined() {
+        let set: PermutationSet = material_set();
+        let selected: Permutation = set
+            .select(&FeatureValues::new().flag("SKINNED", true))
+            .unwrap();
+        assert_eq!(
+            selected.defines(&set.features),
+            vec![
+                ("SKINNED".to_string(), "1".to_string()),
+                ("LIGHT_COUNT".to_string(), "1".to_string()),
+            ]
+        );
+        assert_eq!(
+            selected.to_string(),
+            "HAS_NORMAL_MAP=0 SKINNED=1 LIGHT_COUNT=1"
+        );
+    }
+
+    #[test]
+    fn declarations_are_parsed() {
+        assert_eq!(
+            PermutationSet::parse("HAS_NORMAL_MAP, SKINNED, LIGHT_COUNT in 1..=4,").unwrap(),
+            material_set()
+        );
+        assert_eq!(
+            PermutationSet::parse("LIGHT_COUNT in 1..4")
+                .unwrap()
+                .count(),
+            3
+        );
+        assert!(PermutationSet::parse("SKINNED, SKINNED").is_err());
+        assert!(PermutationSet::parse("2D").is_err());
+        assert!(PermutationSet::parse("LIGHT_COUNT in 4..=1").is_err());
+        assert!(PermutationSet::parse("LIGHT_COUNT in one..=4").is_err());
+        assert!(PermutationSet::parse("LIGHT_COUNT in 4").is_err());
+    }
+
+    #[test]
+    fn variants_are_compiled_once_when_they_are_first_needed() {
+        let mut backend: RecordingBackend = RecordingBackend::new();
+        let mut cache: ResourceCache = ResourceCache::default();
+        let mut desc: ShaderDesc =
+            ShaderDesc::new(ShaderStage::Pixel, PathBuf::from("Material.hlsl"));
+        desc.defines.push(("QUALITY".to_string(), "2".to_string()));
+        let mut variants: ShaderVariants = ShaderVariants::new(desc, material_set());
+
+        let skinned: FeatureValues = FeatureValues::new().flag("SKINNED", true);
+        let first: ShaderId = variants.shader(&mut backend, &mut cache, &skinned).unwrap();
+        assert_eq!(
+            variants.shader(&mut backend, &mut cache, &skinned).unwrap(),
+            first
+        );
+        variants.bind(&mut backend, &mut cache, &skinned).unwrap();
+        assert_eq!(
+            backend.calls,
+            vec![
+                Call::CreateShader(first, ShaderStage::Pixel),
+                Call::SetShader(first),
+            ]
+        );
+        assert_eq!(variants.compiled(), 1);
+        let (compiled, _) = cache.shaders().next().unwrap();
+        assert_eq!(
+            compiled.defines,
+            vec![
+                ("QUALITY".to_string(), "2".to_string()),
+                ("SKINNED".to_string(), "1".to_string()),
+                ("LIGHT_COUNT".to_string(), "1".to_string()),
+            ]
+        );
+
+        variants.compile_all(&mut backend, &mut cache).unwrap();
+        assert_eq!(variants.compiled(), 16);
+        assert_eq!(cache.stats.shaders.misses, 16);
+
+        // Another set of variants of the same shader gets them from the cache
+        let mut again: ShaderVariants = ShaderVariants::new(variants.desc.clone(), material_set());
+        assert_eq!(
+            again.shader(&mut backend, &mut cache, &skinned).unwrap(),
+            first
+        );
+        assert_eq!(cache.stats.shaders.hits, 1);
+
+        let unknown: FeatureValues = FeatureValues::new().flag("FOG", true);
+        match variants.shader(&mut backend, &mut cache, &unknown) {
+            Err(Error::ShaderCompile { path, log, .. }) => {
+                assert_eq!(path, "Material.hlsl");
+                assert_eq!(log, "The shader has no feature FOG");
+            }
+            other => panic!("Expected an error, got {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,1339 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt, fs,
+    path::{Path, PathBuf},
+};
+
+use super::{
+    backend::{ShaderCode, ShaderDesc},
+    shaderlocator::{ShaderLocator, ShaderSet},
+};
+
+/// Includes that nest deeper than this are an include cycle
+const MAX_INCLUDE_DEPTH: usize = 32;
+
+/// Where the preprocessor reads files from. Paths use `/` between folders.
+pub trait FileSystem {
+    /// The contents of the file at `path`, `None` when there is no such file
+    fn read(&self, path: &str) -> Option<String>;
+
+    /// The bytes of the file at `path`, for files that aren't text
+    fn read_bytes(&self, path: &str) -> Option<Vec<u8>> {
+        return self.read(path).map(String::into_bytes);
+    }
+}
+
+/// The files on disk
+pub struct DiskFiles;
+
+impl FileSystem for DiskFiles {
+    fn read(&self, path: &str) -> Option<String> {
+        return fs::read_to_string(path).ok();
+    }
+
+    fn read_bytes(&self, path: &str) -> Option<Vec<u8>> {
+        return fs::read(path).ok();
+    }
+}
+
+/// Files that only exist in memory, by path
+#[derive(Debug, Clone, Default)]
+pub struct MemoryFiles {
+    pub files: HashMap<String, String>,
+}
+
+impl MemoryFiles {
+    pub fn new() -> MemoryFiles {
+        return MemoryFiles::default();
+    }
+
+    pub fn add(mut self, path: &str, contents: &str) -> MemoryFiles {
+        self.files.insert(normalize(path), contents.to_string());
+        return self;
+    }
+}
+
+impl FileSystem for MemoryFiles {
+    fn read(&self, path: &str) -> Option<String> {
+        return self.files.get(path).cloned();
+    }
+}
+
+/// The sources of embedded shaders and asset packs
+impl FileSystem for ShaderSet {
+    fn read(&self, path: &str) -> Option<String> {
+        return self
+            .get(path)
+            .and_then(|asset| asset.source.as_ref())
+            .map(|source| source.to_string());
+    }
+}
+
+/// The files the shaders of a [`ShaderLocator`] can include. Absolute paths and paths in a folder of the
+/// search path are files on disk, other names are looked up like shaders: in the search path, then the
+/// packs and the embedded shaders. This way an embedded shader includes embedded files, not files in the
+/// folder the program was started from.
+impl FileSystem for ShaderLocator {
+    fn read(&self, path: &str) -> Option<String> {
+        return match file_on_disk(self, path) {
+            Some(file) => fs::read_to_string(file).ok(),
+            None => self
+                .packs
+                .iter()
+                .chain([&self.embedded])
+                .find_map(|set| set.read(path)),
+        };
+    }
+
+    fn read_bytes(&self, path: &str) -> Option<Vec<u8>> {
+        return match file_on_disk(self, path) {
+            Some(file) => fs::read(file).ok(),
+            None => self.read(path).map(String::into_bytes),
+        };
+    }
+}
+
+/// The file on disk that `path` names for `locator`, if it isn't a pack or embedded file
+fn file_on_disk(locator: &ShaderLocator, path: &str) -> Option<PathBuf> {
+    let in_search_path: bool = locator
+        .search_path
+        .iter()
+        .any(|dir| Path::new(path).starts_with(normalize(&dir.to_string_lossy())));
+    return match Path::new(path).is_absolute() || in_search_path {
+        true => Some(PathBuf::from(path)),
+        false => locator.find_file(path),
+    };
+}
+
+/// A `#define`. Function-like macros have parameters, an object-like macro has `None`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Macro {
+    pub params: Option<Vec<String>>,
+    pub body: String,
+}
+
+/// An error in a directive, at the line of the file where it is
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PreprocessError {
+    pub file: String,
+    pub line: u32,
+    pub message: String,
+}
+
+impl fmt::Display for PreprocessError {
+    /// Formatted like the errors of the compiler
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return write!(f, "{}({}): error: {}", self.file, self.line, self.message);
+    }
+}
+
+/// The file and line every line of preprocessed code came from
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SourceMap {
+    pub files: Vec<String>,
+    /// Per line of the output, the index in [`SourceMap::files`] and the line in that file
+    lines: Vec<(usize, u32)>,
+}
+
+impl SourceMap {
+    /// The file and line of a line of the output, both start at 1
+    pub fn original(&self, output_line: u32) -> Option<(&str, u32)> {
+        let (file, line) = *self.lines.get((output_line as usize).checked_sub(1)?)?;
+        return Some((&self.files[file], line));
+    }
+
+    /// Rewrite the `name(line,column)` and `name(line)` locations of the output in a compiler log to the
+    /// original files and lines. Only needed for output without `#line` directives, the compiler already
+    /// reports the original locations for the rest.
+    pub fn remap(&self, name: &str, log: &str) -> String {
+        let mut remapped: String = String::new();
+        let mut rest: &str = log;
+        while let Some(start) = rest.find(name) {
+            remapped.push_str(&rest[..start]);
+            let after: &str = &rest[start + name.len()..];
+            let location: Option<(u32, usize)> = after.strip_prefix('(').and_then(|inside| {
+                let digits: usize = inside.find(|c: char| !c.is_ascii_digit())?;
+                let line: u32 = inside[..digits].parse().ok()?;
+                // The length of the ( and the line, the separator after them is kept
+                return matches!(inside.as_bytes()[digits], b',' | b')')
+                    .then_some((line, digits + 1));
+            });
+
+            match location.and_then(|(line, len)| Some((self.original(line)?, len))) {
+                Some(((file, line), len)) => {
+                    remapped.push_str(&format!("{}({}", file, line));
+                    rest = &after[len..];
+                }
+                None => {
+                    remapped.push_str(name);
+                    rest = after;
+                }
+            }
+        }
+        remapped.push_str(rest);
+        return remapped;
+    }
+}
+
+/// What the preprocessor made of a file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Preprocessed {
+    pub code: String,
+    pub source_map: SourceMap,
+    /// Every file that was included, starting with the file itself
+    pub files: Vec<String>,
+}
+
+/// An HLSL preprocessor that doesn't need the compiler. It reads includes from a [`FileSystem`], handles
+/// `#define`, `#undef`, `#ifdef`, `#ifndef`, `#if` and `#elif` with integer expressions, `#else`,
+/// `#endif`, `#error` and `#pragma once`, and expands object-like and function-like macros with `#` and
+/// `##`. Other pragmas are passed on to the compiler. The arguments of a macro call have to be on the
+/// line of the call.
+///
+/// The output has `#line` directives where it doesn't follow the lines of the last file anymore, so the
+/// compiler reports the original locations.
+pub struct Preprocessor<'a> {
+    files: &'a dyn FileSystem,
+    /// Folders that are searched for includes after the folder of the including file, like `/I`
+    pub include_dirs: Vec<String>,
+    /// Write `#line` directives in the output
+    pub line_directives: bool,
+    macros: HashMap<String, Macro>,
+}
+
+impl<'a> Preprocessor<'a> {
+    pub fn new(files: &'a dyn FileSystem) -> Preprocessor<'a> {
+        return Preprocessor {
+            files,
+            include_dirs: vec![],
+            line_directives: true,
+            macros: HashMap::new(),
+        };
+    }
+
+    /// Define an object-like macro, like `/D NAME=VALUE`
+    pub fn define(&mut self, name: &str, value: &str) {
+        self.macros.insert(
+            name.to_string(),
+            Macro {
+                params: None,
+                body: value.to_string(),
+            },
+        );
+    }
+
+    /// Define a macro the way `#define` does, like `SQUARE(x) ((x) * (x))`
+    pub fn define_line(&mut self, definition: &str) -> Result<(), String> {
+        let (name, definition) = parse_define(definition)?;
+        self.macros.insert(name, definition);
+        return Ok(());
+    }
+
+    pub fn undefine(&mut self, name: &str) {
+        self.macros.remove(name);
+    }
+
+    pub fn macros(&self) -> &HashMap<String, Macro> {
+        return &self.macros;
+    }
+
+    /// Preprocess the file at `path`
+    pub fn run(&mut self, path: &str) -> Result<Preprocessed, PreprocessError> {
+        let path: String = normalize(path);
+        let source: String = self.files.read(&path).ok_or_else(|| PreprocessError {
+            file: path.clone(),
+            line: 0,
+            message: "The file doesn't exist".to_string(),
+        })?;
+        return self.run_source(&path, &source);
+    }
+
+    /// Preprocess source that is already loaded. Its includes are relative to the folder of `path`.
+    pub fn run_source(
+        &mut self,
+        path: &str,
+        source: &str,
+    ) -> Result<Preprocessed, PreprocessError> {
+        let mut output: Output = Output {
+            code: String::new(),
+            source_map: SourceMap::default(),
+            last: None,
+            line_directives: self.line_directives,
+            once: HashSet::new(),
+        };
+        self.process(&normalize(path), source, &mut output, 0)?;
+        return Ok(Preprocessed {
+            code: output.code,
+            files: output.source_map.files.clone(),
+            source_map: output.source_map,
+        });
+    }
+
+    fn process(
+        &mut self,
+        path: &str,
+        source: &str,
+        output: &mut Output,
+        depth: usize,
+    ) -> Result<(), PreprocessError> {
+        let file: usize = output.file_index(path);
+        let mut conditions: Vec<Condition> = vec![];
+        let mut in_comment: bool = false;
+
+        for (line, text) in logical_lines(source) {
+            let error = |message: String| PreprocessError {
+                file: path.to_string(),
+                line,
+                message,
+            };
+            let text: String = strip_comments(&text, &mut in_comment);
+            let active: bool = conditions.iter().all(|condition| condition.active);
+
+            let Some(directive) = text.trim_start().strip_prefix('#') else {
+                if active {
+                    let expanded: String = self.expand(&text, &mut vec![]).map_err(error)?;
+                    output.emit(file, line, &expanded);
+                }
+                continue;
+            };
+            let directive: &str = directive.trim();
+            let (name, rest) =
+                match directive.find(|c: char| !c.is_ascii_alphanumeric() && c != '_') {
+                    Some(end) => (&directive[..end], directive[end..].trim()),
+                    None => (directive, ""),
+                };
+
+            match name {
+                "if" | "ifdef" | "ifndef" => {
+                    let value: bool = active
+                        && match name {
+                            "if" => self.evaluate(rest).map_err(error)?,
+                            "ifdef" => self.macros.contains_key(macro_name(rest).map_err(error)?),
+                            _ => !self.macros.contains_key(macro_name(rest).map_err(error)?),
+                        };
+                    conditions.push(Condition {
+                        active: value,
+                        taken: value || !active,
+                        seen_else: false,
+                    });
+                }
+                "elif" | "else" => {
+                    let outer: bool = conditions[..conditions.len().saturating_sub(1)]
+                        .iter()
+                        .all(|condition| condition.active);
+                    let condition: &Condition = conditions
+                        .last()
+                        .ok_or_else(|| error(format!("#{} without #if", name)))?;
+                    if condition.seen_else {
+                        return Err(error(format!("#{} after #else", name)));
+                    }
+                    let value: bool = !condition.taken
+                        && outer
+                        && match name {
+                            "elif" => self.evaluate(rest).map_err(error)?,
+                            _ => true,
+                        };
+                    let condition: &mut Condition = conditions.last_mut().unwrap();
+                    condition.active = value;
+                    condition.taken |= value;
+                    condition.seen_else = name == "else";
+                }
+                "endif" => {
+                    conditions
+                        .pop()
+                        .ok_or_else(|| error("#endif without #if".to_string()))?;
+                }
+                _ if !active => {}
+                "define" => {
+                    let (name, definition) = parse_define(rest).map_err(error)?;
+                    self.macros.insert(name, definition);
+                }
+                "undef" => {
+                    self.macros.remove(macro_name(rest).map_err(error)?);
+                }
+                "include" => {
+                    let included: String = self.include_path(path, rest).map_err(error)?;
+                    if output.once.contains(&included) {
+                        continue;
+                    }
+                    if depth + 1 >= MAX_INCLUDE_DEPTH {
+                        return Err(error(format!(
+                            "Includes nest deeper than {} files, {} might include itself",
+                            MAX_INCLUDE_DEPTH, included
+                        )));
+                    }
+                    let source: String = self.files.read(&included).unwrap_or_default();
+                    self.process(&included, &source, output, depth + 1)?;
+                }
+                "pragma" if rest == "once" => {
+                    output.once.insert(path.to_string());
+                }
+                "pragma" => output.emit(file, line, text.trim()),
+                "error" => return Err(error(format!("#error {}", rest))),
+                // The output gets its own line directives
+                "line" | "" => {}
+                _ => return Err(error(format!("Unknown directive #{}", name))),
+            }
+        }
+
+        if !conditions.is_empty() {
+            return Err(PreprocessError {
+                file: path.to_string(),
+                line: source.lines().count() as u32,
+                message: format!("{} #if without #endif", conditions.len()),
+            });
+        }
+        return Ok(());
+    }
+
+    /// The file an `#include` names. Quoted names are looked for next to the including file first, then
+    /// like names between `<>` in the include folders and as they are.
+    fn include_path(&self, from: &str, argument: &str) -> Result<String, String> {
+        let argument: String = match argument.starts_with(['"', '<']) {
+            true => argument.to_string(),
+            false => self.expand(argument, &mut vec![])?.trim().to_string(),
+        };
+        let (name, local) = match argument.chars().next() {
+            Some('"') => (argument[1..].split('"').next(), true),
+            Some('<') => (argument[1..].split('>').next(), false),
+            _ => (None, false),
+        };
+        let name: &str = name
+            .filter(|name| !name.is_empty() && argument.len() > name.len() + 1)
+            .ok_or_else(|| format!("#include expects \"file\" or <file>, got {}", argument))?;
+
+        let mut candidates: Vec<String> = vec![];
+        if local {
+            candidates.push(match from.rfind('/') {
+                Some(end) => normalize(&format!("{}/{}", &from[..end], name)),
+                None => normalize(name),
+            });
+        }
+        candidates.extend(
+            self.include_dirs
+                .iter()
+                .map(|dir| normalize(&format!("{}/{}", dir, name))),
+        );
+        candidates.push(normalize(name));
+
+        return candidates
+            .into_iter()
+            .find(|candidate| self.files.read(candidate).is_some())
+            .ok_or_else(|| format!("Unable to open include file {}", name));
+    }
+
+    /// Expand the macros in `text`. Macros in `disabled` are being expanded and aren't expanded again.
+    fn expand(&self, text: &str, disabled: &mut Vec<String>) -> Result<String, String> {
+        let tokens: Vec<Token> = tokenize(text);
+        let mut expanded: String = String::new();
+        let mut index: usize = 0;
+        while index < tokens.len() {
+            let token: &Token = &tokens[index];
+            index += 1;
+            let definition: Option<&Macro> = match token.kind {
+                TokenKind::Identifier if !disabled.contains(&token.text) => {
+                    self.macros.get(&token.text)
+                }
+                _ => None,
+            };
+            let Some(definition) = definition else {
+                expanded.push_str(&token.text);
+                continue;
+            };
+
+            let body: String = match &definition.params {
+                None => definition.body.clone(),
+                Some(params) => {
+                    // A function-like macro without arguments is only a name
+                    let mut open: usize = index;
+                    while open < tokens.len() && tokens[open].kind == TokenKind::Space {
+                        open += 1;
+                    }
+                    if tokens.get(open).map(|token| token.text.as_str()) != Some("(") {
+                        expanded.push_str(&token.text);
+                        continue;
+                    }
+                    let (args, end) = macro_arguments(&tokens, open + 1)
+                        .ok_or_else(|| format!("The call of {} has no closing )", token.text))?;
+                    index = end;
+                    let args: Vec<String> = match (params.len(), args.as_slice()) {
+                        (0, [arg]) if arg.trim().is_empty() => vec![],
+                        _ => args,
+                    };
+                    if args.len() != params.len() {
+                        return Err(format!(
+                            "{} expects {} arguments, got {}",
+                            token.text,
+                            params.len(),
+                            args.len()
+                        ));
+                    }
+                    self.substitute(&definition.body, params, &args, disabled)?
+                }
+            };
+
+            disabled.push(token.text.clone());
+            let result: Result<String, String> = self.expand(&body, disabled);
+            disabled.pop();
+            expanded.push_str(&result?);
+        }
+        return Ok(expanded);
+    }
+
+    /// The body of a function-like macro with the arguments in the place of the parameters
+    fn substitute(
+        &self,
+        body: &str,
+        params: &[String],
+        args: &[String],
+        disabled: &mut Vec<String>,
+    ) -> Result<String, String> {
+        let tokens: Vec<Token> = tokenize(body);
+        let argument = |token: &Token| -> Option<&str> {
+            let index: usize = params.iter().position(|param| *param == token.text)?;
+            return Some(args[index].trim());
+        };
+        // Tokens around ## are pasted without expanding them first
+        let next_to_paste = |index: usize, step: isize| -> bool {
+            let mut at: isize = index as isize + step;
+            while at >= 0
+                && (at as usize) < tokens.len()
+                && tokens[at as usize].kind == TokenKind::Space
+            {
+                at += step;
+            }
+            return at >= 0 && (at as usize) < tokens.len() && tokens[at as usize].text == "##";
+        };
+
+        let mut pieces: Vec<String> = vec![];
+        let mut index: usize = 0;
+        while index < tokens.len() {
+            let token: &Token = &tokens[index];
+            if token.text == "#" {
+                let mut name: usize = index + 1;
+                while name < tokens.len() && tokens[name].kind == TokenKind::Space {
+                    name += 1;
+                }
+                if let Some(arg) = tokens.get(name).and_then(argument) {
+                    pieces.push(format!("{:?}", arg));
+                    index = name + 1;
+                    continue;
+                }
+            }
+            pieces.push(match argument(token) {
+                Some(arg) if next_to_paste(index, -1) || next_to_paste(index, 1) => arg.to_string(),
+                Some(arg) => self.expand(arg, disabled)?,
+                None => token.text.clone(),
+            });
+            index += 1;
+        }
+
+        // Paste by dropping the ## and the spaces around it
+        let mut pasted: String = String::new();
+        let mut pasting: bool = false;
+        for piece in pieces {
+            if piece == "##" {
+                pasted.truncate(pasted.trim_end().len());
+                pasting = true;
+            } else if pasting && piece.trim().is_empty() {
+                continue;
+            } else {
+                pasting = false;
+                pasted.push_str(&piece);
+            }
+        }
+        return Ok(pasted);
+    }
+
+    /// The value of the expression of an `#if`
+    fn evaluate(&self, expression: &str) -> Result<bool, String> {
+        let replaced: String = replace_defined(expression, &self.macros)?;
+        let expanded: String = self.expand(&replaced, &mut vec![])?;
+        let tokens: Vec<Token> = tokenize(&expanded)
+            .into_iter()
+            .filter(|token| token.kind != TokenKind::Space)
+            .collect();
+        if tokens.is_empty() {
+            return Err("#if without an expression".to_string());
+        }
+
+        let mut parser: ExpressionParser = ExpressionParser { tokens, index: 0 };
+        let value: i64 = parser.conditional()?;
+        if let Some(token) = parser.tokens.get(parser.index) {
+            return Err(format!("Unexpected {} in #if {}", token.text, expression));
+        }
+        return Ok(value != 0);
+    }
+}
+
+/// Preprocess the HLSL of a shader with its defines. Files are read from `files`, a shader with its
+/// source in the description only reads its includes from there.
+pub fn preprocess_shader(
+    desc: &ShaderDesc,
+    files: &dyn FileSystem,
+) -> Result<Preprocessed, PreprocessError> {
+    let mut preprocessor: Preprocessor = Preprocessor::new(files);
+    for (name, value) in &desc.defines {
+        preprocessor.define(name, value);
+    }
+
+    let path: String = desc.path.to_string_lossy().to_string();
+    return match &desc.code {
+        ShaderCode::Source(source) => preprocessor.run_source(&path, source),
+        ShaderCode::File | ShaderCode::Bytecode(_) => preprocessor.run(&path),
+    };
+}
+
+struct Output {
+    code: String,
+    source_map: SourceMap,
+    /// The file and line of the last line that was written
+    last: Option<(usize, u32)>,
+    line_directives: bool,
+    /// Files with `#pragma once` that were included
+    once: HashSet<String>,
+}
+
+impl Output {
+    fn file_index(&mut self, path: &str) -> usize {
+        return match self.source_map.files.iter().position(|file| file == path) {
+            Some(index) => index,
+            None => {
+                self.source_map.files.push(path.to_string());
+                self.source_map.files.len() - 1
+            }
+        };
+    }
+
+    fn emit(&mut self, file: usize, line: u32, text: &str) {
+        if self.line_directives && self.last != Some((file, line.wrapping_sub(1))) {
+            self.code.push_str(&format!(
+                "#line {} \"{}\"\n",
+                line, self.source_map.files[file]
+            ));
+            self.source_map.lines.push((file, line));
+        }
+        self.code.push_str(text);
+        self.code.push('\n');
+        self.source_map.lines.push((file, line));
+        self.last = Some((file, line));
+    }
+}
+
+struct Condition {
+    /// The lines are used
+    active: bool,
+    /// One of the branches was used, or the whole `#if` is in an unused branch
+    taken: bool,
+    seen_else: bool,
+}
+
+/// The lines of a source with the lines that end in `\` joined, with the line number they start at
+fn logical_lines(source: &str) -> Vec<(u32, String)> {
+    let mut lines: Vec<(u32, String)> = vec![];
+    let mut pending: Option<(u32, String)> = None;
+    for (index, line) in source.lines().enumerate() {
+        let (start, mut text) = pending.take().unwrap_or((index as u32 + 1, String::new()));
+        match line.strip_suffix('\\') {
+            Some(continued) => {
+                text.push_str(continued);
+                pending = Some((start, text));
+            }
+            None => {
+                text.push_str(line);
+                lines.push((start, text));
+            }
+        }
+    }
+    lines.extend(pending);
+    return lines;
+}
+
+/// The line without its comments, a comment becomes a space. `in_comment` is the state of `/* */`
+/// comments between lines.
+fn strip_comments(line: &str, in_comment: &mut bool) -> String {
+    let mut stripped: String = String::new();
+    let mut chars = line.chars().peekable();
+    let mut quote: Option<char> = None;
+    while let Some(c) = chars.next() {
+        if *in_comment {
+            if c == '*' && chars.peek() == Some(&'/') {
+                chars.next();
+                *in_comment = false;
+                stripped.push(' ');
+            }
+            continue;
+        }
+
+        match quote {
+            Some(open) => {
+                stripped.push(c);
+                if c == '\\' {
+                    stripped.extend(chars.next());
+                } else if c == open {
+                    quote = None;
+                }
+            }
+            None if c == '/' && chars.peek() == Some(&'/') => break,
+            None if c == '/' && chars.peek() == Some(&'*') => {
+                chars.next();
+                *in_comment = true;
+            }
+            None => {
+                if c == '"' || c == '\'' {
+                    quote = Some(c);
+                }
+                stripped.push(c);
+            }
+        }
+    }
+    return stripped;
+}
+
+/// The name of a macro after `#ifdef`, `#ifndef` or `#undef`
+fn macro_name(rest: &str) -> Result<&str, String> {
+    let name: &str = rest.split_whitespace().next().unwrap_or_default();
+    if !is_identifier(name) {
+        return Err(format!("Expected the name of a macro, got {:?}", rest));
+    }
+    return Ok(name);
+}
+
+/// The name and macro of what follows `#define`
+fn parse_define(definition: &str) -> Result<(String, Macro), String> {
+    let end: usize = definition
+        .find(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+        .unwrap_or(definition.len());
+    let name: &str = &definition[..end];
+    if !is_identifier(name) {
+        return Err(format!(
+            "Expected the name of a macro, got {:?}",
+            definition
+        ));
+    }
+
+    let rest: &str = &definition[end..];
+    // Only a ( right after the name makes a function-like macro
+    let Some(params) = rest.strip_prefix('(') else {
+        return Ok((
+            name.to_string(),
+            Macro {
+                params: None,
+                body: rest.trim().to_string(),
+            },
+        ));
+    };
+    let close: usize = params
+        .find(')')
+        .ok_or_else(|| format!("The parameters of {} have no closing )", name))?;
+    let params: Vec<String> = match params[..close].trim() {
+        "" => vec![],
+        list => list
+            .split(',')
+            .map(|param| param.trim().to_string())
+            .collect(),
+    };
+    if let Some(param) = params.iter().find(|param| !is_identifier(param)) {
+        return Err(format!("{:?} isn't a parameter name of {}", param, name));
+    }
+    return Ok((
+        name.to_string(),
+        Macro {
+            params: Some(params),
+            body: rest[close + 2..].trim().to_string(),
+        },
+    ));
+}
+
+fn is_identifier(name: &str) -> bool {
+    return name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+}
+
+/// Replace `defined NAME` and `defined(NAME)` with 1 or 0, before macros are expanded
+fn replace_defined(expression: &str, macros: &HashMap<String, Macro>) -> Result<String, String> {
+    let tokens: Vec<Token> = tokenize(expression);
+    let mut replaced: String = String::new();
+    let mut index: usize = 0;
+    let next = |index: &mut usize| -> Option<&Token> {
+        while *index < tokens.len() && tokens[*index].kind == TokenKind::Space {
+            *index += 1;
+        }
+        *index += 1;
+        return tokens.get(*index - 1);
+    };
+    while index < tokens.len() {
+        let token: &Token = &tokens[index];
+        index += 1;
+        if token.text != "defined" {
+            replaced.push_str(&token.text);
+            continue;
+        }
+
+        let mut name: Option<&Token> = next(&mut index);
+        let parenthesized: bool = name.is_some_and(|token| token.text == "(");
+        if parenthesized {
+            name = next(&mut index);
+        }
+        let name: &Token = name
+            .filter(|token| token.kind == TokenKind::Identifier)
+            .ok_or_else(|| "defined expects the name of a macro".to_string())?;
+        if parenthesized && next(&mut index).map(|token| token.text.as_str()) != Some(")") {
+            return Err(format!("defined({} has no closing )", name.text));
+        }
+        replaced.push_str(match macros.contains_key(&name.text) {
+            true => " 1 ",
+            false => " 0 ",
+        });
+    }
+    return Ok(replaced);
+}
+
+/// The arguments of a macro call that starts after the `(` at `start`, and the index after the `)`
+fn macro_arguments(tokens: &[Token], start: usize) -> Option<(Vec<String>, usize)> {
+    let mut args: Vec<String> = vec![String::new()];
+    let mut depth: usize = 0;
+    for (index, token) in tokens.iter().enumerate().skip(start) {
+        match token.text.as_str() {
+            ")" if depth == 0 => return Some((args, index + 1)),
+            "," if depth == 0 => {
+                args.push(String::new());
+                continue;
+            }
+            "(" => depth += 1,
+            ")" => depth -= 1,
+            _ => {}
+        }
+        args.last_mut()?.push_str(&token.text);
+    }
+    return None;
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum TokenKind {
+    Identifier,
+    Number,
+    /// A string or character literal
+    Literal,
+    Punctuation,
+    Space,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Token {
+    kind: TokenKind,
+    text: String,
+}
+
+/// Operators of more than one character
+const OPERATORS: [&str; 9] = ["##", "<<", ">>", "<=", ">=", "==", "!=", "&&", "||"];
+
+fn tokenize(text: &str) -> Vec<Token> {
+    let chars: Vec<char> = text.chars().collect();
+    let mut tokens: Vec<Token> = vec![];
+    let mut index: usize = 0;
+    while index < chars.len() {
+        let c: char = chars[index];
+        let start: usize = index;
+        let kind: TokenKind = if c.is_whitespace() {
+            while index < chars.len() && chars[index].is_whitespace() {
+                index += 1;
+            }
+            TokenKind::Space
+        } else if c.is_ascii_alphabetic() || c == '_' {
+            while index < chars.len()
+                && (chars[index].is_ascii_alphanumeric() || chars[index] == '_')
+            {
+                index += 1;
+            }
+            TokenKind::Identifier
+        } else if c.is_ascii_digit()
+            || (c == '.' && chars.get(index + 1).is_some_and(char::is_ascii_digit))
+        {
+            // Numbers like 1.5e-3f and 0xFFu
+            while index < chars.len()
+                && (chars[index].is_ascii_alphanumeric()
+                    || chars[index] == '.'
+                    || (matches!(chars[index], '+' | '-')
+                        && matches!(chars[index - 1], 'e' | 'E')
+                        && !matches!(chars.get(start + 1), Some('x' | 'X'))))
+            {
+                index += 1;
+            }
+            TokenKind::Number
+        } else if c == '"' || c == '\'' {
+            index += 1;
+            while index < chars.len() && chars[index] != c {
+                index += if chars[index] == '\\' { 2 } else { 1 };
+            }
+            index = (index + 1).min(chars.len());
+            TokenKind::Literal
+        } else {
+            let rest: String = chars[index..chars.len().min(index + 2)].iter().collect();
+            index += match OPERATORS.contains(&rest.as_str()) {
+                true => 2,
+                false => 1,
+            };
+            TokenKind::Punctuation
+        };
+        tokens.push(Token {
+            kind,
+            text: chars[start..index].iter().collect(),
+        });
+    }
+    return tokens;
+}
+
+/// Evaluates the integer expressions of `#if` with the precedence of C. Names that aren't macros are 0.
+struct ExpressionParser {
+    tokens: Vec<Token>,
+    index: usize,
+}
+
+impl ExpressionParser {
+    fn peek(&self) -> Option<&str> {
+        return self.tokens.get(self.index).map(|token| token.text.as_str());
+    }
+
+    fn expect(&mut self, text: &str) -> Result<(), String> {
+        if self.peek() != Some(text) {
+            return Err(format!(
+                "Expected {} in #if, got {}",
+                text,
+                self.peek().unwrap_or("the end")
+            ));
+        }
+        self.index += 1;
+        return Ok(());
+    }
+
+    fn conditional(&mut self) -> Result<i64, String> {
+        let condition: i64 = self.binary(0)?;
+        if self.peek() != Some("?") {
+            return Ok(condition);
+        }
+        self.index += 1;
+        let then: i64 = self.conditional()?;
+        self.expect(":")?;
+        let otherwise: i64 = self.conditional()?;
+        return Ok(if condition != 0 { then } else { otherwise });
+    }
+
+    /// Binary operators from the loosest binding level `level` on
+    fn binary(&mut self, level: usize) -> Result<i64, String> {
+        const LEVELS: [&[&str]; 10] = [
+            &["||"],
+            &["&&"],
+            &["|"],
+            &["^"],
+            &["&"],
+            &["==", "!="],
+            &["<", ">", "<=", ">="],
+            &["<<", ">>"],
+            &["+", "-"],
+            &["*", "/", "%"],
+        ];
+        if level == LEVELS.len() {
+            return self.unary();
+        }
+
+        let mut value: i64 = self.binary(level + 1)?;
+        while let Some(operator) = self
+            .peek()
+            .filter(|operator| LEVELS[level].contains(operator))
+        {
+            let operator: String = operator.to_string();
+            self.index += 1;
+            let right: i64 = self.binary(level + 1)?;
+            value = match operator.as_str() {
+                "||" => (value != 0 || right != 0) as i64,
+                "&&" => (value != 0 && right != 0) as i64,
+                "|" => value | right,
+                "^" => value ^ right,
+                "&" => value & right,
+                "==" => (value == right) as i64,
+                "!=" => (value != right) as i64,
+                "<" => (value < right) as i64,
+                ">" => (value > right) as i64,
+                "<=" => (value <= right) as i64,
+                ">=" => (value >= right) as i64,
+                "<<" => value.wrapping_shl(right as u32),
+                ">>" => value.wrapping_shr(right as u32),
+                "+" => value.wrapping_add(right),
+                "-" => value.wrapping_sub(right),
+                "*" => value.wrapping_mul(right),
+                _ if right == 0 => return Err("Division by zero in #if".to_string()),
+                "/" => value.wrapping_div(right),
+                _ => value.wrapping_rem(right),
+            };
+        }
+        return Ok(value);
+    }
+
+    fn unary(&mut self) -> Result<i64, String> {
+        let token: Token = self
+            .tokens
+            .get(self.index)
+            .cloned()
+            .ok_or_else(|| "#if ends early".to_string())?;
+        self.index += 1;
+        return match (token.kind, token.text.as_str()) {
+            (TokenKind::Punctuation, "!") => Ok((self.unary()? == 0) as i64),
+            (TokenKind::Punctuation, "-") => Ok(self.unary()?.wrapping_neg()),
+            (TokenKind::Punctuation, "+") => self.unary(),
+            (TokenKind::Punctuation, "~") => Ok(!self.unary()?),
+            (TokenKind::Punctuation, "(") => {
+                let value: i64 = self.conditional()?;
+                self.expect(")")?;
+                Ok(value)
+            }
+            (TokenKind::Number, number) => parse_integer(number),
+            (TokenKind::Identifier, "true") => Ok(1),
+            (TokenKind::Identifier, _) => Ok(0),
+            (_, text) => Err(format!("Unexpected {} in #if", text)),
+        };
+    }
+}
+
+/// A decimal, hexadecimal or octal integer with an optional `u` or `l` suffix
+fn parse_integer(number: &str) -> Result<i64, String> {
+    let digits: &str = number.trim_end_matches(['u', 'U', 'l', 'L']);
+    let parsed: Result<i64, std::num::ParseIntError> = match digits
+        .strip_prefix("0x")
+        .or_else(|| digits.strip_prefix("0X"))
+    {
+        Some(hex) => i64::from_str_radix(hex, 16),
+        None if digits.len() > 1 && digits.starts_with('0') => i64::from_str_radix(&digits[1..], 8),
+        None => digits.parse(),
+    };
+    return parsed.map_err(|_| format!("{} isn't an integer in #if", number));
+}
+
+/// Paths use `/` and have no `.` folders, so the same file has one name
+pub fn normalize(path: &str) -> String {
+    let path: String = path.replace('\\', "/");
+    let mut parts: Vec<&str> = vec![];
+    for part in path.split('/') {
+        match part {
+            "." => {}
+            ".." if parts
+                .last()
+                .is_some_and(|last| !last.is_empty() && *last != "..") =>
+            {
+                parts.pop();
+            }
+            _ => parts.push(part),
+        }
+    }
+    return parts.join("/");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(files: &MemoryFiles, path: &str) -> Result<Preprocessed, PreprocessError> {
+        return Preprocessor::new(files).run(path);
+    }
+
+    #[test]
+    fn includes_are_found_next_to_the_file_then_in_the_include_folders() {
+        let files: MemoryFiles = MemoryFiles::new()
+            .add(
+                "shaders/Cube.hlsl",
+                "#include \"Common.hlsli\"\n#include <Lights.hlsli>\nfloat4 main() : SV_Target\n{\n    return TINT;\n}\n",
+            )
+            .add("shaders/Common.hlsli", "#define TINT float4(1, 0, 0, 1)\nfloat scale;\n")
+            .add("lib/Lights.hlsli", "// The sun\nfloat3 sun;\n")
+            // Not next to the file, so the include folder is used
+            .add("Lights.hlsli", "float3 moon;\n");
+        let mut preprocessor: Preprocessor = Preprocessor::new(&files);
+        preprocessor.include_dirs.push("lib".to_string());
+        let preprocessed: Preprocessed = preprocessor.run("./shaders\\Cube.hlsl").unwrap();
+
+        assert_eq!(
+            preprocessed.code,
+            "#line 2 \"shaders/Common.hlsli\"\nfloat scale;\n#line 1 \"lib/Lights.hlsli\"\n\nfloat3 sun;\n\
+             #line 3 \"shaders/Cube.hlsl\"\nfloat4 main() : SV_Target\n{\n    return float4(1, 0, 0, 1);\n}\n"
+        );
+        assert_eq!(
+            preprocessed.files,
+            vec![
+                "shaders/Cube.hlsl",
+                "shaders/Common.hlsli",
+                "lib/Lights.hlsli"
+            ]
+        );
+    }
+
+    #[test]
+    fn include_errors_have_the_line_of_the_include() {
+        let files: MemoryFiles = MemoryFiles::new()
+            .add("Cube.hlsl", "float scale;\n#include \"Missing.hlsli\"\n")
+            .add("Loop.hlsli", "#include \"Loop.hlsli\"\n")
+            .add("Bad.hlsl", "\n\n#include Common.hlsli\n");
+
+        let error: PreprocessError = run(&files, "Cube.hlsl").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Cube.hlsl(2): error: Unable to open include file Missing.hlsli"
+        );
+        assert!(run(&files, "Loop.hlsli")
+            .unwrap_err()
+            .message
+            .contains("might include itself"));
+        assert_eq!(run(&files, "Bad.hlsl").unwrap_err().line, 3);
+        assert_eq!(run(&files, "Missing.hlsl").unwrap_err().line, 0);
+    }
+
+    #[test]
+    fn pragma_once_includes_a_file_once() {
+        let files: MemoryFiles = MemoryFiles::new()
+            .add(
+                "Cube.hlsl",
+                "#include \"Once.hlsli\"\n#include \"Once.hlsli\"\n#include \"Twice.hlsli\"\n#include \"Twice.hlsli\"\n",
+            )
+            .add("Once.hlsli", "#pragma once\nfloat once;\n")
+            .add("Twice.hlsli", "#pragma pack_matrix(row_major)\nfloat twice;\n");
+        let mut preprocessor: Preprocessor = Preprocessor::new(&files);
+        preprocessor.line_directives = false;
+        let preprocessed: Preprocessed = preprocessor.run("Cube.hlsl").unwrap();
+
+        // Other pragmas are for the compiler
+        assert_eq!(
+            preprocessed.code,
+            "float once;\n#pragma pack_matrix(row_major)\nfloat twice;\n#pragma pack_matrix(row_major)\nfloat twice;\n"
+        );
+        assert_eq!(
+            preprocessed.files,
+            vec!["Cube.hlsl", "Once.hlsli", "Twice.hlsli"]
+        );
+    }
+
+    #[test]
+    fn macros_are_expanded() {
+        let source: &str = "#define PI 3.14159\n\
+            #define SQUARE(x) ((x) * (x))\n\
+            #define AREA(r) (PI * SQUARE(r))\n\
+            #define NAME(stage) #stage\n\
+            #define JOIN(a, b) a##b\n\
+            #define SELF SELF + 1\n\
+            #define LONG 1 + \\\n2\n\
+            float volume = CUBE(SCALE);\n\
+            float area = AREA(radius + 1);\n\
+            string name = NAME(pixel);\n\
+            float JOIN(light, Count) = SELF;\n\
+            float SQUARE = LONG;\n\
+            float debug = DEBUG;\n\
+            #undef PI\n\
+            float pi = PI;\n";
+        let files: MemoryFiles = MemoryFiles::new();
+        let mut preprocessor: Preprocessor = Preprocessor::new(&files);
+        preprocessor.line_directives = false;
+        preprocessor.define("SCALE", "2");
+        preprocessor.define("DEBUG", "1");
+        preprocessor.undefine("DEBUG");
+        preprocessor
+            .define_line("CUBE(x) ((x) * (x) * (x))")
+            .unwrap();
+        assert!(preprocessor.define_line("2D 1").is_err());
+        let preprocessed: Preprocessed = preprocessor.run_source("Cube.hlsl", source).unwrap();
+
+        assert_eq!(
+            preprocessed.code.lines().collect::<Vec<&str>>(),
+            vec![
+                "float volume = ((2) * (2) * (2));",
+                "float area = (3.14159 * ((radius + 1) * (radius + 1)));",
+                "string name = \"pixel\";",
+                "float lightCount = SELF + 1;",
+                // A function-like macro without arguments is left alone
+                "float SQUARE = 1 + 2;",
+                "float debug = DEBUG;",
+                "float pi = PI;",
+            ]
+        );
+        assert!(!preprocessor.macros().contains_key("PI"));
+        assert_eq!(
+            preprocessor.macros()["SQUARE"].params,
+            Some(vec!["x".to_string()])
+        );
+    }
+
+    #[test]
+    fn conditions_pick_the_lines_that_are_used() {
+        let source: &str = "#if defined(SHADOWS) && SAMPLES > 2\n\
+            float shadows;\n\
+            #elif SAMPLES * 2 == 4 || 0\n\
+            float two;\n\
+            #else\n\
+            float none;\n\
+            #endif\n\
+            #ifndef SHADOWS\n\
+            #error Not reached\n\
+            #endif\n\
+            #if 0\n\
+            #unknown is skipped\n\
+            #endif\n";
+        let files: MemoryFiles = MemoryFiles::new();
+        let code = |defines: &[(&str, &str)]| -> String {
+            let mut preprocessor: Preprocessor = Preprocessor::new(&files);
+            preprocessor.line_directives = false;
+            for (name, value) in defines {
+                preprocessor.define(name, value);
+            }
+            return match preprocessor.run_source("Cube.hlsl", source) {
+                Ok(preprocessed) => preprocessed.code,
+                Err(e) => e.to_string(),
+            };
+        };
+
+        assert_eq!(
+            code(&[("SHADOWS", ""), ("SAMPLES", "4")]),
+            "float shadows;\n"
+        );
+        assert_eq!(code(&[("SHADOWS", ""), ("SAMPLES", "2")]), "float two;\n");
+        assert_eq!(
+            code(&[("SHADOWS", ""), ("SAMPLES", "0x1")]),
+            "float none;\n"
+        );
+        assert_eq!(code(&[]), "Cube.hlsl(9): error: #error Not reached");
+    }
+
+    #[test]
+    fn bad_directives_are_errors() {
+        let files: MemoryFiles = MemoryFiles::new();
+        let error = |source: &str| -> String {
+            return Preprocessor::new(&files)
+                .run_source("Cube.hlsl", source)
+                .unwrap_err()
+                .to_string();
+        };
+
+        assert_eq!(
+            error("float x;\n#endif\n"),
+            "Cube.hlsl(2): error: #endif without #if"
+        );
+        assert_eq!(error("#else\n"), "Cube.hlsl(1): error: #else without #if");
+        assert_eq!(
+            error("#if 1\n#else\n#elif 1\n#endif\n"),
+            "Cube.hlsl(3): error: #elif after #else"
+        );
+        assert_eq!(
+            error("#if 1\n\n"),
+            "Cube.hlsl(2): error: 1 #if without #endif"
+        );
+        assert_eq!(
+            error("#unknown\n"),
+            "Cube.hlsl(1): error: Unknown directive #unknown"
+        );
+        assert!(error("#if (1\n#endif\n").starts_with("Cube.hlsl(1): error: "));
+    }
+
+    #[test]
+    fn line_directives_and_the_source_map_point_at_the_original_lines() {
+        let files: MemoryFiles = MemoryFiles::new()
+            .add(
+                "Cube.hlsl",
+                "#include \"Common.hlsli\"\n#define UNUSED\n\nfloat4 main() : SV_Target;\n",
+            )
+            .add("Common.hlsli", "float scale;\n#line 100\nfloat bias;\n");
+        let preprocessed: Preprocessed = run(&files, "Cube.hlsl").unwrap();
+        // The #line of the source is dropped, the output has its own
+        assert_eq!(
+            preprocessed.code,
+            "#line 1 \"Common.hlsli\"\nfloat scale;\n#line 3 \"Common.hlsli\"\nfloat bias;\n\
+             #line 3 \"Cube.hlsl\"\n\nfloat4 main() : SV_Target;\n"
+        );
+        let map: &SourceMap = &preprocessed.source_map;
+        assert_eq!(map.original(2), Some(("Common.hlsli", 1)));
+        assert_eq!(map.original(4), Some(("Common.hlsli", 3)));
+        assert_eq!(map.original(7), Some(("Cube.hlsl", 4)));
+        assert_eq!(map.original(0), None);
+        assert_eq!(map.original(8), None);
+
+        let mut preprocessor: Preprocessor = Preprocessor::new(&files);
+        preprocessor.line_directives = false;
+        let preprocessed: Preprocessed = preprocessor.run("Cube.hlsl").unwrap();
+        assert_eq!(
+            preprocessed.code,
+            "float scale;\nfloat bias;\n\nfloat4 main() : SV_Target;\n"
+        );
+        assert_eq!(
+            preprocessed.source_map.remap(
+                "Cube.hlsl",
+                "Cube.hlsl(2,7): error X3000: syntax error\nCube.hlsl(4): warning\nCube.hlsl(9): far\nCube.hlsl"
+            ),
+            "Common.hlsli(3,7): error X3000: syntax error\nCube.hlsl(4): warning\nCube.hlsl(9): far\nCube.hlsl"
+        );
+    }
+
+    #[test]
+    fn shaders_get_their_defines_and_embedded_source() {
+        let files: MemoryFiles = MemoryFiles::new().add("Common.hlsli", "float scale;\n");
+        let mut desc: ShaderDesc = ShaderDesc::new(
+            crate::window::graphics::backend::ShaderStage::Pixel,
+            "Cube.hlsl".into(),
+        );
+        desc.code = ShaderCode::Source("#include \"Common.hlsli\"\nfloat x = SAMPLES;\n".into());
+        desc.defines.push(("SAMPLES".to_string(), "4".to_string()));
+        let preprocessed: Preprocessed = preprocess_shader(&desc, &files).unwrap();
+        assert!(preprocessed
+            .code
+            .ends_with("float scale;\n#line 2 \"Cube.hlsl\"\nfloat x = 4;\n"));
+
+        desc.code = ShaderCode::File;
+        assert!(preprocess_shader(&desc, &files).is_err());
+    }
+
+    #[test]
+    fn embedded_shaders_include_embedded_files() {
+        let mut embedded: ShaderSet = ShaderSet::default();
+        embedded.add_source(
+            "Lit_ps.hlsl",
+            "#include \"lighting/Light.hlsli\"\nfloat4 main() : SV_Target { return LIGHT; }\n",
+        );
+        embedded.add_source(
+            "lighting/Light.hlsli",
+            "#include \"Color.hlsli\"\n#define LIGHT COLOR * 2\n",
+        );
+        embedded.add_source("lighting/Color.hlsli", "#define COLOR 0.5\n");
+        let mut locator: ShaderLocator = ShaderLocator::new(embedded);
+        let desc: ShaderDesc = locator
+            .shader(
+                crate::window::graphics::backend::ShaderStage::Pixel,
+                "Lit_ps.hlsl",
+            )
+            .unwrap();
+
+        let preprocessed: Preprocessed = preprocess_shader(&desc, &locator).unwrap();
+        assert!(preprocessed.code.ends_with("{ return 0.5 * 2; }\n"));
+        assert_eq!(
+            preprocessed.files,
+            vec![
+                "Lit_ps.hlsl",
+                "lighting/Light.hlsli",
+                "lighting/Color.hlsli"
+            ]
+        );
+
+        // A file in the search path replaces an embedded include, like it replaces a shader
+        let dir: PathBuf =
+            std::env::temp_dir().join(format!("preprocessor_{}", std::process::id()));
+        fs::create_dir_all(dir.join("lighting")).unwrap();
+        fs::write(dir.join("lighting/Color.hlsli"), "#define COLOR 0.25\n").unwrap();
+        locator.search_path.push(dir.clone());
+        let preprocessed: Preprocessed = preprocess_shader(&desc, &locator).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+        assert!(preprocessed.code.ends_with("{ return 0.25 * 2; }\n"));
+    }
+
+    #[test]
+    fn paths_are_normalized() {
+        assert_eq!(
+            normalize(".\\shaders/./lib/../Common.hlsli"),
+            "shaders/Common.hlsli"
+        );
+        assert_eq!(normalize("../Common.hlsli"), "../Common.hlsli");
+        assert_eq!(normalize("a/../../Common.hlsli"), "../Common.hlsli");
+    }
+}
@@ -0,0 +1,1044 @@
+use std::{collections::HashMap, fmt};
+
+use windows::Win32::Foundation::E_INVALIDARG;
+
+use super::{
+    backend::{ShaderCode, ShaderDesc, VertexFormat},
+    bindable::{
+        cbufferlayout::{CbufferLayout, HlslType, HlslValue, ScalarType},
+        vertexlayout::VertexLayout,
+    },
+    preprocessor::{preprocess_shader, FileSystem},
+};
+use crate::window::errors::Error;
+
+/// Variables outside of a `cbuffer` end up in a cbuffer with this name
+pub const GLOBALS_CBUFFER: &str = "$Globals";
+
+/// Words in front of a type that don't change its layout
+const MODIFIERS: [&str; 17] = [
+    "in",
+    "out",
+    "inout",
+    "uniform",
+    "const",
+    "extern",
+    "nointerpolation",
+    "linear",
+    "centroid",
+    "noperspective",
+    "sample",
+    "precise",
+    "point",
+    "line",
+    "triangle",
+    "lineadj",
+    "triangleadj",
+];
+
+/// A `cbuffer` with its register, like `b0`, when the shader picks one
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cbuffer {
+    pub name: String,
+    pub register: Option<u32>,
+    pub layout: CbufferLayout,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ResourceKind {
+    Texture,
+    Sampler,
+    /// A buffer that is read like a texture, like `StructuredBuffer`
+    Buffer,
+    /// A `RW` texture or buffer
+    UnorderedAccess,
+}
+
+/// A texture, sampler or buffer that is bound to a register
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResourceBinding {
+    pub name: String,
+    pub kind: ResourceKind,
+    /// The declared type, like `Texture2D<float4>`
+    pub ty: String,
+    pub register: Option<u32>,
+    /// Amount of resources for arrays like `Texture2D textures[4]`
+    pub count: u32,
+}
+
+/// An input or output of a function, like `float3 pos : Position`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Parameter {
+    pub name: String,
+    pub ty: HlslType,
+    /// The semantic without its index, as it is written
+    pub semantic: String,
+    pub semantic_index: u32,
+}
+
+impl Parameter {
+    /// `SV_` semantics are values of the pipeline and not of the vertex buffers
+    pub fn is_system_value(&self) -> bool {
+        return self.semantic.to_ascii_uppercase().starts_with("SV_");
+    }
+
+    /// Semantics are compared without case, `SV_Target` is `SV_TARGET`
+    pub fn has_semantic(&self, semantic: &str, index: u32) -> bool {
+        return self.semantic.eq_ignore_ascii_case(semantic) && self.semantic_index == index;
+    }
+}
+
+impl fmt::Display for Parameter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {} : {}", self.ty, self.name, self.semantic)?;
+        if self.semantic_index != 0 {
+            write!(f, "{}", self.semantic_index)?;
+        }
+        return Ok(());
+    }
+}
+
+/// A function that can be an entry point, with the parameters of structs flattened
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Function {
+    pub name: String,
+    pub inputs: Vec<Parameter>,
+    /// The `out` parameters and the return value
+    pub outputs: Vec<Parameter>,
+}
+
+/// What the declarations of HLSL source say about the interface of a shader. This is no compiler: it only
+/// reads the declarations at the top level and skips the bodies of functions. Run it on preprocessed
+/// source, directives other than `#pragma pack_matrix` are skipped.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ShaderReflection {
+    pub cbuffers: Vec<Cbuffer>,
+    pub resources: Vec<ResourceBinding>,
+    pub functions: Vec<Function>,
+    /// The structs, by name
+    pub structs: HashMap<String, HlslType>,
+}
+
+impl ShaderReflection {
+    pub fn parse(source: &str) -> Result<ShaderReflection, String> {
+        let mut parser: Parser = Parser {
+            tokens: tokenize(source),
+            index: 0,
+            row_major: false,
+            reflection: ShaderReflection::default(),
+            globals: vec![],
+            struct_members: HashMap::new(),
+        };
+        parser.parse()?;
+        return Ok(parser.reflection);
+    }
+
+    /// Preprocess the source of a shader with its defines and read it. Shaders that are only bytecode have
+    /// no source, those are `None`.
+    pub fn of_shader(
+        desc: &ShaderDesc,
+        files: &dyn FileSystem,
+    ) -> Result<Option<ShaderReflection>, String> {
+        if let ShaderCode::Bytecode(_) = desc.code {
+            return Ok(None);
+        }
+        let preprocessed = preprocess_shader(desc, files).map_err(|e| e.to_string())?;
+        return ShaderReflection::parse(&preprocessed.code).map(Some);
+    }
+
+    pub fn cbuffer(&self, name: &str) -> Option<&Cbuffer> {
+        return self.cbuffers.iter().find(|cbuffer| cbuffer.name == name);
+    }
+
+    pub fn resource(&self, name: &str) -> Option<&ResourceBinding> {
+        return self.resources.iter().find(|resource| resource.name == name);
+    }
+
+    pub fn function(&self, name: &str) -> Option<&Function> {
+        return self.functions.iter().find(|function| function.name == name);
+    }
+
+    /// Check that `T` fills the cbuffer `name`, see [`CbufferLayout::validate()`]
+    pub fn validate_cbuffer<T: HlslValue>(&self, name: &str) -> Result<(), String> {
+        let cbuffer: &Cbuffer = self
+            .cbuffer(name)
+            .ok_or_else(|| format!("The shader has no cbuffer {}", name))?;
+        return cbuffer
+            .layout
+            .validate::<T>()
+            .map_err(|e| format!("cbuffer {}: {}", name, e));
+    }
+
+    /// Check that the vertices of `layout` have every input of the entry point, in a format with the same
+    /// kind of numbers
+    pub fn validate_vertex_layout(
+        &self,
+        entry_point: &str,
+        layout: &VertexLayout,
+    ) -> Result<(), String> {
+        let function: &Function = self
+            .function(entry_point)
+            .ok_or_else(|| format!("The shader has no function {}", entry_point))?;
+
+        for input in function
+            .inputs
+            .iter()
+            .filter(|input| !input.is_system_value())
+        {
+            if input.semantic.is_empty() {
+                return Err(format!(
+                    "The input {} of {} has no semantic",
+                    input.name, entry_point
+                ));
+            }
+            let element = layout
+                .elements
+                .iter()
+                .find(|element| {
+                    input.has_semantic(element.kind.semantic(), element.kind.semantic_index())
+                })
+                .ok_or_else(|| {
+                    format!(
+                        "The vertex layout has no element for the input {} of {}",
+                        input, entry_point
+                    )
+                })?;
+
+            // Components that are missing are filled in by the input assembler, so only the kind of
+            // number has to fit
+            let scalar: ScalarType = match &input.ty {
+                HlslType::Scalar(scalar) | HlslType::Vector(scalar, _) => *scalar,
+                ty => {
+                    return Err(format!(
+                        "The input {} of {} is a {}, not a vector",
+                        input, entry_point, ty
+                    ))
+                }
+            };
+            let integer: bool = matches!(scalar, ScalarType::Int | ScalarType::Uint);
+            if integer != (element.format == VertexFormat::UByte4) {
+                return Err(format!(
+                    "The input {} of {} doesn't fit the {:?} vertex element",
+                    input, entry_point, element.format
+                ));
+            }
+        }
+        return Ok(());
+    }
+}
+
+/// Read the source of a shader and check it with `check`, the way the Rust side uses it. Shaders without
+/// source pass.
+pub fn validate_shader(
+    desc: &ShaderDesc,
+    files: &dyn FileSystem,
+    check: impl FnOnce(&ShaderReflection) -> Result<(), String>,
+) -> Result<(), Error> {
+    let path: String = desc.path.display().to_string();
+    return match ShaderReflection::of_shader(desc, files) {
+        Ok(Some(reflection)) => check(&reflection).map_err(|e| {
+            Error::shader_compile(
+                &path,
+                E_INVALIDARG,
+                &format!("The Rust side doesn't match: {}", e),
+            )
+        }),
+        Ok(None) => Ok(()),
+        Err(e) => Err(Error::shader_compile(&path, E_INVALIDARG, &e)),
+    };
+}
+
+/// A declared variable, like `row_major float4x4 bones[4] : register(c0)`
+struct Variable {
+    modifiers: Vec<String>,
+    ty: String,
+    name: String,
+    dimensions: Vec<u32>,
+    semantic: Option<String>,
+    register: Option<String>,
+}
+
+struct Parser {
+    tokens: Vec<String>,
+    index: usize,
+    /// `#pragma pack_matrix(row_major)` makes matrices row major
+    row_major: bool,
+    reflection: ShaderReflection,
+    globals: Vec<(String, HlslType)>,
+    /// The members of the structs with their semantics, for the signatures of functions
+    struct_members: HashMap<String, Vec<Variable>>,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&str> {
+        return self.tokens.get(self.index).map(String::as_str);
+    }
+
+    fn next(&mut self) -> Result<String, String> {
+        let token: String = self
+            .tokens
+            .get(self.index)
+            .cloned()
+            .ok_or_else(|| "The source ends in the middle of a declaration".to_string())?;
+        self.index += 1;
+        return Ok(token);
+    }
+
+    fn expect(&mut self, expected: &str) -> Result<(), String> {
+        let token: String = self.next()?;
+        if token != expected {
+            return Err(format!("Expected {} but got {}", expected, token));
+        }
+        return Ok(());
+    }
+
+    fn eat(&mut self, token: &str) -> bool {
+        if self.peek() == Some(token) {
+            self.index += 1;
+            return true;
+        }
+        return false;
+    }
+
+    /// Skip to after the bracket that closes the one that was just read
+    fn skip_group(&mut self, open: &str, close: &str) -> Result<(), String> {
+        let mut depth: usize = 1;
+        while depth > 0 {
+            let token: String = self.next()?;
+            if token == open {
+                depth += 1;
+            } else if token == close {
+                depth -= 1;
+            }
+        }
+        return Ok(());
+    }
+
+    fn parse(&mut self) -> Result<(), String> {
+        while let Some(token) = self.peek() {
+            match token {
+                ";" => self.index += 1,
+                "#pragma" => {
+                    self.index += 1;
+                    if self.eat("pack_matrix") {
+                        self.expect("(")?;
+                        self.row_major = self.next()? == "row_major";
+                        self.expect(")")?;
+                    }
+                }
+                // Attributes like [numthreads(8, 8, 1)]
+                "[" => {
+                    self.index += 1;
+                    self.skip_group("[", "]")?;
+                }
+                "struct" => {
+                    self.index += 1;
+                    let name: String = self.next()?;
+                    let members: Vec<Variable> = self.members()?;
+                    let ty: HlslType = HlslType::Struct(
+                        members
+                            .iter()
+                            .map(|member| Ok((member.name.clone(), self.hlsl_type(member)?)))
+                            .collect::<Result<Vec<(String, HlslType)>, String>>()?,
+                    );
+                    self.reflection.structs.insert(name.clone(), ty);
+                    self.struct_members.insert(name, members);
+                    self.expect(";")?;
+                }
+                "cbuffer" | "tbuffer" => {
+                    self.index += 1;
+                    let name: String = self.next()?;
+                    let register: Option<u32> = match self.eat(":") {
+                        true => self.register()?,
+                        false => None,
+                    };
+                    let members: Vec<Variable> = self.members()?;
+                    let members: Vec<(String, HlslType)> = members
+                        .iter()
+                        .map(|member| Ok((member.name.clone(), self.hlsl_type(member)?)))
+                        .collect::<Result<Vec<(String, HlslType)>, String>>()?;
+                    self.add_cbuffer(name, register, &members);
+                }
+                "typedef" => self.skip_statement()?,
+                _ => self.declaration()?,
+            }
+        }
+
+        if !self.globals.is_empty() {
+            let globals: Vec<(String, HlslType)> = std::mem::take(&mut self.globals);
+            self.add_cbuffer(GLOBALS_CBUFFER.to_string(), None, &globals);
+        }
+        return Ok(());
+    }
+
+    fn add_cbuffer(&mut self, name: String, register: Option<u32>, members: &[(String, HlslType)]) {
+        let members: Vec<(&str, HlslType)> = members
+            .iter()
+            .map(|(name, ty)| (name.as_str(), ty.clone()))
+            .collect();
+        self.reflection.cbuffers.push(Cbuffer {
+            name,
+            register,
+            layout: CbufferLayout::new(&members),
+        });
+    }
+
+    fn skip_statement(&mut self) -> Result<(), String> {
+        while self.next()? != ";" {}
+        return Ok(());
+    }
+
+    /// The variables between `{` and `}`
+    fn members(&mut self) -> Result<Vec<Variable>, String> {
+        self.expect("{")?;
+        let mut members: Vec<Variable> = vec![];
+        while !self.eat("}") {
+            members.extend(self.variables(true)?);
+            self.expect(";")?;
+        }
+        return Ok(members);
+    }
+
+    /// A declaration of one or more variables, like `float a, b[2] : B`, without the `;`. Parameters of
+    /// functions are one variable, `list` is false for them.
+    fn variables(&mut self, list: bool) -> Result<Vec<Variable>, String> {
+        let mut modifiers: Vec<String> = vec![];
+        while let Some(modifier) = self.peek().filter(|token| is_modifier(token)) {
+            modifiers.push(modifier.to_string());
+            self.index += 1;
+        }
+        let mut ty: String = self.next()?;
+        if self.eat("<") {
+            ty.push('<');
+            while !self.eat(">") {
+                ty.push_str(&self.next()?);
+            }
+            ty.push('>');
+        }
+
+        let mut variables: Vec<Variable> = vec![];
+        loop {
+            let mut variable: Variable = Variable {
+                modifiers: modifiers.clone(),
+                ty: ty.clone(),
+                name: self.next()?,
+                dimensions: vec![],
+                semantic: None,
+                register: None,
+            };
+            if !is_identifier(&variable.name) {
+                return Err(format!(
+                    "Expected a name after {} but got {}",
+                    ty, variable.name
+                ));
+            }
+            while self.eat("[") {
+                let length: String = self.next()?;
+                variable.dimensions.push(length.parse().map_err(|_| {
+                    format!("The length of {} isn't a number: {}", variable.name, length)
+                })?);
+                self.expect("]")?;
+            }
+            while self.eat(":") {
+                match self.next()?.as_str() {
+                    "register" => {
+                        self.expect("(")?;
+                        variable.register = Some(self.next()?);
+                        self.skip_group("(", ")")?;
+                    }
+                    "packoffset" => {
+                        return Err(format!("packoffset of {} isn't supported", variable.name));
+                    }
+                    semantic => variable.semantic = Some(semantic.to_string()),
+                }
+            }
+            // Default values, like the initializer of a static
+            if self.eat("=") {
+                let mut depth: usize = 0;
+                while !(depth == 0 && matches!(self.peek(), Some(";" | "," | ")") | None)) {
+                    match self.next()?.as_str() {
+                        "{" | "(" => depth += 1,
+                        "}" | ")" => depth = depth.saturating_sub(1),
+                        _ => {}
+                    }
+                }
+            }
+            variables.push(variable);
+            if !list || !self.eat(",") {
+                return Ok(variables);
+            }
+        }
+    }
+
+    /// A global variable, resource or function
+    fn declaration(&mut self) -> Result<(), String> {
+        let start: usize = self.index;
+        // A function has a ( after its name, a variable can have one after a : or =
+        let mut probe: usize = self.index;
+        while probe < self.tokens.len()
+            && !matches!(self.tokens[probe].as_str(), ";" | ":" | "(" | "{" | "=")
+        {
+            probe += 1;
+        }
+        if self.tokens.get(probe).map(String::as_str) == Some("(") {
+            return self.function();
+        }
+
+        let variables: Vec<Variable> = self.variables(true)?;
+        self.expect(";")
+            .map_err(|e| format!("{} after the declaration at token {}", e, start))?;
+        for variable in variables {
+            if variable
+                .modifiers
+                .iter()
+                .any(|modifier| modifier == "static" || modifier == "groupshared")
+            {
+                continue;
+            }
+            match resource_kind(&variable.ty) {
+                Some(kind) => self.reflection.resources.push(ResourceBinding {
+                    register: variable.register.as_deref().and_then(register_number),
+                    count: variable.dimensions.iter().product(),
+                    name: variable.name,
+                    kind,
+                    ty: variable.ty,
+                }),
+                // Constants like `static const float PI` aren't in a cbuffer
+                None if variable
+                    .modifiers
+                    .iter()
+                    .any(|modifier| modifier == "const") => {}
+                None => {
+                    let ty: HlslType = self.hlsl_type(&variable)?;
+                    self.globals.push((variable.name, ty));
+                }
+            }
+        }
+        return Ok(());
+    }
+
+    fn function(&mut self) -> Result<(), String> {
+        let mut header: Vec<String> = vec![];
+        while !self.eat("(") {
+            header.push(self.next()?);
+        }
+        let name: String = header
+            .pop()
+            .ok_or_else(|| "A function without a name".to_string())?;
+        let return_type: String = header
+            .into_iter()
+            .filter(|token| !is_modifier(token) && token != "static" && token != "inline")
+            .collect::<Vec<String>>()
+            .join("");
+
+        let mut function: Function = Function {
+            name,
+            inputs: vec![],
+            outputs: vec![],
+        };
+        if !self.eat(")") {
+            loop {
+                let parameter: Variable = self.variables(false)?.remove(0);
+                if parameter
+                    .modifiers
+                    .iter()
+                    .any(|modifier| modifier == "uniform")
+                {
+                    if self.eat(")") {
+                        break;
+                    }
+                    self.expect(",")?;
+                    continue;
+                }
+                let output: bool = parameter.modifiers.iter().any(|modifier| modifier == "out");
+                let input: bool = !output
+                    || parameter
+                        .modifiers
+                        .iter()
+                        .any(|modifier| modifier == "inout");
+                let flattened: Vec<Parameter> = self.parameters(&parameter);
+                if input {
+                    function.inputs.extend(flattened.iter().cloned());
+                }
+                if output
+                    || parameter
+                        .modifiers
+                        .iter()
+                        .any(|modifier| modifier == "inout")
+                {
+                    function.outputs.extend(flattened);
+                }
+                if self.eat(")") {
+                    break;
+                }
+                self.expect(",")?;
+            }
+        }
+
+        let mut semantic: Option<String> = None;
+        if self.eat(":") {
+            semantic = Some(self.next()?);
+        }
+        if return_type != "void" {
+            let returned: Variable = Variable {
+                modifiers: vec![],
+                ty: return_type,
+                name: function.name.clone(),
+                dimensions: vec![],
+                semantic,
+                register: None,
+            };
+            function.outputs.extend(self.parameters(&returned));
+        }
+
+        // A prototype has no body
+        if !self.eat(";") {
+            self.expect("{")?;
+            self.skip_group("{", "}")?;
+        }
+        self.reflection.functions.push(function);
+        return Ok(());
+    }
+
+    /// A parameter, or the members of a struct parameter. Parameters of helper functions can have types
+    /// that can't be in a signature, like textures, those are left out. The semantic is empty when there
+    /// is none.
+    fn parameters(&self, variable: &Variable) -> Vec<Parameter> {
+        if let Some(members) = self.struct_members.get(&variable.ty) {
+            return members
+                .iter()
+                .flat_map(|member| self.parameters(member))
+                .collect();
+        }
+
+        let Ok(ty) = self.hlsl_type(variable) else {
+            return vec![];
+        };
+        let semantic: &str = variable.semantic.as_deref().unwrap_or_default();
+        let digits: usize = semantic
+            .trim_end_matches(|c: char| c.is_ascii_digit())
+            .len();
+        return vec![Parameter {
+            name: variable.name.clone(),
+            ty,
+            semantic: semantic[..digits].to_string(),
+            semantic_index: semantic[digits..].parse().unwrap_or(0),
+        }];
+    }
+
+    fn register(&mut self) -> Result<Option<u32>, String> {
+        self.expect("register")?;
+        self.expect("(")?;
+        let register: String = self.next()?;
+        self.skip_group("(", ")")?;
+        return Ok(register_number(&register));
+    }
+
+    fn hlsl_type(&self, variable: &Variable) -> Result<HlslType, String> {
+        let row_major: bool = match (
+            variable
+                .modifiers
+                .iter()
+                .any(|modifier| modifier == "row_major"),
+            variable
+                .modifiers
+                .iter()
+                .any(|modifier| modifier == "column_major"),
+        ) {
+            (true, _) => true,
+            (_, true) => false,
+            _ => self.row_major,
+        };
+
+        let mut ty: HlslType = match self.reflection.structs.get(&variable.ty) {
+            Some(ty) => ty.clone(),
+            None => parse_type(&variable.ty, row_major).ok_or_else(|| {
+                format!(
+                    "The type {} of {} isn't supported",
+                    variable.ty, variable.name
+                )
+            })?,
+        };
+        for length in variable.dimensions.iter().rev() {
+            ty = HlslType::array(ty, *length);
+        }
+        return Ok(ty);
+    }
+}
+
+/// Types like `float`, `uint2`, `float3x4`, `matrix` and `vector`
+fn parse_type(name: &str, row_major: bool) -> Option<HlslType> {
+    match name {
+        "matrix" => {
+            return Some(HlslType::Matrix {
+                rows: 4,
+                columns: 4,
+                row_major,
+            })
+        }
+        "vector" => return Some(HlslType::float_n(4)),
+        _ => {}
+    }
+
+    let dimensions_at: usize = name
+        .find(|c: char| c.is_ascii_digit())
+        .unwrap_or(name.len());
+    let scalar: ScalarType = match &name[..dimensions_at] {
+        "float" | "half" | "double" | "min16float" => ScalarType::Float,
+        "int" | "min16int" => ScalarType::Int,
+        "uint" | "dword" | "min16uint" => ScalarType::Uint,
+        "bool" => ScalarType::Bool,
+        _ => return None,
+    };
+    let dimensions: Vec<u32> = match &name[dimensions_at..] {
+        "" => vec![],
+        dimensions => dimensions
+            .split('x')
+            .map(|dimension| dimension.parse().ok().filter(|n| (1..=4).contains(n)))
+            .collect::<Option<Vec<u32>>>()?,
+    };
+    return match dimensions.as_slice() {
+        [] | [1] => Some(HlslType::Scalar(scalar)),
+        [components] => Some(HlslType::Vector(scalar, *components)),
+        // The layout of matrices is only known for floats
+        [rows, columns] if scalar == ScalarType::Float => Some(HlslType::Matrix {
+            rows: *rows,
+            columns: *columns,
+            row_major,
+        }),
+        _ => None,
+    };
+}
+
+fn resource_kind(ty: &str) -> Option<ResourceKind> {
+    let base: &str = ty.split('<').next().unwrap_or_default();
+    return match base {
+        "SamplerState" | "SamplerComparisonState" | "sampler" => Some(ResourceKind::Sampler),
+        _ if base.starts_with("RW") || base.starts_with("RasterizerOrdered") => {
+            Some(ResourceKind::UnorderedAccess)
+        }
+        _ if base.starts_with("Texture") => Some(ResourceKind::Texture),
+        "Buffer" | "StructuredBuffer" | "ByteAddressBuffer" => Some(ResourceKind::Buffer),
+        _ => None,
+    };
+}
+
+/// The number of a register like `b0`, `t3` or `s1`
+fn register_number(register: &str) -> Option<u32> {
+    return register.get(1..)?.parse().ok();
+}
+
+fn is_modifier(token: &str) -> bool {
+    return MODIFIERS.contains(&token)
+        || matches!(
+            token,
+            "static" | "groupshared" | "row_major" | "column_major"
+        );
+}
+
+fn is_identifier(token: &str) -> bool {
+    return token.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+        && token.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+}
+
+/// Identifiers, numbers, single characters and `#pragma`. Comments, strings and other directives are
+/// left out.
+fn tokenize(source: &str) -> Vec<String> {
+    let chars: Vec<char> = source.chars().collect();
+    let mut tokens: Vec<String> = vec![];
+    let mut index: usize = 0;
+    let mut line_start: bool = true;
+    while index < chars.len() {
+        let c: char = chars[index];
+        let start: usize = index;
+        if c == '\n' {
+            line_start = true;
+            index += 1;
+            continue;
+        }
+        if c.is_whitespace() {
+            index += 1;
+            continue;
+        }
+
+        if c == '#' && line_start {
+            while index < chars.len() && chars[index] != '\n' {
+                index += 1;
+            }
+            let directive: String = chars[start + 1..index].iter().collect();
+            // Only pack_matrix changes declarations, other pragmas and directives are skipped
+            if let Some(pragma) = directive.trim().strip_prefix("pragma") {
+                tokens.push("#pragma".to_string());
+                tokens.extend(tokenize(pragma));
+            }
+            continue;
+        }
+        line_start = false;
+
+        if c == '/' && chars.get(index + 1) == Some(&'/') {
+            while index < chars.len() && chars[index] != '\n' {
+                index += 1;
+            }
+        } else if c == '/' && chars.get(index + 1) == Some(&'*') {
+            index += 2;
+            while index < chars.len() && !(chars[index - 1] == '*' && chars[index] == '/') {
+                index += 1;
+            }
+            index += 1;
+        } else if c == '"' {
+            index += 1;
+            while index < chars.len() && chars[index] != '"' {
+                index += if chars[index] == '\\' { 2 } else { 1 };
+            }
+            index += 1;
+        } else if c.is_ascii_alphanumeric() || c == '_' || c == '.' {
+            while index < chars.len()
+                && (chars[index].is_ascii_alphanumeric() || matches!(chars[index], '_' | '.'))
+            {
+                index += 1;
+            }
+            tokens.push(chars[start..index].iter().collect());
+        } else {
+            index += 1;
+            tokens.push(c.to_string());
+        }
+    }
+    return tokens;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::window::graphics::{
+        backend::ShaderStage,
+        bindable::vertexlayout::{ElementKind, Vertex},
+        shaderlocator::{ShaderLocator, ShaderSet},
+        CubeVertex, TransformCB, CB2,
+    };
+
+    /// The reflection of an embedded shader, read from its source
+    fn cube_shader(stage: ShaderStage, name: &str) -> ShaderReflection {
+        let embedded: ShaderSet = ShaderSet::embedded();
+        let mut desc: ShaderDesc = ShaderLocator::new(embedded.clone())
+            .shader(stage, name)
+            .unwrap();
+        // The bytecode has no source to read
+        if let ShaderCode::Bytecode(_) = desc.code {
+            desc.code = ShaderCode::Source(embedded.get(name).unwrap().source.clone().unwrap());
+        }
+        return ShaderReflection::of_shader(&desc, &embedded)
+            .unwrap()
+            .unwrap();
+    }
+
+    fn parameter(name: &str, ty: HlslType, semantic: &str, semantic_index: u32) -> Parameter {
+        return Parameter {
+            name: name.to_string(),
+            ty,
+            semantic: semantic.to_string(),
+            semantic_index,
+        };
+    }
+
+    #[test]
+    fn the_cube_vertex_shader_fits_the_rust_side() {
+        let shader: ShaderReflection = cube_shader(ShaderStage::Vertex, "VertexShader.hlsl");
+        let cbuffer: &Cbuffer = shader.cbuffer("CBuf").unwrap();
+        assert_eq!(cbuffer.register, None);
+        assert_eq!(cbuffer.layout, CbufferLayout::of::<TransformCB>());
+        assert_eq!(cbuffer.layout.size, 64);
+        assert_eq!(shader.validate_cbuffer::<TransformCB>("CBuf"), Ok(()));
+        assert!(shader.validate_cbuffer::<CB2>("CBuf").is_err());
+        assert!(shader.validate_cbuffer::<TransformCB>("Transform").is_err());
+        assert!(shader.resources.is_empty());
+
+        let main: &Function = shader.function("main").unwrap();
+        assert_eq!(
+            main.inputs,
+            vec![parameter("pos", HlslType::float_n(3), "Position", 0)]
+        );
+        assert_eq!(
+            main.outputs,
+            vec![parameter("main", HlslType::float_n(4), "SV_Position", 0)]
+        );
+        assert_eq!(
+            shader.validate_vertex_layout("main", &CubeVertex::layout()),
+            Ok(())
+        );
+        assert!(shader
+            .validate_vertex_layout("main", &VertexLayout::new().normal())
+            .is_err());
+        assert!(shader
+            .validate_vertex_layout("shadow", &CubeVertex::layout())
+            .is_err());
+    }
+
+    #[test]
+    fn the_cube_pixel_shader_fits_the_rust_side() {
+        let shader: ShaderReflection = cube_shader(ShaderStage::Pixel, "PixelShader.hlsl");
+        let cbuffer: &Cbuffer = shader.cbuffer("CBuf").unwrap();
+        // The Rust side has a struct of 4 floats where HLSL has a float4, they only match by offset
+        assert_eq!(
+            cbuffer.layout.members[0].ty,
+            HlslType::array(HlslType::float_n(4), 6)
+        );
+        assert_eq!(cbuffer.layout.size, CbufferLayout::of::<CB2>().size);
+        assert_eq!(shader.validate_cbuffer::<CB2>("CBuf"), Ok(()));
+        assert!(shader.validate_cbuffer::<TransformCB>("CBuf").is_err());
+
+        let main: &Function = shader.function("main").unwrap();
+        assert_eq!(
+            main.inputs,
+            vec![parameter(
+                "triangleId",
+                HlslType::Scalar(ScalarType::Uint),
+                "SV_PrimitiveID",
+                0
+            )]
+        );
+        assert!(main.inputs[0].is_system_value());
+        assert!(main.outputs[0].has_semantic("SV_TARGET", 0));
+        // Only system values come in, so any vertices do
+        assert_eq!(
+            shader.validate_vertex_layout("main", &VertexLayout::new()),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn validating_a_shader_reports_what_doesnt_match() {
+        let embedded: ShaderSet = ShaderSet::embedded();
+        let mut desc: ShaderDesc = ShaderDesc::new(ShaderStage::Pixel, "PixelShader.hlsl".into());
+        desc.code = ShaderCode::Source(
+            embedded
+                .get("PixelShader.hlsl")
+                .unwrap()
+                .source
+                .clone()
+                .unwrap(),
+        );
+
+        assert!(validate_shader(&desc, &embedded, |shader| shader
+            .validate_cbuffer::<CB2>("CBuf"))
+        .is_ok());
+        match validate_shader(&desc, &embedded, |shader| {
+            shader.validate_cbuffer::<TransformCB>("CBuf")
+        }) {
+            Err(Error::ShaderCompile { path, log, .. }) => {
+                assert_eq!(path, "PixelShader.hlsl");
+                assert!(log.starts_with("The Rust side doesn't match: cbuffer CBuf: "));
+            }
+            other => panic!("Expected a mismatch, got {:?}", other),
+        }
+
+        // Bytecode can't be checked
+        desc.code = ShaderCode::Bytecode(Vec::new().into());
+        assert!(validate_shader(&desc, &embedded, |_| Err("Not called".to_string())).is_ok());
+    }
+
+    #[test]
+    fn declarations_of_other_shaders_are_read() {
+        let source: &str = "#pragma pack_matrix(row_major)\n\
+            struct Light { float3 direction; float intensity; };\n\
+            struct VertexIn { float3 pos : POSITION; float2 uv : TEXCOORD1; uint4 bones : BLENDINDICES; };\n\
+            cbuffer Frame : register(b1) { float4x4 view; Light lights[2]; };\n\
+            float time;\n\
+            Texture2D<float4> albedo : register(t0);\n\
+            Texture2D shadows[4] : register(t2);\n\
+            SamplerState linear_sampler : register(s3);\n\
+            StructuredBuffer<float4> colors;\n\
+            RWTexture2D<float> output : register(u0);\n\
+            float4 main(VertexIn input, out float depth : SV_Depth) : SV_Target { return time; }\n";
+        let shader: ShaderReflection = ShaderReflection::parse(source).unwrap();
+
+        let frame: &Cbuffer = shader.cbuffer("Frame").unwrap();
+        assert_eq!(frame.register, Some(1));
+        assert_eq!(
+            frame
+                .layout
+                .members
+                .iter()
+                .map(|member| member.name.as_str())
+                .collect::<Vec<&str>>(),
+            vec!["view", "lights"]
+        );
+        assert_eq!(
+            frame.layout.members[0].ty,
+            HlslType::Matrix {
+                rows: 4,
+                columns: 4,
+                row_major: true
+            }
+        );
+        assert_eq!(frame.layout.size, 64 + 16 + 16);
+        let globals: &Cbuffer = shader.cbuffer(GLOBALS_CBUFFER).unwrap();
+        assert_eq!(globals.layout.size, 16);
+
+        let resources: Vec<(&str, ResourceKind, Option<u32>, u32)> = shader
+            .resources
+            .iter()
+            .map(|resource| {
+                (
+                    resource.name.as_str(),
+                    resource.kind,
+                    resource.register,
+                    resource.count,
+                )
+            })
+            .collect();
+        assert_eq!(
+            resources,
+            vec![
+                ("albedo", ResourceKind::Texture, Some(0), 1),
+                ("shadows", ResourceKind::Texture, Some(2), 4),
+                ("linear_sampler", ResourceKind::Sampler, Some(3), 1),
+                ("colors", ResourceKind::Buffer, None, 1),
+                ("output", ResourceKind::UnorderedAccess, Some(0), 1),
+            ]
+        );
+        assert_eq!(shader.resource("albedo").unwrap().ty, "Texture2D<float4>");
+
+        let main: &Function = shader.function("main").unwrap();
+        assert_eq!(
+            main.inputs,
+            vec![
+                parameter("pos", HlslType::float_n(3), "POSITION", 0),
+                parameter("uv", HlslType::float_n(2), "TEXCOORD", 1),
+                parameter(
+                    "bones",
+                    HlslType::Vector(ScalarType::Uint, 4),
+                    "BLENDINDICES",
+                    0
+                ),
+            ]
+        );
+        assert_eq!(
+            main.outputs,
+            vec![
+                parameter("depth", HlslType::Scalar(ScalarType::Float), "SV_Depth", 0),
+                parameter("main", HlslType::float_n(4), "SV_Target", 0),
+            ]
+        );
+        assert!(shader.structs.contains_key("Light"));
+
+        let layout: VertexLayout = VertexLayout::new()
+            .position()
+            .element(ElementKind::TexCoord(1), VertexFormat::Float2)
+            .element(ElementKind::BoneIndices, VertexFormat::UByte4);
+        assert_eq!(shader.validate_vertex_layout("main", &layout), Ok(()));
+        let floats: VertexLayout = VertexLayout::new()
+            .position()
+            .element(ElementKind::TexCoord(1), VertexFormat::Float2)
+            .element(ElementKind::BoneIndices, VertexFormat::Float4);
+        assert!(shader
+            .validate_vertex_layout("main", &floats)
+            .unwrap_err()
+            .contains("bones"));
+    }
+
+    #[test]
+    fn broken_declarations_are_errors() {
+        assert!(ShaderReflection::parse("cbuffer Frame { float4 color; ").is_err());
+        assert!(ShaderReflection::parse("cbuffer Frame { Unknown thing; };").is_err());
+    }
+}
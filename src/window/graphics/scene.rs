@@ -0,0 +1,379 @@
+use directx_math::*;
+
+use std::path::Path;
+
+use super::mesh::{gltf, Mesh};
+
+/// Where a node is relative to its parent: scaled first, then rotated, then moved
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Transform {
+    pub translation: [f32; 3],
+    /// A unit quaternion as `x, y, z, w`
+    pub rotation: [f32; 4],
+    pub scale: [f32; 3],
+}
+
+impl Default for Transform {
+    fn default() -> Transform {
+        return Transform {
+            translation: [0.0, 0.0, 0.0],
+            rotation: [0.0, 0.0, 0.0, 1.0],
+            scale: [1.0, 1.0, 1.0],
+        };
+    }
+}
+
+impl Transform {
+    /// Split a matrix into its translation, rotation and scale. `None` when it can't be split, like
+    /// when it has a projection or shears.
+    pub fn from_matrix(matrix: &XMFLOAT4X4) -> Option<Transform> {
+        let mut scale: XMVECTOR = XMVectorZero();
+        let mut rotation: XMVECTOR = XMVectorZero();
+        let mut translation: XMVECTOR = XMVectorZero();
+        let last_column: [f32; 4] = matrix.m.map(|row| row[3]);
+        if last_column != [0.0, 0.0, 0.0, 1.0]
+            || !XMMatrixDecompose(
+                &mut scale,
+                &mut rotation,
+                &mut translation,
+                XMLoadFloat4x4(matrix),
+            )
+        {
+            return None;
+        }
+
+        let mut transform: Transform = Transform::default();
+        let mut stored: XMFLOAT4 = XMFLOAT4::default();
+        XMStoreFloat4(&mut stored, translation);
+        transform.translation = [stored.x, stored.y, stored.z];
+        XMStoreFloat4(&mut stored, rotation);
+        transform.rotation = [stored.x, stored.y, stored.z, stored.w];
+        XMStoreFloat4(&mut stored, scale);
+        transform.scale = [stored.x, stored.y, stored.z];
+        return Some(transform);
+    }
+
+    /// The matrix for row vectors, like the other matrices of DirectXMath
+    pub fn matrix(&self) -> XMMATRIX {
+        let [x, y, z, w] = self.rotation;
+        return XMMatrixAffineTransformation(
+            XMVectorSet(self.scale[0], self.scale[1], self.scale[2], 0.0),
+            XMVectorZero(),
+            XMVectorSet(x, y, z, w),
+            XMVectorSet(
+                self.translation[0],
+                self.translation[1],
+                self.translation[2],
+                1.0,
+            ),
+        );
+    }
+}
+
+/// How a camera maps what it sees to the screen
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Projection {
+    Perspective {
+        /// The vertical field of view in radians
+        fov_y: f32,
+        /// Width divided by height, the one of the viewport when there is none
+        aspect_ratio: Option<f32>,
+        near: f32,
+        /// `None` for a projection without a far plane
+        far: Option<f32>,
+    },
+    Orthographic {
+        /// Half of the width that is seen
+        x_magnification: f32,
+        /// Half of the height that is seen
+        y_magnification: f32,
+        near: f32,
+        far: f32,
+    },
+}
+
+/// A camera that a node looks through. It looks along its negative z axis with y up.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Camera {
+    pub name: String,
+    pub projection: Projection,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LightKind {
+    /// Light from far away along the negative z axis of the node, in lux
+    Directional,
+    /// Light from the position of the node in all directions, in candela
+    Point,
+    /// Light from the position of the node in a cone around its negative z axis, in candela
+    Spot {
+        /// Angle in radians from the axis to where the light starts to fade
+        inner_cone_angle: f32,
+        /// Angle in radians from the axis to where the light ends
+        outer_cone_angle: f32,
+    },
+}
+
+/// A light that shines from a node
+#[derive(Debug, Clone, PartialEq)]
+pub struct Light {
+    pub name: String,
+    pub kind: LightKind,
+    /// Linear RGB
+    pub color: [f32; 3],
+    pub intensity: f32,
+    /// How far the light reaches, `None` when it fades out without a limit
+    pub range: Option<f32>,
+}
+
+/// A part of a scene. The mesh, camera and light are indices into the lists of the [`Scene`].
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Node {
+    pub name: String,
+    pub transform: Transform,
+    pub children: Vec<usize>,
+    pub mesh: Option<usize>,
+    pub camera: Option<usize>,
+    pub light: Option<usize>,
+}
+
+/// Nodes in a hierarchy with the meshes, cameras and lights they place. Every node has one parent at
+/// most and the nodes without one that are drawn are the roots.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Scene {
+    pub name: String,
+    pub nodes: Vec<Node>,
+    pub roots: Vec<usize>,
+    pub meshes: Vec<Mesh>,
+    pub cameras: Vec<Camera>,
+    pub lights: Vec<Light>,
+}
+
+impl Scene {
+    /// Load a scene file, the format comes from the extension. An OBJ file becomes a scene with one node
+    /// that has its mesh.
+    pub fn load(path: &Path) -> Result<Scene, String> {
+        let extension: String = path
+            .extension()
+            .map(|extension| extension.to_string_lossy().to_lowercase())
+            .unwrap_or_default();
+        return match extension.as_str() {
+            "gltf" | "glb" => gltf::load_gltf(path),
+            _ => Ok(Scene {
+                nodes: vec![Node {
+                    mesh: Some(0),
+                    ..Node::default()
+                }],
+                roots: vec![0],
+                meshes: vec![Mesh::load(path)?],
+                ..Scene::default()
+            }),
+        };
+    }
+
+    /// The matrix of every node that moves it from its own space to the space of the scene, the nodes
+    /// that aren't below a root get `None`
+    pub fn world_matrices(&self) -> Vec<Option<XMFLOAT4X4>> {
+        let mut matrices: Vec<Option<XMFLOAT4X4>> = vec![None; self.nodes.len()];
+        let mut stack: Vec<(usize, XMMATRIX)> = self
+            .roots
+            .iter()
+            .map(|root| (*root, XMMatrixIdentity()))
+            .collect();
+        while let Some((index, parent)) = stack.pop() {
+            let node: &Node = &self.nodes[index];
+            let world: XMMATRIX = XMMatrixMultiply(node.transform.matrix(), &parent);
+            let mut stored: XMFLOAT4X4 = XMFLOAT4X4::default();
+            XMStoreFloat4x4(&mut stored, world);
+            matrices[index] = Some(stored);
+            stack.extend(node.children.iter().map(|child| (*child, world)));
+        }
+        return matrices;
+    }
+
+    /// Check that the indices are in range and that the nodes form trees, so walking them ends
+    pub fn validate(&self) -> Result<(), String> {
+        let mut parents: Vec<Option<usize>> = vec![None; self.nodes.len()];
+        for (index, node) in self.nodes.iter().enumerate() {
+            let check = |what: &str, value: Option<usize>, count: usize| -> Result<(), String> {
+                return match value {
+                    Some(value) if value >= count => Err(format!(
+                        "Node {} uses {} {}, but there are {}",
+                        index, what, value, count
+                    )),
+                    _ => Ok(()),
+                };
+            };
+            check("mesh", node.mesh, self.meshes.len())?;
+            check("camera", node.camera, self.cameras.len())?;
+            check("light", node.light, self.lights.len())?;
+            for child in &node.children {
+                check("child", Some(*child), self.nodes.len())?;
+                if let Some(parent) = parents[*child].replace(index) {
+                    return Err(format!(
+                        "Node {} is a child of both node {} and node {}",
+                        child, parent, index
+                    ));
+                }
+            }
+        }
+
+        for root in &self.roots {
+            match parents.get(*root) {
+                None => return Err(format!("The root {} isn't a node", root)),
+                Some(Some(parent)) => {
+                    return Err(format!("The root {} is a child of node {}", root, parent))
+                }
+                Some(None) => {}
+            }
+        }
+        // A node that has one parent at most is in a cycle when walking up never ends
+        for start in 0..self.nodes.len() {
+            let mut node: usize = start;
+            for _ in 0..self.nodes.len() {
+                match parents[node] {
+                    Some(parent) => node = parent,
+                    None => break,
+                }
+            }
+            if parents[node].is_some() {
+                return Err(format!("The parents of node {} form a cycle", start));
+            }
+        }
+        return Ok(());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stored(matrix: XMMATRIX) -> XMFLOAT4X4 {
+        let mut stored: XMFLOAT4X4 = XMFLOAT4X4::default();
+        XMStoreFloat4x4(&mut stored, matrix);
+        return stored;
+    }
+
+    fn assert_transform_close(transform: Transform, expected: Transform) {
+        let values = |transform: Transform| -> Vec<f32> {
+            return [
+                transform.translation.as_slice(),
+                transform.rotation.as_slice(),
+                transform.scale.as_slice(),
+            ]
+            .concat();
+        };
+        for (value, expected_value) in values(transform).into_iter().zip(values(expected)) {
+            assert!(
+                (value - expected_value).abs() < 1e-5,
+                "{:?} isn't {:?}",
+                transform,
+                expected
+            );
+        }
+    }
+
+    /// Nodes that only have children
+    fn scene(children: &[&[usize]], roots: &[usize]) -> Scene {
+        return Scene {
+            nodes: children
+                .iter()
+                .map(|children| Node {
+                    children: children.to_vec(),
+                    ..Node::default()
+                })
+                .collect(),
+            roots: roots.to_vec(),
+            ..Scene::default()
+        };
+    }
+
+    #[test]
+    fn transforms_survive_a_round_trip_through_their_matrix() {
+        assert_eq!(
+            stored(Transform::default().matrix()).m,
+            stored(XMMatrixIdentity()).m
+        );
+
+        let half: f32 = std::f32::consts::FRAC_1_SQRT_2;
+        let transform: Transform = Transform {
+            translation: [1.0, -2.0, 3.0],
+            rotation: [half, 0.0, 0.0, half],
+            scale: [2.0, 3.0, 4.0],
+        };
+        let matrix: XMFLOAT4X4 = stored(transform.matrix());
+        // Row vectors, so the translation is the last row
+        assert_eq!(matrix.m[3], [1.0, -2.0, 3.0, 1.0]);
+        assert_transform_close(Transform::from_matrix(&matrix).unwrap(), transform);
+    }
+
+    #[test]
+    fn projections_and_shears_are_not_transforms() {
+        let projection: XMFLOAT4X4 = stored(XMMatrixPerspectiveFovLH(1.0, 1.5, 0.1, 100.0));
+        assert_eq!(Transform::from_matrix(&projection), None);
+
+        let mut sheared: XMFLOAT4X4 = stored(XMMatrixIdentity());
+        sheared.m[1][0] = 1.0;
+        assert_eq!(Transform::from_matrix(&sheared), None);
+    }
+
+    #[test]
+    fn children_are_placed_by_their_parents() {
+        let mut scene: Scene = scene(&[&[1], &[], &[]], &[0]);
+        scene.nodes[0].transform.translation = [1.0, 0.0, 0.0];
+        scene.nodes[0].transform.scale = [2.0, 2.0, 2.0];
+        scene.nodes[1].transform.translation = [0.0, 1.0, 0.0];
+
+        let world: Vec<Option<XMFLOAT4X4>> = scene.world_matrices();
+        assert_eq!(world[0].unwrap().m[3], [1.0, 0.0, 0.0, 1.0]);
+        // Scaled by the parent before it is moved
+        assert_eq!(world[1].unwrap().m[1], [0.0, 2.0, 0.0, 0.0]);
+        assert_eq!(world[1].unwrap().m[3], [1.0, 2.0, 0.0, 1.0]);
+        // Node 2 isn't drawn
+        assert!(world[2].is_none());
+    }
+
+    #[test]
+    fn invalid_hierarchies_are_errors() {
+        assert_eq!(scene(&[&[1], &[2], &[]], &[0]).validate(), Ok(()));
+        assert_eq!(
+            scene(&[&[3], &[], &[]], &[0]).validate(),
+            Err("Node 0 uses child 3, but there are 3".to_string())
+        );
+        assert_eq!(
+            scene(&[&[2], &[2], &[]], &[0, 1]).validate(),
+            Err("Node 2 is a child of both node 0 and node 1".to_string())
+        );
+        assert_eq!(
+            scene(&[&[1], &[]], &[2]).validate(),
+            Err("The root 2 isn't a node".to_string())
+        );
+        assert_eq!(
+            scene(&[&[1], &[]], &[1]).validate(),
+            Err("The root 1 is a child of node 0".to_string())
+        );
+        assert_eq!(
+            scene(&[&[], &[2], &[1]], &[0]).validate(),
+            Err("The parents of node 1 form a cycle".to_string())
+        );
+
+        let mut scene: Scene = scene(&[&[]], &[0]);
+        scene.nodes[0].light = Some(0);
+        assert_eq!(
+            scene.validate(),
+            Err("Node 0 uses light 0, but there are 0".to_string())
+        );
+    }
+
+    #[test]
+    fn obj_files_become_a_scene_with_one_node() {
+        let path: std::path::PathBuf =
+            Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/data/mesh/shapes.obj");
+        let scene: Scene = Scene::load(&path).unwrap();
+        assert_eq!(scene.meshes, vec![Mesh::load(&path).unwrap()]);
+        assert_eq!(scene.roots, vec![0]);
+        assert_eq!(scene.nodes[0].mesh, Some(0));
+        assert_eq!(scene.validate(), Ok(()));
+        assert!(Scene::load(&path.with_extension("gltf")).is_err());
+    }
+}
@@ -0,0 +1,452 @@
+use std::{
+    borrow::Cow,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use super::backend::{ShaderCode, ShaderDesc, ShaderStage};
+use crate::log_warn;
+
+/// Folders that are searched for HLSL files before the packs and embedded shaders, separated like `PATH`
+pub const SEARCH_PATH_ENV: &str = "SHADER_PATH";
+/// Asset packs with shaders, separated like `PATH`. See [`ShaderSet::read_pack()`].
+pub const PACK_ENV: &str = "SHADER_PACK";
+
+/// Start of every asset pack
+const PACK_MAGIC: &[u8; 8] = b"SHADERPK";
+const PACK_VERSION: u32 = 1;
+const HAS_SOURCE: u8 = 1;
+const HAS_BYTECODE: u8 = 2;
+
+/// A shader that is built into the program or read from an asset pack, by the name of its file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShaderAsset {
+    /// The file name relative to the shader folder, like `VertexShader.hlsl`
+    pub name: Cow<'static, str>,
+    pub source: Option<Cow<'static, str>>,
+    /// The profile the bytecode was compiled with
+    pub profile: Cow<'static, str>,
+    /// Compiled from the `main` entry point with [`ShaderAsset::profile`] and without defines
+    pub bytecode: Option<Cow<'static, [u8]>>,
+}
+
+impl ShaderAsset {
+    /// The code to create `desc` from. The bytecode is only used when it was compiled the way `desc`
+    /// asks for, otherwise the source is compiled.
+    pub fn code(&self, desc: &ShaderDesc) -> Option<ShaderCode> {
+        let precompiled: bool =
+            desc.entry_point == "main" && desc.profile == self.profile && desc.defines.is_empty();
+        return match (&self.bytecode, &self.source) {
+            (Some(bytecode), _) if precompiled => Some(ShaderCode::Bytecode(bytecode.clone())),
+            (_, Some(source)) => Some(ShaderCode::Source(source.clone())),
+            _ => None,
+        };
+    }
+}
+
+/// A set of shaders by name, like the shaders that are embedded in the program or an asset pack
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ShaderSet {
+    pub assets: Vec<ShaderAsset>,
+}
+
+impl ShaderSet {
+    /// The shaders of `src/window/graphics/shaders`, embedded by the build script. Their bytecode is
+    /// there when the build could compile them with `fxc`.
+    pub fn embedded() -> ShaderSet {
+        return ShaderSet {
+            assets: include!(concat!(env!("OUT_DIR"), "/embedded_shaders.rs")),
+        };
+    }
+
+    pub fn get(&self, name: &str) -> Option<&ShaderAsset> {
+        let name: String = normalize_name(name);
+        return self.assets.iter().find(|asset| asset.name == name);
+    }
+
+    /// Add a shader, it replaces the one with the same name
+    pub fn add(&mut self, asset: ShaderAsset) {
+        self.assets.retain(|existing| existing.name != asset.name);
+        self.assets.push(asset);
+    }
+
+    /// Add HLSL source, like `include_str!("Shader.hlsl")`
+    pub fn add_source(&mut self, name: &str, source: &'static str) {
+        self.add(ShaderAsset {
+            name: Cow::Owned(normalize_name(name)),
+            source: Some(Cow::Borrowed(source)),
+            profile: Cow::Borrowed(""),
+            bytecode: None,
+        });
+    }
+
+    /// Add bytecode that was compiled with `profile`, like `include_bytes!("Shader.cso")`
+    pub fn add_bytecode(&mut self, name: &str, profile: &str, bytecode: &'static [u8]) {
+        self.add(ShaderAsset {
+            name: Cow::Owned(normalize_name(name)),
+            source: None,
+            profile: Cow::Owned(profile.to_string()),
+            bytecode: Some(Cow::Borrowed(bytecode)),
+        });
+    }
+
+    pub fn read_pack(path: &Path) -> Result<ShaderSet, String> {
+        let bytes: Vec<u8> =
+            fs::read(path).map_err(|e| format!("Unable to read {}: {}", path.display(), e))?;
+        return ShaderSet::parse_pack(&bytes).map_err(|e| format!("{}: {}", path.display(), e));
+    }
+
+    pub fn write_pack(&self, path: &Path) -> Result<(), String> {
+        return fs::write(path, self.to_pack())
+            .map_err(|e| format!("Unable to write {}: {}", path.display(), e));
+    }
+
+    /// Read an asset pack: the magic, the version and the amount of shaders, then per shader the name, the
+    /// profile, which parts it has, the source and the bytecode. Numbers are little endian `u32`s and
+    /// strings and bytes start with their length.
+    pub fn parse_pack(bytes: &[u8]) -> Result<ShaderSet, String> {
+        let mut reader: PackReader = PackReader { bytes, offset: 0 };
+        if reader.take(PACK_MAGIC.len())? != PACK_MAGIC {
+            return Err("This is not a shader pack".to_string());
+        }
+        let version: u32 = reader.u32()?;
+        if version != PACK_VERSION {
+            return Err(format!("Shader packs of version {} aren't supported", version));
+        }
+
+        let mut set: ShaderSet = ShaderSet::default();
+        for _ in 0..reader.u32()? {
+            let name: String = reader.string()?;
+            let profile: String = reader.string()?;
+            let parts: u8 = reader.take(1)?[0];
+            let source: Option<String> = match parts & HAS_SOURCE {
+                0 => None,
+                _ => Some(reader.string()?),
+            };
+            let bytecode: Option<Vec<u8>> = match parts & HAS_BYTECODE {
+                0 => None,
+                _ => Some(reader.bytes()?.to_vec()),
+            };
+            set.add(ShaderAsset {
+                name: Cow::Owned(normalize_name(&name)),
+                source: source.map(Cow::Owned),
+                profile: Cow::Owned(profile),
+                bytecode: bytecode.map(Cow::Owned),
+            });
+        }
+        if reader.offset != bytes.len() {
+            return Err(format!(
+                "There are {} bytes after the last shader",
+                bytes.len() - reader.offset
+            ));
+        }
+        return Ok(set);
+    }
+
+    /// The asset pack with the shaders of the set, see [`ShaderSet::parse_pack()`]
+    pub fn to_pack(&self) -> Vec<u8> {
+        let mut pack: Vec<u8> = PACK_MAGIC.to_vec();
+        pack.extend(PACK_VERSION.to_le_bytes());
+        pack.extend((self.assets.len() as u32).to_le_bytes());
+        for asset in &self.assets {
+            write_bytes(&mut pack, asset.name.as_bytes());
+            write_bytes(&mut pack, asset.profile.as_bytes());
+            let mut parts: u8 = 0;
+            if asset.source.is_some() {
+                parts |= HAS_SOURCE;
+            }
+            if asset.bytecode.is_some() {
+                parts |= HAS_BYTECODE;
+            }
+            pack.push(parts);
+            if let Some(source) = &asset.source {
+                write_bytes(&mut pack, source.as_bytes());
+            }
+            if let Some(bytecode) = &asset.bytecode {
+                write_bytes(&mut pack, bytecode);
+            }
+        }
+        return pack;
+    }
+}
+
+struct PackReader<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> PackReader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], String> {
+        let taken: &[u8] = self
+            .bytes
+            .get(self.offset..self.offset.saturating_add(len))
+            .ok_or_else(|| format!("The pack ends early at byte {}", self.bytes.len()))?;
+        self.offset += len;
+        return Ok(taken);
+    }
+
+    fn u32(&mut self) -> Result<u32, String> {
+        let bytes: &[u8] = self.take(4)?;
+        return Ok(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]));
+    }
+
+    fn bytes(&mut self) -> Result<&'a [u8], String> {
+        let len: u32 = self.u32()?;
+        return self.take(len as usize);
+    }
+
+    fn string(&mut self) -> Result<String, String> {
+        let offset: usize = self.offset;
+        return String::from_utf8(self.bytes()?.to_vec())
+            .map_err(|_| format!("The text at byte {} isn't UTF-8", offset));
+    }
+}
+
+fn write_bytes(pack: &mut Vec<u8>, bytes: &[u8]) {
+    pack.extend((bytes.len() as u32).to_le_bytes());
+    pack.extend(bytes);
+}
+
+/// Names use `/` between folders, whatever the platform is
+fn normalize_name(name: &str) -> String {
+    return name.replace('\\', "/").trim_start_matches("./").to_string();
+}
+
+/// Finds the code of shaders by the name of their file. The folders of the search path come first, so a
+/// file there replaces a shipped one and can be reloaded while it is edited. Then the asset packs are
+/// tried in order and the shaders that are embedded in the program last, so the program doesn't depend
+/// on where it is started from.
+#[derive(Debug, Clone)]
+pub struct ShaderLocator {
+    pub search_path: Vec<PathBuf>,
+    pub packs: Vec<ShaderSet>,
+    pub embedded: ShaderSet,
+}
+
+impl ShaderLocator {
+    /// A locator that only has the shaders of `embedded`
+    pub fn new(embedded: ShaderSet) -> ShaderLocator {
+        return ShaderLocator {
+            search_path: vec![],
+            packs: vec![],
+            embedded,
+        };
+    }
+
+    /// The folders of [`SEARCH_PATH_ENV`], the `shaders` folder next to the executable and in debug builds
+    /// the shader folder of the sources, then the packs of [`PACK_ENV`] and the embedded shaders. Packs
+    /// that can't be read are skipped with a warning.
+    pub fn from_env() -> ShaderLocator {
+        let mut locator: ShaderLocator = ShaderLocator::new(ShaderSet::embedded());
+        if let Some(paths) = std::env::var_os(SEARCH_PATH_ENV) {
+            locator.search_path.extend(std::env::split_paths(&paths));
+        }
+        if let Some(dir) = std::env::current_exe().ok().as_deref().and_then(Path::parent) {
+            locator.search_path.push(dir.join("shaders"));
+        }
+        if cfg!(debug_assertions) {
+            locator
+                .search_path
+                .push(Path::new(env!("CARGO_MANIFEST_DIR")).join("src/window/graphics/shaders"));
+        }
+
+        if let Some(paths) = std::env::var_os(PACK_ENV) {
+            for path in std::env::split_paths(&paths) {
+                match ShaderSet::read_pack(&path) {
+                    Ok(pack) => locator.packs.push(pack),
+                    Err(e) => log_warn!("Skipping shader pack: {}", e),
+                }
+            }
+        }
+        return locator;
+    }
+
+    /// The `main` function of the shader `name` with the default profile of `stage`
+    pub fn shader(&self, stage: ShaderStage, name: &str) -> Result<ShaderDesc, String> {
+        return self.locate(ShaderDesc::new(stage, PathBuf::from(name)));
+    }
+
+    /// Find the shader that the path of `desc` names. A file of the search path gets its full path,
+    /// others keep the name and get their code.
+    pub fn locate(&self, mut desc: ShaderDesc) -> Result<ShaderDesc, String> {
+        let name: String = normalize_name(&desc.path.to_string_lossy());
+        if desc.path.is_absolute() {
+            return match desc.path.is_file() {
+                true => Ok(desc),
+                false => Err(format!("There is no shader {}", desc.path.display())),
+            };
+        }
+
+        if let Some(path) = self.find_file(&name) {
+            desc.path = path;
+            desc.code = ShaderCode::File;
+            return Ok(desc);
+        }
+
+        let asset_code: Option<ShaderCode> = self
+            .packs
+            .iter()
+            .chain([&self.embedded])
+            .filter_map(|set| set.get(&name))
+            .find_map(|asset| asset.code(&desc));
+        return match asset_code {
+            Some(code) => {
+                desc.path = PathBuf::from(name);
+                desc.code = code;
+                Ok(desc)
+            }
+            None => Err(format!(
+                "There is no shader {} in {}, {} shader packs or the embedded shaders",
+                name,
+                match self.search_path.is_empty() {
+                    true => "an empty search path".to_string(),
+                    false => self
+                        .search_path
+                        .iter()
+                        .map(|dir| dir.display().to_string())
+                        .collect::<Vec<String>>()
+                        .join(", "),
+                },
+                self.packs.len()
+            )),
+        };
+    }
+
+    /// The first file of the search path with the name
+    pub fn find_file(&self, name: &str) -> Option<PathBuf> {
+        return self
+            .search_path
+            .iter()
+            .map(|dir| dir.join(name))
+            .find(|path| path.is_file());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PACKED_SOURCE: &str = "float4 main() : SV_Target { return 1; }";
+    const EMBEDDED_SOURCE: &str = "float4 main() : SV_Target { return 0; }";
+    const BYTECODE: &[u8] = b"DXBC";
+
+    fn pack(source: &'static str) -> ShaderSet {
+        let mut set: ShaderSet = ShaderSet::default();
+        set.add_source("Cube_ps.hlsl", source);
+        return set;
+    }
+
+    fn embedded() -> ShaderSet {
+        let mut set: ShaderSet = ShaderSet::default();
+        set.add(ShaderAsset {
+            name: Cow::Borrowed("Cube_ps.hlsl"),
+            source: Some(Cow::Borrowed(EMBEDDED_SOURCE)),
+            profile: Cow::Borrowed("ps_5_0"),
+            bytecode: Some(Cow::Borrowed(BYTECODE)),
+        });
+        return set;
+    }
+
+    #[test]
+    fn the_search_path_comes_before_packs_and_embedded_shaders() {
+        let dir: PathBuf =
+            std::env::temp_dir().join(format!("shaderlocator_{}", std::process::id()));
+        let first: PathBuf = dir.join("first");
+        let second: PathBuf = dir.join("second");
+        fs::create_dir_all(&first).unwrap();
+        fs::create_dir_all(&second).unwrap();
+        fs::write(second.join("Cube_ps.hlsl"), "").unwrap();
+
+        let mut locator: ShaderLocator = ShaderLocator::new(embedded());
+        locator.search_path = vec![first.clone(), second.clone()];
+        locator.packs = vec![pack(PACKED_SOURCE)];
+        let found: ShaderDesc = locator.shader(ShaderStage::Pixel, "Cube_ps.hlsl").unwrap();
+        assert_eq!(found.path, second.join("Cube_ps.hlsl"));
+        assert_eq!(found.code, ShaderCode::File);
+
+        // The first folder with the file wins
+        fs::write(first.join("Cube_ps.hlsl"), "").unwrap();
+        let found: ShaderDesc = locator
+            .shader(ShaderStage::Pixel, "./Cube_ps.hlsl")
+            .unwrap();
+        assert_eq!(found.path, first.join("Cube_ps.hlsl"));
+
+        let absolute: ShaderDesc = ShaderDesc::new(ShaderStage::Pixel, dir.join("Missing.hlsl"));
+        assert!(locator.locate(absolute).is_err());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn packs_come_in_order_before_the_embedded_shaders() {
+        let mut locator: ShaderLocator = ShaderLocator::new(embedded());
+        locator.packs = vec![ShaderSet::default(), pack(PACKED_SOURCE), pack("")];
+        let found: ShaderDesc = locator.shader(ShaderStage::Pixel, "Cube_ps.hlsl").unwrap();
+        assert_eq!(found.path, PathBuf::from("Cube_ps.hlsl"));
+        assert_eq!(found.code, ShaderCode::Source(Cow::Borrowed(PACKED_SOURCE)));
+
+        locator.packs.clear();
+        let found: ShaderDesc = locator.shader(ShaderStage::Pixel, "Cube_ps.hlsl").unwrap();
+        assert_eq!(found.code, ShaderCode::Bytecode(Cow::Borrowed(BYTECODE)));
+
+        let error: String = locator
+            .shader(ShaderStage::Pixel, "Sky_ps.hlsl")
+            .unwrap_err();
+        assert!(error.contains("Sky_ps.hlsl"));
+        assert!(error.contains("an empty search path"));
+    }
+
+    #[test]
+    fn bytecode_is_only_used_when_it_was_compiled_the_same_way() {
+        let locator: ShaderLocator = ShaderLocator::new(embedded());
+        let desc: ShaderDesc = ShaderDesc::new(ShaderStage::Pixel, PathBuf::from("Cube_ps.hlsl"));
+        let source: ShaderCode = ShaderCode::Source(Cow::Borrowed(EMBEDDED_SOURCE));
+        assert_eq!(
+            locator.locate(desc.clone()).unwrap().code,
+            ShaderCode::Bytecode(Cow::Borrowed(BYTECODE))
+        );
+
+        let mut other: ShaderDesc = desc.clone();
+        other.entry_point = "shadow".to_string();
+        assert_eq!(locator.locate(other).unwrap().code, source);
+        let mut other: ShaderDesc = desc.clone();
+        other.profile = "ps_4_0".to_string();
+        assert_eq!(locator.locate(other).unwrap().code, source);
+        let mut other: ShaderDesc = desc.clone();
+        other.defines.push(("SHADOWS".to_string(), "1".to_string()));
+        assert_eq!(locator.locate(other).unwrap().code, source);
+
+        // Without source there is nothing to compile
+        let mut set: ShaderSet = ShaderSet::default();
+        set.add_bytecode("Cube_ps.hlsl", "ps_5_0", BYTECODE);
+        let mut other: ShaderDesc = desc.clone();
+        other.profile = "ps_4_0".to_string();
+        assert!(ShaderLocator::new(set).locate(other).is_err());
+    }
+
+    #[test]
+    fn packs_are_read_back_as_they_were_written() {
+        let mut set: ShaderSet = embedded();
+        set.add_source("lights\\Common.hlsli", PACKED_SOURCE);
+        let bytes: Vec<u8> = set.to_pack();
+        let read: ShaderSet = ShaderSet::parse_pack(&bytes).unwrap();
+        assert_eq!(read, set);
+        assert!(read.get("lights/Common.hlsli").is_some());
+
+        assert!(ShaderSet::parse_pack(&bytes[..bytes.len() - 1]).is_err());
+        assert!(ShaderSet::parse_pack(&[bytes.as_slice(), &[0]].concat()).is_err());
+        assert!(ShaderSet::parse_pack(b"SHADERPK\x02\x00\x00\x00").is_err());
+        assert!(ShaderSet::parse_pack(b"NOTAPACK").is_err());
+    }
+
+    #[test]
+    fn the_embedded_shaders_are_there() {
+        let embedded: ShaderSet = ShaderSet::embedded();
+        for name in ["VertexShader.hlsl", "PixelShader.hlsl"] {
+            let asset: &ShaderAsset = embedded.get(name).unwrap();
+            assert!(asset
+                .source
+                .as_deref()
+                .is_some_and(|source| source.contains("main")));
+        }
+    }
+}
@@ -0,0 +1,362 @@
+use std::str::FromStr;
+
+use windows::Win32::Foundation::POINTS;
+
+use super::{
+    errors::crash_report::{self, InputEvent},
+    graphics::{
+        backend::software::SoftwareBackend,
+        null::NullGraphics,
+        shaderlocator::{ShaderLocator, ShaderSet},
+        Renderer,
+    },
+    keyboard::Keyboard,
+    mouse::Mouse,
+    Host,
+};
+
+/// Input that is fed to a [`HeadlessWindow`] instead of the window messages a real window would receive.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum SyntheticInput {
+    KeyDown(u32),
+    KeyUp(u32),
+    Char(char),
+    MouseMove(i16, i16),
+    LeftDown,
+    LeftUp,
+    RightDown,
+    RightUp,
+    WheelDown,
+    WheelUp,
+    /// Scroll the wheel with a delta, like the high word of the wParam of `WM_MOUSEWHEEL`
+    Wheel(i16),
+    /// Stop the frame loop with an exit code, like a `WM_QUIT` message would
+    Quit(usize),
+}
+
+impl SyntheticInput {
+    /// Parse a comma separated list of input, like `0:char=h,1:key-down=13,5:quit=3`. See
+    /// [`SyntheticInput::parse()`].
+    pub fn parse_list(spec: &str) -> Result<Vec<(u32, SyntheticInput)>, String> {
+        return spec
+            .split(',')
+            .map(str::trim)
+            .filter(|input| !input.is_empty())
+            .map(SyntheticInput::parse)
+            .collect();
+    }
+
+    /// Parse the frame and the input that is handled at its start, like `1:key-down=13`. The inputs are
+    /// named like the variants in kebab case and their values follow a `=`, a mouse move is
+    /// `mouse-move=<x>x<y>`.
+    pub fn parse(spec: &str) -> Result<(u32, SyntheticInput), String> {
+        let (frame, input) = spec
+            .split_once(':')
+            .ok_or_else(|| format!("Expected <frame>:<input>, got {}", spec))?;
+        let frame: u32 = frame
+            .parse()
+            .map_err(|_| format!("{} isn't a frame", frame))?;
+        let (name, value) = match input.split_once('=') {
+            Some((name, value)) => (name, Some(value)),
+            None => (input, None),
+        };
+
+        let input: SyntheticInput = match (name, value) {
+            ("key-down", Some(value)) => {
+                SyntheticInput::KeyDown(parse_value::<u8>(name, value)? as u32)
+            }
+            ("key-up", Some(value)) => {
+                SyntheticInput::KeyUp(parse_value::<u8>(name, value)? as u32)
+            }
+            ("char", Some(value)) => SyntheticInput::Char(parse_value(name, value)?),
+            ("mouse-move", Some(value)) => {
+                let (x, y) = value
+                    .split_once('x')
+                    .ok_or_else(|| format!("mouse-move expects <x>x<y>, got {}", value))?;
+                SyntheticInput::MouseMove(parse_value(name, x)?, parse_value(name, y)?)
+            }
+            ("left-down", None) => SyntheticInput::LeftDown,
+            ("left-up", None) => SyntheticInput::LeftUp,
+            ("right-down", None) => SyntheticInput::RightDown,
+            ("right-up", None) => SyntheticInput::RightUp,
+            ("wheel-down", None) => SyntheticInput::WheelDown,
+            ("wheel-up", None) => SyntheticInput::WheelUp,
+            ("wheel", Some(value)) => SyntheticInput::Wheel(parse_value(name, value)?),
+            ("quit", Some(value)) => SyntheticInput::Quit(parse_value(name, value)?),
+            _ => return Err(format!("Unknown input {}", input)),
+        };
+        return Ok((frame, input));
+    }
+}
+
+fn parse_value<T: FromStr>(name: &str, value: &str) -> Result<T, String> {
+    return value
+        .parse()
+        .map_err(|_| format!("{} isn't a valid value for {}", value, name));
+}
+
+/// What draws the frames of a [`HeadlessWindow`]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum HeadlessRenderer {
+    /// [`NullGraphics`]: nothing is drawn, the draw calls are only counted
+    #[default]
+    Null,
+    /// [`SoftwareBackend`]: the frames are rendered on the CPU
+    Software,
+}
+
+impl HeadlessRenderer {
+    pub fn parse(value: &str) -> Result<HeadlessRenderer, String> {
+        return match value {
+            "null" => Ok(HeadlessRenderer::Null),
+            "software" => Ok(HeadlessRenderer::Software),
+            _ => Err(format!(
+                "Unknown renderer {}, expected null or software",
+                value
+            )),
+        };
+    }
+}
+
+/// Settings for running the app without a window.
+#[derive(Debug, Clone)]
+pub struct HeadlessConfig {
+    pub width: i16,
+    pub height: i16,
+    /// The frame loop stops with exit code 0 after this amount of frames
+    pub frames: u32,
+    /// Time that passes every frame in seconds. Every run of the app gets the same frames this way.
+    pub timestep: f32,
+    /// The input that is handled at the start of a frame, as `(frame, input)`
+    pub input: Vec<(u32, SyntheticInput)>,
+    pub renderer: HeadlessRenderer,
+    /// Where the renderer finds the shaders, only the embedded ones unless it is set
+    pub shaders: ShaderLocator,
+}
+
+impl HeadlessConfig {
+    pub fn new(width: i16, height: i16, frames: u32) -> HeadlessConfig {
+        return HeadlessConfig {
+            width,
+            height,
+            frames,
+            timestep: 1.0 / 60.0,
+            input: vec![],
+            renderer: HeadlessRenderer::Null,
+            shaders: ShaderLocator::new(ShaderSet::embedded()),
+        };
+    }
+
+    /// Add input that is handled at the start of `frame`
+    pub fn with_input(mut self, frame: u32, input: SyntheticInput) -> HeadlessConfig {
+        self.input.push((frame, input));
+        return self;
+    }
+}
+
+/// A window that doesn't exist. It runs a fixed amount of frames with synthetic input and a renderer that
+/// doesn't need a GPU (see [`HeadlessRenderer`]), so the app logic and the frame loop can run without a
+/// desktop or GPU (like in CI).
+pub struct HeadlessWindow {
+    pub config: HeadlessConfig,
+    pub keyboard: Keyboard,
+    pub mouse: Mouse,
+    pub graphics: Box<dyn Renderer>,
+    /// The frame that is currently being handled
+    pub frame: u32,
+}
+
+impl HeadlessWindow {
+    pub fn new(config: HeadlessConfig) -> HeadlessWindow {
+        let mut keyboard: Keyboard = Keyboard {
+            key_states: vec![],
+            key_queue: vec![],
+            char_queue: vec![],
+            auto_repeat_enabled: false,
+        };
+        keyboard.reset();
+
+        let mouse: Mouse = Mouse {
+            x: 0,
+            y: 0,
+            event_queue: vec![],
+            left_pressed: false,
+            right_pressed: false,
+            is_in_window: false,
+            wheel_pressed: false,
+            wheel_delta_carry: 0,
+        };
+
+        let graphics: Box<dyn Renderer> = match config.renderer {
+            HeadlessRenderer::Null => Box::new(NullGraphics::default()),
+            HeadlessRenderer::Software => Box::new(SoftwareBackend::new(
+                config.width as u32,
+                config.height as u32,
+                config.shaders.clone(),
+            )),
+        };
+
+        return HeadlessWindow {
+            config,
+            keyboard,
+            mouse,
+            graphics,
+            frame: 0,
+        };
+    }
+
+    /// Handle a single input event the same way [`crate::window::Window::wndproc()`] handles the window messages.
+    /// Returns the exit code if the input was [`SyntheticInput::Quit`].
+    fn handle_input(&mut self, input: SyntheticInput) -> Option<usize> {
+        crash_report::record_input(InputEvent::Synthetic {
+            frame: self.frame,
+            input,
+        });
+
+        match input {
+            SyntheticInput::KeyDown(key_code) => self.keyboard.on_key_press(key_code),
+            SyntheticInput::KeyUp(key_code) => self.keyboard.on_key_release(key_code),
+            SyntheticInput::Char(ch) => self.keyboard.on_char(ch as u32),
+            SyntheticInput::MouseMove(x, y) => {
+                if !self.mouse.is_in_window {
+                    self.mouse.on_mouse_enter();
+                }
+                self.mouse.on_mouse_move(POINTS { x, y });
+            }
+            SyntheticInput::LeftDown => self.mouse.on_left_press(),
+            SyntheticInput::LeftUp => self.mouse.on_left_release(),
+            SyntheticInput::RightDown => self.mouse.on_right_press(),
+            SyntheticInput::RightUp => self.mouse.on_right_release(),
+            SyntheticInput::WheelDown => self.mouse.on_wheel_press(),
+            SyntheticInput::WheelUp => self.mouse.on_wheel_release(),
+            SyntheticInput::Wheel(delta) => {
+                let (x, y) = (self.mouse.x, self.mouse.y);
+                self.mouse.on_wheel_delta(x, y, delta);
+            }
+            SyntheticInput::Quit(exit_code) => return Some(exit_code),
+        }
+        return None;
+    }
+}
+
+impl Host for HeadlessWindow {
+    fn width(&self) -> i16 {
+        return self.config.width;
+    }
+
+    fn height(&self) -> i16 {
+        return self.config.height;
+    }
+
+    fn keyboard(&mut self) -> &mut Keyboard {
+        return &mut self.keyboard;
+    }
+
+    fn mouse(&mut self) -> &mut Mouse {
+        return &mut self.mouse;
+    }
+
+    fn graphics(&mut self) -> &mut dyn Renderer {
+        return self.graphics.as_mut();
+    }
+
+    fn handle_messages(&mut self) -> Option<usize> {
+        if self.frame >= self.config.frames {
+            return Some(0);
+        }
+
+        let frame: u32 = self.frame;
+        let input: Vec<SyntheticInput> = self
+            .config
+            .input
+            .iter()
+            .filter(|(input_frame, _)| *input_frame == frame)
+            .map(|(_, input)| *input)
+            .collect();
+
+        for input in input {
+            if let Some(exit_code) = self.handle_input(input) {
+                return Some(exit_code);
+            }
+        }
+
+        self.frame += 1;
+        return None;
+    }
+
+    fn fixed_timestep(&self) -> Option<f32> {
+        return Some(self.config.timestep);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn input_is_parsed_with_its_frame() {
+        assert_eq!(
+            SyntheticInput::parse_list("0:char=h, 1:key-down=13,1:key-up=13,,2:mouse-move=-5x300"),
+            Ok(vec![
+                (0, SyntheticInput::Char('h')),
+                (1, SyntheticInput::KeyDown(13)),
+                (1, SyntheticInput::KeyUp(13)),
+                (2, SyntheticInput::MouseMove(-5, 300)),
+            ])
+        );
+        assert_eq!(
+            SyntheticInput::parse("3:left-down"),
+            Ok((3, SyntheticInput::LeftDown))
+        );
+        assert_eq!(
+            SyntheticInput::parse("3:wheel=-120"),
+            Ok((3, SyntheticInput::Wheel(-120)))
+        );
+        assert_eq!(
+            SyntheticInput::parse("9:quit=4"),
+            Ok((9, SyntheticInput::Quit(4)))
+        );
+
+        // There are 256 virtual keys
+        assert_eq!(
+            SyntheticInput::parse("0:key-down=256"),
+            Err("256 isn't a valid value for key-down".to_string())
+        );
+        assert_eq!(
+            SyntheticInput::parse("0:char=ab"),
+            Err("ab isn't a valid value for char".to_string())
+        );
+        assert_eq!(
+            SyntheticInput::parse("0:left-down=1"),
+            Err("Unknown input left-down=1".to_string())
+        );
+        assert_eq!(
+            SyntheticInput::parse("quit=1"),
+            Err("Expected <frame>:<input>, got quit=1".to_string())
+        );
+        assert!(SyntheticInput::parse("-1:quit=1").is_err());
+        assert!(SyntheticInput::parse("0:mouse-move=10").is_err());
+    }
+
+    #[test]
+    fn mouse_input_moves_the_mouse_in_the_window() {
+        let config: HeadlessConfig = HeadlessConfig::new(64, 48, 2)
+            .with_input(0, SyntheticInput::MouseMove(10, 20))
+            .with_input(0, SyntheticInput::LeftDown)
+            .with_input(1, SyntheticInput::LeftUp);
+        let mut window: HeadlessWindow = HeadlessWindow::new(config);
+
+        assert_eq!(window.handle_messages(), None);
+        assert!(window.mouse.is_in_window);
+        assert_eq!(
+            (window.mouse.get_pos().x, window.mouse.get_pos().y),
+            (10, 20)
+        );
+        assert!(window.mouse.left_pressed);
+        assert_eq!(window.handle_messages(), None);
+        assert!(!window.mouse.left_pressed);
+        // The frames are done
+        assert_eq!(window.handle_messages(), Some(0));
+        assert_eq!(window.frame, 2);
+    }
+}
@@ -1,334 +1,1324 @@
+use std::{
+    fmt,
+    fs::File,
+    io::{BufWriter, Write},
+    path::Path,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use windows::Win32::Foundation::{LPARAM, WPARAM};
+
+use super::{get_wheel_delta_wparam, make_points};
+
+/// First message id that window classes can use for their own private messages.
+pub const WM_USER: u32 = 0x0400;
+/// First message id that applications can use for their own private messages.
+pub const WM_APP: u32 = 0x8000;
+/// Messages registered with `RegisterWindowMessage` start at this id.
+const WM_REGISTERED: u32 = 0xC000;
+
+const MESSAGE_COUNT: usize = 303;
+
 /// See this [list](https://wiki.winehq.org/List_Of_Windows_Messages)
-/// for all the window messages. All of the windowMessages are in this function as well.<br>
-/// This is just a function to see what messages you are receiving.
-pub fn _id_to_name(id: u32) -> String {
-    let map: std::collections::HashMap<u32, &str> = std::collections::hash_map::HashMap::from([
-        (0, "WM_NUL"),
-        (1, "WM_CREATE"),
-        (2, "WM_DESTROY"),
-        (3, "WM_MOVE"),
-        (5, "WM_SIZE"),
-        (6, "WM_ACTIVATE"),
-        (7, "WM_SETFOCUS"),
-        (8, "WM_KILLFOCUS"),
-        (10, "WM_ENABLE"),
-        (11, "WM_SETREDRAW"),
-        (12, "WM_SETTEXT"),
-        (13, "WM_GETTEXT"),
-        (14, "WM_GETTEXTLENGTH"),
-        (15, "WM_PAINT"),
-        (16, "WM_CLOSE"),
-        (17, "WM_QUERYENDSESSION"),
-        (18, "WM_QUIT"),
-        (19, "WM_QUERYOPEN"),
-        (20, "WM_ERASEBKGND"),
-        (21, "WM_SYSCOLORCHANGE"),
-        (22, "WM_ENDSESSION"),
-        (24, "WM_SHOWWINDOW"),
-        (25, "WM_CTLCOLOR"),
-        (26, "WM_WININICHANGE"),
-        (27, "WM_DEVMODECHANGE"),
-        (28, "WM_ACTIVATEAPP"),
-        (29, "WM_FONTCHANGE"),
-        (30, "WM_TIMECHANGE"),
-        (31, "WM_CANCELMODE"),
-        (32, "WM_SETCURSOR"),
-        (33, "WM_MOUSEACTIVATE"),
-        (34, "WM_CHILDACTIVATE"),
-        (35, "WM_QUEUESYNC"),
-        (36, "WM_GETMINMAXINFO"),
-        (38, "WM_PAINTICON"),
-        (39, "WM_ICONERASEBKGND"),
-        (40, "WM_NEXTDLGCT"),
-        (42, "WM_SPOOLERSTATUS"),
-        (43, "WM_DRAWITEM"),
-        (44, "WM_MEASUREITEM"),
-        (45, "WM_DELETEITEM"),
-        (46, "WM_VKEYTOITEM"),
-        (47, "WM_CHARTOITEM"),
-        (48, "WM_SETFONT"),
-        (49, "WM_GETFONT"),
-        (50, "WM_SETHOTKEY"),
-        (51, "WM_GETHOTKEY"),
-        (55, "WM_QUERYDRAGICON"),
-        (57, "WM_COMPAREITEM"),
-        (61, "WM_GETOBJECT"),
-        (65, "WM_COMPACTING"),
-        (68, "WM_COMMNOTIFY"),
-        (70, "WM_WINDOWPOSCHANGING"),
-        (71, "WM_WINDOWPOSCHANGED"),
-        (72, "WM_POWER"),
-        (73, "WM_COPYGLOBALDATA"),
-        (74, "WM_COPYDATA"),
-        (75, "WM_CANCELJOURNA"),
-        (78, "WM_NOTIFY"),
-        (80, "WM_INPUTLANGCHANGEREQUEST"),
-        (81, "WM_INPUTLANGCHANGE"),
-        (82, "WM_TCARD"),
-        (83, "WM_HELP"),
-        (84, "WM_USERCHANGED"),
-        (85, "WM_NOTIFYFORMAT"),
-        (96, "WM_MSG_CREATE"),
-        (123, "WM_CONTEXTMENU"),
-        (124, "WM_STYLECHANGING"),
-        (125, "WM_STYLECHANGED"),
-        (126, "WM_DISPLAYCHANGE"),
-        (127, "WM_GETICON"),
-        (128, "WM_SETICON"),
-        (129, "WM_NCCREATE"),
-        (130, "WM_NCDESTROY"),
-        (131, "WM_NCCALCSIZE"),
-        (132, "WM_NCHITTEST"),
-        (133, "WM_NCPAINT"),
-        (134, "WM_NCACTIVATE"),
-        (135, "WM_GETDLGCODE"),
-        (136, "WM_SYNCPAINT"),
-        (160, "WM_NCMOUSEMOVE"),
-        (161, "WM_NCLBUTTONDOWN"),
-        (162, "WM_NCLBUTTONUP"),
-        (163, "WM_NCLBUTTONDBLCLK"),
-        (164, "WM_NCRBUTTONDOWN"),
-        (165, "WM_NCRBUTTONUP"),
-        (166, "WM_NCRBUTTONDBLCLK"),
-        (167, "WM_NCMBUTTONDOWN"),
-        (168, "WM_NCMBUTTONUP"),
-        (169, "WM_NCMBUTTONDBLCLK"),
-        (171, "WM_NCXBUTTONDOWN"),
-        (172, "WM_NCXBUTTONUP"),
-        (173, "WM_NCXBUTTONDBLCLK"),
-        (176, "EM_GETSE"),
-        (177, "EM_SETSE"),
-        (178, "EM_GETRECT"),
-        (179, "EM_SETRECT"),
-        (180, "EM_SETRECTNP"),
-        (181, "EM_SCROL"),
-        (182, "EM_LINESCROL"),
-        (183, "EM_SCROLLCARET"),
-        (185, "EM_GETMODIFY"),
-        (187, "EM_SETMODIFY"),
-        (188, "EM_GETLINECOUNT"),
-        (189, "EM_LINEINDEX"),
-        (190, "EM_SETHANDLE"),
-        (191, "EM_GETHANDLE"),
-        (192, "EM_GETTHUMB"),
-        (193, "EM_LINELENGTH"),
-        (194, "EM_REPLACESE"),
-        (195, "EM_SETFONT"),
-        (196, "EM_GETLINE"),
-        (197, "EM_LIMITTEXT"),
-        (197, "EM_SETLIMITTEXT"),
-        (198, "EM_CANUNDO"),
-        (199, "EM_UNDO"),
-        (200, "EM_FMTLINES"),
-        (201, "EM_LINEFROMCHAR"),
-        (202, "EM_SETWORDBREAK"),
-        (203, "EM_SETTABSTOPS"),
-        (204, "EM_SETPASSWORDCHAR"),
-        (205, "EM_EMPTYUNDOBUFFER"),
-        (206, "EM_GETFIRSTVISIBLELINE"),
-        (207, "EM_SETREADONLY"),
-        (209, "EM_SETWORDBREAKPROC"),
-        (209, "EM_GETWORDBREAKPROC"),
-        (210, "EM_GETPASSWORDCHAR"),
-        (211, "EM_SETMARGINS"),
-        (212, "EM_GETMARGINS"),
-        (213, "EM_GETLIMITTEXT"),
-        (214, "EM_POSFROMCHAR"),
-        (215, "EM_CHARFROMPOS"),
-        (216, "EM_SETIMESTATUS"),
-        (217, "EM_GETIMESTATUS"),
-        (224, "SBM_SETPOS"),
-        (225, "SBM_GETPOS"),
-        (226, "SBM_SETRANGE"),
-        (227, "SBM_GETRANGE"),
-        (228, "SBM_ENABLE_ARROWS"),
-        (230, "SBM_SETRANGEREDRAW"),
-        (233, "SBM_SETSCROLLINFO"),
-        (234, "SBM_GETSCROLLINFO"),
-        (235, "SBM_GETSCROLLBARINFO"),
-        (240, "BM_GETCHECK"),
-        (241, "BM_SETCHECK"),
-        (242, "BM_GETSTATE"),
-        (243, "BM_SETSTATE"),
-        (244, "BM_SETSTYLE"),
-        (245, "BM_CLICK"),
-        (246, "BM_GETIMAGE"),
-        (247, "BM_SETIMAGE"),
-        (248, "BM_SETDONTCLICK"),
-        (255, "WM_INPUT"),
-        (256, "WM_KEYDOWN"),
-        (257, "WM_KEYUP"),
-        (258, "WM_CHAR"),
-        (259, "WM_DEADCHAR"),
-        (260, "WM_SYSKEYDOWN"),
-        (261, "WM_SYSKEYUP"),
-        (262, "WM_SYSCHAR"),
-        (263, "WM_SYSDEADCHAR"),
-        (264, "WM_KEYLAST"),
-        (265, "WM_UNICHAR"),
-        (265, "WM_WNT_CONVERTREQUESTEX"),
-        (266, "WM_CONVERTREQUEST"),
-        (267, "WM_CONVERTRESULT"),
-        (268, "WM_INTERIM"),
-        (269, "WM_IME_STARTCOMPOSITION"),
-        (270, "WM_IME_ENDCOMPOSITION"),
-        (271, "WM_IME_COMPOSITION"),
-        (271, "WM_IME_KEYLAST"),
-        (272, "WM_INITDIALOG"),
-        (273, "WM_COMMAND"),
-        (274, "WM_SYSCOMMAND"),
-        (275, "WM_TIMER"),
-        (276, "WM_HSCROL"),
-        (277, "WM_VSCROL"),
-        (278, "WM_INITMENU"),
-        (279, "WM_INITMENUPOPUP"),
-        (280, "WM_SYSTIMER"),
-        (287, "WM_MENUSELECT"),
-        (288, "WM_MENUCHAR"),
-        (289, "WM_ENTERIDLE"),
-        (290, "WM_MENURBUTTONUP"),
-        (291, "WM_MENUDRAG"),
-        (292, "WM_MENUGETOBJECT"),
-        (293, "WM_UNINITMENUPOPUP"),
-        (294, "WM_MENUCOMMAND"),
-        (295, "WM_CHANGEUISTATE"),
-        (296, "WM_UPDATEUISTATE"),
-        (297, "WM_QUERYUISTATE"),
-        (306, "WM_CTLCOLORMSGBOX"),
-        (307, "WM_CTLCOLOREDIT"),
-        (308, "WM_CTLCOLORLISTBOX"),
-        (309, "WM_CTLCOLORBTN"),
-        (310, "WM_CTLCOLORDLG"),
-        (311, "WM_CTLCOLORSCROLLBAR"),
-        (312, "WM_CTLCOLORSTATIC"),
-        (512, "WM_MOUSEFIRST"),
-        (512, "WM_MOUSEMOVE"),
-        (513, "WM_LBUTTONDOWN"),
-        (514, "WM_LBUTTONUP"),
-        (515, "WM_LBUTTONDBLCLK"),
-        (516, "WM_RBUTTONDOWN"),
-        (517, "WM_RBUTTONUP"),
-        (518, "WM_RBUTTONDBLCLK"),
-        (519, "WM_MBUTTONDOWN"),
-        (520, "WM_MBUTTONUP"),
-        (521, "WM_MBUTTONDBLCLK"),
-        (521, "WM_MOUSELAST"),
-        (522, "WM_MOUSEWHEE"),
-        (523, "WM_XBUTTONDOWN"),
-        (524, "WM_XBUTTONUP"),
-        (525, "WM_XBUTTONDBLCLK"),
-        (528, "WM_PARENTNOTIFY"),
-        (529, "WM_ENTERMENULOOP"),
-        (530, "WM_EXITMENULOOP"),
-        (531, "WM_NEXTMENU"),
-        (532, "WM_SIZING"),
-        (533, "WM_CAPTURECHANGED"),
-        (534, "WM_MOVING"),
-        (536, "WM_POWERBROADCAST"),
-        (537, "WM_DEVICECHANGE"),
-        (544, "WM_MDICREATE"),
-        (545, "WM_MDIDESTROY"),
-        (546, "WM_MDIACTIVATE"),
-        (547, "WM_MDIRESTORE"),
-        (548, "WM_MDINEXT"),
-        (549, "WM_MDIMAXIMIZE"),
-        (550, "WM_MDITILE"),
-        (551, "WM_MDICASCADE"),
-        (552, "WM_MDIICONARRANGE"),
-        (553, "WM_MDIGETACTIVE"),
-        (560, "WM_MDISETMENU"),
-        (561, "WM_ENTERSIZEMOVE"),
-        (562, "WM_EXITSIZEMOVE"),
-        (563, "WM_DROPFILES"),
-        (564, "WM_MDIREFRESHMENU"),
-        (640, "WM_IME_REPORT"),
-        (641, "WM_IME_SETCONTEXT"),
-        (642, "WM_IME_NOTIFY"),
-        (643, "WM_IME_CONTRO"),
-        (644, "WM_IME_COMPOSITIONFUL"),
-        (645, "WM_IME_SELECT"),
-        (646, "WM_IME_CHAR"),
-        (648, "WM_IME_REQUEST"),
-        (656, "WM_IMEKEYDOWN"),
-        (656, "WM_IME_KEYDOWN"),
-        (657, "WM_IMEKEYUP"),
-        (657, "WM_IME_KEYUP"),
-        (672, "WM_NCMOUSEHOVER"),
-        (673, "WM_MOUSEHOVER"),
-        (674, "WM_NCMOUSELEAVE"),
-        (675, "WM_MOUSELEAVE"),
-        (768, "WM_CUT"),
-        (769, "WM_COPY"),
-        (770, "WM_PASTE"),
-        (771, "WM_CLEAR"),
-        (772, "WM_UNDO"),
-        (773, "WM_RENDERFORMAT"),
-        (774, "WM_RENDERALLFORMATS"),
-        (775, "WM_DESTROYCLIPBOARD"),
-        (776, "WM_DRAWCLIPBOARD"),
-        (777, "WM_PAINTCLIPBOARD"),
-        (778, "WM_VSCROLLCLIPBOARD"),
-        (779, "WM_SIZECLIPBOARD"),
-        (780, "WM_ASKCBFORMATNAME"),
-        (781, "WM_CHANGECBCHAIN"),
-        (782, "WM_HSCROLLCLIPBOARD"),
-        (783, "WM_QUERYNEWPALETTE"),
-        (784, "WM_PALETTEISCHANGING"),
-        (785, "WM_PALETTECHANGED"),
-        (786, "WM_HOTKEY"),
-        (791, "WM_PRINT"),
-        (792, "WM_PRINTCLIENT"),
-        (793, "WM_APPCOMMAND"),
-        (856, "WM_HANDHELDFIRST"),
-        (863, "WM_HANDHELDLAST"),
-        (864, "WM_AFXFIRST"),
-        (895, "WM_AFXLAST"),
-        (896, "WM_PENWINFIRST"),
-        (897, "WM_RCRESULT"),
-        (898, "WM_HOOKRCRESULT"),
-        (899, "WM_GLOBALRCCHANGE"),
-        (899, "WM_PENMISCINFO"),
-        (900, "WM_SKB"),
-        (901, "WM_HEDITCT"),
-        (901, "WM_PENCT"),
-        (902, "WM_PENMISC"),
-        (903, "WM_CTLINIT"),
-        (904, "WM_PENEVENT"),
-        (911, "WM_PENWINLAST"),
-        (1024, "WM_USER"),
-    ]);
-
-    return map.get(&id).unwrap_or(&"No description found").to_string();
+/// for all the window messages. All of the windowMessages are in this table as well.<br>
+/// The table is sorted on id so we can binary search it. Some ids have more than one name,
+/// the first one is the name we print.
+static MESSAGES_BY_ID: [(u32, &str); MESSAGE_COUNT] = [
+    (0, "WM_NULL"),
+    (1, "WM_CREATE"),
+    (2, "WM_DESTROY"),
+    (3, "WM_MOVE"),
+    (5, "WM_SIZE"),
+    (6, "WM_ACTIVATE"),
+    (7, "WM_SETFOCUS"),
+    (8, "WM_KILLFOCUS"),
+    (10, "WM_ENABLE"),
+    (11, "WM_SETREDRAW"),
+    (12, "WM_SETTEXT"),
+    (13, "WM_GETTEXT"),
+    (14, "WM_GETTEXTLENGTH"),
+    (15, "WM_PAINT"),
+    (16, "WM_CLOSE"),
+    (17, "WM_QUERYENDSESSION"),
+    (18, "WM_QUIT"),
+    (19, "WM_QUERYOPEN"),
+    (20, "WM_ERASEBKGND"),
+    (21, "WM_SYSCOLORCHANGE"),
+    (22, "WM_ENDSESSION"),
+    (24, "WM_SHOWWINDOW"),
+    (25, "WM_CTLCOLOR"),
+    (26, "WM_WININICHANGE"),
+    (27, "WM_DEVMODECHANGE"),
+    (28, "WM_ACTIVATEAPP"),
+    (29, "WM_FONTCHANGE"),
+    (30, "WM_TIMECHANGE"),
+    (31, "WM_CANCELMODE"),
+    (32, "WM_SETCURSOR"),
+    (33, "WM_MOUSEACTIVATE"),
+    (34, "WM_CHILDACTIVATE"),
+    (35, "WM_QUEUESYNC"),
+    (36, "WM_GETMINMAXINFO"),
+    (38, "WM_PAINTICON"),
+    (39, "WM_ICONERASEBKGND"),
+    (40, "WM_NEXTDLGCTL"),
+    (42, "WM_SPOOLERSTATUS"),
+    (43, "WM_DRAWITEM"),
+    (44, "WM_MEASUREITEM"),
+    (45, "WM_DELETEITEM"),
+    (46, "WM_VKEYTOITEM"),
+    (47, "WM_CHARTOITEM"),
+    (48, "WM_SETFONT"),
+    (49, "WM_GETFONT"),
+    (50, "WM_SETHOTKEY"),
+    (51, "WM_GETHOTKEY"),
+    (55, "WM_QUERYDRAGICON"),
+    (57, "WM_COMPAREITEM"),
+    (61, "WM_GETOBJECT"),
+    (65, "WM_COMPACTING"),
+    (68, "WM_COMMNOTIFY"),
+    (70, "WM_WINDOWPOSCHANGING"),
+    (71, "WM_WINDOWPOSCHANGED"),
+    (72, "WM_POWER"),
+    (73, "WM_COPYGLOBALDATA"),
+    (74, "WM_COPYDATA"),
+    (75, "WM_CANCELJOURNAL"),
+    (78, "WM_NOTIFY"),
+    (80, "WM_INPUTLANGCHANGEREQUEST"),
+    (81, "WM_INPUTLANGCHANGE"),
+    (82, "WM_TCARD"),
+    (83, "WM_HELP"),
+    (84, "WM_USERCHANGED"),
+    (85, "WM_NOTIFYFORMAT"),
+    (96, "WM_MSG_CREATE"),
+    (123, "WM_CONTEXTMENU"),
+    (124, "WM_STYLECHANGING"),
+    (125, "WM_STYLECHANGED"),
+    (126, "WM_DISPLAYCHANGE"),
+    (127, "WM_GETICON"),
+    (128, "WM_SETICON"),
+    (129, "WM_NCCREATE"),
+    (130, "WM_NCDESTROY"),
+    (131, "WM_NCCALCSIZE"),
+    (132, "WM_NCHITTEST"),
+    (133, "WM_NCPAINT"),
+    (134, "WM_NCACTIVATE"),
+    (135, "WM_GETDLGCODE"),
+    (136, "WM_SYNCPAINT"),
+    (160, "WM_NCMOUSEMOVE"),
+    (161, "WM_NCLBUTTONDOWN"),
+    (162, "WM_NCLBUTTONUP"),
+    (163, "WM_NCLBUTTONDBLCLK"),
+    (164, "WM_NCRBUTTONDOWN"),
+    (165, "WM_NCRBUTTONUP"),
+    (166, "WM_NCRBUTTONDBLCLK"),
+    (167, "WM_NCMBUTTONDOWN"),
+    (168, "WM_NCMBUTTONUP"),
+    (169, "WM_NCMBUTTONDBLCLK"),
+    (171, "WM_NCXBUTTONDOWN"),
+    (172, "WM_NCXBUTTONUP"),
+    (173, "WM_NCXBUTTONDBLCLK"),
+    (176, "EM_GETSEL"),
+    (177, "EM_SETSEL"),
+    (178, "EM_GETRECT"),
+    (179, "EM_SETRECT"),
+    (180, "EM_SETRECTNP"),
+    (181, "EM_SCROLL"),
+    (182, "EM_LINESCROLL"),
+    (183, "EM_SCROLLCARET"),
+    (185, "EM_GETMODIFY"),
+    (187, "EM_SETMODIFY"),
+    (188, "EM_GETLINECOUNT"),
+    (189, "EM_LINEINDEX"),
+    (190, "EM_SETHANDLE"),
+    (191, "EM_GETHANDLE"),
+    (192, "EM_GETTHUMB"),
+    (193, "EM_LINELENGTH"),
+    (194, "EM_REPLACESEL"),
+    (195, "EM_SETFONT"),
+    (196, "EM_GETLINE"),
+    (197, "EM_LIMITTEXT"),
+    (197, "EM_SETLIMITTEXT"),
+    (198, "EM_CANUNDO"),
+    (199, "EM_UNDO"),
+    (200, "EM_FMTLINES"),
+    (201, "EM_LINEFROMCHAR"),
+    (202, "EM_SETWORDBREAK"),
+    (203, "EM_SETTABSTOPS"),
+    (204, "EM_SETPASSWORDCHAR"),
+    (205, "EM_EMPTYUNDOBUFFER"),
+    (206, "EM_GETFIRSTVISIBLELINE"),
+    (207, "EM_SETREADONLY"),
+    (209, "EM_SETWORDBREAKPROC"),
+    (209, "EM_GETWORDBREAKPROC"),
+    (210, "EM_GETPASSWORDCHAR"),
+    (211, "EM_SETMARGINS"),
+    (212, "EM_GETMARGINS"),
+    (213, "EM_GETLIMITTEXT"),
+    (214, "EM_POSFROMCHAR"),
+    (215, "EM_CHARFROMPOS"),
+    (216, "EM_SETIMESTATUS"),
+    (217, "EM_GETIMESTATUS"),
+    (224, "SBM_SETPOS"),
+    (225, "SBM_GETPOS"),
+    (226, "SBM_SETRANGE"),
+    (227, "SBM_GETRANGE"),
+    (228, "SBM_ENABLE_ARROWS"),
+    (230, "SBM_SETRANGEREDRAW"),
+    (233, "SBM_SETSCROLLINFO"),
+    (234, "SBM_GETSCROLLINFO"),
+    (235, "SBM_GETSCROLLBARINFO"),
+    (240, "BM_GETCHECK"),
+    (241, "BM_SETCHECK"),
+    (242, "BM_GETSTATE"),
+    (243, "BM_SETSTATE"),
+    (244, "BM_SETSTYLE"),
+    (245, "BM_CLICK"),
+    (246, "BM_GETIMAGE"),
+    (247, "BM_SETIMAGE"),
+    (248, "BM_SETDONTCLICK"),
+    (254, "WM_INPUT_DEVICE_CHANGE"),
+    (255, "WM_INPUT"),
+    (256, "WM_KEYDOWN"),
+    (257, "WM_KEYUP"),
+    (258, "WM_CHAR"),
+    (259, "WM_DEADCHAR"),
+    (260, "WM_SYSKEYDOWN"),
+    (261, "WM_SYSKEYUP"),
+    (262, "WM_SYSCHAR"),
+    (263, "WM_SYSDEADCHAR"),
+    (264, "WM_KEYLAST"),
+    (265, "WM_UNICHAR"),
+    (265, "WM_WNT_CONVERTREQUESTEX"),
+    (266, "WM_CONVERTREQUEST"),
+    (267, "WM_CONVERTRESULT"),
+    (268, "WM_INTERIM"),
+    (269, "WM_IME_STARTCOMPOSITION"),
+    (270, "WM_IME_ENDCOMPOSITION"),
+    (271, "WM_IME_COMPOSITION"),
+    (271, "WM_IME_KEYLAST"),
+    (272, "WM_INITDIALOG"),
+    (273, "WM_COMMAND"),
+    (274, "WM_SYSCOMMAND"),
+    (275, "WM_TIMER"),
+    (276, "WM_HSCROLL"),
+    (277, "WM_VSCROLL"),
+    (278, "WM_INITMENU"),
+    (279, "WM_INITMENUPOPUP"),
+    (280, "WM_SYSTIMER"),
+    (287, "WM_MENUSELECT"),
+    (288, "WM_MENUCHAR"),
+    (289, "WM_ENTERIDLE"),
+    (290, "WM_MENURBUTTONUP"),
+    (291, "WM_MENUDRAG"),
+    (292, "WM_MENUGETOBJECT"),
+    (293, "WM_UNINITMENUPOPUP"),
+    (294, "WM_MENUCOMMAND"),
+    (295, "WM_CHANGEUISTATE"),
+    (296, "WM_UPDATEUISTATE"),
+    (297, "WM_QUERYUISTATE"),
+    (306, "WM_CTLCOLORMSGBOX"),
+    (307, "WM_CTLCOLOREDIT"),
+    (308, "WM_CTLCOLORLISTBOX"),
+    (309, "WM_CTLCOLORBTN"),
+    (310, "WM_CTLCOLORDLG"),
+    (311, "WM_CTLCOLORSCROLLBAR"),
+    (312, "WM_CTLCOLORSTATIC"),
+    (512, "WM_MOUSEMOVE"),
+    (512, "WM_MOUSEFIRST"),
+    (513, "WM_LBUTTONDOWN"),
+    (514, "WM_LBUTTONUP"),
+    (515, "WM_LBUTTONDBLCLK"),
+    (516, "WM_RBUTTONDOWN"),
+    (517, "WM_RBUTTONUP"),
+    (518, "WM_RBUTTONDBLCLK"),
+    (519, "WM_MBUTTONDOWN"),
+    (520, "WM_MBUTTONUP"),
+    (521, "WM_MBUTTONDBLCLK"),
+    (521, "WM_MOUSELAST"),
+    (522, "WM_MOUSEWHEEL"),
+    (523, "WM_XBUTTONDOWN"),
+    (524, "WM_XBUTTONUP"),
+    (525, "WM_XBUTTONDBLCLK"),
+    (526, "WM_MOUSEHWHEEL"),
+    (528, "WM_PARENTNOTIFY"),
+    (529, "WM_ENTERMENULOOP"),
+    (530, "WM_EXITMENULOOP"),
+    (531, "WM_NEXTMENU"),
+    (532, "WM_SIZING"),
+    (533, "WM_CAPTURECHANGED"),
+    (534, "WM_MOVING"),
+    (536, "WM_POWERBROADCAST"),
+    (537, "WM_DEVICECHANGE"),
+    (544, "WM_MDICREATE"),
+    (545, "WM_MDIDESTROY"),
+    (546, "WM_MDIACTIVATE"),
+    (547, "WM_MDIRESTORE"),
+    (548, "WM_MDINEXT"),
+    (549, "WM_MDIMAXIMIZE"),
+    (550, "WM_MDITILE"),
+    (551, "WM_MDICASCADE"),
+    (552, "WM_MDIICONARRANGE"),
+    (553, "WM_MDIGETACTIVE"),
+    (560, "WM_MDISETMENU"),
+    (561, "WM_ENTERSIZEMOVE"),
+    (562, "WM_EXITSIZEMOVE"),
+    (563, "WM_DROPFILES"),
+    (564, "WM_MDIREFRESHMENU"),
+    (640, "WM_IME_REPORT"),
+    (641, "WM_IME_SETCONTEXT"),
+    (642, "WM_IME_NOTIFY"),
+    (643, "WM_IME_CONTROL"),
+    (644, "WM_IME_COMPOSITIONFULL"),
+    (645, "WM_IME_SELECT"),
+    (646, "WM_IME_CHAR"),
+    (648, "WM_IME_REQUEST"),
+    (656, "WM_IME_KEYDOWN"),
+    (656, "WM_IMEKEYDOWN"),
+    (657, "WM_IME_KEYUP"),
+    (657, "WM_IMEKEYUP"),
+    (672, "WM_NCMOUSEHOVER"),
+    (673, "WM_MOUSEHOVER"),
+    (674, "WM_NCMOUSELEAVE"),
+    (675, "WM_MOUSELEAVE"),
+    (689, "WM_WTSSESSION_CHANGE"),
+    (736, "WM_DPICHANGED"),
+    (768, "WM_CUT"),
+    (769, "WM_COPY"),
+    (770, "WM_PASTE"),
+    (771, "WM_CLEAR"),
+    (772, "WM_UNDO"),
+    (773, "WM_RENDERFORMAT"),
+    (774, "WM_RENDERALLFORMATS"),
+    (775, "WM_DESTROYCLIPBOARD"),
+    (776, "WM_DRAWCLIPBOARD"),
+    (777, "WM_PAINTCLIPBOARD"),
+    (778, "WM_VSCROLLCLIPBOARD"),
+    (779, "WM_SIZECLIPBOARD"),
+    (780, "WM_ASKCBFORMATNAME"),
+    (781, "WM_CHANGECBCHAIN"),
+    (782, "WM_HSCROLLCLIPBOARD"),
+    (783, "WM_QUERYNEWPALETTE"),
+    (784, "WM_PALETTEISCHANGING"),
+    (785, "WM_PALETTECHANGED"),
+    (786, "WM_HOTKEY"),
+    (791, "WM_PRINT"),
+    (792, "WM_PRINTCLIENT"),
+    (793, "WM_APPCOMMAND"),
+    (797, "WM_CLIPBOARDUPDATE"),
+    (798, "WM_DWMCOMPOSITIONCHANGED"),
+    (799, "WM_DWMNCRENDERINGCHANGED"),
+    (800, "WM_DWMCOLORIZATIONCOLORCHANGED"),
+    (801, "WM_DWMWINDOWMAXIMIZEDCHANGE"),
+    (856, "WM_HANDHELDFIRST"),
+    (863, "WM_HANDHELDLAST"),
+    (864, "WM_AFXFIRST"),
+    (895, "WM_AFXLAST"),
+    (896, "WM_PENWINFIRST"),
+    (897, "WM_RCRESULT"),
+    (898, "WM_HOOKRCRESULT"),
+    (899, "WM_GLOBALRCCHANGE"),
+    (899, "WM_PENMISCINFO"),
+    (900, "WM_SKB"),
+    (901, "WM_HEDITCTL"),
+    (901, "WM_PENCTL"),
+    (902, "WM_PENMISC"),
+    (903, "WM_CTLINIT"),
+    (904, "WM_PENEVENT"),
+    (911, "WM_PENWINLAST"),
+    (1024, "WM_USER"),
+    (32768, "WM_APP"),
+];
+
+/// The same messages as [`MESSAGES_BY_ID`] but sorted on name, so the reverse lookup can binary search as well.
+static MESSAGES_BY_NAME: [(&str, u32); MESSAGE_COUNT] = [
+    ("BM_CLICK", 245),
+    ("BM_GETCHECK", 240),
+    ("BM_GETIMAGE", 246),
+    ("BM_GETSTATE", 242),
+    ("BM_SETCHECK", 241),
+    ("BM_SETDONTCLICK", 248),
+    ("BM_SETIMAGE", 247),
+    ("BM_SETSTATE", 243),
+    ("BM_SETSTYLE", 244),
+    ("EM_CANUNDO", 198),
+    ("EM_CHARFROMPOS", 215),
+    ("EM_EMPTYUNDOBUFFER", 205),
+    ("EM_FMTLINES", 200),
+    ("EM_GETFIRSTVISIBLELINE", 206),
+    ("EM_GETHANDLE", 191),
+    ("EM_GETIMESTATUS", 217),
+    ("EM_GETLIMITTEXT", 213),
+    ("EM_GETLINE", 196),
+    ("EM_GETLINECOUNT", 188),
+    ("EM_GETMARGINS", 212),
+    ("EM_GETMODIFY", 185),
+    ("EM_GETPASSWORDCHAR", 210),
+    ("EM_GETRECT", 178),
+    ("EM_GETSEL", 176),
+    ("EM_GETTHUMB", 192),
+    ("EM_GETWORDBREAKPROC", 209),
+    ("EM_LIMITTEXT", 197),
+    ("EM_LINEFROMCHAR", 201),
+    ("EM_LINEINDEX", 189),
+    ("EM_LINELENGTH", 193),
+    ("EM_LINESCROLL", 182),
+    ("EM_POSFROMCHAR", 214),
+    ("EM_REPLACESEL", 194),
+    ("EM_SCROLL", 181),
+    ("EM_SCROLLCARET", 183),
+    ("EM_SETFONT", 195),
+    ("EM_SETHANDLE", 190),
+    ("EM_SETIMESTATUS", 216),
+    ("EM_SETLIMITTEXT", 197),
+    ("EM_SETMARGINS", 211),
+    ("EM_SETMODIFY", 187),
+    ("EM_SETPASSWORDCHAR", 204),
+    ("EM_SETREADONLY", 207),
+    ("EM_SETRECT", 179),
+    ("EM_SETRECTNP", 180),
+    ("EM_SETSEL", 177),
+    ("EM_SETTABSTOPS", 203),
+    ("EM_SETWORDBREAK", 202),
+    ("EM_SETWORDBREAKPROC", 209),
+    ("EM_UNDO", 199),
+    ("SBM_ENABLE_ARROWS", 228),
+    ("SBM_GETPOS", 225),
+    ("SBM_GETRANGE", 227),
+    ("SBM_GETSCROLLBARINFO", 235),
+    ("SBM_GETSCROLLINFO", 234),
+    ("SBM_SETPOS", 224),
+    ("SBM_SETRANGE", 226),
+    ("SBM_SETRANGEREDRAW", 230),
+    ("SBM_SETSCROLLINFO", 233),
+    ("WM_ACTIVATE", 6),
+    ("WM_ACTIVATEAPP", 28),
+    ("WM_AFXFIRST", 864),
+    ("WM_AFXLAST", 895),
+    ("WM_APP", 32768),
+    ("WM_APPCOMMAND", 793),
+    ("WM_ASKCBFORMATNAME", 780),
+    ("WM_CANCELJOURNAL", 75),
+    ("WM_CANCELMODE", 31),
+    ("WM_CAPTURECHANGED", 533),
+    ("WM_CHANGECBCHAIN", 781),
+    ("WM_CHANGEUISTATE", 295),
+    ("WM_CHAR", 258),
+    ("WM_CHARTOITEM", 47),
+    ("WM_CHILDACTIVATE", 34),
+    ("WM_CLEAR", 771),
+    ("WM_CLIPBOARDUPDATE", 797),
+    ("WM_CLOSE", 16),
+    ("WM_COMMAND", 273),
+    ("WM_COMMNOTIFY", 68),
+    ("WM_COMPACTING", 65),
+    ("WM_COMPAREITEM", 57),
+    ("WM_CONTEXTMENU", 123),
+    ("WM_CONVERTREQUEST", 266),
+    ("WM_CONVERTRESULT", 267),
+    ("WM_COPY", 769),
+    ("WM_COPYDATA", 74),
+    ("WM_COPYGLOBALDATA", 73),
+    ("WM_CREATE", 1),
+    ("WM_CTLCOLOR", 25),
+    ("WM_CTLCOLORBTN", 309),
+    ("WM_CTLCOLORDLG", 310),
+    ("WM_CTLCOLOREDIT", 307),
+    ("WM_CTLCOLORLISTBOX", 308),
+    ("WM_CTLCOLORMSGBOX", 306),
+    ("WM_CTLCOLORSCROLLBAR", 311),
+    ("WM_CTLCOLORSTATIC", 312),
+    ("WM_CTLINIT", 903),
+    ("WM_CUT", 768),
+    ("WM_DEADCHAR", 259),
+    ("WM_DELETEITEM", 45),
+    ("WM_DESTROY", 2),
+    ("WM_DESTROYCLIPBOARD", 775),
+    ("WM_DEVICECHANGE", 537),
+    ("WM_DEVMODECHANGE", 27),
+    ("WM_DISPLAYCHANGE", 126),
+    ("WM_DPICHANGED", 736),
+    ("WM_DRAWCLIPBOARD", 776),
+    ("WM_DRAWITEM", 43),
+    ("WM_DROPFILES", 563),
+    ("WM_DWMCOLORIZATIONCOLORCHANGED", 800),
+    ("WM_DWMCOMPOSITIONCHANGED", 798),
+    ("WM_DWMNCRENDERINGCHANGED", 799),
+    ("WM_DWMWINDOWMAXIMIZEDCHANGE", 801),
+    ("WM_ENABLE", 10),
+    ("WM_ENDSESSION", 22),
+    ("WM_ENTERIDLE", 289),
+    ("WM_ENTERMENULOOP", 529),
+    ("WM_ENTERSIZEMOVE", 561),
+    ("WM_ERASEBKGND", 20),
+    ("WM_EXITMENULOOP", 530),
+    ("WM_EXITSIZEMOVE", 562),
+    ("WM_FONTCHANGE", 29),
+    ("WM_GETDLGCODE", 135),
+    ("WM_GETFONT", 49),
+    ("WM_GETHOTKEY", 51),
+    ("WM_GETICON", 127),
+    ("WM_GETMINMAXINFO", 36),
+    ("WM_GETOBJECT", 61),
+    ("WM_GETTEXT", 13),
+    ("WM_GETTEXTLENGTH", 14),
+    ("WM_GLOBALRCCHANGE", 899),
+    ("WM_HANDHELDFIRST", 856),
+    ("WM_HANDHELDLAST", 863),
+    ("WM_HEDITCTL", 901),
+    ("WM_HELP", 83),
+    ("WM_HOOKRCRESULT", 898),
+    ("WM_HOTKEY", 786),
+    ("WM_HSCROLL", 276),
+    ("WM_HSCROLLCLIPBOARD", 782),
+    ("WM_ICONERASEBKGND", 39),
+    ("WM_IMEKEYDOWN", 656),
+    ("WM_IMEKEYUP", 657),
+    ("WM_IME_CHAR", 646),
+    ("WM_IME_COMPOSITION", 271),
+    ("WM_IME_COMPOSITIONFULL", 644),
+    ("WM_IME_CONTROL", 643),
+    ("WM_IME_ENDCOMPOSITION", 270),
+    ("WM_IME_KEYDOWN", 656),
+    ("WM_IME_KEYLAST", 271),
+    ("WM_IME_KEYUP", 657),
+    ("WM_IME_NOTIFY", 642),
+    ("WM_IME_REPORT", 640),
+    ("WM_IME_REQUEST", 648),
+    ("WM_IME_SELECT", 645),
+    ("WM_IME_SETCONTEXT", 641),
+    ("WM_IME_STARTCOMPOSITION", 269),
+    ("WM_INITDIALOG", 272),
+    ("WM_INITMENU", 278),
+    ("WM_INITMENUPOPUP", 279),
+    ("WM_INPUT", 255),
+    ("WM_INPUTLANGCHANGE", 81),
+    ("WM_INPUTLANGCHANGEREQUEST", 80),
+    ("WM_INPUT_DEVICE_CHANGE", 254),
+    ("WM_INTERIM", 268),
+    ("WM_KEYDOWN", 256),
+    ("WM_KEYLAST", 264),
+    ("WM_KEYUP", 257),
+    ("WM_KILLFOCUS", 8),
+    ("WM_LBUTTONDBLCLK", 515),
+    ("WM_LBUTTONDOWN", 513),
+    ("WM_LBUTTONUP", 514),
+    ("WM_MBUTTONDBLCLK", 521),
+    ("WM_MBUTTONDOWN", 519),
+    ("WM_MBUTTONUP", 520),
+    ("WM_MDIACTIVATE", 546),
+    ("WM_MDICASCADE", 551),
+    ("WM_MDICREATE", 544),
+    ("WM_MDIDESTROY", 545),
+    ("WM_MDIGETACTIVE", 553),
+    ("WM_MDIICONARRANGE", 552),
+    ("WM_MDIMAXIMIZE", 549),
+    ("WM_MDINEXT", 548),
+    ("WM_MDIREFRESHMENU", 564),
+    ("WM_MDIRESTORE", 547),
+    ("WM_MDISETMENU", 560),
+    ("WM_MDITILE", 550),
+    ("WM_MEASUREITEM", 44),
+    ("WM_MENUCHAR", 288),
+    ("WM_MENUCOMMAND", 294),
+    ("WM_MENUDRAG", 291),
+    ("WM_MENUGETOBJECT", 292),
+    ("WM_MENURBUTTONUP", 290),
+    ("WM_MENUSELECT", 287),
+    ("WM_MOUSEACTIVATE", 33),
+    ("WM_MOUSEFIRST", 512),
+    ("WM_MOUSEHOVER", 673),
+    ("WM_MOUSEHWHEEL", 526),
+    ("WM_MOUSELAST", 521),
+    ("WM_MOUSELEAVE", 675),
+    ("WM_MOUSEMOVE", 512),
+    ("WM_MOUSEWHEEL", 522),
+    ("WM_MOVE", 3),
+    ("WM_MOVING", 534),
+    ("WM_MSG_CREATE", 96),
+    ("WM_NCACTIVATE", 134),
+    ("WM_NCCALCSIZE", 131),
+    ("WM_NCCREATE", 129),
+    ("WM_NCDESTROY", 130),
+    ("WM_NCHITTEST", 132),
+    ("WM_NCLBUTTONDBLCLK", 163),
+    ("WM_NCLBUTTONDOWN", 161),
+    ("WM_NCLBUTTONUP", 162),
+    ("WM_NCMBUTTONDBLCLK", 169),
+    ("WM_NCMBUTTONDOWN", 167),
+    ("WM_NCMBUTTONUP", 168),
+    ("WM_NCMOUSEHOVER", 672),
+    ("WM_NCMOUSELEAVE", 674),
+    ("WM_NCMOUSEMOVE", 160),
+    ("WM_NCPAINT", 133),
+    ("WM_NCRBUTTONDBLCLK", 166),
+    ("WM_NCRBUTTONDOWN", 164),
+    ("WM_NCRBUTTONUP", 165),
+    ("WM_NCXBUTTONDBLCLK", 173),
+    ("WM_NCXBUTTONDOWN", 171),
+    ("WM_NCXBUTTONUP", 172),
+    ("WM_NEXTDLGCTL", 40),
+    ("WM_NEXTMENU", 531),
+    ("WM_NOTIFY", 78),
+    ("WM_NOTIFYFORMAT", 85),
+    ("WM_NULL", 0),
+    ("WM_PAINT", 15),
+    ("WM_PAINTCLIPBOARD", 777),
+    ("WM_PAINTICON", 38),
+    ("WM_PALETTECHANGED", 785),
+    ("WM_PALETTEISCHANGING", 784),
+    ("WM_PARENTNOTIFY", 528),
+    ("WM_PASTE", 770),
+    ("WM_PENCTL", 901),
+    ("WM_PENEVENT", 904),
+    ("WM_PENMISC", 902),
+    ("WM_PENMISCINFO", 899),
+    ("WM_PENWINFIRST", 896),
+    ("WM_PENWINLAST", 911),
+    ("WM_POWER", 72),
+    ("WM_POWERBROADCAST", 536),
+    ("WM_PRINT", 791),
+    ("WM_PRINTCLIENT", 792),
+    ("WM_QUERYDRAGICON", 55),
+    ("WM_QUERYENDSESSION", 17),
+    ("WM_QUERYNEWPALETTE", 783),
+    ("WM_QUERYOPEN", 19),
+    ("WM_QUERYUISTATE", 297),
+    ("WM_QUEUESYNC", 35),
+    ("WM_QUIT", 18),
+    ("WM_RBUTTONDBLCLK", 518),
+    ("WM_RBUTTONDOWN", 516),
+    ("WM_RBUTTONUP", 517),
+    ("WM_RCRESULT", 897),
+    ("WM_RENDERALLFORMATS", 774),
+    ("WM_RENDERFORMAT", 773),
+    ("WM_SETCURSOR", 32),
+    ("WM_SETFOCUS", 7),
+    ("WM_SETFONT", 48),
+    ("WM_SETHOTKEY", 50),
+    ("WM_SETICON", 128),
+    ("WM_SETREDRAW", 11),
+    ("WM_SETTEXT", 12),
+    ("WM_SHOWWINDOW", 24),
+    ("WM_SIZE", 5),
+    ("WM_SIZECLIPBOARD", 779),
+    ("WM_SIZING", 532),
+    ("WM_SKB", 900),
+    ("WM_SPOOLERSTATUS", 42),
+    ("WM_STYLECHANGED", 125),
+    ("WM_STYLECHANGING", 124),
+    ("WM_SYNCPAINT", 136),
+    ("WM_SYSCHAR", 262),
+    ("WM_SYSCOLORCHANGE", 21),
+    ("WM_SYSCOMMAND", 274),
+    ("WM_SYSDEADCHAR", 263),
+    ("WM_SYSKEYDOWN", 260),
+    ("WM_SYSKEYUP", 261),
+    ("WM_SYSTIMER", 280),
+    ("WM_TCARD", 82),
+    ("WM_TIMECHANGE", 30),
+    ("WM_TIMER", 275),
+    ("WM_UNDO", 772),
+    ("WM_UNICHAR", 265),
+    ("WM_UNINITMENUPOPUP", 293),
+    ("WM_UPDATEUISTATE", 296),
+    ("WM_USER", 1024),
+    ("WM_USERCHANGED", 84),
+    ("WM_VKEYTOITEM", 46),
+    ("WM_VSCROLL", 277),
+    ("WM_VSCROLLCLIPBOARD", 778),
+    ("WM_WINDOWPOSCHANGED", 71),
+    ("WM_WINDOWPOSCHANGING", 70),
+    ("WM_WININICHANGE", 26),
+    ("WM_WNT_CONVERTREQUESTEX", 265),
+    ("WM_WTSSESSION_CHANGE", 689),
+    ("WM_XBUTTONDBLCLK", 525),
+    ("WM_XBUTTONDOWN", 523),
+    ("WM_XBUTTONUP", 524),
+];
+
+/// Virtual key codes that don't map to a printable character. See
+/// [this](https://learn.microsoft.com/en-us/windows/win32/inputdev/virtual-key-codes) for the full list.
+static VIRTUAL_KEYS: [(u32, &str); 104] = [
+    (0x01, "LBUTTON"),
+    (0x02, "RBUTTON"),
+    (0x03, "CANCEL"),
+    (0x04, "MBUTTON"),
+    (0x05, "XBUTTON1"),
+    (0x06, "XBUTTON2"),
+    (0x08, "BACK"),
+    (0x09, "TAB"),
+    (0x0C, "CLEAR"),
+    (0x0D, "RETURN"),
+    (0x10, "SHIFT"),
+    (0x11, "CONTROL"),
+    (0x12, "MENU"),
+    (0x13, "PAUSE"),
+    (0x14, "CAPITAL"),
+    (0x15, "KANA"),
+    (0x17, "JUNJA"),
+    (0x18, "FINAL"),
+    (0x19, "KANJI"),
+    (0x1B, "ESCAPE"),
+    (0x1C, "CONVERT"),
+    (0x1D, "NONCONVERT"),
+    (0x1E, "ACCEPT"),
+    (0x1F, "MODECHANGE"),
+    (0x20, "SPACE"),
+    (0x21, "PRIOR"),
+    (0x22, "NEXT"),
+    (0x23, "END"),
+    (0x24, "HOME"),
+    (0x25, "LEFT"),
+    (0x26, "UP"),
+    (0x27, "RIGHT"),
+    (0x28, "DOWN"),
+    (0x29, "SELECT"),
+    (0x2A, "PRINT"),
+    (0x2B, "EXECUTE"),
+    (0x2C, "SNAPSHOT"),
+    (0x2D, "INSERT"),
+    (0x2E, "DELETE"),
+    (0x2F, "HELP"),
+    (0x5B, "LWIN"),
+    (0x5C, "RWIN"),
+    (0x5D, "APPS"),
+    (0x5F, "SLEEP"),
+    (0x60, "NUMPAD0"),
+    (0x61, "NUMPAD1"),
+    (0x62, "NUMPAD2"),
+    (0x63, "NUMPAD3"),
+    (0x64, "NUMPAD4"),
+    (0x65, "NUMPAD5"),
+    (0x66, "NUMPAD6"),
+    (0x67, "NUMPAD7"),
+    (0x68, "NUMPAD8"),
+    (0x69, "NUMPAD9"),
+    (0x6A, "MULTIPLY"),
+    (0x6B, "ADD"),
+    (0x6C, "SEPARATOR"),
+    (0x6D, "SUBTRACT"),
+    (0x6E, "DECIMAL"),
+    (0x6F, "DIVIDE"),
+    (0x70, "F1"),
+    (0x71, "F2"),
+    (0x72, "F3"),
+    (0x73, "F4"),
+    (0x74, "F5"),
+    (0x75, "F6"),
+    (0x76, "F7"),
+    (0x77, "F8"),
+    (0x78, "F9"),
+    (0x79, "F10"),
+    (0x7A, "F11"),
+    (0x7B, "F12"),
+    (0x7C, "F13"),
+    (0x7D, "F14"),
+    (0x7E, "F15"),
+    (0x7F, "F16"),
+    (0x80, "F17"),
+    (0x81, "F18"),
+    (0x82, "F19"),
+    (0x83, "F20"),
+    (0x84, "F21"),
+    (0x85, "F22"),
+    (0x86, "F23"),
+    (0x87, "F24"),
+    (0x90, "NUMLOCK"),
+    (0x91, "SCROLL"),
+    (0xA0, "LSHIFT"),
+    (0xA1, "RSHIFT"),
+    (0xA2, "LCONTROL"),
+    (0xA3, "RCONTROL"),
+    (0xA4, "LMENU"),
+    (0xA5, "RMENU"),
+    (0xBA, "OEM_1"),
+    (0xBB, "OEM_PLUS"),
+    (0xBC, "OEM_COMMA"),
+    (0xBD, "OEM_MINUS"),
+    (0xBE, "OEM_PERIOD"),
+    (0xBF, "OEM_2"),
+    (0xC0, "OEM_3"),
+    (0xDB, "OEM_4"),
+    (0xDC, "OEM_5"),
+    (0xDD, "OEM_6"),
+    (0xDE, "OEM_7"),
+    (0xE2, "OEM_102"),
+];
+
+/// The name of a window message. Ids in the private ranges don't have a name so they are printed
+/// relative to the start of their range, like `WM_USER+5`.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum MessageName {
+    Known(&'static str),
+    User(u32),
+    App(u32),
+    Registered(u32),
+    Unknown(u32),
+}
+
+impl fmt::Display for MessageName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MessageName::Known(name) => write!(f, "{}", name),
+            MessageName::User(offset) => write!(f, "WM_USER+{}", offset),
+            MessageName::App(offset) => write!(f, "WM_APP+{}", offset),
+            MessageName::Registered(id) => write!(f, "REGISTERED_0x{:04X}", id),
+            MessageName::Unknown(id) => write!(f, "UNKNOWN_0x{:04X}", id),
+        }
+    }
+}
+
+/// Look up the name of a window message. This doesn't allocate, so it is fine to call it for every message.
+pub fn id_to_name(id: u32) -> MessageName {
+    // partition_point gives us the first entry with this id, which is the preferred name.
+    let index: usize = MESSAGES_BY_ID.partition_point(|(msg_id, _)| *msg_id < id);
+    if let Some((msg_id, name)) = MESSAGES_BY_ID.get(index) {
+        if *msg_id == id {
+            return MessageName::Known(name);
+        }
+    }
+
+    return match id {
+        WM_USER..=0x7FFF => MessageName::User(id - WM_USER),
+        WM_APP..=0xBFFF => MessageName::App(id - WM_APP),
+        WM_REGISTERED..=0xFFFF => MessageName::Registered(id),
+        _ => MessageName::Unknown(id),
+    };
+}
+
+/// Look up the id of a window message by name. Also understands the private ranges
+/// written as `WM_USER+5` or `WM_APP+0x10`.
+pub fn name_to_id(name: &str) -> Option<u32> {
+    if let Ok(index) = MESSAGES_BY_NAME.binary_search_by(|(msg_name, _)| (*msg_name).cmp(name)) {
+        return Some(MESSAGES_BY_NAME[index].1);
+    }
+
+    let (base, max, offset) = if let Some(offset) = name.strip_prefix("WM_USER+") {
+        (WM_USER, 0x7FFF, offset)
+    } else if let Some(offset) = name.strip_prefix("WM_APP+") {
+        (WM_APP, 0xBFFF, offset)
+    } else {
+        return None;
+    };
+
+    let offset: u32 = match offset.strip_prefix("0x") {
+        Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+        None => offset.parse().ok()?,
+    };
+
+    return base.checked_add(offset).filter(|id| *id <= max);
 }
 
-pub fn dx_severity_id_to_str(
-    severity: windows::Win32::Graphics::Dxgi::DXGI_INFO_QUEUE_MESSAGE_SEVERITY,
-) -> Result<String, ()> {
-    match severity.0 {
-        0 => Ok("Corruption".to_string()),
-        1 => Ok("Error".to_string()),
-        2 => Ok("Warning".to_string()),
-        3 => Ok("Info".to_string()),
-        4 => Ok("Message".to_string()),
-        _ => Err(()),
+/// Get a readable name for a virtual key code, like `RETURN` or `A`.
+pub fn virtual_key_name(vk: u32) -> String {
+    if let Ok(index) = VIRTUAL_KEYS.binary_search_by_key(&vk, |(code, _)| *code) {
+        return VIRTUAL_KEYS[index].1.to_string();
     }
+
+    return match vk {
+        // '0'..'9' and 'A'..'Z' use their ascii code as virtual key code
+        0x30..=0x39 | 0x41..=0x5A => char::from_u32(vk).unwrap().to_string(),
+        _ => format!("0x{:02X}", vk),
+    };
+}
+
+/// Format a message with its decoded parameters, like `WM_KEYDOWN vk=RETURN repeat=1 scan=0x1C`.
+pub fn format_message(msg: u32, wparam: WPARAM, lparam: LPARAM) -> String {
+    let params: String = format_params(msg, wparam, lparam);
+    if params.is_empty() {
+        return id_to_name(msg).to_string();
+    }
+    return format!("{} {}", id_to_name(msg), params);
+}
+
+/// Decode the wParam and lParam of a message. Messages without a decoder just print both values in hex.
+pub fn format_params(msg: u32, wparam: WPARAM, lparam: LPARAM) -> String {
+    let low_word = |value: usize| (value & 0xFFFF) as u32;
+    let high_word = |value: usize| ((value >> 16) & 0xFFFF) as u32;
+
+    return match id_to_name(msg) {
+        MessageName::Known("WM_KEYDOWN")
+        | MessageName::Known("WM_KEYUP")
+        | MessageName::Known("WM_SYSKEYDOWN")
+        | MessageName::Known("WM_SYSKEYUP") => format!(
+            "vk={} {}",
+            virtual_key_name(wparam.0 as u32),
+            format_keystroke_flags(lparam)
+        ),
+        MessageName::Known("WM_CHAR")
+        | MessageName::Known("WM_SYSCHAR")
+        | MessageName::Known("WM_DEADCHAR")
+        | MessageName::Known("WM_SYSDEADCHAR") => {
+            let ch: String = match char::from_u32(wparam.0 as u32) {
+                Some(ch) if !ch.is_control() => format!("'{}'", ch),
+                _ => "-".to_string(),
+            };
+            format!("ch={} (0x{:02X}) {}", ch, wparam.0, format_keystroke_flags(lparam))
+        }
+        MessageName::Known("WM_MOUSEMOVE")
+        | MessageName::Known("WM_LBUTTONDOWN")
+        | MessageName::Known("WM_LBUTTONUP")
+        | MessageName::Known("WM_LBUTTONDBLCLK")
+        | MessageName::Known("WM_RBUTTONDOWN")
+        | MessageName::Known("WM_RBUTTONUP")
+        | MessageName::Known("WM_RBUTTONDBLCLK")
+        | MessageName::Known("WM_MBUTTONDOWN")
+        | MessageName::Known("WM_MBUTTONUP")
+        | MessageName::Known("WM_MBUTTONDBLCLK")
+        | MessageName::Known("WM_XBUTTONDOWN")
+        | MessageName::Known("WM_XBUTTONUP")
+        | MessageName::Known("WM_XBUTTONDBLCLK") => {
+            let points = make_points(lparam);
+            format!(
+                "x={} y={} keys={}",
+                points.x,
+                points.y,
+                format_mouse_keys(low_word(wparam.0))
+            )
+        }
+        MessageName::Known("WM_MOUSEWHEEL") | MessageName::Known("WM_MOUSEHWHEEL") => {
+            let points = make_points(lparam);
+            format!(
+                "delta={} x={} y={} keys={}",
+                get_wheel_delta_wparam(wparam),
+                points.x,
+                points.y,
+                format_mouse_keys(low_word(wparam.0))
+            )
+        }
+        MessageName::Known("WM_MOVE") | MessageName::Known("WM_NCHITTEST") => {
+            let points = make_points(lparam);
+            format!("x={} y={}", points.x, points.y)
+        }
+        MessageName::Known("WM_SIZE") => {
+            let kind: &str = match wparam.0 {
+                0 => "RESTORED",
+                1 => "MINIMIZED",
+                2 => "MAXIMIZED",
+                3 => "MAXSHOW",
+                4 => "MAXHIDE",
+                _ => "?",
+            };
+            format!(
+                "type={} w={} h={}",
+                kind,
+                low_word(lparam.0 as usize),
+                high_word(lparam.0 as usize)
+            )
+        }
+        MessageName::Known("WM_ACTIVATE") => {
+            let state: &str = match low_word(wparam.0) {
+                0 => "INACTIVE",
+                1 => "ACTIVE",
+                2 => "CLICKACTIVE",
+                _ => "?",
+            };
+            format!(
+                "state={} minimized={} other=0x{:X}",
+                state,
+                high_word(wparam.0) != 0,
+                lparam.0
+            )
+        }
+        MessageName::Known("WM_ACTIVATEAPP") => {
+            format!("active={} thread={}", wparam.0 != 0, lparam.0)
+        }
+        MessageName::Known("WM_SETFOCUS") | MessageName::Known("WM_KILLFOCUS") => {
+            format!("other=0x{:X}", wparam.0)
+        }
+        MessageName::Known("WM_SHOWWINDOW") => {
+            format!("show={} status={}", wparam.0 != 0, lparam.0)
+        }
+        MessageName::Known("WM_SETCURSOR") => format!(
+            "hwnd=0x{:X} hit={} msg={}",
+            wparam.0,
+            low_word(lparam.0 as usize),
+            id_to_name(high_word(lparam.0 as usize))
+        ),
+        MessageName::Known("WM_SYSCOMMAND") => {
+            let command: &str = match wparam.0 & 0xFFF0 {
+                0xF000 => "SIZE",
+                0xF010 => "MOVE",
+                0xF020 => "MINIMIZE",
+                0xF030 => "MAXIMIZE",
+                0xF060 => "CLOSE",
+                0xF100 => "KEYMENU",
+                0xF120 => "RESTORE",
+                0xF140 => "SCREENSAVE",
+                0xF170 => "MONITORPOWER",
+                _ => "?",
+            };
+            format!("cmd={} (0x{:04X})", command, wparam.0 & 0xFFF0)
+        }
+        MessageName::Known("WM_TIMER") => format!("id={}", wparam.0),
+        MessageName::Known("WM_DPICHANGED") => format!(
+            "dpi_x={} dpi_y={}",
+            low_word(wparam.0),
+            high_word(wparam.0)
+        ),
+        MessageName::Known("WM_CREATE")
+        | MessageName::Known("WM_DESTROY")
+        | MessageName::Known("WM_CLOSE")
+        | MessageName::Known("WM_PAINT")
+        | MessageName::Known("WM_NCDESTROY") => String::new(),
+        MessageName::Known("WM_QUIT") => format!("code={}", wparam.0),
+        _ => format!("wParam=0x{:X} lParam=0x{:X}", wparam.0, lparam.0),
+    };
 }
 
-pub fn dx_category_id_to_str(
-    category: windows::Win32::Graphics::Dxgi::DXGI_INFO_QUEUE_MESSAGE_CATEGORY,
-) -> Result<String, ()> {
-    match category.0 {
-        0 => Ok("Unknown".to_string()),
-        1 => Ok("Miscellaneous".to_string()),
-        2 => Ok("Initialization".to_string()),
-        3 => Ok("Cleanup".to_string()),
-        4 => Ok("Compilation".to_string()),
-        5 => Ok("State Creation".to_string()),
-        6 => Ok("State Setting".to_string()),
-        7 => Ok("State Getting".to_string()),
-        8 => Ok("Resource Manipulation".to_string()),
-        9 => Ok("Excecution".to_string()),
-        10 => Ok("Shader".to_string()),
-        _ => Err(()),
+/// Decode the keystroke flags in the lParam of the keyboard messages.
+/// See https://learn.microsoft.com/en-us/windows/win32/inputdev/about-keyboard-input#keystroke-message-flags
+fn format_keystroke_flags(lparam: LPARAM) -> String {
+    let flags: u32 = lparam.0 as u32;
+
+    let mut formatted: String = format!("repeat={} scan=0x{:02X}", flags & 0xFFFF, (flags >> 16) & 0xFF);
+    if (flags >> 24) & 1 == 1 {
+        formatted.push_str(" extended");
+    }
+    if (flags >> 29) & 1 == 1 {
+        formatted.push_str(" alt");
+    }
+    if (flags >> 30) & 1 == 1 {
+        formatted.push_str(" was_down");
+    }
+    if (flags >> 31) & 1 == 1 {
+        formatted.push_str(" released");
+    }
+    return formatted;
+}
+
+/// Decode the `MK_*` flags that are send with the mouse messages.
+fn format_mouse_keys(keys: u32) -> String {
+    const MOUSE_KEYS: [(u32, &str); 7] = [
+        (0x0001, "LBUTTON"),
+        (0x0002, "RBUTTON"),
+        (0x0004, "SHIFT"),
+        (0x0008, "CONTROL"),
+        (0x0010, "MBUTTON"),
+        (0x0020, "XBUTTON1"),
+        (0x0040, "XBUTTON2"),
+    ];
+
+    let names: Vec<&str> = MOUSE_KEYS
+        .iter()
+        .filter(|(flag, _)| keys & flag != 0)
+        .map(|(_, name)| *name)
+        .collect();
+
+    if names.is_empty() {
+        return "NONE".to_string();
+    }
+    return names.join("|");
+}
+
+/// Decides which messages end up in the spy log.
+#[derive(Debug, Clone, Default)]
+pub struct SpyFilter {
+    /// If this is not empty only these messages are logged
+    pub include: Vec<u32>,
+    /// These messages are never logged
+    pub exclude: Vec<u32>,
+}
+
+impl SpyFilter {
+    /// A filter that logs every message.
+    pub fn all() -> SpyFilter {
+        return SpyFilter::default();
+    }
+
+    /// A filter that skips the messages that are send many times per second while the mouse is moving.
+    pub fn quiet() -> SpyFilter {
+        return SpyFilter {
+            include: vec![],
+            exclude: ["WM_MOUSEMOVE", "WM_NCMOUSEMOVE", "WM_NCHITTEST", "WM_SETCURSOR", "WM_TIMER"]
+                .iter()
+                .filter_map(|name| name_to_id(name))
+                .collect(),
+        };
+    }
+
+    /// Parse a filter from a comma separated list of message names. Names starting with a `-` are excluded,
+    /// the others are included. For example `WM_KEYDOWN,WM_KEYUP` or `-WM_MOUSEMOVE,-WM_NCHITTEST`.
+    pub fn parse(spec: &str) -> Result<SpyFilter, String> {
+        let mut filter: SpyFilter = SpyFilter::all();
+
+        for name in spec.split(',').map(str::trim).filter(|name| !name.is_empty()) {
+            let (list, name) = match name.strip_prefix('-') {
+                Some(name) => (&mut filter.exclude, name),
+                None => (&mut filter.include, name),
+            };
+
+            match name_to_id(name) {
+                Some(id) => list.push(id),
+                None => return Err(format!("Unknown window message: {}", name)),
+            }
+        }
+
+        return Ok(filter);
+    }
+
+    pub fn allows(&self, msg: u32) -> bool {
+        if self.exclude.contains(&msg) {
+            return false;
+        }
+        return self.include.is_empty() || self.include.contains(&msg);
+    }
+}
+
+/// Logs the messages that are received by [`crate::window::Window::wndproc()`] with a timestamp.
+pub struct Spy {
+    pub filter: SpyFilter,
+    out: Box<dyn Write + Send>,
+    started: Instant,
+}
+
+impl Spy {
+    pub fn new(out: Box<dyn Write + Send>, filter: SpyFilter) -> Spy {
+        return Spy {
+            filter,
+            out,
+            started: Instant::now(),
+        };
+    }
+
+    /// Create a spy that writes to a file. The file is truncated if it already exists.
+    pub fn to_file(path: &Path, filter: SpyFilter) -> std::io::Result<Spy> {
+        let file: File = File::create(path)?;
+        return Ok(Spy::new(Box::new(BufWriter::new(file)), filter));
+    }
+
+    pub fn log(&mut self, msg: u32, wparam: WPARAM, lparam: LPARAM) {
+        if !self.filter.allows(msg) {
+            return;
+        }
+
+        let line: String = format_spy_line(self.started.elapsed(), msg, wparam, lparam);
+        // The spy is a debugging tool, we don't want to take the window down if the log can't be written
+        let _ = writeln!(self.out, "{}", line);
+    }
+
+    pub fn flush(&mut self) {
+        let _ = self.out.flush();
+    }
+}
+
+/// Format a single line of the spy log, like `[    1.250000] WM_KEYDOWN vk=RETURN repeat=1 scan=0x1C`.
+pub fn format_spy_line(elapsed: Duration, msg: u32, wparam: WPARAM, lparam: LPARAM) -> String {
+    return format!(
+        "[{:>5}.{:06}] {}",
+        elapsed.as_secs(),
+        elapsed.subsec_micros(),
+        format_message(msg, wparam, lparam)
+    );
+}
+
+/// The spy that is used by [`spy()`]. It lives in a static because we can't pass it to the wndproc.
+static SPY: Mutex<Option<Spy>> = Mutex::new(None);
+
+/// Start logging every window message that passes the filter of `spy`.
+pub fn start_spy(spy: Spy) {
+    if let Ok(mut current) = SPY.lock() {
+        *current = Some(spy);
+    }
+}
+
+/// Stop logging window messages and flush the log.
+pub fn stop_spy() {
+    if let Ok(mut current) = SPY.lock() {
+        if let Some(spy) = current.as_mut() {
+            spy.flush();
+        }
+        *current = None;
+    }
+}
+
+/// Check if a message is keyboard or mouse input
+pub fn is_input_message(msg: u32) -> bool {
+    // WM_KEYFIRST..=WM_KEYLAST and WM_MOUSEFIRST..=WM_MOUSELAST
+    return (0x0100..=0x0109).contains(&msg) || (0x0200..=0x020E).contains(&msg);
+}
+
+/// Log a message with the spy that was started with [`start_spy()`]. Does nothing if no spy is running.
+pub fn spy(msg: u32, wparam: WPARAM, lparam: LPARAM) {
+    if let Ok(mut current) = SPY.lock() {
+        if let Some(spy) = current.as_mut() {
+            spy.log(msg, wparam, lparam);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lparam(low: i16, high: i16) -> LPARAM {
+        return LPARAM(((high as u16 as u32) << 16 | low as u16 as u32) as isize);
+    }
+
+    /// A log that can still be read after it is moved into a [`Spy`]
+    #[derive(Clone, Default)]
+    struct SharedLog(std::sync::Arc<Mutex<Vec<u8>>>);
+
+    impl Write for SharedLog {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            return self.0.lock().unwrap().write(buf);
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            return Ok(());
+        }
+    }
+
+    impl SharedLog {
+        fn lines(&self) -> Vec<String> {
+            return String::from_utf8(self.0.lock().unwrap().clone())
+                .unwrap()
+                .lines()
+                .map(str::to_string)
+                .collect();
+        }
+    }
+
+    #[test]
+    fn tables_are_sorted() {
+        assert!(MESSAGES_BY_ID.windows(2).all(|pair| pair[0].0 <= pair[1].0));
+        assert!(MESSAGES_BY_NAME.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        assert!(VIRTUAL_KEYS.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    }
+
+    #[test]
+    fn known_names_round_trip() {
+        for (name, id) in MESSAGES_BY_NAME.iter().copied() {
+            assert_eq!(name_to_id(name), Some(id), "{}", name);
+            // Aliases like WM_CTLCOLOR share an id, the preferred name still maps back to it
+            assert_eq!(name_to_id(&id_to_name(id).to_string()), Some(id), "{}", name);
+        }
+        assert_eq!(id_to_name(0x0100), MessageName::Known("WM_KEYDOWN"));
+        assert_eq!(name_to_id("WM_KEYDOWN"), Some(0x0100));
+        assert_eq!(name_to_id("WM_NOT_A_MESSAGE"), None);
+    }
+
+    #[test]
+    fn private_ranges_round_trip() {
+        assert_eq!(id_to_name(WM_USER + 5), MessageName::User(5));
+        assert_eq!(id_to_name(WM_USER + 5).to_string(), "WM_USER+5");
+        assert_eq!(id_to_name(WM_APP + 0x10).to_string(), "WM_APP+16");
+        assert_eq!(id_to_name(0xC123).to_string(), "REGISTERED_0xC123");
+        for id in [WM_USER + 1, WM_USER + 0x100, 0x7FFF, WM_APP + 1, WM_APP + 0x3FFF] {
+            assert_eq!(name_to_id(&id_to_name(id).to_string()), Some(id));
+        }
+        assert_eq!(name_to_id("WM_APP+0x10"), Some(WM_APP + 0x10));
+        // Offsets that leave the range aren't valid
+        assert_eq!(name_to_id("WM_USER+0x7C00"), None);
+        assert_eq!(name_to_id("WM_APP+0x4000"), None);
+        assert_eq!(name_to_id("WM_USER+x"), None);
+    }
+
+    #[test]
+    fn format_params_decodes_size() {
+        let size: u32 = name_to_id("WM_SIZE").unwrap();
+        assert_eq!(format_params(size, WPARAM(2), lparam(1024, 768)), "type=MAXIMIZED w=1024 h=768");
+        assert_eq!(format_params(size, WPARAM(9), lparam(0, 0)), "type=? w=0 h=0");
+    }
+
+    #[test]
+    fn format_params_decodes_keys() {
+        let keydown: u32 = name_to_id("WM_KEYDOWN").unwrap();
+        // Repeat count 1, scan code 0x1C, extended and previously down
+        let flags: isize = 1 | 0x1C << 16 | 1 << 24 | 1 << 30;
+        assert_eq!(
+            format_params(keydown, WPARAM(0x0D), LPARAM(flags)),
+            "vk=RETURN repeat=1 scan=0x1C extended was_down"
+        );
+        assert_eq!(
+            format_message(keydown, WPARAM(0x41), LPARAM(1)),
+            "WM_KEYDOWN vk=A repeat=1 scan=0x00"
+        );
+    }
+
+    #[test]
+    fn format_params_decodes_mouse_moves() {
+        let mousemove: u32 = name_to_id("WM_MOUSEMOVE").unwrap();
+        assert_eq!(
+            format_params(mousemove, WPARAM(0x0001 | 0x0008), lparam(-5, 300)),
+            "x=-5 y=300 keys=LBUTTON|CONTROL"
+        );
+        assert_eq!(format_params(mousemove, WPARAM(0), lparam(10, 20)), "x=10 y=20 keys=NONE");
+    }
+
+    #[test]
+    fn format_params_falls_back_to_hex() {
+        assert_eq!(format_params(WM_USER + 1, WPARAM(0xAB), LPARAM(0x10)), "wParam=0xAB lParam=0x10");
+        assert_eq!(format_message(name_to_id("WM_CLOSE").unwrap(), WPARAM(0), LPARAM(0)), "WM_CLOSE");
+    }
+
+    #[test]
+    fn spy_filter_parses_includes_and_excludes() {
+        let filter: SpyFilter = SpyFilter::parse("WM_KEYDOWN, -WM_MOUSEMOVE").unwrap();
+        assert!(filter.allows(name_to_id("WM_KEYDOWN").unwrap()));
+        assert!(!filter.allows(name_to_id("WM_KEYUP").unwrap()));
+        assert!(!filter.allows(name_to_id("WM_MOUSEMOVE").unwrap()));
+        assert!(SpyFilter::parse("WM_NOPE").is_err());
+    }
+
+    #[test]
+    fn spy_lines_have_a_timestamp_and_the_decoded_message() {
+        assert_eq!(
+            format_spy_line(
+                Duration::from_micros(1_250_000),
+                name_to_id("WM_KEYDOWN").unwrap(),
+                WPARAM(0x0D),
+                LPARAM(1 | 0x1C << 16)
+            ),
+            "[    1.250000] WM_KEYDOWN vk=RETURN repeat=1 scan=0x1C"
+        );
+        assert_eq!(
+            format_spy_line(
+                Duration::from_micros(123_456_789),
+                name_to_id("WM_CLOSE").unwrap(),
+                WPARAM(0),
+                LPARAM(0)
+            ),
+            "[  123.456789] WM_CLOSE"
+        );
+    }
+
+    #[test]
+    fn spy_logs_the_messages_that_pass_its_filter() {
+        let log: SharedLog = SharedLog::default();
+        let mut spy: Spy = Spy::new(Box::new(log.clone()), SpyFilter::parse("-WM_MOUSEMOVE").unwrap());
+        spy.log(name_to_id("WM_KEYDOWN").unwrap(), WPARAM(0x41), LPARAM(1));
+        spy.log(name_to_id("WM_MOUSEMOVE").unwrap(), WPARAM(0), lparam(10, 20));
+        spy.log(name_to_id("WM_SIZE").unwrap(), WPARAM(0), lparam(800, 600));
+        spy.log(WM_USER + 1, WPARAM(0xAB), LPARAM(0x10));
+        spy.flush();
+
+        // The timestamps depend on how fast the test runs
+        let messages: Vec<String> = log
+            .lines()
+            .iter()
+            .map(|line| {
+                let (timestamp, message) = line.split_once("] ").unwrap();
+                assert!(timestamp.starts_with('[') && timestamp.contains('.'), "{}", line);
+                return message.to_string();
+            })
+            .collect();
+        assert_eq!(
+            messages,
+            vec![
+                "WM_KEYDOWN vk=A repeat=1 scan=0x00",
+                "WM_SIZE type=RESTORED w=800 h=600",
+                "WM_USER+1 wParam=0xAB lParam=0x10",
+            ]
+        );
+
+        // Only the messages that are included
+        let log: SharedLog = SharedLog::default();
+        let mut spy: Spy = Spy::new(Box::new(log.clone()), SpyFilter::parse("WM_SIZE").unwrap());
+        spy.log(name_to_id("WM_KEYDOWN").unwrap(), WPARAM(0x41), LPARAM(1));
+        spy.log(name_to_id("WM_SIZE").unwrap(), WPARAM(0), lparam(800, 600));
+        assert_eq!(log.lines().len(), 1);
+        assert!(log.lines()[0].ends_with("] WM_SIZE type=RESTORED w=800 h=600"));
     }
 }